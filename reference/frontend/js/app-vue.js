@@ -33,9 +33,13 @@ const app = createApp({
         }
     },
     mounted() {
-        // Start Polling
+        // Start Polling (10Hz; 1Hz system status only while the screener stream is up)
         this.poll();
-        setInterval(this.poll, 100); // 10Hz
+        let ticks = 0;
+        setInterval(() => {
+            if (!store.streaming || ++ticks % 10 === 0) this.poll();
+        }, 100);
+        store.connectScreenerStream();
         
         // Remove loader
         const loader = document.getElementById('loader');
@@ -27,8 +27,10 @@ export default {
                             <tr>
                                 <th class="text-left">SYMBOL</th>
                                 <th class="text-right">SPREAD</th>
+                                <th class="text-right">EDGE</th>
                                 <th class="text-right">RANGE (2m)</th>
                                 <th class="text-right">HITS</th>
+                                <th class="text-right">LIQ (2m)</th>
                                 <th class="text-right">AVG HL</th>
                                 <th class="text-right">ACTION</th>
                             </tr>
@@ -37,15 +39,17 @@ export default {
                             <tr v-for="s in store.screener" :key="s.symbol">
                                 <td class="text-left font-mono font-bold">{{ s.symbol }}</td>
                                 <td class="text-right" :class="spreadColor(s.currentSpread)">{{ fmtPct(s.currentSpread) }}</td>
+                                <td class="text-right" :class="edgeColor(s)" :title="'break-even ' + fmtPct(s.breakEven)">{{ fmtPct(s.edgeAfterCosts) }}</td>
                                 <td class="text-right" :class="rangeColor(s)">{{ fmtRange(s) }}</td>
                                 <td class="text-right">{{ s.hits }}</td>
+                                <td class="text-right" :class="liqColor(s)">{{ fmtUsd(s.liquidationVolume) }}</td>
                                 <td class="text-right">{{ s.estHalfLife?.toFixed(2) || 'N/A' }}</td>
                                 <td class="text-right">
                                     <button class="btn btn-primary" @click="spawn(s.symbol)">+ BOT</button>
                                 </td>
                             </tr>
                             <tr v-if="store.screener.length === 0">
-                                <td colspan="6" class="text-center text-dim" style="padding:40px;">SCANNING MARKETS... NO SIGNALS YET</td>
+                                <td colspan="8" class="text-center text-dim" style="padding:40px;">SCANNING MARKETS... NO SIGNALS YET</td>
                             </tr>
                         </tbody>
                     </table>
@@ -68,6 +72,24 @@ export default {
             if (s.isSpreadNA) return 'N/A';
             return (s.spreadRange * 100).toFixed(3) + '%';
         },
+        fmtUsd(v) {
+            if (!v) return '-';
+            return new Intl.NumberFormat('en-US', {
+                style: 'currency',
+                currency: 'USD',
+                notation: 'compact',
+                maximumFractionDigits: 1
+            }).format(v);
+        },
+        liqColor(s) {
+            if ((s.liquidationVolume || 0) > 1000000) return 'text-red';  // > $1M = cascade
+            if ((s.liquidationVolume || 0) > 100000) return 'text-gold';
+            return 'text-secondary';
+        },
+        edgeColor(s) {
+            if ((s.edgeAfterCosts || 0) > 0) return 'text-green';  // nets positive after costs
+            return 'text-dim';
+        },
         spreadColor(spread) {
             const s = spread || 0;
             if (s < -0.0025) return 'text-green';  // < -0.25% = green (Binance cheaper)
@@ -1,6 +1,7 @@
 import { reactive } from 'vue';
 
 const API_URL = '/api/dashboard/stats';
+const STREAM_URL = '/api/screener/ws';
 
 // Define state first
 const store = reactive({
@@ -17,7 +18,8 @@ const store = reactive({
     bots: [],          // Raw bot data from API (not used in new arch)
     logs: [],          // System Logs
     history: [],       // PnL History for Charts (not used in new arch)
-    screener: [],      // Screener Data from dashboard API
+    screener: [],      // Screener Data from dashboard API / WS stream
+    streaming: false,  // Screener rows pushed over WS (polling only refreshes system status)
     selectedBotSymbol: null, // Currently inspected bot
     
     // --- Computed Helpers (Getters) ---
@@ -56,8 +58,8 @@ store.fetchStats = async function() {
         store.system.bybitConnected = data.system?.bybitConnected || false;
         store.system.lastUpdate = Date.now();
         
-        // Update Screener Data
-        store.screener = data.screener || [];
+        // Update Screener Data (stream owns it while connected)
+        if (!store.streaming) store.screener = data.screener || [];
         
         // Record History (Max 300 points) - Approx 1Hz
         if (Date.now() % 1000 < 500) {
@@ -75,6 +77,30 @@ store.fetchStats = async function() {
     }
 };
 
+// Screener stream: full snapshot, then deltas (changed rows + removed symbols)
+store.connectScreenerStream = function() {
+    const proto = location.protocol === 'https:' ? 'wss:' : 'ws:';
+    const ws = new WebSocket(`${proto}//${location.host}${STREAM_URL}`);
+    const rows = new Map();
+
+    ws.onopen = () => { store.streaming = true; };
+    ws.onmessage = (event) => {
+        const msg = JSON.parse(event.data);
+        if (msg.type === 'full') {
+            rows.clear();
+            msg.rows.forEach(r => rows.set(r.symbol, r));
+        } else if (msg.type === 'delta') {
+            msg.changed.forEach(r => rows.set(r.symbol, r));
+            msg.removed.forEach(s => rows.delete(s));
+        }
+        store.screener = Array.from(rows.values());
+    };
+    ws.onclose = () => {
+        store.streaming = false;
+        setTimeout(store.connectScreenerStream, 2000);
+    };
+};
+
 // Legacy: Keep for backward compatibility but use dashboard endpoint
 store.fetchScreener = async function() {
     // Screener data now comes from dashboard endpoint
@@ -45,7 +45,7 @@ fn benchmark_fixed_point_parse(c: &mut Criterion) {
 }
 
 fn benchmark_fixed_point_write(c: &mut Criterion) {
-    let value = FixedPoint8::from_raw(123_456_789_00);
+    let value = FixedPoint8::from_raw(12_345_678_900);
     let mut buf = [0u8; 32];
 
     c.bench_function("fixed_point_write_to_buffer", |bench| {
@@ -3,10 +3,17 @@
 //! Target: <100ns per update
 
 use criterion::{black_box, criterion_group, criterion_main, Criterion};
-use rust_hft::core::{FixedPoint8, Symbol, TickerData};
+use rust_hft::core::{FixedPoint8, Symbol, SymbolRegistry, TickerData};
 use rust_hft::exchanges::Exchange;
 use rust_hft::hot_path::ThresholdTracker;
 
+fn btc() -> Symbol {
+    if !SymbolRegistry::is_initialized() {
+        let _ = SymbolRegistry::initialize(&["BTCUSDT".to_string()]);
+    }
+    Symbol::from_bytes(b"BTCUSDT").unwrap_or_default()
+}
+
 fn make_ticker(symbol: Symbol, price: i64) -> TickerData {
     TickerData {
         symbol,
@@ -15,12 +22,13 @@ fn make_ticker(symbol: Symbol, price: i64) -> TickerData {
         bid_qty: FixedPoint8::ONE,
         ask_qty: FixedPoint8::ONE,
         timestamp: 1000,
+        update_id: 0,
     }
 }
 
 fn bench_tracker_update(c: &mut Criterion) {
     let mut tracker = ThresholdTracker::new();
-    let symbol = Symbol::BTCUSDT;
+    let symbol = btc();
     let ticker = make_ticker(symbol, 100_000_000);
     
     // Warmup
@@ -0,0 +1,54 @@
+//! Build script
+//!
+//! With the `embed-frontend` feature, generates the table of dashboard
+//! assets included into the binary (`infrastructure::embedded`). The asset
+//! directory defaults to `../reference/frontend`; `HFT_FRONTEND_DIR`
+//! overrides it.
+
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+fn main() {
+    if std::env::var_os("CARGO_FEATURE_EMBED_FRONTEND").is_none() {
+        return;
+    }
+    println!("cargo:rerun-if-env-changed=HFT_FRONTEND_DIR");
+
+    let manifest_dir = PathBuf::from(std::env::var("CARGO_MANIFEST_DIR").expect("set by cargo"));
+    let root = std::env::var_os("HFT_FRONTEND_DIR")
+        .map(PathBuf::from)
+        .unwrap_or_else(|| manifest_dir.join("../reference/frontend"));
+    let root = root
+        .canonicalize()
+        .unwrap_or_else(|e| panic!("frontend directory {}: {}", root.display(), e));
+
+    let mut files = Vec::new();
+    collect(&root, &mut files).unwrap_or_else(|e| panic!("reading {}: {}", root.display(), e));
+    files.sort();
+
+    // Sorted by relative path: looked up with binary search
+    let mut table = String::from("&[\n");
+    for file in &files {
+        let relative = file.strip_prefix(&root).expect("collected under root");
+        let key = relative.to_string_lossy().replace('\\', "/");
+        table.push_str(&format!("    ({:?}, include_bytes!({:?})),\n", key, file.display().to_string()));
+    }
+    table.push_str("]\n");
+
+    let out = PathBuf::from(std::env::var("OUT_DIR").expect("set by cargo")).join("frontend_assets.rs");
+    fs::write(&out, table).unwrap_or_else(|e| panic!("writing {}: {}", out.display(), e));
+}
+
+fn collect(dir: &Path, files: &mut Vec<PathBuf>) -> io::Result<()> {
+    println!("cargo:rerun-if-changed={}", dir.display());
+    for entry in fs::read_dir(dir)? {
+        let path = entry?.path();
+        if path.is_dir() {
+            collect(&path, files)?;
+        } else {
+            files.push(path);
+        }
+    }
+    Ok(())
+}
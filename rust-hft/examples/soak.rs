@@ -0,0 +1,253 @@
+//! Soak test: synthetic high-rate Binance/Bybit feed through parsers and engine
+//!
+//! Generates bookTicker / tickers frames for both venues, parses them with the
+//! production parsers and feeds the results into `AppEngine::handle_message`
+//! (dedup → tracker → sizing/journal → strategies). Reports throughput,
+//! per-message latency percentiles and heap allocations on the measured path.
+//!
+//! Usage:
+//!   cargo run --release --example soak -- --rate 50000 --secs 10 --symbols 200
+//!
+//! `--rate 0` runs unthrottled to find the capacity limit.
+
+use rust_hft::core::registry::SymbolRegistry;
+use rust_hft::engine::AppEngine;
+use rust_hft::exchanges::{BinanceParser, BybitParser, Exchange, ExchangeMessage};
+use rust_hft::hot_path::ThresholdTracker;
+use rust_hft::infrastructure::metrics::MetricsCollector;
+use rust_hft::ws::ConnectionId;
+#[cfg(not(feature = "alloc-tracking"))]
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::fmt::Write;
+#[cfg(not(feature = "alloc-tracking"))]
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::RwLock;
+
+/// System allocator wrapper counting allocations
+/// (with `alloc-tracking` the library's tracking allocator is used instead)
+#[cfg(not(feature = "alloc-tracking"))]
+struct CountingAlloc;
+
+#[cfg(not(feature = "alloc-tracking"))]
+static ALLOCATIONS: AtomicU64 = AtomicU64::new(0);
+
+#[cfg(not(feature = "alloc-tracking"))]
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.alloc(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        ALLOCATIONS.fetch_add(1, Ordering::Relaxed);
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+#[cfg(not(feature = "alloc-tracking"))]
+#[global_allocator]
+static GLOBAL: CountingAlloc = CountingAlloc;
+
+/// Allocations made so far
+#[cfg(not(feature = "alloc-tracking"))]
+fn allocations() -> u64 {
+    ALLOCATIONS.load(Ordering::Relaxed)
+}
+
+#[cfg(feature = "alloc-tracking")]
+fn allocations() -> u64 {
+    rust_hft::infrastructure::alloc_tracking::thread_allocations()
+}
+
+/// Upper bound on stored latency samples (memory guard for long runs)
+const MAX_SAMPLES: usize = 50_000_000;
+
+struct Args {
+    rate: u64,
+    secs: u64,
+    symbols: usize,
+}
+
+fn parse_args() -> Args {
+    let mut args = Args {
+        rate: 50_000,
+        secs: 10,
+        symbols: 200,
+    };
+    let mut it = std::env::args().skip(1);
+    while let Some(flag) = it.next() {
+        let value = it.next().and_then(|v| v.parse::<u64>().ok());
+        match (flag.as_str(), value) {
+            ("--rate", Some(v)) => args.rate = v,
+            ("--secs", Some(v)) => args.secs = v,
+            ("--symbols", Some(v)) => args.symbols = (v as usize).max(1),
+            _ => {
+                eprintln!("usage: soak [--rate MSG_PER_SEC] [--secs N] [--symbols N]");
+                std::process::exit(2);
+            }
+        }
+    }
+    args
+}
+
+/// Deterministic price path (xorshift), +-5 ticks around base
+struct PricePath(u64);
+
+impl PricePath {
+    fn next_ticks(&mut self) -> i64 {
+        self.0 ^= self.0 << 13;
+        self.0 ^= self.0 >> 7;
+        self.0 ^= self.0 << 17;
+        (self.0 % 11) as i64 - 5
+    }
+}
+
+fn percentile(sorted: &[u64], p: f64) -> u64 {
+    if sorted.is_empty() {
+        return 0;
+    }
+    let idx = ((sorted.len() as f64 - 1.0) * p).round() as usize;
+    sorted[idx]
+}
+
+/// Write bookTicker frame; price in 1e-2 units
+fn binance_frame(buf: &mut String, symbol: &str, update_id: u64, price: i64) {
+    buf.clear();
+    let _ = write!(
+        buf,
+        r#"{{"e":"bookTicker","u":{},"s":"{}","b":"{}.{:02}","B":"3.5","a":"{}.{:02}","A":"2.1","T":1700000000000,"E":1700000000000}}"#,
+        update_id,
+        symbol,
+        price / 100,
+        price % 100,
+        (price + 1) / 100,
+        (price + 1) % 100
+    );
+}
+
+/// Write Bybit tickers snapshot frame; price in 1e-2 units
+fn bybit_frame(buf: &mut String, symbol: &str, seq: u64, price: i64) {
+    buf.clear();
+    let _ = write!(
+        buf,
+        r#"{{"topic":"tickers.{}","type":"snapshot","data":{{"symbol":"{}","bid1Price":"{}.{:02}","bid1Size":"4.2","ask1Price":"{}.{:02}","ask1Size":"1.7"}},"cs":{},"ts":1700000000000}}"#,
+        symbol,
+        symbol,
+        price / 100,
+        price % 100,
+        (price + 1) / 100,
+        (price + 1) % 100,
+        seq
+    );
+}
+
+#[tokio::main(flavor = "current_thread")]
+async fn main() {
+    let args = parse_args();
+
+    let names: Vec<String> = (0..args.symbols).map(|i| format!("SOAK{}USDT", i)).collect();
+    SymbolRegistry::initialize(&names).expect("registry init");
+
+    let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+    let metrics = Arc::new(MetricsCollector::new());
+    let mut engine = AppEngine::new(tracker, metrics.clone());
+
+    let duration = Duration::from_secs(args.secs);
+    let expected = if args.rate == 0 { MAX_SAMPLES } else { (args.rate * args.secs) as usize };
+    let mut latencies: Vec<u64> = Vec::with_capacity(expected.min(MAX_SAMPLES));
+    let mut frame = String::with_capacity(512);
+    let mut path = PricePath(0x9E37_79B9_7F4A_7C15);
+    let mut seq = 0u64;
+    let mut parse_failures = 0u64;
+    let mut measured_allocs = 0u64;
+
+    println!(
+        "soak: rate={} msg/s secs={} symbols={}",
+        if args.rate == 0 { "unthrottled".to_string() } else { args.rate.to_string() },
+        args.secs,
+        args.symbols
+    );
+
+    let start = Instant::now();
+    loop {
+        let elapsed = start.elapsed();
+        if elapsed >= duration {
+            break;
+        }
+        // Pace to target rate (sleep when ahead of schedule)
+        if args.rate > 0 {
+            let due = (elapsed.as_secs_f64() * args.rate as f64) as u64;
+            if seq >= due {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+                continue;
+            }
+        }
+
+        seq += 1;
+        let symbol = &names[(seq as usize / 2) % names.len()];
+        // Bybit quotes 0-10 ticks above Binance → spreads cross the threshold sometimes
+        let base = 10_000 + path.next_ticks();
+        let venue = if seq % 2 == 0 { Exchange::Binance } else { Exchange::Bybit };
+        match venue {
+            Exchange::Binance => binance_frame(&mut frame, symbol, seq, base),
+            Exchange::Bybit => bybit_frame(&mut frame, symbol, seq, base + 5 + path.next_ticks()),
+        }
+
+        let allocs_before = allocations();
+        let t0 = Instant::now();
+        let parsed = match venue {
+            Exchange::Binance => BinanceParser::parse_ticker(frame.as_bytes()),
+            Exchange::Bybit => BybitParser::parse_ticker(frame.as_bytes()),
+        };
+        match parsed {
+            Some(result) => {
+                let ticker = result.data.with_update_id(seq);
+                engine
+                    .handle_message(ConnectionId(venue.index() as u64), t0, ExchangeMessage::Ticker(venue, ticker))
+                    .await;
+            }
+            None => parse_failures += 1,
+        }
+        let latency = t0.elapsed().as_nanos() as u64;
+        measured_allocs += allocations() - allocs_before;
+
+        if latencies.len() < MAX_SAMPLES {
+            latencies.push(latency);
+        }
+    }
+    let elapsed = start.elapsed();
+
+    latencies.sort_unstable();
+    let journaled = engine.journal().read().await.total();
+    let snapshot = metrics.snapshot();
+
+    println!("messages:       {}", seq);
+    println!("parse failures: {}", parse_failures);
+    println!("processed:      {}", snapshot.total_messages);
+    println!("opportunities:  {}", journaled);
+    println!("elapsed:        {:.2}s", elapsed.as_secs_f64());
+    println!("throughput:     {:.0} msg/s", seq as f64 / elapsed.as_secs_f64());
+    println!(
+        "latency (us):   p50={:.2} p99={:.2} p99.9={:.2} max={:.2}",
+        percentile(&latencies, 0.50) as f64 / 1_000.0,
+        percentile(&latencies, 0.99) as f64 / 1_000.0,
+        percentile(&latencies, 0.999) as f64 / 1_000.0,
+        latencies.last().copied().unwrap_or(0) as f64 / 1_000.0
+    );
+    println!(
+        "allocations:    {} on measured path ({:.3}/msg)",
+        measured_allocs,
+        measured_allocs as f64 / seq.max(1) as f64
+    );
+
+    #[cfg(feature = "alloc-tracking")]
+    for (subsystem, count) in rust_hft::infrastructure::alloc_tracking::snapshot() {
+        println!("  {:<8} {}", subsystem.name(), count);
+    }
+}
@@ -0,0 +1,143 @@
+//! Relative spread type
+//!
+//! Spreads, thresholds and break-even costs are ratios, not prices. Keeping
+//! them in a distinct type stops them being mixed with prices and makes the
+//! unit explicit at every conversion: stored as a fraction in FixedPoint8
+//! (raw 250_000 = 0.0025 = 25 bps = 0.25%).
+
+use super::FixedPoint8;
+use std::fmt;
+
+/// Relative spread (fraction, displayed in basis points)
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+#[repr(transparent)]
+pub struct Bps(FixedPoint8);
+
+/// Raw FixedPoint8 units per basis point (0.0001)
+const RAW_PER_BPS: i64 = FixedPoint8::SCALE / 10_000;
+
+impl Bps {
+    pub const ZERO: Self = Self(FixedPoint8::ZERO);
+    pub const MAX: Self = Self(FixedPoint8::MAX);
+    pub const MIN: Self = Self(FixedPoint8::MIN);
+
+    /// From a fraction (0.0025 = 25 bps)
+    #[inline(always)]
+    pub const fn from_fraction(fraction: FixedPoint8) -> Self {
+        Self(fraction)
+    }
+
+    /// From raw FixedPoint8 fraction units
+    #[inline(always)]
+    pub const fn from_raw(raw: i64) -> Self {
+        Self(FixedPoint8::from_raw(raw))
+    }
+
+    /// From whole basis points
+    #[inline(always)]
+    pub const fn from_bps(bps: i64) -> Self {
+        Self(FixedPoint8::from_raw(bps * RAW_PER_BPS))
+    }
+
+    /// From a fraction given as f64 (config/cold path)
+    #[inline]
+    pub fn from_fraction_f64(fraction: f64) -> Option<Self> {
+        FixedPoint8::from_f64(fraction).map(Self)
+    }
+
+    /// Relative difference `(value - base) / base` (None if base is zero)
+    #[inline]
+    pub fn relative(value: FixedPoint8, base: FixedPoint8) -> Option<Self> {
+        value.checked_sub(base)?.safe_div(base).map(Self)
+    }
+
+    /// As a fraction
+    #[inline(always)]
+    pub const fn as_fraction(&self) -> FixedPoint8 {
+        self.0
+    }
+
+    /// Raw FixedPoint8 fraction units
+    #[inline(always)]
+    pub const fn as_raw(&self) -> i64 {
+        self.0.as_raw()
+    }
+
+    /// Fraction as f64 (0.0025 for 25 bps)
+    #[inline]
+    pub fn fraction_f64(&self) -> f64 {
+        self.0.to_f64()
+    }
+
+    /// Basis points as f64
+    #[inline]
+    pub fn bps_f64(&self) -> f64 {
+        self.0.to_f64() * 10_000.0
+    }
+
+    /// Percent as f64
+    #[inline]
+    pub fn percent_f64(&self) -> f64 {
+        self.0.to_f64() * 100.0
+    }
+
+    #[inline(always)]
+    pub const fn checked_add(&self, other: Self) -> Option<Self> {
+        match self.0.checked_add(other.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn checked_sub(&self, other: Self) -> Option<Self> {
+        match self.0.checked_sub(other.0) {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn checked_abs(&self) -> Option<Self> {
+        match self.0.checked_abs() {
+            Some(v) => Some(Self(v)),
+            None => None,
+        }
+    }
+
+    #[inline(always)]
+    pub const fn is_positive(&self) -> bool {
+        self.0.is_positive()
+    }
+
+    #[inline(always)]
+    pub const fn is_negative(&self) -> bool {
+        self.0.is_negative()
+    }
+}
+
+impl fmt::Display for Bps {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:.2} bps", self.bps_f64())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversions() {
+        let threshold = Bps::from_raw(250_000);
+        assert_eq!(threshold, Bps::from_bps(25));
+        assert_eq!(threshold.as_fraction(), FixedPoint8::from_raw(250_000));
+        assert!((threshold.bps_f64() - 25.0).abs() < 1e-9);
+        assert!((threshold.percent_f64() - 0.25).abs() < 1e-9);
+        assert_eq!(Bps::from_fraction_f64(0.0025), Some(threshold));
+        assert_eq!(threshold.to_string(), "25.00 bps");
+
+        let spread = Bps::relative(FixedPoint8::from_raw(101 * FixedPoint8::SCALE), FixedPoint8::from_raw(100 * FixedPoint8::SCALE));
+        assert_eq!(spread, Some(Bps::from_bps(100)));
+        assert_eq!(Bps::relative(FixedPoint8::ONE, FixedPoint8::ZERO), None);
+    }
+}
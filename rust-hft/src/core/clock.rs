@@ -0,0 +1,235 @@
+//! Process time source
+//!
+//! Replaces scattered `SystemTime::now` / `Instant::now` calls on busy paths:
+//!
+//! - Coarse clock: wall-clock and monotonic time cached in atomics and
+//!   refreshed by [`spawn_ticker`] (every millisecond by default). A read is
+//!   a couple of atomic loads. Until a ticker runs (tests, tools) reads fall
+//!   back to the system clocks.
+//! - Fine clock: [`now`] extrapolates from the last tick with the invariant
+//!   TSC on x86_64 once [`enable_tsc`] has calibrated it, otherwise it is
+//!   `Instant::now`. Re-anchoring every tick bounds calibration drift to a
+//!   fraction of a tick.
+
+use std::sync::atomic::{fence, AtomicU64, Ordering};
+use std::sync::OnceLock;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+use tokio::task::JoinHandle;
+use tokio::time::MissedTickBehavior;
+
+/// TSC calibration window (startup only)
+const CALIBRATION_WINDOW: Duration = Duration::from_millis(20);
+
+/// Process-wide coarse clock
+static CLOCK: CoarseClock = CoarseClock::new();
+/// Calibrated TSC rate (ns per tick, f64 bits; 0 = TSC disabled)
+static NS_PER_TSC: AtomicU64 = AtomicU64::new(0);
+
+/// Cached clock readings published as a seqlock (single writer)
+struct CoarseClock {
+    /// Odd while a tick is being written, 0 = never ticked
+    seq: AtomicU64,
+    /// Wall clock (ns since epoch)
+    unix_ns: AtomicU64,
+    /// Monotonic time (ns since [`anchor`])
+    mono_ns: AtomicU64,
+    /// TSC reading taken with `mono_ns` (0 = TSC unused)
+    tsc: AtomicU64,
+}
+
+impl CoarseClock {
+    const fn new() -> Self {
+        Self {
+            seq: AtomicU64::new(0),
+            unix_ns: AtomicU64::new(0),
+            mono_ns: AtomicU64::new(0),
+            tsc: AtomicU64::new(0),
+        }
+    }
+
+    fn publish(&self, unix: u64, mono: u64, tsc: u64) {
+        self.seq.fetch_add(1, Ordering::Relaxed);
+        fence(Ordering::Release);
+        self.unix_ns.store(unix, Ordering::Relaxed);
+        self.mono_ns.store(mono, Ordering::Relaxed);
+        self.tsc.store(tsc, Ordering::Relaxed);
+        self.seq.fetch_add(1, Ordering::Release);
+    }
+
+    /// Consistent (unix ns, monotonic ns, tsc) of the last tick (None before the first)
+    #[inline]
+    fn read(&self) -> Option<(u64, u64, u64)> {
+        loop {
+            let seq = self.seq.load(Ordering::Acquire);
+            if seq == 0 {
+                return None;
+            }
+            if seq % 2 == 1 {
+                std::hint::spin_loop();
+                continue;
+            }
+            let tick = (
+                self.unix_ns.load(Ordering::Relaxed),
+                self.mono_ns.load(Ordering::Relaxed),
+                self.tsc.load(Ordering::Relaxed),
+            );
+            fence(Ordering::Acquire);
+            if self.seq.load(Ordering::Relaxed) == seq {
+                return Some(tick);
+            }
+        }
+    }
+}
+
+/// Origin of the cached monotonic time
+fn anchor() -> Instant {
+    static ANCHOR: OnceLock<Instant> = OnceLock::new();
+    *ANCHOR.get_or_init(Instant::now)
+}
+
+fn system_unix_nanos() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |d| d.as_nanos() as u64)
+}
+
+#[inline]
+fn last_tick() -> Option<(u64, u64, u64)> {
+    CLOCK.read()
+}
+
+/// Refresh the coarse clock (single writer: the ticker task)
+fn tick() {
+    let anchor = anchor();
+    let tsc = if NS_PER_TSC.load(Ordering::Relaxed) != 0 { tsc::read() } else { 0 };
+    CLOCK.publish(system_unix_nanos(), anchor.elapsed().as_nanos() as u64, tsc);
+}
+
+/// Refresh the coarse clock every `interval` on the current runtime
+pub fn spawn_ticker(interval: Duration) -> JoinHandle<()> {
+    tick();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval.max(Duration::from_micros(100)));
+        ticker.set_missed_tick_behavior(MissedTickBehavior::Skip);
+        loop {
+            ticker.tick().await;
+            tick();
+        }
+    })
+}
+
+/// Wall-clock time (ns since epoch), at most one tick old
+#[inline]
+pub fn coarse_unix_nanos() -> u64 {
+    last_tick().map_or_else(system_unix_nanos, |(unix, _, _)| unix)
+}
+
+/// Wall-clock time (ms since epoch), at most one tick old
+#[inline]
+pub fn coarse_unix_ms() -> u64 {
+    coarse_unix_nanos() / 1_000_000
+}
+
+/// Monotonic time, at most one tick old
+#[inline]
+pub fn coarse_now() -> Instant {
+    match last_tick() {
+        Some((_, mono, _)) => anchor() + Duration::from_nanos(mono),
+        None => Instant::now(),
+    }
+}
+
+/// Fine monotonic time (TSC-extrapolated when enabled and ticking)
+#[inline]
+pub fn now() -> Instant {
+    let ns_per_tsc = f64::from_bits(NS_PER_TSC.load(Ordering::Relaxed));
+    if ns_per_tsc > 0.0 {
+        if let Some((_, mono, tick_tsc)) = last_tick().filter(|&(_, _, tsc)| tsc != 0) {
+            let elapsed = tsc::read().saturating_sub(tick_tsc) as f64 * ns_per_tsc;
+            return anchor() + Duration::from_nanos(mono + elapsed as u64);
+        }
+    }
+    Instant::now()
+}
+
+/// Calibrate the TSC and use it for [`now`]; false if unavailable
+///
+/// Needs x86_64 with an invariant TSC. Blocks for the calibration window.
+pub fn enable_tsc() -> bool {
+    match tsc::calibrate(CALIBRATION_WINDOW) {
+        Some(ns_per_tsc) => {
+            NS_PER_TSC.store(ns_per_tsc.to_bits(), Ordering::Relaxed);
+            true
+        }
+        None => false,
+    }
+}
+
+#[cfg(target_arch = "x86_64")]
+mod tsc {
+    use std::arch::x86_64::{__cpuid, _rdtsc};
+    use std::time::{Duration, Instant};
+
+    #[inline(always)]
+    pub fn read() -> u64 {
+        // SAFETY: RDTSC is available on every x86_64 CPU
+        unsafe { _rdtsc() }
+    }
+
+    /// Constant rate across P-states and synchronized between cores
+    fn invariant() -> bool {
+        // Leaf 0x8000_0007 EDX bit 8, if the CPU reports that leaf
+        // SAFETY: CPUID is available on every x86_64 CPU
+        unsafe { __cpuid(0x8000_0000).eax >= 0x8000_0007 && __cpuid(0x8000_0007).edx & (1 << 8) != 0 }
+    }
+
+    /// Nanoseconds per TSC tick measured over `window`
+    pub fn calibrate(window: Duration) -> Option<f64> {
+        if !invariant() {
+            return None;
+        }
+        let (start, start_tsc) = (Instant::now(), read());
+        std::thread::sleep(window);
+        let (elapsed, ticks) = (start.elapsed(), read().saturating_sub(start_tsc));
+        (ticks > 0).then(|| elapsed.as_nanos() as f64 / ticks as f64)
+    }
+}
+
+#[cfg(not(target_arch = "x86_64"))]
+mod tsc {
+    use std::time::Duration;
+
+    pub fn read() -> u64 {
+        0
+    }
+
+    pub fn calibrate(_window: Duration) -> Option<f64> {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coarse_clock() {
+        // Without a ticker the system clocks are read directly
+        let before = system_unix_nanos();
+        let unix = coarse_unix_nanos();
+        assert!(unix >= before && unix <= system_unix_nanos());
+        assert!(coarse_now() <= now());
+
+        let clock = CoarseClock::new();
+        assert_eq!(clock.read(), None);
+        clock.publish(1, 2, 3);
+        clock.publish(4, 5, 6);
+        assert_eq!(clock.read(), Some((4, 5, 6)));
+    }
+
+    #[test]
+    fn test_tsc_calibration() {
+        // Not every CI host exposes an invariant TSC
+        if let Some(ns_per_tsc) = tsc::calibrate(Duration::from_millis(5)) {
+            assert!(ns_per_tsc > 0.01 && ns_per_tsc < 10.0, "{}", ns_per_tsc);
+        }
+    }
+}
@@ -3,15 +3,50 @@
 //! Fetches liquid trading pairs from exchange REST APIs.
 //! Filters by 24h volume to find high-liquidity symbols.
 //! Called once at startup - NOT in hot path.
+//!
+//! Instruments metadata is also refreshed periodically to detect symbols
+//! scheduled for delisting/settlement (see [`DelistingNotice`]) and their
+//! trading status (halts, auctions; see [`StatusNotice`]), and stablecoin
+//! rates are refreshed for [`QuoteRates`].
 
-use crate::core::Symbol;
+use crate::core::rates::{QuoteCurrency, QuoteRates};
+use crate::core::{FixedPoint8, Symbol, SymbolMapper, TradingStatus};
 use crate::exchanges::Exchange;
+use crate::infrastructure::config::NetworkConfig;
 use serde::Deserialize;
+use std::net::IpAddr;
+use std::sync::Arc;
 use std::time::Duration;
 
 /// Minimum 24h volume in USDT to include symbol
 pub const DEFAULT_MIN_VOLUME: f64 = 1_000_000.0;
 
+/// Binance Futures 24h ticker endpoint
+pub const BINANCE_TICKERS_URL: &str = "https://fapi.binance.com/fapi/v1/ticker/24hr";
+
+/// Bybit V5 linear tickers endpoint
+pub const BYBIT_TICKERS_URL: &str = "https://api.bybit.com/v5/market/tickers?category=linear";
+
+/// Binance Futures exchange info (instrument status, delivery date)
+pub const BINANCE_INSTRUMENTS_URL: &str = "https://fapi.binance.com/fapi/v1/exchangeInfo";
+
+/// Bybit V5 linear instruments info (instrument status, delivery time)
+pub const BYBIT_INSTRUMENTS_URL: &str =
+    "https://api.bybit.com/v5/market/instruments-info?category=linear&limit=1000";
+
+/// Binance spot USDCUSDT book ticker (USDC rate)
+pub const BINANCE_USDC_RATE_URL: &str = "https://api.binance.com/api/v3/ticker/bookTicker?symbol=USDCUSDT";
+
+/// Bybit V5 spot USDCUSDT ticker (USDC rate)
+pub const BYBIT_USDC_RATE_URL: &str = "https://api.bybit.com/v5/market/tickers?category=spot&symbol=USDCUSDT";
+
+/// Binance statuses of a contract being wound down
+const BINANCE_DELISTING_STATUSES: [&str; 6] =
+    ["PRE_DELIVERING", "DELIVERING", "DELIVERED", "PRE_SETTLE", "SETTLING", "CLOSE"];
+
+/// Bybit statuses of a contract being wound down
+const BYBIT_DELISTING_STATUSES: [&str; 3] = ["Settling", "Delivering", "Closed"];
+
 /// Symbol information from exchange
 #[derive(Debug, Clone)]
 pub struct DiscoveredSymbol {
@@ -26,21 +61,92 @@ pub struct DiscoveredSymbol {
 pub struct SymbolDiscovery {
     client: reqwest::Client,
     min_volume: f64,
+    binance_url: String,
+    bybit_url: String,
+    binance_instruments_url: String,
+    bybit_instruments_url: String,
+    binance_rate_url: String,
+    bybit_rate_url: String,
+    /// Volumes are compared in USDT
+    rates: Arc<QuoteRates>,
 }
 
 impl SymbolDiscovery {
     /// Create new discovery client
     pub fn new() -> Self {
         Self {
-            client: reqwest::Client::builder()
-                .timeout(Duration::from_secs(10))
-                .user_agent("rust-hft/0.1")
-                .build()
-                .unwrap_or_else(|_| reqwest::Client::new()),
+            client: Self::build_client(None),
             min_volume: DEFAULT_MIN_VOLUME,
+            binance_url: BINANCE_TICKERS_URL.to_string(),
+            bybit_url: BYBIT_TICKERS_URL.to_string(),
+            binance_instruments_url: BINANCE_INSTRUMENTS_URL.to_string(),
+            bybit_instruments_url: BYBIT_INSTRUMENTS_URL.to_string(),
+            binance_rate_url: BINANCE_USDC_RATE_URL.to_string(),
+            bybit_rate_url: BYBIT_USDC_RATE_URL.to_string(),
+            rates: Arc::new(QuoteRates::default()),
         }
     }
 
+    /// Create discovery client bound to configured source address
+    pub fn with_network(network: &NetworkConfig) -> Self {
+        Self {
+            client: Self::build_client(network.rest_local_address()),
+            min_volume: DEFAULT_MIN_VOLUME,
+            binance_url: BINANCE_TICKERS_URL.to_string(),
+            bybit_url: BYBIT_TICKERS_URL.to_string(),
+            binance_instruments_url: BINANCE_INSTRUMENTS_URL.to_string(),
+            bybit_instruments_url: BYBIT_INSTRUMENTS_URL.to_string(),
+            binance_rate_url: BINANCE_USDC_RATE_URL.to_string(),
+            bybit_rate_url: BYBIT_USDC_RATE_URL.to_string(),
+            rates: Arc::new(QuoteRates::default()),
+        }
+    }
+
+    fn build_client(local_address: Option<IpAddr>) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent("rust-hft/0.1")
+            .local_address(local_address)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new())
+    }
+
+    /// Override ticker endpoints (mock servers, alternative hosts)
+    pub fn with_endpoints(mut self, binance_url: impl Into<String>, bybit_url: impl Into<String>) -> Self {
+        self.binance_url = binance_url.into();
+        self.bybit_url = bybit_url.into();
+        self
+    }
+
+    /// Override instruments endpoints (mock servers, alternative hosts)
+    pub fn with_instrument_endpoints(
+        mut self,
+        binance_url: impl Into<String>,
+        bybit_url: impl Into<String>,
+    ) -> Self {
+        self.binance_instruments_url = binance_url.into();
+        self.bybit_instruments_url = bybit_url.into();
+        self
+    }
+
+    /// Override the USDC rate endpoints (mock servers, alternative hosts)
+    pub fn with_rate_endpoints(mut self, binance_url: impl Into<String>, bybit_url: impl Into<String>) -> Self {
+        self.binance_rate_url = binance_url.into();
+        self.bybit_rate_url = bybit_url.into();
+        self
+    }
+
+    /// Convert 24h volumes to USDT with shared rates before filtering
+    pub fn with_rates(mut self, rates: Arc<QuoteRates>) -> Self {
+        self.rates = rates;
+        self
+    }
+
+    /// 24h volume in USDT from a volume in `quote`
+    fn usdt_volume(&self, volume: f64, quote: &str) -> f64 {
+        QuoteCurrency::from_asset(quote).map_or(volume, |currency| self.rates.to_usdt_f64(volume, currency))
+    }
+
     /// Create with custom minimum volume
     pub fn with_min_volume(min_volume: f64) -> Self {
         let mut discovery = Self::new();
@@ -53,7 +159,7 @@ impl SymbolDiscovery {
     /// API: GET https://fapi.binance.com/fapi/v1/ticker/24hr
     /// Returns all USDT-margined perpetuals with volume > min_volume
     pub async fn fetch_binance_liquid(&self) -> Result<Vec<DiscoveredSymbol>, DiscoveryError> {
-        let url = "https://fapi.binance.com/fapi/v1/ticker/24hr";
+        let url = self.binance_url.as_str();
         
         tracing::info!("Fetching Binance 24h tickers from {}", url);
         
@@ -76,15 +182,18 @@ impl SymbolDiscovery {
         
         let symbols: Vec<DiscoveredSymbol> = tickers
             .into_iter()
-            .filter(|t| t.quote_volume >= self.min_volume)
             .filter(|t| t.symbol.ends_with("USDT"))
             .filter_map(|t| {
-                let symbol = Symbol::from_bytes(t.symbol.as_bytes())?;
                 let (base, quote) = split_symbol_pair(&t.symbol)?;
+                let volume = self.usdt_volume(t.quote_volume, quote);
+                if volume < self.min_volume {
+                    return None;
+                }
+                let symbol = Symbol::from_exchange_bytes(t.symbol.as_bytes(), Exchange::Binance)?;
                 Some(DiscoveredSymbol {
                     symbol,
                     exchange: Exchange::Binance,
-                    volume_24h: t.quote_volume,
+                    volume_24h: volume,
                     base_asset: base.to_string(),
                     quote_asset: quote.to_string(),
                 })
@@ -100,7 +209,7 @@ impl SymbolDiscovery {
     /// 
     /// API: GET https://api.bybit.com/v5/market/tickers?category=linear
     pub async fn fetch_bybit_liquid(&self) -> Result<Vec<DiscoveredSymbol>, DiscoveryError> {
-        let url = "https://api.bybit.com/v5/market/tickers?category=linear";
+        let url = self.bybit_url.as_str();
         
         tracing::info!("Fetching Bybit tickers from {}", url);
         
@@ -128,16 +237,16 @@ impl SymbolDiscovery {
         
         let symbols: Vec<DiscoveredSymbol> = tickers
             .into_iter()
-            .filter(|t| {
-                t.volume_24h.parse::<f64>().unwrap_or(0.0) * t.last_price.parse::<f64>().unwrap_or(0.0)
-                    >= self.min_volume
-            })
             .filter(|t| t.symbol.ends_with("USDT"))
             .filter_map(|t| {
-                let symbol = Symbol::from_bytes(t.symbol.as_bytes())?;
                 let (base, quote) = split_symbol_pair(&t.symbol)?;
                 let volume = t.volume_24h.parse::<f64>().unwrap_or(0.0) 
                     * t.last_price.parse::<f64>().unwrap_or(0.0);
+                let volume = self.usdt_volume(volume, quote);
+                if volume < self.min_volume {
+                    return None;
+                }
+                let symbol = Symbol::from_exchange_bytes(t.symbol.as_bytes(), Exchange::Bybit)?;
                 Some(DiscoveredSymbol {
                     symbol,
                     exchange: Exchange::Bybit,
@@ -156,6 +265,12 @@ impl SymbolDiscovery {
     /// Fetch and merge symbols from all exchanges
     /// Returns unique symbols sorted by combined volume
     pub async fn fetch_all_liquid(&self) -> Result<Vec<DiscoveredSymbol>, DiscoveryError> {
+        Ok(Self::unique(self.fetch_liquid_by_venue().await?))
+    }
+
+    /// Fetch liquid symbols from all exchanges, one entry per venue listing
+    /// Sorted by volume descending (a symbol on both venues appears twice)
+    pub async fn fetch_liquid_by_venue(&self) -> Result<Vec<DiscoveredSymbol>, DiscoveryError> {
         let (binance_result, bybit_result) = tokio::join!(
             self.fetch_binance_liquid(),
             self.fetch_bybit_liquid()
@@ -180,13 +295,16 @@ impl SymbolDiscovery {
             b.volume_24h.partial_cmp(&a.volume_24h).unwrap_or(std::cmp::Ordering::Equal)
         });
         
-        // Deduplicate by symbol (keep highest volume)
-        let mut seen = std::collections::HashSet::new();
-        all_symbols.retain(|s| seen.insert(s.symbol));
-        
         Ok(all_symbols)
     }
 
+    /// Deduplicate volume-sorted listings by symbol (keep highest volume)
+    pub fn unique(mut symbols: Vec<DiscoveredSymbol>) -> Vec<DiscoveredSymbol> {
+        let mut seen = std::collections::HashSet::new();
+        symbols.retain(|s| seen.insert(s.symbol));
+        symbols
+    }
+
     /// Fetch symbol names only (for registration before parsing)
     /// Returns unique USDT symbol names sorted by volume
     pub async fn fetch_symbol_names(&self) -> Result<Vec<String>, DiscoveryError> {
@@ -227,7 +345,7 @@ impl SymbolDiscovery {
 
     /// Fetch Binance symbol names with volumes
     async fn fetch_binance_names(&self) -> Result<Vec<(String, f64)>, DiscoveryError> {
-        let url = "https://fapi.binance.com/fapi/v1/ticker/24hr";
+        let url = self.binance_url.as_str();
 
         let response = self.client
             .get(url)
@@ -248,7 +366,7 @@ impl SymbolDiscovery {
             .into_iter()
             .filter(|t| t.quote_volume >= self.min_volume)
             .filter(|t| t.symbol.ends_with("USDT"))
-            .map(|t| (t.symbol, t.quote_volume))
+            .map(|t| (canonical_name(&t.symbol, Exchange::Binance), t.quote_volume))
             .collect();
 
         Ok(names)
@@ -256,7 +374,7 @@ impl SymbolDiscovery {
 
     /// Fetch Bybit symbol names with volumes
     async fn fetch_bybit_names(&self) -> Result<Vec<(String, f64)>, DiscoveryError> {
-        let url = "https://api.bybit.com/v5/market/tickers?category=linear";
+        let url = self.bybit_url.as_str();
 
         let response = self.client
             .get(url)
@@ -288,7 +406,7 @@ impl SymbolDiscovery {
             .map(|t| {
                 let volume = t.volume_24h.parse::<f64>().unwrap_or(0.0)
                     * t.last_price.parse::<f64>().unwrap_or(0.0);
-                (t.symbol, volume)
+                (canonical_name(&t.symbol, Exchange::Bybit), volume)
             })
             .collect();
 
@@ -296,21 +414,221 @@ impl SymbolDiscovery {
     }
 }
 
+impl SymbolDiscovery {
+    /// Fetch delisting notices from both exchanges
+    ///
+    /// A notice is raised for registered symbols whose status is a wind-down
+    /// state, or whose delivery/settlement time falls within `horizon_ms` of
+    /// `now_ms`. An exchange that fails to respond is logged and skipped.
+    pub async fn fetch_delistings(&self, now_ms: u64, horizon_ms: u64) -> Vec<DelistingNotice> {
+        self.fetch_instrument_notices(now_ms, horizon_ms).await.0
+    }
+
+    /// Fetch delisting notices and the trading status of registered symbols
+    ///
+    /// One instruments request per exchange serves both; see
+    /// [`Self::fetch_delistings`] for when a delisting notice is raised.
+    pub async fn fetch_instrument_notices(
+        &self,
+        now_ms: u64,
+        horizon_ms: u64,
+    ) -> (Vec<DelistingNotice>, Vec<StatusNotice>) {
+        let (binance, bybit) = tokio::join!(
+            self.fetch_binance_instruments(),
+            self.fetch_bybit_instruments()
+        );
+
+        let (mut notices, mut statuses) = (Vec::new(), Vec::new());
+        match binance {
+            Ok(list) => {
+                notices.extend(list.iter().filter_map(|i| i.delisting(now_ms, horizon_ms)));
+                statuses.extend(list.iter().filter_map(|i| i.trading_status()));
+            }
+            Err(e) => tracing::warn!("Binance instruments refresh failed: {}", e),
+        }
+        match bybit {
+            Ok(list) => {
+                notices.extend(list.iter().filter_map(|i| i.delisting(now_ms, horizon_ms)));
+                statuses.extend(list.iter().filter_map(|i| i.trading_status()));
+            }
+            Err(e) => tracing::warn!("Bybit instruments refresh failed: {}", e),
+        }
+        (notices, statuses)
+    }
+
+    /// Fetch price tick sizes of registered symbols on both exchanges
+    ///
+    /// An exchange that fails to respond is logged and skipped.
+    pub async fn fetch_tick_sizes(&self) -> Vec<(Symbol, Exchange, FixedPoint8)> {
+        let (binance, bybit) = tokio::join!(
+            self.fetch_binance_instruments(),
+            self.fetch_bybit_instruments()
+        );
+
+        let mut ticks = Vec::new();
+        match binance {
+            Ok(list) => ticks.extend(list.iter().filter_map(|i| Some((i.symbol()?, Exchange::Binance, i.tick_size()?)))),
+            Err(e) => tracing::warn!("Binance tick sizes unavailable: {}", e),
+        }
+        match bybit {
+            Ok(list) => ticks.extend(list.iter().filter_map(|i| Some((i.symbol()?, Exchange::Bybit, i.tick_size()?)))),
+            Err(e) => tracing::warn!("Bybit tick sizes unavailable: {}", e),
+        }
+        ticks
+    }
+
+    /// Fetch the USDT rate of USDC from the USDCUSDT spot books
+    ///
+    /// Book mids of the venues that respond are averaged; a venue that fails
+    /// is logged and skipped (empty if both fail). USDT is the unit and USD
+    /// has no liquid pair, so USDC is the only currency fetched.
+    pub async fn fetch_quote_rates(&self) -> Vec<(QuoteCurrency, FixedPoint8)> {
+        let (binance, bybit) = tokio::join!(self.fetch_binance_usdc(), self.fetch_bybit_usdc());
+        let mut mids = Vec::new();
+        match binance {
+            Ok(mid) => mids.extend(mid),
+            Err(e) => tracing::warn!("Binance USDC rate unavailable: {}", e),
+        }
+        match bybit {
+            Ok(mid) => mids.extend(mid),
+            Err(e) => tracing::warn!("Bybit USDC rate unavailable: {}", e),
+        }
+        if mids.is_empty() {
+            return Vec::new();
+        }
+        let sum = mids.iter().try_fold(FixedPoint8::ZERO, |sum, mid| sum.checked_add(*mid));
+        let count = FixedPoint8::from_raw(mids.len() as i64 * FixedPoint8::SCALE);
+        sum.and_then(|sum| sum.safe_div(count)).map(|rate| (QuoteCurrency::Usdc, rate)).into_iter().collect()
+    }
+
+    /// USDCUSDT book mid on Binance spot (None if the book is empty)
+    async fn fetch_binance_usdc(&self) -> Result<Option<FixedPoint8>, DiscoveryError> {
+        let response = self.client
+            .get(&self.binance_rate_url)
+            .send()
+            .await
+            .map_err(|e| DiscoveryError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DiscoveryError::Http(response.status().as_u16()));
+        }
+
+        let book: BinanceBookTicker = response
+            .json()
+            .await
+            .map_err(|e| DiscoveryError::Parse(e.to_string()))?;
+
+        Ok(book_mid(&book.bid_price, &book.ask_price))
+    }
+
+    /// USDCUSDT book mid on Bybit spot (None if the book is empty)
+    async fn fetch_bybit_usdc(&self) -> Result<Option<FixedPoint8>, DiscoveryError> {
+        let response = self.client
+            .get(&self.bybit_rate_url)
+            .send()
+            .await
+            .map_err(|e| DiscoveryError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DiscoveryError::Http(response.status().as_u16()));
+        }
+
+        let bybit_response: BybitSpotTickersResponse = response
+            .json()
+            .await
+            .map_err(|e| DiscoveryError::Parse(e.to_string()))?;
+
+        if bybit_response.ret_code != 0 {
+            return Err(DiscoveryError::Api(bybit_response.ret_msg));
+        }
+
+        Ok(bybit_response.result.list.first().and_then(|t| book_mid(&t.bid_price, &t.ask_price)))
+    }
+
+    /// Fetch Binance Futures instruments
+    ///
+    /// API: GET https://fapi.binance.com/fapi/v1/exchangeInfo
+    async fn fetch_binance_instruments(&self) -> Result<Vec<BinanceInstrument>, DiscoveryError> {
+        let response = self.client
+            .get(&self.binance_instruments_url)
+            .send()
+            .await
+            .map_err(|e| DiscoveryError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DiscoveryError::Http(response.status().as_u16()));
+        }
+
+        let info: BinanceExchangeInfo = response
+            .json()
+            .await
+            .map_err(|e| DiscoveryError::Parse(e.to_string()))?;
+
+        Ok(info.symbols)
+    }
+
+    /// Fetch Bybit V5 linear instruments
+    ///
+    /// API: GET https://api.bybit.com/v5/market/instruments-info?category=linear
+    async fn fetch_bybit_instruments(&self) -> Result<Vec<BybitInstrument>, DiscoveryError> {
+        let response = self.client
+            .get(&self.bybit_instruments_url)
+            .send()
+            .await
+            .map_err(|e| DiscoveryError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(DiscoveryError::Http(response.status().as_u16()));
+        }
+
+        let bybit_response: BybitInstrumentsResponse = response
+            .json()
+            .await
+            .map_err(|e| DiscoveryError::Parse(e.to_string()))?;
+
+        if bybit_response.ret_code != 0 {
+            return Err(DiscoveryError::Api(bybit_response.ret_msg));
+        }
+
+        Ok(bybit_response.result.list)
+    }
+}
+
+/// Symbol scheduled for delisting/settlement on an exchange
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DelistingNotice {
+    pub symbol: Symbol,
+    pub exchange: Exchange,
+    /// Exchange status at detection (e.g. "SETTLING")
+    pub status: String,
+    /// Scheduled delivery/settlement time (Unix millis), if announced
+    pub delivery_time_ms: Option<u64>,
+}
+
+/// Trading status of a symbol on an exchange
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct StatusNotice {
+    pub symbol: Symbol,
+    pub exchange: Exchange,
+    pub status: TradingStatus,
+}
+
 impl Default for SymbolDiscovery {
     fn default() -> Self {
         Self::new()
     }
 }
 
-/// Split symbol like "BTCUSDT" into ("BTC", "USDT")
+/// Split symbol like "BTCUSDT" into ("BTC", "USDT") (any [`QuoteCurrency`])
 fn split_symbol_pair(symbol: &str) -> Option<(&str, &str)> {
-    if symbol.ends_with("USDT") {
-        let base = &symbol[..symbol.len() - 4];
-        if !base.is_empty() {
-            return Some((base, "USDT"));
-        }
-    }
-    None
+    QuoteCurrency::split(symbol).map(|(base, quote)| (base, quote.name()))
+}
+
+/// Mid of a two-sided book from decimal strings
+fn book_mid(bid: &str, ask: &str) -> Option<FixedPoint8> {
+    let bid = FixedPoint8::parse_bytes(bid.as_bytes()).filter(|p| p.is_positive())?;
+    let ask = FixedPoint8::parse_bytes(ask.as_bytes()).filter(|p| p.is_positive())?;
+    bid.checked_add(ask)?.safe_div(FixedPoint8::from_raw(2 * FixedPoint8::SCALE))
 }
 
 // === API Response Types ===
@@ -347,6 +665,182 @@ struct BybitTicker {
     last_price: String,
 }
 
+/// Binance spot book ticker (USDC rate)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceBookTicker {
+    bid_price: String,
+    ask_price: String,
+}
+
+/// Bybit spot tickers response (USDC rate)
+#[derive(Debug, Deserialize)]
+struct BybitSpotTickersResponse {
+    #[serde(rename = "retCode")]
+    ret_code: i32,
+    #[serde(rename = "retMsg")]
+    ret_msg: String,
+    result: BybitSpotResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitSpotResult {
+    list: Vec<BybitSpotTicker>,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitSpotTicker {
+    #[serde(rename = "bid1Price")]
+    bid_price: String,
+    #[serde(rename = "ask1Price")]
+    ask_price: String,
+}
+
+/// Binance exchangeInfo response (only fields used for delisting and tick sizes)
+#[derive(Debug, Deserialize)]
+struct BinanceExchangeInfo {
+    symbols: Vec<BinanceInstrument>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceInstrument {
+    symbol: String,
+    status: String,
+    #[serde(default)]
+    contract_type: String,
+    /// Far-future (2100) for perpetuals unless a delisting is scheduled
+    #[serde(default)]
+    delivery_date: u64,
+    #[serde(default)]
+    filters: Vec<BinanceFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BinanceFilter {
+    filter_type: String,
+    #[serde(default)]
+    tick_size: Option<String>,
+}
+
+impl BinanceInstrument {
+    /// Registered symbol for this listing
+    fn symbol(&self) -> Option<Symbol> {
+        Symbol::from_exchange_bytes(self.symbol.as_bytes(), Exchange::Binance)
+    }
+
+    /// Tick size from PRICE_FILTER
+    fn tick_size(&self) -> Option<FixedPoint8> {
+        let filter = self.filters.iter().find(|f| f.filter_type == "PRICE_FILTER")?;
+        FixedPoint8::parse_bytes(filter.tick_size.as_ref()?.as_bytes()).filter(|t| t.is_positive())
+    }
+
+    /// Trading status of a registered perpetual
+    fn trading_status(&self) -> Option<StatusNotice> {
+        if self.contract_type != "PERPETUAL" {
+            return None;
+        }
+        Some(StatusNotice {
+            symbol: self.symbol()?,
+            exchange: Exchange::Binance,
+            status: TradingStatus::from_binance(self.status.as_bytes()),
+        })
+    }
+
+    /// Delisting notice if wound down or delivering within horizon
+    fn delisting(&self, now_ms: u64, horizon_ms: u64) -> Option<DelistingNotice> {
+        if self.contract_type != "PERPETUAL" {
+            return None;
+        }
+        let winding_down = BINANCE_DELISTING_STATUSES.contains(&self.status.as_str());
+        let due = self.delivery_date > 0 && self.delivery_date <= now_ms.saturating_add(horizon_ms);
+        if !winding_down && !due {
+            return None;
+        }
+        Some(DelistingNotice {
+            symbol: Symbol::from_exchange_bytes(self.symbol.as_bytes(), Exchange::Binance)?,
+            exchange: Exchange::Binance,
+            status: self.status.clone(),
+            delivery_time_ms: due.then_some(self.delivery_date),
+        })
+    }
+}
+
+/// Bybit instruments-info response
+#[derive(Debug, Deserialize)]
+struct BybitInstrumentsResponse {
+    #[serde(rename = "retCode")]
+    ret_code: i32,
+    #[serde(rename = "retMsg")]
+    ret_msg: String,
+    result: BybitInstrumentsResult,
+}
+
+#[derive(Debug, Deserialize)]
+struct BybitInstrumentsResult {
+    list: Vec<BybitInstrument>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitInstrument {
+    symbol: String,
+    status: String,
+    /// "0" for perpetuals unless a delisting is scheduled
+    #[serde(default, deserialize_with = "crate::rest::models::u64_from_str")]
+    delivery_time: u64,
+    #[serde(default)]
+    price_filter: Option<BybitPriceFilter>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitPriceFilter {
+    tick_size: String,
+}
+
+impl BybitInstrument {
+    /// Registered symbol for this listing
+    fn symbol(&self) -> Option<Symbol> {
+        Symbol::from_exchange_bytes(self.symbol.as_bytes(), Exchange::Bybit)
+    }
+
+    /// Tick size from priceFilter
+    fn tick_size(&self) -> Option<FixedPoint8> {
+        FixedPoint8::parse_bytes(self.price_filter.as_ref()?.tick_size.as_bytes()).filter(|t| t.is_positive())
+    }
+
+    /// Trading status of a registered symbol
+    fn trading_status(&self) -> Option<StatusNotice> {
+        Some(StatusNotice {
+            symbol: self.symbol()?,
+            exchange: Exchange::Bybit,
+            status: TradingStatus::from_bybit(self.status.as_bytes()),
+        })
+    }
+
+    /// Delisting notice if wound down or delivering within horizon
+    fn delisting(&self, now_ms: u64, horizon_ms: u64) -> Option<DelistingNotice> {
+        let winding_down = BYBIT_DELISTING_STATUSES.contains(&self.status.as_str());
+        let due = self.delivery_time > 0 && self.delivery_time <= now_ms.saturating_add(horizon_ms);
+        if !winding_down && !due {
+            return None;
+        }
+        Some(DelistingNotice {
+            symbol: Symbol::from_exchange_bytes(self.symbol.as_bytes(), Exchange::Bybit)?,
+            exchange: Exchange::Bybit,
+            status: self.status.clone(),
+            delivery_time_ms: due.then_some(self.delivery_time),
+        })
+    }
+}
+
+/// Canonical registry name for an exchange listing (venue aliases resolved)
+fn canonical_name(name: &str, exchange: Exchange) -> String {
+    String::from_utf8_lossy(SymbolMapper::canonical_name(name.as_bytes(), exchange)).into_owned()
+}
+
 /// Discovery errors
 #[derive(Debug, thiserror::Error)]
 pub enum DiscoveryError {
@@ -375,6 +869,7 @@ mod tests {
         assert_eq!(split_symbol_pair("BTCUSDT"), Some(("BTC", "USDT")));
         assert_eq!(split_symbol_pair("ETHUSDT"), Some(("ETH", "USDT")));
         assert_eq!(split_symbol_pair("1000PEPEUSDT"), Some(("1000PEPE", "USDT")));
+        assert_eq!(split_symbol_pair("BTCUSDC"), Some(("BTC", "USDC")));
         assert_eq!(split_symbol_pair("USDT"), None);
         assert_eq!(split_symbol_pair("BTC"), None);
     }
@@ -416,4 +911,125 @@ mod tests {
         assert_eq!(response.result.list.len(), 1);
         assert_eq!(response.result.list[0].symbol, "BTCUSDT");
     }
+
+    const HOUR_MS: u64 = 3_600_000;
+
+    #[test]
+    fn test_binance_delisting_detection() {
+        crate::test_utils::init_test_registry();
+        let now = 1_700_000_000_000;
+        let json = format!(
+            r#"{{"timezone":"UTC","symbols":[
+                {{"symbol":"BTCUSDT","status":"TRADING","contractType":"PERPETUAL","deliveryDate":4133404800000}},
+                {{"symbol":"ETHUSDT","status":"TRADING","contractType":"PERPETUAL","deliveryDate":{}}},
+                {{"symbol":"SOLUSDT","status":"SETTLING","contractType":"PERPETUAL","deliveryDate":4133404800000}},
+                {{"symbol":"XRPUSDT","status":"TRADING","contractType":"CURRENT_QUARTER","deliveryDate":{}}}
+            ]}}"#,
+            now + 24 * HOUR_MS,
+            now + HOUR_MS
+        );
+        let info: BinanceExchangeInfo = serde_json::from_str(&json).unwrap();
+        let notices: Vec<DelistingNotice> = info
+            .symbols
+            .iter()
+            .filter_map(|i| i.delisting(now, 72 * HOUR_MS))
+            .collect();
+
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].symbol.as_str(), "ETHUSDT");
+        assert_eq!(notices[0].delivery_time_ms, Some(now + 24 * HOUR_MS));
+        assert_eq!(notices[1].symbol.as_str(), "SOLUSDT");
+        assert_eq!(notices[1].status, "SETTLING");
+        assert_eq!(notices[1].delivery_time_ms, None);
+
+        // Outside horizon: not yet flagged
+        assert!(info.symbols[1].delisting(now, HOUR_MS).is_none());
+
+        // Quarterlies are not tracked; a settling perpetual is halted
+        let statuses: Vec<StatusNotice> = info.symbols.iter().filter_map(|i| i.trading_status()).collect();
+        assert_eq!(statuses.len(), 3);
+        assert_eq!((statuses[0].status, statuses[2].status), (TradingStatus::Trading, TradingStatus::Halted));
+    }
+
+    #[test]
+    fn test_bybit_delisting_detection() {
+        crate::test_utils::init_test_registry();
+        let now = 1_700_000_000_000;
+        let json = format!(
+            r#"{{"retCode":0,"retMsg":"OK","result":{{"category":"linear","list":[
+                {{"symbol":"BTCUSDT","status":"Trading","deliveryTime":"0"}},
+                {{"symbol":"ETHUSDT","status":"Trading","deliveryTime":"{}"}},
+                {{"symbol":"SOLUSDT","status":"Closed","deliveryTime":"0"}},
+                {{"symbol":"XRPUSDT","status":"PreLaunch","deliveryTime":"0"}}
+            ]}}}}"#,
+            now + 2 * HOUR_MS
+        );
+        let response: BybitInstrumentsResponse = serde_json::from_str(&json).unwrap();
+        let notices: Vec<DelistingNotice> = response
+            .result
+            .list
+            .iter()
+            .filter_map(|i| i.delisting(now, 72 * HOUR_MS))
+            .collect();
+
+        assert_eq!(notices.len(), 2);
+        assert_eq!(notices[0].exchange, Exchange::Bybit);
+        assert_eq!(notices[0].delivery_time_ms, Some(now + 2 * HOUR_MS));
+        assert_eq!(notices[1].symbol.as_str(), "SOLUSDT");
+
+        let statuses: Vec<TradingStatus> =
+            response.result.list.iter().filter_map(|i| i.trading_status()).map(|s| s.status).collect();
+        assert_eq!(
+            statuses,
+            vec![TradingStatus::Trading, TradingStatus::Trading, TradingStatus::Halted, TradingStatus::Auction]
+        );
+    }
+
+    #[test]
+    fn test_tick_size_extraction() {
+        crate::test_utils::init_test_registry();
+        let binance: BinanceExchangeInfo = serde_json::from_str(
+            r#"{"symbols":[{"symbol":"BTCUSDT","status":"TRADING","filters":[
+                {"filterType":"LOT_SIZE","stepSize":"0.001"},
+                {"filterType":"PRICE_FILTER","tickSize":"0.10"}]}]}"#,
+        )
+        .unwrap();
+        assert_eq!(binance.symbols[0].tick_size(), Some(FixedPoint8::from_raw(10_000_000)));
+
+        let bybit: BybitInstrumentsResponse = serde_json::from_str(
+            r#"{"retCode":0,"retMsg":"OK","result":{"list":[
+                {"symbol":"1000PEPEUSDT","status":"Trading","priceFilter":{"tickSize":"0.0000001"}},
+                {"symbol":"ETHUSDT","status":"Trading"}]}}"#,
+        )
+        .unwrap();
+        let pepe = &bybit.result.list[0];
+        assert_eq!(pepe.symbol().map(|s| s.as_str()), Some("PEPEUSDT"));
+        assert_eq!(pepe.tick_size(), Some(FixedPoint8::from_raw(10)));
+        assert_eq!(bybit.result.list[1].tick_size(), None);
+    }
+
+    #[tokio::test]
+    async fn test_fetch_usdc_rate_averages_venues() {
+        use axum::{routing::get, Router};
+        let app = Router::new()
+            .route("/binance", get(|| async { r#"{"symbol":"USDCUSDT","bidPrice":"0.99980000","bidQty":"1","askPrice":"1.00000000","askQty":"1"}"# }))
+            .route(
+                "/bybit",
+                get(|| async {
+                    r#"{"retCode":0,"retMsg":"OK","result":{"category":"spot","list":[{"symbol":"USDCUSDT","bid1Price":"0.9996","ask1Price":"0.9998"}]}}"#
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let rate = |v: f64| vec![(QuoteCurrency::Usdc, FixedPoint8::from_f64(v).unwrap())];
+        let discovery = SymbolDiscovery::new().with_rate_endpoints(format!("{base}/binance"), format!("{base}/bybit"));
+        assert_eq!(discovery.fetch_quote_rates().await, rate(0.9998));
+        // One venue down: the other's mid alone
+        let discovery = SymbolDiscovery::new().with_rate_endpoints(format!("{base}/missing"), format!("{base}/bybit"));
+        assert_eq!(discovery.fetch_quote_rates().await, rate(0.9997));
+        let discovery = SymbolDiscovery::new().with_rate_endpoints(format!("{base}/missing"), format!("{base}/missing"));
+        assert!(discovery.fetch_quote_rates().await.is_empty());
+    }
 }
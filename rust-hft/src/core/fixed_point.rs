@@ -2,7 +2,10 @@
 //!
 //! Uses i64 internally with 8 decimal places precision.
 //! Zero allocation, Copy type, no panics.
+//! Deserializes from decimal strings (and integers) without going through f64.
 
+use super::Bps;
+use serde::de::{self, Deserialize, Deserializer, Visitor};
 use std::fmt;
 use std::str::FromStr;
 
@@ -133,24 +136,15 @@ impl FixedPoint8 {
         Some(Self(scaled as i64))
     }
 
-    /// Calculate spread in basis points (1 bps = 0.01%)
-    /// Returns spread as FixedPoint8 where 100_000_000 = 100%
-    /// So 100 bps (1%) = 1_000_000 in raw
+    /// Relative spread from `self` to `other`: (other - self) / self
+    ///
+    /// For 100 → 101 this is 1% = 100 bps. None if `self` is zero.
     #[inline]
     pub fn spread_bps(
         &self,
         other: Self
-    ) -> Option<Self> {
-        // spread = (other - self) / self * 10000
-        // For 100 to 101: (1 / 100) * 10000 = 100 bps = 1%
-        // In FixedPoint8: 1% = 0.01 = 1_000_000 raw
-        let diff = other.checked_sub(*self)?;
-        // ratio = diff / self (in FixedPoint8 scale)
-        // For 1/100 = 0.01, in raw: 1_000_000
-        let ratio = diff.safe_div(*self)?;
-        // Multiply by 10000 to convert to bps
-        // 0.01 * 10000 = 100 bps
-        ratio.safe_mul(Self(10_000))
+    ) -> Option<Bps> {
+        Bps::relative(other, *self)
     }
 
     /// Parse from byte slice without allocation
@@ -190,7 +184,7 @@ impl FixedPoint8 {
                 continue;
             }
 
-            if c < b'0' || c > b'9' {
+            if !c.is_ascii_digit() {
                 return None; // Invalid character
             }
 
@@ -250,11 +244,9 @@ impl FixedPoint8 {
         let mut pos = 0;
 
         // Write sign
-        if negative {
-            if pos < buf.len() {
-                buf[pos] = b'-';
-                pos += 1;
-            }
+        if negative && pos < buf.len() {
+            buf[pos] = b'-';
+            pos += 1;
         }
 
         // Write integer part (from right to left)
@@ -298,6 +290,44 @@ impl FixedPoint8 {
         pos
     }
 
+    /// Write with at most `max_decimals` fractional digits, for exchange
+    /// order parameters ("25000.5" rather than "25000.50000000")
+    ///
+    /// Digits beyond `max_decimals` are truncated toward zero, trailing zeros
+    /// are trimmed and whole numbers get no decimal point. Returns number of
+    /// bytes written; buffer must be at least 32 bytes.
+    #[inline]
+    pub fn write_trimmed(&self, buf: &mut [u8], max_decimals: u32) -> usize {
+        let unit = 10i64.pow(8 - max_decimals.min(8));
+        let len = Self(self.0 / unit * unit).write_to_buffer(buf);
+        let Some(point) = buf[..len].iter().position(|&b| b == b'.') else {
+            return len;
+        };
+        let digits = buf[point + 1..len].iter().rposition(|&b| b != b'0').map_or(0, |i| i + 1);
+        if digits == 0 {
+            point
+        } else {
+            point + 1 + digits
+        }
+    }
+
+    /// Fractional digits needed to write the value exactly (0.01 → 2, 5 → 0)
+    ///
+    /// Applied to a tick or lot step size, this is the instrument's precision.
+    #[inline]
+    pub const fn decimals(&self) -> u32 {
+        let mut frac = (self.0 % Self::SCALE).unsigned_abs();
+        if frac == 0 {
+            return 0;
+        }
+        let mut decimals = 8;
+        while frac % 10 == 0 {
+            frac /= 10;
+            decimals -= 1;
+        }
+        decimals
+    }
+
     /// Get the sign (-1, 0, 1)
     #[inline(always)]
     pub const fn signum(&self) -> i64 {
@@ -346,6 +376,43 @@ impl FromStr for FixedPoint8 {
     }
 }
 
+/// Accepts decimal strings ("123.45") and integers; floats are rejected so
+/// REST payloads never lose precision through f64.
+impl<'de> Deserialize<'de> for FixedPoint8 {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        struct FixedVisitor;
+
+        impl Visitor<'_> for FixedVisitor {
+            type Value = FixedPoint8;
+
+            fn expecting(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                f.write_str("a decimal string or integer")
+            }
+
+            fn visit_str<E: de::Error>(self, v: &str) -> Result<FixedPoint8, E> {
+                FixedPoint8::parse_bytes(v.as_bytes())
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Str(v), &self))
+            }
+
+            fn visit_i64<E: de::Error>(self, v: i64) -> Result<FixedPoint8, E> {
+                v.checked_mul(FixedPoint8::SCALE)
+                    .map(FixedPoint8)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Signed(v), &self))
+            }
+
+            fn visit_u64<E: de::Error>(self, v: u64) -> Result<FixedPoint8, E> {
+                i64::try_from(v)
+                    .ok()
+                    .and_then(|v| v.checked_mul(FixedPoint8::SCALE))
+                    .map(FixedPoint8)
+                    .ok_or_else(|| E::invalid_value(de::Unexpected::Unsigned(v), &self))
+            }
+        }
+
+        deserializer.deserialize_any(FixedVisitor)
+    }
+}
+
 // HFT Hot Path Checklist verified:
 // ✓ No heap allocations (all stack-based)
 // ✓ No panics (all checked operations return Option)
@@ -355,6 +422,8 @@ impl FromStr for FixedPoint8 {
 // ✓ No formatting in hot path (write_to_buffer for zero-alloc)
 
 #[cfg(test)]
+// Raw literals group the 8 fractional digits apart from the integer part
+#[allow(clippy::inconsistent_digit_grouping)]
 mod tests {
     use super::*;
 
@@ -451,6 +520,31 @@ mod tests {
         assert_eq!(&buf[..len], b"123.45678900");
     }
 
+    #[test]
+    fn test_write_trimmed() {
+        let trimmed = |raw: i64, max: u32| {
+            let mut buf = [0u8; 32];
+            let len = FixedPoint8::from_raw(raw).write_trimmed(&mut buf, max);
+            String::from_utf8(buf[..len].to_vec()).unwrap()
+        };
+        assert_eq!(trimmed(2_500_050_000_000, 8), "25000.5");
+        assert_eq!(trimmed(2_500_000_000_000, 2), "25000");
+        assert_eq!(trimmed(12_345_678_900, 3), "123.456"); // truncated, not rounded
+        assert_eq!(trimmed(-50_000_000, 8), "-0.5");
+        assert_eq!(trimmed(1, 8), "0.00000001");
+        assert_eq!(trimmed(1, 4), "0");
+        assert_eq!(trimmed(0, 8), "0");
+    }
+
+    #[test]
+    fn test_decimals() {
+        assert_eq!(FixedPoint8::from_raw(1_000_000).decimals(), 2); // 0.01
+        assert_eq!(FixedPoint8::from_raw(10).decimals(), 7); // PEPE tick 0.0000001
+        assert_eq!(FixedPoint8::from_raw(50_000_000).decimals(), 1);
+        assert_eq!(FixedPoint8::from_raw(500_000_000).decimals(), 0);
+        assert_eq!(FixedPoint8::from_raw(-150_000_000).decimals(), 1);
+    }
+
     #[test]
     fn test_display() {
         let value = FixedPoint8::from_raw(123_456_789_00);
@@ -491,9 +585,20 @@ mod tests {
         let a = FixedPoint8::from_raw(100 * FixedPoint8::SCALE); // 100.0
         let b = FixedPoint8::from_raw(101 * FixedPoint8::SCALE); // 101.0
         let spread = a.spread_bps(b).unwrap();
-        // 100 bps = 100 raw (since 1 bps = 1 unit in this representation)
-        // Allow small rounding error
-        assert!(spread.as_raw() >= 99 && spread.as_raw() <= 101,
-            "Expected ~100 bps, got {} (raw)", spread.as_raw());
+        assert_eq!(spread, Bps::from_bps(100));
+        assert_eq!(spread.as_fraction(), FixedPoint8::from_raw(1_000_000));
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let v: FixedPoint8 = serde_json::from_str("\"0.00012345\"").unwrap();
+        assert_eq!(v.as_raw(), 12_345);
+        let v: FixedPoint8 = serde_json::from_str("-42").unwrap();
+        assert_eq!(v, FixedPoint8::from_raw(-4_200_000_000));
+
+        // Floats would go through f64, garbage is invalid
+        assert!(serde_json::from_str::<FixedPoint8>("1.5").is_err());
+        assert!(serde_json::from_str::<FixedPoint8>("\"abc\"").is_err());
+        assert!(serde_json::from_str::<FixedPoint8>("\"\"").is_err());
     }
 }
@@ -1,9 +1,9 @@
 //! Market data types
 //!
-//! TickerData and TradeData are core structures for market data.
+//! TickerData, TradeData, MarkPriceData and LiquidationData are core structures for market data.
 //! Optimized for cache-line alignment (64 bytes).
 
-use super::{FixedPoint8, Symbol};
+use super::{Bps, FixedPoint8, Symbol};
 
 /// Best bid/ask ticker data
 #[repr(C, align(64))]
@@ -21,10 +21,12 @@ pub struct TickerData {
     pub ask_qty: FixedPoint8,
     /// Timestamp (nanoseconds since epoch)
     pub timestamp: u64,
+    /// Exchange update id / sequence (0 if unknown)
+    pub update_id: u64,
 }
 
 /// Trade side
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 #[repr(u8)]
 pub enum Side {
     Buy = 1,
@@ -67,12 +69,109 @@ pub struct TradeData {
     pub quantity: FixedPoint8,
     /// Timestamp (nanoseconds since epoch)
     pub timestamp: u64,
+    /// Exchange trade id / sequence (0 if unknown)
+    pub trade_id: u64,
     /// Trade side
     pub side: Side,
     /// Is buyer maker (true = limit order, false = market order)
     pub is_buyer_maker: bool,
 }
 
+/// Mark/index price update (markPrice stream, Bybit tickers)
+#[repr(C, align(64))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MarkPriceData {
+    /// Trading pair symbol
+    pub symbol: Symbol,
+    /// Mark price (used by the exchange for PnL and liquidation)
+    pub mark_price: FixedPoint8,
+    /// Index price (spot composite)
+    pub index_price: FixedPoint8,
+    /// Current funding rate (0.0001 = 0.01%)
+    pub funding_rate: FixedPoint8,
+    /// Next funding time (nanoseconds since epoch, 0 if unknown)
+    pub next_funding_time: u64,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+/// Forced liquidation order (forceOrder / allLiquidation streams)
+#[repr(C, align(64))]
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LiquidationData {
+    /// Trading pair symbol
+    pub symbol: Symbol,
+    /// Liquidation order price
+    pub price: FixedPoint8,
+    /// Liquidated quantity
+    pub quantity: FixedPoint8,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+    /// Side of the liquidation order (Sell = long liquidated, Buy = short liquidated)
+    pub side: Side,
+}
+
+/// Top-of-book shape of a single-venue quote
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookState {
+    /// Bid below ask
+    Normal,
+    /// Bid equal to ask
+    Locked,
+    /// Bid above ask
+    Crossed,
+}
+
+/// Venue trading state of a symbol (instruments info, ticker phase)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum TradingStatus {
+    /// Continuous trading: quotes are executable
+    #[default]
+    Trading,
+    /// Trading halted, not yet open, or wound down
+    Halted,
+    /// Call auction: quotes are indicative until the match
+    Auction,
+}
+
+impl TradingStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            TradingStatus::Trading => "trading",
+            TradingStatus::Halted => "halted",
+            TradingStatus::Auction => "auction",
+        }
+    }
+
+    /// Binance Futures exchangeInfo `status` (anything unknown is halted)
+    pub fn from_binance(status: &[u8]) -> Self {
+        match status {
+            b"TRADING" => TradingStatus::Trading,
+            b"AUCTION_MATCH" => TradingStatus::Auction,
+            _ => TradingStatus::Halted,
+        }
+    }
+
+    /// Bybit instruments-info `status` (anything unknown is halted)
+    pub fn from_bybit(status: &[u8]) -> Self {
+        match status {
+            b"Trading" => TradingStatus::Trading,
+            b"PreLaunch" => TradingStatus::Auction,
+            _ => TradingStatus::Halted,
+        }
+    }
+
+    /// Bybit ticker `curPreListingPhase` of a pre-listed contract
+    pub fn from_bybit_phase(phase: &[u8]) -> Option<Self> {
+        match phase {
+            b"ContinuousTrading" | b"Finished" => Some(TradingStatus::Trading),
+            b"CallAuction" | b"CallAuctionNoCancel" | b"CrossMatching" => Some(TradingStatus::Auction),
+            b"NotStarted" => Some(TradingStatus::Halted),
+            _ => None,
+        }
+    }
+}
+
 impl TickerData {
     /// Create new ticker data
     #[inline(always)]
@@ -91,9 +190,17 @@ impl TickerData {
             ask_price,
             ask_qty,
             timestamp,
+            update_id: 0,
         }
     }
 
+    /// Set exchange update id
+    #[inline(always)]
+    pub const fn with_update_id(mut self, update_id: u64) -> Self {
+        self.update_id = update_id;
+        self
+    }
+
     /// Calculate spread as FixedPoint8 (ask - bid)
     /// Returns None if subtraction overflows
     #[inline]
@@ -101,10 +208,9 @@ impl TickerData {
         self.ask_price.checked_sub(self.bid_price)
     }
 
-    /// Calculate spread in basis points
-    /// Returns spread * 10000 (so 1% = 100 bps)
+    /// Relative bid/ask spread (ask - bid) / bid
     #[inline]
-    pub fn spread_bps(&self) -> Option<FixedPoint8> {
+    pub fn spread_bps(&self) -> Option<Bps> {
         self.bid_price.spread_bps(self.ask_price)
     }
 
@@ -121,6 +227,16 @@ impl TickerData {
     pub fn is_valid(&self) -> bool {
         self.bid_price.as_raw() < self.ask_price.as_raw()
     }
+
+    /// Classify top of book (locked/crossed quotes indicate bad feed handling)
+    #[inline]
+    pub fn book_state(&self) -> BookState {
+        match self.bid_price.cmp(&self.ask_price) {
+            std::cmp::Ordering::Less => BookState::Normal,
+            std::cmp::Ordering::Equal => BookState::Locked,
+            std::cmp::Ordering::Greater => BookState::Crossed,
+        }
+    }
 }
 
 impl TradeData {
@@ -139,11 +255,19 @@ impl TradeData {
             price,
             quantity,
             timestamp,
+            trade_id: 0,
             side,
             is_buyer_maker,
         }
     }
 
+    /// Set exchange trade id
+    #[inline(always)]
+    pub const fn with_trade_id(mut self, trade_id: u64) -> Self {
+        self.trade_id = trade_id;
+        self
+    }
+
     /// Calculate notional value (price * quantity)
     #[inline]
     pub fn notional(&self) -> Option<FixedPoint8> {
@@ -151,12 +275,67 @@ impl TradeData {
     }
 }
 
+impl MarkPriceData {
+    /// Create new mark price data
+    #[inline(always)]
+    pub const fn new(
+        symbol: Symbol,
+        mark_price: FixedPoint8,
+        index_price: FixedPoint8,
+        funding_rate: FixedPoint8,
+        next_funding_time: u64,
+        timestamp: u64,
+    ) -> Self {
+        Self {
+            symbol,
+            mark_price,
+            index_price,
+            funding_rate,
+            next_funding_time,
+            timestamp,
+        }
+    }
+
+    /// Premium of mark over index ((mark - index) / index)
+    /// Returns None if index is zero or on overflow
+    #[inline]
+    pub fn premium(&self) -> Option<FixedPoint8> {
+        self.mark_price
+            .checked_sub(self.index_price)?
+            .safe_div(self.index_price)
+    }
+}
+
+impl LiquidationData {
+    /// Create new liquidation data
+    #[inline(always)]
+    pub const fn new(
+        symbol: Symbol,
+        price: FixedPoint8,
+        quantity: FixedPoint8,
+        timestamp: u64,
+        side: Side,
+    ) -> Self {
+        Self {
+            symbol,
+            price,
+            quantity,
+            timestamp,
+            side,
+        }
+    }
+
+    /// Liquidated notional (price * quantity)
+    #[inline]
+    pub fn notional(&self) -> Option<FixedPoint8> {
+        self.price.safe_mul(self.quantity)
+    }
+}
+
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     #[test]
     fn test_ticker_creation() {
@@ -188,6 +367,39 @@ mod tests {
         assert_eq!(trade.symbol, sym);
         assert_eq!(trade.side, Side::Buy);
     }
+
+    #[test]
+    fn test_mark_price_premium() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mark = MarkPriceData::new(
+            sym,
+            FixedPoint8::from_raw(101 * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(100 * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(10_000),
+            0,
+            1234567890,
+        );
+        // (101 - 100) / 100 = 1%
+        assert_eq!(mark.premium(), Some(FixedPoint8::from_raw(1_000_000)));
+
+        let no_index = MarkPriceData { index_price: FixedPoint8::ZERO, ..mark };
+        assert!(no_index.premium().is_none());
+    }
+
+    #[test]
+    fn test_liquidation_notional() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let liq = LiquidationData::new(
+            sym,
+            FixedPoint8::from_raw(10_000 * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(50_000_000), // 0.5
+            1000,
+            Side::Sell,
+        );
+        assert_eq!(liq.notional(), Some(FixedPoint8::from_raw(5_000 * FixedPoint8::SCALE)));
+    }
 }
 
 // HFT Hot Path Checklist verified:
@@ -2,22 +2,35 @@
 //! 
 //! This module contains the fundamental types used throughout the system:
 //! - FixedPoint8: Fixed-point arithmetic for prices
+//! - Bps: Relative spreads/thresholds, kept apart from prices
 //! - Symbol: Interned string for trading pairs
 //! - TickerData: Best bid/ask data
 //! - TradeData: Individual trade information
+//! - MarkPriceData: Mark/index price and funding
+//! - LiquidationData: Forced liquidation orders
+//! - Position: Open position valued at mark price
 //! - SymbolDiscovery: Dynamic symbol loading (cold path)
+//! - QuoteRates: USDT value of each quote currency
 //! - SymbolRegistry: Pre-registration for hot path lookups
+//! - clock: Cached coarse clock and TSC fine timer
 
+pub mod bps;
+pub mod clock;
 pub mod discovery;
 pub mod fixed_point;
 pub mod market_data;
+pub mod position;
+pub mod rates;
 pub mod registry;
 pub mod symbol;
 pub mod symbol_map;
 
-pub use discovery::{DiscoveredSymbol, DiscoveryError, SymbolDiscovery, DEFAULT_MIN_VOLUME};
+pub use bps::Bps;
+pub use discovery::{DelistingNotice, DiscoveredSymbol, DiscoveryError, StatusNotice, SymbolDiscovery, DEFAULT_MIN_VOLUME};
 pub use fixed_point::FixedPoint8;
-pub use market_data::{Side, TickerData, TradeData};
+pub use market_data::{BookState, LiquidationData, MarkPriceData, Side, TickerData, TradeData, TradingStatus};
+pub use position::Position;
+pub use rates::{QuoteCurrency, QuoteRates};
 pub use registry::{SymbolRegistry, RegistryError, MAX_SYMBOLS};
 pub use symbol::Symbol;
 pub use symbol_map::SymbolMapper;
@@ -0,0 +1,131 @@
+//! Open position on a single exchange
+//!
+//! Unrealized PnL and liquidation distance are measured against the
+//! exchange mark price (not last trade or book mid), matching how the
+//! exchange itself values the position.
+
+use super::{FixedPoint8, MarkPriceData, Side, Symbol};
+use crate::exchanges::Exchange;
+
+/// Perpetual futures position
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Position {
+    /// Trading pair symbol
+    pub symbol: Symbol,
+    /// Exchange holding the position
+    pub exchange: Exchange,
+    /// Buy = long, Sell = short
+    pub side: Side,
+    /// Position size (always positive)
+    pub quantity: FixedPoint8,
+    /// Average entry price
+    pub entry_price: FixedPoint8,
+    /// Liquidation price reported by the exchange (ZERO if unknown)
+    pub liquidation_price: FixedPoint8,
+}
+
+impl Position {
+    /// Create new position
+    #[inline(always)]
+    pub const fn new(
+        symbol: Symbol,
+        exchange: Exchange,
+        side: Side,
+        quantity: FixedPoint8,
+        entry_price: FixedPoint8,
+        liquidation_price: FixedPoint8,
+    ) -> Self {
+        Self {
+            symbol,
+            exchange,
+            side,
+            quantity,
+            entry_price,
+            liquidation_price,
+        }
+    }
+
+    /// Unrealized PnL at the given mark price (quote currency)
+    /// Long: (mark - entry) * qty, Short: (entry - mark) * qty
+    #[inline]
+    pub fn unrealized_pnl(&self, mark: &MarkPriceData) -> Option<FixedPoint8> {
+        let diff = match self.side {
+            Side::Buy => mark.mark_price.checked_sub(self.entry_price)?,
+            Side::Sell => self.entry_price.checked_sub(mark.mark_price)?,
+        };
+        diff.safe_mul(self.quantity)
+    }
+
+    /// Distance to liquidation as a fraction of mark price
+    /// (0.05 = mark must move 5% against us). Negative means past liquidation.
+    /// Returns None if liquidation price is unknown or mark is zero.
+    #[inline]
+    pub fn liquidation_distance(&self, mark: &MarkPriceData) -> Option<FixedPoint8> {
+        if !self.liquidation_price.is_positive() {
+            return None;
+        }
+        let diff = match self.side {
+            Side::Buy => mark.mark_price.checked_sub(self.liquidation_price)?,
+            Side::Sell => self.liquidation_price.checked_sub(mark.mark_price)?,
+        };
+        diff.safe_div(mark.mark_price)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(value: i64) -> FixedPoint8 {
+        FixedPoint8::from_raw(value * FixedPoint8::SCALE)
+    }
+
+    fn mark(symbol: Symbol, price: i64) -> MarkPriceData {
+        MarkPriceData::new(symbol, fp(price), fp(price), FixedPoint8::ZERO, 0, 1000)
+    }
+
+    #[test]
+    fn test_long_pnl_and_liquidation() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let pos = Position::new(sym, Exchange::Binance, Side::Buy, fp(2), fp(100), fp(80));
+
+        let m = mark(sym, 110);
+        assert_eq!(pos.unrealized_pnl(&m), Some(fp(20)));
+
+        // (100 - 80) / 100 = 20%
+        let m = mark(sym, 100);
+        assert_eq!(pos.liquidation_distance(&m), Some(FixedPoint8::from_raw(20_000_000)));
+    }
+
+    #[test]
+    fn test_short_pnl_and_liquidation() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let pos = Position::new(sym, Exchange::Bybit, Side::Sell, fp(1), fp(100), fp(125));
+
+        let m = mark(sym, 110);
+        assert_eq!(pos.unrealized_pnl(&m), Some(fp(-10)));
+
+        // (125 - 100) / 100 = 25%
+        let m = mark(sym, 100);
+        assert_eq!(pos.liquidation_distance(&m), Some(FixedPoint8::from_raw(25_000_000)));
+
+        // Past liquidation is negative
+        let m = mark(sym, 130);
+        assert!(pos.liquidation_distance(&m).unwrap().is_negative());
+    }
+
+    #[test]
+    fn test_unknown_liquidation_price() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let pos = Position::new(sym, Exchange::Binance, Side::Buy, fp(1), fp(100), FixedPoint8::ZERO);
+        assert!(pos.liquidation_distance(&mark(sym, 100)).is_none());
+    }
+}
+
+// HFT Hot Path Checklist verified:
+// ✓ No heap allocations (Copy types only)
+// ✓ No panics (checked math returns Option)
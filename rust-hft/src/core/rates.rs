@@ -0,0 +1,159 @@
+//! Quote currency conversion
+//!
+//! Venues quote contracts in different stablecoins, while balances, limits
+//! and reports are kept in USDT. [`QuoteRates`] holds the USDT value of one
+//! unit of each quote currency: USDT is 1 by definition, USDC follows the
+//! USDCUSDT spot books (refreshed in the background, see
+//! [`crate::core::SymbolDiscovery::fetch_quote_rates`]) and USD, which has no
+//! liquid pair on the venues, is pinned by config. Factors are atomics so the
+//! engine reads them without locking; a fetched rate far from par is
+//! rejected as a glitch rather than trusted.
+
+use crate::core::{FixedPoint8, Symbol};
+use crate::infrastructure::config::RatesConfig;
+use std::sync::atomic::{AtomicI64, AtomicU64, Ordering};
+
+/// Currency a contract is quoted (and settled) in
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum QuoteCurrency {
+    Usdt,
+    Usdc,
+    Usd,
+}
+
+impl QuoteCurrency {
+    pub const COUNT: usize = 3;
+    /// Longest suffix first: "USDT" and "USDC" both end a "USD" prefix
+    pub const ALL: [QuoteCurrency; Self::COUNT] = [Self::Usdt, Self::Usdc, Self::Usd];
+
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Asset name, e.g. "USDC"
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::Usdt => "USDT",
+            Self::Usdc => "USDC",
+            Self::Usd => "USD",
+        }
+    }
+
+    /// Currency by asset name (case-insensitive)
+    pub fn from_asset(asset: &str) -> Option<Self> {
+        Self::ALL.into_iter().find(|c| c.name().eq_ignore_ascii_case(asset))
+    }
+
+    /// Split a symbol name into base asset and quote currency ("BTCUSDC" → ("BTC", Usdc))
+    pub fn split(name: &str) -> Option<(&str, Self)> {
+        Self::ALL.into_iter().find_map(|quote| {
+            let base = name.strip_suffix(quote.name())?;
+            (!base.is_empty()).then_some((base, quote))
+        })
+    }
+
+    /// Quote currency of a registered symbol (USDT when unrecognized)
+    #[inline]
+    pub fn of(symbol: Symbol) -> Self {
+        Self::split(symbol.as_str()).map_or(Self::Usdt, |(_, quote)| quote)
+    }
+}
+
+/// USDT value of one unit of each quote currency
+#[derive(Debug)]
+pub struct QuoteRates {
+    /// Raw FixedPoint8 factors (indexed by QuoteCurrency::index)
+    factors: [AtomicI64; QuoteCurrency::COUNT],
+    /// Last accepted refresh per currency (Unix millis, 0 = config value)
+    updated_ms: [AtomicU64; QuoteCurrency::COUNT],
+    max_depeg: FixedPoint8,
+}
+
+impl QuoteRates {
+    pub fn new(config: &RatesConfig) -> Self {
+        let usd = FixedPoint8::from_f64(config.usd_rate).filter(|r| r.is_positive()).unwrap_or(FixedPoint8::ONE);
+        let factors = QuoteCurrency::ALL.map(|c| AtomicI64::new(if c == QuoteCurrency::Usd { usd } else { FixedPoint8::ONE }.as_raw()));
+        Self {
+            factors,
+            updated_ms: std::array::from_fn(|_| AtomicU64::new(0)),
+            max_depeg: FixedPoint8::from_f64(config.max_depeg.max(0.0)).unwrap_or(FixedPoint8::ZERO),
+        }
+    }
+
+    /// USDT per unit of `currency`
+    #[inline]
+    pub fn factor(&self, currency: QuoteCurrency) -> FixedPoint8 {
+        FixedPoint8::from_raw(self.factors[currency.index()].load(Ordering::Relaxed))
+    }
+
+    /// `amount` of `currency` in USDT (unconverted on overflow)
+    #[inline]
+    pub fn to_usdt(&self, amount: FixedPoint8, currency: QuoteCurrency) -> FixedPoint8 {
+        if currency == QuoteCurrency::Usdt {
+            return amount;
+        }
+        amount.safe_mul(self.factor(currency)).unwrap_or(amount)
+    }
+
+    /// Float variant for cold-path aggregates (24h volumes)
+    #[inline]
+    pub fn to_usdt_f64(&self, amount: f64, currency: QuoteCurrency) -> f64 {
+        amount * self.factor(currency).to_f64()
+    }
+
+    /// Accept a fetched rate (false if off par by more than `max_depeg`)
+    ///
+    /// USDT is the unit and never changes.
+    pub fn update(&self, currency: QuoteCurrency, rate: FixedPoint8, now_ms: u64) -> bool {
+        let depeg = rate.checked_sub(FixedPoint8::ONE).and_then(|d| d.checked_abs());
+        if currency == QuoteCurrency::Usdt || !rate.is_positive() || depeg.is_none_or(|d| d > self.max_depeg) {
+            return false;
+        }
+        self.factors[currency.index()].store(rate.as_raw(), Ordering::Relaxed);
+        self.updated_ms[currency.index()].store(now_ms, Ordering::Relaxed);
+        true
+    }
+
+    /// Last accepted refresh of `currency` (None = still the config value)
+    pub fn updated_ms(&self, currency: QuoteCurrency) -> Option<u64> {
+        Some(self.updated_ms[currency.index()].load(Ordering::Relaxed)).filter(|&ms| ms != 0)
+    }
+}
+
+impl Default for QuoteRates {
+    fn default() -> Self {
+        Self::new(&RatesConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conversion_and_depeg_guard() {
+        assert_eq!(QuoteCurrency::split("BTCUSDC"), Some(("BTC", QuoteCurrency::Usdc)));
+        assert_eq!(QuoteCurrency::split("1000PEPEUSDT"), Some(("1000PEPE", QuoteCurrency::Usdt)));
+        assert_eq!(QuoteCurrency::split("BTCUSD"), Some(("BTC", QuoteCurrency::Usd)));
+        assert_eq!(QuoteCurrency::split("USDT"), None);
+        assert_eq!(QuoteCurrency::from_asset("usdc"), Some(QuoteCurrency::Usdc));
+
+        let rates = QuoteRates::new(&RatesConfig { usd_rate: 1.002, ..Default::default() });
+        let fp = |v: f64| FixedPoint8::from_f64(v).unwrap();
+        assert_eq!(rates.to_usdt(fp(100.0), QuoteCurrency::Usdt), fp(100.0));
+        assert_eq!(rates.to_usdt(fp(100.0), QuoteCurrency::Usdc), fp(100.0));
+        assert_eq!(rates.to_usdt(fp(100.0), QuoteCurrency::Usd), fp(100.2));
+
+        assert!(rates.update(QuoteCurrency::Usdc, fp(0.9995), 1_000));
+        assert_eq!(rates.to_usdt(fp(1_000.0), QuoteCurrency::Usdc), fp(999.5));
+        assert_eq!(rates.updated_ms(QuoteCurrency::Usdc), Some(1_000));
+        assert_eq!(rates.updated_ms(QuoteCurrency::Usd), None);
+
+        // Glitched book and the unit itself are rejected
+        assert!(!rates.update(QuoteCurrency::Usdc, fp(0.5), 2_000));
+        assert!(!rates.update(QuoteCurrency::Usdt, fp(1.001), 2_000));
+        assert_eq!(rates.factor(QuoteCurrency::Usdc), fp(0.9995));
+        assert_eq!(rates.to_usdt_f64(2_000.0, QuoteCurrency::Usdc), 1_999.0);
+    }
+}
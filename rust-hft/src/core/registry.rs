@@ -2,24 +2,44 @@
 //!
 //! Symbols are stored as u32 IDs with pre-registered lookup.
 //! Zero-allocation parsing from JSON byte slices.
+//!
+//! The global registry is an immutable snapshot behind an atomic pointer.
+//! [`SymbolRegistry::initialize`] merges names into a copy and swaps it in,
+//! so it is idempotent, keeps existing IDs and can be called in any order.
+//! Replaced snapshots are leaked on purpose: readers hold `&'static`
+//! references and initialization happens a handful of times per process.
+//!
+//! Initialization is transactional: capacity is checked before any name is
+//! leaked and the merged copy is only published once complete, so a failed
+//! call leaves the registry exactly as it was.
+//! [`SymbolRegistry::initialize_trimmed`] instead registers what fits and
+//! reports the rest.
 
 use crate::core::Symbol;
-use std::sync::atomic::{AtomicU32, Ordering};
-use std::sync::OnceLock;
+use std::collections::{HashMap, HashSet};
+use std::ptr;
+use std::sync::atomic::{AtomicPtr, Ordering};
 
 /// Maximum number of symbols supported
 pub const MAX_SYMBOLS: usize = 5000;
 
-/// Global symbol registry
-static SYMBOL_REGISTRY: OnceLock<SymbolRegistry> = OnceLock::new();
+/// Lookup table slots per registered name, at least (load factor ≤ 1/2)
+const SLOTS_PER_NAME: usize = 2;
 
-/// Next symbol ID counter (for registration)
-static NEXT_SYMBOL_ID: AtomicU32 = AtomicU32::new(0);
+/// Smallest lookup table
+const MIN_TABLE_SLOTS: usize = 64;
+
+/// Global symbol registry (null until first initialize)
+static SYMBOL_REGISTRY: AtomicPtr<SymbolRegistry> = AtomicPtr::new(ptr::null_mut());
 
 /// Symbol registry with array-based lookup
+///
+/// The open-addressing lookup table is grown before publication so it
+/// never fills past [`SLOTS_PER_NAME`]'s load factor.
+#[derive(Clone)]
 pub struct SymbolRegistry {
     names: Box<[Option<&'static str>; MAX_SYMBOLS]>,
-    lookup_table: Box<[Option<u32>; MAX_SYMBOLS]>,
+    lookup_table: Box<[Option<u32>]>,
     count: u32,
 }
 
@@ -27,45 +47,136 @@ impl SymbolRegistry {
     fn new() -> Self {
         Self {
             names: Box::new([None; MAX_SYMBOLS]),
-            lookup_table: Box::new([None; MAX_SYMBOLS]),
+            lookup_table: vec![None; MIN_TABLE_SLOTS].into_boxed_slice(),
             count: 0,
         }
     }
 
+    /// Register names missing from the global registry (merge)
+    ///
+    /// Existing names keep their IDs; new names get the next free IDs in
+    /// order. Lock-free: a merged copy is swapped in with compare-and-swap
+    /// and rebuilt if another initializer won the race. Nothing is added
+    /// (or leaked) if the merge would exceed capacity.
     pub fn initialize(symbols: &[String]) -> Result<(), RegistryError> {
-        let mut registry = Self::new();
+        Self::merge(symbols, false).map(|_| ())
+    }
+
+    /// Register as many new names as fit, in order; returns the dropped ones
+    ///
+    /// For discovery lists that may outgrow [`MAX_SYMBOLS`]: the overflow is
+    /// logged and skipped instead of failing startup.
+    pub fn initialize_trimmed(symbols: &[String]) -> Vec<String> {
+        let dropped = Self::merge(symbols, true).unwrap_or_default();
+        if !dropped.is_empty() {
+            tracing::warn!(
+                "Symbol registry full ({} max): dropped {} symbols: {}",
+                MAX_SYMBOLS,
+                dropped.len(),
+                dropped.join(", ")
+            );
+        }
+        dropped
+    }
 
-        for name in symbols {
-            if registry.count >= MAX_SYMBOLS as u32 {
-                return Err(RegistryError::CapacityExceeded);
+    fn merge(symbols: &[String], trim: bool) -> Result<Vec<String>, RegistryError> {
+        // Names leaked by an earlier attempt are reused after a lost race
+        let mut leaked: HashMap<&str, &'static str> = HashMap::new();
+        let mut current = SYMBOL_REGISTRY.load(Ordering::Acquire);
+        loop {
+            // SAFETY: non-null pointers come from Box::into_raw below and are never freed
+            let base = unsafe { current.as_ref() };
+            let (pending, dropped) = Self::plan(base, symbols, MAX_SYMBOLS, trim)?;
+            if pending.is_empty() && base.is_some() {
+                return Ok(dropped); // Nothing new
             }
 
-            let id = NEXT_SYMBOL_ID.fetch_add(1, Ordering::SeqCst);
-            if id as usize >= MAX_SYMBOLS {
-                return Err(RegistryError::CapacityExceeded);
+            // Build fully, then publish
+            let mut registry = base.cloned().unwrap_or_else(Self::new);
+            registry.reserve(pending.len())?;
+            for name in &pending {
+                let name = *leaked
+                    .entry(name)
+                    .or_insert_with(|| Box::leak(name.to_string().into_boxed_str()));
+                registry.push(name)?;
             }
 
-            let static_name: &'static str = Box::leak(name.clone().into_boxed_str());
-            registry.names[id as usize] = Some(static_name);
+            let total = registry.count;
+            let next = Box::into_raw(Box::new(registry));
+            match SYMBOL_REGISTRY.compare_exchange(current, next, Ordering::AcqRel, Ordering::Acquire) {
+                Ok(_) => {
+                    tracing::info!("Symbol registry: {} new symbols, {} total", pending.len(), total);
+                    return Ok(dropped);
+                }
+                Err(actual) => {
+                    // SAFETY: `next` was never published
+                    drop(unsafe { Box::from_raw(next) });
+                    current = actual;
+                }
+            }
+        }
+    }
+
+    /// New names to register on top of `base` and names dropped for capacity
+    ///
+    /// Duplicates and empty names are skipped. Without `trim`, overflow is
+    /// an error and nothing is registered.
+    fn plan<'a>(
+        base: Option<&Self>,
+        symbols: &'a [String],
+        capacity: usize,
+        trim: bool,
+    ) -> Result<(Vec<&'a str>, Vec<String>), RegistryError> {
+        let mut seen = HashSet::new();
+        let mut pending: Vec<&str> = symbols
+            .iter()
+            .map(String::as_str)
+            .filter(|name| !name.is_empty())
+            .filter(|name| base.is_none_or(|registry| registry.lookup(name.as_bytes()).is_none()))
+            .filter(|name| seen.insert(*name))
+            .collect();
 
-            let hash = hash_symbol_name(static_name.as_bytes());
-            let slot = find_slot(&registry.lookup_table, hash, static_name);
-            registry.lookup_table[slot] = Some(id);
-            registry.count += 1;
+        let available = capacity.saturating_sub(base.map_or(0, |registry| registry.count as usize));
+        if pending.len() <= available {
+            return Ok((pending, Vec::new()));
         }
+        if !trim {
+            return Err(RegistryError::CapacityExceeded { requested: pending.len(), available });
+        }
+        let dropped = pending.split_off(available).into_iter().map(str::to_owned).collect();
+        Ok((pending, dropped))
+    }
+
+    /// Grow the lookup table so `additional` more names keep the load factor
+    fn reserve(&mut self, additional: usize) -> Result<(), RegistryError> {
+        let slots = ((self.count as usize + additional) * SLOTS_PER_NAME)
+            .next_power_of_two()
+            .max(MIN_TABLE_SLOTS);
+        if slots <= self.lookup_table.len() {
+            return Ok(());
+        }
+        let mut table = vec![None; slots].into_boxed_slice();
+        for (symbol, name) in self.symbols() {
+            let slot = find_slot(&table, hash_symbol_name(name.as_bytes()))?;
+            table[slot] = Some(symbol.as_raw());
+        }
+        self.lookup_table = table;
+        Ok(())
+    }
 
-        SYMBOL_REGISTRY
-            .set(registry)
-            .map_err(|_| RegistryError::AlreadyInitialized)?;
-        tracing::info!(
-            "Symbol registry initialized with {} symbols",
-            SYMBOL_REGISTRY.get().unwrap().count
-        );
+    /// Append a name known to be absent (capacity reserved by the caller)
+    fn push(&mut self, name: &'static str) -> Result<(), RegistryError> {
+        let id = self.count;
+        let slot = find_slot(&self.lookup_table, hash_symbol_name(name.as_bytes()))?;
+        self.lookup_table[slot] = Some(id);
+        self.names[id as usize] = Some(name);
+        self.count += 1;
         Ok(())
     }
 
     pub fn try_global() -> Option<&'static Self> {
-        SYMBOL_REGISTRY.get()
+        // SAFETY: non-null pointers come from Box::into_raw and are never freed
+        unsafe { SYMBOL_REGISTRY.load(Ordering::Acquire).as_ref() }
     }
 
     pub fn lookup(&self, name: &[u8]) -> Option<Symbol> {
@@ -74,37 +185,37 @@ impl SymbolRegistry {
         }
 
         // Hash-based lookup for all symbols
-        let hash = hash_symbol_name(name);
-        let mut probe = 0;
-        loop {
-            let slot = (hash as usize + probe) % MAX_SYMBOLS;
-            match self.lookup_table[slot] {
-                Some(id) => {
-                    if let Some(stored_name) = self.names[id as usize] {
-                        if stored_name.as_bytes() == name {
-                            return Some(Symbol::from_raw(id));
-                        }
-                    }
-                    probe += 1;
-                    if probe >= MAX_SYMBOLS {
-                        return None;
-                    }
+        let hash = hash_symbol_name(name) as usize;
+        let len = self.lookup_table.len();
+        for probe in 0..len {
+            let id = self.lookup_table[(hash + probe) % len]?;
+            if let Some(stored_name) = self.names[id as usize] {
+                if stored_name.as_bytes() == name {
+                    return Some(Symbol::from_raw(id));
                 }
-                None => return None,
             }
         }
+        None
     }
 
     pub fn get_name(&self, symbol: Symbol) -> Option<&'static str> {
         self.names.get(symbol.as_raw() as usize)?.as_ref().copied()
     }
 
+    /// Registered symbols with their names, in ID order
+    pub fn symbols(&self) -> impl Iterator<Item = (Symbol, &'static str)> + '_ {
+        self.names
+            .iter()
+            .enumerate()
+            .filter_map(|(id, name)| Some((Symbol::from_raw(id as u32), (*name)?)))
+    }
+
     pub fn count(&self) -> u32 {
         self.count
     }
 
     pub fn is_initialized() -> bool {
-        SYMBOL_REGISTRY.get().is_some()
+        !SYMBOL_REGISTRY.load(Ordering::Acquire).is_null()
     }
 }
 
@@ -118,29 +229,22 @@ fn hash_symbol_name(name: &[u8]) -> u32 {
     hash
 }
 
+/// First free slot on the probe sequence of `hash` (linear probing)
 #[inline]
-fn find_slot(table: &[Option<u32>; MAX_SYMBOLS], hash: u32, _name: &str) -> usize {
-    let mut probe = 0;
-    loop {
-        let slot = (hash as usize + probe) % MAX_SYMBOLS;
-        match table[slot] {
-            None => return slot,
-            Some(_id) => {
-                probe += 1;
-                if probe >= MAX_SYMBOLS {
-                    panic!("Symbol lookup table full");
-                }
-            }
-        }
-    }
+fn find_slot(table: &[Option<u32>], hash: u32) -> Result<usize, RegistryError> {
+    let len = table.len();
+    (0..len)
+        .map(|probe| (hash as usize + probe) % len)
+        .find(|&slot| table[slot].is_none())
+        .ok_or(RegistryError::TableFull)
 }
 
 #[derive(Debug, thiserror::Error)]
 pub enum RegistryError {
-    #[error("Registry already initialized")]
-    AlreadyInitialized,
-    #[error("Symbol capacity exceeded")]
-    CapacityExceeded,
+    #[error("Symbol capacity exceeded: {requested} new symbols, {available} slots free")]
+    CapacityExceeded { requested: usize, available: usize },
+    #[error("Symbol lookup table full")]
+    TableFull,
 }
 
 #[cfg(test)]
@@ -154,19 +258,102 @@ mod tests {
     }
     #[test]
     fn test_registry_initialization() {
-        if !SymbolRegistry::is_initialized() {
-            let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
-            assert!(SymbolRegistry::initialize(&symbols).is_ok());
-        }
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+        assert!(SymbolRegistry::initialize(&symbols).is_ok());
+        // Idempotent regardless of who initialized first
+        assert!(SymbolRegistry::initialize(&symbols).is_ok());
+        assert!(SymbolRegistry::is_initialized());
     }
     #[test]
     fn test_registry_lookup() {
-        if !SymbolRegistry::is_initialized() {
-            let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
-            SymbolRegistry::initialize(&symbols).ok();
-        }
+        let symbols = vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()];
+        SymbolRegistry::initialize(&symbols).unwrap();
         let registry = SymbolRegistry::try_global().unwrap();
         assert!(registry.lookup(b"BTCUSDT").is_some());
         assert!(registry.lookup(b"ETHUSDT").is_some());
     }
+    #[test]
+    fn test_registry_merge_keeps_ids() {
+        SymbolRegistry::initialize(&["BTCUSDT".to_string()]).unwrap();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        let names = vec!["MERGEAUSDT".to_string(), "BTCUSDT".to_string(), "MERGEAUSDT".to_string()];
+        SymbolRegistry::initialize(&names).unwrap();
+
+        let registry = SymbolRegistry::try_global().unwrap();
+        assert_eq!(registry.lookup(b"BTCUSDT"), Some(btc));
+        let merged = registry.lookup(b"MERGEAUSDT").unwrap();
+        assert_eq!(registry.get_name(merged), Some("MERGEAUSDT"));
+        assert_eq!(registry.symbols().filter(|(_, name)| *name == "MERGEAUSDT").count(), 1);
+    }
+    #[test]
+    fn test_plan_capacity() {
+        let names: Vec<String> = ["A", "B", "", "A", "C", "D"].iter().map(|s| s.to_string()).collect();
+
+        // All or nothing
+        let err = SymbolRegistry::plan(None, &names, 3, false).unwrap_err();
+        assert!(matches!(err, RegistryError::CapacityExceeded { requested: 4, available: 3 }));
+
+        // Trimmed in order, duplicates and empty names skipped
+        let (pending, dropped) = SymbolRegistry::plan(None, &names, 3, true).unwrap();
+        assert_eq!(pending, ["A", "B", "C"]);
+        assert_eq!(dropped, ["D"]);
+
+        // Already registered names take no new slot
+        let mut base = SymbolRegistry::new();
+        base.push("A").unwrap();
+        base.push("B").unwrap();
+        let (pending, dropped) = SymbolRegistry::plan(Some(&base), &names, 4, false).unwrap();
+        assert_eq!((pending, dropped.len()), (vec!["C", "D"], 0));
+    }
+    #[test]
+    fn test_lookup_table_growth() {
+        let mut registry = SymbolRegistry::new();
+        let names: Vec<&'static str> = (0..300).map(|i| &*format!("GROW{}USDT", i).leak()).collect();
+        for chunk in names.chunks(100) {
+            registry.reserve(chunk.len()).unwrap();
+            for name in chunk {
+                registry.push(name).unwrap();
+            }
+            assert!(registry.lookup_table.len() >= registry.count as usize * SLOTS_PER_NAME);
+        }
+        for (id, name) in names.iter().enumerate() {
+            assert_eq!(registry.lookup(name.as_bytes()), Some(Symbol::from_raw(id as u32)));
+        }
+        assert_eq!(registry.lookup(b"MISSING"), None);
+
+        let full = [Some(0u32), Some(1)];
+        assert!(matches!(find_slot(&full, 7), Err(RegistryError::TableFull)));
+        assert_eq!(find_slot(&[Some(0), None], 0).unwrap(), 1);
+    }
+    #[test]
+    fn test_initialize_trimmed_within_capacity() {
+        let names = vec!["TRIMAUSDT".to_string(), "TRIMBUSDT".to_string()];
+        assert!(SymbolRegistry::initialize_trimmed(&names).is_empty());
+        let registry = SymbolRegistry::try_global().unwrap();
+        assert!(registry.lookup(b"TRIMAUSDT").is_some());
+        assert!(registry.lookup(b"TRIMBUSDT").is_some());
+    }
+    #[test]
+    fn test_concurrent_initialize() {
+        let handles: Vec<_> = (0..4)
+            .map(|t| {
+                std::thread::spawn(move || {
+                    let names: Vec<String> = (0..8).map(|i| format!("RACE{}{}USDT", t, i)).collect();
+                    SymbolRegistry::initialize(&names).unwrap();
+                })
+            })
+            .collect();
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        let registry = SymbolRegistry::try_global().unwrap();
+        for t in 0..4 {
+            for i in 0..8 {
+                let name = format!("RACE{}{}USDT", t, i);
+                let symbol = registry.lookup(name.as_bytes()).unwrap();
+                assert_eq!(registry.get_name(symbol), Some(name.as_str()));
+            }
+        }
+    }
 }
@@ -34,15 +34,23 @@ impl Symbol {
         None
     }
 
+    /// Parse an exchange listing name, resolving venue aliases to the canonical symbol
     #[inline]
-    pub fn as_str(&self) -> &'static str {
+    pub fn from_exchange_bytes(bytes: &[u8], exchange: crate::exchanges::Exchange) -> Option<Self> {
+        Self::from_bytes(crate::core::SymbolMapper::canonical_name(bytes, exchange))
+    }
+
+    /// Registered name (None if the registry has no entry for this ID)
+    #[inline]
+    pub fn name(&self) -> Option<&'static str> {
         // Lookup in registry (single source of truth)
-        if let Some(registry) = crate::core::registry::SymbolRegistry::try_global() {
-            if let Some(name) = registry.get_name(*self) {
-                return name;
-            }
-        }
-        "UNKNOWN"
+        crate::core::registry::SymbolRegistry::try_global()?.get_name(*self)
+    }
+
+    /// Registered name, or "UNKNOWN" for unregistered IDs (display only)
+    #[inline]
+    pub fn as_str(&self) -> &'static str {
+        self.name().unwrap_or("UNKNOWN")
     }
 
     #[inline(always)]
@@ -59,11 +67,9 @@ impl Default for Symbol {
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     #[test]
     fn test_parse_symbols_via_registry() {
@@ -117,6 +123,17 @@ mod tests {
         assert_eq!(a, c);
     }
 
+    #[test]
+    fn test_name_misses_unregistered_id() {
+        init_test_registry();
+
+        assert_eq!(Symbol::from_bytes(b"BTCUSDT").unwrap().name(), Some("BTCUSDT"));
+        let unregistered = Symbol::from_raw(Symbol::MAX_SYMBOLS - 1);
+        assert_eq!(unregistered.name(), None);
+        assert_eq!(unregistered.as_str(), "UNKNOWN");
+        assert_eq!(Symbol::UNKNOWN.name(), None);
+    }
+
     #[test]
     fn test_unknown_symbol() {
         assert!(!Symbol::UNKNOWN.is_valid());
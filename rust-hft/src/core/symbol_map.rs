@@ -1,33 +1,67 @@
 //! Symbol mapping for exchange-specific naming
 //!
-//! Simplified: just uses Symbol::as_str() which queries the registry.
+//! Names come from the registry (the only symbol table). A small alias
+//! table maps venue listings whose name differs from the canonical symbol
+//! (e.g. Bybit `1000PEPEUSDT` → `PEPEUSDT`). Aliased listings must quote the
+//! same contract size on every venue: prices are not rescaled.
 
 use crate::core::Symbol;
 use crate::exchanges::Exchange;
 
+/// Venue listing name → canonical symbol name
+struct SymbolAlias {
+    exchange: Exchange,
+    name: &'static str,
+    canonical: &'static str,
+}
+
+/// Known listings that differ from the canonical name (1000× contracts on both venues)
+const ALIASES: &[SymbolAlias] = &[
+    SymbolAlias { exchange: Exchange::Binance, name: "1000PEPEUSDT", canonical: "PEPEUSDT" },
+    SymbolAlias { exchange: Exchange::Bybit, name: "1000PEPEUSDT", canonical: "PEPEUSDT" },
+    SymbolAlias { exchange: Exchange::Binance, name: "1000BONKUSDT", canonical: "BONKUSDT" },
+    SymbolAlias { exchange: Exchange::Bybit, name: "1000BONKUSDT", canonical: "BONKUSDT" },
+    SymbolAlias { exchange: Exchange::Binance, name: "1000FLOKIUSDT", canonical: "FLOKIUSDT" },
+    SymbolAlias { exchange: Exchange::Bybit, name: "1000FLOKIUSDT", canonical: "FLOKIUSDT" },
+    SymbolAlias { exchange: Exchange::Binance, name: "1000SHIBUSDT", canonical: "SHIBUSDT" },
+    SymbolAlias { exchange: Exchange::Bybit, name: "SHIB1000USDT", canonical: "SHIBUSDT" },
+];
+
 pub struct SymbolMapper;
 
 impl SymbolMapper {
-    /// Get exchange-specific name for a symbol
-    /// Currently just returns the symbol name from registry
+    /// Get exchange-specific name for a symbol (None if unregistered)
+    #[inline]
+    pub fn get_name(symbol: Symbol, exchange: Exchange) -> Option<&'static str> {
+        let name = symbol.name()?;
+        Some(
+            ALIASES
+                .iter()
+                .find(|a| a.exchange == exchange && a.canonical == name)
+                .map_or(name, |a| a.name),
+        )
+    }
+
+    /// Canonical name for an exchange listing (the name itself if not aliased)
     #[inline]
-    pub fn get_name(symbol: Symbol, _exchange: Exchange) -> Option<&'static str> {
-        Some(symbol.as_str())
+    pub fn canonical_name(name: &[u8], exchange: Exchange) -> &[u8] {
+        ALIASES
+            .iter()
+            .find(|a| a.exchange == exchange && a.name.as_bytes() == name)
+            .map_or(name, |a| a.canonical.as_bytes())
     }
 
     /// Parse symbol from exchange name
     #[inline]
-    pub fn from_exchange_name(name: &str, _exchange: Exchange) -> Option<Symbol> {
-        Symbol::from_bytes(name.as_bytes())
+    pub fn from_exchange_name(name: &str, exchange: Exchange) -> Option<Symbol> {
+        Symbol::from_exchange_bytes(name.as_bytes(), exchange)
     }
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     #[test]
     fn test_get_name() {
@@ -41,6 +75,7 @@ mod tests {
             SymbolMapper::get_name(btc, Exchange::Bybit),
             Some("BTCUSDT")
         );
+        assert_eq!(SymbolMapper::get_name(Symbol::UNKNOWN, Exchange::Binance), None);
     }
 
     #[test]
@@ -55,4 +90,16 @@ mod tests {
             Some(Symbol::from_bytes(b"ETHUSDT").unwrap())
         );
     }
+
+    #[test]
+    fn test_aliases_round_trip() {
+        init_test_registry();
+        let pepe = Symbol::from_bytes(b"PEPEUSDT").unwrap();
+        assert_eq!(SymbolMapper::from_exchange_name("1000PEPEUSDT", Exchange::Bybit), Some(pepe));
+        assert_eq!(SymbolMapper::from_exchange_name("1000PEPEUSDT", Exchange::Binance), Some(pepe));
+        assert_eq!(SymbolMapper::get_name(pepe, Exchange::Bybit), Some("1000PEPEUSDT"));
+        assert_eq!(SymbolMapper::canonical_name(b"SHIB1000USDT", Exchange::Bybit), b"SHIBUSDT");
+        // Aliases are per venue
+        assert_eq!(SymbolMapper::canonical_name(b"SHIB1000USDT", Exchange::Binance), b"SHIB1000USDT");
+    }
 }
@@ -0,0 +1,136 @@
+//! Opportunity alert conflation
+//!
+//! A spread oscillating around the threshold would alert on every update.
+//! Alerts are conflated per opportunity episode instead: one alert when the
+//! episode opens, then at most one update per interval carrying the peak
+//! and the number of spread updates conflated since the last alert, and a
+//! summary when the episode closes (spread back under the threshold or
+//! direction flipped, see [`super::episodes`]).
+
+use super::episodes::Episode;
+use crate::core::{Bps, Symbol};
+use crate::hot_path::SpreadEvent;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Alert to publish
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Alert {
+    /// First update of an episode above the threshold
+    Opened(SpreadEvent),
+    /// Latest update, with the episode peak so far and updates since the last alert
+    Update { event: SpreadEvent, peak: Bps, conflated: u64 },
+    /// Episode summary
+    Closed(Episode),
+}
+
+impl Alert {
+    /// Alert kind for rate limiting and payloads
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::Opened(_) => "opened",
+            Self::Update { .. } => "update",
+            Self::Closed(_) => "closed",
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Alerted {
+    last: Instant,
+    peak: Bps,
+    conflated: u64,
+}
+
+/// Per-symbol alert state for open episodes
+#[derive(Debug)]
+pub struct AlertConflator {
+    interval: Duration,
+    open: HashMap<Symbol, Alerted>,
+}
+
+impl AlertConflator {
+    /// At most one update alert per `interval` and episode
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval,
+            open: HashMap::new(),
+        }
+    }
+
+    /// Spread update above the threshold; returns the alert to publish, if any
+    pub fn on_opportunity(&mut self, event: &SpreadEvent, now: Instant) -> Option<Alert> {
+        let Some(alerted) = self.open.get_mut(&event.symbol) else {
+            self.open.insert(event.symbol, Alerted { last: now, peak: event.spread, conflated: 0 });
+            return Some(Alert::Opened(*event));
+        };
+        alerted.peak = alerted.peak.max(event.spread);
+        alerted.conflated += 1;
+        if now.saturating_duration_since(alerted.last) < self.interval {
+            return None;
+        }
+        let alert = Alert::Update { event: *event, peak: alerted.peak, conflated: alerted.conflated };
+        alerted.last = now;
+        alerted.conflated = 0;
+        Some(alert)
+    }
+
+    /// Completed episode; a summary if its opening was alerted
+    pub fn on_episode_end(&mut self, episode: &Episode) -> Option<Alert> {
+        self.open.remove(&episode.symbol).map(|_| Alert::Closed(*episode))
+    }
+
+    /// Episodes with an open alert
+    pub fn open_count(&self) -> usize {
+        self.open.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::Exchange;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_alert_conflation() {
+        init_test_registry();
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let event = |spread: i64| SpreadEvent {
+            symbol,
+            spread: Bps::from_bps(spread),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            timestamp: 0,
+            spread_ticks: None,
+        };
+        let t0 = Instant::now();
+        let secs = Duration::from_secs;
+        let mut alerts = AlertConflator::new(secs(10));
+
+        assert_eq!(alerts.on_opportunity(&event(30), t0), Some(Alert::Opened(event(30))));
+        // Oscillation within the interval is conflated
+        for (i, spread) in [40, 30, 35].into_iter().enumerate() {
+            assert_eq!(alerts.on_opportunity(&event(spread), t0 + secs(i as u64 + 1)), None);
+        }
+        let update = alerts.on_opportunity(&event(32), t0 + secs(10));
+        assert_eq!(update, Some(Alert::Update { event: event(32), peak: Bps::from_bps(40), conflated: 4 }));
+        assert_eq!(alerts.on_opportunity(&event(33), t0 + secs(11)), None);
+
+        let episode = Episode {
+            id: 1,
+            symbol,
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            start: 0,
+            end: 1,
+            peak_spread: Bps::from_bps(40),
+            updates: 6,
+            aborted: false,
+        };
+        assert_eq!(alerts.on_episode_end(&episode), Some(Alert::Closed(episode)));
+        assert_eq!((alerts.on_episode_end(&episode), alerts.open_count()), (None, 0));
+        // The next episode alerts on its first update again
+        assert!(matches!(alerts.on_opportunity(&event(30), t0 + secs(12)), Some(Alert::Opened(_))));
+    }
+}
@@ -0,0 +1,410 @@
+//! Capital partitioning between strategy instances
+//!
+//! Each configured instance (`[[strategies.instances]]`) runs with a
+//! [`StrategyBudget`]: its capital, order and per-symbol notional limits,
+//! minimum spread and symbol set. The allocator keeps the open positions of
+//! every partitioned instance from the orders the live path accepted, valued
+//! at entry price: an accepted order counts as filled. Venue fills (the Bybit
+//! private stream) reach the position book but carry no instance, so they do
+//! not adjust partitions. An order that would raise an instance's exposure
+//! beyond its limits is rejected; risk-reducing orders always pass. Budgets
+//! are admitted only while their capital sums to at most the account margin,
+//! so the sum of commitments cannot exceed it either.
+//!
+//! Strategies added without a budget are unpartitioned and unrestricted.
+
+use super::strategy::OrderRequest;
+use crate::core::{Bps, FixedPoint8, Side, Symbol, TickerData};
+use crate::exchanges::Exchange;
+use crate::hot_path::SpreadEvent;
+use crate::infrastructure::config::StrategyInstanceConfig;
+use crate::{HftError, Result};
+use std::collections::HashMap;
+
+/// Capital and limits of one strategy instance
+#[derive(Debug, Clone, PartialEq)]
+pub struct StrategyBudget {
+    pub name: String,
+    pub capital: FixedPoint8,
+    pub max_order_notional: FixedPoint8,
+    pub max_symbol_notional: FixedPoint8,
+    /// Spread updates below this are not delivered (None = all)
+    pub min_spread: Option<Bps>,
+    /// Symbols the instance may trade (uppercase; empty = all)
+    pub symbols: Vec<String>,
+}
+
+impl StrategyBudget {
+    /// Budget from config (capital must be positive)
+    pub fn from_config(config: &StrategyInstanceConfig) -> Result<Self> {
+        let amount = |field: &str, value: f64| {
+            FixedPoint8::from_f64(value)
+                .filter(|v| v.is_positive())
+                .ok_or_else(|| HftError::Config(format!("strategy instance '{}': invalid {} {}", config.name, field, value)))
+        };
+        let capital = amount("capital", config.capital)?;
+        let min_spread = config
+            .min_spread
+            .map(|fraction| {
+                Bps::from_fraction_f64(fraction).ok_or_else(|| {
+                    HftError::Config(format!("strategy instance '{}': invalid min_spread {}", config.name, fraction))
+                })
+            })
+            .transpose()?;
+        Ok(Self {
+            name: config.name.clone(),
+            capital,
+            max_order_notional: config
+                .max_order_notional
+                .map_or(Ok(capital), |v| amount("max_order_notional", v))?,
+            max_symbol_notional: config
+                .max_symbol_notional
+                .map_or(Ok(capital), |v| amount("max_symbol_notional", v))?,
+            min_spread,
+            symbols: config.symbols.iter().map(|s| s.to_ascii_uppercase()).collect(),
+        })
+    }
+
+    fn trades(&self, symbol: Symbol) -> bool {
+        self.symbols.is_empty() || self.symbols.iter().any(|s| s == symbol.as_str())
+    }
+}
+
+/// Why the allocator rejected an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AllocationRejected {
+    /// Symbol outside the instance's symbol set
+    SymbolNotAllocated,
+    /// No limit price and no quote to value the order
+    NoPrice,
+    /// Order notional above `max_order_notional`
+    OrderNotional,
+    /// Open notional in the symbol would exceed `max_symbol_notional`
+    SymbolNotional,
+    /// Open notional would exceed the instance's capital
+    Capital,
+}
+
+impl AllocationRejected {
+    /// Short reason for logs and reports
+    pub const fn reason(self) -> &'static str {
+        match self {
+            Self::SymbolNotAllocated => "symbol-not-allocated",
+            Self::NoPrice => "no-price",
+            Self::OrderNotional => "order-notional",
+            Self::SymbolNotional => "symbol-notional",
+            Self::Capital => "capital",
+        }
+    }
+}
+
+/// Open position of an instance on one venue
+#[derive(Debug, Clone, Copy, Default)]
+struct Holding {
+    /// Signed quantity (long > 0, short < 0)
+    net: FixedPoint8,
+    /// Entry price of the open quantity
+    entry: FixedPoint8,
+}
+
+impl Holding {
+    fn notional(&self) -> FixedPoint8 {
+        self.net.checked_abs().and_then(|q| q.safe_mul(self.entry)).unwrap_or(FixedPoint8::MAX)
+    }
+
+    /// Position after filling `order` at `price`
+    fn after(&self, order: &OrderRequest, price: FixedPoint8) -> Holding {
+        let net = match order.side {
+            Side::Buy => self.net.checked_add(order.quantity),
+            Side::Sell => self.net.checked_sub(order.quantity),
+        }
+        .unwrap_or(FixedPoint8::MAX);
+        let adding = match order.side {
+            Side::Buy => !self.net.is_negative(),
+            Side::Sell => !self.net.is_positive(),
+        };
+        let entry = if adding {
+            // Quantity-weighted entry
+            let held = self.net.checked_abs().unwrap_or(FixedPoint8::ZERO);
+            held.safe_mul(self.entry)
+                .and_then(|cost| cost.checked_add(order.quantity.safe_mul(price)?))
+                .and_then(|cost| cost.safe_div(held.checked_add(order.quantity)?))
+                .unwrap_or(price)
+        } else if net.signum() == self.net.signum() || net.is_zero() {
+            self.entry
+        } else {
+            // Flipped: the new side opens at the fill price
+            price
+        };
+        Holding { net, entry }
+    }
+}
+
+#[derive(Debug)]
+struct Account {
+    budget: StrategyBudget,
+    holdings: HashMap<(Exchange, Symbol), Holding>,
+    /// Sum of open notional over holdings
+    committed: FixedPoint8,
+}
+
+/// Per-instance budgets, indexed like the engine's strategies
+#[derive(Debug, Default)]
+pub struct CapitalAllocator {
+    /// None = unpartitioned strategy
+    accounts: Vec<Option<Account>>,
+    /// Last mid price per venue and symbol (values market orders)
+    mids: HashMap<(Exchange, Symbol), FixedPoint8>,
+}
+
+impl CapitalAllocator {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register the next strategy without a budget
+    pub fn add_unpartitioned(&mut self) {
+        self.accounts.push(None);
+    }
+
+    /// Register the next strategy with `budget`
+    ///
+    /// Fails if the name is taken or the total allocated capital would
+    /// exceed `margin`.
+    pub fn add(&mut self, budget: StrategyBudget, margin: FixedPoint8) -> Result<()> {
+        if self.accounts.iter().flatten().any(|a| a.budget.name == budget.name) {
+            return Err(HftError::Config(format!("duplicate strategy instance '{}'", budget.name)));
+        }
+        let allocated = self.allocated().checked_add(budget.capital).unwrap_or(FixedPoint8::MAX);
+        if allocated > margin {
+            return Err(HftError::Config(format!(
+                "strategy instance '{}': allocated capital {} exceeds account margin {}",
+                budget.name, allocated, margin
+            )));
+        }
+        self.accounts.push(Some(Account {
+            budget,
+            holdings: HashMap::new(),
+            committed: FixedPoint8::ZERO,
+        }));
+        Ok(())
+    }
+
+    /// Whether any strategy has a budget
+    #[inline]
+    pub fn is_partitioned(&self) -> bool {
+        self.accounts.iter().any(Option::is_some)
+    }
+
+    /// Capital allocated over all budgets
+    pub fn allocated(&self) -> FixedPoint8 {
+        self.accounts
+            .iter()
+            .flatten()
+            .fold(FixedPoint8::ZERO, |sum, a| sum.checked_add(a.budget.capital).unwrap_or(FixedPoint8::MAX))
+    }
+
+    /// Budget of strategy `idx` (None if unpartitioned)
+    pub fn budget(&self, idx: usize) -> Option<&StrategyBudget> {
+        self.account(idx).map(|a| &a.budget)
+    }
+
+    /// Open notional of strategy `idx`
+    pub fn committed(&self, idx: usize) -> FixedPoint8 {
+        self.account(idx).map_or(FixedPoint8::ZERO, |a| a.committed)
+    }
+
+    fn account(&self, idx: usize) -> Option<&Account> {
+        self.accounts.get(idx).and_then(Option::as_ref)
+    }
+
+    /// Remember the latest mid price (values market orders)
+    #[inline]
+    pub fn observe_ticker(&mut self, exchange: Exchange, ticker: &TickerData) {
+        if let Some(mid) = ticker.mid_price() {
+            self.mids.insert((exchange, ticker.symbol), mid);
+        }
+    }
+
+    /// Whether strategy `idx` should see this spread update
+    #[inline]
+    pub fn accepts_spread(&self, idx: usize, event: &SpreadEvent) -> bool {
+        self.account(idx).is_none_or(|a| {
+            a.budget.trades(event.symbol) && a.budget.min_spread.is_none_or(|min| event.spread >= min)
+        })
+    }
+
+    /// Check `order` of strategy `idx` against its budget; the valuation price
+    pub fn check(&self, idx: usize, order: &OrderRequest) -> std::result::Result<FixedPoint8, AllocationRejected> {
+        let price = order
+            .price
+            .or_else(|| self.mids.get(&(order.exchange, order.symbol)).copied());
+        let Some(account) = self.account(idx) else {
+            return Ok(price.unwrap_or(FixedPoint8::ZERO));
+        };
+        let budget = &account.budget;
+        if !budget.trades(order.symbol) {
+            return Err(AllocationRejected::SymbolNotAllocated);
+        }
+        let price = price.ok_or(AllocationRejected::NoPrice)?;
+
+        let holding = account.holdings.get(&(order.exchange, order.symbol)).copied().unwrap_or_default();
+        let next = holding.after(order, price);
+        let (before, after) = (holding.notional(), next.notional());
+        if after <= before {
+            // Reduces exposure
+            return Ok(price);
+        }
+        if order.quantity.safe_mul(price).is_none_or(|notional| notional > budget.max_order_notional) {
+            return Err(AllocationRejected::OrderNotional);
+        }
+        if after > budget.max_symbol_notional {
+            return Err(AllocationRejected::SymbolNotional);
+        }
+        let committed = account
+            .committed
+            .checked_sub(before)
+            .and_then(|c| c.checked_add(after))
+            .unwrap_or(FixedPoint8::MAX);
+        if committed > budget.capital {
+            return Err(AllocationRejected::Capital);
+        }
+        Ok(price)
+    }
+
+    /// Book an accepted order of strategy `idx` at `price`
+    pub fn commit(&mut self, idx: usize, order: &OrderRequest, price: FixedPoint8) {
+        let Some(account) = self.accounts.get_mut(idx).and_then(Option::as_mut) else {
+            return;
+        };
+        let key = (order.exchange, order.symbol);
+        let holding = account.holdings.get(&key).copied().unwrap_or_default();
+        let next = holding.after(order, price);
+        account.committed = account
+            .committed
+            .checked_sub(holding.notional())
+            .and_then(|c| c.checked_add(next.notional()))
+            .unwrap_or(FixedPoint8::MAX)
+            .max(FixedPoint8::ZERO);
+        if next.net.is_zero() {
+            account.holdings.remove(&key);
+        } else {
+            account.holdings.insert(key, next);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    fn budget(name: &str, capital: f64) -> StrategyBudget {
+        StrategyBudget::from_config(&StrategyInstanceConfig {
+            name: name.to_string(),
+            plugin: "arb".to_string(),
+            capital,
+            max_order_notional: Some(capital / 2.0),
+            symbols: vec!["btcusdt".to_string()],
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    fn order(side: Side, qty: f64, price: Option<f64>) -> OrderRequest {
+        OrderRequest {
+            exchange: Exchange::Binance,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side,
+            quantity: fp(qty),
+            price: price.map(fp),
+        }
+    }
+
+    #[test]
+    fn test_budgets_bounded_by_margin() {
+        let mut allocator = CapitalAllocator::new();
+        allocator.add_unpartitioned();
+        allocator.add(budget("majors", 600.0), fp(1000.0)).unwrap();
+        assert!(matches!(allocator.add(budget("majors", 100.0), fp(1000.0)), Err(HftError::Config(_))));
+        assert!(matches!(allocator.add(budget("alts", 500.0), fp(1000.0)), Err(HftError::Config(_))));
+        allocator.add(budget("alts", 400.0), fp(1000.0)).unwrap();
+        assert_eq!(allocator.allocated(), fp(1000.0));
+        assert_eq!(allocator.budget(2).map(|b| b.name.as_str()), Some("alts"));
+        assert!(allocator.budget(0).is_none());
+
+        let invalid = StrategyInstanceConfig {
+            name: "zero".to_string(),
+            ..Default::default()
+        };
+        assert!(StrategyBudget::from_config(&invalid).is_err());
+    }
+
+    #[test]
+    fn test_orders_limited_by_budget() {
+        init_test_registry();
+        let mut allocator = CapitalAllocator::new();
+        allocator.add_unpartitioned();
+        allocator.add(budget("majors", 1000.0), fp(1000.0)).unwrap();
+
+        // Unpartitioned strategies are unrestricted
+        assert!(allocator.check(0, &order(Side::Buy, 100.0, Some(100.0))).is_ok());
+
+        let eth = OrderRequest {
+            symbol: Symbol::from_bytes(b"ETHUSDT").unwrap(),
+            ..order(Side::Buy, 1.0, Some(100.0))
+        };
+        assert_eq!(allocator.check(1, &eth), Err(AllocationRejected::SymbolNotAllocated));
+        assert_eq!(allocator.check(1, &order(Side::Buy, 1.0, None)), Err(AllocationRejected::NoPrice));
+        assert_eq!(allocator.check(1, &order(Side::Buy, 6.0, Some(100.0))), Err(AllocationRejected::OrderNotional));
+
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        allocator.observe_ticker(Exchange::Binance, &TickerData::new(btc, fp(99.0), fp(1.0), fp(101.0), fp(1.0), 0));
+        for _ in 0..2 {
+            let buy = order(Side::Buy, 5.0, None);
+            let price = allocator.check(1, &buy).unwrap();
+            assert_eq!(price, fp(100.0));
+            allocator.commit(1, &buy, price);
+        }
+        assert_eq!(allocator.committed(1), fp(1000.0));
+        assert_eq!(allocator.check(1, &order(Side::Buy, 0.5, None)), Err(AllocationRejected::SymbolNotional));
+        let bybit = OrderRequest {
+            exchange: Exchange::Bybit,
+            ..order(Side::Buy, 0.5, Some(100.0))
+        };
+        assert_eq!(allocator.check(1, &bybit), Err(AllocationRejected::Capital));
+
+        // Reducing frees capital; reducing orders always pass
+        let sell = order(Side::Sell, 4.0, Some(110.0));
+        assert_eq!(allocator.check(1, &sell), Ok(fp(110.0)));
+        allocator.commit(1, &sell, fp(110.0));
+        assert_eq!(allocator.committed(1), fp(600.0));
+        assert!(allocator.check(1, &order(Side::Buy, 4.0, None)).is_ok());
+    }
+
+    #[test]
+    fn test_spread_filter() {
+        init_test_registry();
+        let mut allocator = CapitalAllocator::new();
+        let mut majors = budget("majors", 100.0);
+        majors.min_spread = Some(Bps::from_bps(10));
+        allocator.add(majors, fp(100.0)).unwrap();
+
+        let event = |symbol: &[u8], bps| SpreadEvent {
+            symbol: Symbol::from_bytes(symbol).unwrap(),
+            spread: Bps::from_bps(bps),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            timestamp: 0,
+            spread_ticks: None,
+        };
+        assert!(allocator.accepts_spread(0, &event(b"BTCUSDT", 12)));
+        assert!(!allocator.accepts_spread(0, &event(b"BTCUSDT", 5)));
+        assert!(!allocator.accepts_spread(0, &event(b"ETHUSDT", 12)));
+        assert!(allocator.accepts_spread(1, &event(b"ETHUSDT", 0)));
+    }
+}
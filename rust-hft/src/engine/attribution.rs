@@ -0,0 +1,221 @@
+//! PnL attribution of fills to strategies, symbols and episodes
+//!
+//! Each submitted order remembers who sent it (a strategy instance, the
+//! operator, or the engine flattening positions) and the opportunity episode
+//! open on its symbol at the time. Fills on the same venue, symbol and side
+//! consume those tags oldest first, partial fills included, as in
+//! [`super::fill_quality`]. Every fill adds its USDT notional, the PnL it
+//! realized in the position book and an estimated taker fee to the buckets
+//! of its origin, symbol and episode; net PnL is realized minus fees.
+//!
+//! PnL is realized by the fill that closes a position, so a close sent by
+//! one strategy against a position another opened is credited to the
+//! closer. Fills without a tag (orders placed outside this process) count
+//! as unattributed.
+
+use super::strategy::{Fill, OrderRequest};
+use crate::core::{FixedPoint8, QuoteCurrency, QuoteRates, Side, Symbol};
+use crate::exchanges::Exchange;
+use crate::hot_path::CostModel;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+use std::sync::Arc;
+
+/// Tags without a fill for this long are dropped
+const PENDING_TTL_MS: u64 = 60_000;
+
+/// Most recent episodes kept in the report
+const RECENT_EPISODES: usize = 100;
+
+/// Who sent an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum OrderOrigin {
+    /// Strategy instance, by registration index
+    Strategy(usize),
+    /// Operator order from the API
+    Manual,
+    /// Engine close (operator or delisting flatten)
+    Flatten,
+}
+
+/// Attribution of one fill
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct FillTag {
+    /// None = no matching submitted order
+    pub origin: Option<OrderOrigin>,
+    /// Opportunity episode open when the order was decided
+    pub episode: Option<u64>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    timestamp_ms: u64,
+    tag: FillTag,
+    /// Quantity not yet filled
+    remaining: FixedPoint8,
+}
+
+/// Fills, notional and PnL of one bucket (USDT)
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct PnlStats {
+    pub fills: u64,
+    pub notional: FixedPoint8,
+    pub realized: FixedPoint8,
+    /// Estimated at the configured taker rate
+    pub fees: FixedPoint8,
+}
+
+impl PnlStats {
+    fn add(&mut self, notional: FixedPoint8, realized: FixedPoint8, fee: FixedPoint8) {
+        self.fills += 1;
+        self.notional = self.notional.checked_add(notional).unwrap_or(self.notional);
+        self.realized = self.realized.checked_add(realized).unwrap_or(self.realized);
+        self.fees = self.fees.checked_add(fee).unwrap_or(self.fees);
+    }
+
+    /// Realized PnL after fees
+    pub fn net(&self) -> FixedPoint8 {
+        self.realized.checked_sub(self.fees).unwrap_or(self.realized)
+    }
+}
+
+/// Pending order tags and PnL buckets
+#[derive(Debug, Default)]
+pub struct PnlAttribution {
+    pending: HashMap<(Exchange, Symbol, Side), VecDeque<Pending>>,
+    /// None = unattributed
+    by_origin: BTreeMap<Option<OrderOrigin>, PnlStats>,
+    by_symbol: HashMap<Symbol, PnlStats>,
+    /// Most recent episodes, by id (bounded by RECENT_EPISODES)
+    by_episode: BTreeMap<u64, (Symbol, PnlStats)>,
+    fees: CostModel,
+    rates: Arc<QuoteRates>,
+}
+
+impl PnlAttribution {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Estimate fees with the taker rates of `costs`
+    pub fn set_fee_model(&mut self, costs: CostModel) {
+        self.fees = costs;
+    }
+
+    /// Convert notional and fees with shared rates
+    pub fn set_rates(&mut self, rates: Arc<QuoteRates>) {
+        self.rates = rates;
+    }
+
+    /// Remember who sent a submitted order
+    pub fn on_submit(&mut self, order: &OrderRequest, origin: OrderOrigin, episode: Option<u64>, now_ms: u64) {
+        let queue = self.pending.entry((order.exchange, order.symbol, order.side)).or_default();
+        while queue.front().is_some_and(|p| p.timestamp_ms + PENDING_TTL_MS < now_ms) {
+            queue.pop_front();
+        }
+        queue.push_back(Pending {
+            timestamp_ms: now_ms,
+            tag: FillTag { origin: Some(origin), episode },
+            remaining: order.quantity,
+        });
+    }
+
+    /// Attribute `fill`, which realized `realized` USDT in the position book
+    pub fn on_fill(&mut self, fill: &Fill, realized: FixedPoint8) -> FillTag {
+        let tag = self.take_tag(fill);
+        let quote = QuoteCurrency::of(fill.symbol);
+        let notional = fill.price.safe_mul(fill.quantity).map_or(FixedPoint8::ZERO, |n| self.rates.to_usdt(n, quote));
+        let fee = notional.safe_mul(self.fees.taker_fee(fill.exchange)).unwrap_or(FixedPoint8::ZERO);
+
+        self.by_origin.entry(tag.origin).or_default().add(notional, realized, fee);
+        self.by_symbol.entry(fill.symbol).or_default().add(notional, realized, fee);
+        if let Some(id) = tag.episode {
+            self.by_episode.entry(id).or_insert((fill.symbol, PnlStats::default())).1.add(notional, realized, fee);
+            while self.by_episode.len() > RECENT_EPISODES {
+                self.by_episode.pop_first();
+            }
+        }
+        tag
+    }
+
+    fn take_tag(&mut self, fill: &Fill) -> FillTag {
+        let Some(queue) = self.pending.get_mut(&(fill.exchange, fill.symbol, fill.side)) else {
+            return FillTag::default();
+        };
+        let Some(front) = queue.front_mut() else {
+            return FillTag::default();
+        };
+        let tag = front.tag;
+        match front.remaining.checked_sub(fill.quantity) {
+            Some(left) if left.is_positive() => front.remaining = left,
+            _ => {
+                queue.pop_front();
+            }
+        }
+        tag
+    }
+
+    /// Buckets per origin (None = unattributed), in origin order
+    pub fn origins(&self) -> impl Iterator<Item = (Option<OrderOrigin>, &PnlStats)> {
+        self.by_origin.iter().map(|(&origin, stats)| (origin, stats))
+    }
+
+    /// Buckets per symbol, unordered
+    pub fn symbols(&self) -> impl Iterator<Item = (Symbol, &PnlStats)> {
+        self.by_symbol.iter().map(|(&symbol, stats)| (symbol, stats))
+    }
+
+    /// Buckets of the most recent episodes, oldest first
+    pub fn episodes(&self) -> impl DoubleEndedIterator<Item = (u64, Symbol, &PnlStats)> {
+        self.by_episode.iter().map(|(&id, (symbol, stats))| (id, *symbol, stats))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::CostConfig;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    #[test]
+    fn test_fills_attributed_to_origin_symbol_and_episode() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut attribution = PnlAttribution::new();
+        attribution.set_fee_model(CostModel::new(&CostConfig { taker_fee_binance: 0.001, ..Default::default() }));
+        let order = |side, qty| OrderRequest { exchange: Exchange::Binance, symbol: btc, side, quantity: fp(qty), price: None };
+        let fill = |side, qty, price| Fill { exchange: Exchange::Binance, symbol: btc, side, price: fp(price), quantity: fp(qty), timestamp: 0 };
+
+        // Strategy 0 buys 2 in episode 7, filled in two parts
+        attribution.on_submit(&order(Side::Buy, 2.0), OrderOrigin::Strategy(0), Some(7), 1_000);
+        let tag = attribution.on_fill(&fill(Side::Buy, 1.5, 100.0), FixedPoint8::ZERO);
+        assert_eq!(tag, FillTag { origin: Some(OrderOrigin::Strategy(0)), episode: Some(7) });
+        assert_eq!(attribution.on_fill(&fill(Side::Buy, 0.5, 100.0), FixedPoint8::ZERO), tag);
+        // Operator closes at a profit; an untracked fill is unattributed
+        attribution.on_submit(&order(Side::Sell, 2.0), OrderOrigin::Manual, None, 2_000);
+        assert_eq!(attribution.on_fill(&fill(Side::Sell, 2.0, 110.0), fp(20.0)).origin, Some(OrderOrigin::Manual));
+        assert_eq!(attribution.on_fill(&fill(Side::Sell, 1.0, 110.0), FixedPoint8::ZERO), FillTag::default());
+
+        let origins: Vec<_> = attribution.origins().map(|(o, s)| (o, s.fills, s.net())).collect();
+        assert_eq!(
+            origins,
+            vec![
+                (None, 1, fp(-0.11)),
+                (Some(OrderOrigin::Strategy(0)), 2, fp(-0.2)),
+                (Some(OrderOrigin::Manual), 1, fp(19.78)),
+            ]
+        );
+        let (_, btc_stats) = attribution.symbols().next().unwrap();
+        assert_eq!((btc_stats.fills, btc_stats.notional, btc_stats.realized), (4, fp(530.0), fp(20.0)));
+        let episodes: Vec<_> = attribution.episodes().map(|(id, symbol, s)| (id, symbol, s.fills, s.fees)).collect();
+        assert_eq!(episodes, vec![(7, btc, 2, fp(0.2))]);
+
+        // Stale tags expire
+        attribution.on_submit(&order(Side::Buy, 1.0), OrderOrigin::Strategy(1), None, 3_000);
+        attribution.on_submit(&order(Side::Buy, 1.0), OrderOrigin::Strategy(2), None, 3_000 + PENDING_TTL_MS + 1);
+        assert_eq!(attribution.on_fill(&fill(Side::Buy, 1.0, 100.0), FixedPoint8::ZERO).origin, Some(OrderOrigin::Strategy(2)));
+    }
+}
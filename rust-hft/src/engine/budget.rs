@@ -0,0 +1,208 @@
+//! Per-opportunity execution budget
+//!
+//! An execution attempt opens when the first leg of an opportunity is sent
+//! and must have one leg acknowledged on each venue within the budget
+//! (default 150 ms from the decision). Attempts that miss it are aborted:
+//! the engine cancels the legs still unacknowledged and marks the open
+//! episode aborted. Every finished attempt carries its timing breakdown
+//! (send and ack per leg, relative to the decision) for the journal.
+
+use crate::core::{Side, Symbol};
+use crate::exchanges::Exchange;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Timing of one leg, relative to the decision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LegTiming {
+    pub side: Side,
+    /// Decision to send
+    pub sent: Duration,
+    /// Decision to acknowledgement (None = not acknowledged)
+    pub acked: Option<Duration>,
+}
+
+/// How an attempt ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum AttemptOutcome {
+    /// Both legs acknowledged within the budget
+    Completed,
+    /// Budget ran out first; unacknowledged legs are cancelled
+    Aborted,
+}
+
+/// Finished execution attempt with its timing breakdown
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ExecutionAttempt {
+    pub symbol: Symbol,
+    /// Decision time (nanoseconds since epoch)
+    pub timestamp: u64,
+    /// Legs indexed by Exchange::index (None = never sent)
+    pub legs: [Option<LegTiming>; Exchange::COUNT],
+    pub outcome: AttemptOutcome,
+    /// Decision to the last ack (completed) or to the abort
+    pub elapsed: Duration,
+}
+
+impl ExecutionAttempt {
+    /// Venues with a leg sent but not acknowledged
+    pub fn unacked(&self) -> impl Iterator<Item = Exchange> + '_ {
+        Exchange::ALL
+            .into_iter()
+            .filter(|e| self.legs[e.index()].is_some_and(|leg| leg.acked.is_none()))
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Pending {
+    decided: Instant,
+    timestamp: u64,
+    legs: [Option<LegTiming>; Exchange::COUNT],
+}
+
+/// Open attempts (one per symbol) and their deadlines
+#[derive(Debug)]
+pub struct ExecutionBudget {
+    budget: Duration,
+    pending: HashMap<Symbol, Pending>,
+    finished: Vec<ExecutionAttempt>,
+}
+
+impl ExecutionBudget {
+    /// Attempts must have both legs acknowledged within `budget`
+    pub fn new(budget: Duration) -> Self {
+        Self {
+            budget,
+            pending: HashMap::new(),
+            finished: Vec::new(),
+        }
+    }
+
+    /// Record a leg sent at `now` for a decision taken at `decided`
+    ///
+    /// Opens an attempt for the symbol unless one is pending; `timestamp`
+    /// is the decision time since epoch (ns).
+    pub fn on_sent(&mut self, symbol: Symbol, exchange: Exchange, side: Side, decided: Instant, timestamp: u64, now: Instant) {
+        let pending = self.pending.entry(symbol).or_insert(Pending {
+            decided,
+            timestamp,
+            legs: [None; Exchange::COUNT],
+        });
+        pending.legs[exchange.index()] = Some(LegTiming {
+            side,
+            sent: now.saturating_duration_since(pending.decided),
+            acked: None,
+        });
+    }
+
+    /// Record the venue's acknowledgement of a pending leg at `now`
+    ///
+    /// The attempt completes once a leg on each venue is acknowledged.
+    /// Returns the leg's round trip (sent to acked) on its first ack.
+    pub fn on_ack(&mut self, symbol: Symbol, exchange: Exchange, now: Instant) -> Option<Duration> {
+        let pending = self.pending.get_mut(&symbol)?;
+        let leg = pending.legs[exchange.index()].as_mut()?;
+        let elapsed = now.saturating_duration_since(pending.decided);
+        let round_trip = leg.acked.is_none().then(|| elapsed.saturating_sub(leg.sent));
+        leg.acked.get_or_insert(elapsed);
+        if pending.legs.iter().all(|leg| leg.is_some_and(|l| l.acked.is_some())) {
+            let pending = self.pending.remove(&symbol).expect("pending attempt");
+            self.finished.push(ExecutionAttempt {
+                symbol,
+                timestamp: pending.timestamp,
+                legs: pending.legs,
+                outcome: AttemptOutcome::Completed,
+                elapsed,
+            });
+        }
+        round_trip
+    }
+
+    /// Earliest deadline of a pending attempt
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.pending.values().map(|p| p.decided + self.budget).min()
+    }
+
+    /// Abort attempts past their deadline at `now` and take every finished attempt
+    pub fn take_finished(&mut self, now: Instant) -> Vec<ExecutionAttempt> {
+        let budget = self.budget;
+        let finished = &mut self.finished;
+        self.pending.retain(|&symbol, pending| {
+            if now.saturating_duration_since(pending.decided) < budget {
+                return true;
+            }
+            finished.push(ExecutionAttempt {
+                symbol,
+                timestamp: pending.timestamp,
+                legs: pending.legs,
+                outcome: AttemptOutcome::Aborted,
+                elapsed: now.saturating_duration_since(pending.decided),
+            });
+            false
+        });
+        std::mem::take(finished)
+    }
+
+    /// Attempts still waiting for acknowledgements
+    pub fn pending_count(&self) -> usize {
+        self.pending.len()
+    }
+
+    /// Legs sent but not yet acknowledged
+    pub fn unacked_legs(&self) -> Vec<(Exchange, Symbol)> {
+        let mut legs: Vec<(Exchange, Symbol)> = self
+            .pending
+            .iter()
+            .flat_map(|(&symbol, pending)| {
+                Exchange::ALL
+                    .into_iter()
+                    .filter(|e| pending.legs[e.index()].is_some_and(|leg| leg.acked.is_none()))
+                    .map(move |e| (e, symbol))
+            })
+            .collect();
+        legs.sort_unstable_by_key(|&(e, symbol)| (symbol, e.index()));
+        legs
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_execution_budget() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let ms = Duration::from_millis;
+        let t0 = Instant::now();
+        let mut budget = ExecutionBudget::new(ms(150));
+
+        // BTC: both legs acked in time
+        budget.on_sent(btc, Exchange::Binance, Side::Buy, t0, 7, t0 + ms(1));
+        budget.on_sent(btc, Exchange::Bybit, Side::Sell, t0, 7, t0 + ms(2));
+        // ETH: the Bybit leg is never acknowledged
+        budget.on_sent(eth, Exchange::Binance, Side::Sell, t0 + ms(10), 8, t0 + ms(11));
+        budget.on_sent(eth, Exchange::Bybit, Side::Buy, t0 + ms(10), 8, t0 + ms(12));
+        assert_eq!(budget.next_deadline(), Some(t0 + ms(150)));
+
+        // Round trip: sent to acked
+        assert_eq!(budget.on_ack(btc, Exchange::Bybit, t0 + ms(40)), Some(ms(38)));
+        assert_eq!(budget.on_ack(btc, Exchange::Bybit, t0 + ms(45)), None);
+        budget.on_ack(btc, Exchange::Binance, t0 + ms(55));
+        budget.on_ack(eth, Exchange::Binance, t0 + ms(30));
+        let done = budget.take_finished(t0 + ms(100));
+        assert_eq!(done.len(), 1);
+        assert_eq!((done[0].symbol, done[0].outcome, done[0].elapsed), (btc, AttemptOutcome::Completed, ms(55)));
+        let bybit = done[0].legs[Exchange::Bybit.index()].unwrap();
+        assert_eq!((bybit.side, bybit.sent, bybit.acked), (Side::Sell, ms(2), Some(ms(40))));
+
+        assert!(budget.take_finished(t0 + ms(159)).is_empty());
+        let aborted = budget.take_finished(t0 + ms(160));
+        assert_eq!((aborted[0].symbol, aborted[0].outcome, aborted[0].timestamp), (eth, AttemptOutcome::Aborted, 8));
+        assert_eq!(aborted[0].unacked().collect::<Vec<_>>(), vec![Exchange::Bybit]);
+        assert_eq!(budget.pending_count(), 0);
+        assert_eq!(budget.next_deadline(), None);
+    }
+}
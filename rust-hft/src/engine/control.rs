@@ -0,0 +1,194 @@
+//! Operator pause/resume control
+//!
+//! Pausing stops the execution path (every strategy order is suppressed)
+//! and optionally freezes spread tracking, while WebSocket connections stay
+//! up so resuming needs no resubscription. Useful around news events.
+//! Shared between the engine and the API (`/api/pause`, `/api/resume`).
+//!
+//! A shutdown request (`/api/shutdown`) ends the engine loop so the run
+//! summary is written before the process exits.
+//!
+//! Execution eligibility is also switched per symbol: seeded from
+//! `[execution]` `enabled_symbols` / `disabled_symbols` and toggled via
+//! `/api/symbols/{symbol}/execution`. Ineligible symbols are still screened.
+//!
+//! Manual orders (`/api/order`) reach the engine loop through the
+//! [`ManualOrderDesk`] held here.
+
+use super::manual::ManualOrderDesk;
+use crate::infrastructure::config::ExecutionConfig;
+use parking_lot::RwLock;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::Notify;
+
+/// Pause state for monitoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct PauseStatus {
+    /// Execution disabled for every strategy
+    pub paused: bool,
+    /// Ticker updates no longer reach the tracker
+    pub tracker_paused: bool,
+    /// Time since the pause started (None = running)
+    pub paused_for: Option<Duration>,
+}
+
+#[derive(Debug, Default)]
+struct PauseState {
+    tracker_paused: bool,
+    /// None = running
+    since: Option<Instant>,
+}
+
+/// Per-symbol execution switches (uppercase names)
+#[derive(Debug)]
+struct Eligibility {
+    /// Symbols without an override
+    default: bool,
+    overrides: HashMap<String, bool>,
+}
+
+impl Default for Eligibility {
+    fn default() -> Self {
+        Self {
+            default: true,
+            overrides: HashMap::new(),
+        }
+    }
+}
+
+/// Shared pause switch (interior locking, cheap reads)
+#[derive(Debug, Default)]
+pub struct EngineControl {
+    inner: RwLock<PauseState>,
+    eligibility: RwLock<Eligibility>,
+    shutdown: Notify,
+    orders: ManualOrderDesk,
+}
+
+impl EngineControl {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Pause execution (and the tracker if `tracker`); re-pausing keeps the start time
+    pub fn pause(&self, tracker: bool) {
+        let mut inner = self.inner.write();
+        inner.tracker_paused = tracker;
+        inner.since.get_or_insert_with(Instant::now);
+    }
+
+    /// Resume execution and tracking
+    pub fn resume(&self) {
+        *self.inner.write() = PauseState::default();
+    }
+
+    /// Execution paused
+    #[inline]
+    pub fn is_paused(&self) -> bool {
+        self.inner.read().since.is_some()
+    }
+
+    /// Tracker paused (implies execution paused)
+    #[inline]
+    pub fn is_tracker_paused(&self) -> bool {
+        self.inner.read().tracker_paused
+    }
+
+    /// Replace execution eligibility with the configured symbol lists
+    pub fn configure_symbols(&self, config: &ExecutionConfig) {
+        let enabled = config.enabled_symbols.iter().map(|name| (name.to_ascii_uppercase(), true));
+        let disabled = config.disabled_symbols.iter().map(|name| (name.to_ascii_uppercase(), false));
+        *self.eligibility.write() = Eligibility {
+            default: config.enabled_symbols.is_empty(),
+            overrides: enabled.chain(disabled).collect(),
+        };
+    }
+
+    /// Allow or forbid execution on `symbol` from now on
+    pub fn set_symbol_enabled(&self, symbol: &str, enabled: bool) {
+        let mut eligibility = self.eligibility.write();
+        let symbol = symbol.to_ascii_uppercase();
+        if enabled == eligibility.default {
+            eligibility.overrides.remove(&symbol);
+        } else {
+            eligibility.overrides.insert(symbol, enabled);
+        }
+    }
+
+    /// Execution allowed on `symbol` (registered, i.e. uppercase, name)
+    #[inline]
+    pub fn is_symbol_enabled(&self, symbol: &str) -> bool {
+        let eligibility = self.eligibility.read();
+        eligibility.overrides.get(symbol).copied().unwrap_or(eligibility.default)
+    }
+
+    /// Ask the engine loop to stop (kept until the engine waits for it)
+    pub fn request_shutdown(&self) {
+        self.shutdown.notify_one();
+    }
+
+    /// Completes once shutdown is requested
+    pub async fn shutdown_requested(&self) {
+        self.shutdown.notified().await;
+    }
+
+    /// Manual order channel to the engine loop
+    pub fn orders(&self) -> &ManualOrderDesk {
+        &self.orders
+    }
+
+    /// Current state
+    pub fn status(&self) -> PauseStatus {
+        let inner = self.inner.read();
+        PauseStatus {
+            paused: inner.since.is_some(),
+            tracker_paused: inner.tracker_paused,
+            paused_for: inner.since.map(|since| since.elapsed()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_pause_resume() {
+        let control = EngineControl::new();
+        assert_eq!(control.status(), PauseStatus::default());
+
+        control.pause(false);
+        assert!(control.is_paused() && !control.is_tracker_paused());
+        assert!(control.status().paused_for.is_some());
+
+        // Re-pausing can extend the pause to the tracker
+        control.pause(true);
+        assert!(control.is_paused() && control.is_tracker_paused());
+
+        control.resume();
+        assert_eq!(control.status(), PauseStatus::default());
+    }
+
+    #[test]
+    fn test_symbol_eligibility() {
+        let control = EngineControl::new();
+        assert!(control.is_symbol_enabled("BTCUSDT"));
+
+        // Allowlist mode: only vetted symbols execute, disabled wins
+        control.configure_symbols(&ExecutionConfig {
+            enabled_symbols: vec!["btcusdt".to_string(), "ETHUSDT".to_string()],
+            disabled_symbols: vec!["ETHUSDT".to_string()],
+            ..ExecutionConfig::default()
+        });
+        assert!(control.is_symbol_enabled("BTCUSDT"));
+        assert!(!control.is_symbol_enabled("ETHUSDT"));
+        assert!(!control.is_symbol_enabled("SOLUSDT"));
+
+        // Runtime toggles
+        control.set_symbol_enabled("solusdt", true);
+        control.set_symbol_enabled("BTCUSDT", false);
+        assert!(control.is_symbol_enabled("SOLUSDT"));
+        assert!(!control.is_symbol_enabled("BTCUSDT"));
+    }
+}
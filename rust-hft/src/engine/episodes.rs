@@ -0,0 +1,184 @@
+//! Opportunity episodes
+//!
+//! An episode is a contiguous run of spread updates above the opportunity
+//! threshold in one direction. It completes when the spread falls back below
+//! the threshold or flips direction. Completed episodes and fills are sent
+//! to the history sink (see `infrastructure::history`).
+
+use super::strategy::Fill;
+use crate::core::{Bps, Symbol};
+use crate::exchanges::Exchange;
+use crate::hot_path::SpreadEvent;
+use std::collections::HashMap;
+
+/// Completed (or in-progress) opportunity episode
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Episode {
+    /// Sequence number, continued across runs from the history store
+    /// (fills are tagged with it)
+    pub id: u64,
+    pub symbol: Symbol,
+    pub long_ex: Exchange,
+    pub short_ex: Exchange,
+    /// First update above threshold (nanoseconds since epoch)
+    pub start: u64,
+    /// Last update above threshold, or the update that closed it
+    pub end: u64,
+    pub peak_spread: Bps,
+    /// Updates above threshold
+    pub updates: u64,
+    /// An execution attempt during the episode ran out of budget
+    pub aborted: bool,
+}
+
+/// Records sent to the history sink
+#[derive(Debug, Clone)]
+pub enum HistoryEvent {
+    Episode(Episode),
+    /// Fill with its attribution (see `attribution`)
+    Execution { fill: Fill, strategy: Option<String>, episode: Option<u64> },
+}
+
+/// Tracks open episodes per symbol
+#[derive(Debug, Default)]
+pub struct EpisodeTracker {
+    open: HashMap<Symbol, Episode>,
+    /// Completed episodes per symbol since startup
+    completed: HashMap<Symbol, u64>,
+    /// Id of the last episode opened
+    last_id: u64,
+}
+
+impl EpisodeTracker {
+    /// Create tracker with no open episodes
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Number new episodes after `last_id` (highest id already stored)
+    pub fn resume_after(&mut self, last_id: u64) {
+        self.last_id = self.last_id.max(last_id);
+    }
+
+    /// Feed spread update; returns the episode it completed, if any
+    pub fn observe(&mut self, event: &SpreadEvent, threshold: Bps) -> Option<Episode> {
+        let done = self.transition(event, threshold);
+        if let Some(episode) = &done {
+            *self.completed.entry(episode.symbol).or_default() += 1;
+        }
+        done
+    }
+
+    fn transition(&mut self, event: &SpreadEvent, threshold: Bps) -> Option<Episode> {
+        if event.spread <= threshold {
+            return self.open.remove(&event.symbol).map(|mut episode| {
+                episode.end = event.timestamp;
+                episode
+            });
+        }
+
+        let fresh = Episode {
+            id: self.last_id + 1,
+            symbol: event.symbol,
+            long_ex: event.long_ex,
+            short_ex: event.short_ex,
+            start: event.timestamp,
+            end: event.timestamp,
+            peak_spread: event.spread,
+            updates: 1,
+            aborted: false,
+        };
+        match self.open.get_mut(&event.symbol) {
+            Some(episode) if episode.long_ex == event.long_ex => {
+                episode.end = event.timestamp;
+                episode.peak_spread = episode.peak_spread.max(event.spread);
+                episode.updates += 1;
+                None
+            }
+            // Direction flipped: previous episode completes, a new one starts
+            Some(episode) => {
+                self.last_id = fresh.id;
+                Some(std::mem::replace(episode, fresh))
+            }
+            None => {
+                self.last_id = fresh.id;
+                self.open.insert(event.symbol, fresh);
+                None
+            }
+        }
+    }
+
+    /// Mark the open episode of `symbol` aborted; false if none is open
+    pub fn abort(&mut self, symbol: Symbol) -> bool {
+        self.open.get_mut(&symbol).map(|episode| episode.aborted = true).is_some()
+    }
+
+    /// Open episode of `symbol`
+    pub fn open_episode(&self, symbol: Symbol) -> Option<&Episode> {
+        self.open.get(&symbol)
+    }
+
+    /// Id of the open episode of `symbol`
+    pub fn open_id(&self, symbol: Symbol) -> Option<u64> {
+        self.open.get(&symbol).map(|episode| episode.id)
+    }
+
+    /// Number of open episodes
+    pub fn open_count(&self) -> usize {
+        self.open.len()
+    }
+
+    /// Completed episodes per symbol since startup
+    pub fn completed_counts(&self) -> Vec<(Symbol, u64)> {
+        self.completed.iter().map(|(&symbol, &count)| (symbol, count)).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_episode_lifecycle() {
+        init_test_registry();
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let threshold = Bps::from_bps(5);
+        let event = |spread: i64, long_ex: Exchange, timestamp: u64| SpreadEvent {
+            symbol,
+            spread: Bps::from_raw(spread),
+            long_ex,
+            short_ex: if long_ex == Exchange::Binance { Exchange::Bybit } else { Exchange::Binance },
+            timestamp,
+            spread_ticks: None,
+        };
+        let mut tracker = EpisodeTracker::new();
+
+        assert_eq!(tracker.observe(&event(10_000, Exchange::Binance, 1), threshold), None);
+        assert_eq!(tracker.observe(&event(60_000, Exchange::Binance, 2), threshold), None);
+        assert_eq!(tracker.observe(&event(90_000, Exchange::Binance, 3), threshold), None);
+        assert_eq!(tracker.open_count(), 1);
+
+        let done = tracker.observe(&event(20_000, Exchange::Binance, 4), threshold).unwrap();
+        assert_eq!((done.id, done.start, done.end, done.updates, done.aborted), (1, 2, 4, 2, false));
+        assert_eq!(done.peak_spread.as_raw(), 90_000);
+        assert_eq!(tracker.open_count(), 0);
+
+        // Direction flip closes the episode and opens a new one
+        assert!(!tracker.abort(symbol));
+        tracker.observe(&event(60_000, Exchange::Binance, 5), threshold);
+        assert!(tracker.abort(symbol));
+        assert_eq!(tracker.open_id(symbol), Some(2));
+        let flipped = tracker.observe(&event(70_000, Exchange::Bybit, 6), threshold).unwrap();
+        assert_eq!((flipped.long_ex, flipped.start, flipped.end), (Exchange::Binance, 5, 5));
+        assert!(flipped.aborted);
+        assert_eq!(tracker.open_id(symbol), Some(3));
+        assert_eq!(tracker.open_count(), 1);
+        assert_eq!(tracker.completed_counts(), vec![(symbol, 2)]);
+
+        // Ids continue after those already stored
+        tracker.resume_after(41);
+        tracker.observe(&event(70_000, Exchange::Binance, 7), threshold).unwrap();
+        assert_eq!(tracker.open_id(symbol), Some(42));
+    }
+}
@@ -0,0 +1,165 @@
+//! Execution engine
+//!
+//! Receives order requests from strategies and decides whether they may be
+//! sent. Per-symbol cooldowns and trade rate limits are enforced here, per
+//! venue, so every strategy is subject to them and both legs of an
+//! opportunity pass together. No venue order gateway is wired yet:
+//! accepted orders are logged (paper) and count as attempts, and a simulated
+//! gateway acknowledges each one `paper_ack_ms` after it is sent (see
+//! [`ExecutionEngine::take_acks`]), so execution budgets and round-trip
+//! latency are measured as they would be against a venue.
+//! Construction requires a [`TradingPermit`], so none exists in read-only mode.
+
+use super::strategy::OrderRequest;
+use super::throttle::{Suppressed, Throttle};
+use crate::core::Symbol;
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::{ExecutionConfig, TradingPermit};
+use crate::infrastructure::metrics::MetricsCollector;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Result of submitting an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionOutcome {
+    /// Order accepted and sent
+    Submitted,
+    /// Order suppressed by throttling
+    Suppressed(Suppressed),
+}
+
+/// Throttled order execution
+pub struct ExecutionEngine {
+    throttle: Throttle,
+    metrics: Arc<MetricsCollector>,
+    /// Simulated gateway: acknowledgements due, oldest first
+    acks: VecDeque<(Instant, Exchange, Symbol)>,
+    ack_delay: Duration,
+    _permit: TradingPermit,
+}
+
+impl ExecutionEngine {
+    /// Create execution engine from config
+    pub fn new(config: &ExecutionConfig, metrics: Arc<MetricsCollector>, permit: TradingPermit) -> Self {
+        Self {
+            throttle: Throttle::new(config),
+            metrics,
+            acks: VecDeque::new(),
+            ack_delay: Duration::from_millis(config.paper_ack_ms),
+            _permit: permit,
+        }
+    }
+
+    /// Submit order at `now`, subject to symbol cooldown and rate limit on its venue
+    pub fn submit(&mut self, order: &OrderRequest, now: Instant) -> ExecutionOutcome {
+        if let Err(reason) = self.throttle.check(order.symbol, order.exchange, now) {
+            match reason {
+                Suppressed::Cooldown { .. } => self.metrics.record_suppressed_cooldown(),
+                Suppressed::RateLimit => self.metrics.record_suppressed_rate_limit(),
+            }
+            tracing::debug!("Suppressed {} order: {:?}", order.symbol.as_str(), reason);
+            return ExecutionOutcome::Suppressed(reason);
+        }
+
+        tracing::info!(
+            "Execute {:?} {} {:?} qty={} price={:?}",
+            order.exchange,
+            order.symbol.as_str(),
+            order.side,
+            order.quantity,
+            order.price
+        );
+        self.throttle.record(order.symbol, order.exchange, now);
+        self.metrics.record_order_submitted();
+        self.acks.push_back((now + self.ack_delay, order.exchange, order.symbol));
+        ExecutionOutcome::Submitted
+    }
+
+    /// Submit a risk-reducing order (flatten), bypassing throttling
+    ///
+    /// The attempt is still recorded so regular orders cool down after it.
+    pub fn submit_flatten(&mut self, order: &OrderRequest, now: Instant) -> ExecutionOutcome {
+        tracing::warn!(
+            "Flatten {:?} {} {:?} qty={}",
+            order.exchange,
+            order.symbol.as_str(),
+            order.side,
+            order.quantity
+        );
+        self.throttle.record(order.symbol, order.exchange, now);
+        self.metrics.record_order_submitted();
+        self.acks.push_back((now + self.ack_delay, order.exchange, order.symbol));
+        ExecutionOutcome::Submitted
+    }
+
+    /// When the next simulated acknowledgement is due
+    pub fn next_ack(&self) -> Option<Instant> {
+        self.acks.front().map(|&(due, ..)| due)
+    }
+
+    /// Take the simulated acknowledgements due at `now`, in send order
+    pub fn take_acks(&mut self, now: Instant) -> Vec<(Exchange, Symbol)> {
+        let due = self.acks.partition_point(|&(due, ..)| due <= now);
+        self.acks.drain(..due).map(|(_, exchange, symbol)| (exchange, symbol)).collect()
+    }
+
+    /// Cancel every open order on `exchange`
+    pub fn cancel_all(&mut self, exchange: Exchange) {
+        tracing::warn!("Cancel all open orders on {:?}", exchange);
+    }
+
+    /// Cancel open orders in `symbol` on `exchange`
+    pub fn cancel(&mut self, exchange: Exchange, symbol: Symbol) {
+        tracing::warn!("Cancel {:?} {} open orders", exchange, symbol.as_str());
+    }
+
+    /// Record a failed execution attempt so the symbol still cools down
+    pub fn record_failure(&mut self, order: &OrderRequest, now: Instant) {
+        self.throttle.record(order.symbol, order.exchange, now);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Side};
+    use crate::infrastructure::config::Config;
+    use crate::test_utils::init_test_registry;
+    use std::time::Duration;
+
+    #[test]
+    fn test_suppressed_signals_counted() {
+        init_test_registry();
+        let metrics = Arc::new(MetricsCollector::new());
+        let config = ExecutionConfig {
+            cooldown_ms: 1_000,
+            max_trades_per_minute: 1,
+            ..Default::default()
+        };
+        let permit = Config::default().trading_permit().unwrap();
+        let mut exec = ExecutionEngine::new(&config, metrics.clone(), permit);
+        let order = OrderRequest {
+            exchange: Exchange::Binance,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side: Side::Buy,
+            quantity: FixedPoint8::ONE,
+            price: None,
+        };
+        let t0 = Instant::now();
+
+        assert_eq!(exec.submit(&order, t0), ExecutionOutcome::Submitted);
+        assert!(matches!(
+            exec.submit(&order, t0 + Duration::from_millis(10)),
+            ExecutionOutcome::Suppressed(Suppressed::Cooldown { .. })
+        ));
+        assert_eq!(
+            exec.submit(&order, t0 + Duration::from_secs(2)),
+            ExecutionOutcome::Suppressed(Suppressed::RateLimit)
+        );
+
+        let snapshot = metrics.snapshot();
+        assert_eq!(snapshot.suppressed_cooldown, 1);
+        assert_eq!(snapshot.suppressed_rate_limit, 1);
+    }
+}
@@ -0,0 +1,273 @@
+//! Hot-standby failover with lease fencing
+//!
+//! Two instances share one account: the active one trades and replicates its
+//! state, the passive one mirrors it and takes over when the active side goes
+//! silent. A silent peer may be dead or merely cut off, so promotion alone
+//! could leave two traders. Fencing closes that gap with a lease: the active
+//! side only trades while it has heard from the standby within `lease`, and
+//! the standby only promotes after `takeover` (> `lease`) of silence, by
+//! which time a cut-off active side has already stopped.
+//!
+//! Every promotion bumps the epoch. When both sides claim to be active (the
+//! old primary reconnecting after a takeover), the higher epoch keeps the
+//! role; on equal epochs (both configured primary) the listening side does.
+//! A standby promotes only once the active side has acknowledged it, so an
+//! active side that never heard from a standby has no lease to honour.
+//! A configured primary still starts fenced: it may be a crashed primary
+//! restarting while its promoted standby trades at a newer epoch. It trades
+//! once it hears the other instance (and keeps the role), or after
+//! `takeover` of silence, by which time a running peer would have dialled in.
+
+use crate::infrastructure::config::{FailoverRole, StandbyConfig};
+use std::time::{Duration, Instant};
+
+/// Role and epoch announced on every replication frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Contact {
+    pub epoch: u64,
+    pub active: bool,
+    /// Sender has heard from the receiver (the standby may promote)
+    pub paired: bool,
+}
+
+/// Failover state change
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FailoverTransition {
+    /// First frame from the other instance since startup or promotion
+    Paired { active: bool },
+    /// Active side silent past the takeover timeout; this instance now trades
+    Promoted { epoch: u64, silent: Duration },
+    /// The other instance holds a newer epoch; this one stops trading
+    Demoted { epoch: u64 },
+    /// Standby silent past the lease; trading fenced
+    LeaseExpired,
+    /// Nothing heard for `takeover` after startup; the primary trades unpaired
+    Unpaired { waited: Duration },
+    /// Standby heard again; trading resumes
+    LeaseRenewed { fenced: Duration },
+}
+
+/// Active/passive role, epoch and lease of one instance
+#[derive(Debug, Clone)]
+pub struct FailoverGuard {
+    role: FailoverRole,
+    epoch: u64,
+    /// Wins ties between two active sides of the same epoch
+    listener: bool,
+    lease: Duration,
+    takeover: Duration,
+    /// Last frame from the other instance (None = not heard since startup or promotion)
+    last_contact: Option<Instant>,
+    /// Standby: the active side has acknowledged us
+    synced: bool,
+    /// Active: lease ran out at this time
+    fenced_since: Option<Instant>,
+    /// Configured primary: fenced at startup until first contact or this time
+    startup_fence: Option<Instant>,
+}
+
+impl FailoverGuard {
+    /// Guard for an instance started at `now`
+    pub fn new(config: &StandbyConfig, now: Instant) -> Self {
+        let takeover = Duration::from_millis(config.takeover_ms);
+        Self {
+            role: config.role,
+            // A configured primary starts ahead of a fresh standby
+            epoch: u64::from(config.role == FailoverRole::Primary),
+            listener: config.listen.is_some(),
+            lease: Duration::from_millis(config.lease_ms),
+            takeover,
+            last_contact: None,
+            synced: false,
+            fenced_since: None,
+            startup_fence: (config.role == FailoverRole::Primary).then_some(now + takeover),
+        }
+    }
+
+    #[inline]
+    pub fn role(&self) -> FailoverRole {
+        self.role
+    }
+
+    #[inline]
+    pub fn epoch(&self) -> u64 {
+        self.epoch
+    }
+
+    #[inline]
+    pub fn is_active(&self) -> bool {
+        self.role == FailoverRole::Primary
+    }
+
+    /// What this instance announces on its frames
+    pub fn contact(&self) -> Contact {
+        Contact {
+            epoch: self.epoch,
+            active: self.is_active(),
+            paired: self.last_contact.is_some(),
+        }
+    }
+
+    /// This instance may trade at `now`: active, past the startup fence and within its lease
+    #[inline]
+    pub fn may_execute(&self, now: Instant) -> bool {
+        self.is_active()
+            && match self.last_contact {
+                Some(at) => now.saturating_duration_since(at) < self.lease,
+                None => self.startup_fence.is_none_or(|until| now >= until),
+            }
+    }
+
+    /// Frame from the other instance
+    pub fn on_contact(&mut self, remote: Contact, now: Instant) -> Option<FailoverTransition> {
+        let first = self.last_contact.is_none();
+        self.last_contact = Some(now);
+        self.startup_fence = None;
+        if remote.active && self.is_active() {
+            let yields = remote.epoch > self.epoch || (remote.epoch == self.epoch && !self.listener);
+            if yields {
+                self.role = FailoverRole::Standby;
+                self.epoch = remote.epoch;
+                self.synced = remote.paired;
+                self.fenced_since = None;
+                return Some(FailoverTransition::Demoted { epoch: remote.epoch });
+            }
+        }
+        if !self.is_active() {
+            self.epoch = self.epoch.max(remote.epoch);
+            self.synced = remote.active && remote.paired;
+        }
+        if let Some(since) = self.fenced_since.take() {
+            return Some(FailoverTransition::LeaseRenewed { fenced: now.saturating_duration_since(since) });
+        }
+        first.then_some(FailoverTransition::Paired { active: remote.active })
+    }
+
+    /// Lease expiry (active) or takeover (standby) due at `now`
+    pub fn check(&mut self, now: Instant) -> Option<FailoverTransition> {
+        if let Some(until) = self.startup_fence.filter(|&until| now >= until) {
+            self.startup_fence = None;
+            return Some(FailoverTransition::Unpaired { waited: self.takeover + now.saturating_duration_since(until) });
+        }
+        let silent = now.saturating_duration_since(self.last_contact?);
+        if self.is_active() {
+            if silent >= self.lease && self.fenced_since.is_none() {
+                self.fenced_since = Some(now);
+                return Some(FailoverTransition::LeaseExpired);
+            }
+            return None;
+        }
+        if !self.synced || silent < self.takeover {
+            return None;
+        }
+        self.role = FailoverRole::Primary;
+        self.epoch += 1;
+        self.last_contact = None;
+        self.synced = false;
+        Some(FailoverTransition::Promoted { epoch: self.epoch, silent })
+    }
+
+    /// When [`Self::check`] next has something to do
+    pub fn next_deadline(&self) -> Option<Instant> {
+        if self.startup_fence.is_some() {
+            return self.startup_fence;
+        }
+        let at = self.last_contact?;
+        match self.role {
+            FailoverRole::Primary if self.fenced_since.is_none() => Some(at + self.lease),
+            FailoverRole::Standby if self.synced => Some(at + self.takeover),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(role: FailoverRole, listen: bool, now: Instant) -> FailoverGuard {
+        FailoverGuard::new(
+            &StandbyConfig {
+                enabled: true,
+                role,
+                listen: listen.then(|| "127.0.0.1:0".to_string()),
+                connect: (!listen).then(|| "127.0.0.1:0".to_string()),
+                lease_ms: 1_000,
+                takeover_ms: 3_000,
+                ..Default::default()
+            },
+            now,
+        )
+    }
+
+    #[test]
+    fn test_takeover_is_fenced_by_lease() {
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+        let mut primary = guard(FailoverRole::Primary, true, t0);
+        let mut standby = guard(FailoverRole::Standby, false, t0);
+        // Unpaired primary is fenced; a standby never acknowledged does not promote
+        assert!(!primary.may_execute(t0) && !standby.may_execute(t0));
+        standby.on_contact(primary.contact(), t0);
+        assert_eq!(standby.check(t0 + ms(10_000)), None);
+
+        assert_eq!(primary.on_contact(standby.contact(), t0), Some(FailoverTransition::Paired { active: false }));
+        assert_eq!(standby.on_contact(primary.contact(), t0), None);
+        assert_eq!(standby.next_deadline(), Some(t0 + ms(3_000)));
+
+        // Partition: the primary fences itself before the standby takes over
+        assert!(primary.may_execute(t0 + ms(999)));
+        assert!(!primary.may_execute(t0 + ms(1_000)));
+        assert_eq!(primary.check(t0 + ms(1_000)), Some(FailoverTransition::LeaseExpired));
+        assert_eq!(primary.check(t0 + ms(1_500)), None);
+        assert_eq!(standby.check(t0 + ms(2_999)), None);
+        assert_eq!(standby.check(t0 + ms(3_000)), Some(FailoverTransition::Promoted { epoch: 2, silent: ms(3_000) }));
+        assert!(standby.may_execute(t0 + ms(3_000)));
+
+        // Link back: the old primary sees the newer epoch and stands down
+        let now = t0 + ms(5_000);
+        assert_eq!(primary.on_contact(standby.contact(), now), Some(FailoverTransition::Demoted { epoch: 2 }));
+        assert!(!primary.may_execute(now) && primary.role() == FailoverRole::Standby);
+        assert_eq!(standby.on_contact(primary.contact(), now), Some(FailoverTransition::Paired { active: false }));
+        assert!(standby.may_execute(now));
+
+        // Two configured primaries: the listener keeps the role
+        let mut listener = guard(FailoverRole::Primary, true, t0);
+        let mut dialer = guard(FailoverRole::Primary, false, t0);
+        assert_eq!(dialer.on_contact(listener.contact(), t0), Some(FailoverTransition::Demoted { epoch: 1 }));
+        assert_eq!(listener.on_contact(dialer.contact(), t0), Some(FailoverTransition::Paired { active: false }));
+    }
+
+    #[test]
+    fn test_primary_restarts_after_standby_promotion() {
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+        let mut primary = guard(FailoverRole::Primary, true, t0);
+        let mut standby = guard(FailoverRole::Standby, false, t0);
+        primary.on_contact(standby.contact(), t0);
+        standby.on_contact(primary.contact(), t0);
+        assert_eq!(standby.check(t0 + ms(3_000)), Some(FailoverTransition::Promoted { epoch: 2, silent: ms(3_000) }));
+
+        // The crashed primary comes back with its configured role and epoch 1
+        let t1 = t0 + ms(10_000);
+        let mut restarted = guard(FailoverRole::Primary, true, t1);
+        assert!(standby.may_execute(t1));
+        assert!(!restarted.may_execute(t1));
+        assert!(!restarted.may_execute(t1 + ms(2_999)));
+        assert_eq!(restarted.next_deadline(), Some(t1 + ms(3_000)));
+
+        // The promoted side dials in before the fence lapses: the restart stands down
+        let now = t1 + ms(1_000);
+        assert_eq!(restarted.on_contact(standby.contact(), now), Some(FailoverTransition::Demoted { epoch: 2 }));
+        assert_eq!(standby.on_contact(restarted.contact(), now), Some(FailoverTransition::Paired { active: false }));
+        assert!(!restarted.may_execute(t1 + ms(5_000)));
+        assert_eq!(restarted.check(t1 + ms(5_000)), None);
+
+        // Nobody dials in: the primary trades unpaired after the takeover timeout
+        let mut lone = guard(FailoverRole::Primary, true, t1);
+        assert_eq!(lone.check(t1 + ms(2_999)), None);
+        assert_eq!(lone.check(t1 + ms(3_000)), Some(FailoverTransition::Unpaired { waited: ms(3_000) }));
+        assert!(lone.may_execute(t1 + ms(3_000)));
+        assert_eq!(lone.next_deadline(), None);
+    }
+}
@@ -0,0 +1,321 @@
+//! Fill quality: slippage of realized fills against the decision price
+//!
+//! Each submitted order remembers the venue's quote at decision time (mid
+//! and the touch it would take: ask for buys, bid for sells) and its limit
+//! price. Fills on the same venue, symbol and side consume those decisions
+//! oldest first, partial fills included. Every fill yields one leg with its
+//! slippage in basis points against the mid, the touch and the submitted
+//! price; positive is adverse (paid more on a buy, received less on a
+//! sell). Legs aggregate per venue and UTC day, weighted by notional.
+//!
+//! Fills without a matching decision (orders placed outside this process,
+//! or decided before a venue had a quote) are not scored.
+
+use super::strategy::{Fill, OrderRequest};
+use crate::core::{FixedPoint8, Side, Symbol, TickerData};
+use crate::exchanges::Exchange;
+use std::collections::{HashMap, VecDeque};
+
+const DAY_MS: u64 = 86_400_000;
+
+/// UTC days of statistics kept
+const RETAIN_DAYS: usize = 7;
+
+/// Latest scored legs kept
+const RECENT_LEGS: usize = 100;
+
+/// Decisions without a fill for this long are dropped
+const PENDING_TTL_MS: u64 = 60_000;
+
+/// Quote and price at the moment an order was submitted
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Decision {
+    timestamp_ms: u64,
+    mid: FixedPoint8,
+    touch: FixedPoint8,
+    /// Limit price (None = market order)
+    submitted: Option<FixedPoint8>,
+    /// Quantity not yet filled
+    remaining: FixedPoint8,
+}
+
+/// Slippage of one fill against its decision
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LegSlippage {
+    /// Fill time (ms since epoch)
+    pub timestamp_ms: u64,
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub decision_mid: FixedPoint8,
+    pub decision_touch: FixedPoint8,
+    pub submitted_price: Option<FixedPoint8>,
+    pub fill_price: FixedPoint8,
+    pub quantity: FixedPoint8,
+    pub vs_mid_bps: f64,
+    pub vs_touch_bps: f64,
+    /// None for market orders
+    pub vs_submitted_bps: Option<f64>,
+}
+
+/// Notional-weighted slippage of one venue over one day
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct SlippageStats {
+    pub fills: u64,
+    pub notional: f64,
+    vs_mid: f64,
+    vs_touch: f64,
+    /// Notional of limit-order fills (the vs-submitted weight)
+    submitted_notional: f64,
+    vs_submitted: f64,
+    /// Most adverse fill against the mid
+    pub worst_vs_mid_bps: f64,
+}
+
+impl SlippageStats {
+    fn add(&mut self, leg: &LegSlippage) {
+        let notional = leg.fill_price.to_f64() * leg.quantity.to_f64();
+        self.worst_vs_mid_bps = if self.fills == 0 { leg.vs_mid_bps } else { self.worst_vs_mid_bps.max(leg.vs_mid_bps) };
+        self.fills += 1;
+        self.notional += notional;
+        self.vs_mid += leg.vs_mid_bps * notional;
+        self.vs_touch += leg.vs_touch_bps * notional;
+        if let Some(bps) = leg.vs_submitted_bps {
+            self.submitted_notional += notional;
+            self.vs_submitted += bps * notional;
+        }
+    }
+
+    pub fn avg_vs_mid_bps(&self) -> f64 {
+        weighted(self.vs_mid, self.notional).unwrap_or(0.0)
+    }
+
+    pub fn avg_vs_touch_bps(&self) -> f64 {
+        weighted(self.vs_touch, self.notional).unwrap_or(0.0)
+    }
+
+    /// None until a limit order filled
+    pub fn avg_vs_submitted_bps(&self) -> Option<f64> {
+        weighted(self.vs_submitted, self.submitted_notional)
+    }
+}
+
+fn weighted(sum: f64, weight: f64) -> Option<f64> {
+    (weight > 0.0).then(|| sum / weight)
+}
+
+/// Statistics of one UTC day, indexed by `Exchange::index`
+#[derive(Debug, Clone, PartialEq)]
+pub struct DailyQuality {
+    /// Day start (ms since epoch)
+    pub day_start_ms: u64,
+    pub venues: [SlippageStats; Exchange::COUNT],
+}
+
+impl DailyQuality {
+    /// UTC date, e.g. "2026-10-16"
+    pub fn date(&self) -> String {
+        time::OffsetDateTime::from_unix_timestamp((self.day_start_ms / 1000) as i64)
+            .map_or_else(|_| format!("day-{}", self.day_start_ms / DAY_MS), |t| t.date().to_string())
+    }
+}
+
+/// Decision quotes, pending decisions and slippage statistics
+#[derive(Debug, Default)]
+pub struct FillQuality {
+    /// Last screened (bid, ask) per venue and symbol
+    quotes: HashMap<(Exchange, Symbol), (FixedPoint8, FixedPoint8)>,
+    pending: HashMap<(Exchange, Symbol, Side), VecDeque<Decision>>,
+    /// Oldest day first
+    days: VecDeque<DailyQuality>,
+    /// Oldest leg first
+    recent: VecDeque<LegSlippage>,
+}
+
+impl FillQuality {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the latest quote (decisions are priced against it)
+    #[inline]
+    pub fn observe_ticker(&mut self, exchange: Exchange, ticker: &TickerData) {
+        self.quotes.insert((exchange, ticker.symbol), (ticker.bid_price, ticker.ask_price));
+    }
+
+    /// Record the decision behind a submitted order (false without a quote)
+    pub fn on_submit(&mut self, order: &OrderRequest, now_ms: u64) -> bool {
+        let Some(&(bid, ask)) = self.quotes.get(&(order.exchange, order.symbol)) else {
+            return false;
+        };
+        let Some(mid) = bid.checked_add(ask).map(|sum| FixedPoint8::from_raw(sum.as_raw() / 2)) else {
+            return false;
+        };
+        let touch = if order.side.is_buy() { ask } else { bid };
+        let queue = self.pending.entry((order.exchange, order.symbol, order.side)).or_default();
+        while queue.front().is_some_and(|d| d.timestamp_ms + PENDING_TTL_MS < now_ms) {
+            queue.pop_front();
+        }
+        queue.push_back(Decision {
+            timestamp_ms: now_ms,
+            mid,
+            touch,
+            submitted: order.price,
+            remaining: order.quantity,
+        });
+        true
+    }
+
+    /// Score `fill` against the oldest open decision on its venue, symbol and side
+    pub fn on_fill(&mut self, fill: &Fill) -> Option<LegSlippage> {
+        let queue = self.pending.get_mut(&(fill.exchange, fill.symbol, fill.side))?;
+        let decision = *queue.front()?;
+        match decision.remaining.checked_sub(fill.quantity) {
+            Some(left) if left.is_positive() => queue.front_mut()?.remaining = left,
+            _ => {
+                queue.pop_front();
+            }
+        }
+        let sign = if fill.side.is_buy() { 1.0 } else { -1.0 };
+        let bps = |reference: FixedPoint8| {
+            let reference = reference.to_f64();
+            if reference > 0.0 {
+                sign * (fill.price.to_f64() - reference) / reference * 1e4
+            } else {
+                0.0
+            }
+        };
+        let leg = LegSlippage {
+            timestamp_ms: fill.timestamp / 1_000_000,
+            exchange: fill.exchange,
+            symbol: fill.symbol,
+            side: fill.side,
+            decision_mid: decision.mid,
+            decision_touch: decision.touch,
+            submitted_price: decision.submitted,
+            fill_price: fill.price,
+            quantity: fill.quantity,
+            vs_mid_bps: bps(decision.mid),
+            vs_touch_bps: bps(decision.touch),
+            vs_submitted_bps: decision.submitted.map(bps),
+        };
+        self.day_mut(leg.timestamp_ms).venues[leg.exchange.index()].add(&leg);
+        if self.recent.len() == RECENT_LEGS {
+            self.recent.pop_front();
+        }
+        self.recent.push_back(leg);
+        Some(leg)
+    }
+
+    /// Daily statistics, oldest first
+    pub fn days(&self) -> impl Iterator<Item = &DailyQuality> {
+        self.days.iter()
+    }
+
+    /// Latest scored legs, oldest first
+    pub fn recent(&self) -> impl DoubleEndedIterator<Item = &LegSlippage> {
+        self.recent.iter()
+    }
+
+    fn day_mut(&mut self, timestamp_ms: u64) -> &mut DailyQuality {
+        let day_start_ms = timestamp_ms / DAY_MS * DAY_MS;
+        if self.days.back().is_none_or(|d| d.day_start_ms < day_start_ms) {
+            if self.days.len() == RETAIN_DAYS {
+                self.days.pop_front();
+            }
+            self.days.push_back(DailyQuality { day_start_ms, venues: Default::default() });
+        }
+        // A late fill from an earlier day counts toward the current one
+        self.days.back_mut().expect("day pushed above")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DAY0: u64 = 20_000 * DAY_MS;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    fn btc() -> Symbol {
+        Symbol::from_bytes(b"BTCUSDT").unwrap()
+    }
+
+    fn ticker(bid: f64, ask: f64) -> TickerData {
+        TickerData {
+            symbol: btc(),
+            bid_price: fp(bid),
+            bid_qty: FixedPoint8::ONE,
+            ask_price: fp(ask),
+            ask_qty: FixedPoint8::ONE,
+            timestamp: 0,
+            update_id: 0,
+        }
+    }
+
+    fn order(exchange: Exchange, side: Side, qty: f64, price: Option<f64>) -> OrderRequest {
+        OrderRequest { exchange, symbol: btc(), side, quantity: fp(qty), price: price.map(fp) }
+    }
+
+    fn fill(exchange: Exchange, side: Side, price: f64, qty: f64, at_ms: u64) -> Fill {
+        Fill { exchange, symbol: btc(), side, price: fp(price), quantity: fp(qty), timestamp: at_ms * 1_000_000 }
+    }
+
+    #[test]
+    fn test_slippage_against_decision() {
+        let mut quality = FillQuality::new();
+        // No quote yet: nothing to score against
+        assert!(!quality.on_submit(&order(Exchange::Binance, Side::Buy, 1.0, None), DAY0));
+
+        quality.observe_ticker(Exchange::Binance, &ticker(99.0, 101.0));
+        quality.observe_ticker(Exchange::Bybit, &ticker(99.0, 101.0));
+        assert!(quality.on_submit(&order(Exchange::Binance, Side::Buy, 2.0, Some(101.0)), DAY0));
+        assert!(quality.on_submit(&order(Exchange::Bybit, Side::Sell, 1.0, None), DAY0));
+
+        // Buy paid 101.5: adverse vs mid 100, touch 101 and limit 101
+        let leg = quality.on_fill(&fill(Exchange::Binance, Side::Buy, 101.5, 1.0, DAY0 + 5)).unwrap();
+        assert!((leg.vs_mid_bps - 150.0).abs() < 1e-6);
+        assert!((leg.vs_touch_bps - 49.504_950).abs() < 1e-3);
+        assert!(leg.vs_submitted_bps.unwrap() > 0.0);
+        // Partial fill: the rest of the same order scores against the same decision
+        let leg = quality.on_fill(&fill(Exchange::Binance, Side::Buy, 100.5, 1.0, DAY0 + 6)).unwrap();
+        assert!((leg.vs_mid_bps - 50.0).abs() < 1e-6);
+        assert!(quality.on_fill(&fill(Exchange::Binance, Side::Buy, 100.5, 1.0, DAY0 + 7)).is_none());
+
+        // Sell received 99.5 against touch 99: favorable
+        let leg = quality.on_fill(&fill(Exchange::Bybit, Side::Sell, 99.5, 1.0, DAY0 + 8)).unwrap();
+        assert!(leg.vs_touch_bps < 0.0 && leg.vs_submitted_bps.is_none());
+
+        let day = quality.days().next().unwrap();
+        let binance = day.venues[Exchange::Binance.index()];
+        assert_eq!(binance.fills, 2);
+        // Weighted by notional: (150 * 101.5 + 50 * 100.5) / 202
+        assert!((binance.avg_vs_mid_bps() - 100.247_524).abs() < 1e-3);
+        assert!((binance.worst_vs_mid_bps - 150.0).abs() < 1e-6);
+        assert!(day.venues[Exchange::Bybit.index()].avg_vs_submitted_bps().is_none());
+        assert_eq!(quality.recent().count(), 3);
+    }
+
+    #[test]
+    fn test_days_roll_and_pending_expire() {
+        let mut quality = FillQuality::new();
+        quality.observe_ticker(Exchange::Binance, &ticker(99.0, 101.0));
+        quality.on_submit(&order(Exchange::Binance, Side::Buy, 1.0, None), DAY0);
+        // The stale decision is dropped when the next one arrives
+        quality.on_submit(&order(Exchange::Binance, Side::Buy, 1.0, None), DAY0 + PENDING_TTL_MS + 1);
+        quality.on_fill(&fill(Exchange::Binance, Side::Buy, 101.0, 1.0, DAY0 + PENDING_TTL_MS + 2)).unwrap();
+        assert!(quality.on_fill(&fill(Exchange::Binance, Side::Buy, 101.0, 1.0, DAY0 + PENDING_TTL_MS + 3)).is_none());
+
+        for day in 1..=RETAIN_DAYS as u64 {
+            quality.on_submit(&order(Exchange::Binance, Side::Buy, 1.0, None), DAY0 + day * DAY_MS);
+            quality.on_fill(&fill(Exchange::Binance, Side::Buy, 101.0, 1.0, DAY0 + day * DAY_MS + 1)).unwrap();
+        }
+        let days: Vec<_> = quality.days().map(|d| d.day_start_ms).collect();
+        assert_eq!(days.len(), RETAIN_DAYS);
+        assert_eq!(days[0], DAY0 + DAY_MS);
+        assert_eq!(quality.days().last().unwrap().date(), "2024-10-11");
+    }
+}
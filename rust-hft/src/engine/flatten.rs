@@ -0,0 +1,410 @@
+//! Position flattening (panic close)
+//!
+//! Operators close positions on one symbol or on every symbol from the
+//! dashboard (`POST /api/flatten/{symbol|all}`), the operational
+//! complement to pausing the engine. Open positions are closed with market
+//! orders on every venue; after each retry interval positions are checked
+//! again and any residual is re-sent, up to the configured number of
+//! retries. The job ends with a report of what was closed and what is left,
+//! sent back to the caller.
+//!
+//! A leg is checked against the venue's own position reports (private
+//! stream, see [`ReportedPositions`]) when there are any, otherwise against
+//! the fill-based position book. Only venue reports confirm a leg flat: a
+//! job that is flat by the book alone ends [`FlattenStatus::Unverified`].
+
+use super::positions::PositionBook;
+use super::strategy::OrderRequest;
+use crate::core::{FixedPoint8, Side, Symbol};
+use crate::exchanges::{Exchange, PositionUpdate};
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+use tokio::sync::oneshot;
+
+/// Positions to close
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlattenTarget {
+    Symbol(Symbol),
+    All,
+}
+
+impl FlattenTarget {
+    /// Symbol name, or "all"
+    pub fn name(&self) -> &'static str {
+        match self {
+            FlattenTarget::Symbol(symbol) => symbol.as_str(),
+            FlattenTarget::All => "all",
+        }
+    }
+}
+
+/// Flatten request and the channel its report is sent back on
+#[derive(Debug)]
+pub struct FlattenRequest {
+    pub target: FlattenTarget,
+    pub reply: oneshot::Sender<FlattenReport>,
+}
+
+/// Net positions as last reported by the venues
+///
+/// Reports of a venue whose private stream is lost are dropped, since the
+/// venue may have changed them unseen.
+#[derive(Debug, Default)]
+pub struct ReportedPositions {
+    net: HashMap<(Exchange, Symbol), FixedPoint8>,
+}
+
+impl ReportedPositions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Apply a venue's position report
+    pub fn apply(&mut self, update: &PositionUpdate) {
+        let net = match update.side {
+            Some(Side::Sell) => FixedPoint8::from_raw(update.size.as_raw().saturating_neg()),
+            Some(Side::Buy) => update.size,
+            None => FixedPoint8::ZERO,
+        };
+        self.net.insert((update.exchange, update.symbol), net);
+    }
+
+    /// Drop every report of `exchange`
+    pub fn forget(&mut self, exchange: Exchange) {
+        self.net.retain(|&(venue, _), _| venue != exchange);
+    }
+
+    /// Signed net reported for `symbol` on `exchange` (None = no report)
+    pub fn net(&self, exchange: Exchange, symbol: Symbol) -> Option<FixedPoint8> {
+        self.net.get(&(exchange, symbol)).copied()
+    }
+
+    /// Symbols with a reported open position
+    fn open_symbols(&self) -> impl Iterator<Item = Symbol> + '_ {
+        self.net.iter().filter(|(_, net)| !net.is_zero()).map(|(&(_, symbol), _)| symbol)
+    }
+}
+
+/// One venue position being closed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FlattenLeg {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    /// Signed net quantity when the job started
+    pub initial: FixedPoint8,
+    /// Signed net quantity at the last check (ZERO = closed)
+    pub remaining: FixedPoint8,
+    /// `remaining` comes from the venue's position report, not from fills
+    pub confirmed: bool,
+    /// Close orders sent
+    pub orders: u32,
+}
+
+impl FlattenLeg {
+    /// Refresh `remaining` from the venue report, or the book without one
+    fn check(&mut self, positions: &PositionBook, reported: &ReportedPositions) {
+        let report = reported.net(self.exchange, self.symbol);
+        self.confirmed = report.is_some();
+        self.remaining = report.unwrap_or_else(|| positions.net(self.exchange, self.symbol));
+    }
+}
+
+/// How a flatten job ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FlattenStatus {
+    /// Every leg confirmed flat by the venues' position reports
+    Verified,
+    /// Flat by the fill-based book only; the venues did not confirm it
+    Unverified,
+    /// Positions still open after the last retry
+    Incomplete,
+}
+
+impl FlattenStatus {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Self::Verified => "verified",
+            Self::Unverified => "unverified",
+            Self::Incomplete => "incomplete",
+        }
+    }
+}
+
+/// Final state of a flatten job
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FlattenReport {
+    pub target: FlattenTarget,
+    pub legs: Vec<FlattenLeg>,
+    /// Rounds of close orders sent
+    pub attempts: u32,
+    pub status: FlattenStatus,
+    pub elapsed: Duration,
+}
+
+#[derive(Debug)]
+struct FlattenJob {
+    target: FlattenTarget,
+    legs: Vec<FlattenLeg>,
+    attempts: u32,
+    started: Instant,
+    next_check: Instant,
+    reply: Option<oneshot::Sender<FlattenReport>>,
+}
+
+impl FlattenJob {
+    fn report(&self, now: Instant) -> FlattenReport {
+        let status = if self.legs.iter().any(|leg| !leg.remaining.is_zero()) {
+            FlattenStatus::Incomplete
+        } else if !self.legs.is_empty() && self.legs.iter().all(|leg| leg.confirmed) {
+            FlattenStatus::Verified
+        } else {
+            FlattenStatus::Unverified
+        };
+        FlattenReport {
+            target: self.target,
+            legs: self.legs.clone(),
+            attempts: self.attempts,
+            status,
+            elapsed: now.saturating_duration_since(self.started),
+        }
+    }
+
+    /// Close orders for the open legs, counting them as sent
+    fn close_orders(&mut self) -> Vec<OrderRequest> {
+        self.attempts += 1;
+        let mut orders = Vec::new();
+        for leg in self.legs.iter_mut().filter(|leg| !leg.remaining.is_zero()) {
+            let Some(quantity) = leg.remaining.checked_abs() else { continue };
+            leg.orders += 1;
+            orders.push(OrderRequest {
+                exchange: leg.exchange,
+                symbol: leg.symbol,
+                side: if leg.remaining.is_positive() { Side::Sell } else { Side::Buy },
+                quantity,
+                price: None,
+            });
+        }
+        orders
+    }
+}
+
+/// Running flatten jobs with retry and verification
+#[derive(Debug)]
+pub struct Flattener {
+    retries: u32,
+    interval: Duration,
+    jobs: Vec<FlattenJob>,
+}
+
+impl Flattener {
+    /// Re-send residuals up to `retries` times, checking every `interval`
+    pub fn new(retries: u32, interval: Duration) -> Self {
+        Self {
+            retries,
+            interval,
+            jobs: Vec::new(),
+        }
+    }
+
+    /// Start closing `request.target`; returns the first round of orders
+    ///
+    /// Legs are the open positions in `reported`, or in `positions` on venues
+    /// without a report. A target without open positions is reported at once.
+    pub fn start(
+        &mut self,
+        request: FlattenRequest,
+        positions: &PositionBook,
+        reported: &ReportedPositions,
+        now: Instant,
+    ) -> Vec<OrderRequest> {
+        let mut symbols = match request.target {
+            FlattenTarget::Symbol(symbol) => vec![symbol],
+            FlattenTarget::All => positions.open_symbols().into_iter().chain(reported.open_symbols()).collect(),
+        };
+        symbols.sort_unstable();
+        symbols.dedup();
+        let legs = symbols
+            .into_iter()
+            .flat_map(|symbol| Exchange::ALL.into_iter().map(move |exchange| (exchange, symbol)))
+            .filter_map(|(exchange, symbol)| {
+                let mut leg = FlattenLeg {
+                    exchange,
+                    symbol,
+                    initial: FixedPoint8::ZERO,
+                    remaining: FixedPoint8::ZERO,
+                    confirmed: false,
+                    orders: 0,
+                };
+                leg.check(positions, reported);
+                leg.initial = leg.remaining;
+                (!leg.remaining.is_zero()).then_some(leg)
+            })
+            .collect();
+        let mut job = FlattenJob {
+            target: request.target,
+            legs,
+            attempts: 0,
+            started: now,
+            next_check: now + self.interval,
+            reply: Some(request.reply),
+        };
+        if job.legs.is_empty() {
+            Self::finish(job, now);
+            return Vec::new();
+        }
+        let orders = job.close_orders();
+        self.jobs.push(job);
+        orders
+    }
+
+    /// Earliest check of a running job
+    pub fn next_deadline(&self) -> Option<Instant> {
+        self.jobs.iter().map(|job| job.next_check).min()
+    }
+
+    /// Verify jobs due at `now` against `reported`, or `positions` without a report
+    ///
+    /// Jobs that are flat or out of retries are reported and returned;
+    /// the others get another round of close orders, appended to `orders`.
+    pub fn poll(
+        &mut self,
+        positions: &PositionBook,
+        reported: &ReportedPositions,
+        now: Instant,
+        orders: &mut Vec<OrderRequest>,
+    ) -> Vec<FlattenReport> {
+        let mut finished = Vec::new();
+        let mut i = 0;
+        while i < self.jobs.len() {
+            let job = &mut self.jobs[i];
+            if now < job.next_check {
+                i += 1;
+                continue;
+            }
+            for leg in &mut job.legs {
+                leg.check(positions, reported);
+            }
+            let flat = job.legs.iter().all(|leg| leg.remaining.is_zero());
+            if flat || job.attempts > self.retries {
+                finished.push(Self::finish(self.jobs.swap_remove(i), now));
+                continue;
+            }
+            orders.extend(job.close_orders());
+            job.next_check = now + self.interval;
+            i += 1;
+        }
+        finished
+    }
+
+    fn finish(mut job: FlattenJob, now: Instant) -> FlattenReport {
+        let report = job.report(now);
+        if let Some(reply) = job.reply.take() {
+            let _ = reply.send(report.clone());
+        }
+        report
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Side;
+    use crate::engine::strategy::Fill;
+    use crate::test_utils::init_test_registry;
+
+    fn fill(exchange: Exchange, symbol: Symbol, side: Side, qty: i64) -> Fill {
+        Fill { exchange, symbol, side, price: FixedPoint8::ONE, quantity: FixedPoint8::from_raw(qty), timestamp: 0 }
+    }
+
+    #[test]
+    fn test_flatten_retry_and_verify() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let mut positions = PositionBook::new();
+        positions.apply_fill(&fill(Exchange::Binance, btc, Side::Buy, 300));
+        positions.apply_fill(&fill(Exchange::Bybit, btc, Side::Sell, 300));
+        positions.apply_fill(&fill(Exchange::Bybit, eth, Side::Buy, 50));
+
+        let t0 = Instant::now();
+        let step = Duration::from_millis(500);
+        let mut flattener = Flattener::new(1, step);
+        let reported = ReportedPositions::new();
+        let (reply, mut report) = oneshot::channel();
+        let orders = flattener.start(FlattenRequest { target: FlattenTarget::All, reply }, &positions, &reported, t0);
+        assert_eq!(orders.len(), 3);
+        assert_eq!(flattener.next_deadline(), Some(t0 + step));
+
+        // Binance closes in part, Bybit BTC in full, ETH not at all
+        positions.apply_fill(&fill(Exchange::Binance, btc, Side::Sell, 200));
+        positions.apply_fill(&fill(Exchange::Bybit, btc, Side::Buy, 300));
+        let mut orders = Vec::new();
+        assert!(flattener.poll(&positions, &reported, t0, &mut orders).is_empty());
+        assert!(flattener.poll(&positions, &reported, t0 + step, &mut orders).is_empty());
+        assert_eq!(orders.len(), 2);
+        assert_eq!((orders[0].side, orders[0].quantity.as_raw()), (Side::Sell, 100));
+
+        // Out of retries with ETH still open
+        positions.apply_fill(&fill(Exchange::Binance, btc, Side::Sell, 100));
+        orders.clear();
+        let finished = flattener.poll(&positions, &reported, t0 + step * 2, &mut orders);
+        assert!(orders.is_empty() && flattener.next_deadline().is_none());
+        let report = report.try_recv().unwrap();
+        assert_eq!(finished, vec![report.clone()]);
+        assert_eq!((report.attempts, report.status), (2, FlattenStatus::Incomplete));
+        let open: Vec<_> = report.legs.iter().filter(|l| !l.remaining.is_zero()).map(|l| (l.symbol, l.orders)).collect();
+        assert_eq!(open, vec![(eth, 2)]);
+
+        // Nothing open in the book, but no venue confirms it
+        let (reply, mut report) = oneshot::channel();
+        assert!(flattener.start(FlattenRequest { target: FlattenTarget::Symbol(btc), reply }, &positions, &reported, t0).is_empty());
+        let report = report.try_recv().unwrap();
+        assert_eq!((report.status, report.legs.len()), (FlattenStatus::Unverified, 0));
+    }
+
+    #[test]
+    fn test_flatten_verified_by_venue_reports() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let report = |side, size| PositionUpdate {
+            exchange: Exchange::Bybit,
+            symbol: btc,
+            side,
+            size: FixedPoint8::from_raw(size),
+            entry_price: FixedPoint8::ONE,
+            liquidation_price: FixedPoint8::ZERO,
+            timestamp: 0,
+        };
+        // The book missed the fills: only the venue knows about the short
+        let positions = PositionBook::new();
+        let mut reported = ReportedPositions::new();
+        reported.apply(&report(Some(Side::Sell), 70));
+
+        let t0 = Instant::now();
+        let step = Duration::from_millis(500);
+        let mut flattener = Flattener::new(2, step);
+        let (reply, mut done) = oneshot::channel();
+        let orders = flattener.start(FlattenRequest { target: FlattenTarget::All, reply }, &positions, &reported, t0);
+        assert_eq!(orders.len(), 1);
+        assert_eq!((orders[0].exchange, orders[0].side, orders[0].quantity.as_raw()), (Exchange::Bybit, Side::Buy, 70));
+
+        // Venue still reports a residual: re-sent
+        reported.apply(&report(Some(Side::Sell), 20));
+        let mut orders = Vec::new();
+        assert!(flattener.poll(&positions, &reported, t0 + step, &mut orders).is_empty());
+        assert_eq!((orders[0].side, orders[0].quantity.as_raw()), (Side::Buy, 20));
+
+        // Venue reports flat
+        reported.apply(&report(None, 0));
+        orders.clear();
+        assert_eq!(flattener.poll(&positions, &reported, t0 + step * 2, &mut orders).len(), 1);
+        let done = done.try_recv().unwrap();
+        assert_eq!((done.status, done.attempts), (FlattenStatus::Verified, 2));
+        assert!(done.legs[0].confirmed && done.legs[0].remaining.is_zero());
+
+        // Reports of a lost stream no longer count
+        reported.apply(&report(Some(Side::Buy), 5));
+        reported.forget(Exchange::Bybit);
+        assert_eq!(reported.net(Exchange::Bybit, btc), None);
+    }
+}
@@ -0,0 +1,275 @@
+//! Per-connection inbound flood protection (Hot Path)
+//!
+//! Each feed task meters its connection with a token bucket refilled at
+//! `supervision.max_messages_per_sec` (one second of burst). Beyond the
+//! limit the latest ticker and mark price per symbol are held back and
+//! delivered as budget returns (older updates for the same symbol are
+//! conflated away); trades and liquidations are dropped. Heartbeats and
+//! errors always pass. A flooding venue therefore cannot fill the shared
+//! engine queue and starve the other connections.
+
+use crate::core::Symbol;
+use crate::exchanges::ExchangeMessage;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Quiet period (no message held back or dropped) that ends a flood
+const FLOOD_END_QUIET: Duration = Duration::from_secs(1);
+
+/// Conflation key: one pending update per stream kind and symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+enum Stream {
+    Ticker(Symbol),
+    MarkPrice(Symbol),
+}
+
+impl Stream {
+    fn of(msg: &ExchangeMessage) -> Option<Self> {
+        match msg {
+            ExchangeMessage::Ticker(_, ticker) => Some(Self::Ticker(ticker.symbol)),
+            ExchangeMessage::MarkPrice(_, mark) => Some(Self::MarkPrice(mark.symbol)),
+            _ => None,
+        }
+    }
+}
+
+/// What happened to an admitted message
+#[derive(Debug)]
+pub enum Admission {
+    /// Within budget: forward now
+    Forward(ExchangeMessage),
+    /// Held back until budget returns
+    Held,
+    /// Replaced an update still held back for the same stream
+    Conflated,
+    /// Over budget and not conflatable
+    Dropped,
+}
+
+/// Flood start/end transitions for alerting
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FloodEvent {
+    Started,
+    Ended {
+        duration: Duration,
+        conflated: u64,
+        dropped: u64,
+    },
+}
+
+/// Token bucket with per-stream conflation for one connection
+#[derive(Debug)]
+pub struct FloodGuard {
+    /// Refill rate (messages per second)
+    rate: f64,
+    /// Bucket size (one second of messages)
+    burst: f64,
+    tokens: f64,
+    refilled: Instant,
+    /// Held-back updates by stream with their arrival time
+    pending: HashMap<Stream, (Instant, ExchangeMessage)>,
+    /// Delivery order of `pending`
+    order: VecDeque<Stream>,
+    /// Flood in progress: (start, last message held back or dropped)
+    flood: Option<(Instant, Instant)>,
+    /// Reported state (the last event returned by `poll_event`)
+    reported: bool,
+    conflated: u64,
+    dropped: u64,
+}
+
+impl FloodGuard {
+    /// Guard admitting `max_per_sec` messages per second (at least 1)
+    pub fn new(max_per_sec: u32, now: Instant) -> Self {
+        let rate = f64::from(max_per_sec.max(1));
+        Self {
+            rate,
+            burst: rate,
+            tokens: rate,
+            refilled: now,
+            pending: HashMap::new(),
+            order: VecDeque::new(),
+            flood: None,
+            reported: false,
+            conflated: 0,
+            dropped: 0,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.refilled).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.burst);
+        self.refilled = now;
+    }
+
+    fn take(&mut self) -> bool {
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            true
+        } else {
+            false
+        }
+    }
+
+    fn limited(&mut self, now: Instant) {
+        let start = self.flood.map_or(now, |(start, _)| start);
+        self.flood = Some((start, now));
+    }
+
+    /// Meter a message that arrived at `now`
+    ///
+    /// Call [`release`](Self::release) first so held-back updates, which
+    /// are older, get the budget before this one.
+    pub fn admit(&mut self, msg: ExchangeMessage, now: Instant) -> Admission {
+        self.refill(now);
+        let Some(stream) = Stream::of(&msg) else {
+            if matches!(msg, ExchangeMessage::Heartbeat | ExchangeMessage::Error(_)) || self.take() {
+                return Admission::Forward(msg);
+            }
+            self.limited(now);
+            self.dropped += 1;
+            return Admission::Dropped;
+        };
+        if let Some(held) = self.pending.get_mut(&stream) {
+            // Keeps its place in the delivery order
+            *held = (now, msg);
+            self.limited(now);
+            self.conflated += 1;
+            return Admission::Conflated;
+        }
+        // Never overtake held-back updates
+        if self.order.is_empty() && self.take() {
+            return Admission::Forward(msg);
+        }
+        self.limited(now);
+        self.pending.insert(stream, (now, msg));
+        self.order.push_back(stream);
+        Admission::Held
+    }
+
+    /// Next held-back update the budget allows, with its arrival time
+    pub fn release(&mut self, now: Instant) -> Option<(Instant, ExchangeMessage)> {
+        self.refill(now);
+        let stream = *self.order.front()?;
+        if !self.take() {
+            return None;
+        }
+        self.order.pop_front();
+        self.pending.remove(&stream)
+    }
+
+    /// Updates currently held back
+    #[inline]
+    pub fn pending(&self) -> usize {
+        self.order.len()
+    }
+
+    /// Flood transition since the last call, if any
+    ///
+    /// A flood ends once nothing is held back and no message has been
+    /// limited for a second; `Ended` carries the totals of that flood.
+    pub fn poll_event(&mut self, now: Instant) -> Option<FloodEvent> {
+        let (start, last) = self.flood?;
+        if !self.reported {
+            self.reported = true;
+            return Some(FloodEvent::Started);
+        }
+        if self.order.is_empty() && now.saturating_duration_since(last) >= FLOOD_END_QUIET {
+            let event = FloodEvent::Ended {
+                duration: last.saturating_duration_since(start),
+                conflated: std::mem::take(&mut self.conflated),
+                dropped: std::mem::take(&mut self.dropped),
+            };
+            self.flood = None;
+            self.reported = false;
+            return Some(event);
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, MarkPriceData, Side, TickerData, TradeData};
+    use crate::exchanges::Exchange;
+    use crate::test_utils::init_test_registry;
+
+    fn ticker(symbol: &str, bid: f64) -> ExchangeMessage {
+        let symbol = Symbol::from_bytes(symbol.as_bytes()).unwrap();
+        let price = FixedPoint8::from_f64(bid).unwrap();
+        let ticker = TickerData::new(symbol, price, FixedPoint8::ONE, price, FixedPoint8::ONE, 1);
+        ExchangeMessage::Ticker(Exchange::Binance, ticker)
+    }
+
+    fn bid(msg: &ExchangeMessage) -> f64 {
+        match msg {
+            ExchangeMessage::Ticker(_, ticker) => ticker.bid_price.to_f64(),
+            other => panic!("expected ticker, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_flood_conflates_tickers() {
+        init_test_registry();
+        let t0 = Instant::now();
+        let mut guard = FloodGuard::new(2, t0);
+
+        assert!(matches!(guard.admit(ticker("BTCUSDT", 1.0), t0), Admission::Forward(_)));
+        assert!(matches!(guard.admit(ticker("ETHUSDT", 1.0), t0), Admission::Forward(_)));
+        // Budget spent: held, then conflated to the latest
+        assert!(matches!(guard.admit(ticker("BTCUSDT", 2.0), t0), Admission::Held));
+        assert!(matches!(guard.admit(ticker("ETHUSDT", 2.0), t0), Admission::Held));
+        assert!(matches!(guard.admit(ticker("BTCUSDT", 3.0), t0), Admission::Conflated));
+        assert!(matches!(guard.admit(ExchangeMessage::Heartbeat, t0), Admission::Forward(_)));
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mark = MarkPriceData::new(btc, FixedPoint8::ONE, FixedPoint8::ONE, FixedPoint8::ZERO, 0, 1);
+        assert!(matches!(guard.admit(ExchangeMessage::MarkPrice(Exchange::Binance, mark), t0), Admission::Held));
+        assert_eq!(guard.pending(), 3);
+        assert!(guard.release(t0).is_none());
+
+        // Half a second refills one token: held updates go out oldest first
+        let t1 = t0 + Duration::from_millis(500);
+        let (arrived, msg) = guard.release(t1).unwrap();
+        assert_eq!((arrived, bid(&msg)), (t0, 3.0));
+        assert!(guard.release(t1).is_none());
+        // New ticker for a stream still held must not overtake it
+        assert!(matches!(guard.admit(ticker("ETHUSDT", 4.0), t1), Admission::Conflated));
+
+        let t2 = t1 + Duration::from_secs(1);
+        assert_eq!(bid(&guard.release(t2).unwrap().1), 4.0);
+        assert!(matches!(guard.release(t2), Some((_, ExchangeMessage::MarkPrice(..)))));
+        assert_eq!(guard.pending(), 0);
+    }
+
+    #[test]
+    fn test_flood_events() {
+        init_test_registry();
+        let t0 = Instant::now();
+        let mut guard = FloodGuard::new(1, t0);
+        assert_eq!(guard.poll_event(t0), None);
+
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let trade = |at| ExchangeMessage::Trade(Exchange::Bybit, TradeData::new(btc, FixedPoint8::ONE, FixedPoint8::ONE, at, Side::Buy, false));
+        assert!(matches!(guard.admit(trade(1), t0), Admission::Forward(_)));
+        assert!(matches!(guard.admit(trade(2), t0), Admission::Dropped));
+        assert!(matches!(guard.admit(ticker("BTCUSDT", 1.0), t0), Admission::Held));
+        assert!(matches!(guard.admit(ticker("BTCUSDT", 2.0), t0), Admission::Conflated));
+        assert_eq!(guard.poll_event(t0), Some(FloodEvent::Started));
+        assert_eq!(guard.poll_event(t0), None);
+
+        // Still holding an update: not over yet
+        let t1 = t0 + Duration::from_secs(2);
+        assert_eq!(guard.poll_event(t1), None);
+        assert!(guard.release(t1).is_some());
+        assert_eq!(
+            guard.poll_event(t1),
+            Some(FloodEvent::Ended {
+                duration: Duration::ZERO,
+                conflated: 1,
+                dropped: 1
+            })
+        );
+        assert_eq!(guard.poll_event(t1), None);
+    }
+}
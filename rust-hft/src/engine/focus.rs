@@ -0,0 +1,238 @@
+//! Top-N symbol auto-focus
+//!
+//! Every symbol is tracked cheaply from its ticker streams. The top-N by
+//! opportunity score are upgraded to trade/depth streams and are the only
+//! symbols eligible for execution.
+//!
+//! Hysteresis prevents churn:
+//! - A symbol enters only when it ranks inside the top-N and a slot is free
+//! - A focused symbol leaves only after its rank drops below
+//!   `top_n + exit_margin` AND it has been focused for `min_dwell`
+//!
+//! Cold path: re-ranked every `rescore_secs`.
+
+use crate::core::{FixedPoint8, Symbol};
+use crate::exchanges::ExchangeClient;
+use crate::hot_path::ScreenerStats;
+use crate::infrastructure::config::FocusConfig;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Opportunity score used for ranking: 2-minute spread range
+///
+/// Symbols whose spread never crosses zero (no arbitrage) score zero.
+#[inline]
+pub fn opportunity_score(stats: &ScreenerStats) -> FixedPoint8 {
+    if stats.is_valid {
+        stats.spread_range.as_fraction()
+    } else {
+        FixedPoint8::ZERO
+    }
+}
+
+/// Symbols entering / leaving focus in one re-ranking
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct FocusChange {
+    pub promoted: Vec<Symbol>,
+    pub demoted: Vec<Symbol>,
+}
+
+impl FocusChange {
+    /// No symbol changed focus
+    pub fn is_empty(&self) -> bool {
+        self.promoted.is_empty() && self.demoted.is_empty()
+    }
+}
+
+/// Top-N selector with rank/dwell hysteresis
+#[derive(Debug)]
+pub struct FocusSelector {
+    top_n: usize,
+    exit_rank: usize,
+    min_dwell: Duration,
+    rescore_interval: Duration,
+    /// Focused symbols and when they were promoted
+    focused: HashMap<Symbol, Instant>,
+}
+
+impl FocusSelector {
+    /// Create selector from config
+    pub fn new(config: &FocusConfig) -> Self {
+        Self {
+            top_n: config.top_n,
+            exit_rank: config.top_n.saturating_add(config.exit_margin),
+            min_dwell: Duration::from_secs(config.min_dwell_secs),
+            rescore_interval: Duration::from_secs(config.rescore_secs),
+            focused: HashMap::with_capacity(config.top_n),
+        }
+    }
+
+    /// Interval between re-rankings
+    #[inline]
+    pub fn rescore_interval(&self) -> Duration {
+        self.rescore_interval
+    }
+
+    /// Whether symbol is focused (eligible for execution)
+    #[inline]
+    pub fn is_focused(&self, symbol: Symbol) -> bool {
+        self.focused.contains_key(&symbol)
+    }
+
+    /// Focused symbols, sorted
+    pub fn focused(&self) -> Vec<Symbol> {
+        let mut symbols: Vec<Symbol> = self.focused.keys().copied().collect();
+        symbols.sort();
+        symbols
+    }
+
+    /// Number of focused symbols
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.focused.len()
+    }
+
+    /// No symbol is focused
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.focused.is_empty()
+    }
+
+    /// Drop symbol from focus immediately (no dwell); returns true if it was focused
+    pub fn remove(&mut self, symbol: Symbol) -> bool {
+        self.focused.remove(&symbol).is_some()
+    }
+
+    /// Re-rank symbols by score at `now` and apply promotions/demotions
+    ///
+    /// Symbols with a non-positive score are unranked.
+    pub fn update(&mut self, scores: &[(Symbol, FixedPoint8)], now: Instant) -> FocusChange {
+        let mut ranked: Vec<(Symbol, FixedPoint8)> = scores
+            .iter()
+            .copied()
+            .filter(|(_, score)| score.is_positive())
+            .collect();
+        ranked.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        let rank: HashMap<Symbol, usize> = ranked
+            .iter()
+            .enumerate()
+            .map(|(i, (symbol, _))| (*symbol, i))
+            .collect();
+
+        let mut change = FocusChange::default();
+        for (&symbol, &since) in &self.focused {
+            let in_band = rank.get(&symbol).is_some_and(|&r| r < self.exit_rank);
+            if !in_band && now.saturating_duration_since(since) >= self.min_dwell {
+                change.demoted.push(symbol);
+            }
+        }
+        for symbol in &change.demoted {
+            self.focused.remove(symbol);
+        }
+
+        for (symbol, _) in ranked.iter().take(self.top_n) {
+            if self.focused.len() >= self.top_n {
+                break;
+            }
+            if !self.focused.contains_key(symbol) {
+                self.focused.insert(*symbol, now);
+                change.promoted.push(*symbol);
+            }
+        }
+
+        change.demoted.sort();
+        change
+    }
+}
+
+/// Bring a feed's focused streams in line with `target`
+///
+/// `upgraded` holds the symbols currently upgraded on this feed and is
+/// updated in place. Errors are logged; the next change retries the diff.
+pub async fn apply_focus(exchange: &mut ExchangeClient, upgraded: &mut Vec<Symbol>, target: &[Symbol]) {
+    let added: Vec<Symbol> = target.iter().copied().filter(|s| !upgraded.contains(s)).collect();
+    let removed: Vec<Symbol> = upgraded.iter().copied().filter(|s| !target.contains(s)).collect();
+
+    if let Err(e) = exchange.unsubscribe_focus(&removed).await {
+        tracing::warn!("Failed to downgrade {} symbols on {}: {}", removed.len(), exchange.name(), e);
+        return;
+    }
+    upgraded.retain(|s| !removed.contains(s));
+
+    if let Err(e) = exchange.subscribe_focus(&added).await {
+        tracing::warn!("Failed to upgrade {} symbols on {}: {}", added.len(), exchange.name(), e);
+        return;
+    }
+    upgraded.extend(added);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    fn sym(name: &str) -> Symbol {
+        Symbol::from_bytes(name.as_bytes()).unwrap()
+    }
+
+    fn selector() -> FocusSelector {
+        FocusSelector::new(&FocusConfig {
+            enabled: true,
+            top_n: 2,
+            exit_margin: 1,
+            min_dwell_secs: 60,
+            rescore_secs: 10,
+        })
+    }
+
+    fn scores(ranked: &[&str]) -> Vec<(Symbol, FixedPoint8)> {
+        ranked
+            .iter()
+            .enumerate()
+            .map(|(i, name)| (sym(name), FixedPoint8::from_raw(1_000 - i as i64)))
+            .collect()
+    }
+
+    #[test]
+    fn test_promotes_top_n() {
+        init_test_registry();
+        let mut focus = selector();
+        let t0 = Instant::now();
+
+        let change = focus.update(&scores(&["ETHUSDT", "BTCUSDT", "SOLUSDT"]), t0);
+        assert_eq!(change.promoted, vec![sym("ETHUSDT"), sym("BTCUSDT")]);
+        assert!(change.demoted.is_empty());
+        assert!(focus.is_focused(sym("BTCUSDT")));
+        assert!(!focus.is_focused(sym("SOLUSDT")));
+
+        // Zero scores are never focused
+        let mut focus = selector();
+        let change = focus.update(&[(sym("BTCUSDT"), FixedPoint8::ZERO)], t0);
+        assert!(change.is_empty());
+    }
+
+    #[test]
+    fn test_hysteresis() {
+        init_test_registry();
+        let mut focus = selector();
+        let t0 = Instant::now();
+        focus.update(&scores(&["ETHUSDT", "BTCUSDT", "SOLUSDT"]), t0);
+
+        // BTC slips to rank 3 (inside exit band): stays focused, SOL can't enter
+        let later = t0 + Duration::from_secs(120);
+        let change = focus.update(&scores(&["ETHUSDT", "SOLUSDT", "BTCUSDT"]), later);
+        assert!(change.is_empty());
+
+        // BTC falls out of the band but is still within min dwell: stays
+        let mut fresh = selector();
+        fresh.update(&scores(&["ETHUSDT", "BTCUSDT"]), t0);
+        let change = fresh.update(&scores(&["ETHUSDT", "SOLUSDT", "XRPUSDT", "BTCUSDT"]), t0 + Duration::from_secs(30));
+        assert!(change.is_empty());
+
+        // After min dwell: BTC demoted, SOL promoted into the free slot
+        let change = fresh.update(&scores(&["ETHUSDT", "SOLUSDT", "XRPUSDT", "BTCUSDT"]), t0 + Duration::from_secs(60));
+        assert_eq!(change.demoted, vec![sym("BTCUSDT")]);
+        assert_eq!(change.promoted, vec![sym("SOLUSDT")]);
+        assert_eq!(fresh.focused().len(), 2);
+    }
+}
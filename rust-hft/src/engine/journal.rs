@@ -0,0 +1,276 @@
+//! Opportunity journal
+//!
+//! Bounded in-memory log of opportunities that crossed the threshold,
+//! with the sizing decision and fill probability taken for each, and the
+//! net edge after costs (all-taker and with a maker-fee passive leg).
+//! Execution attempts are logged alongside with their per-leg timing
+//! breakdown (see [`super::budget`]) for tuning the execution budget.
+//! Written by the engine loop, read by the API (cold path).
+//!
+//! Every record also counts towards per-symbol hit counts in
+//! [`HEATMAP_BUCKET_NS`] buckets, kept for [`HEATMAP_RETENTION_HOURS`] after
+//! the records themselves are evicted, for the spread activity heatmap.
+
+use super::budget::ExecutionAttempt;
+use super::sizing::SizeDecision;
+use crate::core::{Bps, Symbol};
+use crate::exchanges::Exchange;
+use crate::hot_path::NetEdges;
+use std::collections::{BTreeMap, HashMap, VecDeque};
+
+/// Default number of journal entries kept
+pub const DEFAULT_JOURNAL_CAPACITY: usize = 1024;
+
+/// Finest heatmap time bucket (5 minutes, ns)
+pub const HEATMAP_BUCKET_NS: u64 = 5 * 60 * 1_000_000_000;
+
+/// Hours of heatmap hit counts kept
+pub const HEATMAP_RETENTION_HOURS: u64 = 24;
+
+const HOUR_NS: u64 = 3_600 * 1_000_000_000;
+
+/// Single journaled opportunity
+#[derive(Debug, Clone, Copy)]
+pub struct OpportunityRecord {
+    pub symbol: Symbol,
+    pub spread: Bps,
+    pub long_ex: Exchange,
+    pub short_ex: Exchange,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+    /// Chosen size and constraints
+    pub size: SizeDecision,
+    /// Spread net of costs, all-taker and with a passive leg (None without both books)
+    pub edges: Option<NetEdges>,
+}
+
+/// Opportunity hit counts as a (symbol × time bucket) matrix
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Heatmap {
+    /// Start of the first bucket (ns since epoch)
+    pub start: u64,
+    /// Bucket width (ns)
+    pub bucket: u64,
+    /// Row labels, most active symbol first
+    pub symbols: Vec<Symbol>,
+    /// `counts[row][column]`, one column per bucket, oldest first
+    pub counts: Vec<Vec<u32>>,
+}
+
+/// Bounded opportunity journal (oldest entries dropped first)
+#[derive(Debug)]
+pub struct OpportunityJournal {
+    entries: VecDeque<OpportunityRecord>,
+    capacity: usize,
+    total: u64,
+    /// Finished execution attempts (same capacity as `entries`)
+    attempts: VecDeque<ExecutionAttempt>,
+    /// Hit counts by bucket index (timestamp / HEATMAP_BUCKET_NS)
+    hits: BTreeMap<u64, HashMap<Symbol, u32>>,
+}
+
+impl OpportunityJournal {
+    /// Create journal keeping at most `capacity` entries
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            entries: VecDeque::with_capacity(capacity),
+            capacity: capacity.max(1),
+            total: 0,
+            attempts: VecDeque::new(),
+            hits: BTreeMap::new(),
+        }
+    }
+
+    /// Append record, evicting the oldest when full
+    pub fn record(&mut self, record: OpportunityRecord) {
+        if self.entries.len() == self.capacity {
+            self.entries.pop_front();
+        }
+        self.entries.push_back(record);
+        self.total += 1;
+        self.count_hit(record.symbol, record.timestamp);
+    }
+
+    /// Append a finished execution attempt, evicting the oldest when full
+    pub fn record_attempt(&mut self, attempt: ExecutionAttempt) {
+        if self.attempts.len() == self.capacity {
+            self.attempts.pop_front();
+        }
+        self.attempts.push_back(attempt);
+    }
+
+    /// Most recent `n` execution attempts, newest first
+    pub fn recent_attempts(&self, n: usize) -> impl Iterator<Item = &ExecutionAttempt> {
+        self.attempts.iter().rev().take(n)
+    }
+
+    fn count_hit(&mut self, symbol: Symbol, timestamp: u64) {
+        let bucket = timestamp / HEATMAP_BUCKET_NS;
+        let newest = self.hits.last_key_value().map_or(bucket, |(&last, _)| last.max(bucket));
+        let oldest = newest.saturating_sub(HEATMAP_RETENTION_HOURS * HOUR_NS / HEATMAP_BUCKET_NS);
+        if bucket < oldest {
+            return;
+        }
+        *self.hits.entry(bucket).or_default().entry(symbol).or_insert(0) += 1;
+        self.hits = self.hits.split_off(&oldest);
+    }
+
+    /// Hit counts over the last `hours` up to `now` (ns since epoch)
+    ///
+    /// `bucket` is rounded up to a multiple of [`HEATMAP_BUCKET_NS`] and
+    /// `hours` clamped to 1..=[`HEATMAP_RETENTION_HOURS`]. Buckets are
+    /// aligned to multiples of their width; the last one contains `now`.
+    pub fn heatmap(&self, now: u64, hours: u64, bucket: u64) -> Heatmap {
+        let ratio = bucket.div_ceil(HEATMAP_BUCKET_NS).max(1);
+        let bucket = ratio * HEATMAP_BUCKET_NS;
+        let columns = (hours.clamp(1, HEATMAP_RETENTION_HOURS) * HOUR_NS).div_ceil(bucket);
+        let end = now / bucket + 1;
+        let first = end.saturating_sub(columns);
+
+        let mut rows: HashMap<Symbol, Vec<u32>> = HashMap::new();
+        for (&fine, symbols) in self.hits.range(first * ratio..end * ratio) {
+            let column = (fine / ratio - first) as usize;
+            for (&symbol, &hits) in symbols {
+                rows.entry(symbol).or_insert_with(|| vec![0; columns as usize])[column] += hits;
+            }
+        }
+        let mut rows: Vec<(Symbol, Vec<u32>)> = rows.into_iter().collect();
+        rows.sort_by_cached_key(|(symbol, counts)| {
+            (std::cmp::Reverse(counts.iter().map(|&c| c as u64).sum::<u64>()), symbol.as_str())
+        });
+        let (symbols, counts) = rows.into_iter().unzip();
+        Heatmap {
+            start: first * bucket,
+            bucket,
+            symbols,
+            counts,
+        }
+    }
+
+    /// Canonical byte form of the held records, oldest first (one line each)
+    ///
+    /// Raw fixed-point values only, so equal journals give equal bytes.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut out = Vec::new();
+        for r in &self.entries {
+            out.extend_from_slice(
+                format!(
+                    "{} {} {} {} {} {} {:?} {} {} {} {} {}\n",
+                    r.timestamp,
+                    r.symbol.as_str(),
+                    r.long_ex.name(),
+                    r.short_ex.name(),
+                    r.spread.as_raw(),
+                    r.size.quantity.as_raw(),
+                    r.size.constraint,
+                    r.size.liquidity_cap.as_raw(),
+                    r.size.balance_cap.as_raw(),
+                    r.size.notional_cap.as_raw(),
+                    r.size.volatility_cap.as_raw(),
+                    r.size.fill_probability.as_raw(),
+                )
+                .as_bytes(),
+            );
+        }
+        out
+    }
+
+    /// Most recent `n` records, newest first
+    pub fn recent(&self, n: usize) -> impl Iterator<Item = &OpportunityRecord> {
+        self.entries.iter().rev().take(n)
+    }
+
+    /// Records currently held
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Check if journal is empty
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Total records ever written (including evicted)
+    pub fn total(&self) -> u64 {
+        self.total
+    }
+}
+
+impl Default for OpportunityJournal {
+    fn default() -> Self {
+        Self::new(DEFAULT_JOURNAL_CAPACITY)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FixedPoint8;
+    use crate::engine::sizing::SizeConstraint;
+    use crate::test_utils::init_test_registry;
+
+    fn record(symbol: Symbol, ts: u64) -> OpportunityRecord {
+        OpportunityRecord {
+            symbol,
+            spread: Bps::from_bps(30),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            timestamp: ts,
+            size: SizeDecision {
+                quantity: FixedPoint8::ONE,
+                constraint: SizeConstraint::Liquidity,
+                liquidity_cap: FixedPoint8::ONE,
+                balance_cap: FixedPoint8::ONE,
+                notional_cap: FixedPoint8::ONE,
+                volatility_cap: FixedPoint8::ONE,
+                fill_probability: FixedPoint8::ONE,
+            },
+            edges: None,
+        }
+    }
+
+    #[test]
+    fn test_bounded_newest_first() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut journal = OpportunityJournal::new(2);
+        journal.record(record(sym, 1));
+        journal.record(record(sym, 2));
+        journal.record(record(sym, 3));
+
+        assert_eq!(journal.len(), 2);
+        assert_eq!(journal.total(), 3);
+        let ts: Vec<u64> = journal.recent(10).map(|r| r.timestamp).collect();
+        assert_eq!(ts, vec![3, 2]);
+    }
+
+    #[test]
+    fn test_heatmap() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let minute = 60 * 1_000_000_000;
+        let now = 1_700_000_000 * 1_000_000_000 / (15 * minute) * (15 * minute) + 14 * minute;
+
+        // Counts outlive the bounded records
+        let mut journal = OpportunityJournal::new(1);
+        journal.record(record(eth, now - 25 * HOUR_NS));
+        for offset in [0, 4 * minute, 20 * minute] {
+            journal.record(record(btc, now - offset));
+        }
+        journal.record(record(eth, now - 2 * minute));
+        journal.record(record(eth, now - 3 * HOUR_NS));
+
+        // 15 minute buckets over 1 hour: last column holds `now`
+        let map = journal.heatmap(now, 1, 15 * minute);
+        assert_eq!((map.start, map.bucket), (now - 14 * minute - 45 * minute, 15 * minute));
+        assert_eq!(map.symbols, vec![btc, eth]);
+        assert_eq!(map.counts, vec![vec![0, 0, 1, 2], vec![0, 0, 0, 1]]);
+
+        // Bucket width rounded up to 5 minutes; hits older than retention dropped
+        let map = journal.heatmap(now, 48, 7 * minute);
+        assert_eq!(map.bucket, 10 * minute);
+        assert_eq!(map.counts.iter().map(|row| row.len()).max(), Some(144));
+        assert_eq!(map.counts.iter().flatten().sum::<u32>(), 5);
+    }
+}
@@ -0,0 +1,115 @@
+//! Order acknowledgement latency per venue
+//!
+//! Every acknowledged order leg yields a round trip (sent to acked) for its
+//! venue. Each venue keeps a smoothed estimate and a rolling window of the
+//! last round trips for percentiles. The estimates score venues: fill
+//! probability is computed with the slower leg's latency, and when a batch
+//! holds legs on both venues the slower venue's legs are sent first so both
+//! reach their books at about the same time. Until a venue is measured the
+//! sizing prior (`sizing.order_latency_ms`) stands in.
+
+use crate::exchanges::Exchange;
+use crate::hot_path::LatencyEstimator;
+use std::collections::VecDeque;
+use std::time::Duration;
+
+/// Round trips kept per venue for percentiles
+const WINDOW: usize = 512;
+
+/// Rolling round-trip percentiles of one venue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LatencySummary {
+    /// Round trips measured since startup
+    pub samples: u64,
+    pub p50: Duration,
+    pub p90: Duration,
+    pub p99: Duration,
+    /// Smoothed estimate used for scoring
+    pub estimate: Duration,
+}
+
+/// Order round-trip measurements per venue
+#[derive(Debug, Clone)]
+pub struct VenueLatency {
+    estimators: [LatencyEstimator; Exchange::COUNT],
+    /// Last round trips (ns), oldest first
+    windows: [VecDeque<u64>; Exchange::COUNT],
+}
+
+impl VenueLatency {
+    /// Every venue starts at `prior` until measured
+    pub fn new(prior: Duration) -> Self {
+        Self {
+            estimators: [LatencyEstimator::new(prior); Exchange::COUNT],
+            windows: Default::default(),
+        }
+    }
+
+    /// Record a round trip on `exchange`
+    pub fn record(&mut self, exchange: Exchange, round_trip: Duration) {
+        self.estimators[exchange.index()].record(round_trip);
+        let window = &mut self.windows[exchange.index()];
+        if window.len() == WINDOW {
+            window.pop_front();
+        }
+        window.push_back(round_trip.as_nanos().min(u64::MAX as u128) as u64);
+    }
+
+    /// Smoothed round trip of `exchange` (the prior until measured)
+    #[inline]
+    pub fn estimate(&self, exchange: Exchange) -> Duration {
+        self.estimators[exchange.index()].estimate()
+    }
+
+    /// Round trip of a two-leg opportunity: the slower venue's estimate
+    #[inline]
+    pub fn pair_estimate(&self, a: Exchange, b: Exchange) -> Duration {
+        self.estimate(a).max(self.estimate(b))
+    }
+
+    /// Percentiles over the window (None until `exchange` is measured)
+    pub fn summary(&self, exchange: Exchange) -> Option<LatencySummary> {
+        let mut sorted: Vec<u64> = self.windows[exchange.index()].iter().copied().collect();
+        if sorted.is_empty() {
+            return None;
+        }
+        sorted.sort_unstable();
+        let at = |q: f64| Duration::from_nanos(sorted[((q * sorted.len() as f64).ceil() as usize).clamp(1, sorted.len()) - 1]);
+        Some(LatencySummary {
+            samples: self.estimators[exchange.index()].samples(),
+            p50: at(0.50),
+            p90: at(0.90),
+            p99: at(0.99),
+            estimate: self.estimate(exchange),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_venue_latency() {
+        let ms = Duration::from_millis;
+        let mut latency = VenueLatency::new(ms(50));
+        assert_eq!(latency.summary(Exchange::Bybit), None);
+        assert_eq!(latency.pair_estimate(Exchange::Binance, Exchange::Bybit), ms(50));
+
+        for i in 1..=100 {
+            latency.record(Exchange::Bybit, ms(i));
+        }
+        latency.record(Exchange::Binance, ms(5));
+        let bybit = latency.summary(Exchange::Bybit).unwrap();
+        assert_eq!((bybit.samples, bybit.p50, bybit.p90, bybit.p99), (100, ms(50), ms(90), ms(99)));
+        // The first measurement replaces the prior
+        assert_eq!(latency.estimate(Exchange::Binance), ms(5));
+        assert_eq!(latency.pair_estimate(Exchange::Binance, Exchange::Bybit), latency.estimate(Exchange::Bybit));
+
+        // The window keeps the most recent round trips only
+        for _ in 0..WINDOW {
+            latency.record(Exchange::Bybit, ms(1));
+        }
+        assert_eq!(latency.summary(Exchange::Bybit).unwrap().p99, ms(1));
+    }
+}
@@ -0,0 +1,127 @@
+//! Manual order entry
+//!
+//! Operators place orders from the dashboard (`POST /api/order`) to hedge
+//! or flatten by hand during incidents. Orders travel over a channel to the
+//! engine loop and take the same route as strategy orders: the live gates
+//! (pause, per-symbol switch, delisting, halts, warm-up, private stream)
+//! and the execution throttle. The caller waits for the outcome.
+//!
+//! Flatten requests ([`super::flatten`]) share the channel.
+
+use super::flatten::{FlattenReport, FlattenRequest, FlattenTarget};
+use super::shadow::LiveOutcome;
+use super::strategy::OrderRequest;
+use parking_lot::Mutex;
+use std::time::Duration;
+use tokio::sync::{mpsc, oneshot};
+
+/// Orders waiting for the engine loop
+const MANUAL_QUEUE_CAPACITY: usize = 16;
+
+/// Longest wait for the engine loop to route an order
+const ROUTE_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Longest wait for a flatten report (retries included)
+const FLATTEN_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Operator command for the engine loop
+#[derive(Debug)]
+pub enum ManualCommand {
+    Order(ManualOrder),
+    Flatten(FlattenRequest),
+}
+
+/// Manual order and the channel its outcome is sent back on
+#[derive(Debug)]
+pub struct ManualOrder {
+    pub order: OrderRequest,
+    pub reply: oneshot::Sender<LiveOutcome>,
+}
+
+/// Channel from the API to the engine loop
+#[derive(Debug)]
+pub struct ManualOrderDesk {
+    tx: mpsc::Sender<ManualCommand>,
+    /// Taken by the engine loop when it starts
+    rx: Mutex<Option<mpsc::Receiver<ManualCommand>>>,
+}
+
+impl ManualOrderDesk {
+    pub fn new() -> Self {
+        let (tx, rx) = mpsc::channel(MANUAL_QUEUE_CAPACITY);
+        Self {
+            tx,
+            rx: Mutex::new(Some(rx)),
+        }
+    }
+
+    /// Route `order` through the engine loop and wait for the outcome
+    ///
+    /// None if the engine loop is not running or does not answer in time.
+    pub async fn submit(&self, order: OrderRequest) -> Option<LiveOutcome> {
+        let (reply, outcome) = oneshot::channel();
+        let routed = async {
+            self.tx.send(ManualCommand::Order(ManualOrder { order, reply })).await.ok()?;
+            outcome.await.ok()
+        };
+        tokio::time::timeout(ROUTE_TIMEOUT, routed).await.ok().flatten()
+    }
+
+    /// Close positions on `target` and wait for the final report
+    ///
+    /// None if the engine loop is not running or does not finish in time.
+    pub async fn flatten(&self, target: FlattenTarget) -> Option<FlattenReport> {
+        let (reply, report) = oneshot::channel();
+        let flattened = async {
+            self.tx.send(ManualCommand::Flatten(FlattenRequest { target, reply })).await.ok()?;
+            report.await.ok()
+        };
+        tokio::time::timeout(FLATTEN_TIMEOUT, flattened).await.ok().flatten()
+    }
+
+    /// Receiving end for the engine loop (None once taken)
+    pub fn take_receiver(&self) -> Option<mpsc::Receiver<ManualCommand>> {
+        self.rx.lock().take()
+    }
+}
+
+impl Default for ManualOrderDesk {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Side, Symbol};
+    use crate::exchanges::Exchange;
+    use crate::test_utils::init_test_registry;
+
+    #[tokio::test]
+    async fn test_manual_order_round_trip() {
+        init_test_registry();
+        let desk = ManualOrderDesk::new();
+        let order = OrderRequest {
+            exchange: Exchange::Bybit,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side: Side::Sell,
+            quantity: FixedPoint8::ONE,
+            price: None,
+        };
+        let mut rx = desk.take_receiver().unwrap();
+        assert!(desk.take_receiver().is_none());
+
+        let engine = tokio::spawn(async move {
+            let Some(ManualCommand::Order(manual)) = rx.recv().await else {
+                panic!("expected an order");
+            };
+            assert_eq!(manual.order.side, Side::Sell);
+            manual.reply.send(LiveOutcome::Suppressed("paused")).unwrap();
+            // Loop stopped: later orders get no outcome
+        });
+        assert_eq!(desk.submit(order).await, Some(LiveOutcome::Suppressed("paused")));
+        engine.await.unwrap();
+        assert_eq!(desk.submit(order).await, None);
+    }
+}
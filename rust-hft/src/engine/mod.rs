@@ -0,0 +1,1786 @@
+//! Core Application Engine
+//!
+//! Orchestrates WebSocket clients, message routing, and state management.
+//! Connects Hot Path (exchanges) to Warm Path (tracker) and Cold Path (API).
+//!
+//! Each exchange feed is an actor task restarted by the [`supervisor`], with
+//! a [`flood`] guard capping each connection's inbound message rate. When
+//! every feed of a venue is disconnected the venue is down: its tickers are
+//! marked stale and no spreads are computed against them. Symbols halted or
+//! in auction on either venue get no spreads until both venues trade them
+//! again. In auto-focus mode (see [`focus`]) only the top-N symbols get
+//! trade/depth streams. Loop iterations over the latency budget are logged
+//! as [`stall`]s.
+//!
+//! Opportunities are sized (see [`sizing`]), scored by the probability their
+//! quotes survive the order round trip (measured per venue, see
+//! [`latency`]) and recorded in the [`journal`]. Completed opportunity
+//! [`episodes`] and fills go to an optional history sink. Alerts are
+//! conflated per episode (see [`alerts`]); openings and closes also go to an
+//! optional webhook sink, and the alert log and each webhook are rate
+//! limited with summaries of what was suppressed.
+//!
+//! User strategies (see [`strategy`]) are driven from the same message loop.
+//! Their orders, and operator [`manual`] orders, pass through the
+//! [`execution`] engine with per-symbol [`throttle`]s; strategy instances
+//! with a budget trade within their capital partition (see [`allocator`]).
+//! Both legs of an opportunity must be acknowledged within the execution
+//! [`budget`], otherwise the outstanding legs are cancelled and the episode
+//! is marked aborted. Orders are dropped in read-only mode (no execution
+//! engine), until the startup [`warmup`] gate opens, while an operator has
+//! paused the engine (see [`control`]), on halted symbols, and on symbols
+//! scheduled for delisting, which are also unsubscribed and flattened from
+//! the fill-based [`positions`] book. When a venue's private (fill) stream
+//! is lost, new orders there are frozen and, by default, its open orders
+//! cancelled (see [`private_stream`]). With a [`peer`] instance in another
+//! region only the legs on this instance's venues are executed, and nothing
+//! while the peer is lost. As a hot standby (see [`failover`]) the engine
+//! mirrors the active instance's positions and tickers and trades only once
+//! it takes over; the active side stops trading when its lease from the
+//! standby runs out.
+//!
+//! Operators can [`flatten`] positions with retried market closes. Fills are
+//! tagged with the strategy and episode behind their order, and realized PnL
+//! and fees are [`attribution`]ed per strategy, symbol and episode. In
+//! [`shadow`] mode every order is also paper-filled and compared with the
+//! live outcome in a daily report. Captures can be [`replay`]ed to verify
+//! that journals are deterministic, and when the loop stops a session
+//! [`report`] summarizes the run.
+
+pub mod alerts;
+pub mod allocator;
+pub mod attribution;
+pub mod budget;
+pub mod control;
+pub mod execution;
+pub mod failover;
+pub mod fill_quality;
+pub mod flatten;
+pub mod flood;
+pub mod focus;
+pub mod episodes;
+pub mod journal;
+pub mod latency;
+pub mod manual;
+pub mod peer;
+pub mod positions;
+pub mod private_stream;
+pub mod replay;
+pub mod report;
+pub mod shadow;
+pub mod sizing;
+pub mod stall;
+pub mod strategy;
+pub mod supervisor;
+pub mod throttle;
+pub mod warmup;
+#[cfg(feature = "plugins")]
+pub mod plugins;
+
+pub use alerts::{Alert, AlertConflator};
+pub use allocator::{AllocationRejected, CapitalAllocator, StrategyBudget};
+pub use attribution::{FillTag, OrderOrigin, PnlAttribution, PnlStats};
+pub use budget::{AttemptOutcome, ExecutionAttempt, ExecutionBudget, LegTiming};
+pub use control::{EngineControl, PauseStatus};
+pub use execution::{ExecutionEngine, ExecutionOutcome};
+pub use failover::{Contact, FailoverGuard, FailoverTransition};
+pub use fill_quality::{DailyQuality, FillQuality, LegSlippage, SlippageStats};
+pub use flatten::{FlattenLeg, FlattenReport, FlattenRequest, FlattenStatus, FlattenTarget, Flattener, ReportedPositions};
+pub use focus::{FocusChange, FocusSelector};
+pub use episodes::{Episode, EpisodeTracker, HistoryEvent};
+pub use journal::{OpportunityJournal, OpportunityRecord};
+pub use latency::{LatencySummary, VenueLatency};
+pub use manual::{ManualCommand, ManualOrder, ManualOrderDesk};
+pub use positions::PositionBook;
+pub use peer::{PeerGuard, PeerTransition};
+pub use private_stream::{PrivateStreamGuard, StreamTransition};
+pub use replay::{CapturedMessage, ReplayMismatch};
+pub use report::SessionReport;
+pub use stall::{Stall, StallDetector};
+pub use shadow::{LiveOutcome, ShadowDecision, ShadowExecutor, ShadowReport};
+pub use sizing::{SizeConstraint, SizeDecision, SizingInputs, SizingModel};
+pub use strategy::{Action, Fill, OrderRequest, Strategy};
+pub use supervisor::{ExchangeFactory, FeedLinks, RestartPolicy, Supervisor, VenueFeeds};
+pub use throttle::{Suppressed, Throttle};
+pub use warmup::{WarmupGate, WarmupStatus};
+
+use crate::core::{clock, DelistingNotice, FixedPoint8, QuoteCurrency, QuoteRates, Side, StatusNotice, Symbol, TickerData, TradingStatus};
+use crate::exchanges::{ExchangeClient, ExchangeMessage, Exchange, OrderState, UserEvent, UserStreamReport};
+use crate::hot_path::{CostModel, Deduplicator, QuoteRejection, SpreadEvent, SymbolState, ThresholdTracker, Verdict, OPPORTUNITY_SPREAD};
+use crate::infrastructure::alert_limit::{Admission, AlertLimiter};
+use crate::infrastructure::config::{AlertConfig, ExecutionConfig, FocusConfig, HftConfig, PrivateStreamAction, SizingConfig, SupervisionConfig, TradingPermit, WarmupConfig};
+use crate::infrastructure::peer::PeerEvent;
+use crate::infrastructure::standby::{ReplicaState, Replication, StandbyEvent};
+use crate::infrastructure::metrics::{
+    EpisodePnlSnapshot, ExecutionQualitySnapshot, LegSlippageSnapshot, MetricsCollector, OrderLatencySnapshot, PnlAttributionSnapshot,
+    PnlBucketSnapshot, VenueQualitySnapshot,
+};
+use crate::infrastructure::presence::SymbolPresence;
+use crate::infrastructure::startup::StartupStage;
+use crate::infrastructure::watchdog::Heartbeat;
+use crate::ws::{CircuitBreakerConfig, ConnectionId};
+use crate::Result;
+use std::collections::HashSet;
+use std::path::PathBuf;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch, RwLock};
+
+/// Capacity of the delisting notice channel
+const DELISTING_QUEUE_CAPACITY: usize = 64;
+
+/// Capacity of the trading status notice channel (one per symbol and venue per refresh)
+const STATUS_QUEUE_CAPACITY: usize = 256;
+
+/// Capacity of the peer link event channel
+const PEER_EVENT_QUEUE_CAPACITY: usize = 4096;
+
+/// Tags tickers received from the peer instance (deduplicated against local feeds)
+pub const PEER_CONNECTION: ConnectionId = ConnectionId(u16::MAX as u64);
+
+/// Alert limiter channel of the opportunity alert log
+const ALERT_LOG_CHANNEL: &str = "log";
+
+/// Capacity of the standby link event channel
+const STANDBY_EVENT_QUEUE_CAPACITY: usize = 256;
+
+/// Capacity of the private stream report channel (orders, fills, health)
+const USER_STREAM_QUEUE_CAPACITY: usize = 1024;
+
+/// Tags tickers replicated from the active instance
+pub const REPLICA_CONNECTION: ConnectionId = ConnectionId(u16::MAX as u64 - 1);
+
+/// Main engine managing the trading lifecycle
+pub struct AppEngine {
+    tracker: Arc<RwLock<ThresholdTracker>>,
+    metrics: Arc<MetricsCollector>,
+    /// Feed factories and restart policy
+    supervisor: Supervisor,
+    /// Feed → engine channel capacity
+    queue_capacity: usize,
+    strategies: Vec<Box<dyn Strategy>>,
+    /// Capital partitions, indexed like `strategies`
+    allocator: CapitalAllocator,
+    sizing: SizingModel,
+    /// Order round-trip latency per venue: fill probability and leg order
+    order_latency: VenueLatency,
+    journal: Arc<RwLock<OpportunityJournal>>,
+    /// None in read-only mode (no trading permit)
+    execution: Option<ExecutionEngine>,
+    /// Ack deadlines of in-flight opportunity legs
+    budget: ExecutionBudget,
+    /// Slippage of fills against the quote at decision time
+    fill_quality: FillQuality,
+    /// Realized PnL and fees per strategy, symbol and episode
+    attribution: PnlAttribution,
+    /// Paper execution alongside the live path (None = shadow mode off)
+    shadow: Option<ShadowExecutor>,
+    /// Directory for daily shadow reports (None = log only)
+    shadow_dir: Option<PathBuf>,
+    /// Redundant connections deliver each update more than once
+    dedup: Deduplicator,
+    /// Top-N auto-focus (None = disabled, every symbol may execute)
+    focus: Option<FocusSelector>,
+    /// Focused set published to feed tasks (created by run)
+    focus_tx: Option<watch::Sender<Vec<Symbol>>>,
+    last_rescore: Instant,
+    /// Operator flatten jobs awaiting verification
+    flattener: Flattener,
+    /// Net positions from fills (flattened on delisting)
+    positions: PositionBook,
+    /// Positions as reported by the venues' private streams
+    reported: ReportedPositions,
+    /// Quote currency → USDT factors (sizing and PnL)
+    rates: Arc<QuoteRates>,
+    /// Symbols scheduled for delisting (no new orders)
+    delisted: HashSet<Symbol>,
+    /// Delisted set published to feed tasks (unsubscribe)
+    delisted_tx: watch::Sender<Vec<Symbol>>,
+    delisting_tx: mpsc::Sender<DelistingNotice>,
+    delisting_rx: Option<mpsc::Receiver<DelistingNotice>>,
+    /// Symbols halted or in auction on a venue (no new orders)
+    suspended: HashSet<Symbol>,
+    status_tx: mpsc::Sender<StatusNotice>,
+    status_rx: Option<mpsc::Receiver<StatusNotice>>,
+    /// One opportunity alert per episode, with rate-limited updates
+    alerts: AlertConflator,
+    /// Open opportunity episodes (completed ones go to the history sink)
+    episodes: EpisodeTracker,
+    history_tx: Option<mpsc::Sender<HistoryEvent>>,
+    /// Opportunity openings and closes for the webhook sink
+    webhook_tx: Option<mpsc::Sender<Alert>>,
+    /// Alert budgets shared by the alert log and the webhook sink
+    alert_limit: Arc<AlertLimiter>,
+    /// Execution disabled until warmed up
+    warmup: WarmupGate,
+    /// Bumped per processed message (systemd watchdog)
+    heartbeat: Option<Heartbeat>,
+    /// Loop iteration timing against the stall budget
+    stall: StallDetector,
+    /// Subscription state published to the API
+    presence: Arc<SymbolPresence>,
+    /// Operator pause switch (toggled from the API)
+    control: Arc<EngineControl>,
+    /// Venues with a connected feed, as last applied to the tracker
+    venues_up: [bool; Exchange::COUNT],
+    /// Private stream health per venue (orders frozen while lost)
+    private_stream: PrivateStreamGuard,
+    /// Leg ownership with a peer instance (None = no peer)
+    peer: Option<PeerGuard>,
+    /// Local-venue tickers for the peer link
+    peer_out: Option<mpsc::Sender<(Exchange, TickerData)>>,
+    peer_tx: mpsc::Sender<PeerEvent>,
+    peer_rx: Option<mpsc::Receiver<PeerEvent>>,
+    /// Hot-standby role and lease (None = no standby)
+    failover: Option<FailoverGuard>,
+    /// Role announced on standby link heartbeats
+    failover_contact: Option<watch::Sender<Contact>>,
+    /// Snapshots and fills for the standby link (sent while active)
+    replica_out: Option<mpsc::Sender<Replication>>,
+    snapshot_interval: Duration,
+    next_snapshot: Option<Instant>,
+    /// Legs the active instance left unacknowledged (cancelled on takeover)
+    replica_legs: Vec<(Exchange, Symbol)>,
+    standby_tx: mpsc::Sender<StandbyEvent>,
+    standby_rx: Option<mpsc::Receiver<StandbyEvent>>,
+    /// Reports from the venues' private stream tasks
+    user_tx: mpsc::Sender<UserStreamReport>,
+    user_rx: Option<mpsc::Receiver<UserStreamReport>>,
+    running: bool,
+}
+
+impl AppEngine {
+    /// Create new engine with shared tracker and metrics
+    ///
+    /// The engine starts read-only; see [`Self::set_execution_config`].
+    pub fn new(tracker: Arc<RwLock<ThresholdTracker>>, metrics: Arc<MetricsCollector>) -> Self {
+        let (delisting_tx, delisting_rx) = mpsc::channel(DELISTING_QUEUE_CAPACITY);
+        let (status_tx, status_rx) = mpsc::channel(STATUS_QUEUE_CAPACITY);
+        let (peer_tx, peer_rx) = mpsc::channel(PEER_EVENT_QUEUE_CAPACITY);
+        let (standby_tx, standby_rx) = mpsc::channel(STANDBY_EVENT_QUEUE_CAPACITY);
+        let (user_tx, user_rx) = mpsc::channel(USER_STREAM_QUEUE_CAPACITY);
+        metrics.set_read_only(true);
+        let sizing = SizingModel::new(&SizingConfig::default());
+        Self {
+            tracker,
+            metrics: metrics.clone(),
+            supervisor: Supervisor::default(),
+            queue_capacity: HftConfig::default().engine_queue_capacity,
+            strategies: Vec::new(),
+            allocator: CapitalAllocator::new(),
+            sizing,
+            order_latency: VenueLatency::new(sizing.order_latency()),
+            journal: Arc::new(RwLock::new(OpportunityJournal::default())),
+            execution: None,
+            budget: ExecutionBudget::new(Duration::from_millis(ExecutionConfig::default().leg_budget_ms)),
+            fill_quality: FillQuality::new(),
+            attribution: PnlAttribution::new(),
+            shadow: None,
+            shadow_dir: None,
+            dedup: Deduplicator::new(),
+            focus: None,
+            focus_tx: None,
+            last_rescore: Instant::now(),
+            flattener: Self::flattener(&ExecutionConfig::default()),
+            positions: PositionBook::new(),
+            reported: ReportedPositions::new(),
+            rates: Arc::new(QuoteRates::default()),
+            delisted: HashSet::new(),
+            delisted_tx: watch::Sender::new(Vec::new()),
+            delisting_tx,
+            delisting_rx: Some(delisting_rx),
+            suspended: HashSet::new(),
+            status_tx,
+            status_rx: Some(status_rx),
+            alerts: AlertConflator::new(Duration::from_secs(AlertConfig::default().update_secs)),
+            episodes: EpisodeTracker::new(),
+            history_tx: None,
+            webhook_tx: None,
+            alert_limit: Arc::new(AlertLimiter::default()),
+            warmup: WarmupGate::disabled(Instant::now()),
+            heartbeat: None,
+            stall: StallDetector::new(Duration::from_millis(HftConfig::default().stall_budget_ms)),
+            presence: Arc::new(SymbolPresence::new()),
+            control: Arc::new(EngineControl::new()),
+            venues_up: [false; Exchange::COUNT],
+            private_stream: PrivateStreamGuard::default(),
+            peer: None,
+            peer_out: None,
+            peer_tx,
+            peer_rx: Some(peer_rx),
+            failover: None,
+            failover_contact: None,
+            replica_out: None,
+            snapshot_interval: Duration::ZERO,
+            next_snapshot: None,
+            replica_legs: Vec::new(),
+            standby_tx,
+            standby_rx: Some(standby_rx),
+            user_tx,
+            user_rx: Some(user_rx),
+            running: false,
+        }
+    }
+
+    /// Set opportunity sizing model (resets the latency estimate to its prior)
+    pub fn set_sizing(&mut self, sizing: SizingModel) {
+        self.order_latency = VenueLatency::new(sizing.order_latency());
+        self.sizing = sizing;
+    }
+
+    /// Share quote currency rates (refreshed in the background)
+    pub fn set_rates(&mut self, rates: Arc<QuoteRates>) {
+        self.positions.set_rates(rates.clone());
+        self.attribution.set_rates(rates.clone());
+        self.rates = rates;
+    }
+
+    /// Fee rates for PnL attribution (configured, not detected, tiers)
+    pub fn set_cost_model(&mut self, costs: CostModel) {
+        self.attribution.set_fee_model(costs);
+    }
+
+    /// Record a measured order round trip on `exchange` (sent to acknowledged)
+    pub fn record_order_latency(&mut self, exchange: Exchange, round_trip: Duration) {
+        self.order_latency.record(exchange, round_trip);
+        let venues = Exchange::ALL
+            .into_iter()
+            .filter_map(|exchange| {
+                let summary = self.order_latency.summary(exchange)?;
+                let ms = |d: Duration| d.as_secs_f64() * 1e3;
+                Some(OrderLatencySnapshot {
+                    exchange: exchange.name(),
+                    samples: summary.samples,
+                    p50_ms: ms(summary.p50),
+                    p90_ms: ms(summary.p90),
+                    p99_ms: ms(summary.p99),
+                    estimate_ms: ms(summary.estimate),
+                })
+            })
+            .collect();
+        self.metrics.set_order_latency(venues);
+    }
+
+    /// Order round-trip measurements per venue
+    pub fn order_latency(&self) -> &VenueLatency {
+        &self.order_latency
+    }
+
+    /// Fill slippage statistics
+    pub fn fill_quality(&self) -> &FillQuality {
+        &self.fill_quality
+    }
+
+    /// Publish daily slippage per venue and the latest scored fills
+    fn publish_execution_quality(&self) {
+        let side = |side: Side| if side.is_buy() { "buy" } else { "sell" };
+        let days = self
+            .fill_quality
+            .days()
+            .flat_map(|day| {
+                Exchange::ALL.into_iter().filter_map(move |exchange| {
+                    let stats = day.venues[exchange.index()];
+                    (stats.fills > 0).then(|| VenueQualitySnapshot {
+                        date: day.date(),
+                        exchange: exchange.name(),
+                        fills: stats.fills,
+                        notional: stats.notional,
+                        avg_vs_mid_bps: stats.avg_vs_mid_bps(),
+                        avg_vs_touch_bps: stats.avg_vs_touch_bps(),
+                        avg_vs_submitted_bps: stats.avg_vs_submitted_bps(),
+                        worst_vs_mid_bps: stats.worst_vs_mid_bps,
+                    })
+                })
+            })
+            .collect();
+        let recent = self
+            .fill_quality
+            .recent()
+            .rev()
+            .map(|leg| LegSlippageSnapshot {
+                timestamp_ms: leg.timestamp_ms,
+                exchange: leg.exchange.name(),
+                symbol: leg.symbol.as_str().to_string(),
+                side: side(leg.side),
+                decision_mid: leg.decision_mid.to_f64(),
+                decision_touch: leg.decision_touch.to_f64(),
+                submitted_price: leg.submitted_price.map(|p| p.to_f64()),
+                fill_price: leg.fill_price.to_f64(),
+                quantity: leg.quantity.to_f64(),
+                vs_mid_bps: leg.vs_mid_bps,
+                vs_touch_bps: leg.vs_touch_bps,
+                vs_submitted_bps: leg.vs_submitted_bps,
+            })
+            .collect();
+        self.metrics.set_execution_quality(ExecutionQualitySnapshot { days, recent });
+    }
+
+    /// Set execution throttling (cooldowns, trades per minute)
+    /// Requires a trading permit: without one the engine stays read-only.
+    pub fn set_execution_config(&mut self, config: &ExecutionConfig, permit: TradingPermit) {
+        self.execution = Some(ExecutionEngine::new(config, self.metrics.clone(), permit));
+        self.private_stream.set_action(config.private_stream_loss);
+        self.budget = ExecutionBudget::new(Duration::from_millis(config.leg_budget_ms));
+        self.flattener = Self::flattener(config);
+        self.metrics.set_read_only(false);
+    }
+
+    fn flattener(config: &ExecutionConfig) -> Flattener {
+        Flattener::new(config.flatten_retries, Duration::from_millis(config.flatten_retry_ms))
+    }
+
+    /// Paper-execute every order alongside the live path
+    ///
+    /// Works in read-only mode too. Daily reports are logged and, with a
+    /// directory, written to `shadow-<date>.txt` there.
+    pub fn enable_shadow(&mut self, report_dir: Option<PathBuf>) {
+        let mut shadow = ShadowExecutor::new();
+        shadow.roll(clock::coarse_unix_ms(), &self.positions);
+        self.shadow = Some(shadow);
+        self.shadow_dir = report_dir;
+    }
+
+    /// Shadow report of the current day so far (None unless shadow mode is on)
+    pub fn shadow_report(&self) -> Option<ShadowReport> {
+        self.shadow.as_ref().map(|shadow| shadow.report(&self.positions))
+    }
+
+    /// Close the shadow day at midnight UTC: log and write its report
+    fn roll_shadow(&mut self, now_ms: u64) {
+        let Some(report) = self.shadow.as_mut().and_then(|shadow| shadow.roll(now_ms, &self.positions)) else {
+            return;
+        };
+        tracing::info!("{}", report.render());
+        if let Some(dir) = self.shadow_dir.clone() {
+            tokio::task::spawn_blocking(move || match report.write_to(&dir) {
+                Ok(path) => tracing::info!("Shadow report written to {}", path.display()),
+                Err(e) => tracing::warn!("Failed to write shadow report: {}", e),
+            });
+        }
+    }
+
+    /// No execution engine (orders are dropped)
+    pub fn is_read_only(&self) -> bool {
+        self.execution.is_none()
+    }
+
+    /// Enable top-N auto-focus (no-op if disabled in config)
+    pub fn set_focus_config(&mut self, config: &FocusConfig) {
+        self.focus = config.enabled.then(|| FocusSelector::new(config));
+    }
+
+    /// Set the opportunity alert update interval
+    pub fn set_alert_config(&mut self, config: &AlertConfig) {
+        self.alerts = AlertConflator::new(Duration::from_secs(config.update_secs));
+        self.alert_limit = Arc::new(AlertLimiter::new(&config.rate_limit));
+    }
+
+    /// Alert budgets (the webhook sink's targets are channels of it)
+    pub fn alert_limiter(&self) -> Arc<AlertLimiter> {
+        self.alert_limit.clone()
+    }
+
+    /// Start the warm-up gate now (execution disabled until warm)
+    pub fn set_warmup_config(&mut self, config: &WarmupConfig) {
+        self.warmup = WarmupGate::new(config, Instant::now());
+        let status = self.warmup_status();
+        self.metrics.set_warmup(status.active, status.remaining.as_secs(), status.warm_symbols);
+    }
+
+    /// Warm-up gate state
+    pub fn warmup_status(&self) -> WarmupStatus {
+        self.warmup.status(Instant::now())
+    }
+
+    /// Auto-focus selector (None when disabled)
+    pub fn focus(&self) -> Option<&FocusSelector> {
+        self.focus.as_ref()
+    }
+
+    /// Sender for delisting notices (instruments refresh task)
+    pub fn delisting_sender(&self) -> mpsc::Sender<DelistingNotice> {
+        self.delisting_tx.clone()
+    }
+
+    /// Sender for trading status notices (instruments refresh task)
+    pub fn status_sender(&self) -> mpsc::Sender<StatusNotice> {
+        self.status_tx.clone()
+    }
+
+    /// Coordinate legs with a peer instance; local-venue tickers go to `out`
+    ///
+    /// Execution stays fenced until the peer has joined.
+    pub fn set_peer(&mut self, guard: PeerGuard, out: mpsc::Sender<(Exchange, TickerData)>) {
+        self.peer = Some(guard);
+        self.peer_out = Some(out);
+    }
+
+    /// Sender for peer link events (peer link task)
+    pub fn peer_sender(&self) -> mpsc::Sender<PeerEvent> {
+        self.peer_tx.clone()
+    }
+
+    /// Run as one side of a hot-standby pair; state goes to `out` while active
+    ///
+    /// Returns the role announcements for the standby link's heartbeats.
+    pub fn set_failover(&mut self, guard: FailoverGuard, out: mpsc::Sender<Replication>, snapshot_interval: Duration) -> watch::Receiver<Contact> {
+        let (tx, rx) = watch::channel(guard.contact());
+        self.next_snapshot = guard.is_active().then(Instant::now);
+        self.failover = Some(guard);
+        self.failover_contact = Some(tx);
+        self.replica_out = Some(out);
+        self.snapshot_interval = snapshot_interval;
+        rx
+    }
+
+    /// Sender for standby link events (standby link task)
+    pub fn standby_sender(&self) -> mpsc::Sender<StandbyEvent> {
+        self.standby_tx.clone()
+    }
+
+    /// Sender for private stream reports (venue private stream tasks)
+    pub fn user_stream_sender(&self) -> mpsc::Sender<UserStreamReport> {
+        self.user_tx.clone()
+    }
+
+    /// Send completed episodes and fills to the history sink
+    pub fn set_history_sink(&mut self, tx: mpsc::Sender<HistoryEvent>) {
+        self.history_tx = Some(tx);
+    }
+
+    /// Number episodes after the highest id in the history store
+    pub fn resume_episode_ids(&mut self, last_id: u64) {
+        self.episodes.resume_after(last_id);
+    }
+
+    /// Send opportunity openings and closes to the webhook sink
+    pub fn set_webhook_sink(&mut self, tx: mpsc::Sender<Alert>) {
+        self.webhook_tx = Some(tx);
+    }
+
+    /// Report message-loop progress to the watchdog
+    pub fn set_heartbeat(&mut self, heartbeat: Heartbeat) {
+        self.heartbeat = Some(heartbeat);
+    }
+
+    /// Queue record for the history sink (dropped if the writer lags)
+    fn record_history(&self, event: HistoryEvent) {
+        if let Some(tx) = &self.history_tx {
+            if let Err(e) = tx.try_send(event) {
+                self.metrics.debug().record_history_dropped();
+                tracing::warn!("History sink full or closed, dropped {:?}", e.into_inner());
+            }
+        }
+    }
+
+    /// Log `alert` within the alert log's budget and hand it to the webhook sink
+    fn emit_alert(&self, alert: &Alert, size: Option<&SizeDecision>) {
+        let now = Instant::now();
+        for summary in self.alert_limit.take_summaries(ALERT_LOG_CHANNEL, now) {
+            tracing::warn!(
+                "{} similar {} alerts suppressed over {:?}",
+                summary.count,
+                summary.kind,
+                now.saturating_duration_since(summary.since)
+            );
+        }
+        if let Admission::Send { suppressed } = self.alert_limit.admit(ALERT_LOG_CHANNEL, alert.kind(), now) {
+            publish_alert(alert, size, suppressed);
+        }
+        self.record_webhook(alert);
+    }
+
+    fn record_webhook(&self, alert: &Alert) {
+        if matches!(alert, Alert::Update { .. }) {
+            return;
+        }
+        if let Some(tx) = &self.webhook_tx {
+            if tx.try_send(*alert).is_err() {
+                tracing::warn!("Webhook sink full or closed, dropped {:?}", alert);
+            }
+        }
+    }
+
+    /// Completed opportunity episodes per symbol since startup
+    pub fn episode_counts(&self) -> Vec<(Symbol, u64)> {
+        self.episodes.completed_counts()
+    }
+
+    /// Episode currently open on `symbol`
+    pub fn open_episode(&self, symbol: Symbol) -> Option<&Episode> {
+        self.episodes.open_episode(symbol)
+    }
+
+    /// Net positions from fills
+    pub fn positions(&self) -> &PositionBook {
+        &self.positions
+    }
+
+    /// Whether symbol is scheduled for delisting
+    pub fn is_delisted(&self, symbol: Symbol) -> bool {
+        self.delisted.contains(&symbol)
+    }
+
+    /// Shared symbol presence (listings are filled by discovery)
+    pub fn presence(&self) -> Arc<SymbolPresence> {
+        self.presence.clone()
+    }
+
+    /// Shared pause and shutdown switch (toggled by /api/pause, /api/resume
+    /// and /api/shutdown)
+    pub fn control(&self) -> Arc<EngineControl> {
+        self.control.clone()
+    }
+
+    /// Summary of the run so far (written on shutdown)
+    pub async fn session_report(&self) -> SessionReport {
+        let opportunities = self.journal.read().await.total();
+        SessionReport::collect(&self.metrics, opportunities, self.positions.fills(), self.positions.realized_pnl())
+    }
+
+    /// Get opportunity journal reference
+    pub fn journal(&self) -> Arc<RwLock<OpportunityJournal>> {
+        self.journal.clone()
+    }
+
+    /// Get metrics collector reference
+    pub fn metrics(&self) -> Arc<MetricsCollector> {
+        self.metrics.clone()
+    }
+
+    /// Add exchange feed; `factory` builds a fresh client for every (re)start
+    pub fn add_exchange(&mut self, factory: impl Fn() -> ExchangeClient + Send + Sync + 'static) {
+        self.supervisor.add(Arc::new(factory));
+    }
+
+    /// Set feed restart policy
+    pub fn set_supervision_config(&mut self, config: &SupervisionConfig) {
+        self.supervisor.set_policy(RestartPolicy::new(config));
+        self.supervisor.set_flood_limit(config.max_messages_per_sec);
+        self.supervisor.set_max_connections(Some(config.max_connections_per_venue));
+        self.supervisor.set_circuit_breaker(CircuitBreakerConfig {
+            failure_threshold: config.circuit_failure_threshold,
+            open_duration: Duration::from_secs(config.circuit_open_secs),
+        });
+    }
+
+    /// Set feed → engine channel capacity (applies on the next run)
+    pub fn set_queue_capacity(&mut self, capacity: usize) {
+        self.queue_capacity = capacity.max(1);
+    }
+
+    /// Set the loop iteration budget (zero disables stall detection)
+    pub fn set_stall_budget(&mut self, budget: Duration) {
+        self.stall = StallDetector::new(budget);
+    }
+
+    /// Set feed restart policy directly
+    pub fn set_restart_policy(&mut self, policy: RestartPolicy) {
+        self.supervisor.set_policy(policy);
+    }
+
+    /// Add strategy (called in registration order for every event)
+    pub fn add_strategy(&mut self, strategy: Box<dyn Strategy>) {
+        tracing::info!("Registered strategy: {}", strategy.name());
+        self.allocator.add_unpartitioned();
+        self.strategies.push(strategy);
+    }
+
+    /// Add strategy instance trading within `budget`
+    ///
+    /// Fails if the instance name is taken or the capital allocated over
+    /// all instances would exceed the sizing account balance.
+    pub fn add_strategy_instance(&mut self, strategy: Box<dyn Strategy>, budget: StrategyBudget) -> Result<()> {
+        tracing::info!(
+            "Registered strategy instance {} ({}): capital {}, max order {}, max per symbol {}",
+            budget.name,
+            strategy.name(),
+            budget.capital,
+            budget.max_order_notional,
+            budget.max_symbol_notional
+        );
+        self.allocator.add(budget, self.sizing.account_balance())?;
+        self.strategies.push(strategy);
+        Ok(())
+    }
+
+    /// Capital partitions of strategy instances
+    pub fn allocator(&self) -> &CapitalAllocator {
+        &self.allocator
+    }
+
+    /// PnL attribution per strategy, symbol and episode
+    pub fn attribution(&self) -> &PnlAttribution {
+        &self.attribution
+    }
+
+    /// Name an order origin is reported under
+    fn origin_name(&self, origin: OrderOrigin) -> String {
+        match origin {
+            // Instances report under their configured name
+            OrderOrigin::Strategy(idx) => self
+                .allocator
+                .budget(idx)
+                .map(|b| b.name.clone())
+                .or_else(|| self.strategies.get(idx).map(|s| s.name().to_string()))
+                .unwrap_or_else(|| format!("strategy-{}", idx)),
+            OrderOrigin::Manual => "manual".to_string(),
+            OrderOrigin::Flatten => "flatten".to_string(),
+        }
+    }
+
+    /// Publish realized PnL and fees per strategy, symbol and recent episode
+    fn publish_pnl_attribution(&self) {
+        let bucket = |name: String, stats: &PnlStats| PnlBucketSnapshot {
+            name,
+            fills: stats.fills,
+            notional: stats.notional.to_f64(),
+            realized_pnl: stats.realized.to_f64(),
+            fees: stats.fees.to_f64(),
+            net_pnl: stats.net().to_f64(),
+        };
+        let by_net = |rows: &mut Vec<PnlBucketSnapshot>| rows.sort_by(|a, b| b.net_pnl.total_cmp(&a.net_pnl));
+        let mut strategies: Vec<PnlBucketSnapshot> = self
+            .attribution
+            .origins()
+            .map(|(origin, stats)| bucket(origin.map_or_else(|| "unattributed".to_string(), |o| self.origin_name(o)), stats))
+            .collect();
+        by_net(&mut strategies);
+        let mut symbols: Vec<PnlBucketSnapshot> =
+            self.attribution.symbols().map(|(symbol, stats)| bucket(symbol.as_str().to_string(), stats)).collect();
+        by_net(&mut symbols);
+        let episodes = self
+            .attribution
+            .episodes()
+            .rev()
+            .map(|(id, symbol, stats)| EpisodePnlSnapshot {
+                id,
+                symbol: symbol.as_str().to_string(),
+                fills: stats.fills,
+                notional: stats.notional.to_f64(),
+                realized_pnl: stats.realized.to_f64(),
+                fees: stats.fees.to_f64(),
+                net_pnl: stats.net().to_f64(),
+            })
+            .collect();
+        self.metrics.set_pnl_attribution(PnlAttributionSnapshot { strategies, symbols, episodes });
+    }
+
+    /// Deliver fill to all strategies and handle resulting actions
+    pub fn dispatch_fill(&mut self, fill: &Fill) {
+        let realized = self.positions.apply_fill(fill);
+        let tag = self.attribution.on_fill(fill, realized);
+        self.publish_pnl_attribution();
+        if self.failover.as_ref().is_some_and(|f| f.is_active()) {
+            if let Some(out) = &self.replica_out {
+                // Dropped if the link lags; the next snapshot carries it
+                let _ = out.try_send(Replication::Fill(*fill));
+            }
+        }
+        if self.fill_quality.on_fill(fill).is_some() {
+            self.publish_execution_quality();
+        }
+        self.record_history(HistoryEvent::Execution {
+            fill: *fill,
+            strategy: tag.origin.map(|origin| self.origin_name(origin)),
+            episode: tag.episode,
+        });
+        for i in 0..self.strategies.len() {
+            let actions = self.strategies[i].on_fill(fill);
+            self.handle_actions(i, actions);
+        }
+    }
+
+    /// Handle actions returned by strategy at index
+    /// Orders go through the execution engine (throttled); cancels are logged only.
+    fn handle_actions(&mut self, strategy_idx: usize, mut actions: Vec<Action>) {
+        if actions.is_empty() {
+            return;
+        }
+        // Legs on the slower venue go first so both reach their books together
+        // (a batch with cancels keeps the strategy's order)
+        if actions.iter().all(|a| matches!(a, Action::PlaceOrder(_))) {
+            let latency = &self.order_latency;
+            actions.sort_by_key(|a| match a {
+                Action::PlaceOrder(order) => std::cmp::Reverse(latency.estimate(order.exchange)),
+                Action::CancelAll { .. } => std::cmp::Reverse(Duration::ZERO),
+            });
+        }
+        let (decided, decided_ns) = (Instant::now(), clock::coarse_unix_nanos());
+        for action in actions {
+            // Instances log under their configured name
+            let name = self
+                .allocator
+                .budget(strategy_idx)
+                .map_or_else(|| self.strategies[strategy_idx].name(), |b| b.name.as_str());
+            match action {
+                Action::PlaceOrder(order) => {
+                    tracing::info!(
+                        "[{}] PlaceOrder {:?} {} {:?} qty={} price={:?}",
+                        name,
+                        order.exchange,
+                        order.symbol.as_str(),
+                        order.side,
+                        order.quantity,
+                        order.price
+                    );
+                    let outcome = match self.allocator.check(strategy_idx, &order) {
+                        Ok(price) => {
+                            let outcome = self.route_order(&order, OrderOrigin::Strategy(strategy_idx));
+                            if outcome == LiveOutcome::Submitted {
+                                self.allocator.commit(strategy_idx, &order, price);
+                                self.budget.on_sent(order.symbol, order.exchange, order.side, decided, decided_ns, Instant::now());
+                            }
+                            outcome
+                        }
+                        Err(rejected) => {
+                            tracing::debug!("Suppressed {} order of {}: {:?}", order.symbol.as_str(), name, rejected);
+                            self.metrics.record_suppressed_risk();
+                            LiveOutcome::Suppressed(rejected.reason())
+                        }
+                    };
+                    if let Some(shadow) = &mut self.shadow {
+                        shadow.record(&order, outcome, clock::coarse_unix_ms());
+                    }
+                }
+                Action::CancelAll { exchange, symbol } => {
+                    tracing::info!("[{}] CancelAll {:?} {}", name, exchange, symbol.as_str())
+                }
+            }
+        }
+    }
+
+    /// Route an operator order like a strategy order and reply with the outcome
+    ///
+    /// Manual orders have no capital partition and no execution budget.
+    pub fn handle_manual_order(&mut self, manual: ManualOrder) {
+        let ManualOrder { order, reply } = manual;
+        tracing::warn!(
+            "[manual] PlaceOrder {:?} {} {:?} qty={} price={:?}",
+            order.exchange,
+            order.symbol.as_str(),
+            order.side,
+            order.quantity,
+            order.price
+        );
+        let outcome = self.route_order(&order, OrderOrigin::Manual);
+        if let Some(shadow) = &mut self.shadow {
+            shadow.record(&order, outcome, clock::coarse_unix_ms());
+        }
+        let _ = reply.send(outcome);
+    }
+
+    /// Start closing positions for an operator flatten request
+    ///
+    /// Close orders bypass the live gates and throttling, like delisting
+    /// flattens; in read-only mode none are sent and the report shows the
+    /// positions still open.
+    pub fn start_flatten(&mut self, request: FlattenRequest) {
+        tracing::warn!("[manual] Flatten {}", request.target.name());
+        let now = Instant::now();
+        let orders = self.flattener.start(request, &self.positions, &self.reported, now);
+        self.submit_flatten_orders(&orders, now);
+    }
+
+    /// Re-check flatten jobs due at `now`, re-sending residual closes
+    pub fn verify_flatten(&mut self, now: Instant) {
+        let mut orders = Vec::new();
+        for report in self.flattener.poll(&self.positions, &self.reported, now, &mut orders) {
+            let open = report.legs.iter().filter(|leg| !leg.remaining.is_zero()).count();
+            match report.status {
+                FlattenStatus::Verified => tracing::warn!(
+                    "Flatten {} done: {} positions closed after {} rounds in {:?}",
+                    report.target.name(),
+                    report.legs.len(),
+                    report.attempts,
+                    report.elapsed
+                ),
+                FlattenStatus::Unverified => tracing::error!(
+                    "ALERT: flatten {} unverified: {} positions closed by fills after {} rounds, not confirmed by the venues",
+                    report.target.name(),
+                    report.legs.len(),
+                    report.attempts
+                ),
+                FlattenStatus::Incomplete => tracing::error!(
+                    "ALERT: flatten {} incomplete after {} rounds: {} of {} positions still open",
+                    report.target.name(),
+                    report.attempts,
+                    open,
+                    report.legs.len()
+                ),
+            }
+        }
+        self.submit_flatten_orders(&orders, now);
+    }
+
+    fn submit_flatten_orders(&mut self, orders: &[OrderRequest], now: Instant) {
+        if self.failover.as_ref().is_some_and(|f| !f.may_execute(now)) {
+            if !orders.is_empty() {
+                tracing::warn!("Dropped {} flatten orders: standby or failover lease expired", orders.len());
+            }
+            return;
+        }
+        let Some(execution) = &mut self.execution else {
+            if !orders.is_empty() {
+                tracing::warn!("Dropped {} flatten orders: read-only mode", orders.len());
+            }
+            return;
+        };
+        for order in orders {
+            if execution.submit_flatten(order, now) == ExecutionOutcome::Submitted {
+                let now_ms = clock::coarse_unix_ms();
+                self.fill_quality.on_submit(order, now_ms);
+                self.attribution.on_submit(order, OrderOrigin::Flatten, self.episodes.open_id(order.symbol), now_ms);
+            }
+        }
+    }
+
+    /// Pass an order through the live gates and the execution engine
+    fn route_order(&mut self, order: &OrderRequest, origin: OrderOrigin) -> LiveOutcome {
+        if self.control.is_paused() {
+            tracing::debug!("Suppressed {} order: engine paused", order.symbol.as_str());
+            self.metrics.record_suppressed_paused();
+            return LiveOutcome::Suppressed("paused");
+        }
+        if !self.control.is_symbol_enabled(order.symbol.as_str()) {
+            tracing::debug!("Suppressed {} order: execution disabled for symbol", order.symbol.as_str());
+            self.metrics.record_suppressed_disabled();
+            return LiveOutcome::Suppressed("disabled");
+        }
+        if self.delisted.contains(&order.symbol) {
+            tracing::warn!("Rejected {} order: symbol is being delisted", order.symbol.as_str());
+            self.metrics.record_suppressed_delisted();
+            return LiveOutcome::Suppressed("delisted");
+        }
+        if self.suspended.contains(&order.symbol) {
+            tracing::debug!("Suppressed {} order: halted or in auction", order.symbol.as_str());
+            self.metrics.record_suppressed_halted();
+            return LiveOutcome::Suppressed("halted");
+        }
+        if self.focus.as_ref().is_some_and(|f| !f.is_focused(order.symbol)) {
+            tracing::debug!("Suppressed {} order: not focused", order.symbol.as_str());
+            self.metrics.record_suppressed_unfocused();
+            return LiveOutcome::Suppressed("unfocused");
+        }
+        if self.peer.as_ref().is_some_and(|peer| !peer.may_execute(order.exchange)) {
+            tracing::debug!("Suppressed {} order: {} leg not owned or peer lost", order.symbol.as_str(), order.exchange.name());
+            self.metrics.record_suppressed_peer();
+            return LiveOutcome::Suppressed("peer");
+        }
+        if self.failover.as_ref().is_some_and(|f| !f.may_execute(Instant::now())) {
+            tracing::debug!("Suppressed {} order: standby or failover lease expired", order.symbol.as_str());
+            self.metrics.record_suppressed_standby();
+            return LiveOutcome::Suppressed("standby");
+        }
+        if self.private_stream.is_frozen(order.exchange) {
+            tracing::debug!("Suppressed {} order: {} private stream lost", order.symbol.as_str(), order.exchange.name());
+            self.metrics.record_suppressed_private_stream();
+            return LiveOutcome::Suppressed("private-stream");
+        }
+        if !self.warmup.is_ready(order.symbol, Instant::now()) {
+            tracing::debug!("Suppressed {} order: warming up", order.symbol.as_str());
+            self.metrics.record_suppressed_warmup();
+            return LiveOutcome::Suppressed("warmup");
+        }
+        let Some(execution) = &mut self.execution else {
+            tracing::debug!("Dropped {} order: read-only mode", order.symbol.as_str());
+            self.metrics.record_suppressed_read_only();
+            return LiveOutcome::Suppressed("read-only");
+        };
+        match execution.submit(order, Instant::now()) {
+            ExecutionOutcome::Submitted => {
+                let now_ms = clock::coarse_unix_ms();
+                self.fill_quality.on_submit(order, now_ms);
+                self.attribution.on_submit(order, origin, self.episodes.open_id(order.symbol), now_ms);
+                LiveOutcome::Submitted
+            }
+            ExecutionOutcome::Suppressed(Suppressed::Cooldown { .. }) => LiveOutcome::Suppressed("cooldown"),
+            ExecutionOutcome::Suppressed(Suppressed::RateLimit) => LiveOutcome::Suppressed("rate-limit"),
+        }
+    }
+
+    /// Size opportunity from current book state on both legs
+    /// Buy leg takes the ask on `long_ex`, sell leg hits the bid on `short_ex`.
+    /// The price is converted to USDT, the currency of balances and limits.
+    fn size_opportunity(&self, state: &mut SymbolState, event: &SpreadEvent) -> Option<SizeDecision> {
+        // Only top-of-book is tracked, so top-N quantity is level 1
+        let buy = *state.ticker(event.long_ex)?;
+        let sell = *state.ticker(event.short_ex)?;
+        Some(self.sizing.size(&SizingInputs {
+            price: self.rates.to_usdt(buy.ask_price, QuoteCurrency::of(event.symbol)),
+            buy_book_qty: buy.ask_qty,
+            sell_book_qty: sell.bid_qty,
+            balance: self.sizing.account_balance(),
+            volatility: state.spread_range().as_fraction(),
+            fill_probability: state.fill_probability(event.long_ex, event.short_ex, self.order_latency.pair_estimate(event.long_ex, event.short_ex)),
+        }))
+    }
+
+    /// Start the engine and all components
+    pub async fn run(&mut self, symbols: &[Symbol]) -> Result<()> {
+        if self.running {
+            return Ok(());
+        }
+        self.running = true;
+
+        tracing::info!("Starting AppEngine with {} exchanges", self.supervisor.len());
+        self.presence.set_subscribed(symbols);
+
+        // Each feed is a supervised actor task (connect, subscribe, forward),
+        // rebuilt from its factory when it exits. Messages are aggregated over
+        // an MPSC channel and a single loop updates the tracker (actor model),
+        // which avoids lock contention on the tracker.
+        let (tx, mut rx) = tokio::sync::mpsc::channel(self.queue_capacity);
+        self.metrics.debug().set_queue_capacity(self.queue_capacity);
+
+        // Feeds apply focus changes (stream upgrades) between messages
+        if self.focus.is_some() {
+            self.focus_tx = Some(watch::Sender::new(Vec::new()));
+        }
+
+        let venues = Arc::new(watch::Sender::new([0; Exchange::COUNT]));
+        let mut venues_rx = venues.subscribe();
+        let links = FeedLinks {
+            tx,
+            metrics: self.metrics.clone(),
+            focus: self.focus_tx.as_ref().map(|tx| tx.subscribe()),
+            delisted: self.delisted_tx.subscribe(),
+            venues,
+        };
+        // Only feed tasks hold senders now, so the loop below ends once every
+        // feed has stopped for good
+        if let Err(e) = self.supervisor.spawn(symbols, links) {
+            self.running = false;
+            return Err(e);
+        }
+
+        // 3. Process Aggregated Messages
+        tracing::info!("Engine running. Processing messages...");
+        
+        let mut delistings = self.delisting_rx.take();
+        let mut statuses = self.status_rx.take();
+        let mut manual = self.control.orders().take_receiver();
+        let mut peers = self.peer.is_some().then(|| self.peer_rx.take()).flatten();
+        let mut standby = self.failover.is_some().then(|| self.standby_rx.take()).flatten();
+        let mut user_reports = self.user_rx.take();
+        let control = self.control.clone();
+        loop {
+            let paper_ack = self.execution.as_ref().and_then(|e| e.next_ack());
+            let deadline = [self.budget.next_deadline(), self.flattener.next_deadline(), self.failover_deadline(), paper_ack]
+                .into_iter()
+                .flatten()
+                .min();
+            let branch = tokio::select! {
+                msg = rx.recv() => {
+                    let Some((connection, arrived, msg)) = msg else {
+                        break;
+                    };
+                    self.stall.begin(Instant::now());
+                    self.metrics.debug().record_queue_depth(rx.len());
+                    self.roll_shadow(clock::coarse_unix_ms());
+                    self.handle_message(connection, arrived, msg).await;
+                    self.stall.lap("handle_message", Instant::now());
+                    self.deliver_paper_acks(Instant::now());
+                    self.settle_attempts(Instant::now()).await;
+                    self.stall.lap("settle_attempts", Instant::now());
+                    self.metrics.processing().record(arrived.elapsed());
+                    self.rescore_focus(Instant::now()).await;
+                    self.stall.lap("rescore_focus", Instant::now());
+                    if let Some(heartbeat) = &self.heartbeat {
+                        heartbeat.beat();
+                    }
+                    "message"
+                }
+                Some(notice) = async {
+                    match delistings.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.handle_delisting(notice);
+                    "delisting"
+                }
+                Some(notice) = async {
+                    match statuses.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.apply_trading_status(notice).await;
+                    "trading_status"
+                }
+                Some(event) = async {
+                    match peers.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.apply_peer_event(event).await;
+                    "peer"
+                }
+                Some(event) = async {
+                    match standby.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.apply_standby_event(event).await;
+                    "standby"
+                }
+                Some(report) = async {
+                    match user_reports.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.apply_user_report(report);
+                    "private_stream"
+                }
+                Some(command) = async {
+                    match manual.as_mut() {
+                        Some(rx) => rx.recv().await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    match command {
+                        ManualCommand::Order(order) => self.handle_manual_order(order),
+                        ManualCommand::Flatten(request) => self.start_flatten(request),
+                    }
+                    "manual"
+                }
+                _ = async {
+                    match deadline {
+                        Some(deadline) => tokio::time::sleep_until(deadline.into()).await,
+                        None => std::future::pending().await,
+                    }
+                } => {
+                    self.stall.begin(Instant::now());
+                    self.deliver_paper_acks(Instant::now());
+                    self.stall.lap("deliver_paper_acks", Instant::now());
+                    self.settle_attempts(Instant::now()).await;
+                    self.stall.lap("settle_attempts", Instant::now());
+                    self.verify_flatten(Instant::now());
+                    self.stall.lap("verify_flatten", Instant::now());
+                    self.check_failover(Instant::now()).await;
+                    self.stall.lap("check_failover", Instant::now());
+                    "deadline"
+                }
+                Ok(()) = venues_rx.changed() => {
+                    self.stall.begin(Instant::now());
+                    let feeds = *venues_rx.borrow_and_update();
+                    self.apply_venue_feeds(feeds).await;
+                    "venue_feeds"
+                }
+                _ = control.shutdown_requested() => {
+                    tracing::warn!("Shutdown requested, stopping engine");
+                    break;
+                }
+            };
+            if let Some(stall) = self.stall.finish(branch, Instant::now()) {
+                self.metrics.record_engine_stall(stall.duration);
+                tracing::warn!(
+                    "Engine loop stalled {:.1}ms in {} (budget {:?}), folded stages (us):\n{}",
+                    stall.duration.as_secs_f64() * 1e3,
+                    stall.branch,
+                    self.stall.budget(),
+                    stall.folded
+                );
+            }
+        }
+        
+        Ok(())
+    }
+
+    /// Mark venues down (tickers stale) or back up as their feeds connect
+    ///
+    /// A venue that has never connected is not reported down.
+    pub async fn apply_venue_feeds(&mut self, feeds: VenueFeeds) {
+        for exchange in Exchange::ALL {
+            let up = feeds[exchange.index()] != 0;
+            if up == self.venues_up[exchange.index()] {
+                continue;
+            }
+            self.venues_up[exchange.index()] = up;
+            let affected = self.tracker.write().await.set_venue_down(exchange, !up);
+            if up {
+                tracing::info!("{} back up: spreads resume per symbol on its next ticker", exchange.name());
+            } else {
+                tracing::warn!("{} down: {} symbols stale, spread generation suppressed", exchange.name(), affected);
+            }
+        }
+    }
+
+    /// Report a venue's acknowledgement of an order leg (measures its round trip)
+    ///
+    /// Every acknowledgement, simulated for paper orders or from a venue,
+    /// goes through here so /api/latency and the budget see the same data.
+    pub fn on_order_ack(&mut self, exchange: Exchange, symbol: Symbol) {
+        if let Some(round_trip) = self.budget.on_ack(symbol, exchange, Instant::now()) {
+            self.record_order_latency(exchange, round_trip);
+        }
+    }
+
+    /// Acknowledge paper orders whose simulated gateway response is due at `now`
+    fn deliver_paper_acks(&mut self, now: Instant) {
+        let Some(execution) = &mut self.execution else { return };
+        for (exchange, symbol) in execution.take_acks(now) {
+            self.on_order_ack(exchange, symbol);
+        }
+    }
+
+    /// Journal finished execution attempts, aborting those over budget at `now`
+    ///
+    /// Legs of an aborted attempt still unacknowledged are cancelled and the
+    /// symbol's open episode is marked aborted.
+    pub async fn settle_attempts(&mut self, now: Instant) {
+        let finished = self.budget.take_finished(now);
+        if finished.is_empty() {
+            return;
+        }
+        for attempt in &finished {
+            if attempt.outcome == AttemptOutcome::Aborted {
+                tracing::warn!(
+                    "Aborted {} execution after {:?}: legs {:?}",
+                    attempt.symbol.as_str(),
+                    attempt.elapsed,
+                    attempt.legs
+                );
+                if let Some(execution) = &mut self.execution {
+                    for exchange in attempt.unacked() {
+                        execution.cancel(exchange, attempt.symbol);
+                    }
+                }
+                self.episodes.abort(attempt.symbol);
+            }
+        }
+        let mut journal = self.journal.write().await;
+        for attempt in finished {
+            journal.record_attempt(attempt);
+        }
+    }
+
+    /// Apply a health report from a venue's private user-data stream
+    ///
+    /// On loss new orders on the venue are frozen and, with the
+    /// `cancel_all` action, its open orders are cancelled; orders resume
+    /// when the stream is healthy again.
+    pub fn on_private_stream(&mut self, exchange: Exchange, healthy: bool) {
+        match self.private_stream.on_health(exchange, healthy, Instant::now()) {
+            Some(StreamTransition::Lost { action }) => {
+                tracing::error!(
+                    "ALERT: {} private stream lost, fills unseen; new orders frozen ({:?})",
+                    exchange.name(),
+                    action
+                );
+                // Positions may change unseen until the stream is back
+                self.reported.forget(exchange);
+                if action == PrivateStreamAction::CancelAll {
+                    if let Some(execution) = &mut self.execution {
+                        execution.cancel_all(exchange);
+                    }
+                }
+            }
+            Some(StreamTransition::Restored { outage }) => {
+                tracing::warn!("{} private stream restored after {:?}: orders resume", exchange.name(), outage);
+            }
+            None => {}
+        }
+    }
+
+    /// Apply a report from a venue's private stream
+    ///
+    /// Health goes to the private stream guard, executions are dispatched as
+    /// fills, order updates acknowledge the leg they belong to (a reject is
+    /// the venue's answer too) and positions verify flatten jobs. Balances
+    /// are not used yet.
+    pub fn apply_user_report(&mut self, report: UserStreamReport) {
+        let event = match report {
+            UserStreamReport::Health { exchange, healthy } => return self.on_private_stream(exchange, healthy),
+            UserStreamReport::Event(event) => event,
+        };
+        match event {
+            UserEvent::Execution(execution) => self.dispatch_fill(&Fill {
+                exchange: execution.exchange,
+                symbol: execution.symbol,
+                side: execution.side,
+                price: execution.price,
+                quantity: execution.quantity,
+                timestamp: execution.timestamp,
+            }),
+            UserEvent::Order(order) => match order.state {
+                OrderState::New | OrderState::PartiallyFilled | OrderState::Filled => {
+                    self.on_order_ack(order.exchange, order.symbol)
+                }
+                OrderState::Rejected => {
+                    tracing::warn!("{} rejected {} order {}", order.exchange.name(), order.symbol.as_str(), order.order_id);
+                    self.on_order_ack(order.exchange, order.symbol);
+                }
+                OrderState::Cancelled | OrderState::Expired => {
+                    tracing::debug!("{} {} order {} {:?}", order.exchange.name(), order.symbol.as_str(), order.order_id, order.state)
+                }
+            },
+            UserEvent::Position(position) => self.reported.apply(&position),
+            UserEvent::Balance(_) => {}
+        }
+    }
+
+    /// Apply a peer link event: leg ownership changes and peer tickers
+    pub async fn apply_peer_event(&mut self, event: PeerEvent) {
+        let Some(peer) = &mut self.peer else { return };
+        match event {
+            PeerEvent::Joined { node_id, venues } => {
+                let PeerTransition::Joined { node_id, conceded } = peer.on_joined(&node_id, &venues, Instant::now()) else {
+                    return;
+                };
+                if conceded.is_empty() {
+                    tracing::info!("Peer {} joined with {:?}: executing {:?} legs", node_id, venues, peer.local_venues());
+                } else {
+                    tracing::error!("ALERT: peer {} also claims {:?}; standing down on them", node_id, conceded);
+                }
+            }
+            PeerEvent::Lost => {
+                if let Some(PeerTransition::Lost { up }) = peer.on_lost(Instant::now()) {
+                    tracing::error!("ALERT: peer lost after {:?}; execution fenced until it rejoins", up);
+                }
+            }
+            PeerEvent::Ticker(exchange, ticker) => {
+                self.handle_message(PEER_CONNECTION, Instant::now(), ExchangeMessage::Ticker(exchange, ticker)).await;
+            }
+        }
+    }
+
+    /// Next failover check or state snapshot
+    fn failover_deadline(&self) -> Option<Instant> {
+        let failover = self.failover.as_ref()?;
+        [failover.next_deadline(), self.next_snapshot].into_iter().flatten().min()
+    }
+
+    /// Apply a standby link event: role changes and replicated state
+    ///
+    /// Replicated state is only applied while passive; the active side's own
+    /// book is authoritative.
+    pub async fn apply_standby_event(&mut self, event: StandbyEvent) {
+        let Some(failover) = &mut self.failover else { return };
+        match event {
+            StandbyEvent::Contact(remote) => {
+                let transition = failover.on_contact(remote, Instant::now());
+                self.on_failover(transition);
+            }
+            StandbyEvent::Snapshot(state) if !failover.is_active() => {
+                self.positions.restore(&state.positions, state.realized, state.fills);
+                self.replica_legs = state.open_legs;
+                for (exchange, ticker) in state.tickers {
+                    self.handle_message(REPLICA_CONNECTION, Instant::now(), ExchangeMessage::Ticker(exchange, ticker)).await;
+                }
+            }
+            StandbyEvent::Fill(fill) if !failover.is_active() => {
+                self.positions.apply_fill(&fill);
+            }
+            StandbyEvent::Snapshot(_) | StandbyEvent::Fill(_) => {
+                tracing::warn!("Ignored replicated state while active (epoch {})", failover.epoch());
+            }
+            StandbyEvent::Lost => tracing::warn!("Standby link lost; failover timed from the last heartbeat"),
+        }
+    }
+
+    /// Run due failover checks and send a state snapshot if one is due
+    pub async fn check_failover(&mut self, now: Instant) {
+        let Some(failover) = &mut self.failover else { return };
+        let transition = failover.check(now);
+        self.on_failover(transition);
+        if self.next_snapshot.is_some_and(|at| at <= now) {
+            self.next_snapshot = Some(now + self.snapshot_interval.max(Duration::from_millis(1)));
+            let state = ReplicaState {
+                positions: self.positions.export(),
+                realized: self.positions.realized_pnl(),
+                fills: self.positions.fills(),
+                open_legs: self.budget.unacked_legs(),
+                tickers: self.tracker.read().await.latest_tickers(),
+            };
+            if let Some(out) = &self.replica_out {
+                // Dropped if the link lags; the next snapshot supersedes it
+                let _ = out.try_send(Replication::Snapshot(state));
+            }
+        }
+    }
+
+    /// Log a failover transition and act on role changes
+    fn on_failover(&mut self, transition: Option<FailoverTransition>) {
+        let Some(failover) = &self.failover else { return };
+        let active = failover.is_active();
+        if let Some(tx) = &self.failover_contact {
+            tx.send_replace(failover.contact());
+        }
+        match transition {
+            Some(FailoverTransition::Paired { active: remote_active }) => {
+                tracing::info!("Failover pair up: this instance {}, peer {}", role_name(active), role_name(remote_active));
+            }
+            Some(FailoverTransition::Promoted { epoch, silent }) => {
+                tracing::error!(
+                    "ALERT: active instance silent for {:?}; taking over execution (epoch {}), cancelling {} inherited legs",
+                    silent,
+                    epoch,
+                    self.replica_legs.len()
+                );
+                if let Some(execution) = &mut self.execution {
+                    for &(exchange, symbol) in &self.replica_legs {
+                        execution.cancel(exchange, symbol);
+                    }
+                }
+                self.replica_legs.clear();
+            }
+            Some(FailoverTransition::Demoted { epoch }) => {
+                tracing::error!("ALERT: another instance is active at epoch {}; standing down to standby", epoch);
+            }
+            Some(FailoverTransition::LeaseExpired) => {
+                tracing::error!("ALERT: standby silent past the lease; trading fenced until it is heard again");
+            }
+            Some(FailoverTransition::Unpaired { waited }) => {
+                tracing::warn!("No other instance heard {:?} after startup; trading unpaired", waited);
+            }
+            Some(FailoverTransition::LeaseRenewed { fenced }) => {
+                tracing::warn!("Standby heard again after {:?} fenced: trading resumes", fenced);
+            }
+            None => {}
+        }
+        // Only the active side snapshots
+        if active && self.next_snapshot.is_none() {
+            self.next_snapshot = Some(Instant::now());
+        } else if !active {
+            self.next_snapshot = None;
+        }
+    }
+
+    /// Apply a venue trading status change (instruments refresh or stream)
+    ///
+    /// While the symbol is halted or in auction on either venue its spreads
+    /// are suppressed and new orders in it rejected.
+    pub async fn apply_trading_status(&mut self, notice: StatusNotice) {
+        let StatusNotice { symbol, exchange, status } = notice;
+        let suspension = {
+            let mut tracker = self.tracker.write().await;
+            if !tracker.set_trading_status(symbol, exchange, status) {
+                return;
+            }
+            tracker.suspension(symbol)
+        };
+        if suspension.is_some() {
+            self.suspended.insert(symbol);
+            if status != TradingStatus::Trading {
+                tracing::warn!(
+                    "{} {} on {}: opportunities and execution suspended",
+                    symbol.as_str(),
+                    status.name(),
+                    exchange.name()
+                );
+            }
+        } else if self.suspended.remove(&symbol) {
+            tracing::info!("{} trading again on both venues: suspension lifted", symbol.as_str());
+        }
+    }
+
+    /// Act on a delisting notice (first notice per symbol only)
+    ///
+    /// Alerts, drops the symbol from focus, unsubscribes it on every feed,
+    /// flattens known positions and notifies strategies. New orders in the
+    /// symbol are rejected from then on.
+    pub fn handle_delisting(&mut self, notice: DelistingNotice) {
+        let symbol = notice.symbol;
+        if !self.delisted.insert(symbol) {
+            return;
+        }
+        tracing::error!(
+            "ALERT: {} scheduled for delisting on {:?} (status {}, delivery {:?}); unsubscribing and flattening",
+            symbol.as_str(),
+            notice.exchange,
+            notice.status,
+            notice.delivery_time_ms
+        );
+
+        if let Some(focus) = self.focus.as_mut() {
+            if focus.remove(symbol) {
+                self.metrics.set_focused_symbols(focus.len());
+                self.presence.set_focused(&focus.focused());
+                if let Some(tx) = &self.focus_tx {
+                    tx.send_replace(focus.focused());
+                }
+            }
+        }
+        self.delisted_tx.send_modify(|delisted| delisted.push(symbol));
+        self.presence.mark_delisted(symbol);
+
+        let orders = self.positions.flatten_orders(symbol);
+        self.submit_flatten_orders(&orders, Instant::now());
+
+        for i in 0..self.strategies.len() {
+            let actions = self.strategies[i].on_delisting(&notice);
+            self.handle_actions(i, actions);
+        }
+    }
+
+    /// Re-rank auto-focus if due and publish the focused set to feeds
+    async fn rescore_focus(&mut self, now: Instant) {
+        let Some(focus) = self.focus.as_mut() else {
+            return;
+        };
+        if now.saturating_duration_since(self.last_rescore) < focus.rescore_interval() {
+            return;
+        }
+        self.last_rescore = now;
+
+        let delisted = &self.delisted;
+        let scores: Vec<(Symbol, FixedPoint8)> = self
+            .tracker
+            .write()
+            .await
+            .get_all_stats()
+            .iter()
+            .filter(|stats| !delisted.contains(&stats.symbol))
+            .map(|stats| (stats.symbol, focus::opportunity_score(stats)))
+            .collect();
+        let change = focus.update(&scores, now);
+        if change.is_empty() {
+            return;
+        }
+
+        let names = |symbols: &[Symbol]| symbols.iter().map(|s| s.as_str()).collect::<Vec<_>>().join(",");
+        tracing::info!(
+            "Focus updated ({} symbols): +[{}] -[{}]",
+            focus.len(),
+            names(&change.promoted),
+            names(&change.demoted)
+        );
+        self.metrics.set_focused_symbols(focus.len());
+        self.presence.set_focused(&focus.focused());
+        if let Some(tx) = &self.focus_tx {
+            tx.send_replace(focus.focused());
+        }
+    }
+
+    /// Process one message from `connection` received at `arrived`
+    ///
+    /// Called by the run loop for every channel message; also usable directly
+    /// to drive the engine without live connections (replay, load tests).
+    pub async fn handle_message(&mut self, connection: ConnectionId, arrived: Instant, msg: ExchangeMessage) {
+        let feed = connection.index();
+        #[cfg(feature = "alloc-tracking")]
+        let _scope = crate::infrastructure::alloc_tracking::scope(
+            crate::infrastructure::alloc_tracking::Subsystem::Engine,
+        );
+        match self.dedup.check(feed, &msg, arrived) {
+            Verdict::Fresh => {}
+            Verdict::Duplicate { winner, lag } => {
+                self.metrics.feed_race().record(winner, feed, lag);
+                self.metrics.record_duplicate();
+                return;
+            }
+            Verdict::Stale => {
+                self.metrics.record_duplicate();
+                return;
+            }
+        }
+
+        tracing::debug!("Engine received message: {:?}", msg);
+        match msg {
+            ExchangeMessage::Ticker(exchange, ticker) => {
+                tracing::info!("Ticker received: {:?} from {:?}", ticker, exchange);
+                if connection != PEER_CONNECTION && self.peer.as_ref().is_some_and(|peer| peer.is_local(exchange)) {
+                    if let Some(out) = &self.peer_out {
+                        // Dropped if the link lags; the peer's next update supersedes it
+                        let _ = out.try_send((exchange, ticker));
+                    }
+                }
+                // Record metrics (cold path - don't block hot path)
+                match exchange {
+                    Exchange::Binance => self.metrics.record_binance_message(),
+                    Exchange::Bybit => self.metrics.record_bybit_message(),
+                }
+
+                // Quarantine glitched, locked and crossed quotes before strategies and spread history see them
+                if let Err(rejection) = self.tracker.write().await.screen_quote(&ticker, exchange) {
+                    tracing::warn!(
+                        "Quarantined {} quote from {:?}: {:?} (bid {} ask {})",
+                        ticker.symbol.as_str(),
+                        exchange,
+                        rejection,
+                        ticker.bid_price,
+                        ticker.ask_price
+                    );
+                    match rejection {
+                        QuoteRejection::Locked => self.metrics.record_locked_book(),
+                        QuoteRejection::Crossed => self.metrics.record_crossed_book(),
+                        _ => self.metrics.record_quarantined(),
+                    }
+                    return;
+                }
+                if let Some(shadow) = &mut self.shadow {
+                    shadow.observe_ticker(exchange, &ticker);
+                }
+                if self.execution.is_some() {
+                    self.fill_quality.observe_ticker(exchange, &ticker);
+                }
+                if self.allocator.is_partitioned() {
+                    self.allocator.observe_ticker(exchange, &ticker);
+                }
+                
+                for i in 0..self.strategies.len() {
+                    let actions = self.strategies[i].on_ticker(exchange, &ticker);
+                    self.handle_actions(i, actions);
+                }
+
+                if self.control.is_tracker_paused() {
+                    return;
+                }
+
+                // Update tracker (Warm Path), release lock before strategies run
+                let (event, size, edges) = {
+                    let mut tracker = self.tracker.write().await;
+                    let event = tracker.update(ticker, exchange);
+                    let (size, edges) = match &event {
+                        Some(e) if e.spread > OPPORTUNITY_SPREAD => (
+                            tracker.state_mut(e.symbol).and_then(|state| self.size_opportunity(state, e)),
+                            tracker.net_edges(e),
+                        ),
+                        _ => (None, None),
+                    };
+                    (event, size, edges)
+                };
+                if let Some(event) = event {
+                    self.warmup.record_sample(event.symbol);
+                    let warmup = self.warmup.status(Instant::now());
+                    self.metrics.set_warmup(warmup.active, warmup.remaining.as_secs(), warmup.warm_symbols);
+                    if !warmup.active {
+                        self.metrics.startup().observe(StartupStage::Warmup, Instant::now());
+                    }
+
+                    if let Some(episode) = self.episodes.observe(&event, OPPORTUNITY_SPREAD) {
+                        if let Some(alert) = self.alerts.on_episode_end(&episode) {
+                            self.emit_alert(&alert, None);
+                        }
+                        self.record_history(HistoryEvent::Episode(episode));
+                    }
+
+                    // Alert on significant spreads, conflated per episode
+                    if let Some(size) = size {
+                        if let Some(alert) = self.alerts.on_opportunity(&event, Instant::now()) {
+                            self.emit_alert(&alert, Some(&size));
+                        }
+                        self.journal.write().await.record(OpportunityRecord {
+                            symbol: event.symbol,
+                            spread: event.spread,
+                            long_ex: event.long_ex,
+                            short_ex: event.short_ex,
+                            timestamp: event.timestamp,
+                            size,
+                            edges,
+                        });
+                    } else {
+                        tracing::debug!("Spread updated: {} {:.4}%", event.symbol.as_str(), event.spread.percent_f64());
+                    }
+
+                    for i in 0..self.strategies.len() {
+                        if !self.allocator.accepts_spread(i, &event) {
+                            continue;
+                        }
+                        let actions = self.strategies[i].on_spread(&event);
+                        self.handle_actions(i, actions);
+                    }
+                } else {
+                    tracing::debug!("No arbitrage opportunity for this tick");
+                }
+            }
+            ExchangeMessage::Trade(exchange, trade) => {
+                tracing::debug!("Trade received from {:?}", exchange);
+                match exchange {
+                    Exchange::Binance => self.metrics.record_binance_message(),
+                    Exchange::Bybit => self.metrics.record_bybit_message(),
+                }
+                self.tracker.write().await.record_trade(&trade, exchange);
+
+                for i in 0..self.strategies.len() {
+                    let actions = self.strategies[i].on_trade(exchange, &trade);
+                    self.handle_actions(i, actions);
+                }
+            }
+            ExchangeMessage::MarkPrice(exchange, mark) => {
+                match exchange {
+                    Exchange::Binance => self.metrics.record_binance_message(),
+                    Exchange::Bybit => self.metrics.record_bybit_message(),
+                }
+                self.tracker.write().await.update_mark_price(mark, exchange);
+            }
+            ExchangeMessage::Liquidation(exchange, liquidation) => {
+                tracing::debug!(
+                    "Liquidation on {:?}: {} {:?} {} @ {}",
+                    exchange,
+                    liquidation.symbol.as_str(),
+                    liquidation.side,
+                    liquidation.quantity,
+                    liquidation.price
+                );
+                match exchange {
+                    Exchange::Binance => self.metrics.record_binance_message(),
+                    Exchange::Bybit => self.metrics.record_bybit_message(),
+                }
+                self.tracker.write().await.record_liquidation(liquidation);
+            }
+            ExchangeMessage::Status(notice) => {
+                self.apply_trading_status(notice).await;
+            }
+            ExchangeMessage::Heartbeat => {
+                // Heartbeat received - connection alive
+                tracing::debug!("Heartbeat received");
+            }
+            ExchangeMessage::Error(e) => {
+                self.metrics.record_exchange_error();
+                tracing::error!("Exchange error: [{:?}] {}", e.exchange, e.message);
+            }
+        }
+    }
+}
+
+/// Log an opportunity alert (`suppressed` similar ones dropped before it)
+fn publish_alert(alert: &Alert, size: Option<&SizeDecision>, suppressed: u64) {
+    let suppressed = if suppressed > 0 { format!(" ({} similar alerts suppressed)", suppressed) } else { String::new() };
+    match alert {
+        Alert::Opened(event) | Alert::Update { event, .. } => {
+            let size = size.map(|s| format!(" size={} ({:?}) p_fill={:.3}", s.quantity, s.constraint, s.fill_probability.to_f64()));
+            let update = match alert {
+                Alert::Update { peak, conflated, .. } => format!(" [update: peak {:.4}%, {} updates conflated]", peak.percent_f64(), conflated),
+                _ => String::new(),
+            };
+            tracing::info!(
+                "OPPORTUNITY: {} {:.4}% Buy {:?} Sell {:?}{}{}{}",
+                event.symbol.as_str(),
+                event.spread.percent_f64(),
+                event.long_ex,
+                event.short_ex,
+                size.unwrap_or_default(),
+                update,
+                suppressed
+            );
+        }
+        Alert::Closed(episode) => tracing::info!(
+            "OPPORTUNITY closed: {} Buy {:?} Sell {:?} peak {:.4}% over {} updates in {:.1}s{}{}",
+            episode.symbol.as_str(),
+            episode.long_ex,
+            episode.short_ex,
+            episode.peak_spread.percent_f64(),
+            episode.updates,
+            episode.end.saturating_sub(episode.start) as f64 / 1e9,
+            if episode.aborted { " (execution aborted)" } else { "" },
+            suppressed
+        ),
+    }
+}
+
+/// Failover role for logs
+fn role_name(active: bool) -> &'static str {
+    if active { "active" } else { "standby" }
+}
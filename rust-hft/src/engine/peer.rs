@@ -0,0 +1,160 @@
+//! Multi-region leg ownership
+//!
+//! With a peer instance deployed near the other venue, each instance
+//! executes only the legs on its own venues; the peer executes the rest.
+//! Leg ownership needs the peer to be reachable: during a partition neither
+//! side can tell a dead peer from a cut link, so taking over its legs could
+//! double-trade them. While the peer is lost this instance is fenced and
+//! executes nothing. A venue claimed by both nodes (misconfiguration) stays
+//! with the lower node id; the other stands down on it.
+
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::PeerConfig;
+use crate::{HftError, Result};
+use std::time::{Duration, Instant};
+
+/// Peer link state change
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PeerTransition {
+    /// Peer announced itself; `conceded` are venues both claim that it keeps
+    Joined { node_id: String, conceded: Vec<Exchange> },
+    /// Peer lost after `up`; execution fenced
+    Lost { up: Duration },
+}
+
+#[derive(Debug, Clone)]
+struct Peer {
+    node_id: String,
+    venues: [bool; Exchange::COUNT],
+    since: Instant,
+}
+
+/// Leg ownership and fencing for one instance
+#[derive(Debug, Clone)]
+pub struct PeerGuard {
+    node_id: String,
+    /// Venues whose legs this instance executes
+    local: [bool; Exchange::COUNT],
+    /// Connected peer (None = lost or never seen)
+    peer: Option<Peer>,
+}
+
+impl PeerGuard {
+    /// Guard from config (an error on unknown venues or a missing node id)
+    pub fn new(config: &PeerConfig) -> Result<Self> {
+        if config.node_id.is_empty() {
+            return Err(HftError::Config("peer.node_id must be set".to_string()));
+        }
+        Ok(Self {
+            node_id: config.node_id.clone(),
+            local: venue_mask(&config.venues)?,
+            peer: None,
+        })
+    }
+
+    #[inline]
+    pub fn node_id(&self) -> &str {
+        &self.node_id
+    }
+
+    /// Venues this instance claims, in Exchange order
+    pub fn local_venues(&self) -> Vec<Exchange> {
+        Exchange::ALL.into_iter().filter(|e| self.local[e.index()]).collect()
+    }
+
+    /// `exchange` is one of this instance's venues (its tickers go to the peer)
+    #[inline]
+    pub fn is_local(&self, exchange: Exchange) -> bool {
+        self.local[exchange.index()]
+    }
+
+    /// Peer announced itself with `venues`
+    pub fn on_joined(&mut self, node_id: &str, venues: &[Exchange], now: Instant) -> PeerTransition {
+        let mut claimed = [false; Exchange::COUNT];
+        for venue in venues {
+            claimed[venue.index()] = true;
+        }
+        self.peer = Some(Peer { node_id: node_id.to_string(), venues: claimed, since: now });
+        let conceded = self.local_venues().into_iter().filter(|e| self.concedes(*e)).collect();
+        PeerTransition::Joined { node_id: node_id.to_string(), conceded }
+    }
+
+    /// Peer link dropped or timed out (None if it was not connected)
+    pub fn on_lost(&mut self, now: Instant) -> Option<PeerTransition> {
+        let peer = self.peer.take()?;
+        Some(PeerTransition::Lost { up: now.saturating_duration_since(peer.since) })
+    }
+
+    /// Peer connected; while false every order is fenced
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.peer.is_some()
+    }
+
+    /// Both claim `exchange` and the peer wins the tie-break
+    fn concedes(&self, exchange: Exchange) -> bool {
+        self.peer
+            .as_ref()
+            .is_some_and(|peer| peer.venues[exchange.index()] && peer.node_id.as_str() < self.node_id.as_str())
+    }
+
+    /// This instance may send orders on `exchange` now
+    #[inline]
+    pub fn may_execute(&self, exchange: Exchange) -> bool {
+        self.is_local(exchange) && self.is_connected() && !self.concedes(exchange)
+    }
+}
+
+/// Per-venue flags from venue names
+pub fn venue_mask(venues: &[String]) -> Result<[bool; Exchange::COUNT]> {
+    let mut mask = [false; Exchange::COUNT];
+    for name in venues {
+        let exchange = Exchange::from_name(name).ok_or_else(|| HftError::Config(format!("Unknown peer venue {}", name)))?;
+        mask[exchange.index()] = true;
+    }
+    Ok(mask)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn guard(node_id: &str, venues: &[&str]) -> PeerGuard {
+        PeerGuard::new(&PeerConfig {
+            enabled: true,
+            node_id: node_id.to_string(),
+            venues: venues.iter().map(|v| v.to_string()).collect(),
+            ..Default::default()
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_leg_ownership_and_fencing() {
+        let t0 = Instant::now();
+        let mut tokyo = guard("tokyo", &["binance"]);
+        // Fenced until the peer is seen
+        assert!(!tokyo.may_execute(Exchange::Binance));
+
+        let joined = tokyo.on_joined("singapore", &[Exchange::Bybit], t0);
+        assert_eq!(joined, PeerTransition::Joined { node_id: "singapore".to_string(), conceded: vec![] });
+        assert!(tokyo.may_execute(Exchange::Binance));
+        assert!(!tokyo.may_execute(Exchange::Bybit));
+
+        // Partition: neither side executes
+        assert_eq!(tokyo.on_lost(t0 + Duration::from_secs(5)), Some(PeerTransition::Lost { up: Duration::from_secs(5) }));
+        assert_eq!(tokyo.on_lost(t0 + Duration::from_secs(6)), None);
+        assert!(!tokyo.may_execute(Exchange::Binance));
+
+        // Both claim binance: the lower node id keeps it
+        let joined = tokyo.on_joined("singapore", &[Exchange::Binance, Exchange::Bybit], t0);
+        assert_eq!(joined, PeerTransition::Joined { node_id: "singapore".to_string(), conceded: vec![Exchange::Binance] });
+        assert!(!tokyo.may_execute(Exchange::Binance));
+        let mut singapore = guard("singapore", &["binance", "bybit"]);
+        singapore.on_joined("tokyo", &[Exchange::Binance], t0);
+        assert!(singapore.may_execute(Exchange::Binance) && singapore.may_execute(Exchange::Bybit));
+
+        assert!(PeerGuard::new(&PeerConfig { node_id: "x".into(), venues: vec!["okx".into()], ..Default::default() }).is_err());
+        assert!(PeerGuard::new(&PeerConfig::default()).is_err());
+    }
+}
@@ -0,0 +1,193 @@
+//! Compile-time strategy plugin registry (feature = "plugins")
+//!
+//! Strategy crates register named factories with [`register_strategy!`];
+//! the submissions are collected at link time, so linking the crate into
+//! the binary is all it takes (reference it, e.g. `use my_strategies as _;`,
+//! so the linker keeps it). [`StrategyRegistry::linked`] gathers them at
+//! startup and builds the strategies listed in `[strategies] enabled` from
+//! config, plus every `[[strategies.instances]]` entry with its capital
+//! budget. Everything here runs once at startup (cold path); the engine
+//! only sees `Box<dyn Strategy>`.
+
+use super::{Strategy, StrategyBudget};
+use crate::infrastructure::config::StrategyConfig;
+use crate::{HftError, Result};
+
+#[doc(hidden)]
+pub use inventory;
+
+/// Strategy constructor, receives `[strategies.params.<name>]` (empty if absent)
+pub type StrategyFactory = fn(&toml::Table) -> Result<Box<dyn Strategy>>;
+
+/// Factory submitted by [`register_strategy!`]
+pub struct StrategyPlugin {
+    pub name: &'static str,
+    pub factory: StrategyFactory,
+}
+
+inventory::collect!(StrategyPlugin);
+
+/// Register a strategy factory from any crate linked into the binary
+///
+/// ```ignore
+/// fn make_momentum(params: &toml::Table) -> rust_hft::Result<Box<dyn Strategy>> { ... }
+/// rust_hft::register_strategy!("momentum", make_momentum);
+/// ```
+#[macro_export]
+macro_rules! register_strategy {
+    ($name:expr, $factory:expr) => {
+        $crate::engine::plugins::inventory::submit! {
+            $crate::engine::plugins::StrategyPlugin { name: $name, factory: $factory }
+        }
+    };
+}
+
+/// Named strategy factories
+#[derive(Default)]
+pub struct StrategyRegistry {
+    factories: Vec<(&'static str, StrategyFactory)>,
+}
+
+impl StrategyRegistry {
+    /// Create empty registry
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registry of every strategy submitted with [`register_strategy!`]
+    pub fn linked() -> Self {
+        let mut registry = Self::new();
+        for plugin in inventory::iter::<StrategyPlugin> {
+            registry.register(plugin.name, plugin.factory);
+        }
+        registry
+    }
+
+    /// Register factory under name (later registration with same name wins)
+    pub fn register(&mut self, name: &'static str, factory: StrategyFactory) {
+        if let Some(entry) = self.factories.iter_mut().find(|(n, _)| *n == name) {
+            tracing::warn!("Strategy plugin '{}' registered twice, replacing", name);
+            entry.1 = factory;
+        } else {
+            self.factories.push((name, factory));
+        }
+    }
+
+    /// Names of all registered strategies
+    pub fn names(&self) -> impl Iterator<Item = &'static str> + '_ {
+        self.factories.iter().map(|(n, _)| *n)
+    }
+
+    /// Build a single strategy by name
+    pub fn build(&self, name: &str, params: &toml::Table) -> Result<Box<dyn Strategy>> {
+        let (_, factory) = self
+            .factories
+            .iter()
+            .find(|(n, _)| *n == name)
+            .ok_or_else(|| HftError::Config(format!("Unknown strategy plugin: {}", name)))?;
+        factory(params)
+    }
+
+    /// Build all strategies enabled in config, in config order
+    pub fn build_enabled(&self, config: &StrategyConfig) -> Result<Vec<Box<dyn Strategy>>> {
+        let empty = toml::Table::new();
+        config
+            .enabled
+            .iter()
+            .map(|name| self.build(name, config.params.get(name).unwrap_or(&empty)))
+            .collect()
+    }
+
+    /// Build all configured strategy instances with their budgets, in config order
+    pub fn build_instances(&self, config: &StrategyConfig) -> Result<Vec<(Box<dyn Strategy>, StrategyBudget)>> {
+        config
+            .instances
+            .iter()
+            .map(|instance| Ok((self.build(&instance.plugin, &instance.params)?, StrategyBudget::from_config(instance)?)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Named(String);
+
+    impl Strategy for Named {
+        fn name(&self) -> &str {
+            &self.0
+        }
+    }
+
+    fn make_named(params: &toml::Table) -> Result<Box<dyn Strategy>> {
+        let label = params
+            .get("label")
+            .and_then(|v| v.as_str())
+            .unwrap_or("default");
+        Ok(Box::new(Named(label.to_string())))
+    }
+
+    #[test]
+    fn test_build_enabled_with_params() {
+        let mut registry = StrategyRegistry::new();
+        registry.register("named", make_named);
+
+        let config: StrategyConfig = toml::from_str(
+            r#"
+            enabled = ["named"]
+            [params.named]
+            label = "custom"
+            "#,
+        )
+        .unwrap();
+
+        let strategies = registry.build_enabled(&config).unwrap();
+        assert_eq!(strategies.len(), 1);
+        assert_eq!(strategies[0].name(), "custom");
+    }
+
+    #[test]
+    fn test_build_instances() {
+        let mut registry = StrategyRegistry::new();
+        registry.register("named", make_named);
+
+        let config: StrategyConfig = toml::from_str(
+            r#"
+            [[instances]]
+            name = "majors"
+            plugin = "named"
+            capital = 500.0
+            symbols = ["BTCUSDT"]
+            params = { label = "majors-arb" }
+
+            [[instances]]
+            name = "alts"
+            plugin = "named"
+            capital = 250.0
+            min_spread = 0.002
+            "#,
+        )
+        .unwrap();
+
+        let instances = registry.build_instances(&config).unwrap();
+        assert_eq!(instances.len(), 2);
+        assert_eq!(instances[0].0.name(), "majors-arb");
+        assert_eq!(instances[0].1.symbols, vec!["BTCUSDT".to_string()]);
+        assert_eq!(instances[1].0.name(), "default");
+        assert!(instances[1].1.min_spread.is_some());
+    }
+
+    #[test]
+    fn test_unknown_strategy() {
+        let registry = StrategyRegistry::new();
+        let config = StrategyConfig {
+            enabled: vec!["missing".to_string()],
+            ..Default::default()
+        };
+        assert!(matches!(
+            registry.build_enabled(&config),
+            Err(HftError::Config(_))
+        ));
+    }
+}
@@ -0,0 +1,232 @@
+//! Net position book built from fills
+//!
+//! Tracks signed net quantity per (exchange, symbol) so the engine can
+//! flatten a symbol on its own (e.g. ahead of a delisting) without asking
+//! the strategy that opened it. Realized PnL uses average entry prices,
+//! excludes fees and is converted from each symbol's quote currency to USDT.
+
+use super::strategy::{Fill, OrderRequest};
+use crate::core::{FixedPoint8, QuoteCurrency, QuoteRates, Side, Symbol};
+use crate::exchanges::Exchange;
+use std::collections::HashMap;
+use std::sync::Arc;
+
+/// Open position on one venue
+#[derive(Debug, Clone, Copy)]
+struct Position {
+    /// Signed quantity (long > 0, short < 0)
+    net: FixedPoint8,
+    /// Average entry price of the open quantity
+    entry: FixedPoint8,
+}
+
+/// Signed net quantity per exchange and symbol (long > 0, short < 0)
+#[derive(Debug, Default)]
+pub struct PositionBook {
+    open: HashMap<(Exchange, Symbol), Position>,
+    /// Realized PnL in USDT
+    realized: FixedPoint8,
+    fills: u64,
+    rates: Arc<QuoteRates>,
+}
+
+impl PositionBook {
+    /// Create empty book
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Convert realized PnL with shared rates
+    pub fn set_rates(&mut self, rates: Arc<QuoteRates>) {
+        self.rates = rates;
+    }
+
+    /// Apply fill to net quantity and realize PnL on the closed part
+    ///
+    /// Returns the PnL this fill realized (USDT, ZERO when opening).
+    pub fn apply_fill(&mut self, fill: &Fill) -> FixedPoint8 {
+        self.fills += 1;
+        let key = (fill.exchange, fill.symbol);
+        let pos = self.open.get(&key).copied().unwrap_or(Position {
+            net: FixedPoint8::ZERO,
+            entry: FixedPoint8::ZERO,
+        });
+        let next = match fill.side {
+            Side::Buy => pos.net.checked_add(fill.quantity),
+            Side::Sell => pos.net.checked_sub(fill.quantity),
+        }
+        .unwrap_or(pos.net);
+
+        let adding = match fill.side {
+            Side::Buy => !pos.net.is_negative(),
+            Side::Sell => !pos.net.is_positive(),
+        };
+        let mut realized = FixedPoint8::ZERO;
+        let entry = if adding {
+            // Opening or adding: quantity-weighted entry
+            Self::weighted_entry(pos, fill).unwrap_or(fill.price)
+        } else {
+            // Reducing, closing or flipping: the closed part realizes PnL
+            let closed = fill.quantity.min(pos.net.checked_abs().unwrap_or(FixedPoint8::MAX));
+            let per_unit = if pos.net.is_positive() {
+                fill.price.checked_sub(pos.entry)
+            } else {
+                pos.entry.checked_sub(fill.price)
+            };
+            if let Some(pnl) = per_unit.and_then(|p| p.safe_mul(closed)) {
+                realized = self.rates.to_usdt(pnl, QuoteCurrency::of(fill.symbol));
+                self.realized = self.realized.checked_add(realized).unwrap_or(self.realized);
+            }
+            // A flipped position opens at the fill price
+            if next.signum() == pos.net.signum() { pos.entry } else { fill.price }
+        };
+
+        if next.is_zero() {
+            self.open.remove(&key);
+        } else {
+            self.open.insert(key, Position { net: next, entry });
+        }
+        realized
+    }
+
+    fn weighted_entry(pos: Position, fill: &Fill) -> Option<FixedPoint8> {
+        let held = pos.net.checked_abs()?;
+        let cost = held.safe_mul(pos.entry)?.checked_add(fill.quantity.safe_mul(fill.price)?)?;
+        cost.safe_div(held.checked_add(fill.quantity)?)
+    }
+
+    /// Signed net quantity (ZERO if flat)
+    pub fn net(&self, exchange: Exchange, symbol: Symbol) -> FixedPoint8 {
+        self.open.get(&(exchange, symbol)).map_or(FixedPoint8::ZERO, |p| p.net)
+    }
+
+    /// Realized PnL in USDT since startup (before fees)
+    #[inline]
+    pub fn realized_pnl(&self) -> FixedPoint8 {
+        self.realized
+    }
+
+    /// Fills applied since startup
+    #[inline]
+    pub fn fills(&self) -> u64 {
+        self.fills
+    }
+
+    /// Open positions as (exchange, symbol, net, entry), for replication
+    pub fn export(&self) -> Vec<(Exchange, Symbol, FixedPoint8, FixedPoint8)> {
+        let mut positions: Vec<_> = self.open.iter().map(|(&(e, s), p)| (e, s, p.net, p.entry)).collect();
+        positions.sort_unstable_by_key(|&(e, s, _, _)| (s, e.index()));
+        positions
+    }
+
+    /// Replace the book with replicated state (see [`Self::export`])
+    pub fn restore(&mut self, positions: &[(Exchange, Symbol, FixedPoint8, FixedPoint8)], realized: FixedPoint8, fills: u64) {
+        self.open = positions
+            .iter()
+            .filter(|(_, _, net, _)| !net.is_zero())
+            .map(|&(e, s, net, entry)| ((e, s), Position { net, entry }))
+            .collect();
+        self.realized = realized;
+        self.fills = fills;
+    }
+
+    /// Symbols with an open position on any venue
+    pub fn open_symbols(&self) -> Vec<Symbol> {
+        let mut symbols: Vec<Symbol> = self.open.keys().map(|&(_, symbol)| symbol).collect();
+        symbols.sort_unstable();
+        symbols.dedup();
+        symbols
+    }
+
+    /// Market orders closing every open position in `symbol`
+    pub fn flatten_orders(&self, symbol: Symbol) -> Vec<OrderRequest> {
+        let mut orders: Vec<OrderRequest> = self
+            .open
+            .iter()
+            .filter(|((_, s), _)| *s == symbol)
+            .filter_map(|(&(exchange, symbol), &Position { net, .. })| {
+                Some(OrderRequest {
+                    exchange,
+                    symbol,
+                    side: if net.is_positive() { Side::Sell } else { Side::Buy },
+                    quantity: net.checked_abs()?,
+                    price: None,
+                })
+            })
+            .collect();
+        orders.sort_by_key(|o| o.exchange.index());
+        orders
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    fn fill(exchange: Exchange, symbol: Symbol, side: Side, qty: i64) -> Fill {
+        Fill {
+            exchange,
+            symbol,
+            side,
+            price: FixedPoint8::ONE,
+            quantity: FixedPoint8::from_raw(qty),
+            timestamp: 0,
+        }
+    }
+
+    #[test]
+    fn test_net_and_flatten() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let mut book = PositionBook::new();
+
+        book.apply_fill(&fill(Exchange::Binance, btc, Side::Buy, 300));
+        book.apply_fill(&fill(Exchange::Binance, btc, Side::Sell, 100));
+        book.apply_fill(&fill(Exchange::Bybit, btc, Side::Sell, 200));
+        book.apply_fill(&fill(Exchange::Bybit, eth, Side::Buy, 50));
+        assert_eq!(book.net(Exchange::Binance, btc).as_raw(), 200);
+        assert_eq!(book.net(Exchange::Bybit, btc).as_raw(), -200);
+
+        let orders = book.flatten_orders(btc);
+        assert_eq!(orders.len(), 2);
+        assert_eq!((orders[0].exchange, orders[0].side), (Exchange::Binance, Side::Sell));
+        assert_eq!((orders[1].exchange, orders[1].side), (Exchange::Bybit, Side::Buy));
+        assert!(orders.iter().all(|o| o.quantity.as_raw() == 200 && o.price.is_none()));
+
+        // Closing fill removes the entry
+        book.apply_fill(&fill(Exchange::Bybit, eth, Side::Sell, 50));
+        assert!(book.flatten_orders(eth).is_empty());
+        assert_eq!(book.fills(), 5);
+    }
+
+    #[test]
+    fn test_realized_pnl() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let price = |p: i64| FixedPoint8::from_raw(p * FixedPoint8::SCALE);
+        let trade = |side, qty: i64, p: i64| Fill {
+            price: price(p),
+            quantity: price(qty),
+            ..fill(Exchange::Binance, btc, side, 0)
+        };
+        let mut book = PositionBook::new();
+
+        // Long 1 @ 100 + 1 @ 110: entry 105
+        book.apply_fill(&trade(Side::Buy, 1, 100));
+        book.apply_fill(&trade(Side::Buy, 1, 110));
+        assert_eq!(book.realized_pnl(), FixedPoint8::ZERO);
+        // Sell 1 @ 120: +15
+        book.apply_fill(&trade(Side::Sell, 1, 120));
+        assert_eq!(book.realized_pnl(), price(15));
+        // Sell 3 @ 100: closes 1 (-5), flips short 2 @ 100
+        assert_eq!(book.apply_fill(&trade(Side::Sell, 3, 100)), price(-5));
+        assert_eq!(book.realized_pnl(), price(10));
+        assert_eq!(book.net(Exchange::Binance, btc), price(-2));
+        // Cover 2 @ 90: +20
+        book.apply_fill(&trade(Side::Buy, 2, 90));
+        assert_eq!(book.realized_pnl(), price(30));
+        assert_eq!(book.net(Exchange::Binance, btc), FixedPoint8::ZERO);
+    }
+}
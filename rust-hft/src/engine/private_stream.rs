@@ -0,0 +1,114 @@
+//! Private stream loss guard
+//!
+//! Fills arrive on each venue's private user-data stream; while it is down
+//! the bot cannot see what executed. When a venue's stream goes from
+//! healthy to lost, the configured safety action is taken for that venue:
+//! `freeze` blocks new orders there, `cancel_all` also cancels every open
+//! order on it. New orders resume once the stream reports healthy again.
+
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::PrivateStreamAction;
+use std::time::{Duration, Instant};
+
+/// Health transition of a venue's private stream
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StreamTransition {
+    /// Stream lost: take `action` on the venue
+    Lost { action: PrivateStreamAction },
+    /// Stream healthy again after `outage`
+    Restored { outage: Duration },
+}
+
+/// Per-venue private stream health and order freeze
+#[derive(Debug, Clone)]
+pub struct PrivateStreamGuard {
+    action: PrivateStreamAction,
+    /// When each venue's stream was lost (None = healthy)
+    lost_since: [Option<Instant>; Exchange::COUNT],
+}
+
+impl PrivateStreamGuard {
+    /// Guard taking `action` on stream loss; every venue starts healthy
+    pub fn new(action: PrivateStreamAction) -> Self {
+        Self {
+            action,
+            lost_since: [None; Exchange::COUNT],
+        }
+    }
+
+    /// Change the action taken on the next loss
+    pub fn set_action(&mut self, action: PrivateStreamAction) {
+        self.action = action;
+    }
+
+    /// Report the stream health of `exchange` at `now`
+    ///
+    /// Returns the transition, or None if the health did not change.
+    pub fn on_health(&mut self, exchange: Exchange, healthy: bool, now: Instant) -> Option<StreamTransition> {
+        let lost_since = &mut self.lost_since[exchange.index()];
+        match (*lost_since, healthy) {
+            (None, false) => {
+                *lost_since = Some(now);
+                Some(StreamTransition::Lost { action: self.action })
+            }
+            (Some(since), true) => {
+                *lost_since = None;
+                Some(StreamTransition::Restored {
+                    outage: now.saturating_duration_since(since),
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// New orders on `exchange` are blocked
+    #[inline]
+    pub fn is_frozen(&self, exchange: Exchange) -> bool {
+        self.lost_since[exchange.index()].is_some()
+    }
+}
+
+impl Default for PrivateStreamGuard {
+    fn default() -> Self {
+        Self::new(PrivateStreamAction::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_private_stream_transitions() {
+        let t0 = Instant::now();
+        let mut guard = PrivateStreamGuard::new(PrivateStreamAction::Freeze);
+        assert_eq!(guard.on_health(Exchange::Bybit, true, t0), None);
+
+        assert_eq!(
+            guard.on_health(Exchange::Bybit, false, t0),
+            Some(StreamTransition::Lost {
+                action: PrivateStreamAction::Freeze
+            })
+        );
+        // Repeated reports are not transitions; the other venue is unaffected
+        assert_eq!(guard.on_health(Exchange::Bybit, false, t0 + Duration::from_secs(1)), None);
+        assert!(guard.is_frozen(Exchange::Bybit));
+        assert!(!guard.is_frozen(Exchange::Binance));
+
+        assert_eq!(
+            guard.on_health(Exchange::Bybit, true, t0 + Duration::from_secs(3)),
+            Some(StreamTransition::Restored {
+                outage: Duration::from_secs(3)
+            })
+        );
+        assert!(!guard.is_frozen(Exchange::Bybit));
+
+        guard.set_action(PrivateStreamAction::CancelAll);
+        assert_eq!(
+            guard.on_health(Exchange::Binance, false, t0),
+            Some(StreamTransition::Lost {
+                action: PrivateStreamAction::CancelAll
+            })
+        );
+    }
+}
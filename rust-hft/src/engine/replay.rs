@@ -0,0 +1,219 @@
+//! Replay determinism verification
+//!
+//! A capture (messages with connection id and arrival offset) is replayed
+//! through two fresh engines and their opportunity journals must be
+//! byte-identical. A difference means engine output depends on something
+//! other than the input (wall clock, hash iteration order), which would make
+//! backtests built on replays untrustworthy.
+
+use super::AppEngine;
+use crate::exchanges::ExchangeMessage;
+use crate::ws::ConnectionId;
+use std::time::{Duration, Instant};
+
+/// One captured message
+#[derive(Debug, Clone)]
+pub struct CapturedMessage {
+    /// Connection the message arrived on
+    pub connection: ConnectionId,
+    /// Arrival time relative to the first message
+    pub offset: Duration,
+    pub msg: ExchangeMessage,
+}
+
+/// First differing journal line between two replays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ReplayMismatch {
+    /// Zero-based line index
+    pub line: usize,
+    /// Line in each replay (empty if that journal is shorter)
+    pub first: String,
+    pub second: String,
+}
+
+/// Drive `engine` through `capture` and return its journal bytes
+pub async fn replay(engine: &mut AppEngine, capture: &[CapturedMessage]) -> Vec<u8> {
+    let start = Instant::now();
+    for captured in capture {
+        engine
+            .handle_message(captured.connection, start + captured.offset, captured.msg.clone())
+            .await;
+    }
+    engine.journal().read().await.to_bytes()
+}
+
+/// Replay `capture` through two engines from `make_engine` and compare journals
+///
+/// Returns the journal bytes when both replays agree.
+pub async fn verify_determinism(
+    mut make_engine: impl FnMut() -> AppEngine,
+    capture: &[CapturedMessage],
+) -> Result<Vec<u8>, ReplayMismatch> {
+    let first = replay(&mut make_engine(), capture).await;
+    let second = replay(&mut make_engine(), capture).await;
+    match first_difference(&first, &second) {
+        Some(mismatch) => Err(mismatch),
+        None => Ok(first),
+    }
+}
+
+/// First differing line of two journals (None if byte-identical)
+pub fn first_difference(first: &[u8], second: &[u8]) -> Option<ReplayMismatch> {
+    if first == second {
+        return None;
+    }
+    let mut a = first.split(|&b| b == b'\n');
+    let mut b = second.split(|&b| b == b'\n');
+    let mut line = 0;
+    loop {
+        let (x, y) = (a.next(), b.next());
+        if x != y {
+            let text = |l: Option<&[u8]>| String::from_utf8_lossy(l.unwrap_or_default()).into_owned();
+            return Some(ReplayMismatch { line, first: text(x), second: text(y) });
+        }
+        line += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Symbol, TickerData};
+    use crate::exchanges::Exchange;
+    use crate::hot_path::ThresholdTracker;
+    use crate::infrastructure::metrics::MetricsCollector;
+    use crate::test_utils::{init_test_registry, skew_capture, VenueClock};
+    use std::sync::Arc;
+    use tokio::sync::RwLock;
+
+    fn ticker(exchange: Exchange, update_id: u64, bid: i64, ask: i64, ms: u64) -> CapturedMessage {
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = TickerData::new(
+            symbol,
+            FixedPoint8::from_raw(bid * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(2 * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(ask * FixedPoint8::SCALE),
+            FixedPoint8::from_raw(2 * FixedPoint8::SCALE),
+            ms * 1_000_000,
+        )
+        .with_update_id(update_id);
+        CapturedMessage {
+            connection: ConnectionId(exchange.index() as u64),
+            offset: Duration::from_millis(ms),
+            msg: ExchangeMessage::Ticker(exchange, ticker),
+        }
+    }
+
+    #[tokio::test]
+    async fn test_replay_is_deterministic() {
+        init_test_registry();
+        let capture = vec![
+            ticker(Exchange::Binance, 1, 50_000, 50_001, 0),
+            ticker(Exchange::Bybit, 1, 50_300, 50_301, 5),
+            ticker(Exchange::Bybit, 2, 50_320, 50_321, 10),
+        ];
+        let make_engine = || {
+            let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+            AppEngine::new(tracker, Arc::new(MetricsCollector::new()))
+        };
+
+        let journal = verify_determinism(make_engine, &capture).await.unwrap();
+        assert_eq!(journal.iter().filter(|&&b| b == b'\n').count(), 2);
+    }
+
+    fn make_engine() -> AppEngine {
+        let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+        AppEngine::new(tracker, Arc::new(MetricsCollector::new()))
+    }
+
+    /// Quotes moving on both venues with Bybit ~0.6% rich
+    fn moving_market() -> Vec<CapturedMessage> {
+        const EPOCH_MS: u64 = 1_700_000_000_000;
+        (0..4u64)
+            .flat_map(|i| {
+                let step = i as i64 * 10;
+                let ms = EPOCH_MS + i * 20;
+                [
+                    ticker(Exchange::Binance, i + 1, 50_000 + step, 50_001 + step, ms),
+                    ticker(Exchange::Bybit, i + 1, 50_300 + step, 50_301 + step, ms + 5),
+                ]
+            })
+            .collect()
+    }
+
+    /// Journal lines without the timestamp column
+    fn decisions(journal: &[u8]) -> Vec<String> {
+        String::from_utf8_lossy(journal)
+            .lines()
+            .map(|line| line.split_once(' ').map_or("", |(_, rest)| rest).to_string())
+            .collect()
+    }
+
+    #[tokio::test]
+    async fn test_clock_skew_moves_only_timestamps() {
+        init_test_registry();
+        let capture = moving_market();
+        // Bybit clock 250 ms ahead, Binance 40 ms behind; arrival order unchanged
+        let bybit = ConnectionId(Exchange::Bybit.index() as u64);
+        let skewed = skew_capture(&capture, |conn| VenueClock {
+            clock_offset_ns: if conn == bybit { 250_000_000 } else { -40_000_000 },
+            latency: Duration::ZERO,
+        });
+
+        let baseline = verify_determinism(make_engine, &capture).await.unwrap();
+        let journal = verify_determinism(make_engine, &skewed).await.unwrap();
+        assert_ne!(baseline, journal);
+        // Quote turnover is measured per venue clock, so sizing and fill
+        // probability are unaffected by the offset between venues
+        assert_eq!(decisions(&journal), decisions(&baseline));
+        let last = decisions(&journal).pop().unwrap();
+        let p_fill: i64 = last.rsplit(' ').next().unwrap().parse().unwrap();
+        assert!(p_fill > 0 && p_fill < FixedPoint8::ONE.as_raw(), "{}", last);
+    }
+
+    #[tokio::test]
+    async fn test_latency_asymmetry_between_redundant_feeds() {
+        init_test_registry();
+        let capture = moving_market();
+        // Second Binance connection delivering the same updates
+        let backup = ConnectionId(2);
+        let redundant: Vec<CapturedMessage> = capture
+            .iter()
+            .cloned()
+            .flat_map(|m| {
+                let copy = matches!(m.msg, ExchangeMessage::Ticker(Exchange::Binance, _))
+                    .then(|| CapturedMessage { connection: backup, ..m.clone() });
+                std::iter::once(m).chain(copy)
+            })
+            .collect();
+        let latency = |conn: ConnectionId| VenueClock {
+            clock_offset_ns: 0,
+            latency: Duration::from_millis(if conn == backup { 4 } else { 1 }),
+        };
+
+        let metrics = Arc::new(MetricsCollector::new());
+        let mut engine = AppEngine::new(Arc::new(RwLock::new(ThresholdTracker::new())), metrics.clone());
+        let journal = replay(&mut engine, &skew_capture(&redundant, latency)).await;
+
+        // Late copies are dropped: same decisions as a single connection
+        let single = replay(&mut make_engine(), &skew_capture(&capture, latency)).await;
+        assert_eq!(journal, single);
+
+        // The slow connection loses every race by the latency difference
+        let race = metrics.feed_race().snapshot();
+        let primary = race.iter().find(|r| r.feed == 0).unwrap();
+        let slow = race.iter().find(|r| r.feed == 2).unwrap();
+        assert_eq!((primary.wins, primary.losses), (4, 0));
+        assert_eq!((slow.wins, slow.losses), (0, 4));
+        assert_eq!(slow.mean_lag_us, 3_000.0);
+    }
+
+    #[test]
+    fn test_first_difference() {
+        assert_eq!(first_difference(b"a\nb\n", b"a\nb\n"), None);
+        let mismatch = first_difference(b"a\nb\n", b"a\nc\n").unwrap();
+        assert_eq!((mismatch.line, mismatch.first.as_str(), mismatch.second.as_str()), (1, "b", "c"));
+        let shorter = first_difference(b"a\n", b"a\nb\n").unwrap();
+        assert_eq!((shorter.line, shorter.second.as_str()), (1, "b"));
+    }
+}
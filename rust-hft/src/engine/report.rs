@@ -0,0 +1,158 @@
+//! End-of-run session report (Cold Path)
+//!
+//! Built when the engine loop stops and written to the log and a file, so
+//! each run leaves an at-a-glance account: traffic per venue and
+//! connection, processing latency, opportunities and orders, realized PnL,
+//! reconnects and errors.
+
+use crate::core::FixedPoint8;
+use crate::infrastructure::metrics::{ConnectionSnapshot, MetricsCollector};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+/// Summary of one engine run
+#[derive(Debug, Clone)]
+pub struct SessionReport {
+    pub uptime: Duration,
+    pub binance_messages: u64,
+    pub bybit_messages: u64,
+    pub duplicate_messages: u64,
+    pub connections: Vec<ConnectionSnapshot>,
+    /// Receive → processed latency percentiles (None without messages)
+    pub processing_p50: Option<Duration>,
+    pub processing_p99: Option<Duration>,
+    /// Opportunities journaled
+    pub opportunities: u64,
+    /// Orders accepted by the execution engine
+    pub orders_submitted: u64,
+    /// Orders suppressed by throttling, focus, warm-up, pause, disabled symbol,
+    /// strategy capital limits or read-only mode
+    pub orders_suppressed: u64,
+    pub fills: u64,
+    /// Realized PnL in quote currency (before fees)
+    pub realized_pnl: FixedPoint8,
+    /// Feed task restarts and feeds given up on
+    pub reconnects: u64,
+    pub failed_tasks: u64,
+    pub exchange_errors: u64,
+    pub quarantined_quotes: u64,
+    /// Engine loop iterations over the stall budget, and the longest
+    pub engine_stalls: u64,
+    pub max_stall: Duration,
+}
+
+impl SessionReport {
+    /// Collect metrics; engine-owned figures are passed in
+    pub fn collect(metrics: &MetricsCollector, opportunities: u64, fills: u64, realized_pnl: FixedPoint8) -> Self {
+        let snapshot = metrics.snapshot();
+        Self {
+            uptime: Duration::from_secs(snapshot.uptime_seconds),
+            binance_messages: snapshot.binance_messages,
+            bybit_messages: snapshot.bybit_messages,
+            duplicate_messages: snapshot.duplicate_messages,
+            connections: metrics.connections().snapshot(&metrics.feed_race().labels()),
+            processing_p50: metrics.processing().percentile(0.50),
+            processing_p99: metrics.processing().percentile(0.99),
+            opportunities,
+            orders_submitted: snapshot.orders_submitted,
+            orders_suppressed: snapshot.suppressed_cooldown
+                + snapshot.suppressed_rate_limit
+                + snapshot.suppressed_unfocused
+                + snapshot.suppressed_warmup
+                + snapshot.suppressed_paused
+                + snapshot.suppressed_disabled
+                + snapshot.suppressed_delisted
+                + snapshot.suppressed_halted
+                + snapshot.suppressed_peer
+                + snapshot.suppressed_standby
+                + snapshot.suppressed_private_stream
+                + snapshot.suppressed_risk
+                + snapshot.suppressed_read_only,
+            fills,
+            realized_pnl,
+            reconnects: snapshot.task_restarts,
+            failed_tasks: snapshot.failed_tasks,
+            exchange_errors: snapshot.exchange_errors,
+            quarantined_quotes: snapshot.quarantined_quotes,
+            engine_stalls: snapshot.engine_stalls,
+            max_stall: Duration::from_micros(snapshot.max_stall_us),
+        }
+    }
+
+    /// Plain-text report
+    pub fn render(&self) -> String {
+        let latency = |d: Option<Duration>| d.map_or_else(|| "n/a".to_string(), |d| format!("{:?}", d));
+        let secs = self.uptime.as_secs();
+        let mut out = String::with_capacity(1024);
+        let _ = writeln!(out, "=== Session report ===");
+        let _ = writeln!(out, "uptime              {}h {:02}m {:02}s", secs / 3600, secs / 60 % 60, secs % 60);
+        let _ = writeln!(out, "messages binance    {}", self.binance_messages);
+        let _ = writeln!(out, "messages bybit      {}", self.bybit_messages);
+        let _ = writeln!(out, "duplicates          {}", self.duplicate_messages);
+        for conn in &self.connections {
+            let _ = writeln!(
+                out,
+                "  {:<17} {} msgs, max gap {:.0} ms, latency mean {:.1} ms max {:.1} ms",
+                conn.label, conn.messages, conn.max_gap_ms, conn.mean_latency_ms, conn.max_latency_ms
+            );
+            if conn.conflated + conn.dropped > 0 {
+                let _ = writeln!(out, "  {:<17} flood: {} conflated, {} dropped", "", conn.conflated, conn.dropped);
+            }
+        }
+        let _ = writeln!(
+            out,
+            "processing p50/p99  {} / {}",
+            latency(self.processing_p50),
+            latency(self.processing_p99)
+        );
+        let _ = writeln!(out, "opportunities       {}", self.opportunities);
+        let _ = writeln!(out, "orders submitted    {}", self.orders_submitted);
+        let _ = writeln!(out, "orders suppressed   {}", self.orders_suppressed);
+        let _ = writeln!(out, "fills               {}", self.fills);
+        let _ = writeln!(out, "realized pnl        {} (before fees)", self.realized_pnl);
+        let _ = writeln!(out, "reconnects          {} ({} feeds failed)", self.reconnects, self.failed_tasks);
+        let _ = writeln!(out, "exchange errors     {}", self.exchange_errors);
+        let _ = writeln!(out, "quarantined quotes  {}", self.quarantined_quotes);
+        let _ = writeln!(out, "engine stalls       {} (max {:?})", self.engine_stalls, self.max_stall);
+        out
+    }
+
+    /// Write the report to `dir/session-<unix_ms>.txt`
+    pub fn write_to(&self, dir: &Path, unix_ms: u64) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("session-{}.txt", unix_ms));
+        std::fs::write(&path, self.render())?;
+        Ok(path)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_session_report() {
+        let metrics = MetricsCollector::new();
+        metrics.feed_race().set_labels(vec!["binance#0".to_string()]);
+        metrics.record_binance_message();
+        metrics.record_order_submitted();
+        metrics.record_suppressed_cooldown();
+        metrics.record_exchange_error();
+        metrics.processing().record(Duration::from_micros(3));
+
+        let report = SessionReport::collect(&metrics, 7, 2, FixedPoint8::from_raw(150_000_000));
+        assert_eq!((report.binance_messages, report.orders_submitted, report.orders_suppressed), (1, 1, 1));
+        assert_eq!(report.processing_p99, Some(Duration::from_nanos(4096)));
+
+        let text = report.render();
+        assert!(text.contains("opportunities       7"), "{}", text);
+        assert!(text.contains("binance#0"), "{}", text);
+        assert!(text.contains("realized pnl        1.5"), "{}", text);
+
+        let dir = std::env::temp_dir().join(format!("rust-hft-report-{}", std::process::id()));
+        let path = report.write_to(&dir, 1).unwrap();
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), text);
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
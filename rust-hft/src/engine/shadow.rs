@@ -0,0 +1,313 @@
+//! Shadow (paper) execution
+//!
+//! With `execution.shadow` enabled every strategy order is also filled on
+//! paper against the last quote of its venue, in parallel with the live
+//! path, whatever that path decided (submitted, throttled, paused, warming
+//! up or read-only). Both decision streams are kept per UTC day; at the
+//! day rollover and on shutdown a [`ShadowReport`] compares hypothetical
+//! and realized results, which is the tool for tuning thresholds without
+//! turning trading off.
+
+use super::positions::PositionBook;
+use super::strategy::{Fill, OrderRequest};
+use crate::core::{FixedPoint8, Side, Symbol, TickerData};
+use crate::exchanges::Exchange;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt::Write;
+use std::path::{Path, PathBuf};
+
+const DAY_MS: u64 = 86_400_000;
+
+/// What the live path did with an order
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LiveOutcome {
+    /// Accepted by the execution engine
+    Submitted,
+    /// Not sent (reason, e.g. "paused", "cooldown", "read-only")
+    Suppressed(&'static str),
+}
+
+/// One strategy order with both outcomes
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ShadowDecision {
+    /// Decision time (ms since epoch)
+    pub timestamp_ms: u64,
+    pub order: OrderRequest,
+    pub live: LiveOutcome,
+    /// Paper fill price (None = not marketable or no quote)
+    pub paper_price: Option<FixedPoint8>,
+}
+
+/// Hypothetical vs realized results of one UTC day
+#[derive(Debug, Clone)]
+pub struct ShadowReport {
+    /// Day start (ms since epoch)
+    pub day_start_ms: u64,
+    pub decisions: Vec<ShadowDecision>,
+    /// Fills and realized PnL of the live book during the day
+    pub live_fills: u64,
+    pub live_pnl: FixedPoint8,
+    /// Fills and realized PnL of the paper book during the day
+    pub paper_fills: u64,
+    pub paper_pnl: FixedPoint8,
+}
+
+impl ShadowReport {
+    /// UTC date of the report, e.g. "2026-10-16"
+    pub fn date(&self) -> String {
+        time::OffsetDateTime::from_unix_timestamp((self.day_start_ms / 1000) as i64)
+            .map_or_else(|_| format!("day-{}", self.day_start_ms / DAY_MS), |t| t.date().to_string())
+    }
+
+    /// Orders the live path submitted
+    pub fn live_submitted(&self) -> u64 {
+        self.decisions.iter().filter(|d| d.live == LiveOutcome::Submitted).count() as u64
+    }
+
+    /// Plain-text report followed by the decision stream
+    pub fn render(&self) -> String {
+        let mut suppressed: BTreeMap<&str, u64> = BTreeMap::new();
+        for decision in &self.decisions {
+            if let LiveOutcome::Suppressed(reason) = decision.live {
+                *suppressed.entry(reason).or_default() += 1;
+            }
+        }
+        let submitted = self.live_submitted();
+        let unfilled = self.decisions.iter().filter(|d| d.paper_price.is_none()).count();
+        let difference = self.paper_pnl.checked_sub(self.live_pnl).unwrap_or(FixedPoint8::ZERO);
+
+        let mut out = String::with_capacity(1024 + self.decisions.len() * 96);
+        let _ = writeln!(out, "=== Shadow report {} (UTC) ===", self.date());
+        let _ = writeln!(out, "decisions           {}", self.decisions.len());
+        let _ = writeln!(out, "live submitted      {} ({} suppressed)", submitted, self.decisions.len() as u64 - submitted);
+        for (reason, count) in &suppressed {
+            let _ = writeln!(out, "  {:<17} {}", reason, count);
+        }
+        let _ = writeln!(out, "live fills          {}", self.live_fills);
+        let _ = writeln!(out, "live realized pnl   {}", self.live_pnl);
+        let _ = writeln!(out, "paper fills         {} ({} unfilled)", self.paper_fills, unfilled);
+        let _ = writeln!(out, "paper realized pnl  {}", self.paper_pnl);
+        let _ = writeln!(out, "paper - live        {} (before fees)", difference);
+        let _ = writeln!(out, "--- decisions (ms, venue, symbol, side, qty, live, paper) ---");
+        for d in &self.decisions {
+            let live = match d.live {
+                LiveOutcome::Submitted => "submitted",
+                LiveOutcome::Suppressed(reason) => reason,
+            };
+            let paper = d.paper_price.map_or_else(|| "unfilled".to_string(), |p| p.to_string());
+            let _ = writeln!(
+                out,
+                "{} {} {} {:?} {} {} {}",
+                d.timestamp_ms,
+                d.order.exchange.name(),
+                d.order.symbol.as_str(),
+                d.order.side,
+                d.order.quantity,
+                live,
+                paper
+            );
+        }
+        out
+    }
+
+    /// Write the report to `dir/shadow-<date>.txt` (replaces a partial day)
+    pub fn write_to(&self, dir: &Path) -> std::io::Result<PathBuf> {
+        std::fs::create_dir_all(dir)?;
+        let path = dir.join(format!("shadow-{}.txt", self.date()));
+        std::fs::write(&path, self.render())?;
+        Ok(path)
+    }
+}
+
+/// Paper book and daily decision log
+#[derive(Debug, Default)]
+pub struct ShadowExecutor {
+    /// Last screened quote per venue and symbol
+    quotes: HashMap<(Exchange, Symbol), TickerData>,
+    paper: PositionBook,
+    /// Current UTC day (days since epoch; None before the first roll)
+    day: Option<u64>,
+    decisions: Vec<ShadowDecision>,
+    /// (fills, realized PnL) of both books at the start of the day
+    live_start: (u64, FixedPoint8),
+    paper_start: (u64, FixedPoint8),
+}
+
+impl ShadowExecutor {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Remember the latest quote (paper fills execute against it)
+    #[inline]
+    pub fn observe_ticker(&mut self, exchange: Exchange, ticker: &TickerData) {
+        self.quotes.insert((exchange, ticker.symbol), *ticker);
+    }
+
+    /// Paper-fill `order` and log it with the live outcome
+    ///
+    /// Buys take the ask, sells hit the bid; a limit order fills only if
+    /// marketable. The whole quantity fills (top-of-book size is ignored).
+    pub fn record(&mut self, order: &OrderRequest, live: LiveOutcome, now_ms: u64) -> ShadowDecision {
+        let paper_price = self.quotes.get(&(order.exchange, order.symbol)).and_then(|quote| {
+            let price = match order.side {
+                Side::Buy => quote.ask_price,
+                Side::Sell => quote.bid_price,
+            };
+            let marketable = order.price.is_none_or(|limit| match order.side {
+                Side::Buy => limit >= price,
+                Side::Sell => limit <= price,
+            });
+            marketable.then_some(price)
+        });
+        if let Some(price) = paper_price {
+            self.paper.apply_fill(&Fill {
+                exchange: order.exchange,
+                symbol: order.symbol,
+                side: order.side,
+                price,
+                quantity: order.quantity,
+                timestamp: now_ms * 1_000_000,
+            });
+        }
+        let decision = ShadowDecision {
+            timestamp_ms: now_ms,
+            order: *order,
+            live,
+            paper_price,
+        };
+        self.decisions.push(decision);
+        decision
+    }
+
+    /// Close the day if `now_ms` is past it: the finished day's report
+    pub fn roll(&mut self, now_ms: u64, live: &PositionBook) -> Option<ShadowReport> {
+        let today = now_ms / DAY_MS;
+        match self.day {
+            Some(day) if day == today => None,
+            Some(_) => {
+                let report = self.report(live);
+                self.start_day(today, live);
+                Some(report)
+            }
+            None => {
+                self.start_day(today, live);
+                None
+            }
+        }
+    }
+
+    /// Report of the current day so far
+    pub fn report(&self, live: &PositionBook) -> ShadowReport {
+        let since = |(fills, pnl): (u64, FixedPoint8), book: &PositionBook| {
+            (
+                book.fills() - fills,
+                book.realized_pnl().checked_sub(pnl).unwrap_or(FixedPoint8::ZERO),
+            )
+        };
+        let (live_fills, live_pnl) = since(self.live_start, live);
+        let (paper_fills, paper_pnl) = since(self.paper_start, &self.paper);
+        ShadowReport {
+            day_start_ms: self.day.unwrap_or_default() * DAY_MS,
+            decisions: self.decisions.clone(),
+            live_fills,
+            live_pnl,
+            paper_fills,
+            paper_pnl,
+        }
+    }
+
+    fn start_day(&mut self, day: u64, live: &PositionBook) {
+        self.day = Some(day);
+        self.decisions.clear();
+        self.live_start = (live.fills(), live.realized_pnl());
+        self.paper_start = (self.paper.fills(), self.paper.realized_pnl());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    /// 2026-10-16 00:00 UTC
+    const DAY0: u64 = 1_792_108_800_000;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    fn order(side: Side, price: Option<f64>) -> OrderRequest {
+        OrderRequest {
+            exchange: Exchange::Binance,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side,
+            quantity: FixedPoint8::ONE,
+            price: price.map(fp),
+        }
+    }
+
+    #[test]
+    fn test_shadow_paper_fills() {
+        init_test_registry();
+        let mut shadow = ShadowExecutor::new();
+        let live = PositionBook::new();
+        assert!(shadow.roll(DAY0 + 1, &live).is_none());
+
+        // No quote yet: nothing to fill against
+        let first = shadow.record(&order(Side::Buy, None), LiveOutcome::Suppressed("warmup"), DAY0 + 2);
+        assert_eq!(first.paper_price, None);
+
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        shadow.observe_ticker(Exchange::Binance, &TickerData::new(btc, fp(100.0), fp(1.0), fp(101.0), fp(1.0), 0));
+        assert_eq!(shadow.record(&order(Side::Buy, None), LiveOutcome::Submitted, DAY0 + 3).paper_price, Some(fp(101.0)));
+        // Limit below the ask: not marketable
+        assert_eq!(shadow.record(&order(Side::Buy, Some(100.5)), LiveOutcome::Submitted, DAY0 + 4).paper_price, None);
+
+        shadow.observe_ticker(Exchange::Binance, &TickerData::new(btc, fp(104.0), fp(1.0), fp(105.0), fp(1.0), 0));
+        let sell = shadow.record(&order(Side::Sell, Some(103.0)), LiveOutcome::Suppressed("paused"), DAY0 + 5);
+        assert_eq!(sell.paper_price, Some(fp(104.0)));
+
+        let report = shadow.report(&live);
+        assert_eq!(report.date(), "2026-10-16");
+        assert_eq!((report.decisions.len(), report.live_submitted()), (4, 2));
+        assert_eq!((report.paper_fills, report.paper_pnl), (2, fp(3.0)));
+        assert_eq!((report.live_fills, report.live_pnl), (0, FixedPoint8::ZERO));
+        let text = report.render();
+        assert!(text.contains("paper - live        3.00000000"), "{}", text);
+        assert!(text.contains("  paused            1"), "{}", text);
+        assert!(text.contains(&format!("{} binance BTCUSDT Sell 1.00000000 paused 104.00000000", DAY0 + 5)), "{}", text);
+    }
+
+    #[test]
+    fn test_shadow_daily_rollover() {
+        init_test_registry();
+        let mut shadow = ShadowExecutor::new();
+        let mut live = PositionBook::new();
+        shadow.roll(DAY0, &live);
+        shadow.record(&order(Side::Buy, None), LiveOutcome::Submitted, DAY0 + 10);
+        live.apply_fill(&Fill {
+            exchange: Exchange::Binance,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side: Side::Buy,
+            price: fp(100.0),
+            quantity: FixedPoint8::ONE,
+            timestamp: 0,
+        });
+        assert!(shadow.roll(DAY0 + DAY_MS - 1, &live).is_none());
+
+        let report = shadow.roll(DAY0 + DAY_MS, &live).unwrap();
+        assert_eq!((report.decisions.len(), report.live_fills), (1, 1));
+        assert_eq!(report.day_start_ms, DAY0);
+
+        // The new day starts empty, relative to the books at rollover
+        let next = shadow.report(&live);
+        assert_eq!((next.decisions.len(), next.live_fills), (0, 0));
+        assert_eq!(next.date(), "2026-10-17");
+
+        let dir = std::env::temp_dir().join(format!("rust-hft-shadow-{}", std::process::id()));
+        let path = report.write_to(&dir).unwrap();
+        assert!(path.ends_with("shadow-2026-10-16.txt"));
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+}
@@ -0,0 +1,274 @@
+//! Opportunity sizing model
+//!
+//! Chooses the order quantity for a two-leg opportunity as the minimum of
+//! independent caps, and reports which cap was binding:
+//! - Liquidity: max participation in top-N book quantity on both legs
+//! - Balance: fraction of available quote balance
+//! - Max notional: hard per-trade notional limit
+//! - Volatility: max notional scaled down when the spread is volatile
+//!
+//! Opportunities whose quotes are unlikely to survive until the order lands
+//! (fill probability below the configured minimum) are not traded.
+//!
+//! Warm path: FixedPoint8 only, no allocation.
+
+use crate::core::FixedPoint8;
+use crate::infrastructure::config::SizingConfig;
+use serde::Serialize;
+use std::time::Duration;
+
+/// Constraint that determined the chosen size
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SizeConstraint {
+    Liquidity,
+    Balance,
+    MaxNotional,
+    Volatility,
+    /// Best cap fell below minimum notional, size is zero
+    MinNotional,
+    /// Quotes unlikely to survive the order round trip, size is zero
+    FillProbability,
+}
+
+/// Market/account inputs for a single sizing decision
+#[derive(Debug, Clone, Copy)]
+pub struct SizingInputs {
+    /// Reference price (buy leg ask)
+    pub price: FixedPoint8,
+    /// Top-N ask quantity on the buy venue
+    pub buy_book_qty: FixedPoint8,
+    /// Top-N bid quantity on the sell venue
+    pub sell_book_qty: FixedPoint8,
+    /// Available quote balance (min across venues)
+    pub balance: FixedPoint8,
+    /// Spread volatility as a fraction (e.g. 2-minute spread range)
+    pub volatility: FixedPoint8,
+    /// Probability both quotes survive the order round trip (0..1)
+    pub fill_probability: FixedPoint8,
+}
+
+/// Chosen size with every cap for journaling
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SizeDecision {
+    /// Base-asset quantity (zero = do not trade)
+    pub quantity: FixedPoint8,
+    /// Binding constraint
+    pub constraint: SizeConstraint,
+    pub liquidity_cap: FixedPoint8,
+    pub balance_cap: FixedPoint8,
+    pub notional_cap: FixedPoint8,
+    pub volatility_cap: FixedPoint8,
+    /// Fill probability score the decision was taken with
+    pub fill_probability: FixedPoint8,
+}
+
+/// Sizing model (parameters pre-converted to FixedPoint8)
+#[derive(Debug, Clone, Copy)]
+pub struct SizingModel {
+    max_participation: FixedPoint8,
+    balance_fraction: FixedPoint8,
+    max_notional: FixedPoint8,
+    min_notional: FixedPoint8,
+    target_volatility: FixedPoint8,
+    account_balance: FixedPoint8,
+    min_fill_probability: FixedPoint8,
+    order_latency: Duration,
+}
+
+impl SizingModel {
+    /// Build model from config (cold path)
+    pub fn new(config: &SizingConfig) -> Self {
+        let fp = |v: f64| FixedPoint8::from_f64(v).unwrap_or(FixedPoint8::ZERO);
+        Self {
+            max_participation: fp(config.max_participation),
+            balance_fraction: fp(config.balance_fraction),
+            max_notional: fp(config.max_notional),
+            min_notional: fp(config.min_notional),
+            target_volatility: fp(config.target_volatility),
+            account_balance: fp(config.account_balance),
+            min_fill_probability: fp(config.min_fill_probability),
+            order_latency: Duration::from_secs_f64(config.order_latency_ms.max(0.0) / 1000.0),
+        }
+    }
+
+    /// Configured account balance (used until balances are fetched from venues)
+    #[inline(always)]
+    pub fn account_balance(&self) -> FixedPoint8 {
+        self.account_balance
+    }
+
+    /// Order round-trip latency assumed until round trips are measured
+    #[inline(always)]
+    pub fn order_latency(&self) -> Duration {
+        self.order_latency
+    }
+
+    /// Compute order size for opportunity
+    pub fn size(&self, inputs: &SizingInputs) -> SizeDecision {
+        let zero = SizeDecision {
+            quantity: FixedPoint8::ZERO,
+            constraint: SizeConstraint::MinNotional,
+            liquidity_cap: FixedPoint8::ZERO,
+            balance_cap: FixedPoint8::ZERO,
+            notional_cap: FixedPoint8::ZERO,
+            volatility_cap: FixedPoint8::ZERO,
+            fill_probability: inputs.fill_probability,
+        };
+        if !inputs.price.is_positive() {
+            return zero;
+        }
+
+        let book_qty = inputs.buy_book_qty.min(inputs.sell_book_qty);
+        let liquidity_cap = book_qty
+            .safe_mul(self.max_participation)
+            .unwrap_or(FixedPoint8::ZERO);
+
+        let balance_cap = inputs
+            .balance
+            .safe_mul(self.balance_fraction)
+            .and_then(|n| n.safe_div(inputs.price))
+            .unwrap_or(FixedPoint8::ZERO);
+
+        let notional_cap = self
+            .max_notional
+            .safe_div(inputs.price)
+            .unwrap_or(FixedPoint8::ZERO);
+
+        // Scale max notional by target/actual volatility (never above 1.0)
+        let volatility_cap = if inputs.volatility > self.target_volatility
+            && self.target_volatility.is_positive()
+        {
+            self.target_volatility
+                .safe_div(inputs.volatility)
+                .and_then(|scale| notional_cap.safe_mul(scale))
+                .unwrap_or(FixedPoint8::ZERO)
+        } else {
+            notional_cap
+        };
+
+        let caps = [
+            (liquidity_cap, SizeConstraint::Liquidity),
+            (balance_cap, SizeConstraint::Balance),
+            (notional_cap, SizeConstraint::MaxNotional),
+            (volatility_cap, SizeConstraint::Volatility),
+        ];
+        let (mut quantity, mut constraint) = caps[0];
+        for &(cap, c) in &caps[1..] {
+            if cap < quantity {
+                quantity = cap;
+                constraint = c;
+            }
+        }
+
+        let notional = quantity.safe_mul(inputs.price).unwrap_or(FixedPoint8::ZERO);
+        if notional < self.min_notional || !quantity.is_positive() {
+            quantity = FixedPoint8::ZERO;
+            constraint = SizeConstraint::MinNotional;
+        } else if inputs.fill_probability < self.min_fill_probability {
+            quantity = FixedPoint8::ZERO;
+            constraint = SizeConstraint::FillProbability;
+        }
+
+        SizeDecision {
+            quantity,
+            constraint,
+            liquidity_cap,
+            balance_cap,
+            notional_cap,
+            volatility_cap,
+            fill_probability: inputs.fill_probability,
+        }
+    }
+}
+
+/// Sum quantity over the top `n` price levels of (price, qty) book side
+#[inline]
+pub fn top_n_qty(levels: &[(FixedPoint8, FixedPoint8)], n: usize) -> FixedPoint8 {
+    levels
+        .iter()
+        .take(n)
+        .fold(FixedPoint8::ZERO, |acc, (_, qty)| acc.checked_add(*qty).unwrap_or(acc))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    fn model() -> SizingModel {
+        SizingModel::new(&SizingConfig {
+            max_participation: 0.1,
+            balance_fraction: 0.5,
+            max_notional: 10_000.0,
+            min_notional: 10.0,
+            target_volatility: 0.002,
+            account_balance: 50_000.0,
+            min_fill_probability: 0.5,
+            order_latency_ms: 50.0,
+        })
+    }
+
+    fn inputs() -> SizingInputs {
+        SizingInputs {
+            price: fp(100.0),
+            buy_book_qty: fp(5_000.0),
+            sell_book_qty: fp(4_000.0),
+            balance: fp(50_000.0),
+            volatility: fp(0.001),
+            fill_probability: FixedPoint8::ONE,
+        }
+    }
+
+    #[test]
+    fn test_max_notional_binding() {
+        // liquidity 400, balance 250, notional 100, volatility 100
+        let d = model().size(&inputs());
+        assert_eq!(d.quantity, fp(100.0));
+        assert_eq!(d.constraint, SizeConstraint::MaxNotional);
+        assert_eq!(d.liquidity_cap, fp(400.0));
+        assert_eq!(d.balance_cap, fp(250.0));
+    }
+
+    #[test]
+    fn test_liquidity_binding() {
+        let d = model().size(&SizingInputs { sell_book_qty: fp(300.0), ..inputs() });
+        assert_eq!(d.quantity, fp(30.0));
+        assert_eq!(d.constraint, SizeConstraint::Liquidity);
+    }
+
+    #[test]
+    fn test_volatility_scales_down() {
+        // vol 2x target → half of max notional
+        let d = model().size(&SizingInputs { volatility: fp(0.004), ..inputs() });
+        assert_eq!(d.quantity, fp(50.0));
+        assert_eq!(d.constraint, SizeConstraint::Volatility);
+    }
+
+    #[test]
+    fn test_min_notional() {
+        let d = model().size(&SizingInputs { balance: fp(10.0), ..inputs() });
+        assert_eq!(d.quantity, FixedPoint8::ZERO);
+        assert_eq!(d.constraint, SizeConstraint::MinNotional);
+    }
+
+    #[test]
+    fn test_unlikely_fill_skipped() {
+        let d = model().size(&SizingInputs { fill_probability: fp(0.3), ..inputs() });
+        assert_eq!(d.quantity, FixedPoint8::ZERO);
+        assert_eq!(d.constraint, SizeConstraint::FillProbability);
+        assert_eq!(d.fill_probability, fp(0.3));
+        // Caps are still reported for the journal
+        assert_eq!(d.notional_cap, fp(100.0));
+    }
+
+    #[test]
+    fn test_top_n_qty() {
+        let levels = [(fp(100.0), fp(1.0)), (fp(99.0), fp(2.0)), (fp(98.0), fp(4.0))];
+        assert_eq!(top_n_qty(&levels, 2), fp(3.0));
+        assert_eq!(top_n_qty(&levels, 10), fp(7.0));
+    }
+}
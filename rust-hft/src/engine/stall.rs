@@ -0,0 +1,145 @@
+//! Event-loop stall detection
+//!
+//! The engine loop must never block: a synchronous call that sneaks into a
+//! handler delays every queued message behind it. Each loop iteration is
+//! timed from wake-up to completion, split into named stages. An iteration
+//! over the budget is a stall: it bumps the stall counter and is logged with
+//! its stages in folded-stack form (`engine;message;handle_message 12034`,
+//! microseconds), so grepped log lines feed straight into `flamegraph.pl`.
+//!
+//! Time is passed in by the caller; nothing allocates unless a stall is
+//! reported.
+
+use std::fmt::Write;
+use std::time::{Duration, Instant};
+
+/// Stages recorded per iteration (extra laps are merged into the last)
+const MAX_STAGES: usize = 4;
+
+/// Root frame of every folded stack
+const ROOT_FRAME: &str = "engine";
+
+/// Iteration that exceeded the budget
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stall {
+    /// Select branch that woke the loop
+    pub branch: &'static str,
+    pub duration: Duration,
+    /// One `engine;<branch>;<stage> <micros>` line per stage
+    pub folded: String,
+}
+
+/// Times loop iterations against a budget
+#[derive(Debug)]
+pub struct StallDetector {
+    /// Zero disables detection
+    budget: Duration,
+    started: Instant,
+    last_lap: Instant,
+    stages: [(&'static str, Duration); MAX_STAGES],
+    len: usize,
+}
+
+impl StallDetector {
+    pub fn new(budget: Duration) -> Self {
+        let now = Instant::now();
+        Self {
+            budget,
+            started: now,
+            last_lap: now,
+            stages: [("", Duration::ZERO); MAX_STAGES],
+            len: 0,
+        }
+    }
+
+    /// Detection off
+    pub fn disabled() -> Self {
+        Self::new(Duration::ZERO)
+    }
+
+    #[inline]
+    pub fn budget(&self) -> Duration {
+        self.budget
+    }
+
+    /// Loop woke up
+    #[inline]
+    pub fn begin(&mut self, now: Instant) {
+        self.started = now;
+        self.last_lap = now;
+        self.len = 0;
+    }
+
+    /// Stage `stage` finished at `now`
+    #[inline]
+    pub fn lap(&mut self, stage: &'static str, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.last_lap);
+        self.last_lap = now;
+        if self.len < MAX_STAGES {
+            self.stages[self.len] = (stage, elapsed);
+            self.len += 1;
+        } else {
+            self.stages[MAX_STAGES - 1].1 += elapsed;
+        }
+    }
+
+    /// Iteration of `branch` finished; the stall if over budget
+    #[inline]
+    pub fn finish(&mut self, branch: &'static str, now: Instant) -> Option<Stall> {
+        let duration = now.saturating_duration_since(self.started);
+        if self.budget.is_zero() || duration <= self.budget {
+            return None;
+        }
+        // Time after the last lap is attributed to the branch itself
+        let tail = now.saturating_duration_since(self.last_lap);
+        let mut folded = String::new();
+        for (stage, elapsed) in &self.stages[..self.len] {
+            let _ = writeln!(folded, "{};{};{} {}", ROOT_FRAME, branch, stage, elapsed.as_micros());
+        }
+        if !tail.is_zero() || self.len == 0 {
+            let _ = writeln!(folded, "{};{} {}", ROOT_FRAME, branch, tail.as_micros());
+        }
+        folded.pop();
+        Some(Stall { branch, duration, folded })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stall_detection() {
+        let t0 = Instant::now();
+        let ms = Duration::from_millis;
+        let mut detector = StallDetector::new(ms(10));
+
+        detector.begin(t0);
+        detector.lap("handle_message", t0 + ms(4));
+        assert_eq!(detector.finish("message", t0 + ms(5)), None);
+
+        detector.begin(t0 + ms(100));
+        detector.lap("handle_message", t0 + ms(125));
+        detector.lap("settle_attempts", t0 + ms(126));
+        let stall = detector.finish("message", t0 + ms(126)).unwrap();
+        assert_eq!((stall.branch, stall.duration), ("message", ms(26)));
+        assert_eq!(stall.folded, "engine;message;handle_message 25000\nengine;message;settle_attempts 1000");
+
+        // Unstaged branch: the whole iteration is the branch frame
+        detector.begin(t0 + ms(200));
+        let stall = detector.finish("delisting", t0 + ms(230)).unwrap();
+        assert_eq!(stall.folded, "engine;delisting 30000");
+
+        // Laps past the stage capacity merge into the last stage
+        detector.begin(t0);
+        for i in 1..=6 {
+            detector.lap("stage", t0 + ms(i * 5));
+        }
+        let stall = detector.finish("message", t0 + ms(30)).unwrap();
+        assert!(stall.folded.ends_with("engine;message;stage 15000"), "{}", stall.folded);
+
+        let mut disabled = StallDetector::disabled();
+        disabled.begin(t0);
+        assert_eq!(disabled.finish("message", t0 + Duration::from_secs(5)), None);
+    }
+}
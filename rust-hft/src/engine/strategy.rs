@@ -0,0 +1,200 @@
+//! Strategy plug-in interface
+//!
+//! Strategies receive market events from the engine loop and return
+//! actions for the engine to carry out. The engine owns the strategies
+//! and calls them sequentially from its single consumer task, so
+//! implementations need no internal locking.
+
+use crate::core::{DelistingNotice, FixedPoint8, Side, Symbol, TickerData, TradeData};
+use crate::exchanges::Exchange;
+use crate::hot_path::SpreadEvent;
+
+/// Order request produced by a strategy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct OrderRequest {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub quantity: FixedPoint8,
+    /// Limit price (None = market order)
+    pub price: Option<FixedPoint8>,
+}
+
+impl OrderRequest {
+    /// Limit price as an order parameter at the precision of `tick` (the
+    /// instrument's tick size; all 8 decimals if unknown), e.g. "25000.5"
+    pub fn price_param(&self, tick: Option<FixedPoint8>) -> Option<String> {
+        self.price.map(|price| order_param(price, tick))
+    }
+
+    /// Quantity as an order parameter at the precision of the lot `step`
+    pub fn quantity_param(&self, step: Option<FixedPoint8>) -> String {
+        order_param(self.quantity, step)
+    }
+}
+
+fn order_param(value: FixedPoint8, step: Option<FixedPoint8>) -> String {
+    let mut buf = [0u8; 32];
+    let len = value.write_trimmed(&mut buf, step.map_or(8, |s| s.decimals()));
+    String::from_utf8_lossy(&buf[..len]).into_owned()
+}
+
+/// Action requested by a strategy
+#[derive(Debug, Clone, PartialEq)]
+pub enum Action {
+    /// Place a new order
+    PlaceOrder(OrderRequest),
+    /// Cancel all open orders for symbol on exchange
+    CancelAll { exchange: Exchange, symbol: Symbol },
+}
+
+/// Execution report delivered back to strategies
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Fill {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub price: FixedPoint8,
+    pub quantity: FixedPoint8,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+/// User-provided trading strategy
+///
+/// All callbacks default to no-op so a strategy only implements the events
+/// it cares about. Returning an empty `Vec` does not allocate.
+pub trait Strategy: Send {
+    /// Strategy name (for logging/metrics)
+    fn name(&self) -> &str;
+
+    /// Called on every ticker update
+    fn on_ticker(&mut self, _exchange: Exchange, _ticker: &TickerData) -> Vec<Action> {
+        Vec::new()
+    }
+
+    /// Called on every trade
+    fn on_trade(&mut self, _exchange: Exchange, _trade: &TradeData) -> Vec<Action> {
+        Vec::new()
+    }
+
+    /// Called when the tracker produces a new cross-exchange spread
+    fn on_spread(&mut self, _event: &SpreadEvent) -> Vec<Action> {
+        Vec::new()
+    }
+
+    /// Called when an order placed by this strategy is filled
+    fn on_fill(&mut self, _fill: &Fill) -> Vec<Action> {
+        Vec::new()
+    }
+
+    /// Called once when a symbol is scheduled for delisting
+    ///
+    /// The engine flattens known positions and blocks new orders in the
+    /// symbol itself; strategies only need to drop their own state.
+    fn on_delisting(&mut self, _notice: &DelistingNotice) -> Vec<Action> {
+        Vec::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Bps;
+    use crate::test_utils::init_test_registry;
+
+    /// Buys the cheap leg whenever the spread crosses a threshold
+    struct ThresholdStrategy {
+        threshold: Bps,
+        fills: usize,
+    }
+
+    impl Strategy for ThresholdStrategy {
+        fn name(&self) -> &str {
+            "threshold"
+        }
+
+        fn on_spread(&mut self, event: &SpreadEvent) -> Vec<Action> {
+            if event.spread < self.threshold {
+                return Vec::new();
+            }
+            vec![Action::PlaceOrder(OrderRequest {
+                exchange: event.long_ex,
+                symbol: event.symbol,
+                side: Side::Buy,
+                quantity: FixedPoint8::ONE,
+                price: None,
+            })]
+        }
+
+        fn on_fill(&mut self, _fill: &Fill) -> Vec<Action> {
+            self.fills += 1;
+            Vec::new()
+        }
+    }
+
+    #[test]
+    fn test_default_callbacks_are_noop() {
+        init_test_registry();
+        struct Noop;
+        impl Strategy for Noop {
+            fn name(&self) -> &str {
+                "noop"
+            }
+        }
+
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = TickerData::new(sym, FixedPoint8::ONE, FixedPoint8::ONE, FixedPoint8::ONE, FixedPoint8::ONE, 0);
+        let mut s = Noop;
+        assert!(s.on_ticker(Exchange::Binance, &ticker).is_empty());
+    }
+
+    #[test]
+    fn test_strategy_actions() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut strategy: Box<dyn Strategy> = Box::new(ThresholdStrategy {
+            threshold: Bps::from_bps(25),
+            fills: 0,
+        });
+
+        let mut event = SpreadEvent {
+            symbol: sym,
+            spread: Bps::from_bps(10),
+            long_ex: Exchange::Bybit,
+            short_ex: Exchange::Binance,
+            timestamp: 1000,
+            spread_ticks: None,
+        };
+        assert!(strategy.on_spread(&event).is_empty());
+
+        event.spread = Bps::from_bps(30);
+        let actions = strategy.on_spread(&event);
+        assert_eq!(actions.len(), 1);
+        match &actions[0] {
+            Action::PlaceOrder(order) => {
+                assert_eq!(order.exchange, Exchange::Bybit);
+                assert_eq!(order.side, Side::Buy);
+            }
+            other => panic!("unexpected action {:?}", other),
+        }
+    }
+
+    #[test]
+    fn test_order_params() {
+        init_test_registry();
+        let order = OrderRequest {
+            exchange: Exchange::Binance,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side: Side::Buy,
+            quantity: FixedPoint8::from_raw(150_000), // 0.0015
+            price: Some(FixedPoint8::from_raw(2_500_050_000_000)), // 25000.5
+        };
+        let tick = FixedPoint8::from_raw(10_000_000); // 0.1
+        let step = FixedPoint8::from_raw(100_000); // 0.001
+        assert_eq!(order.price_param(Some(tick)).as_deref(), Some("25000.5"));
+        assert_eq!(order.price_param(None).as_deref(), Some("25000.5"));
+        assert_eq!(order.quantity_param(Some(step)), "0.001");
+        assert_eq!(OrderRequest { price: None, ..order }.price_param(Some(tick)), None);
+    }
+}
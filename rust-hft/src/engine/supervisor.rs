@@ -0,0 +1,688 @@
+//! Feed task supervision
+//!
+//! Each exchange connection runs as its own actor task. The [`Supervisor`]
+//! owns a factory closure per feed, so when a run ends (connection closed,
+//! connect/subscribe failure, repeated read errors or a panic) it can build
+//! a fresh client and restart it according to its [`RestartPolicy`]. A
+//! restarted feed resubscribes every live symbol (delisted ones excluded)
+//! and re-applies the current focus set. Task state is published through
+//! [`MetricsCollector::tasks`] and surfaced in `/api/health`. With a flood
+//! limit each run meters its inbound messages through a [`FloodGuard`].
+//! Connected feeds per venue are published as [`VenueFeeds`]; a venue is
+//! up while any of its feeds is connected. Symbols beyond one connection's
+//! [`VenueCapacity`] are sharded across extra connections built from the
+//! same factory, within the per-venue connection cap. Every (re)connect
+//! first asks the venue's reconnect circuit ([`MetricsCollector::circuits`]):
+//! after repeated connect failures all feeds of that venue hold off until a
+//! single probe connect succeeds, and the circuit opening is alerted.
+
+use super::flood::{Admission, FloodEvent, FloodGuard};
+use super::focus;
+use crate::core::{clock, Symbol};
+use crate::exchanges::{Exchange, ExchangeClient, ExchangeMessage};
+use crate::infrastructure::config::SupervisionConfig;
+use crate::infrastructure::metrics::{MetricsCollector, TaskState};
+use crate::infrastructure::startup::StartupStage;
+use crate::ws::subscription::VenueCapacity;
+use crate::ws::{CircuitBreakerConfig, ConnectionId};
+use crate::{HftError, Result};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+/// Builds a fresh client for every (re)start of a feed
+pub type ExchangeFactory = Arc<dyn Fn() -> ExchangeClient + Send + Sync>;
+
+/// Message forwarded to the engine: (connection, arrival time, message)
+pub type FeedMessage = (ConnectionId, Instant, ExchangeMessage);
+
+/// Connected feeds per venue (bit per feed index, indexed by Exchange::index)
+pub type VenueFeeds = [u64; Exchange::COUNT];
+
+/// One planned connection: (factory index, first client, symbol shard)
+type FeedPlan = (usize, ExchangeClient, Arc<[Symbol]>);
+
+/// Consecutive read errors that end a run
+const MAX_CONSECUTIVE_ERRORS: u32 = 10;
+
+/// Delay after a read error before polling again
+const ERROR_RETRY_DELAY: Duration = Duration::from_secs(1);
+
+/// Recheck interval while another feed's probe holds the venue circuit
+const CIRCUIT_RECHECK: Duration = Duration::from_secs(1);
+
+/// Restart decision for exited feed tasks (exponential backoff)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RestartPolicy {
+    /// Consecutive restarts before giving up (None = unlimited)
+    max_restarts: Option<u32>,
+    initial_backoff: Duration,
+    /// Backoff cap; a run at least this long resets the backoff
+    max_backoff: Duration,
+}
+
+impl Default for RestartPolicy {
+    fn default() -> Self {
+        Self::new(&SupervisionConfig::default())
+    }
+}
+
+impl RestartPolicy {
+    pub fn new(config: &SupervisionConfig) -> Self {
+        Self {
+            max_restarts: config.max_restarts,
+            initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+            max_backoff: Duration::from_millis(config.max_backoff_ms.max(config.initial_backoff_ms)),
+        }
+    }
+
+    /// Never restart: a feed ends with its first run
+    pub const fn never() -> Self {
+        Self {
+            max_restarts: Some(0),
+            initial_backoff: Duration::ZERO,
+            max_backoff: Duration::ZERO,
+        }
+    }
+
+    /// Delay before consecutive restart `attempt` (0-based); None = give up
+    pub fn backoff(&self, attempt: u32) -> Option<Duration> {
+        if self.max_restarts.is_some_and(|max| attempt >= max) {
+            return None;
+        }
+        let factor = 1u32 << attempt.min(31);
+        Some(self.initial_backoff.saturating_mul(factor).min(self.max_backoff))
+    }
+
+    /// A run this long counts as healthy and resets the backoff
+    #[inline]
+    fn is_healthy_run(&self, ran: Duration) -> bool {
+        ran >= self.max_backoff
+    }
+}
+
+/// Engine-side channels every feed run attaches to
+#[derive(Clone)]
+pub struct FeedLinks {
+    pub tx: mpsc::Sender<FeedMessage>,
+    pub metrics: Arc<MetricsCollector>,
+    /// Focused set (None = auto-focus disabled)
+    pub focus: Option<watch::Receiver<Vec<Symbol>>>,
+    /// Symbols scheduled for delisting (unsubscribed, never resubscribed)
+    pub delisted: watch::Receiver<Vec<Symbol>>,
+    /// Connected feeds per venue
+    pub venues: Arc<watch::Sender<VenueFeeds>>,
+}
+
+/// Supervisor settings every feed task is spawned with
+#[derive(Debug, Clone, Copy)]
+struct FeedSettings {
+    policy: RestartPolicy,
+    /// Inbound messages per second (None = unlimited)
+    flood_limit: Option<u32>,
+}
+
+/// Why a single feed run ended
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum TaskExit {
+    /// Exchange closed the connection
+    Closed,
+    /// Connect/subscribe failure, repeated read errors or panic
+    Failed(String),
+    /// Engine dropped the receiver: stop without restart
+    EngineStopped,
+}
+
+/// Owns feed factories and spawns one supervised task per feed
+#[derive(Default)]
+pub struct Supervisor {
+    factories: Vec<ExchangeFactory>,
+    policy: RestartPolicy,
+    /// Inbound messages per second per connection (None = unlimited)
+    flood_limit: Option<u32>,
+    /// Connections per venue across feeds and shards (None = unlimited)
+    max_connections: Option<usize>,
+    /// Per-venue reconnect circuit settings
+    circuit: CircuitBreakerConfig,
+}
+
+impl Supervisor {
+    pub fn new(policy: RestartPolicy) -> Self {
+        Self {
+            factories: Vec::new(),
+            policy,
+            flood_limit: None,
+            max_connections: None,
+            circuit: CircuitBreakerConfig::default(),
+        }
+    }
+
+    /// Replace the restart policy (applies to tasks spawned afterwards)
+    pub fn set_policy(&mut self, policy: RestartPolicy) {
+        self.policy = policy;
+    }
+
+    /// Set the per-connection inbound rate limit (applies to tasks spawned afterwards)
+    pub fn set_flood_limit(&mut self, max_messages_per_sec: Option<u32>) {
+        self.flood_limit = max_messages_per_sec;
+    }
+
+    /// Set the per-venue connection cap (applies to tasks spawned afterwards)
+    pub fn set_max_connections(&mut self, max_connections_per_venue: Option<usize>) {
+        self.max_connections = max_connections_per_venue;
+    }
+
+    /// Set the per-venue reconnect circuit (applies to tasks spawned afterwards)
+    pub fn set_circuit_breaker(&mut self, config: CircuitBreakerConfig) {
+        self.circuit = config;
+    }
+
+    /// Add feed; `factory` is called once per (re)start
+    pub fn add(&mut self, factory: ExchangeFactory) {
+        self.factories.push(factory);
+    }
+
+    /// Number of feeds
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.factories.len()
+    }
+
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.factories.is_empty()
+    }
+
+    /// Split every factory's feed into connections that fit its venue
+    ///
+    /// Fails when a venue would need more connections than the cap allows.
+    fn plan(&self, symbols: &[Symbol]) -> Result<Vec<FeedPlan>> {
+        let mut feeds = Vec::with_capacity(self.factories.len());
+        let mut per_venue = [0usize; Exchange::COUNT];
+        for (index, factory) in self.factories.iter().enumerate() {
+            let client = factory();
+            let exchange = client.exchange();
+            let capacity = venue_capacity(exchange);
+            let shards = capacity.connections_for(symbols.len()).max(1);
+            per_venue[exchange.index()] += shards;
+            if let Some(max) = self.max_connections.filter(|&max| per_venue[exchange.index()] > max) {
+                return Err(HftError::Config(format!(
+                    "{} needs {} connections for {} symbols ({} per connection at {} streams per symbol, \
+                     {} streams per connection) but supervision.max_connections_per_venue = {}",
+                    exchange.name(),
+                    per_venue[exchange.index()],
+                    symbols.len(),
+                    capacity.symbols_per_connection(),
+                    capacity.streams_per_symbol,
+                    capacity.streams_per_connection,
+                    max
+                )));
+            }
+            let mut client = Some(client);
+            for shard in symbols.chunks(capacity.symbols_per_connection()) {
+                feeds.push((index, client.take().unwrap_or_else(|| factory()), shard.into()));
+            }
+            if let Some(client) = client {
+                feeds.push((index, client, Arc::from([])));
+            }
+        }
+        if feeds.len() > u64::BITS as usize {
+            return Err(HftError::Config(format!("{} feed connections exceed the limit of {}", feeds.len(), u64::BITS)));
+        }
+        Ok(feeds)
+    }
+
+    /// Spawn one supervised task per connection (feed id = registration order)
+    ///
+    /// A feed whose symbols exceed one connection's capacity is sharded
+    /// across several connections. Labels connections as "<exchange>#<n>"
+    /// for the race and task metrics. Tasks end once their policy gives up
+    /// or the engine drops the receiving end of `links.tx`. Fails without
+    /// spawning anything when the symbols cannot fit the venue caps.
+    pub fn spawn(&self, symbols: &[Symbol], links: FeedLinks) -> Result<Vec<JoinHandle<()>>> {
+        let feeds = self.plan(symbols)?;
+        let mut labels: Vec<String> = Vec::with_capacity(feeds.len());
+        for (_, client, _) in &feeds {
+            let n = labels.iter().filter(|l| l.starts_with(client.name())).count();
+            labels.push(format!("{}#{}", client.name(), n));
+        }
+        links.metrics.feed_race().set_labels(labels.clone());
+        links.metrics.tasks().register(&labels);
+        links.metrics.circuits().configure(self.circuit);
+
+        Ok(feeds
+            .into_iter()
+            .zip(labels)
+            .enumerate()
+            .map(|(feed, ((index, client, shard), label))| {
+                tokio::spawn(supervise(
+                    feed,
+                    label,
+                    client,
+                    self.factories[index].clone(),
+                    FeedSettings {
+                        policy: self.policy,
+                        flood_limit: self.flood_limit,
+                    },
+                    shard,
+                    links.clone(),
+                ))
+            })
+            .collect())
+    }
+}
+
+/// Subscription limits of one `exchange` connection
+fn venue_capacity(exchange: Exchange) -> VenueCapacity {
+    match exchange {
+        Exchange::Binance => VenueCapacity::BINANCE,
+        Exchange::Bybit => VenueCapacity::BYBIT,
+    }
+}
+
+/// Restart loop for one feed
+async fn supervise(
+    feed: usize,
+    label: String,
+    first: ExchangeClient,
+    factory: ExchangeFactory,
+    settings: FeedSettings,
+    symbols: Arc<[Symbol]>,
+    links: FeedLinks,
+) {
+    let FeedSettings { policy, flood_limit } = settings;
+    let tasks = || links.metrics.tasks();
+    let exchange = first.exchange();
+    let mut next = Some(first);
+    let mut attempt = 0;
+    loop {
+        // An open circuit holds every feed of the venue until its probe is due
+        while !links.metrics.circuits().allow_attempt(exchange, Instant::now()) {
+            tasks().set_state(feed, TaskState::Restarting);
+            let retry_in = links.metrics.circuits().status(exchange, Instant::now()).retry_in_ms;
+            tokio::select! {
+                _ = tokio::time::sleep(Duration::from_millis(retry_in).max(CIRCUIT_RECHECK)) => {}
+                _ = links.tx.closed() => {
+                    tasks().set_state(feed, TaskState::Stopped);
+                    return;
+                }
+            }
+        }
+        let client = next.take().unwrap_or_else(|| factory());
+        tasks().set_state(feed, TaskState::Starting);
+        let started = Instant::now();
+
+        // Run in its own task so a panic ends only this run
+        let exit = tokio::spawn(run_feed(feed, label.clone(), client, flood_limit, symbols.clone(), links.clone()))
+            .await
+            .unwrap_or_else(|e| TaskExit::Failed(format!("task panicked: {}", e)));
+        set_connected(&links.metrics, &links.venues, feed, exchange, false);
+
+        let (reason, closed) = match exit {
+            TaskExit::EngineStopped => {
+                tasks().set_state(feed, TaskState::Stopped);
+                return;
+            }
+            TaskExit::Closed => ("connection closed".to_string(), true),
+            TaskExit::Failed(reason) => (reason, false),
+        };
+        tracing::warn!("{} exited: {}", label, reason);
+        tasks().record_exit(feed, reason);
+
+        if policy.is_healthy_run(started.elapsed()) {
+            attempt = 0;
+        }
+        let Some(delay) = policy.backoff(attempt) else {
+            let state = if closed { TaskState::Stopped } else { TaskState::Failed };
+            tracing::error!("{} not restarted after {} consecutive attempts ({:?})", label, attempt, state);
+            tasks().set_state(feed, state);
+            return;
+        };
+        attempt += 1;
+        tasks().set_state(feed, TaskState::Restarting);
+        tasks().record_restart(feed);
+        tracing::warn!("Restarting {} in {:?} (attempt {})", label, delay, attempt);
+        tokio::time::sleep(delay).await;
+
+        if links.tx.is_closed() {
+            tasks().set_state(feed, TaskState::Stopped);
+            return;
+        }
+    }
+}
+
+/// Connect, subscribe and forward messages until the connection ends
+async fn run_feed(
+    feed: usize,
+    label: String,
+    mut exchange: ExchangeClient,
+    flood_limit: Option<u32>,
+    symbols: Arc<[Symbol]>,
+    links: FeedLinks,
+) -> TaskExit {
+    let FeedLinks {
+        tx,
+        metrics,
+        focus: mut focus_rx,
+        delisted: mut delisted_rx,
+        venues,
+    } = links;
+
+    tracing::info!("Connecting {}...", label);
+    if let Err(e) = exchange.connect().await {
+        if metrics.circuits().record_failure(exchange.exchange(), Instant::now()) {
+            let status = metrics.circuits().status(exchange.exchange(), Instant::now());
+            tracing::error!(
+                "ALERT: reconnect circuit open for {} after {} consecutive connect failures, next probe in {}ms: {}",
+                exchange.name(),
+                status.consecutive_failures,
+                status.retry_in_ms,
+                e
+            );
+        }
+        return TaskExit::Failed(format!("connect failed: {}", e));
+    }
+    metrics.circuits().record_success(exchange.exchange());
+    set_connected(&metrics, &venues, feed, exchange.exchange(), true);
+
+    // Delisted symbols are never resubscribed
+    let mut removed = delisted_rx.borrow_and_update().clone();
+    let live: Vec<Symbol> = symbols.iter().copied().filter(|s| !removed.contains(s)).collect();
+
+    tracing::info!("Subscribing to {} symbols on {}...", live.len(), label);
+    if let Err(e) = exchange.subscribe_tickers(&live).await {
+        return TaskExit::Failed(format!("ticker subscription failed: {}", e));
+    }
+    if let Err(e) = exchange.subscribe_mark_prices(&live).await {
+        return TaskExit::Failed(format!("mark price subscription failed: {}", e));
+    }
+    if let Err(e) = exchange.subscribe_liquidations(&live).await {
+        return TaskExit::Failed(format!("liquidation subscription failed: {}", e));
+    }
+
+    // A fresh connection has no focused streams: re-apply the current set
+    let mut upgraded: Vec<Symbol> = Vec::new();
+    if let Some(rx) = focus_rx.as_mut() {
+        rx.mark_changed();
+    }
+    metrics.tasks().set_state(feed, TaskState::Running);
+    if metrics.tasks().all_running() {
+        metrics.startup().observe(StartupStage::Subscriptions, clock::now());
+    }
+    tracing::info!("Started message loop for {}", label);
+
+    let connection = ConnectionId(feed as u64);
+    let mut flood = flood_limit.map(|limit| FloodGuard::new(limit, clock::now()));
+    let mut errors = 0;
+    loop {
+        match exchange.next_message().await {
+            Ok(Some(msg)) => {
+                errors = 0;
+                // Stamp arrival before channel queueing for race measurement
+                let arrived = clock::now();
+                metrics.connections().record(feed, arrived, exchange_latency(&msg));
+                if let Some(buffers) = exchange.buffer_stats() {
+                    metrics.debug().record_buffers(feed, buffers.read_buffer_capacity, buffers.max_frame_len);
+                    metrics.debug().record_send_lanes(feed, &buffers.send_lanes);
+                    metrics.debug().record_server_pings(feed, &buffers.server_pings);
+                }
+                if let Some(guard) = flood.as_mut() {
+                    // Held-back updates are older: they get the budget first
+                    while let Some(held) = guard.release(arrived) {
+                        if tx.send((connection, held.0, held.1)).await.is_err() {
+                            return TaskExit::EngineStopped;
+                        }
+                    }
+                    match guard.admit(msg, arrived) {
+                        Admission::Forward(msg) => {
+                            if tx.send((connection, arrived, msg)).await.is_err() {
+                                return TaskExit::EngineStopped;
+                            }
+                        }
+                        Admission::Held => {}
+                        Admission::Conflated => metrics.connections().record_conflated(feed),
+                        Admission::Dropped => metrics.connections().record_dropped(feed),
+                    }
+                    match guard.poll_event(arrived) {
+                        Some(FloodEvent::Started) => tracing::warn!(
+                            "{} over {} msg/s: conflating tickers, dropping other market data",
+                            label,
+                            flood_limit.unwrap_or_default()
+                        ),
+                        Some(FloodEvent::Ended { duration, conflated, dropped }) => tracing::warn!(
+                            "{} flood ended after {:?}: {} updates conflated, {} dropped",
+                            label,
+                            duration,
+                            conflated,
+                            dropped
+                        ),
+                        None => {}
+                    }
+                } else if tx.send((connection, arrived, msg)).await.is_err() {
+                    return TaskExit::EngineStopped;
+                }
+                if delisted_rx.has_changed().unwrap_or(false) {
+                    let delisted = delisted_rx.borrow_and_update().clone();
+                    let fresh: Vec<Symbol> = delisted
+                        .iter()
+                        .copied()
+                        .filter(|s| !removed.contains(s) && symbols.contains(s))
+                        .collect();
+                    match exchange.unsubscribe_all(&fresh).await {
+                        Ok(()) => removed = delisted,
+                        Err(e) => tracing::error!("{} failed to unsubscribe delisted symbols: {}", label, e),
+                    }
+                }
+                if let Some(rx) = focus_rx.as_mut() {
+                    if rx.has_changed().unwrap_or(false) {
+                        // Each shard upgrades only the symbols it carries
+                        let target: Vec<Symbol> =
+                            rx.borrow_and_update().iter().copied().filter(|s| symbols.contains(s)).collect();
+                        focus::apply_focus(&mut exchange, &mut upgraded, &target).await;
+                    }
+                }
+            }
+            Ok(None) => return TaskExit::Closed,
+            Err(e) => {
+                errors += 1;
+                tracing::error!("{} error ({}/{}): {}", label, errors, MAX_CONSECUTIVE_ERRORS, e);
+                if errors >= MAX_CONSECUTIVE_ERRORS {
+                    return TaskExit::Failed(format!("{} consecutive read errors, last: {}", errors, e));
+                }
+                tokio::time::sleep(ERROR_RETRY_DELAY).await;
+            }
+        }
+    }
+}
+
+/// Exchange event time → now (None without an exchange timestamp)
+///
+/// Clock skew between the venue and this host is included; a skewed clock
+/// ahead of ours reads as zero.
+fn exchange_latency(msg: &ExchangeMessage) -> Option<Duration> {
+    let sent = msg.timestamp()?;
+    Some(Duration::from_nanos(clock::coarse_unix_nanos().saturating_sub(sent)))
+}
+
+/// Mark one feed of `exchange` (dis)connected and refresh the venue flag in metrics
+fn set_connected(metrics: &MetricsCollector, venues: &watch::Sender<VenueFeeds>, feed: usize, exchange: Exchange, connected: bool) {
+    let bit = 1u64 << (feed % u64::BITS as usize);
+    venues.send_if_modified(|venues| {
+        let before = venues[exchange.index()];
+        if connected {
+            venues[exchange.index()] |= bit;
+        } else {
+            venues[exchange.index()] &= !bit;
+        }
+        venues[exchange.index()] != before
+    });
+    let up = venues.borrow()[exchange.index()] != 0;
+    if venues.borrow().iter().all(|feeds| *feeds != 0) {
+        metrics.startup().observe(StartupStage::Connections, clock::now());
+    }
+    match exchange {
+        Exchange::Binance => metrics.set_binance_connected(up),
+        Exchange::Bybit => metrics.set_bybit_connected(up),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::exchanges::{BinanceWsClient, BybitWsClient};
+    use crate::ws::CircuitState;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+
+    #[test]
+    fn test_backoff() {
+        let policy = RestartPolicy::new(&SupervisionConfig {
+            max_restarts: Some(3),
+            initial_backoff_ms: 100,
+            max_backoff_ms: 300,
+            ..SupervisionConfig::default()
+        });
+        assert_eq!(policy.backoff(0), Some(Duration::from_millis(100)));
+        assert_eq!(policy.backoff(1), Some(Duration::from_millis(200)));
+        assert_eq!(policy.backoff(2), Some(Duration::from_millis(300)));
+        assert_eq!(policy.backoff(3), None);
+        assert!(policy.is_healthy_run(Duration::from_millis(300)));
+
+        assert_eq!(RestartPolicy::never().backoff(0), None);
+        assert_eq!(RestartPolicy::default().backoff(u32::MAX), Some(Duration::from_secs(30)));
+    }
+
+    #[tokio::test]
+    async fn test_failed_feed_rebuilt_until_policy_gives_up() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = built.clone();
+        let mut supervisor = Supervisor::new(RestartPolicy::new(&SupervisionConfig {
+            max_restarts: Some(2),
+            initial_backoff_ms: 1,
+            max_backoff_ms: 1_000,
+            ..SupervisionConfig::default()
+        }));
+        // Nothing listens on port 1: every connect fails
+        supervisor.add(Arc::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            ExchangeClient::Binance(BinanceWsClient::new().with_url("ws://127.0.0.1:1"))
+        }));
+
+        let metrics = Arc::new(MetricsCollector::new());
+        let (tx, _rx) = mpsc::channel(1);
+        let links = FeedLinks {
+            tx,
+            metrics: metrics.clone(),
+            focus: None,
+            delisted: watch::Sender::new(Vec::new()).subscribe(),
+            venues: Arc::new(watch::Sender::new([0; Exchange::COUNT])),
+        };
+        for handle in supervisor.spawn(&[], links).unwrap() {
+            handle.await.unwrap();
+        }
+
+        assert_eq!(built.load(Ordering::Relaxed), 3);
+        let tasks = metrics.tasks().snapshot();
+        assert_eq!(tasks[0].label, "binance#0");
+        assert_eq!(tasks[0].state, TaskState::Failed);
+        assert_eq!(tasks[0].restarts, 2);
+        assert!(tasks[0].last_error.as_deref().unwrap().starts_with("connect failed"));
+        assert_eq!(metrics.snapshot().failed_tasks, 1);
+        assert!(!metrics.is_connected());
+    }
+
+    #[tokio::test]
+    async fn test_connect_failures_open_venue_circuit() {
+        let built = Arc::new(AtomicUsize::new(0));
+        let counter = built.clone();
+        let mut supervisor = Supervisor::new(RestartPolicy::new(&SupervisionConfig {
+            initial_backoff_ms: 1,
+            max_backoff_ms: 1_000,
+            ..SupervisionConfig::default()
+        }));
+        supervisor.set_circuit_breaker(CircuitBreakerConfig {
+            failure_threshold: 2,
+            open_duration: Duration::from_secs(60),
+        });
+        supervisor.add(Arc::new(move || {
+            counter.fetch_add(1, Ordering::Relaxed);
+            ExchangeClient::Bybit(BybitWsClient::new().with_url("ws://127.0.0.1:1"))
+        }));
+
+        let metrics = Arc::new(MetricsCollector::new());
+        let (tx, rx) = mpsc::channel(1);
+        let links = FeedLinks {
+            tx,
+            metrics: metrics.clone(),
+            focus: None,
+            delisted: watch::Sender::new(Vec::new()).subscribe(),
+            venues: Arc::new(watch::Sender::new([0; Exchange::COUNT])),
+        };
+        let handles = supervisor.spawn(&[], links).unwrap();
+
+        tokio::time::timeout(Duration::from_secs(5), async {
+            while metrics.circuits().status(Exchange::Bybit, Instant::now()).state != CircuitState::Open {
+                tokio::time::sleep(Duration::from_millis(5)).await;
+            }
+        })
+        .await
+        .unwrap();
+        // Open circuit: no further client is built despite unlimited restarts
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(built.load(Ordering::Relaxed), 2);
+        let health = metrics.circuits().health(Instant::now());
+        assert_eq!(health[Exchange::Bybit.index()].circuit.times_opened, 1);
+        assert_eq!(health[Exchange::Binance.index()].circuit.state, CircuitState::Closed);
+        assert_eq!(metrics.tasks().snapshot()[0].state, TaskState::Restarting);
+
+        // A held feed still stops with the engine
+        drop(rx);
+        for handle in handles {
+            tokio::time::timeout(Duration::from_secs(1), handle).await.unwrap().unwrap();
+        }
+        assert_eq!(metrics.tasks().snapshot()[0].state, TaskState::Stopped);
+    }
+
+    #[test]
+    fn test_plan_shards_symbols_within_venue_caps() {
+        let mut supervisor = Supervisor::new(RestartPolicy::never());
+        supervisor.add(Arc::new(|| ExchangeClient::Binance(BinanceWsClient::new())));
+        supervisor.add(Arc::new(|| ExchangeClient::Bybit(BybitWsClient::new())));
+        let symbols: Vec<Symbol> = (1..=600).map(Symbol::from_raw).collect();
+
+        // 600 symbols: 3 Binance connections (256 each), 3 Bybit (200 each)
+        let Ok(plan) = supervisor.plan(&symbols) else { panic!("no connection cap") };
+        let shards: Vec<(usize, usize)> = plan.iter().map(|(index, _, shard)| (*index, shard.len())).collect();
+        assert_eq!(shards, vec![(0, 256), (0, 256), (0, 88), (1, 200), (1, 200), (1, 200)]);
+        assert_eq!(plan[2].2[0], Symbol::from_raw(513));
+        assert_eq!(supervisor.plan(&[]).map(|plan| plan.len()).ok(), Some(2));
+
+        supervisor.set_max_connections(Some(2));
+        let err = supervisor.plan(&symbols).err().unwrap().to_string();
+        assert!(err.contains("binance needs 3 connections for 600 symbols"), "{}", err);
+        assert!(supervisor.plan(&symbols[..400]).is_ok());
+    }
+
+    #[test]
+    fn test_venue_up_while_any_feed_connected() {
+        let metrics = MetricsCollector::new();
+        let tx = watch::Sender::new([0; Exchange::COUNT]);
+        let mut venues = tx.subscribe();
+
+        set_connected(&metrics, &tx, 0, Exchange::Bybit, true);
+        set_connected(&metrics, &tx, 1, Exchange::Bybit, true);
+        assert!(venues.has_changed().unwrap());
+        assert_eq!(*venues.borrow_and_update(), [0, 0b11]);
+
+        // One redundant feed drops: the venue stays up
+        set_connected(&metrics, &tx, 0, Exchange::Bybit, false);
+        assert!(metrics.snapshot().bybit_connected);
+        set_connected(&metrics, &tx, 1, Exchange::Bybit, false);
+        assert_eq!(*venues.borrow_and_update(), [0, 0]);
+        assert!(!metrics.is_connected());
+
+        // Repeated disconnects (failed connects) are not changes
+        set_connected(&metrics, &tx, 1, Exchange::Bybit, false);
+        assert!(!venues.has_changed().unwrap());
+    }
+}
@@ -0,0 +1,196 @@
+//! Per-symbol trade throttling
+//!
+//! After an execution attempt (filled or failed) a symbol is held in
+//! cooldown on that venue, and the number of attempts per rolling minute is
+//! capped. State is kept per (symbol, exchange) so the two legs of one
+//! opportunity, sent back to back, do not throttle each other; the next
+//! opportunity on the symbol cools down on both venues. State is indexed by
+//! Symbol ID like the tracker; time is passed in by the caller so decisions
+//! are deterministic.
+
+use crate::core::{Symbol, MAX_SYMBOLS};
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::ExecutionConfig;
+use std::collections::{HashMap, VecDeque};
+use std::time::{Duration, Instant};
+
+/// Rate limit window
+const RATE_WINDOW: Duration = Duration::from_secs(60);
+
+/// Reason a signal was suppressed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Suppressed {
+    /// Symbol traded too recently
+    Cooldown { remaining: Duration },
+    /// Max trades per minute reached
+    RateLimit,
+}
+
+#[derive(Debug)]
+struct SymbolThrottle {
+    cooldown: Duration,
+    last_attempt: Option<Instant>,
+    /// Attempt times within RATE_WINDOW, oldest first
+    recent: VecDeque<Instant>,
+}
+
+/// Cooldown and rate limiter for all symbols
+#[derive(Debug)]
+pub struct Throttle {
+    default_cooldown: Duration,
+    max_per_minute: usize,
+    /// Cooldown overrides by symbol name, resolved when a symbol is first seen
+    overrides: HashMap<String, Duration>,
+    /// Indexed by symbol ID, then exchange index
+    states: Vec<Option<SymbolThrottle>>,
+}
+
+impl Throttle {
+    /// Create throttle from config
+    pub fn new(config: &ExecutionConfig) -> Self {
+        let mut states = Vec::with_capacity(MAX_SYMBOLS * Exchange::COUNT);
+        states.resize_with(MAX_SYMBOLS * Exchange::COUNT, || None);
+        Self {
+            default_cooldown: Duration::from_millis(config.cooldown_ms),
+            max_per_minute: config.max_trades_per_minute as usize,
+            overrides: config
+                .symbol_cooldown_ms
+                .iter()
+                .map(|(name, ms)| (name.to_ascii_uppercase(), Duration::from_millis(*ms)))
+                .collect(),
+            states,
+        }
+    }
+
+    /// Check whether symbol may be traded on `exchange` at `now`
+    pub fn check(&mut self, symbol: Symbol, exchange: Exchange, now: Instant) -> Result<(), Suppressed> {
+        let max = self.max_per_minute;
+        let Some(state) = self.state(symbol, exchange) else {
+            return Ok(());
+        };
+
+        if let Some(last) = state.last_attempt {
+            let elapsed = now.saturating_duration_since(last);
+            if elapsed < state.cooldown {
+                return Err(Suppressed::Cooldown {
+                    remaining: state.cooldown - elapsed,
+                });
+            }
+        }
+
+        while let Some(&t) = state.recent.front() {
+            if now.saturating_duration_since(t) < RATE_WINDOW {
+                break;
+            }
+            state.recent.pop_front();
+        }
+        if state.recent.len() >= max {
+            return Err(Suppressed::RateLimit);
+        }
+        Ok(())
+    }
+
+    /// Record an execution attempt (success or failure) on `exchange` at `now`
+    pub fn record(&mut self, symbol: Symbol, exchange: Exchange, now: Instant) {
+        if let Some(state) = self.state(symbol, exchange) {
+            state.last_attempt = Some(now);
+            state.recent.push_back(now);
+        }
+    }
+
+    /// Cooldown applied to symbol
+    pub fn cooldown(&self, symbol: Symbol) -> Duration {
+        self.overrides
+            .get(symbol.as_str())
+            .copied()
+            .unwrap_or(self.default_cooldown)
+    }
+
+    fn state(&mut self, symbol: Symbol, exchange: Exchange) -> Option<&mut SymbolThrottle> {
+        let cooldown = self.cooldown(symbol);
+        let index = symbol.as_raw() as usize * Exchange::COUNT + exchange.index();
+        let slot = self.states.get_mut(index)?;
+        Some(slot.get_or_insert_with(|| SymbolThrottle {
+            cooldown,
+            last_attempt: None,
+            recent: VecDeque::new(),
+        }))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    fn config() -> ExecutionConfig {
+        ExecutionConfig {
+            cooldown_ms: 1_000,
+            max_trades_per_minute: 3,
+            symbol_cooldown_ms: HashMap::from([("ethusdt".to_string(), 10_000)]),
+            ..ExecutionConfig::default()
+        }
+    }
+
+    #[test]
+    fn test_cooldown_after_attempt() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut throttle = Throttle::new(&config());
+        let t0 = Instant::now();
+
+        assert_eq!(throttle.check(sym, Exchange::Binance, t0), Ok(()));
+        throttle.record(sym, Exchange::Binance, t0);
+        assert!(matches!(
+            throttle.check(sym, Exchange::Binance, t0 + Duration::from_millis(400)),
+            Err(Suppressed::Cooldown { remaining }) if remaining == Duration::from_millis(600)
+        ));
+        assert_eq!(throttle.check(sym, Exchange::Binance, t0 + Duration::from_secs(1)), Ok(()));
+    }
+
+    #[test]
+    fn test_symbol_override() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let throttle = Throttle::new(&config());
+        assert_eq!(throttle.cooldown(btc), Duration::from_secs(1));
+        assert_eq!(throttle.cooldown(eth), Duration::from_secs(10));
+    }
+
+    #[test]
+    fn test_rate_limit_window() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut throttle = Throttle::new(&config());
+        let t0 = Instant::now();
+
+        for i in 0..3 {
+            let t = t0 + Duration::from_secs(2 * i);
+            assert_eq!(throttle.check(sym, Exchange::Binance, t), Ok(()));
+            throttle.record(sym, Exchange::Binance, t);
+        }
+        assert_eq!(
+            throttle.check(sym, Exchange::Binance, t0 + Duration::from_secs(10)),
+            Err(Suppressed::RateLimit)
+        );
+        // First attempt leaves the window
+        assert_eq!(throttle.check(sym, Exchange::Binance, t0 + Duration::from_secs(60)), Ok(()));
+    }
+
+    #[test]
+    fn test_legs_on_other_venue_not_throttled() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut throttle = Throttle::new(&config());
+        let t0 = Instant::now();
+
+        throttle.record(sym, Exchange::Binance, t0);
+        assert_eq!(throttle.check(sym, Exchange::Bybit, t0), Ok(()));
+        throttle.record(sym, Exchange::Bybit, t0);
+        assert!(matches!(
+            throttle.check(sym, Exchange::Bybit, t0 + Duration::from_millis(10)),
+            Err(Suppressed::Cooldown { .. })
+        ));
+    }
+}
@@ -0,0 +1,122 @@
+//! Startup warm-up gate
+//!
+//! Right after startup the rolling windows are empty (or only seeded from
+//! klines) and the first spreads are not representative. Execution stays
+//! disabled until the warm-up period has elapsed, and afterwards for any
+//! symbol with fewer than `min_samples` live spread updates.
+
+use crate::core::{Symbol, MAX_SYMBOLS};
+use crate::infrastructure::config::WarmupConfig;
+use std::time::{Duration, Instant};
+
+/// Warm-up state for monitoring
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct WarmupStatus {
+    /// Warm-up period still running (execution disabled for every symbol)
+    pub active: bool,
+    pub remaining: Duration,
+    /// Symbols with at least `min_samples` spread updates
+    pub warm_symbols: usize,
+}
+
+/// Execution gate during startup warm-up
+#[derive(Debug)]
+pub struct WarmupGate {
+    started: Instant,
+    min_duration: Duration,
+    min_samples: u32,
+    /// Spread updates per symbol (indexed by Symbol ID)
+    samples: Vec<u32>,
+    warm_symbols: usize,
+}
+
+impl WarmupGate {
+    /// Create gate starting at `now`
+    pub fn new(config: &WarmupConfig, now: Instant) -> Self {
+        Self {
+            started: now,
+            min_duration: Duration::from_secs(config.gate_secs),
+            min_samples: config.gate_min_samples,
+            samples: vec![0; MAX_SYMBOLS],
+            warm_symbols: 0,
+        }
+    }
+
+    /// Gate that is open immediately
+    pub fn disabled(now: Instant) -> Self {
+        Self {
+            started: now,
+            min_duration: Duration::ZERO,
+            min_samples: 0,
+            samples: vec![0; MAX_SYMBOLS],
+            warm_symbols: 0,
+        }
+    }
+
+    /// Count a live spread update for symbol
+    #[inline]
+    pub fn record_sample(&mut self, symbol: Symbol) {
+        if let Some(count) = self.samples.get_mut(symbol.as_raw() as usize) {
+            *count = count.saturating_add(1);
+            if *count == self.min_samples.max(1) {
+                self.warm_symbols += 1;
+            }
+        }
+    }
+
+    /// Whether orders in symbol may execute at `now`
+    #[inline]
+    pub fn is_ready(&self, symbol: Symbol, now: Instant) -> bool {
+        now.saturating_duration_since(self.started) >= self.min_duration
+            && self
+                .samples
+                .get(symbol.as_raw() as usize)
+                .is_some_and(|&count| count >= self.min_samples)
+    }
+
+    /// Current warm-up state
+    pub fn status(&self, now: Instant) -> WarmupStatus {
+        let remaining = self.min_duration.saturating_sub(now.saturating_duration_since(self.started));
+        WarmupStatus {
+            active: !remaining.is_zero(),
+            remaining,
+            warm_symbols: self.warm_symbols,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_gate_duration_and_samples() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let t0 = Instant::now();
+        let config = WarmupConfig { gate_secs: 60, gate_min_samples: 2, ..Default::default() };
+        let mut gate = WarmupGate::new(&config, t0);
+
+        gate.record_sample(btc);
+        gate.record_sample(btc);
+        gate.record_sample(btc);
+        gate.record_sample(eth);
+
+        // Enough samples, but still inside the warm-up period
+        assert!(!gate.is_ready(btc, t0 + Duration::from_secs(30)));
+        let status = gate.status(t0 + Duration::from_secs(30));
+        assert!(status.active);
+        assert_eq!(status.remaining, Duration::from_secs(30));
+        assert_eq!(status.warm_symbols, 1);
+
+        // Period over: BTC ready, ETH still lacks samples
+        let later = t0 + Duration::from_secs(60);
+        assert!(!gate.status(later).active);
+        assert!(gate.is_ready(btc, later));
+        assert!(!gate.is_ready(eth, later));
+
+        assert!(WarmupGate::disabled(t0).is_ready(eth, t0));
+    }
+}
@@ -1,18 +1,46 @@
 //! Binance Futures WebSocket client
 //!
 //! Native WebSocket client for Binance Futures exchange.
-//! Handles aggTrade and bookTicker streams.
+//! Handles aggTrade, bookTicker, markPrice and forceOrder streams.
+//!
+//! Binance drops every connection after 24h. The client rotates ahead of
+//! that (make-before-break, see [`crate::ws::rotation`]) and reconnects
+//! transparently on scheduled closes.
 
-use crate::core::{Symbol, TickerData, TradeData, SymbolMapper};
-use crate::ws::connection::WebSocketConnection;
-use crate::ws::subscription::{StreamType, SubscriptionManager};
+use crate::core::{clock, LiquidationData, MarkPriceData, Symbol, TickerData, TradeData, SymbolMapper};
+use crate::infrastructure::config::{FeedTuning, NetworkConfig};
+use crate::hot_path::{InvariantChecker, InvariantCounters};
+use crate::infrastructure::unparsed::{ParseFailure, ParseQuarantine};
+use crate::ws::connection::{BufferStats, CloseKind, WebSocketConnection};
+use crate::ws::rotation::{RotationPolicy, Rotator};
+use crate::ws::subscription::{StreamType, SubscriptionManager, VenueCapacity, MAX_BATCH_SIZE};
 use crate::ws::ping::ConnectionMonitor;
-use crate::exchanges::parsing::{BinanceParser, BinanceMessageType};
-use crate::exchanges::traits::{ExchangeMessage, WebSocketExchange};
+use crate::exchanges::parsing::{validate_structure, BinanceParser, BinanceMessageType, FrameDefect};
+use crate::exchanges::traits::{ErrorKind, ExchangeError, ExchangeMessage, WebSocketExchange};
 use crate::exchanges::Exchange;
 use crate::{HftError, Result};
 
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
 use tokio::time::Instant;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Binance forced connection lifetime is 24h; rotate with margin
+const DEFAULT_MAX_CONNECTION_AGE: Duration = Duration::from_secs(23 * 3600);
+
+/// Stream name suffix per subscribed stream type
+const STREAM_SUFFIXES: [(StreamType, &str); 4] = [
+    (StreamType::Trade, "aggTrade"),
+    (StreamType::Ticker, "bookTicker"),
+    (StreamType::MarkPrice, "markPrice@1s"),
+    (StreamType::Liquidation, "forceOrder"),
+];
+
+/// Wall-clock receive time (nanoseconds since epoch, coarse clock)
+fn unix_nanos() -> u64 {
+    clock::coarse_unix_nanos()
+}
 
 /// Binance Futures WebSocket client
 pub struct BinanceWsClient {
@@ -24,6 +52,20 @@ pub struct BinanceWsClient {
     monitor: ConnectionMonitor,
     /// Last message timestamp
     last_message: Instant,
+    /// Outbound network settings (source address)
+    network: NetworkConfig,
+    /// Buffer size and keepalive timing
+    tuning: FeedTuning,
+    /// WebSocket endpoint (WS_URL unless overridden)
+    url: String,
+    /// Make-before-break rotation schedule
+    rotator: Rotator,
+    /// Frames handed over by a rotation, processed before the connection
+    backlog: VecDeque<Message>,
+    /// Unparseable frames kept for diagnosis (None = log only)
+    quarantine: Option<Arc<ParseQuarantine>>,
+    /// Sampled market data invariants
+    invariants: InvariantChecker,
 }
 
 impl BinanceWsClient {
@@ -34,17 +76,62 @@ impl BinanceWsClient {
     pub fn new() -> Self {
         Self {
             connection: None,
-            subscriptions: SubscriptionManager::new(),
+            subscriptions: SubscriptionManager::with_capacity(VenueCapacity::BINANCE),
             monitor: ConnectionMonitor::new("binance".to_string()),
             last_message: Instant::now(),
+            network: NetworkConfig::default(),
+            tuning: FeedTuning::default(),
+            url: Self::WS_URL.to_string(),
+            rotator: Rotator::new(RotationPolicy::after(DEFAULT_MAX_CONNECTION_AGE)),
+            backlog: VecDeque::new(),
+            quarantine: None,
+            invariants: InvariantChecker::new(Exchange::Binance, FeedTuning::default().assert_sample_every),
         }
     }
 
+    /// Set outbound network settings (source address binding)
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Set buffer size and keepalive timing
+    pub fn with_tuning(mut self, tuning: FeedTuning) -> Self {
+        self.tuning = tuning;
+        self.invariants.set_every(tuning.assert_sample_every);
+        self
+    }
+
+    /// Keep frames that fail to parse in `quarantine`
+    pub fn with_quarantine(mut self, quarantine: Arc<ParseQuarantine>) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Count invariant violations into shared `counters`
+    pub fn with_invariants(mut self, counters: Arc<InvariantCounters>) -> Self {
+        self.invariants.set_counters(counters);
+        self
+    }
+
+    /// Override WebSocket endpoint (mock servers, alternative hosts)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Override connection age at which the client rotates (default 23h)
+    pub fn with_max_connection_age(mut self, age: Duration) -> Self {
+        self.rotator = Rotator::new(RotationPolicy::after(age));
+        self
+    }
+
     /// Connect to Binance WebSocket
     pub async fn connect(&mut self) -> Result<()> {
-        let conn = WebSocketConnection::connect(Self::WS_URL)
+        let mut conn = WebSocketConnection::connect_with(&self.url, &self.network)
             .await
             .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        conn.set_read_buffer_capacity(self.tuning.read_buffer_bytes);
         
         self.monitor = ConnectionMonitor::new("binance".to_string());
         self.connection = Some(conn);
@@ -59,6 +146,7 @@ impl BinanceWsClient {
         }
 
         // Request subscription
+        self.subscriptions.check_capacity(symbols, StreamType::Trade)?;
         self.subscriptions.request_subscription(symbols, StreamType::Trade);
         
         // Create batch subscription message
@@ -79,8 +167,9 @@ impl BinanceWsClient {
                 "id": 1
             });
             
+            self.pace().await;
             if let Some(conn) = self.connection.as_mut() {
-                conn.send_text(&request.to_string()).await
+                conn.send_bulk_text(&request.to_string()).await
                     .map_err(|e| HftError::WebSocket(e.to_string()))?;
             }
         }
@@ -94,6 +183,8 @@ impl BinanceWsClient {
             return Ok(());
         }
 
+        self.subscriptions.check_capacity(symbols, StreamType::Ticker)?;
+
         self.subscriptions.request_subscription(symbols, StreamType::Ticker);
         
         let batches = self.subscriptions.create_batches(StreamType::Ticker);
@@ -116,8 +207,9 @@ impl BinanceWsClient {
             tracing::debug!("Binance subscribe batch {}: {} symbols", i, params.len());
             tracing::trace!("Request: {}", request);
             
+            self.pace().await;
             if let Some(conn) = self.connection.as_mut() {
-                conn.send_text(&request.to_string()).await
+                conn.send_bulk_text(&request.to_string()).await
                     .map_err(|e| HftError::WebSocket(e.to_string()))?;
                 tracing::debug!("Sent subscription request to Binance");
             }
@@ -126,59 +218,326 @@ impl BinanceWsClient {
         Ok(())
     }
 
+    /// Subscribe to markPrice stream (1s updates) for symbols
+    pub async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.check_capacity(symbols, StreamType::MarkPrice)?;
+
+        self.subscriptions.request_subscription(symbols, StreamType::MarkPrice);
+
+        let batches = self.subscriptions.create_batches(StreamType::MarkPrice);
+        tracing::info!("Subscribing to {} batches of mark prices on Binance", batches.len());
+
+        for batch in batches {
+            let params: Vec<String> = batch.symbols.iter()
+                .map(|s| {
+                    let name = SymbolMapper::get_name(*s, Exchange::Binance).unwrap_or(s.as_str());
+                    format!("{}@markPrice@1s", name.to_lowercase())
+                })
+                .collect();
+
+            let request = serde_json::json!({
+                "method": "SUBSCRIBE",
+                "params": params,
+                "id": 1
+            });
+
+            self.pace().await;
+            if let Some(conn) = self.connection.as_mut() {
+                conn.send_bulk_text(&request.to_string()).await
+                    .map_err(|e| HftError::WebSocket(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Subscribe to forceOrder (liquidation) stream for symbols
+    pub async fn subscribe_force_orders(&mut self, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.check_capacity(symbols, StreamType::Liquidation)?;
+
+        self.subscriptions.request_subscription(symbols, StreamType::Liquidation);
+
+        let batches = self.subscriptions.create_batches(StreamType::Liquidation);
+        tracing::info!("Subscribing to {} batches of liquidations on Binance", batches.len());
+
+        for batch in batches {
+            let params: Vec<String> = batch.symbols.iter()
+                .map(|s| {
+                    let name = SymbolMapper::get_name(*s, Exchange::Binance).unwrap_or(s.as_str());
+                    format!("{}@forceOrder", name.to_lowercase())
+                })
+                .collect();
+
+            let request = serde_json::json!({
+                "method": "SUBSCRIBE",
+                "params": params,
+                "id": 1
+            });
+
+            self.pace().await;
+            if let Some(conn) = self.connection.as_mut() {
+                conn.send_bulk_text(&request.to_string()).await
+                    .map_err(|e| HftError::WebSocket(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Unsubscribe symbols from a stream
+    ///
+    /// Cancelled streams are not replayed on reconnect or rotation.
+    pub async fn unsubscribe(&mut self, stream_type: StreamType, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.cancel_subscription(symbols, stream_type);
+        let Some((_, suffix)) = STREAM_SUFFIXES.iter().find(|(t, _)| *t == stream_type) else {
+            return Ok(());
+        };
+
+        for chunk in symbols.chunks(MAX_BATCH_SIZE) {
+            let params: Vec<String> = chunk.iter()
+                .map(|s| {
+                    let name = SymbolMapper::get_name(*s, Exchange::Binance).unwrap_or(s.as_str());
+                    format!("{}@{}", name.to_lowercase(), suffix)
+                })
+                .collect();
+
+            let request = serde_json::json!({
+                "method": "UNSUBSCRIBE",
+                "params": params,
+                "id": 1
+            });
+
+            self.pace().await;
+            if let Some(conn) = self.connection.as_mut() {
+                conn.send_bulk_text(&request.to_string()).await
+                    .map_err(|e| HftError::WebSocket(e.to_string()))?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Wait for the next control-message slot (Binance drops connections
+    /// sending more than 10 messages per second)
+    async fn pace(&mut self) {
+        let delay = self.subscriptions.reserve_send(Instant::now().into_std());
+        if !delay.is_zero() {
+            tokio::time::sleep(delay).await;
+        }
+    }
+
+    /// Open a new connection and replay every subscription on it
+    async fn open_subscribed(&self) -> Result<WebSocketConnection> {
+        let mut conn = WebSocketConnection::connect_with(&self.url, &self.network)
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        conn.set_read_buffer_capacity(self.tuning.read_buffer_bytes);
+
+        // A fresh connection has the full message budget; space the replay
+        let spacing = VenueCapacity::BINANCE.send_spacing();
+        let mut sent = 0;
+        for (stream_type, suffix) in STREAM_SUFFIXES {
+            let symbols = self.subscriptions.get_subscribed(stream_type);
+            for chunk in symbols.chunks(MAX_BATCH_SIZE) {
+                let params: Vec<String> = chunk.iter()
+                    .map(|s| {
+                        let name = SymbolMapper::get_name(*s, Exchange::Binance).unwrap_or(s.as_str());
+                        format!("{}@{}", name.to_lowercase(), suffix)
+                    })
+                    .collect();
+                let request = serde_json::json!({
+                    "method": "SUBSCRIBE",
+                    "params": params,
+                    "id": 1
+                });
+                if sent > 0 {
+                    tokio::time::sleep(spacing).await;
+                }
+                sent += 1;
+                conn.send_bulk_text(&request.to_string()).await
+                    .map_err(|e| HftError::WebSocket(e.to_string()))?;
+            }
+        }
+
+        Ok(conn)
+    }
+
+    /// Whether a raw frame carries market data (vs acks)
+    fn is_data_frame(text: &str) -> bool {
+        matches!(
+            BinanceParser::detect_message_type(text.as_bytes()),
+            BinanceMessageType::AggTrade
+                | BinanceMessageType::BookTicker
+                | BinanceMessageType::MarkPrice
+                | BinanceMessageType::ForceOrder
+        )
+    }
+
+    /// Replace the connection before the 24h forced disconnect
+    async fn rotate_if_due(&mut self) {
+        if !self.connection.as_ref().is_some_and(|c| self.rotator.is_due(c)) {
+            return;
+        }
+
+        let result = match self.open_subscribed().await {
+            Ok(conn) => self
+                .rotator
+                .switch(&mut self.connection, conn, Self::is_data_frame)
+                .await
+                .map_err(|e| HftError::WebSocket(e.to_string())),
+            Err(e) => {
+                self.rotator.record_failure();
+                Err(e)
+            }
+        };
+
+        match result {
+            Ok(backlog) => {
+                self.backlog.extend(backlog);
+                self.monitor = ConnectionMonitor::new("binance".to_string());
+                tracing::info!("Binance connection rotated ahead of 24h limit");
+            }
+            Err(e) => {
+                tracing::warn!("Binance rotation failed, keeping current connection: {}", e);
+            }
+        }
+    }
+
     /// Receive and process next message
+    ///
+    /// Scheduled server closes (rotation/maintenance) reconnect and resubscribe
+    /// transparently; other closes return `Ok(None)`.
     pub async fn recv(&mut self) -> Result<Option<BinanceMessage>> {
-        if let Some(conn) = self.connection.as_mut() {
-            loop {
-                match conn.recv().await {
-                    Ok(Some(msg)) => {
-                        self.last_message = Instant::now();
-                        self.monitor.record_activity();
-                        
-                        // Log raw message at debug level
-                        if let Ok(text) = msg.to_text() {
-                            tracing::debug!("Binance raw message (first 200 chars): {}", &text[..text.len().min(200)]);
+        self.rotate_if_due().await;
+
+        loop {
+            let Some(conn) = self.connection.as_mut() else {
+                return Ok(None);
+            };
+            let next = match self.backlog.pop_front() {
+                Some(msg) => Ok(Some(msg)),
+                None => conn.recv().await,
+            };
+            match next {
+                Ok(Some(msg)) => {
+                    self.last_message = Instant::from_std(clock::coarse_now());
+                    self.monitor.record_activity();
+                    // Pings are answered by the connection; control frames carry no data
+                    if msg.is_ping() || msg.is_pong() {
+                        continue;
+                    }
+                    
+                    // Log raw message at debug level
+                    if let Ok(text) = msg.to_text() {
+                        tracing::debug!("Binance raw message (first 200 chars): {}", &text[..text.len().min(200)]);
+                    }
+                    
+                    // Parse message
+                    if let Ok(text) = msg.to_text() {
+                        if let Err(defect) = self.check_frame(text) {
+                            tracing::warn!("Binance frame rejected: {:?}", defect);
+                            if let Some(quarantine) = &self.quarantine {
+                                quarantine.record(Exchange::Binance, ParseFailure { kind: defect.kind() }, text);
+                            }
+                            continue;
                         }
-                        
-                        // Parse message
-                        if let Ok(text) = msg.to_text() {
-                            match Self::parse_message(text) {
-                                Ok(Some(parsed)) => {
-                                    tracing::debug!("Parsed Binance message: {:?}", parsed);
-                                    return Ok(Some(parsed));
+                        match Self::parse_message(text) {
+                            Ok(Some(mut parsed)) => {
+                                // Spot bookTicker carries no event time: stamp with receive time
+                                if let BinanceMessage::Ticker(ticker) = &mut parsed {
+                                    if ticker.timestamp == 0 {
+                                        ticker.timestamp = unix_nanos();
+                                    }
                                 }
-                                Ok(None) => {
-                                    tracing::debug!("Unknown/ignored Binance message");
-                                    continue; // Unknown message, skip
-                                }
-                                Err(e) => {
-                                    tracing::warn!("Parse error: {}", e);
-                                    continue;
+                                self.check_invariants(&parsed, text);
+                                tracing::debug!("Parsed Binance message: {:?}", parsed);
+                                return Ok(Some(parsed));
+                            }
+                            Ok(None) => {
+                                tracing::debug!("Unknown/ignored Binance message");
+                                continue; // Unknown message, skip
+                            }
+                            Err(failure) => {
+                                tracing::warn!("Binance {} parse error", failure.kind);
+                                if let Some(quarantine) = &self.quarantine {
+                                    quarantine.record(Exchange::Binance, failure, text);
                                 }
+                                continue;
                             }
                         }
                     }
-                    Ok(None) => {
-                        // Connection closed
-                        tracing::warn!("Binance connection closed");
-                        self.connection = None;
-                        return Ok(None);
-                    }
-                    Err(e) => {
-                        tracing::error!("Binance WebSocket error: {}", e);
-                        return Err(HftError::WebSocket(e.to_string()));
+                }
+                Ok(None) => {
+                    // Connection closed
+                    let scheduled = conn.last_close()
+                        .is_some_and(|c| c.kind == CloseKind::ScheduledRotation);
+                    self.connection = None;
+                    if scheduled {
+                        tracing::info!("Binance scheduled disconnect, reconnecting");
+                        let conn = self.open_subscribed().await?;
+                        self.connection = Some(conn);
+                        self.monitor = ConnectionMonitor::new("binance".to_string());
+                        continue;
                     }
+                    tracing::warn!("Binance connection closed");
+                    return Ok(None);
+                }
+                Err(e) => {
+                    tracing::error!("Binance WebSocket error: {}", e);
+                    return Err(HftError::WebSocket(e.to_string()));
                 }
             }
         }
-        
-        Ok(None)
+    }
+
+    /// Structural check ahead of field extraction (when enabled)
+    #[inline]
+    fn check_frame(&self, text: &str) -> std::result::Result<(), FrameDefect> {
+        match self.tuning.max_frame_bytes {
+            0 => Ok(()),
+            max_len => validate_structure(text.as_bytes(), max_len),
+        }
+    }
+
+    /// Sampled invariant check of market data (logged, quarantined if configured)
+    fn check_invariants(&mut self, parsed: &BinanceMessage, text: &str) {
+        if !matches!(parsed, BinanceMessage::Ticker(_) | BinanceMessage::Trade(_)) || !self.invariants.due() {
+            return;
+        }
+        let violation = match parsed {
+            BinanceMessage::Ticker(ticker) => self.invariants.check_ticker(ticker),
+            BinanceMessage::Trade(trade) => self.invariants.check_trade(trade),
+            _ => None,
+        };
+        let Some(invariant) = violation else {
+            return;
+        };
+        tracing::warn!("Binance invariant violated: {}", invariant.name());
+        if self.tuning.capture_violations {
+            if let Some(quarantine) = &self.quarantine {
+                quarantine.record(Exchange::Binance, ParseFailure { kind: invariant.kind() }, text);
+            }
+        }
     }
 
     /// Parse Binance message into structured data
+    ///
+    /// A detected message type whose fields fail to parse is a [`ParseFailure`].
     fn parse_message(
         text: &str,
-    ) -> Result<Option<BinanceMessage>> {
+    ) -> std::result::Result<Option<BinanceMessage>, ParseFailure> {
         let data = text.as_bytes();
         
         // Detect message type and parse accordingly
@@ -186,20 +545,36 @@ impl BinanceWsClient {
             BinanceMessageType::AggTrade => {
                 match BinanceParser::parse_trade(data) {
                     Some(result) => Ok(Some(BinanceMessage::Trade(result.data))),
-                    None => Ok(None),
+                    None => Err(ParseFailure { kind: "aggTrade" }),
                 }
             }
             BinanceMessageType::BookTicker => {
                 match BinanceParser::parse_ticker(data) {
                     Some(result) => Ok(Some(BinanceMessage::Ticker(result.data))),
-                    None => Ok(None),
+                    None => Err(ParseFailure { kind: "bookTicker" }),
+                }
+            }
+            BinanceMessageType::MarkPrice => {
+                match BinanceParser::parse_mark_price(data) {
+                    Some(result) => Ok(Some(BinanceMessage::MarkPrice(result.data))),
+                    None => Err(ParseFailure { kind: "markPriceUpdate" }),
+                }
+            }
+            BinanceMessageType::ForceOrder => {
+                match BinanceParser::parse_liquidation(data) {
+                    Some(result) => Ok(Some(BinanceMessage::Liquidation(result.data))),
+                    None => Err(ParseFailure { kind: "forceOrder" }),
                 }
             }
             BinanceMessageType::SubscriptionResponse => {
                 Ok(Some(BinanceMessage::SubscriptionConfirmed))
             }
+            BinanceMessageType::Error => {
+                let msg = BinanceParser::error_message(data).unwrap_or_default();
+                Ok(Some(BinanceMessage::Error(String::from_utf8_lossy(msg).into_owned())))
+            }
             BinanceMessageType::Unknown => {
-                // Unknown message type, could be heartbeat or error
+                // Unknown message type, could be heartbeat
                 Ok(None)
             }
         }
@@ -212,6 +587,11 @@ impl BinanceWsClient {
             .unwrap_or(false)
     }
 
+    /// Get connection buffer sizes (None if not connected yet)
+    pub fn buffer_stats(&self) -> Option<BufferStats> {
+        self.connection.as_ref().map(|c| c.buffer_stats())
+    }
+
     /// Get connection health
     pub fn health(&self) -> bool {
         self.monitor.is_healthy()
@@ -260,6 +640,14 @@ impl WebSocketExchange for BinanceWsClient {
         self.subscribe_book_tickers(symbols).await
     }
 
+    async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> crate::Result<()> {
+        self.subscribe_mark_prices(symbols).await
+    }
+
+    async fn subscribe_liquidations(&mut self, symbols: &[Symbol]) -> crate::Result<()> {
+        self.subscribe_force_orders(symbols).await
+    }
+
     async fn next_message(&mut self) -> crate::Result<Option<ExchangeMessage>> {
         match self.recv().await? {
             Some(BinanceMessage::Trade(trade)) => {
@@ -268,12 +656,25 @@ impl WebSocketExchange for BinanceWsClient {
             Some(BinanceMessage::Ticker(ticker)) => {
                 Ok(Some(ExchangeMessage::Ticker(Exchange::Binance, ticker)))
             }
+            Some(BinanceMessage::MarkPrice(mark)) => {
+                Ok(Some(ExchangeMessage::MarkPrice(Exchange::Binance, mark)))
+            }
+            Some(BinanceMessage::Liquidation(liq)) => {
+                Ok(Some(ExchangeMessage::Liquidation(Exchange::Binance, liq)))
+            }
             Some(BinanceMessage::Heartbeat) => Ok(Some(ExchangeMessage::Heartbeat)),
             Some(BinanceMessage::SubscriptionConfirmed) => {
                 // Subscription confirmations don't map to ExchangeMessage
                 // Could be treated as Heartbeat or ignored
                 Ok(Some(ExchangeMessage::Heartbeat))
             }
+            Some(BinanceMessage::Error(message)) => {
+                Ok(Some(ExchangeMessage::Error(ExchangeError {
+                    exchange: Exchange::Binance,
+                    kind: ErrorKind::Unknown,
+                    message,
+                })))
+            }
             None => Ok(None),
         }
     }
@@ -298,10 +699,16 @@ pub enum BinanceMessage {
     Trade(TradeData),
     /// Ticker/bookTicker data
     Ticker(TickerData),
+    /// markPriceUpdate data
+    MarkPrice(MarkPriceData),
+    /// forceOrder data
+    Liquidation(LiquidationData),
     /// Subscription confirmation
     SubscriptionConfirmed,
     /// Ping/pong
     Heartbeat,
+    /// Request rejected
+    Error(String),
 }
 
 #[cfg(test)]
@@ -316,13 +723,124 @@ mod tests {
 
     #[test]
     fn test_parse_agg_trade() {
-        let client = BinanceWsClient::new();
+        let _client = BinanceWsClient::new();
         // Note: This test would need actual JSON parsing
     }
 
     #[test]
     fn test_parse_book_ticker() {
-        let client = BinanceWsClient::new();
+        let _client = BinanceWsClient::new();
         // Note: This test would need actual JSON parsing
     }
+
+    #[tokio::test]
+    async fn test_reconnect_on_scheduled_close() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::protocol::{frame::coding::CloseCode, CloseFrame};
+        use tokio_tungstenite::tungstenite::Message;
+
+        crate::test_utils::init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = |u: u64| {
+            format!(
+                r#"{{"e":"bookTicker","u":{},"s":"BTCUSDT","b":"100.0","B":"1.0","a":"100.1","A":"1.0","T":1700000000000,"E":1700000000000}}"#,
+                u
+            )
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut resubscribed = String::new();
+            for (conn, update_id) in [(0, 1), (1, 2)] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let sub = ws.next().await.unwrap().unwrap();
+                if conn == 1 {
+                    resubscribed = sub.to_text().unwrap().to_string();
+                }
+                ws.send(Message::text(ticker(update_id))).await.unwrap();
+                if conn == 0 {
+                    let frame = CloseFrame { code: CloseCode::Away, reason: "going away".into() };
+                    ws.send(Message::Close(Some(frame))).await.unwrap();
+                    while let Some(Ok(_)) = ws.next().await {}
+                }
+            }
+            resubscribed
+        });
+
+        let mut client = BinanceWsClient::new().with_url(url);
+        client.connect().await.unwrap();
+        client.subscribe_book_tickers(&[btc]).await.unwrap();
+
+        for expected in [1, 2] {
+            match client.recv().await.unwrap() {
+                Some(BinanceMessage::Ticker(t)) => assert_eq!(t.update_id, expected),
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert!(server.await.unwrap().contains("btcusdt@bookTicker"));
+    }
+
+    #[tokio::test]
+    async fn test_rotation_without_gap() {
+        use futures_util::{SinkExt, StreamExt};
+
+        crate::test_utils::init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = |u: u64| {
+            format!(
+                r#"{{"e":"bookTicker","u":{},"s":"BTCUSDT","b":"100.0","B":"1.0","a":"100.1","A":"1.0","T":1700000000000,"E":1700000000000}}"#,
+                u
+            )
+        };
+
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut old_closed = Vec::new();
+            for frames in [vec![ticker(1), ticker(2)], vec![r#"{"result":null,"id":1}"#.to_string(), ticker(3)]] {
+                let (stream, _) = listener.accept().await.unwrap();
+                old_closed.push(tokio::spawn(async move {
+                    let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                    ws.next().await.unwrap().unwrap(); // subscription
+                    for (i, frame) in frames.into_iter().enumerate() {
+                        if i > 0 {
+                            tokio::time::sleep(Duration::from_millis(50)).await;
+                        }
+                        ws.send(Message::text(frame)).await.unwrap();
+                    }
+                    while let Some(Ok(msg)) = ws.next().await {
+                        if msg.is_close() {
+                            return true;
+                        }
+                    }
+                    false
+                }));
+            }
+            old_closed.remove(0).await.unwrap()
+        });
+
+        let mut client = BinanceWsClient::new()
+            .with_url(url)
+            .with_max_connection_age(Duration::from_millis(200));
+        client.connect().await.unwrap();
+        client.subscribe_book_tickers(&[btc]).await.unwrap();
+
+        let mut ids = Vec::new();
+        ids.push(match client.recv().await.unwrap() {
+            Some(BinanceMessage::Ticker(t)) => t.update_id,
+            other => panic!("unexpected {:?}", other),
+        });
+        // Update 2 is buffered on the old connection when rotation starts
+        tokio::time::sleep(Duration::from_millis(250)).await;
+        for _ in 0..2 {
+            match client.recv().await.unwrap() {
+                Some(BinanceMessage::Ticker(t)) => ids.push(t.update_id),
+                other => panic!("unexpected {:?}", other),
+            }
+        }
+        assert_eq!(ids, vec![1, 2, 3]);
+        assert!(server.await.unwrap(), "old connection closed after switch");
+    }
 }
@@ -1,20 +1,50 @@
 //! Bybit Futures WebSocket client (V5 API)
 //!
 //! Native WebSocket client for Bybit Futures exchange using V5 API.
-//! Handles public trade and ticker streams.
+//! Handles public trade, ticker and liquidation streams. Mark/index price and funding
+//! ride on the tickers topic and are split out into MarkPriceData.
+//! A pre-listed contract's auction phase, also on the tickers topic, is
+//! reported as a status change ahead of the ticker that carried it.
 //!
 //! HFT: Uses array-based ticker cache for O(1) lookup (no HashMap hashing).
-
-use crate::core::{FixedPoint8, Symbol, TickerData, TradeData, SymbolMapper, MAX_SYMBOLS};
-use crate::ws::connection::WebSocketConnection;
-use crate::ws::subscription::{StreamType, SubscriptionManager};
+//!
+//! Optional make-before-break rotation (see [`crate::ws::rotation`]) for
+//! deployments that cap connection lifetime; disabled by default.
+//!
+//! Authenticated account topics live on a separate endpoint, see [`private`].
+
+use crate::core::{
+    clock, FixedPoint8, LiquidationData, MarkPriceData, StatusNotice, Symbol, TickerData, TradeData, TradingStatus, SymbolMapper,
+    MAX_SYMBOLS,
+};
+use crate::infrastructure::config::{FeedTuning, NetworkConfig};
+use crate::hot_path::{InvariantChecker, InvariantCounters};
+use crate::infrastructure::unparsed::{ParseFailure, ParseQuarantine};
+use crate::ws::connection::{BufferStats, WebSocketConnection};
+use crate::ws::rotation::{RotationPolicy, Rotator};
+use crate::ws::subscription::{StreamType, SubscriptionManager, VenueCapacity};
 use crate::ws::ping::ConnectionMonitor;
-use crate::exchanges::parsing::{BybitParser, BybitMessageType, BybitTickerUpdate};
+use crate::exchanges::parsing::{validate_structure, BybitParser, BybitMessageType, BybitTickerUpdate, FrameDefect};
 use crate::exchanges::traits::{ErrorKind, ExchangeError, ExchangeMessage, WebSocketExchange};
 use crate::exchanges::Exchange;
 use crate::{HftError, Result};
+use std::collections::VecDeque;
+use std::sync::Arc;
 use std::time::Duration;
 use tokio::time::{timeout, Instant};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+pub mod private;
+
+pub use private::BybitPrivateClient;
+
+/// Topic prefix per subscribed stream type (mark prices ride on tickers)
+const TOPIC_PREFIXES: [(StreamType, &str); 4] = [
+    (StreamType::Trade, "publicTrade"),
+    (StreamType::Ticker, "tickers"),
+    (StreamType::Liquidation, "allLiquidation"),
+    (StreamType::OrderBook, "orderbook.1"),
+];
 
 /// Bybit Futures WebSocket client (V5 API)
 pub struct BybitWsClient {
@@ -28,6 +58,32 @@ pub struct BybitWsClient {
     last_message: Instant,
     /// Local ticker cache for delta merging (array-based for O(1) lookup)
     tickers: Box<[Option<TickerData>; MAX_SYMBOLS]>,
+    /// Local mark price cache for delta merging (array-based for O(1) lookup)
+    marks: Box<[Option<MarkPriceData>; MAX_SYMBOLS]>,
+    /// Symbol whose mark was updated by the same delta as a ticker, emitted on next call
+    pending_mark: Option<Symbol>,
+    /// Last pre-listing phase per symbol (None = never reported)
+    statuses: Box<[Option<TradingStatus>; MAX_SYMBOLS]>,
+    /// Ticker from a delta that changed status, emitted on next call
+    pending_ticker: Option<TickerData>,
+    /// Remaining entries of the last allLiquidation frame, emitted on following calls
+    pending_liquidations: VecDeque<LiquidationData>,
+    /// Whether mark price updates should be emitted
+    marks_enabled: bool,
+    /// Outbound network settings (source address)
+    network: NetworkConfig,
+    /// Buffer size and keepalive timing
+    tuning: FeedTuning,
+    /// Mainnet WebSocket endpoint (WS_URL unless overridden)
+    url: String,
+    /// Make-before-break rotation schedule
+    rotator: Rotator,
+    /// Frames handed over by a rotation, processed before the connection
+    backlog: VecDeque<Message>,
+    /// Unparseable frames kept for diagnosis (None = log only)
+    quarantine: Option<Arc<ParseQuarantine>>,
+    /// Sampled market data invariants
+    invariants: InvariantChecker,
 }
 
 impl BybitWsClient {
@@ -40,13 +96,63 @@ impl BybitWsClient {
     pub fn new() -> Self {
         Self {
             connection: None,
-            subscriptions: SubscriptionManager::new(),
+            subscriptions: SubscriptionManager::with_capacity(VenueCapacity::BYBIT),
             monitor: ConnectionMonitor::new("bybit".to_string()),
             last_message: Instant::now(),
             tickers: Box::new([None; MAX_SYMBOLS]),
+            marks: Box::new([None; MAX_SYMBOLS]),
+            pending_mark: None,
+            statuses: Box::new([None; MAX_SYMBOLS]),
+            pending_ticker: None,
+            pending_liquidations: VecDeque::new(),
+            marks_enabled: false,
+            network: NetworkConfig::default(),
+            tuning: FeedTuning::default(),
+            url: Self::WS_URL.to_string(),
+            rotator: Rotator::default(),
+            backlog: VecDeque::new(),
+            quarantine: None,
+            invariants: InvariantChecker::new(Exchange::Bybit, FeedTuning::default().assert_sample_every),
         }
     }
+
+    /// Set outbound network settings (source address binding)
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Set buffer size and keepalive timing
+    pub fn with_tuning(mut self, tuning: FeedTuning) -> Self {
+        self.tuning = tuning;
+        self.invariants.set_every(tuning.assert_sample_every);
+        self
+    }
+
+    /// Keep frames that fail to parse in `quarantine`
+    pub fn with_quarantine(mut self, quarantine: Arc<ParseQuarantine>) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Count invariant violations into shared `counters`
+    pub fn with_invariants(mut self, counters: Arc<InvariantCounters>) -> Self {
+        self.invariants.set_counters(counters);
+        self
+    }
     
+    /// Override mainnet WebSocket endpoint (mock servers, alternative hosts)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Rotate connections older than `age` (make-before-break)
+    pub fn with_max_connection_age(mut self, age: Duration) -> Self {
+        self.rotator = Rotator::new(RotationPolicy::after(age));
+        self
+    }
+
     /// Create new Bybit client for testnet
     pub fn new_testnet() -> Self {
         let mut client = Self::new();
@@ -56,6 +162,10 @@ impl BybitWsClient {
 
     /// Merge ticker update into cache and return full ticker (hot path)
     /// O(1) array lookup by Symbol ID, no hashing
+    ///
+    /// A snapshot (sent after every subscribe, so after each reconnect)
+    /// replaces the cached ticker: fields it omits are cleared rather than
+    /// left stale from the previous connection.
     #[inline]
     fn merge_ticker(&mut self, update: BybitTickerUpdate) -> Option<TickerData> {
         let id = update.symbol.as_raw() as usize;
@@ -64,15 +174,19 @@ impl BybitWsClient {
         if id >= MAX_SYMBOLS {
             return None;
         }
+        if update.snapshot {
+            self.tickers[id] = None;
+        }
         
         // Get or create ticker entry
-        let ticker = self.tickers[id].get_or_insert_with(|| TickerData {
+        let ticker = self.tickers[id].get_or_insert(TickerData {
             symbol: update.symbol,
             bid_price: FixedPoint8::ZERO,
             ask_price: FixedPoint8::ZERO,
             bid_qty: FixedPoint8::ZERO,
             ask_qty: FixedPoint8::ZERO,
             timestamp: 0,
+            update_id: 0,
         });
         
         // Update fields from delta
@@ -80,6 +194,7 @@ impl BybitWsClient {
         if let Some(q) = update.bid_qty { ticker.bid_qty = q; }
         if let Some(p) = update.ask_price { ticker.ask_price = p; }
         if let Some(q) = update.ask_qty { ticker.ask_qty = q; }
+        if let Some(cs) = update.sequence { ticker.update_id = cs; }
         if update.timestamp > ticker.timestamp { ticker.timestamp = update.timestamp; }
         
         // Return copy if valid (has both prices)
@@ -90,13 +205,61 @@ impl BybitWsClient {
         }
     }
 
+    /// Record a reported pre-listing phase; true if it changed
+    #[inline]
+    fn record_status(&mut self, symbol: Symbol, status: TradingStatus) -> bool {
+        match self.statuses.get_mut(symbol.as_raw() as usize) {
+            Some(slot) => slot.replace(status) != Some(status),
+            None => false,
+        }
+    }
+
+    /// Merge mark/index/funding fields into cache and return full mark (hot path)
+    /// O(1) array lookup by Symbol ID, no hashing; snapshots reset the cache
+    #[inline]
+    fn merge_mark(&mut self, update: &BybitTickerUpdate) -> Option<MarkPriceData> {
+        let id = update.symbol.as_raw() as usize;
+        if id >= MAX_SYMBOLS {
+            return None;
+        }
+        if update.snapshot {
+            self.marks[id] = None;
+        }
+        if !update.has_mark_fields() {
+            return None;
+        }
+
+        let mark = self.marks[id].get_or_insert(MarkPriceData {
+            symbol: update.symbol,
+            mark_price: FixedPoint8::ZERO,
+            index_price: FixedPoint8::ZERO,
+            funding_rate: FixedPoint8::ZERO,
+            next_funding_time: 0,
+            timestamp: 0,
+        });
+
+        if let Some(p) = update.mark_price { mark.mark_price = p; }
+        if let Some(p) = update.index_price { mark.index_price = p; }
+        if let Some(r) = update.funding_rate { mark.funding_rate = r; }
+        if let Some(t) = update.next_funding_time { mark.next_funding_time = t; }
+        if update.timestamp > mark.timestamp { mark.timestamp = update.timestamp; }
+
+        // Return copy if valid (has both mark and index)
+        if mark.mark_price.is_positive() && mark.index_price.is_positive() {
+            Some(*mark)
+        } else {
+            None
+        }
+    }
+
     /// Connect to Bybit WebSocket
     pub async fn connect(&mut self, testnet: bool) -> Result<()> {
-        let url = if testnet { Self::WS_URL_TESTNET } else { Self::WS_URL };
+        let url = if testnet { Self::WS_URL_TESTNET } else { self.url.as_str() };
         
-        let conn = WebSocketConnection::connect(url)
+        let mut conn = WebSocketConnection::connect_with(url, &self.network)
             .await
             .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        conn.set_read_buffer_capacity(self.tuning.read_buffer_bytes);
         
         self.monitor = ConnectionMonitor::new(
             if testnet { "bybit-testnet".to_string() } else { "bybit".to_string() }
@@ -113,6 +276,7 @@ impl BybitWsClient {
         }
 
         // Request subscription
+        self.subscriptions.check_capacity(symbols, StreamType::Trade)?;
         self.subscriptions.request_subscription(symbols, StreamType::Trade);
         
         // Create topics for V5 protocol
@@ -131,7 +295,7 @@ impl BybitWsClient {
         });
         
         if let Some(conn) = self.connection.as_mut() {
-            conn.send_text(&subscribe_msg.to_string())
+            conn.send_bulk_text(&subscribe_msg.to_string())
                 .await
                 .map_err(|e| HftError::WebSocket(e.to_string()))?;
         }
@@ -145,6 +309,8 @@ impl BybitWsClient {
             return Ok(());
         }
 
+        self.subscriptions.check_capacity(symbols, StreamType::Ticker)?;
+
         self.subscriptions.request_subscription(symbols, StreamType::Ticker);
         
         let topics: Vec<String> = symbols
@@ -161,7 +327,7 @@ impl BybitWsClient {
         });
         
         if let Some(conn) = self.connection.as_mut() {
-            conn.send_text(&subscribe_msg.to_string())
+            conn.send_bulk_text(&subscribe_msg.to_string())
                 .await
                 .map_err(|e| HftError::WebSocket(e.to_string()))?;
         }
@@ -169,12 +335,69 @@ impl BybitWsClient {
         Ok(())
     }
 
+    /// Subscribe to mark/index price updates for symbols
+    ///
+    /// Bybit publishes mark/index/funding on the tickers topic, so this
+    /// subscribes to tickers (if not already) and enables mark extraction.
+    pub async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.marks_enabled = true;
+
+        // Topics already requested via subscribe_tickers are not sent again
+        let missing: Vec<Symbol> = symbols
+            .iter()
+            .copied()
+            .filter(|s| !self.subscriptions.is_subscribed(*s, StreamType::Ticker))
+            .collect();
+        self.subscriptions.check_capacity(symbols, StreamType::MarkPrice)?;
+        self.subscriptions.request_subscription(symbols, StreamType::MarkPrice);
+
+        self.subscribe_tickers(&missing).await
+    }
+
+    /// Subscribe to allLiquidation stream for symbols
+    pub async fn subscribe_all_liquidations(&mut self, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.check_capacity(symbols, StreamType::Liquidation)?;
+
+        self.subscriptions.request_subscription(symbols, StreamType::Liquidation);
+
+        let topics: Vec<String> = symbols
+            .iter()
+            .map(|s| {
+                let name = SymbolMapper::get_name(*s, Exchange::Bybit).unwrap_or(s.as_str());
+                format!("allLiquidation.{}", name)
+            })
+            .collect();
+
+        let subscribe_msg = serde_json::json!({
+            "op": "subscribe",
+            "args": topics,
+        });
+
+        if let Some(conn) = self.connection.as_mut() {
+            conn.send_bulk_text(&subscribe_msg.to_string())
+                .await
+                .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
     /// Subscribe to orderbook stream for symbols
     pub async fn subscribe_orderbook(&mut self, symbols: &[Symbol]) -> Result<()> {
         if symbols.is_empty() {
             return Ok(());
         }
 
+        self.subscriptions.check_capacity(symbols, StreamType::OrderBook)?;
+
         self.subscriptions.request_subscription(symbols, StreamType::OrderBook);
         
         let topics: Vec<String> = symbols
@@ -191,7 +414,7 @@ impl BybitWsClient {
         });
         
         if let Some(conn) = self.connection.as_mut() {
-            conn.send_text(&subscribe_msg.to_string())
+            conn.send_bulk_text(&subscribe_msg.to_string())
                 .await
                 .map_err(|e| HftError::WebSocket(e.to_string()))?;
         }
@@ -199,59 +422,239 @@ impl BybitWsClient {
         Ok(())
     }
 
-    /// Receive and process next message
-    pub async fn recv(&mut self) -> Result<Option<BybitMessage>> {
+    /// Unsubscribe symbols from a stream
+    ///
+    /// Cancelled topics are not replayed on reconnect or rotation.
+    pub async fn unsubscribe(&mut self, stream_type: StreamType, symbols: &[Symbol]) -> Result<()> {
+        if symbols.is_empty() {
+            return Ok(());
+        }
+
+        self.subscriptions.cancel_subscription(symbols, stream_type);
+        let Some((_, prefix)) = TOPIC_PREFIXES.iter().find(|(t, _)| *t == stream_type) else {
+            return Ok(());
+        };
+
+        let topics: Vec<String> = symbols
+            .iter()
+            .map(|s| {
+                let name = SymbolMapper::get_name(*s, Exchange::Bybit).unwrap_or(s.as_str());
+                format!("{}.{}", prefix, name)
+            })
+            .collect();
+
+        let unsubscribe_msg = serde_json::json!({
+            "op": "unsubscribe",
+            "args": topics,
+        });
+
         if let Some(conn) = self.connection.as_mut() {
-            loop {
-                // Send ping if inactive for 20s
-                if self.last_message.elapsed() > Duration::from_secs(20) {
-                    let ping_msg = serde_json::json!({"op": "ping"});
-                    if let Err(e) = conn.send_text(&ping_msg.to_string()).await {
-                        return Err(HftError::WebSocket(e.to_string()));
+            conn.send_bulk_text(&unsubscribe_msg.to_string())
+                .await
+                .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        }
+
+        Ok(())
+    }
+
+    /// Open a new connection to `url` and replay every subscription on it
+    async fn open_subscribed(&self, url: &str) -> Result<WebSocketConnection> {
+        let mut conn = WebSocketConnection::connect_with(url, &self.network)
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        conn.set_read_buffer_capacity(self.tuning.read_buffer_bytes);
+
+        for (stream_type, prefix) in TOPIC_PREFIXES {
+            let mut symbols = self.subscriptions.get_subscribed(stream_type);
+            if stream_type == StreamType::Ticker {
+                for s in self.subscriptions.get_subscribed(StreamType::MarkPrice) {
+                    if !symbols.contains(&s) {
+                        symbols.push(s);
                     }
-                    self.last_message = Instant::now(); 
                 }
+            }
+            if symbols.is_empty() {
+                continue;
+            }
+
+            let topics: Vec<String> = symbols
+                .iter()
+                .map(|s| {
+                    let name = SymbolMapper::get_name(*s, Exchange::Bybit).unwrap_or(s.as_str());
+                    format!("{}.{}", prefix, name)
+                })
+                .collect();
+            let subscribe_msg = serde_json::json!({
+                "op": "subscribe",
+                "args": topics,
+            });
+            conn.send_bulk_text(&subscribe_msg.to_string())
+                .await
+                .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        }
+
+        Ok(conn)
+    }
+
+    /// Whether a raw frame carries market data (vs acks/pongs)
+    fn is_data_frame(text: &str) -> bool {
+        matches!(
+            BybitParser::detect_message_type(text.as_bytes()),
+            BybitMessageType::PublicTrade
+                | BybitMessageType::Ticker
+                | BybitMessageType::TickerSnapshot
+                | BybitMessageType::Liquidation
+        )
+    }
+
+    /// Replace the connection once it reaches the configured max age
+    async fn rotate_if_due(&mut self) {
+        let url = match self.connection.as_ref() {
+            Some(conn) if self.rotator.is_due(conn) => conn.url().to_string(),
+            _ => return,
+        };
+
+        let result = match self.open_subscribed(&url).await {
+            Ok(conn) => self
+                .rotator
+                .switch(&mut self.connection, conn, Self::is_data_frame)
+                .await
+                .map_err(|e| HftError::WebSocket(e.to_string())),
+            Err(e) => {
+                self.rotator.record_failure();
+                Err(e)
+            }
+        };
+
+        match result {
+            Ok(backlog) => {
+                self.backlog.extend(backlog);
+                tracing::info!("Bybit connection rotated");
+            }
+            Err(e) => {
+                tracing::warn!("Bybit rotation failed, keeping current connection: {}", e);
+            }
+        }
+    }
+
+    /// Receive and process next message
+    pub async fn recv(&mut self) -> Result<Option<BybitMessage>> {
+        self.rotate_if_due().await;
+
+        loop {
+            let Some(conn) = self.connection.as_mut() else {
+                return Ok(None);
+            };
+
+            // Send ping after the configured inactivity
+            if self.last_message.elapsed() > self.tuning.ping_interval {
+                let ping_msg = serde_json::json!({"op": "ping"});
+                if let Err(e) = conn.send_text(&ping_msg.to_string()).await {
+                    return Err(HftError::WebSocket(e.to_string()));
+                }
+                self.last_message = Instant::from_std(clock::coarse_now());
+            }
 
-                // Wait for message with timeout to allow ping check
-                match timeout(Duration::from_secs(5), conn.recv()).await {
-                    Ok(Ok(Some(msg))) => {
-                        self.last_message = Instant::now();
-                        self.monitor.record_activity();
-                        
-                        if let Ok(text) = msg.to_text() {
-                            match Self::parse_message(text) {
-                                Ok(Some(parsed)) => return Ok(Some(parsed)),
-                                Ok(None) => {
-                                    tracing::debug!("Ignored Bybit msg: {}", text);
-                                    continue;
-                                },
-                                Err(e) => {
-                                    tracing::warn!("Parse error: {}", e);
-                                    continue;
+            // Wait for message with timeout to allow ping check
+            let next = match self.backlog.pop_front() {
+                Some(msg) => Ok(Ok(Some(msg))),
+                None => timeout(self.tuning.recv_timeout, conn.recv()).await,
+            };
+            match next {
+                Ok(Ok(Some(msg))) => {
+                    self.last_message = Instant::from_std(clock::coarse_now());
+                    self.monitor.record_activity();
+                    // Pings are answered by the connection; control frames carry no data
+                    if msg.is_ping() || msg.is_pong() {
+                        continue;
+                    }
+                    
+                    if let Ok(text) = msg.to_text() {
+                        if let Err(defect) = self.check_frame(text) {
+                            tracing::warn!("Bybit frame rejected: {:?}", defect);
+                            if let Some(quarantine) = &self.quarantine {
+                                quarantine.record(Exchange::Bybit, ParseFailure { kind: defect.kind() }, text);
+                            }
+                            continue;
+                        }
+                        match Self::parse_message(text) {
+                            Ok(Some(parsed)) => {
+                                self.check_invariants(&parsed, text);
+                                return Ok(Some(parsed));
+                            }
+                            Ok(None) => {
+                                tracing::debug!("Ignored Bybit msg: {}", text);
+                                continue;
+                            },
+                            Err(failure) => {
+                                tracing::warn!("Bybit {} parse error", failure.kind);
+                                if let Some(quarantine) = &self.quarantine {
+                                    quarantine.record(Exchange::Bybit, failure, text);
                                 }
+                                continue;
                             }
                         }
                     }
-                    Ok(Ok(None)) => {
-                        self.connection = None;
-                        return Ok(None);
-                    }
-                    Ok(Err(e)) => {
-                        return Err(HftError::WebSocket(e.to_string()));
-                    }
-                    Err(_) => {
-                        // Timeout, loop again to check ping
-                        continue;
-                    }
+                }
+                Ok(Ok(None)) => {
+                    self.connection = None;
+                    return Ok(None);
+                }
+                Ok(Err(e)) => {
+                    return Err(HftError::WebSocket(e.to_string()));
+                }
+                Err(_) => {
+                    // Timeout, loop again to check ping
+                    continue;
                 }
             }
         }
-        
-        Ok(None)
+    }
+
+    /// Structural check ahead of field extraction (when enabled)
+    #[inline]
+    fn check_frame(&self, text: &str) -> std::result::Result<(), FrameDefect> {
+        match self.tuning.max_frame_bytes {
+            0 => Ok(()),
+            max_len => validate_structure(text.as_bytes(), max_len),
+        }
+    }
+
+    /// Sampled invariant check of market data (logged, quarantined if configured)
+    ///
+    /// Deltas are checked on the fields they carry, before the merge.
+    fn check_invariants(&mut self, parsed: &BybitMessage, text: &str) {
+        let market_data = matches!(parsed, BybitMessage::Trade(_) | BybitMessage::Ticker(_) | BybitMessage::TickerUpdate(_));
+        if !market_data || !self.invariants.due() {
+            return;
+        }
+        let violation = match parsed {
+            BybitMessage::Trade(trade) => self.invariants.check_trade(trade),
+            BybitMessage::Ticker(ticker) => self.invariants.check_ticker(ticker),
+            BybitMessage::TickerUpdate(update) => self.invariants.check_quote(
+                update.symbol,
+                update.bid_price,
+                update.ask_price,
+                [update.bid_qty, update.ask_qty],
+                update.timestamp,
+            ),
+            _ => None,
+        };
+        let Some(invariant) = violation else {
+            return;
+        };
+        tracing::warn!("Bybit invariant violated: {}", invariant.name());
+        if self.tuning.capture_violations {
+            if let Some(quarantine) = &self.quarantine {
+                quarantine.record(Exchange::Bybit, ParseFailure { kind: invariant.kind() }, text);
+            }
+        }
     }
 
     /// Parse Bybit V5 message
-    fn parse_message(text: &str) -> Result<Option<BybitMessage>> {
+    ///
+    /// A detected message type whose fields fail to parse is a [`ParseFailure`].
+    fn parse_message(text: &str) -> std::result::Result<Option<BybitMessage>, ParseFailure> {
         let data = text.as_bytes();
 
         // Detect message type and parse accordingly
@@ -259,13 +662,19 @@ impl BybitWsClient {
             BybitMessageType::PublicTrade => {
                 match BybitParser::parse_public_trade(data) {
                     Some(result) => Ok(Some(BybitMessage::Trade(result.data))),
-                    None => Ok(None),
+                    None => Err(ParseFailure { kind: "publicTrade" }),
                 }
             }
-            BybitMessageType::Ticker => {
+            BybitMessageType::Ticker | BybitMessageType::TickerSnapshot => {
                 match BybitParser::parse_ticker_update(data) {
                     Some(result) => Ok(Some(BybitMessage::TickerUpdate(result.data))),
-                    None => Ok(None),
+                    None => Err(ParseFailure { kind: "tickers" }),
+                }
+            }
+            BybitMessageType::Liquidation => {
+                match BybitParser::parse_liquidations(data) {
+                    Some(result) => Ok(Some(BybitMessage::Liquidations(result.data))),
+                    None => Err(ParseFailure { kind: "allLiquidation" }),
                 }
             }
             BybitMessageType::Pong => {
@@ -274,6 +683,10 @@ impl BybitWsClient {
             BybitMessageType::SubscriptionResponse => {
                 Ok(Some(BybitMessage::SubscriptionSuccess))
             }
+            BybitMessageType::Error => {
+                let msg = BybitParser::error_message(data).unwrap_or_default();
+                Ok(Some(BybitMessage::Error(String::from_utf8_lossy(msg).into_owned())))
+            }
             BybitMessageType::Unknown => {
                 // Unknown message type
                 Ok(None)
@@ -288,6 +701,11 @@ impl BybitWsClient {
             .unwrap_or(false)
     }
 
+    /// Get connection buffer sizes (None if not connected yet)
+    pub fn buffer_stats(&self) -> Option<BufferStats> {
+        self.connection.as_ref().map(|c| c.buffer_stats())
+    }
+
     /// Get connection health
     pub fn health(&self) -> bool {
         self.monitor.is_healthy()
@@ -350,18 +768,63 @@ impl WebSocketExchange for BybitWsClient {
         self.subscribe_tickers(symbols).await
     }
 
+    async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> crate::Result<()> {
+        self.subscribe_mark_prices(symbols).await
+    }
+
+    async fn subscribe_liquidations(&mut self, symbols: &[Symbol]) -> crate::Result<()> {
+        self.subscribe_all_liquidations(symbols).await
+    }
+
     async fn next_message(&mut self) -> crate::Result<Option<ExchangeMessage>> {
+        // Ticker held back behind a status change
+        if let Some(ticker) = self.pending_ticker.take() {
+            return Ok(Some(ExchangeMessage::Ticker(Exchange::Bybit, ticker)));
+        }
+
+        // Liquidations batched with the previous one
+        if let Some(liq) = self.pending_liquidations.pop_front() {
+            return Ok(Some(ExchangeMessage::Liquidation(Exchange::Bybit, liq)));
+        }
+
+        // Mark update split from the previous ticker delta
+        if let Some(symbol) = self.pending_mark.take() {
+            if let Some(mark) = self.marks.get(symbol.as_raw() as usize).copied().flatten() {
+                return Ok(Some(ExchangeMessage::MarkPrice(Exchange::Bybit, mark)));
+            }
+        }
+
         match self.recv().await? {
             Some(BybitMessage::Trade(trade)) => {
                 Ok(Some(ExchangeMessage::Trade(Exchange::Bybit, trade)))
             }
+            Some(BybitMessage::Liquidations(liqs)) => {
+                self.pending_liquidations.extend(liqs);
+                Ok(self
+                    .pending_liquidations
+                    .pop_front()
+                    .map(|liq| ExchangeMessage::Liquidation(Exchange::Bybit, liq)))
+            }
             Some(BybitMessage::Ticker(ticker)) => {
                 // Should not happen for V5 linear (deltas only), but support it
                 Ok(Some(ExchangeMessage::Ticker(Exchange::Bybit, ticker)))
             }
             Some(BybitMessage::TickerUpdate(update)) => {
-                if let Some(ticker) = self.merge_ticker(update) {
+                let symbol = update.symbol;
+                let status = update.status.filter(|&status| self.record_status(symbol, status));
+                let mark = if self.marks_enabled { self.merge_mark(&update) } else { None };
+
+                if let Some(status) = status {
+                    // Status first, so the ticker is judged under it
+                    self.pending_ticker = self.merge_ticker(update);
+                    self.pending_mark = mark.map(|m| m.symbol);
+                    let notice = StatusNotice { symbol, exchange: Exchange::Bybit, status };
+                    Ok(Some(ExchangeMessage::Status(notice)))
+                } else if let Some(ticker) = self.merge_ticker(update) {
+                    self.pending_mark = mark.map(|m| m.symbol);
                     Ok(Some(ExchangeMessage::Ticker(Exchange::Bybit, ticker)))
+                } else if let Some(mark) = mark {
+                    Ok(Some(ExchangeMessage::MarkPrice(Exchange::Bybit, mark)))
                 } else {
                     // Update processed but ticker not yet valid/complete
                     Ok(None)
@@ -407,6 +870,8 @@ pub enum BybitMessage {
     Ticker(TickerData),
     /// Ticker update (delta)
     TickerUpdate(BybitTickerUpdate),
+    /// Liquidations batched in one allLiquidation frame (at least one)
+    Liquidations(Vec<LiquidationData>),
     /// Orderbook data
     OrderBook(OrderBookData),
     /// Subscription success response
@@ -442,6 +907,78 @@ mod tests {
         assert!(!client.is_connected());
     }
 
+    #[test]
+    fn test_merge_mark_delta() {
+        crate::test_utils::init_test_registry();
+        let mut client = BybitWsClient::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        let mut update = BybitTickerUpdate {
+            symbol: sym,
+            snapshot: false,
+            bid_price: None,
+            bid_qty: None,
+            ask_price: None,
+            ask_qty: None,
+            mark_price: Some(FixedPoint8::from_raw(5_000_000_000_000)),
+            index_price: None,
+            funding_rate: Some(FixedPoint8::from_raw(10_000)),
+            next_funding_time: None,
+            sequence: None,
+            status: None,
+            timestamp: 1000,
+        };
+        // Index missing: not yet complete
+        assert!(client.merge_mark(&update).is_none());
+
+        update.mark_price = None;
+        update.funding_rate = None;
+        update.index_price = Some(FixedPoint8::from_raw(4_999_000_000_000));
+        update.timestamp = 2000;
+        let mark = client.merge_mark(&update).unwrap();
+        assert_eq!(mark.mark_price.as_raw(), 5_000_000_000_000);
+        assert_eq!(mark.index_price.as_raw(), 4_999_000_000_000);
+        assert_eq!(mark.funding_rate.as_raw(), 10_000);
+        assert_eq!(mark.timestamp, 2000);
+    }
+
+    #[test]
+    fn test_snapshot_resets_ticker_cache() {
+        crate::test_utils::init_test_registry();
+        let mut client = BybitWsClient::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let fp = |v: i64| Some(FixedPoint8::from_raw(v * FixedPoint8::SCALE));
+        let update = BybitTickerUpdate {
+            symbol: sym,
+            snapshot: true,
+            bid_price: fp(100),
+            bid_qty: fp(1),
+            ask_price: fp(101),
+            ask_qty: fp(1),
+            mark_price: None,
+            index_price: None,
+            funding_rate: None,
+            next_funding_time: None,
+            sequence: Some(50),
+            status: None,
+            timestamp: 5000,
+        };
+        assert!(client.merge_ticker(update).is_some());
+
+        // Delta merges into the cached ticker
+        let delta = BybitTickerUpdate { snapshot: false, ask_price: None, ask_qty: None, bid_price: fp(99), ..update };
+        let merged = client.merge_ticker(delta).unwrap();
+        assert_eq!((merged.bid_price, merged.ask_price), (fp(99).unwrap(), fp(101).unwrap()));
+
+        // A snapshot omitting the ask does not inherit the stale one
+        let partial = BybitTickerUpdate { ask_price: None, ask_qty: None, ..update };
+        assert!(client.merge_ticker(partial).is_none());
+
+        // After reconnect sequence and time restart instead of keeping the old maximum
+        let restarted = client.merge_ticker(BybitTickerUpdate { sequence: Some(1), timestamp: 10, ..update }).unwrap();
+        assert_eq!((restarted.update_id, restarted.timestamp), (1, 10));
+    }
+
     #[test]
     fn test_bybit_urls() {
         assert_eq!(BybitWsClient::WS_URL, "wss://stream.bybit.com/v5/public/linear");
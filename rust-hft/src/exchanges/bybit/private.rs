@@ -0,0 +1,654 @@
+//! Bybit V5 private WebSocket (Cold Path)
+//!
+//! Authenticates with `auth` (api key, expiry, HMAC of `GET/realtime` +
+//! expiry) and subscribes to the order, execution, position and wallet
+//! topics. Frames decode into the venue-neutral [`UserEvent`]s; only linear
+//! contracts are reported. A failed auth or subscribe ack is an error, so a
+//! bad key fails the connect instead of leaving a silent stream. A data frame
+//! that fails to decode is logged and quarantined; the stream carries on.
+//! [`BybitPrivateClient::run`] keeps the stream connected and forwards its
+//! events and health to the engine.
+
+use crate::core::{clock, FixedPoint8, Side, SymbolMapper};
+use crate::exchanges::user_data::{
+    BalanceUpdate, ExecutionUpdate, OrderState, OrderUpdate, PositionUpdate, UserEvent, UserStreamReport,
+};
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::{FeedTuning, NetworkConfig};
+use crate::infrastructure::unparsed::{ParseFailure, ParseQuarantine};
+use crate::rest::models::{fixed_or_zero, u64_from_str};
+use crate::rest::RequestSigner;
+use crate::ws::connection::WebSocketConnection;
+use crate::{HftError, Result};
+use serde::Deserialize;
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::Duration;
+use tokio::sync::mpsc;
+use tokio::time::{timeout, Instant};
+
+/// Private topics subscribed after auth
+pub const TOPICS: [&str; 4] = ["order", "execution", "position", "wallet"];
+
+/// Validity of the auth signature
+const AUTH_EXPIRY: Duration = Duration::from_secs(10);
+
+/// Wait for the auth and subscribe acks
+const ACK_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Wait before reconnecting a failed or closed stream
+const RECONNECT_DELAY: Duration = Duration::from_secs(2);
+
+/// Bybit private WebSocket client
+pub struct BybitPrivateClient {
+    connection: Option<WebSocketConnection>,
+    signer: RequestSigner,
+    /// Outbound network settings (source address)
+    network: NetworkConfig,
+    /// Receive timeout and keepalive timing
+    tuning: FeedTuning,
+    /// WebSocket endpoint (WS_URL unless overridden)
+    url: String,
+    /// Last message timestamp
+    last_message: Instant,
+    /// Events of a multi-entry frame not yet returned
+    pending: VecDeque<UserEvent>,
+    /// Where frames that fail to decode are kept (debug endpoint)
+    quarantine: Option<Arc<ParseQuarantine>>,
+}
+
+impl BybitPrivateClient {
+    /// Bybit private WebSocket URL
+    pub const WS_URL: &'static str = "wss://stream.bybit.com/v5/private";
+    /// Bybit Testnet private URL
+    pub const WS_URL_TESTNET: &'static str = "wss://stream-testnet.bybit.com/v5/private";
+
+    pub fn new(signer: RequestSigner) -> Self {
+        Self {
+            connection: None,
+            signer,
+            network: NetworkConfig::default(),
+            tuning: FeedTuning::default(),
+            url: Self::WS_URL.to_string(),
+            last_message: Instant::now(),
+            pending: VecDeque::new(),
+            quarantine: None,
+        }
+    }
+
+    /// Client with `BYBIT_API_KEY` / `BYBIT_API_SECRET` (None if unset)
+    pub fn from_env() -> Option<Self> {
+        RequestSigner::from_env("BYBIT").map(Self::new)
+    }
+
+    /// Set outbound network settings (source address binding)
+    pub fn with_network(mut self, network: NetworkConfig) -> Self {
+        self.network = network;
+        self
+    }
+
+    /// Set receive timeout and keepalive timing
+    pub fn with_tuning(mut self, tuning: FeedTuning) -> Self {
+        self.tuning = tuning;
+        self
+    }
+
+    /// Override WebSocket endpoint (testnet, mock servers)
+    pub fn with_url(mut self, url: impl Into<String>) -> Self {
+        self.url = url.into();
+        self
+    }
+
+    /// Keep frames that fail to decode in `quarantine`
+    pub fn with_quarantine(mut self, quarantine: Arc<ParseQuarantine>) -> Self {
+        self.quarantine = Some(quarantine);
+        self
+    }
+
+    /// Connect, authenticate and subscribe to every private topic
+    pub async fn connect(&mut self) -> Result<()> {
+        let mut conn = WebSocketConnection::connect_with(&self.url, &self.network)
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?;
+
+        let expires = clock::coarse_unix_ms() + AUTH_EXPIRY.as_millis() as u64;
+        conn.send_text(&auth_message(&self.signer, expires))
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        timeout(ACK_TIMEOUT, await_ack(&mut conn, "auth"))
+            .await
+            .map_err(|_| HftError::WebSocket("Bybit private auth timed out".to_string()))??;
+
+        let subscribe = serde_json::json!({"op": "subscribe", "args": TOPICS});
+        conn.send_text(&subscribe.to_string())
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?;
+        timeout(ACK_TIMEOUT, await_ack(&mut conn, "subscribe"))
+            .await
+            .map_err(|_| HftError::WebSocket("Bybit private subscribe timed out".to_string()))??;
+
+        self.pending.clear();
+        self.last_message = Instant::now();
+        self.connection = Some(conn);
+        Ok(())
+    }
+
+    #[inline]
+    pub fn is_connected(&self) -> bool {
+        self.connection.as_ref().is_some_and(|c| c.is_connected())
+    }
+
+    /// Next account event (None once the connection closed)
+    ///
+    /// A rejected op is returned as an error; undecodable frames are skipped.
+    pub async fn next_event(&mut self) -> Result<Option<UserEvent>> {
+        loop {
+            if let Some(event) = self.pending.pop_front() {
+                return Ok(Some(event));
+            }
+            let Some(conn) = self.connection.as_mut() else {
+                return Ok(None);
+            };
+
+            // Send ping after the configured inactivity
+            if self.last_message.elapsed() > self.tuning.ping_interval {
+                conn.send_text(r#"{"op":"ping"}"#)
+                    .await
+                    .map_err(|e| HftError::WebSocket(e.to_string()))?;
+                self.last_message = Instant::now();
+            }
+
+            match timeout(self.tuning.recv_timeout, conn.recv()).await {
+                Ok(Ok(Some(msg))) => {
+                    self.last_message = Instant::now();
+                    if let Ok(text) = msg.to_text() {
+                        match parse_frame(text)? {
+                            Ok(events) => self.pending.extend(events),
+                            Err(failure) => {
+                                tracing::warn!("Bybit private {} parse error", failure.kind);
+                                if let Some(quarantine) = &self.quarantine {
+                                    quarantine.record(Exchange::Bybit, failure, text);
+                                }
+                            }
+                        }
+                    }
+                }
+                Ok(Ok(None)) => {
+                    self.connection = None;
+                    return Ok(None);
+                }
+                Ok(Err(e)) => return Err(HftError::WebSocket(e.to_string())),
+                // Timeout, loop again to check ping
+                Err(_) => continue,
+            }
+        }
+    }
+
+    /// Close the connection
+    pub async fn disconnect(&mut self) -> Result<()> {
+        if let Some(mut conn) = self.connection.take() {
+            conn.close().await.map_err(|e| HftError::WebSocket(e.to_string()))?;
+        }
+        Ok(())
+    }
+
+    /// Keep the stream connected, forwarding events and health to `reports`
+    ///
+    /// Healthy is reported once authenticated and subscribed, lost when a
+    /// connect fails or the connection ends. Returns once `reports` closes.
+    pub async fn run(mut self, reports: mpsc::Sender<UserStreamReport>) {
+        let health = |healthy| UserStreamReport::Health { exchange: Exchange::Bybit, healthy };
+        while !reports.is_closed() {
+            match self.connect().await {
+                Ok(()) => {
+                    tracing::info!("Bybit private stream connected");
+                    if reports.send(health(true)).await.is_err() {
+                        return;
+                    }
+                    loop {
+                        match self.next_event().await {
+                            Ok(Some(event)) => {
+                                if reports.send(UserStreamReport::Event(event)).await.is_err() {
+                                    return;
+                                }
+                            }
+                            Ok(None) => {
+                                tracing::warn!("Bybit private stream closed");
+                                break;
+                            }
+                            Err(e) => {
+                                tracing::warn!("Bybit private stream failed: {}", e);
+                                break;
+                            }
+                        }
+                    }
+                    let _ = self.disconnect().await;
+                }
+                Err(e) => tracing::warn!("Bybit private stream connect failed: {}", e),
+            }
+            if reports.send(health(false)).await.is_err() {
+                return;
+            }
+            tokio::time::sleep(RECONNECT_DELAY).await;
+        }
+    }
+}
+
+/// `auth` op valid until `expires_ms`
+fn auth_message(signer: &RequestSigner, expires_ms: u64) -> String {
+    serde_json::json!({
+        "op": "auth",
+        "args": [signer.api_key(), expires_ms, signer.bybit_ws_signature(expires_ms)],
+    })
+    .to_string()
+}
+
+/// Read frames until the ack of `op`
+async fn await_ack(conn: &mut WebSocketConnection, op: &str) -> Result<()> {
+    loop {
+        let msg = conn
+            .recv()
+            .await
+            .map_err(|e| HftError::WebSocket(e.to_string()))?
+            .ok_or_else(|| HftError::WebSocket(format!("Bybit private connection closed during {}", op)))?;
+        let Ok(text) = msg.to_text() else { continue };
+        let Ok(frame) = serde_json::from_str::<Frame>(text) else { continue };
+        if frame.op.as_deref() == Some(op) {
+            return frame.ack();
+        }
+    }
+}
+
+/// Envelope of every private frame (op acks and topic pushes)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct Frame {
+    #[serde(default)]
+    op: Option<String>,
+    #[serde(default)]
+    success: Option<bool>,
+    #[serde(default, rename = "ret_msg")]
+    ret_msg: String,
+    #[serde(default)]
+    topic: Option<String>,
+    #[serde(default)]
+    creation_time: u64,
+    #[serde(default)]
+    data: serde_json::Value,
+}
+
+impl Frame {
+    /// Error for a rejected op
+    fn ack(&self) -> Result<()> {
+        match self.success {
+            Some(false) => Err(HftError::WebSocket(format!(
+                "Bybit private {} rejected: {}",
+                self.op.as_deref().unwrap_or("request"),
+                self.ret_msg
+            ))),
+            _ => Ok(()),
+        }
+    }
+
+    fn entries<T: for<'de> Deserialize<'de>>(self, kind: &'static str) -> std::result::Result<Vec<T>, ParseFailure> {
+        serde_json::from_value(self.data).map_err(|_| ParseFailure { kind })
+    }
+}
+
+/// Decode one private frame (acks and pongs yield no events)
+///
+/// A rejected op is the outer error: the stream is unusable. A frame whose
+/// data fails to decode is an inner [`ParseFailure`] named after its topic.
+pub fn parse_frame(text: &str) -> Result<std::result::Result<Vec<UserEvent>, ParseFailure>> {
+    let Ok(frame) = serde_json::from_str::<Frame>(text) else {
+        return Ok(Err(ParseFailure { kind: "frame" }));
+    };
+    if frame.op.is_some() {
+        frame.ack()?;
+        return Ok(Ok(Vec::new()));
+    }
+    let created = frame.creation_time * 1_000_000;
+    let events = match frame.topic.as_deref() {
+        Some("order") => frame
+            .entries::<BybitOrderEvent>("order")
+            .map(|entries| entries.iter().filter_map(BybitOrderEvent::to_event).collect()),
+        Some("execution") => frame
+            .entries::<BybitExecutionEvent>("execution")
+            .map(|entries| entries.iter().filter_map(BybitExecutionEvent::to_event).collect()),
+        Some("position") => frame
+            .entries::<BybitPositionEvent>("position")
+            .map(|entries| entries.iter().filter_map(BybitPositionEvent::to_event).collect()),
+        Some("wallet") => frame.entries::<BybitWalletEvent>("wallet").map(|wallets| {
+            wallets
+                .into_iter()
+                .flat_map(|wallet| wallet.coin)
+                .map(|coin| {
+                    UserEvent::Balance(BalanceUpdate {
+                        exchange: Exchange::Bybit,
+                        asset: coin.coin,
+                        wallet_balance: coin.wallet_balance,
+                        available: coin.available_to_withdraw,
+                        timestamp: created,
+                    })
+                })
+                .collect()
+        }),
+        _ => Ok(Vec::new()),
+    };
+    Ok(events)
+}
+
+/// Linear contract symbol (None for other categories or unknown symbols)
+fn linear_symbol(category: &str, symbol: &str) -> Option<crate::core::Symbol> {
+    (category == "linear").then(|| SymbolMapper::from_exchange_name(symbol, Exchange::Bybit))?
+}
+
+/// `order` topic entry
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitOrderEvent {
+    category: String,
+    symbol: String,
+    order_id: String,
+    #[serde(default)]
+    order_link_id: String,
+    side: String,
+    order_status: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    qty: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    cum_exec_qty: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    avg_price: FixedPoint8,
+    #[serde(deserialize_with = "u64_from_str")]
+    updated_time: u64,
+}
+
+impl BybitOrderEvent {
+    fn to_event(&self) -> Option<UserEvent> {
+        let state = match self.order_status.as_str() {
+            "Created" | "New" | "Untriggered" | "Triggered" => OrderState::New,
+            "PartiallyFilled" => OrderState::PartiallyFilled,
+            "Filled" => OrderState::Filled,
+            "Cancelled" | "PartiallyFilledCanceled" | "Deactivated" => OrderState::Cancelled,
+            "Rejected" => OrderState::Rejected,
+            _ => return None,
+        };
+        Some(UserEvent::Order(OrderUpdate {
+            exchange: Exchange::Bybit,
+            symbol: linear_symbol(&self.category, &self.symbol)?,
+            side: Side::from_bytes(self.side.as_bytes())?,
+            order_id: self.order_id.clone(),
+            client_order_id: self.order_link_id.clone(),
+            state,
+            price: self.price,
+            quantity: self.qty,
+            filled_quantity: self.cum_exec_qty,
+            average_price: self.avg_price,
+            timestamp: self.updated_time * 1_000_000,
+        }))
+    }
+}
+
+/// `execution` topic entry
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitExecutionEvent {
+    category: String,
+    symbol: String,
+    order_id: String,
+    exec_id: String,
+    side: String,
+    /// Trade, AdlTrade, BustTrade, Funding, Settle...
+    exec_type: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    exec_price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    exec_qty: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    exec_fee: FixedPoint8,
+    #[serde(default)]
+    is_maker: bool,
+    #[serde(deserialize_with = "u64_from_str")]
+    exec_time: u64,
+}
+
+impl BybitExecutionEvent {
+    fn to_event(&self) -> Option<UserEvent> {
+        // Funding and settlement are cash flows, not fills
+        if !matches!(self.exec_type.as_str(), "Trade" | "AdlTrade" | "BustTrade") {
+            return None;
+        }
+        Some(UserEvent::Execution(ExecutionUpdate {
+            exchange: Exchange::Bybit,
+            symbol: linear_symbol(&self.category, &self.symbol)?,
+            side: Side::from_bytes(self.side.as_bytes())?,
+            order_id: self.order_id.clone(),
+            execution_id: self.exec_id.clone(),
+            price: self.exec_price,
+            quantity: self.exec_qty,
+            fee: self.exec_fee,
+            is_maker: self.is_maker,
+            timestamp: self.exec_time * 1_000_000,
+        }))
+    }
+}
+
+/// `position` topic entry
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitPositionEvent {
+    category: String,
+    symbol: String,
+    /// "Buy", "Sell" or "" when flat
+    side: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    size: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    entry_price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    liq_price: FixedPoint8,
+    #[serde(deserialize_with = "u64_from_str")]
+    updated_time: u64,
+}
+
+impl BybitPositionEvent {
+    fn to_event(&self) -> Option<UserEvent> {
+        Some(UserEvent::Position(PositionUpdate {
+            exchange: Exchange::Bybit,
+            symbol: linear_symbol(&self.category, &self.symbol)?,
+            side: Side::from_bytes(self.side.as_bytes()),
+            size: self.size,
+            entry_price: self.entry_price,
+            liquidation_price: self.liq_price,
+            timestamp: self.updated_time * 1_000_000,
+        }))
+    }
+}
+
+/// `wallet` topic entry (one per account type)
+#[derive(Debug, Deserialize)]
+struct BybitWalletEvent {
+    coin: Vec<BybitWalletCoin>,
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BybitWalletCoin {
+    coin: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    wallet_balance: FixedPoint8,
+    #[serde(default, deserialize_with = "fixed_or_zero")]
+    available_to_withdraw: FixedPoint8,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+
+    fn fixed(value: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(value).unwrap()
+    }
+
+    #[test]
+    fn test_parse_private_topics() {
+        crate::test_utils::init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        let order = r#"{"topic":"order","creationTime":1700000000000,"data":[
+            {"category":"linear","symbol":"BTCUSDT","orderId":"o1","orderLinkId":"c1","side":"Buy","orderStatus":"PartiallyFilled",
+             "price":"100.5","qty":"2","cumExecQty":"0.5","avgPrice":"100.4","updatedTime":"1700000000001"},
+            {"category":"spot","symbol":"BTCUSDT","orderId":"o2","orderLinkId":"","side":"Sell","orderStatus":"New",
+             "price":"1","qty":"1","cumExecQty":"0","avgPrice":"","updatedTime":"1700000000001"}]}"#;
+        let events = parse_frame(order).unwrap().unwrap();
+        assert_eq!(events.len(), 1);
+        let UserEvent::Order(update) = &events[0] else { panic!("expected order") };
+        assert_eq!((update.symbol, update.side, update.state), (btc, Side::Buy, OrderState::PartiallyFilled));
+        assert_eq!((update.filled_quantity, update.average_price), (fixed(0.5), fixed(100.4)));
+        assert_eq!((update.client_order_id.as_str(), update.timestamp), ("c1", 1_700_000_000_001_000_000));
+        assert!(!update.state.is_final());
+
+        let execution = r#"{"topic":"execution","creationTime":1700000000000,"data":[
+            {"category":"linear","symbol":"BTCUSDT","orderId":"o1","execId":"e1","side":"Buy","execType":"Trade",
+             "execPrice":"100.4","execQty":"0.5","execFee":"0.03","isMaker":false,"execTime":"1700000000002"},
+            {"category":"linear","symbol":"BTCUSDT","orderId":"","execId":"e2","side":"Buy","execType":"Funding",
+             "execPrice":"100.4","execQty":"0.5","execFee":"0.01","isMaker":false,"execTime":"1700000000002"}]}"#;
+        let events = parse_frame(execution).unwrap().unwrap();
+        assert_eq!(events.len(), 1);
+        let UserEvent::Execution(fill) = &events[0] else { panic!("expected execution") };
+        assert_eq!((fill.price, fill.quantity, fill.fee, fill.is_maker), (fixed(100.4), fixed(0.5), fixed(0.03), false));
+        assert_eq!(fill.execution_id, "e1");
+
+        let position = r#"{"topic":"position","creationTime":1700000000000,"data":[
+            {"category":"linear","symbol":"BTCUSDT","side":"","size":"0","entryPrice":"0","liqPrice":"","updatedTime":"1700000000003"}]}"#;
+        let UserEvent::Position(flat) = parse_frame(position).unwrap().unwrap()[0] else { panic!("expected position") };
+        assert_eq!((flat.side, flat.to_position()), (None, None));
+
+        let wallet = r#"{"topic":"wallet","creationTime":1700000000000,"data":[{"accountType":"UNIFIED","coin":[
+            {"coin":"USDT","walletBalance":"1000.5","availableToWithdraw":"900"},{"coin":"BTC","walletBalance":"0.1","availableToWithdraw":""}]}]}"#;
+        let events = parse_frame(wallet).unwrap().unwrap();
+        assert_eq!(events.len(), 2);
+        let UserEvent::Balance(usdt) = &events[0] else { panic!("expected balance") };
+        assert_eq!((usdt.asset.as_str(), usdt.wallet_balance, usdt.available), ("USDT", fixed(1000.5), fixed(900.0)));
+        assert_eq!((usdt.exchange, usdt.timestamp), (Exchange::Bybit, 1_700_000_000_000_000_000));
+
+        assert!(parse_frame(r#"{"success":true,"ret_msg":"pong","op":"ping"}"#).unwrap().unwrap().is_empty());
+        let rejected = parse_frame(r#"{"success":false,"ret_msg":"topic not found","op":"subscribe"}"#);
+        assert!(rejected.unwrap_err().to_string().contains("subscribe rejected: topic not found"));
+
+        // Undecodable data is a failure of its topic, not of the stream
+        let bad = parse_frame(r#"{"topic":"execution","creationTime":1,"data":[{"category":"linear"}]}"#).unwrap();
+        assert_eq!(bad.unwrap_err(), ParseFailure { kind: "execution" });
+        assert_eq!(parse_frame("not json").unwrap().unwrap_err(), ParseFailure { kind: "frame" });
+    }
+
+    #[tokio::test]
+    async fn test_connect_authenticates_then_subscribes() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        crate::test_utils::init_test_registry();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v5/private", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            // (auth accepted, subscribe accepted) per connection
+            for (auth_ok, subscribe_ok) in [(true, true), (false, false), (true, false)] {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                let auth: serde_json::Value = serde_json::from_str(ws.next().await.unwrap().unwrap().to_text().unwrap()).unwrap();
+                requests.push(auth);
+                let ack = format!(r#"{{"success":{},"ret_msg":"{}","op":"auth"}}"#, auth_ok, if auth_ok { "" } else { "Invalid apikey" });
+                ws.send(Message::text(ack)).await.unwrap();
+                if auth_ok {
+                    let subscribe = ws.next().await.unwrap().unwrap().to_text().unwrap().to_string();
+                    assert!(subscribe.contains(r#""args":["order","execution","position","wallet"]"#), "{}", subscribe);
+                    let ack = format!(
+                        r#"{{"success":{},"ret_msg":"{}","op":"subscribe"}}"#,
+                        subscribe_ok,
+                        if subscribe_ok { "" } else { "topic not found" }
+                    );
+                    ws.send(Message::text(ack)).await.unwrap();
+                }
+                if subscribe_ok {
+                    ws.send(Message::text(
+                        r#"{"topic":"order","creationTime":1,"data":[{"category":"linear","symbol":"ETHUSDT","orderId":"o9","side":"Sell",
+                           "orderStatus":"Filled","price":"0","qty":"1","cumExecQty":"1","avgPrice":"10","updatedTime":"1"}]}"#,
+                    ))
+                    .await
+                    .unwrap();
+                }
+                while let Some(Ok(_)) = ws.next().await {}
+            }
+            requests
+        });
+
+        let mut client = BybitPrivateClient::new(RequestSigner::new("key", "secret")).with_url(url.clone());
+        client.connect().await.unwrap();
+        let Some(UserEvent::Order(order)) = client.next_event().await.unwrap() else { panic!("expected order") };
+        assert_eq!((order.order_id.as_str(), order.state), ("o9", OrderState::Filled));
+        assert!(order.state.is_final());
+        client.disconnect().await.unwrap();
+
+        let mut rejected = BybitPrivateClient::new(RequestSigner::new("key", "wrong")).with_url(url.clone());
+        let err = rejected.connect().await.unwrap_err();
+        assert!(err.to_string().contains("auth rejected: Invalid apikey"), "{}", err);
+        drop(rejected);
+
+        // A rejected subscription fails the connect too
+        let mut unsubscribed = BybitPrivateClient::new(RequestSigner::new("key", "secret")).with_url(url);
+        let err = unsubscribed.connect().await.unwrap_err();
+        assert!(err.to_string().contains("subscribe rejected: topic not found"), "{}", err);
+        assert!(!unsubscribed.is_connected());
+        drop(unsubscribed);
+
+        let requests = server.await.unwrap();
+        let args = requests[0]["args"].as_array().unwrap();
+        assert_eq!(args[0], "key");
+        let expires = args[1].as_u64().unwrap();
+        assert_eq!(args[2], RequestSigner::new("key", "secret").bybit_ws_signature(expires));
+    }
+
+    #[tokio::test]
+    async fn test_run_reports_health_and_events() {
+        use futures_util::{SinkExt, StreamExt};
+        use tokio_tungstenite::tungstenite::Message;
+
+        crate::test_utils::init_test_registry();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/v5/private", listener.local_addr().unwrap());
+        tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            ws.next().await.unwrap().unwrap();
+            ws.send(Message::text(r#"{"success":true,"ret_msg":"","op":"auth"}"#)).await.unwrap();
+            ws.next().await.unwrap().unwrap();
+            ws.send(Message::text(r#"{"success":true,"ret_msg":"","op":"subscribe"}"#)).await.unwrap();
+            ws.send(Message::text(r#"{"topic":"execution","creationTime":1,"data":{"unexpected":true}}"#)).await.unwrap();
+            ws.send(Message::text(
+                r#"{"topic":"execution","creationTime":1,"data":[{"category":"linear","symbol":"BTCUSDT","orderId":"o1","execId":"e1",
+                   "side":"Sell","execType":"Trade","execPrice":"100","execQty":"1","execFee":"0.05","isMaker":true,"execTime":"1"}]}"#,
+            ))
+            .await
+            .unwrap();
+            let _ = ws.close(None).await;
+        });
+
+        let (tx, mut rx) = mpsc::channel(8);
+        let quarantine = Arc::new(ParseQuarantine::default());
+        let client = BybitPrivateClient::new(RequestSigner::new("key", "secret")).with_url(url).with_quarantine(quarantine.clone());
+        let task = tokio::spawn(client.run(tx));
+        let health = |healthy| UserStreamReport::Health { exchange: Exchange::Bybit, healthy };
+        assert_eq!(rx.recv().await.unwrap(), health(true));
+        // The undecodable frame is quarantined and the stream carries on
+        let Some(UserStreamReport::Event(UserEvent::Execution(fill))) = rx.recv().await else { panic!("expected execution") };
+        assert_eq!((fill.side, fill.quantity, fill.is_maker), (Side::Sell, fixed(1.0), true));
+        let unparsed = quarantine.snapshot();
+        assert_eq!((unparsed.counts[0].kind, unparsed.counts[0].count), ("execution", 1));
+        assert_eq!(rx.recv().await.unwrap(), health(false));
+        task.abort();
+    }
+}
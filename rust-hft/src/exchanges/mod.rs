@@ -4,13 +4,17 @@ pub mod binance;
 pub mod bybit;
 pub mod parsing;
 pub mod traits;
+pub mod user_data;
 
 pub use binance::{BinanceWsClient, BinanceMessage};
-pub use bybit::{BybitWsClient, BybitMessage, OrderBookData};
+pub use bybit::{BybitPrivateClient, BybitWsClient, BybitMessage, OrderBookData};
 pub use parsing::{BinanceParser, BybitParser};
 pub use traits::{AnyExchange, ErrorKind, ExchangeError, ExchangeMessage, WebSocketExchange};
+pub use user_data::{BalanceUpdate, ExecutionUpdate, OrderState, OrderUpdate, PositionUpdate, UserEvent, UserStreamReport};
 
 use crate::core::Symbol;
+use crate::ws::subscription::StreamType;
+use crate::ws::BufferStats;
 use crate::Result;
 
 /// Enum dispatch for exchange clients
@@ -35,6 +39,13 @@ impl ExchangeClient {
         }
     }
 
+    pub fn exchange(&self) -> Exchange {
+        match self {
+            Self::Binance(_) => Exchange::Binance,
+            Self::Bybit(_) => Exchange::Bybit,
+        }
+    }
+
     pub async fn subscribe_tickers(&mut self, symbols: &[Symbol]) -> Result<()> {
         match self {
             Self::Binance(c) => c.subscribe_tickers(symbols).await,
@@ -42,26 +53,153 @@ impl ExchangeClient {
         }
     }
 
+    pub async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> Result<()> {
+        match self {
+            Self::Binance(c) => c.subscribe_mark_prices(symbols).await,
+            Self::Bybit(c) => c.subscribe_mark_prices(symbols).await,
+        }
+    }
+
+    pub async fn subscribe_liquidations(&mut self, symbols: &[Symbol]) -> Result<()> {
+        match self {
+            Self::Binance(c) => c.subscribe_liquidations(symbols).await,
+            Self::Bybit(c) => c.subscribe_liquidations(symbols).await,
+        }
+    }
+
+    /// Upgrade symbols to focused streams: trades, plus depth where available
+    ///
+    /// Binance top-of-book already arrives via bookTicker, so only trades are
+    /// added there; Bybit also gets the level-1 order book.
+    pub async fn subscribe_focus(&mut self, symbols: &[Symbol]) -> Result<()> {
+        match self {
+            Self::Binance(c) => c.subscribe_agg_trades(symbols).await,
+            Self::Bybit(c) => {
+                c.subscribe_public_trades(symbols).await?;
+                c.subscribe_orderbook(symbols).await
+            }
+        }
+    }
+
+    /// Drop focused streams for symbols (see [`Self::subscribe_focus`])
+    pub async fn unsubscribe_focus(&mut self, symbols: &[Symbol]) -> Result<()> {
+        match self {
+            Self::Binance(c) => c.unsubscribe(StreamType::Trade, symbols).await,
+            Self::Bybit(c) => {
+                c.unsubscribe(StreamType::Trade, symbols).await?;
+                c.unsubscribe(StreamType::OrderBook, symbols).await
+            }
+        }
+    }
+
+    /// Drop every stream for symbols (delisted instruments)
+    pub async fn unsubscribe_all(&mut self, symbols: &[Symbol]) -> Result<()> {
+        const ALL: [StreamType; 5] = [
+            StreamType::Ticker,
+            StreamType::MarkPrice,
+            StreamType::Liquidation,
+            StreamType::Trade,
+            StreamType::OrderBook,
+        ];
+        for stream_type in ALL {
+            match self {
+                Self::Binance(c) => c.unsubscribe(stream_type, symbols).await?,
+                Self::Bybit(c) => c.unsubscribe(stream_type, symbols).await?,
+            }
+        }
+        Ok(())
+    }
+
     pub async fn next_message(&mut self) -> Result<Option<ExchangeMessage>> {
         match self {
             Self::Binance(c) => c.next_message().await,
             Self::Bybit(c) => c.next_message().await,
         }
     }
+
+    pub fn buffer_stats(&self) -> Option<BufferStats> {
+        match self {
+            Self::Binance(c) => c.buffer_stats(),
+            Self::Bybit(c) => c.buffer_stats(),
+        }
+    }
 }
 
 /// Exchange identifier
+///
+/// Per-exchange state is kept in `[T; Exchange::COUNT]` arrays indexed by
+/// [`ExchangeId`], so adding a venue means a new variant here (plus its
+/// entry in `ALL`, `id` and `name`) rather than new fields and matches.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub enum Exchange {
     Binance,
     Bybit,
 }
 
+/// Compact exchange id: dense index in 0..Exchange::COUNT
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ExchangeId(u8);
+
+impl ExchangeId {
+    /// Index into per-exchange arrays
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+
+    /// Exchange registered under this id
+    #[inline(always)]
+    pub const fn exchange(self) -> Exchange {
+        Exchange::ALL[self.0 as usize]
+    }
+}
+
 impl Exchange {
+    /// Number of supported exchanges (for per-exchange arrays)
+    pub const COUNT: usize = 2;
+
+    /// Every exchange, in id order
+    pub const ALL: [Exchange; Self::COUNT] = [Exchange::Binance, Exchange::Bybit];
+
+    /// Compact id (position in [`Self::ALL`])
+    #[inline(always)]
+    pub const fn id(&self) -> ExchangeId {
+        match self {
+            Exchange::Binance => ExchangeId(0),
+            Exchange::Bybit => ExchangeId(1),
+        }
+    }
+
+    /// Dense index in 0..COUNT
+    #[inline(always)]
+    pub const fn index(&self) -> usize {
+        self.id().index()
+    }
+
     pub fn name(&self) -> &'static str {
         match self {
             Exchange::Binance => "binance",
             Exchange::Bybit => "bybit",
         }
     }
+
+    /// Exchange by name (case-insensitive), e.g. "bybit"
+    pub fn from_name(name: &str) -> Option<Self> {
+        Exchange::ALL.into_iter().find(|e| e.name().eq_ignore_ascii_case(name))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_exchange_ids_are_dense() {
+        for (index, exchange) in Exchange::ALL.into_iter().enumerate() {
+            assert_eq!(exchange.index(), index);
+            assert_eq!(exchange.id().exchange(), exchange);
+            assert_eq!(Exchange::from_name(&exchange.name().to_uppercase()), Some(exchange));
+        }
+        assert_eq!(Exchange::from_name("okx"), None);
+    }
 }
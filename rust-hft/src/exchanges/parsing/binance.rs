@@ -1,10 +1,11 @@
 //! Binance message parser
 //!
-//! Parses Binance WebSocket messages into TradeData/TickerData.
+//! Parses Binance WebSocket messages into TradeData/TickerData/MarkPriceData/LiquidationData.
 //! Zero-copy, zero-allocation hot path.
 
-use super::{find_field, parse_bool, parse_timestamp_ms, ParseResult};
-use crate::core::{FixedPoint8, Side, Symbol, TickerData, TradeData};
+use super::{contains, find_field, parse_bool, parse_timestamp_ms, parse_u64, ParseResult};
+use crate::core::{FixedPoint8, LiquidationData, MarkPriceData, Side, Symbol, TickerData, TradeData};
+use crate::exchanges::Exchange;
 
 /// Binance message parser
 pub struct BinanceParser;
@@ -27,6 +28,7 @@ impl BinanceParser {
     /// }
     #[inline]
     pub fn parse_trade(data: &[u8]) -> Option<ParseResult<TradeData>> {
+        crate::hot_path_guard!("BinanceParser::parse_trade");
         // Quick check for aggTrade event type
         if !Self::is_agg_trade(data) {
             return None;
@@ -34,7 +36,7 @@ impl BinanceParser {
 
         // Parse symbol
         let symbol_bytes = find_field(data, b"s")?;
-        let symbol = Symbol::from_bytes(symbol_bytes)?;
+        let symbol = Symbol::from_exchange_bytes(symbol_bytes, Exchange::Binance)?;
 
         // Parse price
         let price_bytes = find_field(data, b"p")?;
@@ -61,7 +63,11 @@ impl BinanceParser {
             Side::Buy
         };
 
-        let trade = TradeData::new(symbol, price, quantity, timestamp, side, is_buyer_maker);
+        // Aggregate trade id (monotonic per symbol)
+        let trade_id = find_field(data, b"a").and_then(parse_u64).unwrap_or(0);
+
+        let trade = TradeData::new(symbol, price, quantity, timestamp, side, is_buyer_maker)
+            .with_trade_id(trade_id);
 
         Some(ParseResult {
             data: trade,
@@ -71,19 +77,23 @@ impl BinanceParser {
 
     /// Parse bookTicker message into TickerData
     ///
-    /// Binance bookTicker format:
+    /// Binance futures bookTicker format:
     /// {
     ///   "e": "bookTicker",
     ///   "u": 400900217,
+    ///   "E": 1568014460893,
+    ///   "T": 1568014460891,
     ///   "s": "BTCUSDT",
     ///   "b": "25000.50",
     ///   "B": "1.5",
     ///   "a": "25001.00",
     ///   "A": "2.0"
     /// }
-    /// Note: No timestamp in bookTicker, use current time
+    /// Timestamp is the event time ("E", else transaction time "T"); spot
+    /// bookTicker has neither and yields 0 (the client fills in receive time).
     #[inline]
     pub fn parse_ticker(data: &[u8]) -> Option<ParseResult<TickerData>> {
+        crate::hot_path_guard!("BinanceParser::parse_ticker");
         // Quick check for bookTicker event type
         if !Self::is_book_ticker(data) {
             return None;
@@ -91,7 +101,7 @@ impl BinanceParser {
 
         // Parse symbol
         let symbol_bytes = find_field(data, b"s")?;
-        let symbol = Symbol::from_bytes(symbol_bytes)?;
+        let symbol = Symbol::from_exchange_bytes(symbol_bytes, Exchange::Binance)?;
 
         // Parse bid price and quantity
         let bid_price_bytes = find_field(data, b"b")?;
@@ -107,10 +117,17 @@ impl BinanceParser {
         let ask_qty_bytes = find_field(data, b"A")?;
         let ask_qty = FixedPoint8::parse_bytes(ask_qty_bytes)?;
 
-        // bookTicker doesn't have timestamp, use 0 (caller should fill with current time)
-        let timestamp = 0;
+        // Event time, else transaction time (milliseconds → nanoseconds); 0 on spot
+        let timestamp = find_field(data, b"E")
+            .or_else(|| find_field(data, b"T"))
+            .and_then(parse_timestamp_ms)
+            .unwrap_or(0);
+
+        // Order book update id (monotonic per symbol)
+        let update_id = find_field(data, b"u").and_then(parse_u64).unwrap_or(0);
 
-        let ticker = TickerData::new(symbol, bid_price, bid_qty, ask_price, ask_qty, timestamp);
+        let ticker = TickerData::new(symbol, bid_price, bid_qty, ask_price, ask_qty, timestamp)
+            .with_update_id(update_id);
 
         Some(ParseResult {
             data: ticker,
@@ -118,6 +135,106 @@ impl BinanceParser {
         })
     }
 
+    /// Parse markPriceUpdate message into MarkPriceData
+    ///
+    /// Binance markPriceUpdate format:
+    /// {
+    ///   "e": "markPriceUpdate",
+    ///   "E": 1562305380000,
+    ///   "s": "BTCUSDT",
+    ///   "p": "11794.15000000",
+    ///   "i": "11784.62659091",
+    ///   "P": "11784.25641265",
+    ///   "r": "0.00038167",
+    ///   "T": 1562306400000
+    /// }
+    #[inline]
+    pub fn parse_mark_price(data: &[u8]) -> Option<ParseResult<MarkPriceData>> {
+        if !Self::is_mark_price(data) {
+            return None;
+        }
+
+        let symbol_bytes = find_field(data, b"s")?;
+        let symbol = Symbol::from_exchange_bytes(symbol_bytes, Exchange::Binance)?;
+
+        let mark_price = FixedPoint8::parse_bytes(find_field(data, b"p")?)?;
+        let index_price = FixedPoint8::parse_bytes(find_field(data, b"i")?)?;
+
+        // Funding fields are empty on delivery contracts, treat as zero
+        let funding_rate = find_field(data, b"r")
+            .and_then(FixedPoint8::parse_bytes)
+            .unwrap_or(FixedPoint8::ZERO);
+        let next_funding_time = find_field(data, b"T")
+            .and_then(parse_timestamp_ms)
+            .unwrap_or(0);
+
+        // Event time (milliseconds → nanoseconds)
+        let timestamp = parse_timestamp_ms(find_field(data, b"E")?)?;
+
+        let mark = MarkPriceData::new(
+            symbol,
+            mark_price,
+            index_price,
+            funding_rate,
+            next_funding_time,
+            timestamp,
+        );
+
+        Some(ParseResult {
+            data: mark,
+            consumed: data.len(),
+        })
+    }
+
+    /// Parse forceOrder message into LiquidationData
+    ///
+    /// Binance forceOrder format:
+    /// {
+    ///   "e": "forceOrder",
+    ///   "E": 1568014460893,
+    ///   "o": {
+    ///     "s": "BTCUSDT",
+    ///     "S": "SELL",
+    ///     "o": "LIMIT",
+    ///     "f": "IOC",
+    ///     "q": "0.014",
+    ///     "p": "9910",
+    ///     "ap": "9910",
+    ///     "X": "FILLED",
+    ///     "l": "0.014",
+    ///     "z": "0.014",
+    ///     "T": 1568014460893
+    ///   }
+    /// }
+    #[inline]
+    pub fn parse_liquidation(data: &[u8]) -> Option<ParseResult<LiquidationData>> {
+        if !Self::is_force_order(data) {
+            return None;
+        }
+
+        let symbol = Symbol::from_exchange_bytes(find_field(data, b"s")?, Exchange::Binance)?;
+        let side = Side::from_bytes(find_field(data, b"S")?)?;
+
+        // Prefer average fill price, fall back to order price
+        let price = find_field(data, b"ap")
+            .and_then(FixedPoint8::parse_bytes)
+            .filter(|p| p.is_positive())
+            .or_else(|| find_field(data, b"p").and_then(FixedPoint8::parse_bytes))?;
+
+        // Filled quantity, fall back to order quantity
+        let quantity = find_field(data, b"z")
+            .and_then(FixedPoint8::parse_bytes)
+            .filter(|q| q.is_positive())
+            .or_else(|| find_field(data, b"q").and_then(FixedPoint8::parse_bytes))?;
+
+        let timestamp = parse_timestamp_ms(find_field(data, b"T")?)?;
+
+        Some(ParseResult {
+            data: LiquidationData::new(symbol, price, quantity, timestamp, side),
+            consumed: data.len(),
+        })
+    }
+
     /// Check if message is aggTrade (fast path)
     #[inline(always)]
     fn is_agg_trade(data: &[u8]) -> bool {
@@ -132,6 +249,26 @@ impl BinanceParser {
         data.windows(10).any(|w| w == b"bookTicker")
     }
 
+    /// Check if message is markPriceUpdate (fast path)
+    #[inline(always)]
+    fn is_mark_price(data: &[u8]) -> bool {
+        data.windows(15).any(|w| w == b"markPriceUpdate")
+    }
+
+    /// Check if message is forceOrder (fast path)
+    #[inline(always)]
+    fn is_force_order(data: &[u8]) -> bool {
+        data.windows(10).any(|w| w == b"forceOrder")
+    }
+
+    /// Error text of a request error response
+    ///
+    /// Binance format: {"error":{"code":2,"msg":"Invalid request: ..."},"id":1}
+    #[inline]
+    pub fn error_message(data: &[u8]) -> Option<&[u8]> {
+        find_field(data, b"msg")
+    }
+
     /// Detect message type without full parsing
     #[inline]
     pub fn detect_message_type(data: &[u8]) -> BinanceMessageType {
@@ -139,8 +276,14 @@ impl BinanceParser {
             BinanceMessageType::AggTrade
         } else if Self::is_book_ticker(data) {
             BinanceMessageType::BookTicker
-        } else if data.windows(12).any(|w| w == br#""result":null"#) {
+        } else if Self::is_mark_price(data) {
+            BinanceMessageType::MarkPrice
+        } else if Self::is_force_order(data) {
+            BinanceMessageType::ForceOrder
+        } else if contains(data, br#""result":null"#) {
             BinanceMessageType::SubscriptionResponse
+        } else if contains(data, br#""msg":"#) {
+            BinanceMessageType::Error
         } else {
             BinanceMessageType::Unknown
         }
@@ -152,16 +295,18 @@ impl BinanceParser {
 pub enum BinanceMessageType {
     AggTrade,
     BookTicker,
+    MarkPrice,
+    ForceOrder,
     SubscriptionResponse,
+    /// Request rejected (bad subscribe/unsubscribe)
+    Error,
     Unknown,
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     const AGG_TRADE_MSG: &[u8] = br#"{
         "e": "aggTrade",
@@ -186,6 +331,35 @@ mod tests {
         "A": "2.0"
     }"#;
 
+    const MARK_PRICE_MSG: &[u8] = br#"{
+        "e": "markPriceUpdate",
+        "E": 1562305380000,
+        "s": "BTCUSDT",
+        "p": "11794.15000000",
+        "i": "11784.62659091",
+        "P": "11784.25641265",
+        "r": "0.00038167",
+        "T": 1562306400000
+    }"#;
+
+    const FORCE_ORDER_MSG: &[u8] = br#"{
+        "e": "forceOrder",
+        "E": 1568014460893,
+        "o": {
+            "s": "BTCUSDT",
+            "S": "SELL",
+            "o": "LIMIT",
+            "f": "IOC",
+            "q": "0.014",
+            "p": "9910",
+            "ap": "9910.5",
+            "X": "FILLED",
+            "l": "0.014",
+            "z": "0.014",
+            "T": 1568014460893
+        }
+    }"#;
+
     #[test]
     fn test_detect_message_type() {
         assert_eq!(
@@ -196,6 +370,37 @@ mod tests {
             BinanceParser::detect_message_type(BOOK_TICKER_MSG),
             BinanceMessageType::BookTicker
         );
+        assert_eq!(
+            BinanceParser::detect_message_type(MARK_PRICE_MSG),
+            BinanceMessageType::MarkPrice
+        );
+        assert_eq!(
+            BinanceParser::detect_message_type(FORCE_ORDER_MSG),
+            BinanceMessageType::ForceOrder
+        );
+    }
+
+    #[test]
+    fn test_parse_force_order() {
+        init_test_registry();
+        let liq = BinanceParser::parse_liquidation(FORCE_ORDER_MSG).unwrap().data;
+        assert_eq!(liq.symbol.as_str(), "BTCUSDT");
+        assert_eq!(liq.side, Side::Sell);
+        assert_eq!(liq.price, FixedPoint8::from_raw(991_050_000_000));
+        assert_eq!(liq.quantity, FixedPoint8::from_raw(1_400_000));
+        assert_eq!(liq.timestamp, 1_568_014_460_893_000_000);
+    }
+
+    #[test]
+    fn test_parse_mark_price() {
+        init_test_registry();
+        let mark = BinanceParser::parse_mark_price(MARK_PRICE_MSG).unwrap().data;
+        assert_eq!(mark.symbol.as_str(), "BTCUSDT");
+        assert_eq!(mark.mark_price, FixedPoint8::from_raw(1_179_415_000_000));
+        assert_eq!(mark.index_price, FixedPoint8::from_raw(1_178_462_659_091));
+        assert_eq!(mark.funding_rate, FixedPoint8::from_raw(38_167));
+        assert_eq!(mark.next_funding_time, 1_562_306_400_000_000_000);
+        assert_eq!(mark.timestamp, 1_562_305_380_000_000_000);
     }
 
     #[test]
@@ -204,6 +409,7 @@ mod tests {
         let result = BinanceParser::parse_trade(AGG_TRADE_MSG).unwrap();
         let trade = result.data;
         assert_eq!(trade.symbol.as_str(), "BTCUSDT");
+        assert_eq!(trade.trade_id, 12345);
     }
 
     #[test]
@@ -212,6 +418,19 @@ mod tests {
         let result = BinanceParser::parse_ticker(BOOK_TICKER_MSG).unwrap();
         let ticker = result.data;
         assert_eq!(ticker.symbol.as_str(), "BTCUSDT");
+        assert_eq!(ticker.update_id, 400900217);
+        assert_eq!(ticker.timestamp, 0);
+    }
+
+    #[test]
+    fn test_parse_futures_book_ticker_event_time() {
+        init_test_registry();
+        let msg = br#"{"e":"bookTicker","u":1,"s":"BTCUSDT","b":"1.0","B":"1.0","a":"2.0","A":"1.0","T":1568014460891,"E":1568014460893}"#;
+        let ticker = BinanceParser::parse_ticker(msg).unwrap().data;
+        assert_eq!(ticker.timestamp, 1_568_014_460_893_000_000);
+
+        let no_event = br#"{"e":"bookTicker","u":1,"s":"BTCUSDT","b":"1.0","B":"1.0","a":"2.0","A":"1.0","T":1568014460891}"#;
+        assert_eq!(BinanceParser::parse_ticker(no_event).unwrap().data.timestamp, 1_568_014_460_891_000_000);
     }
 
     #[test]
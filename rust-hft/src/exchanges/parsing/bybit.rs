@@ -1,29 +1,52 @@
 //! Bybit V5 message parser
 //!
-//! Parses Bybit V5 WebSocket messages into TradeData/TickerData.
+//! Parses Bybit V5 WebSocket messages into TradeData/TickerData/LiquidationData.
 //! Zero-copy, zero-allocation hot path.
 
-use super::{find_field, parse_timestamp_ms, ParseResult};
-use crate::core::{FixedPoint8, Side, Symbol, TickerData, TradeData};
+use super::{contains, find_field, parse_timestamp_ms, parse_u64, ParseResult};
+use crate::core::{FixedPoint8, LiquidationData, Side, Symbol, TickerData, TradeData, TradingStatus};
+use crate::exchanges::Exchange;
 
 /// Bybit V5 message parser
 pub struct BybitParser;
 
-/// Partial ticker update from Bybit delta
-#[derive(Debug, Clone, Copy)]
+/// Ticker update from Bybit (full snapshot or partial delta)
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct BybitTickerUpdate {
     pub symbol: Symbol,
+    /// "type":"snapshot": replaces cached state instead of merging into it
+    pub snapshot: bool,
     pub bid_price: Option<FixedPoint8>,
     pub bid_qty: Option<FixedPoint8>,
     pub ask_price: Option<FixedPoint8>,
     pub ask_qty: Option<FixedPoint8>,
+    pub mark_price: Option<FixedPoint8>,
+    pub index_price: Option<FixedPoint8>,
+    pub funding_rate: Option<FixedPoint8>,
+    pub next_funding_time: Option<u64>,
+    /// Cross sequence ("cs"), monotonic per symbol
+    pub sequence: Option<u64>,
+    /// Pre-listing phase ("curPreListingPhase"; None for listed contracts)
+    pub status: Option<TradingStatus>,
     pub timestamp: u64,
 }
 
+impl BybitTickerUpdate {
+    /// Returns true if the delta carries any mark/index/funding field
+    #[inline(always)]
+    pub fn has_mark_fields(&self) -> bool {
+        self.mark_price.is_some()
+            || self.index_price.is_some()
+            || self.funding_rate.is_some()
+            || self.next_funding_time.is_some()
+    }
+}
+
 impl BybitParser {
     /// Parse public trade message into TradeData
     #[inline]
     pub fn parse_public_trade(data: &[u8]) -> Option<ParseResult<TradeData>> {
+        crate::hot_path_guard!("BybitParser::parse_public_trade");
         if !Self::is_public_trade(data) {
             return None;
         }
@@ -33,13 +56,14 @@ impl BybitParser {
     /// Parse ticker message into TickerData (snapshot)
     #[inline]
     pub fn parse_ticker(data: &[u8]) -> Option<ParseResult<TickerData>> {
+        crate::hot_path_guard!("BybitParser::parse_ticker");
         if !Self::is_ticker(data) {
             return None;
         }
 
         let symbol_bytes =
             find_field(data, b"symbol").or_else(|| Self::extract_symbol_from_topic(data))?;
-        let symbol = Symbol::from_bytes(symbol_bytes)?;
+        let symbol = Symbol::from_exchange_bytes(symbol_bytes, Exchange::Bybit)?;
 
         let bid_price = FixedPoint8::parse_bytes(find_field(data, b"bid1Price")?)?;
         let bid_qty = FixedPoint8::parse_bytes(find_field(data, b"bid1Size")?)?;
@@ -58,21 +82,28 @@ impl BybitParser {
         })
     }
 
-    /// Parse ticker message into BybitTickerUpdate (delta)
+    /// Parse ticker message into BybitTickerUpdate (snapshot or delta)
     #[inline]
     pub fn parse_ticker_update(data: &[u8]) -> Option<ParseResult<BybitTickerUpdate>> {
+        crate::hot_path_guard!("BybitParser::parse_ticker_update");
         if !Self::is_ticker(data) {
             return None;
         }
 
         let symbol_bytes =
             find_field(data, b"symbol").or_else(|| Self::extract_symbol_from_topic(data))?;
-        let symbol = Symbol::from_bytes(symbol_bytes)?;
+        let symbol = Symbol::from_exchange_bytes(symbol_bytes, Exchange::Bybit)?;
 
         let bid_price = find_field(data, b"bid1Price").and_then(FixedPoint8::parse_bytes);
         let bid_qty = find_field(data, b"bid1Size").and_then(FixedPoint8::parse_bytes);
         let ask_price = find_field(data, b"ask1Price").and_then(FixedPoint8::parse_bytes);
         let ask_qty = find_field(data, b"ask1Size").and_then(FixedPoint8::parse_bytes);
+        let mark_price = find_field(data, b"markPrice").and_then(FixedPoint8::parse_bytes);
+        let index_price = find_field(data, b"indexPrice").and_then(FixedPoint8::parse_bytes);
+        let funding_rate = find_field(data, b"fundingRate").and_then(FixedPoint8::parse_bytes);
+        let next_funding_time = find_field(data, b"nextFundingTime").and_then(parse_timestamp_ms);
+        let sequence = find_field(data, b"cs").and_then(parse_u64);
+        let status = find_field(data, b"curPreListingPhase").and_then(TradingStatus::from_bybit_phase);
 
         let timestamp = find_field(data, b"ts")
             .and_then(parse_timestamp_ms)
@@ -81,16 +112,70 @@ impl BybitParser {
         Some(ParseResult {
             data: BybitTickerUpdate {
                 symbol,
+                snapshot: Self::is_snapshot(data),
                 bid_price,
                 bid_qty,
                 ask_price,
                 ask_qty,
+                mark_price,
+                index_price,
+                funding_rate,
+                next_funding_time,
+                sequence,
+                status,
                 timestamp,
             },
             consumed: data.len(),
         })
     }
 
+    /// Parse allLiquidation message into one LiquidationData per entry of the data array
+    ///
+    /// Bybit batches every liquidation of a push interval into one frame.
+    /// It reports the side of the liquidated position ("Buy" = long
+    /// liquidated); it is inverted here to the liquidation order side so it
+    /// matches Binance forceOrder semantics. Fails if any entry is malformed.
+    #[inline]
+    pub fn parse_liquidations(data: &[u8]) -> Option<ParseResult<Vec<LiquidationData>>> {
+        if !Self::is_liquidation(data) {
+            return None;
+        }
+
+        let data_start = data.windows(7).position(|w| w == b"\"data\":")?;
+        let data_section = &data[data_start + 7..];
+        let array_end = data_section.iter().position(|&b| b == b']')?;
+        // Entries are flat objects: each ends at its first closing brace
+        let mut rest = &data_section[..array_end];
+        let mut entries = Vec::with_capacity(1);
+        while let Some(obj_start) = rest.iter().position(|&b| b == b'{') {
+            let obj_len = rest[obj_start..].iter().position(|&b| b == b'}')? + 1;
+            entries.push(Self::parse_liquidation_entry(&rest[obj_start..obj_start + obj_len])?);
+            rest = &rest[obj_start + obj_len..];
+        }
+        if entries.is_empty() {
+            return None;
+        }
+
+        Some(ParseResult {
+            data: entries,
+            consumed: data.len(),
+        })
+    }
+
+    /// Parse one allLiquidation entry object
+    #[inline]
+    fn parse_liquidation_entry(obj: &[u8]) -> Option<LiquidationData> {
+        let symbol = Symbol::from_exchange_bytes(find_field(obj, b"s")?, Exchange::Bybit)?;
+        let price = FixedPoint8::parse_bytes(find_field(obj, b"p")?)?;
+        let qty = FixedPoint8::parse_bytes(find_field(obj, b"v")?)?;
+        let timestamp = parse_timestamp_ms(find_field(obj, b"T")?)?;
+        let side = match Side::from_bytes(find_field(obj, b"S")?)? {
+            Side::Buy => Side::Sell,
+            Side::Sell => Side::Buy,
+        };
+        Some(LiquidationData::new(symbol, price, qty, timestamp, side))
+    }
+
     /// Parse first trade from data array
     #[inline]
     fn parse_first_trade_in_array(data: &[u8]) -> Option<ParseResult<TradeData>> {
@@ -103,14 +188,18 @@ impl BybitParser {
         let obj_start = data_section.iter().position(|&b| b == b'{')?;
         let obj_section = &data_section[obj_start..];
 
-        let symbol = Symbol::from_bytes(find_field(obj_section, b"s")?)?;
+        let symbol = Symbol::from_exchange_bytes(find_field(obj_section, b"s")?, Exchange::Bybit)?;
         let price = FixedPoint8::parse_bytes(find_field(obj_section, b"p")?)?;
         let qty = FixedPoint8::parse_bytes(find_field(obj_section, b"v")?)?;
         let timestamp = parse_timestamp_ms(find_field(obj_section, b"T")?)?;
         let side = Side::from_bytes(find_field(obj_section, b"S")?).unwrap_or(Side::Buy);
         let is_buyer_maker = matches!(side, Side::Sell);
 
-        let trade = TradeData::new(symbol, price, qty, timestamp, side, is_buyer_maker);
+        // Cross sequence, monotonic per symbol
+        let seq = find_field(obj_section, b"seq").and_then(parse_u64).unwrap_or(0);
+
+        let trade = TradeData::new(symbol, price, qty, timestamp, side, is_buyer_maker)
+            .with_trade_id(seq);
 
         Some(ParseResult {
             data: trade,
@@ -135,23 +224,66 @@ impl BybitParser {
         data.windows(11).any(|w| w == b"publicTrade")
     }
 
+    /// Check if message is allLiquidation
+    #[inline(always)]
+    fn is_liquidation(data: &[u8]) -> bool {
+        data.windows(14).any(|w| w == b"allLiquidation")
+    }
+
     /// Check if message is tickers
     #[inline(always)]
     fn is_ticker(data: &[u8]) -> bool {
         data.windows(7).any(|w| w == b"tickers")
     }
 
+    /// Check if message is a full snapshot ("type" precedes the data object)
+    #[inline(always)]
+    fn is_snapshot(data: &[u8]) -> bool {
+        find_field(data, b"type") == Some(b"snapshot".as_slice())
+    }
+
+    /// Error text of a rejected request ("ret_msg")
+    #[inline]
+    pub fn error_message(data: &[u8]) -> Option<&[u8]> {
+        find_field(data, b"ret_msg")
+    }
+
     /// Detect message type
+    ///
+    /// Control frames (acks, pongs, errors) carry no topic and are classified
+    /// first, so an error echoing a topic name is not taken for market data.
+    /// Market data frames lead with "topic", which keeps that check cheap.
     #[inline]
     pub fn detect_message_type(data: &[u8]) -> BybitMessageType {
-        if Self::is_public_trade(data) {
+        if !contains(data, br#""topic""#) {
+            Self::detect_control(data)
+        } else if Self::is_public_trade(data) {
             BybitMessageType::PublicTrade
+        } else if Self::is_liquidation(data) {
+            BybitMessageType::Liquidation
         } else if Self::is_ticker(data) {
-            BybitMessageType::Ticker
-        } else if data.windows(10).any(|w| w == b"\"op\":\"pong\"") {
+            if Self::is_snapshot(data) {
+                BybitMessageType::TickerSnapshot
+            } else {
+                BybitMessageType::Ticker
+            }
+        } else {
+            BybitMessageType::Unknown
+        }
+    }
+
+    /// Classify a frame without topic
+    ///
+    /// Public pongs answer with {"success":true,"ret_msg":"pong","op":"ping"},
+    /// private ones with {"op":"pong"}.
+    #[inline]
+    fn detect_control(data: &[u8]) -> BybitMessageType {
+        if contains(data, br#""ret_msg":"pong""#) || contains(data, br#""op":"pong""#) {
             BybitMessageType::Pong
-        } else if data.windows(21).any(|w| w == b"\"success\":true") {
+        } else if contains(data, br#""success":true"#) {
             BybitMessageType::SubscriptionResponse
+        } else if contains(data, br#""success":false"#) {
+            BybitMessageType::Error
         } else {
             BybitMessageType::Unknown
         }
@@ -162,18 +294,22 @@ impl BybitParser {
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum BybitMessageType {
     PublicTrade,
+    Liquidation,
+    /// Ticker delta (merge into cached state)
     Ticker,
+    /// Ticker snapshot (first frame after subscribe/reconnect)
+    TickerSnapshot,
     Pong,
     SubscriptionResponse,
+    /// Request rejected ("success":false)
+    Error,
     Unknown,
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     #[test]
     fn test_detect_public_trade() {
@@ -195,6 +331,44 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_parse_liquidation() {
+        init_test_registry();
+        let data = br#"{"topic":"allLiquidation.BTCUSDT","type":"snapshot","ts":1739502303204,"data":[{"T":1739502302929,"s":"BTCUSDT","S":"Buy","v":"0.25","p":"96000.5"}]}"#;
+        assert_eq!(
+            BybitParser::detect_message_type(data),
+            BybitMessageType::Liquidation
+        );
+
+        let liqs = BybitParser::parse_liquidations(data).unwrap().data;
+        assert_eq!(liqs.len(), 1);
+        let liq = liqs[0];
+        assert_eq!(liq.symbol.as_str(), "BTCUSDT");
+        // Long position liquidated → sell order
+        assert_eq!(liq.side, Side::Sell);
+        assert_eq!(liq.price, FixedPoint8::from_raw(9_600_050_000_000));
+        assert_eq!(liq.quantity, FixedPoint8::from_raw(25_000_000));
+        assert_eq!(liq.timestamp, 1_739_502_302_929_000_000);
+
+        // One bad entry rejects the frame, an empty batch is not a liquidation
+        let bad = br#"{"topic":"allLiquidation.BTCUSDT","data":[{"T":1,"s":"BTCUSDT","S":"Buy","v":"0.25","p":"96000.5"},{"T":2,"s":"BTCUSDT","S":"Buy","v":"x","p":"96000.5"}]}"#;
+        assert!(BybitParser::parse_liquidations(bad).is_none());
+        let empty = br#"{"topic":"allLiquidation.BTCUSDT","data":[]}"#;
+        assert!(BybitParser::parse_liquidations(empty).is_none());
+    }
+
+    #[test]
+    fn test_detect_ticker_snapshot() {
+        init_test_registry();
+        let snapshot = br#"{"topic":"tickers.BTCUSDT","type":"snapshot","data":{"symbol":"BTCUSDT","bid1Price":"50000.00"},"ts":1}"#;
+        assert_eq!(BybitParser::detect_message_type(snapshot), BybitMessageType::TickerSnapshot);
+        assert!(BybitParser::parse_ticker_update(snapshot).unwrap().data.snapshot);
+
+        let delta = br#"{"topic":"tickers.BTCUSDT","type":"delta","data":{"symbol":"BTCUSDT","bid1Price":"50000.00"},"ts":2}"#;
+        assert_eq!(BybitParser::detect_message_type(delta), BybitMessageType::Ticker);
+        assert!(!BybitParser::parse_ticker_update(delta).unwrap().data.snapshot);
+    }
+
     #[test]
     fn test_detect_unknown() {
         let data = b"{\"unknown\":\"message\"}";
@@ -219,10 +393,19 @@ mod tests {
         assert!(parsed.data.timestamp > 0);
     }
 
+    #[test]
+    fn test_parse_ticker_resolves_alias() {
+        init_test_registry();
+        let data = br#"{"topic":"tickers.1000PEPEUSDT","data":{"symbol":"1000PEPEUSDT","bid1Price":"0.0123","bid1Size":"100","ask1Price":"0.0124","ask1Size":"200","ts":"1234567890123"}}"#;
+
+        let parsed = BybitParser::parse_ticker(data).unwrap();
+        assert_eq!(parsed.data.symbol.as_str(), "PEPEUSDT");
+    }
+
     #[test]
     fn test_parse_ticker_update_delta() {
         init_test_registry();
-        let data = br#"{"topic":"tickers.BTCUSDT","data":{"symbol":"BTCUSDT","bid1Price":"50000.50","ts":"1234567890123"}}"#;
+        let data = br#"{"topic":"tickers.BTCUSDT","data":{"symbol":"BTCUSDT","bid1Price":"50000.50","ts":"1234567890123"},"cs":24987956059}"#;
 
         let result = BybitParser::parse_ticker_update(data);
         assert!(result.is_some());
@@ -231,6 +414,32 @@ mod tests {
         assert_eq!(parsed.data.symbol.as_str(), "BTCUSDT");
         assert!(parsed.data.bid_price.is_some());
         assert!(parsed.data.ask_price.is_none());
+        assert!(!parsed.data.has_mark_fields());
+        assert_eq!(parsed.data.sequence, Some(24987956059));
+        assert_eq!(parsed.data.status, None);
+    }
+
+    #[test]
+    fn test_parse_ticker_update_pre_listing_phase() {
+        init_test_registry();
+        let data = br#"{"topic":"tickers.BTCUSDT","type":"delta","data":{"symbol":"BTCUSDT","curPreListingPhase":"CallAuction","bid1Price":"50000.50"},"ts":1673272861686}"#;
+
+        let update = BybitParser::parse_ticker_update(data).unwrap().data;
+        assert_eq!(update.status, Some(TradingStatus::Auction));
+    }
+
+    #[test]
+    fn test_parse_ticker_update_mark_fields() {
+        init_test_registry();
+        let data = br#"{"topic":"tickers.BTCUSDT","type":"delta","data":{"symbol":"BTCUSDT","markPrice":"50010.25","indexPrice":"50005.10","fundingRate":"0.0001","nextFundingTime":"1673280000000"},"ts":1673272861686}"#;
+
+        let update = BybitParser::parse_ticker_update(data).unwrap().data;
+        assert!(update.has_mark_fields());
+        assert_eq!(update.mark_price, Some(FixedPoint8::from_raw(5_001_025_000_000)));
+        assert_eq!(update.index_price, Some(FixedPoint8::from_raw(5_000_510_000_000)));
+        assert_eq!(update.funding_rate, Some(FixedPoint8::from_raw(10_000)));
+        assert_eq!(update.next_funding_time, Some(1_673_280_000_000_000_000));
+        assert!(update.bid_price.is_none());
     }
 
     #[test]
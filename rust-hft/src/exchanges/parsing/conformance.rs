@@ -0,0 +1,235 @@
+//! Exchange message spec conformance
+//!
+//! Captured frames in `tests/fixtures/<venue>/` are run through message
+//! type detection and the matching parser, the same dispatch the clients
+//! use, and compared field by field with the expected result. A venue
+//! renaming a field or changing a value format breaks these tests instead
+//! of silently dropping messages in production.
+
+use super::{BinanceMessageType, BinanceParser, BybitMessageType, BybitParser, BybitTickerUpdate};
+use crate::core::{FixedPoint8, LiquidationData, MarkPriceData, Side, Symbol, TickerData, TradeData};
+use crate::test_utils::init_test_registry;
+
+macro_rules! fixture {
+    ($venue:literal, $name:literal) => {
+        ($name, include_bytes!(concat!(env!("CARGO_MANIFEST_DIR"), "/tests/fixtures/", $venue, "/", $name, ".json")).as_slice())
+    };
+}
+
+/// Parse outcome of one frame
+#[derive(Debug, PartialEq)]
+enum Parsed {
+    Trade(TradeData),
+    Ticker(TickerData),
+    TickerUpdate(BybitTickerUpdate),
+    MarkPrice(MarkPriceData),
+    Liquidation(LiquidationData),
+    /// Bybit batches liquidations per frame
+    Liquidations(Vec<LiquidationData>),
+    SubscriptionAck,
+    Pong,
+    Error(String),
+    /// Detected as market data but the parser rejected it
+    Malformed,
+    Unknown,
+}
+
+fn parse_binance(data: &[u8]) -> Parsed {
+    match BinanceParser::detect_message_type(data) {
+        BinanceMessageType::AggTrade => BinanceParser::parse_trade(data).map_or(Parsed::Malformed, |r| Parsed::Trade(r.data)),
+        BinanceMessageType::BookTicker => BinanceParser::parse_ticker(data).map_or(Parsed::Malformed, |r| Parsed::Ticker(r.data)),
+        BinanceMessageType::MarkPrice => {
+            BinanceParser::parse_mark_price(data).map_or(Parsed::Malformed, |r| Parsed::MarkPrice(r.data))
+        }
+        BinanceMessageType::ForceOrder => {
+            BinanceParser::parse_liquidation(data).map_or(Parsed::Malformed, |r| Parsed::Liquidation(r.data))
+        }
+        BinanceMessageType::SubscriptionResponse => Parsed::SubscriptionAck,
+        BinanceMessageType::Error => error(BinanceParser::error_message(data)),
+        BinanceMessageType::Unknown => Parsed::Unknown,
+    }
+}
+
+fn parse_bybit(data: &[u8]) -> Parsed {
+    match BybitParser::detect_message_type(data) {
+        BybitMessageType::PublicTrade => {
+            BybitParser::parse_public_trade(data).map_or(Parsed::Malformed, |r| Parsed::Trade(r.data))
+        }
+        BybitMessageType::Ticker | BybitMessageType::TickerSnapshot => {
+            BybitParser::parse_ticker_update(data).map_or(Parsed::Malformed, |r| Parsed::TickerUpdate(r.data))
+        }
+        BybitMessageType::Liquidation => {
+            BybitParser::parse_liquidations(data).map_or(Parsed::Malformed, |r| Parsed::Liquidations(r.data))
+        }
+        BybitMessageType::Pong => Parsed::Pong,
+        BybitMessageType::SubscriptionResponse => Parsed::SubscriptionAck,
+        BybitMessageType::Error => error(BybitParser::error_message(data)),
+        BybitMessageType::Unknown => Parsed::Unknown,
+    }
+}
+
+fn error(msg: Option<&[u8]>) -> Parsed {
+    Parsed::Error(String::from_utf8_lossy(msg.unwrap_or_default()).into_owned())
+}
+
+fn fp(value: &str) -> FixedPoint8 {
+    FixedPoint8::parse_bytes(value.as_bytes()).unwrap()
+}
+
+fn sym(name: &str) -> Symbol {
+    Symbol::from_bytes(name.as_bytes()).unwrap()
+}
+
+/// Milliseconds → nanoseconds
+const fn ms(ms: u64) -> u64 {
+    ms * 1_000_000
+}
+
+fn check(venue: &str, parse: fn(&[u8]) -> Parsed, cases: Vec<((&str, &[u8]), Parsed)>) {
+    for ((name, data), expected) in cases {
+        assert_eq!(parse(data), expected, "{}/{}.json", venue, name);
+    }
+}
+
+#[test]
+fn test_binance_fixtures() {
+    init_test_registry();
+    let btc = sym("BTCUSDT");
+    check(
+        "binance",
+        parse_binance,
+        vec![
+            (
+                fixture!("binance", "agg_trade"),
+                // Buyer is maker: the aggressor sold
+                Parsed::Trade(
+                    TradeData::new(btc, fp("37012.40"), fp("0.015"), ms(1_700_000_000_120), Side::Sell, true)
+                        .with_trade_id(2_163_958_722),
+                ),
+            ),
+            (
+                fixture!("binance", "book_ticker"),
+                // Event time wins over transaction time
+                Parsed::Ticker(
+                    TickerData::new(sym("ETHUSDT"), fp("2045.31"), fp("12.904"), fp("2045.32"), fp("30.117"), ms(1_700_000_000_101))
+                        .with_update_id(3_628_452_167_921),
+                ),
+            ),
+            (
+                fixture!("binance", "book_ticker_spot_combined"),
+                // Spot frames carry no time; the client stamps receive time
+                Parsed::Ticker(
+                    TickerData::new(sym("SOLUSDT"), fp("56.12"), fp("31.21"), fp("56.13"), fp("40.66"), 0)
+                        .with_update_id(40_090_021_799),
+                ),
+            ),
+            (
+                fixture!("binance", "mark_price"),
+                Parsed::MarkPrice(MarkPriceData::new(
+                    btc,
+                    fp("37015.1"),
+                    fp("37030.12765957"),
+                    fp("0.0001"),
+                    ms(1_700_006_400_000),
+                    ms(1_700_000_001_000),
+                )),
+            ),
+            (
+                fixture!("binance", "force_order"),
+                // Average fill price and filled quantity
+                Parsed::Liquidation(LiquidationData::new(btc, fp("36961.30"), fp("0.120"), ms(1_700_000_001_998), Side::Sell)),
+            ),
+            (fixture!("binance", "subscribe_ack"), Parsed::SubscriptionAck),
+            (
+                fixture!("binance", "error"),
+                Parsed::Error("Invalid request: request ID must be an unsigned integer".to_string()),
+            ),
+        ],
+    );
+}
+
+#[test]
+fn test_bybit_fixtures() {
+    init_test_registry();
+    let eth = sym("ETHUSDT");
+    let update = BybitTickerUpdate {
+        symbol: eth,
+        snapshot: false,
+        bid_price: None,
+        bid_qty: None,
+        ask_price: None,
+        ask_qty: None,
+        mark_price: None,
+        index_price: None,
+        funding_rate: None,
+        next_funding_time: None,
+        sequence: None,
+        status: None,
+        timestamp: 0,
+    };
+    check(
+        "bybit",
+        parse_bybit,
+        vec![
+            (
+                fixture!("bybit", "public_trade"),
+                Parsed::Trade(
+                    TradeData::new(sym("BTCUSDT"), fp("37013.50"), fp("0.004"), ms(1_700_000_000_147), Side::Buy, false)
+                        .with_trade_id(120_453_967_521),
+                ),
+            ),
+            (
+                fixture!("bybit", "ticker_snapshot"),
+                Parsed::TickerUpdate(BybitTickerUpdate {
+                    snapshot: true,
+                    bid_price: Some(fp("2045.34")),
+                    bid_qty: Some(fp("84.17")),
+                    ask_price: Some(fp("2045.35")),
+                    ask_qty: Some(fp("10.02")),
+                    mark_price: Some(fp("2045.28")),
+                    index_price: Some(fp("2046.02")),
+                    funding_rate: Some(fp("0.0001")),
+                    next_funding_time: Some(ms(1_700_006_400_000)),
+                    sequence: Some(171_540_987_652),
+                    timestamp: ms(1_700_000_000_230),
+                    ..update
+                }),
+            ),
+            (
+                fixture!("bybit", "ticker_delta"),
+                // Only changed fields are present
+                Parsed::TickerUpdate(BybitTickerUpdate {
+                    bid_price: Some(fp("2045.36")),
+                    bid_qty: Some(fp("3.10")),
+                    ask_price: Some(fp("2045.37")),
+                    ask_qty: Some(fp("12.55")),
+                    sequence: Some(171_540_987_660),
+                    timestamp: ms(1_700_000_000_310),
+                    ..update
+                }),
+            ),
+            (
+                fixture!("bybit", "liquidation"),
+                // Short position liquidated: the liquidation order buys
+                Parsed::Liquidations(vec![LiquidationData::new(sym("SOLUSDT"), fp("56.340"), fp("35.2"), ms(1_700_000_003_015), Side::Buy)]),
+            ),
+            (
+                fixture!("bybit", "liquidation_batch"),
+                // Every entry of the push interval, in frame order
+                Parsed::Liquidations(vec![
+                    LiquidationData::new(sym("SOLUSDT"), fp("56.340"), fp("35.2"), ms(1_700_000_003_015), Side::Buy),
+                    LiquidationData::new(sym("SOLUSDT"), fp("56.120"), fp("4.7"), ms(1_700_000_003_044), Side::Sell),
+                    LiquidationData::new(sym("SOLUSDT"), fp("56.355"), fp("120.0"), ms(1_700_000_003_089), Side::Buy),
+                ]),
+            ),
+            (fixture!("bybit", "subscribe_ack"), Parsed::SubscriptionAck),
+            (
+                fixture!("bybit", "subscribe_error"),
+                // Echoed topic must not be mistaken for ticker data
+                Parsed::Error("Invalid symbol :[tickers.FOOUSDT]".to_string()),
+            ),
+            (fixture!("bybit", "pong"), Parsed::Pong),
+            (fixture!("bybit", "pong_private"), Parsed::Pong),
+        ],
+    );
+}
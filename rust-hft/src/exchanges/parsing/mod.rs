@@ -2,12 +2,21 @@
 //!
 //! Hot path parsing without heap allocations.
 //! Target: <500ns per message parse time.
+//!
+//! Parse results are pinned against captured exchange messages in
+//! `tests/fixtures` (see `conformance`), so format drift fails CI.
+//! Feeds can run a cheap structural check first (see `structure`).
 
 pub mod binance;
 pub mod bybit;
+pub mod structure;
+
+#[cfg(test)]
+mod conformance;
 
 pub use binance::{BinanceMessageType, BinanceParser};
 pub use bybit::{BybitMessageType, BybitParser, BybitTickerUpdate};
+pub use structure::{validate_structure, FrameDefect};
 
 /// Parse result containing data and bytes consumed
 #[derive(Debug, Clone, Copy)]
@@ -72,6 +81,12 @@ pub fn find_field<'a>(data: &'a [u8], field: &[u8]) -> Option<&'a [u8]> {
     None
 }
 
+/// Check if `pattern` occurs anywhere in `data`
+#[inline(always)]
+pub fn contains(data: &[u8], pattern: &[u8]) -> bool {
+    !pattern.is_empty() && data.windows(pattern.len()).any(|w| w == pattern)
+}
+
 /// Find nth occurrence of a field in array/object
 #[inline]
 pub fn find_field_nth<'a>(data: &'a [u8], field: &[u8], n: usize) -> Option<&'a [u8]> {
@@ -79,23 +94,16 @@ pub fn find_field_nth<'a>(data: &'a [u8], field: &[u8], n: usize) -> Option<&'a
     let mut count = 0;
 
     loop {
-        match find_field(remaining, field) {
-            Some(value) => {
-                if count == n {
-                    return Some(value);
-                }
-                count += 1;
-                // Move past this field
-                if let Some(pos) = remaining.windows(field.len() + 2).position(|w| {
-                    w[0] == b'"' && &w[1..w.len() - 1] == field && w[w.len() - 1] == b'"'
-                }) {
-                    remaining = &remaining[pos + field.len() + 2..];
-                } else {
-                    return None;
-                }
-            }
-            None => return None,
+        let value = find_field(remaining, field)?;
+        if count == n {
+            return Some(value);
         }
+        count += 1;
+        // Move past this field
+        let pos = remaining.windows(field.len() + 2).position(|w| {
+            w[0] == b'"' && &w[1..w.len() - 1] == field && w[w.len() - 1] == b'"'
+        })?;
+        remaining = &remaining[pos + field.len() + 2..];
     }
 }
 
@@ -114,7 +122,7 @@ pub fn parse_u64(bytes: &[u8]) -> Option<u64> {
 
     let mut result: u64 = 0;
     for &b in bytes {
-        if b < b'0' || b > b'9' {
+        if !b.is_ascii_digit() {
             return None;
         }
         result = result.checked_mul(10)?;
@@ -151,6 +159,14 @@ mod tests {
         assert_eq!(find_field(data, b"T"), Some(b"1672304484973".as_slice()));
     }
 
+    #[test]
+    fn test_contains() {
+        let data = br#"{"result":null,"id":1}"#;
+        assert!(contains(data, br#""result":null"#));
+        assert!(!contains(data, br#""error":"#));
+        assert!(!contains(data, b""));
+    }
+
     #[test]
     fn test_parse_u64() {
         assert_eq!(parse_u64(b"123"), Some(123));
@@ -0,0 +1,144 @@
+//! Structural frame validation
+//!
+//! The field extractors scan for keys without validating the document, and
+//! what they return feeds symbol-indexed arrays downstream. A truncated or
+//! corrupted frame can still contain a plausible `"s":"..."` and slip
+//! through. When enabled ([`crate::infrastructure::config::FeedTuning`]),
+//! feeds run [`validate_structure`] first: one pass over the bytes, no
+//! allocation, checking length bounds, a top-level object, balanced and
+//! correctly nested braces and brackets outside strings, terminated strings
+//! and nesting depth. It is not a JSON validator: numbers and literals are
+//! left to the field parsers.
+
+/// Shortest frame accepted ("{}")
+pub const MIN_FRAME_BYTES: usize = 2;
+
+/// Deepest nesting accepted (venue frames nest 3 levels at most)
+pub const MAX_DEPTH: u32 = 16;
+
+/// Why a frame was rejected
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FrameDefect {
+    TooShort,
+    TooLong,
+    /// Top level is not an object
+    NotObject,
+    /// Closing brace/bracket without (or not matching) its opener, or
+    /// unclosed ones at the end
+    Unbalanced,
+    TooDeep,
+    /// String still open at the end of the frame
+    UnterminatedString,
+    /// Raw control character inside a string
+    ControlCharacter,
+    /// Bytes after the top-level object closed
+    TrailingData,
+}
+
+impl FrameDefect {
+    /// Quarantine kind (see [`crate::infrastructure::unparsed::ParseFailure`])
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::TooShort => "malformed/tooShort",
+            Self::TooLong => "malformed/tooLong",
+            Self::NotObject => "malformed/notObject",
+            Self::Unbalanced => "malformed/unbalanced",
+            Self::TooDeep => "malformed/tooDeep",
+            Self::UnterminatedString => "malformed/unterminatedString",
+            Self::ControlCharacter => "malformed/controlCharacter",
+            Self::TrailingData => "malformed/trailingData",
+        }
+    }
+}
+
+/// Check that `data` is structurally a JSON object of at most `max_len` bytes
+#[inline]
+pub fn validate_structure(data: &[u8], max_len: usize) -> Result<(), FrameDefect> {
+    if data.len() < MIN_FRAME_BYTES {
+        return Err(FrameDefect::TooShort);
+    }
+    if data.len() > max_len {
+        return Err(FrameDefect::TooLong);
+    }
+    let data = data.trim_ascii();
+    if data.first() != Some(&b'{') {
+        return Err(FrameDefect::NotObject);
+    }
+
+    // One bit per open level: 1 = object, 0 = array
+    let mut stack: u32 = 0;
+    let mut depth: u32 = 0;
+    let mut in_string = false;
+    let mut escaped = false;
+    for (i, &b) in data.iter().enumerate() {
+        if in_string {
+            match b {
+                _ if escaped => escaped = false,
+                b'\\' => escaped = true,
+                b'"' => in_string = false,
+                0x00..=0x1f => return Err(FrameDefect::ControlCharacter),
+                _ => {}
+            }
+            continue;
+        }
+        match b {
+            b'"' => in_string = true,
+            b'{' | b'[' => {
+                if depth == MAX_DEPTH {
+                    return Err(FrameDefect::TooDeep);
+                }
+                stack = (stack << 1) | u32::from(b == b'{');
+                depth += 1;
+            }
+            b'}' | b']' => {
+                if depth == 0 || (stack & 1 == 1) != (b == b'}') {
+                    return Err(FrameDefect::Unbalanced);
+                }
+                stack >>= 1;
+                depth -= 1;
+                if depth == 0 && i + 1 != data.len() {
+                    return Err(FrameDefect::TrailingData);
+                }
+            }
+            _ => {}
+        }
+    }
+    if in_string {
+        return Err(FrameDefect::UnterminatedString);
+    }
+    if depth != 0 {
+        return Err(FrameDefect::Unbalanced);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_validate_structure() {
+        let max = 1024;
+        let ticker = br#"{"e":"bookTicker","s":"BTCUSDT","b":"25000.1","B":"1","a":"25000.2","A":"2"}"#;
+        assert_eq!(validate_structure(ticker, max), Ok(()));
+        // Braces and escapes inside strings do not count
+        assert_eq!(validate_structure(br#"{"topic":"a}]{","data":[{"s":"x\"}"}]}"#, max), Ok(()));
+        assert_eq!(validate_structure(b" {}\n", max), Ok(()));
+
+        let cases: [(&[u8], FrameDefect); 9] = [
+            (b"{", FrameDefect::TooShort),
+            (&ticker[..], FrameDefect::TooLong),
+            (br#"[{"s":"BTCUSDT"}]"#, FrameDefect::NotObject),
+            (br#"{"data":[{"s":"BTCUSDT"}}"#, FrameDefect::Unbalanced),
+            (br#"{"e":"bookTicker","s":"BTCUSDT""#, FrameDefect::Unbalanced),
+            (br#"{"e":"bookTicker","s":"BTCUS"#, FrameDefect::UnterminatedString),
+            (b"{\"s\":\"BTC\nUSDT\"}", FrameDefect::ControlCharacter),
+            (br#"{"s":"BTCUSDT"}{"s":"ETHUSDT"}"#, FrameDefect::TrailingData),
+            (b"{\"a\":[[[[[[[[[[[[[[[[]]]]]]]]]]]]]]]]}", FrameDefect::TooDeep),
+        ];
+        for (frame, defect) in cases {
+            let max = if defect == FrameDefect::TooLong { 16 } else { max };
+            assert_eq!(validate_structure(frame, max), Err(defect), "{}", String::from_utf8_lossy(frame));
+        }
+    }
+}
@@ -3,7 +3,7 @@
 //! Zero-cost abstraction for unified exchange interface.
 //! No dynamic dispatch in hot path - use generics for monomorphization.
 
-use crate::core::{Symbol, TickerData, TradeData};
+use crate::core::{LiquidationData, MarkPriceData, StatusNotice, Symbol, TickerData, TradeData};
 use crate::exchanges::Exchange;
 use crate::Result;
 
@@ -15,12 +15,33 @@ pub enum ExchangeMessage {
     Trade(Exchange, TradeData),
     /// Ticker data from specific exchange
     Ticker(Exchange, TickerData),
+    /// Mark/index price and funding from specific exchange
+    MarkPrice(Exchange, MarkPriceData),
+    /// Forced liquidation from specific exchange
+    Liquidation(Exchange, LiquidationData),
+    /// Trading status change of a symbol (halt, auction, resumed)
+    Status(StatusNotice),
     /// Connection heartbeat
     Heartbeat,
     /// Error message (cold path, allocated)
     Error(ExchangeError),
 }
 
+impl ExchangeMessage {
+    /// Exchange event time (nanoseconds since epoch; None if absent or unknown)
+    #[inline]
+    pub fn timestamp(&self) -> Option<u64> {
+        let ts = match self {
+            Self::Trade(_, trade) => trade.timestamp,
+            Self::Ticker(_, ticker) => ticker.timestamp,
+            Self::MarkPrice(_, mark) => mark.timestamp,
+            Self::Liquidation(_, liquidation) => liquidation.timestamp,
+            Self::Status(_) | Self::Heartbeat | Self::Error(_) => 0,
+        };
+        (ts != 0).then_some(ts)
+    }
+}
+
 /// Exchange-specific error information
 #[derive(Debug, Clone, PartialEq)]
 pub struct ExchangeError {
@@ -65,6 +86,12 @@ pub trait WebSocketExchange: Send + Sync {
     /// Subscribe to ticker stream for given symbols
     async fn subscribe_tickers(&mut self, symbols: &[Symbol]) -> Result<()>;
     
+    /// Subscribe to mark/index price stream for given symbols
+    async fn subscribe_mark_prices(&mut self, symbols: &[Symbol]) -> Result<()>;
+    
+    /// Subscribe to liquidation stream for given symbols
+    async fn subscribe_liquidations(&mut self, symbols: &[Symbol]) -> Result<()>;
+    
     /// Receive next message (hot path)
     /// Returns `Ok(None)` if connection closed gracefully
     async fn next_message(&mut self) -> Result<Option<ExchangeMessage>>;
@@ -102,11 +129,9 @@ impl<T: WebSocketExchange> AnyExchange for T {
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     #[test]
     fn test_exchange_message_variants() {
@@ -0,0 +1,132 @@
+//! Private account events (Cold Path)
+//!
+//! Venue-neutral order, execution, position and balance updates decoded from
+//! authenticated account streams, so the engine handles every venue's
+//! private stream through one interface. Stream tasks forward them, with the
+//! stream's health, as [`UserStreamReport`]s.
+
+use crate::core::{FixedPoint8, Position, Side, Symbol};
+use crate::exchanges::Exchange;
+
+/// Order lifecycle state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum OrderState {
+    /// Resting or not yet triggered
+    New,
+    PartiallyFilled,
+    Filled,
+    /// Cancelled by the user or the venue (any unfilled remainder)
+    Cancelled,
+    Rejected,
+    /// Expired by time in force
+    Expired,
+}
+
+impl OrderState {
+    /// No further updates follow
+    #[inline]
+    pub fn is_final(&self) -> bool {
+        !matches!(self, Self::New | Self::PartiallyFilled)
+    }
+}
+
+/// Order status change
+#[derive(Debug, Clone, PartialEq)]
+pub struct OrderUpdate {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub order_id: String,
+    /// Client order id ("" when none was set)
+    pub client_order_id: String,
+    pub state: OrderState,
+    /// Limit price (zero for market orders)
+    pub price: FixedPoint8,
+    pub quantity: FixedPoint8,
+    pub filled_quantity: FixedPoint8,
+    /// Average fill price (zero before the first fill)
+    pub average_price: FixedPoint8,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+/// Single trade execution against one of our orders
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExecutionUpdate {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    pub side: Side,
+    pub order_id: String,
+    pub execution_id: String,
+    pub price: FixedPoint8,
+    pub quantity: FixedPoint8,
+    /// Fee paid in the settlement asset (negative = rebate)
+    pub fee: FixedPoint8,
+    pub is_maker: bool,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+/// Position after a change (flat positions carry no side)
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PositionUpdate {
+    pub exchange: Exchange,
+    pub symbol: Symbol,
+    /// None when flat
+    pub side: Option<Side>,
+    pub size: FixedPoint8,
+    pub entry_price: FixedPoint8,
+    pub liquidation_price: FixedPoint8,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+impl PositionUpdate {
+    /// Core position (None when flat)
+    pub fn to_position(&self) -> Option<Position> {
+        let side = self.side.filter(|_| !self.size.is_zero())?;
+        Some(Position::new(self.symbol, self.exchange, side, self.size, self.entry_price, self.liquidation_price))
+    }
+}
+
+/// Balance of one asset after a change
+#[derive(Debug, Clone, PartialEq)]
+pub struct BalanceUpdate {
+    pub exchange: Exchange,
+    pub asset: String,
+    pub wallet_balance: FixedPoint8,
+    /// Available for new positions or withdrawal
+    pub available: FixedPoint8,
+    /// Timestamp (nanoseconds since epoch)
+    pub timestamp: u64,
+}
+
+/// Event from a private account stream
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserEvent {
+    Order(OrderUpdate),
+    Execution(ExecutionUpdate),
+    Position(PositionUpdate),
+    Balance(BalanceUpdate),
+}
+
+impl UserEvent {
+    /// Venue the event came from
+    #[inline]
+    pub fn exchange(&self) -> Exchange {
+        match self {
+            Self::Order(order) => order.exchange,
+            Self::Execution(execution) => execution.exchange,
+            Self::Position(position) => position.exchange,
+            Self::Balance(balance) => balance.exchange,
+        }
+    }
+}
+
+/// Report from a venue's private stream task
+#[derive(Debug, Clone, PartialEq)]
+pub enum UserStreamReport {
+    /// Stream authenticated and subscribed (true) or lost (false)
+    Health { exchange: Exchange, healthy: bool },
+    Event(UserEvent),
+}
@@ -3,95 +3,112 @@
 //! Zero-allocation implementation of arbitrage spread calculation.
 //! Uses FixedPoint8 for precision and speed.
 
-use crate::core::{FixedPoint8, Symbol, TickerData};
+use crate::core::{Bps, FixedPoint8, Symbol, TickerData};
 use crate::exchanges::Exchange;
 
 /// Spread calculation result
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct SpreadEvent {
     pub symbol: Symbol,
-    /// Spread value (bps or percentage)
-    pub spread: FixedPoint8,
+    /// Relative spread (Bid_Short - Ask_Long) / Ask_Long
+    pub spread: Bps,
     /// Exchange to Buy on
     pub long_ex: Exchange,
     /// Exchange to Sell on
     pub short_ex: Exchange,
     /// Timestamp (max of both tickers)
     pub timestamp: u64,
+    /// Spread in price ticks of the coarser venue (None until tick sizes are known)
+    pub spread_ticks: Option<FixedPoint8>,
 }
 
 /// Zero-allocation spread calculator
 pub struct SpreadCalculator;
 
 impl SpreadCalculator {
-    /// Calculate spread between two tickers
+    /// Best spread across every venue with a quote
     ///
     /// Formula: (Bid_Short - Ask_Long) / Ask_Long
-    /// Returns the best spread opportunity (Long A/Short B or Long B/Short A)
+    /// Tries every ordered long/short pair of quoted venues (a small fixed
+    /// loop over ExchangeId) and returns the best; None with fewer than two
+    /// quotes. `tickers` is indexed by ExchangeId.
     #[inline]
     pub fn calculate(
         symbol: Symbol,
-        binance: &TickerData,
-        bybit: &TickerData,
+        tickers: &[Option<TickerData>; Exchange::COUNT],
     ) -> Option<SpreadEvent> {
-        // Validate symbols match
-        // In hot path we assume caller checked this, but debug assert helps
-        debug_assert_eq!(binance.symbol, symbol);
-        debug_assert_eq!(bybit.symbol, symbol);
-        debug_assert_eq!(binance.symbol, bybit.symbol);
-
-        // 1. Check Long Binance (Buy) / Short Bybit (Sell)
-        // Profit = (Bybit Bid - Binance Ask) / Binance Ask
-        // We want to buy low (Ask) and sell high (Bid)
-        let spread_long_binance = if binance.ask_price.is_positive() {
-            bybit
-                .bid_price
-                .checked_sub(binance.ask_price)
-                .and_then(|diff| diff.safe_div(binance.ask_price))
-                .unwrap_or(FixedPoint8::ZERO)
-        } else {
-            FixedPoint8::ZERO
-        };
-
-        // 2. Check Long Bybit (Buy) / Short Binance (Sell)
-        // Profit = (Binance Bid - Bybit Ask) / Bybit Ask
-        let spread_long_bybit = if bybit.ask_price.is_positive() {
-            binance
-                .bid_price
-                .checked_sub(bybit.ask_price)
-                .and_then(|diff| diff.safe_div(bybit.ask_price))
-                .unwrap_or(FixedPoint8::ZERO)
-        } else {
-            FixedPoint8::ZERO
-        };
-
-        // Select better spread
-        if spread_long_binance > spread_long_bybit {
-            Some(SpreadEvent {
-                symbol,
-                spread: spread_long_binance,
-                long_ex: Exchange::Binance,
-                short_ex: Exchange::Bybit,
-                timestamp: std::cmp::max(binance.timestamp, bybit.timestamp),
-            })
-        } else {
-            Some(SpreadEvent {
-                symbol,
-                spread: spread_long_bybit,
-                long_ex: Exchange::Bybit,
-                short_ex: Exchange::Binance,
-                timestamp: std::cmp::max(binance.timestamp, bybit.timestamp),
-            })
+        crate::hot_path_guard!("SpreadCalculator::calculate");
+        let mut best: Option<SpreadEvent> = None;
+        for long_ex in Exchange::ALL {
+            let Some(long) = &tickers[long_ex.index()] else {
+                continue;
+            };
+            // In hot path we assume caller checked this, but debug assert helps
+            debug_assert_eq!(long.symbol, symbol);
+            for short_ex in Exchange::ALL {
+                let Some(short) = tickers[short_ex.index()].as_ref().filter(|_| short_ex != long_ex) else {
+                    continue;
+                };
+                // Buy low (Ask) on the long venue, sell high (Bid) on the short one
+                let spread = Self::pair_spread(long, short);
+                // Later pairs win ties (stable choice for equal books)
+                if best.is_some_and(|b| spread < b.spread) {
+                    continue;
+                }
+                best = Some(SpreadEvent {
+                    symbol,
+                    spread,
+                    long_ex,
+                    short_ex,
+                    timestamp: std::cmp::max(long.timestamp, short.timestamp),
+                    spread_ticks: None,
+                });
+            }
         }
+        best
+    }
+
+    /// (Bid_Short - Ask_Long) / Ask_Long (ZERO without a positive ask)
+    #[inline(always)]
+    fn pair_spread(long: &TickerData, short: &TickerData) -> Bps {
+        if !long.ask_price.is_positive() {
+            return Bps::ZERO;
+        }
+        let spread = short
+            .bid_price
+            .checked_sub(long.ask_price)
+            .and_then(|diff| diff.safe_div(long.ask_price))
+            .unwrap_or(FixedPoint8::ZERO);
+        Bps::from_fraction(spread)
+    }
+
+    /// Spread of `event` in ticks: (Bid_Short - Ask_Long) / coarser tick size
+    ///
+    /// On low-priced assets one tick is a large fraction of price, so a
+    /// percentage spread of 1-2 ticks is quote noise rather than an
+    /// opportunity. `tickers` and `tick_sizes` are indexed by ExchangeId;
+    /// None if either leg's ticker or tick size is unknown.
+    #[inline]
+    pub fn spread_in_ticks(
+        event: &SpreadEvent,
+        tickers: &[Option<TickerData>; Exchange::COUNT],
+        tick_sizes: &[Option<FixedPoint8>; Exchange::COUNT],
+    ) -> Option<FixedPoint8> {
+        let (long, short) = (event.long_ex.index(), event.short_ex.index());
+        let tick = tick_sizes[long]?.max(tick_sizes[short]?);
+        if !tick.is_positive() {
+            return None;
+        }
+        let (long, short) = (tickers[long].as_ref()?, tickers[short].as_ref()?);
+        short.bid_price.checked_sub(long.ask_price)?.safe_div(tick)
     }
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::{registry::SymbolRegistry, FixedPoint8};
-
+    use crate::test_utils::init_test_registry;
+    use crate::core::FixedPoint8;
 
     fn make_ticker(bid: i64, ask: i64) -> TickerData {
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
@@ -102,6 +119,7 @@ mod tests {
             bid_qty: FixedPoint8::ONE,
             ask_qty: FixedPoint8::ONE,
             timestamp: 1000,
+            update_id: 0,
         }
     }
 
@@ -112,11 +130,11 @@ mod tests {
         let bybit = make_ticker(101, 102);
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
 
-        let event = SpreadCalculator::calculate(sym, &binance, &bybit).unwrap();
+        let event = SpreadCalculator::calculate(sym, &[Some(binance), Some(bybit)]).unwrap();
 
         assert_eq!(event.long_ex, Exchange::Binance);
         assert_eq!(event.short_ex, Exchange::Bybit);
-        assert_eq!(event.spread, FixedPoint8::from_raw(1_000_000));
+        assert_eq!(event.spread, Bps::from_bps(100));
     }
 
     #[test]
@@ -126,11 +144,11 @@ mod tests {
         let bybit = make_ticker(99, 100);
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
 
-        let event = SpreadCalculator::calculate(sym, &binance, &bybit).unwrap();
+        let event = SpreadCalculator::calculate(sym, &[Some(binance), Some(bybit)]).unwrap();
 
         assert_eq!(event.long_ex, Exchange::Bybit);
         assert_eq!(event.short_ex, Exchange::Binance);
-        assert_eq!(event.spread, FixedPoint8::from_raw(1_000_000));
+        assert_eq!(event.spread, Bps::from_bps(100));
     }
 
     #[test]
@@ -140,8 +158,29 @@ mod tests {
         let bybit = make_ticker(100, 101);
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
 
-        let event = SpreadCalculator::calculate(sym, &binance, &bybit).unwrap();
+        let event = SpreadCalculator::calculate(sym, &[Some(binance), Some(bybit)]).unwrap();
         assert!(event.spread.is_negative());
+
+        // One venue quoting: no pair
+        assert_eq!(SpreadCalculator::calculate(sym, &[Some(binance), None]), None);
+    }
+
+    #[test]
+    fn test_spread_in_ticks() {
+        init_test_registry();
+        let binance = make_ticker(99, 100);
+        let bybit = make_ticker(101, 102);
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let event = SpreadCalculator::calculate(sym, &[Some(binance), Some(bybit)]).unwrap();
+        assert_eq!(event.spread_ticks, None);
+
+        // 1.0 price difference, coarser tick 0.5 → 2 ticks
+        let ticks = [Some(FixedPoint8::from_raw(10_000_000)), Some(FixedPoint8::from_raw(50_000_000))];
+        let tickers = [Some(binance), Some(bybit)];
+        let spread_ticks = SpreadCalculator::spread_in_ticks(&event, &tickers, &ticks);
+        assert_eq!(spread_ticks, Some(FixedPoint8::from_raw(2 * FixedPoint8::SCALE)));
+
+        assert_eq!(SpreadCalculator::spread_in_ticks(&event, &tickers, &[ticks[0], None]), None);
     }
 }
 
@@ -149,4 +188,4 @@ mod tests {
 // ✓ No heap allocations
 // ✓ Stack-only returns (Option<SpreadEvent>)
 // ✓ FixedPoint8 arithmetic (no floats)
-// ✓ Branchless potential (if/else is predictable)
+// ✓ Fixed-size pair loop over ExchangeId (unrolled for two venues)
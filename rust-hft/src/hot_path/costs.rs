@@ -0,0 +1,411 @@
+//! Break-even cost model (Warm Path)
+//!
+//! Estimates the spread an opportunity must exceed to net positive:
+//! - Taker fees on both legs, entry and exit
+//! - Slippage from top-of-book depth for a reference notional
+//! - Funding differential over the expected holding time
+//!
+//! A passive variant quotes one leg as maker: that leg pays the maker fee
+//! (negative = rebate) and no slippage, which lowers the break-even and
+//! moves the limit price the passive leg may quote at (see [`NetEdges`]).
+//!
+//! All values are fractions of price (0.001 = 0.1%), like SpreadEvent::spread.
+
+use crate::core::{Bps, FixedPoint8, MarkPriceData, TickerData};
+use crate::exchanges::Exchange;
+use crate::hot_path::SpreadEvent;
+use crate::infrastructure::config::CostConfig;
+
+/// Funding settlement interval on both venues (hours)
+const FUNDING_INTERVAL_HOURS: f64 = 8.0;
+
+/// Break-even components for one opportunity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CostBreakdown {
+    /// Round-trip fees, both legs (maker on a passive leg)
+    pub fees: FixedPoint8,
+    /// Expected entry slippage, both legs
+    pub slippage: FixedPoint8,
+    /// Net funding paid over holding time (negative = received)
+    pub funding: FixedPoint8,
+    /// Sum of all costs
+    pub break_even: FixedPoint8,
+}
+
+/// Spread net of costs, all-taker and with one passive leg
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct NetEdges {
+    /// Spread minus the all-taker break-even
+    pub taker: Bps,
+    /// Spread minus the break-even with the leg on `passive_ex` quoted as maker
+    pub passive: Bps,
+    /// Venue whose leg is better quoted passively (higher passive edge)
+    pub passive_ex: Exchange,
+    /// Least favourable passive limit price that still breaks even against
+    /// the hedge leg, kept behind the touch (None without a price)
+    pub passive_price: Option<FixedPoint8>,
+}
+
+/// Per-venue fee and holding assumptions (pre-converted to FixedPoint8)
+#[derive(Debug, Clone, Copy)]
+pub struct CostModel {
+    taker_fees: [FixedPoint8; Exchange::COUNT],
+    /// Maker fees (negative = rebate)
+    maker_fees: [FixedPoint8; Exchange::COUNT],
+    reference_notional: FixedPoint8,
+    funding_periods: FixedPoint8,
+}
+
+impl CostModel {
+    /// Build model from config (cold path)
+    pub fn new(config: &CostConfig) -> Self {
+        let fp = |v: f64| FixedPoint8::from_f64(v).unwrap_or(FixedPoint8::ZERO);
+        Self {
+            taker_fees: [fp(config.taker_fee_binance), fp(config.taker_fee_bybit)],
+            maker_fees: [fp(config.maker_fee_binance), fp(config.maker_fee_bybit)],
+            reference_notional: fp(config.reference_notional),
+            funding_periods: fp(config.holding_hours / FUNDING_INTERVAL_HOURS),
+        }
+    }
+
+    /// Taker fee for exchange
+    #[inline(always)]
+    pub fn taker_fee(&self, exchange: Exchange) -> FixedPoint8 {
+        self.taker_fees[exchange.index()]
+    }
+
+    /// Maker fee for exchange (negative = rebate)
+    #[inline(always)]
+    pub fn maker_fee(&self, exchange: Exchange) -> FixedPoint8 {
+        self.maker_fees[exchange.index()]
+    }
+
+    /// Order notional used for slippage and fixed-fee estimates
+    #[inline(always)]
+    pub fn reference_notional(&self) -> FixedPoint8 {
+        self.reference_notional
+    }
+
+    /// Replace taker fee for exchange (detected account fee tier)
+    pub fn set_taker_fee(&mut self, exchange: Exchange, fee: FixedPoint8) {
+        self.taker_fees[exchange.index()] = fee;
+    }
+
+    /// Replace maker fee for exchange (detected account fee tier)
+    pub fn set_maker_fee(&mut self, exchange: Exchange, fee: FixedPoint8) {
+        self.maker_fees[exchange.index()] = fee;
+    }
+
+    /// Break-even for opportunity given the book on both legs
+    ///
+    /// `buy` is the long venue ticker, `sell` the short venue ticker.
+    /// Marks supply funding rates; a missing mark counts as zero funding.
+    pub fn break_even(
+        &self,
+        event: &SpreadEvent,
+        buy: &TickerData,
+        sell: &TickerData,
+        long_mark: Option<&MarkPriceData>,
+        short_mark: Option<&MarkPriceData>,
+    ) -> CostBreakdown {
+        self.breakdown(event, buy, sell, long_mark, short_mark, None)
+    }
+
+    /// Break-even with the leg on `passive` quoted as maker, entry and exit
+    ///
+    /// The passive leg pays its maker fee and no slippage; the hedge leg
+    /// stays a taker.
+    pub fn break_even_passive(
+        &self,
+        event: &SpreadEvent,
+        buy: &TickerData,
+        sell: &TickerData,
+        long_mark: Option<&MarkPriceData>,
+        short_mark: Option<&MarkPriceData>,
+        passive: Exchange,
+    ) -> CostBreakdown {
+        self.breakdown(event, buy, sell, long_mark, short_mark, Some(passive))
+    }
+
+    /// Net edges of the opportunity, with the better passive leg and its price
+    ///
+    /// `ticks` are the venues' price tick sizes (None = unknown: the passive
+    /// leg joins the touch instead of improving it by a tick).
+    pub fn net_edges(
+        &self,
+        event: &SpreadEvent,
+        buy: &TickerData,
+        sell: &TickerData,
+        long_mark: Option<&MarkPriceData>,
+        short_mark: Option<&MarkPriceData>,
+        ticks: &[Option<FixedPoint8>; Exchange::COUNT],
+    ) -> NetEdges {
+        let edge = |be: CostBreakdown| event.spread.checked_sub(Bps::from_fraction(be.break_even)).unwrap_or(Bps::MIN);
+        let taker = edge(self.break_even(event, buy, sell, long_mark, short_mark));
+        let passive_long = self.break_even_passive(event, buy, sell, long_mark, short_mark, event.long_ex);
+        let passive_short = self.break_even_passive(event, buy, sell, long_mark, short_mark, event.short_ex);
+        let (passive_ex, breakdown) = if edge(passive_long) >= edge(passive_short) {
+            (event.long_ex, passive_long)
+        } else {
+            (event.short_ex, passive_short)
+        };
+        let passive_price = if passive_ex == event.long_ex {
+            Self::passive_buy_price(buy, sell.bid_price, breakdown.break_even, ticks[passive_ex.index()])
+        } else {
+            Self::passive_sell_price(sell, buy.ask_price, breakdown.break_even, ticks[passive_ex.index()])
+        };
+        NetEdges {
+            taker,
+            passive: edge(breakdown),
+            passive_ex,
+            passive_price,
+        }
+    }
+
+    /// Highest passive bid on `book` that breaks even selling at `hedge_bid`
+    ///
+    /// (hedge_bid - P) / P >= break_even, capped one tick below the ask (at
+    /// the bid without a tick) so the order rests, rounded down to the tick.
+    fn passive_buy_price(
+        book: &TickerData,
+        hedge_bid: FixedPoint8,
+        break_even: FixedPoint8,
+        tick: Option<FixedPoint8>,
+    ) -> Option<FixedPoint8> {
+        let limit = hedge_bid.safe_div(FixedPoint8::ONE.checked_add(break_even)?)?;
+        let touch = match tick {
+            Some(tick) => book.ask_price.checked_sub(tick)?,
+            None => book.bid_price,
+        };
+        let price = limit.min(touch);
+        let price = match tick.filter(|t| t.is_positive()) {
+            Some(tick) => FixedPoint8::from_raw(price.as_raw() - price.as_raw().rem_euclid(tick.as_raw())),
+            None => price,
+        };
+        price.is_positive().then_some(price)
+    }
+
+    /// Lowest passive ask on `book` that breaks even buying at `hedge_ask`
+    ///
+    /// (P - hedge_ask) / hedge_ask >= break_even, kept one tick above the
+    /// bid (at the ask without a tick), rounded up to the tick.
+    fn passive_sell_price(
+        book: &TickerData,
+        hedge_ask: FixedPoint8,
+        break_even: FixedPoint8,
+        tick: Option<FixedPoint8>,
+    ) -> Option<FixedPoint8> {
+        let limit = hedge_ask.safe_mul(FixedPoint8::ONE.checked_add(break_even)?)?;
+        let touch = match tick {
+            Some(tick) => book.bid_price.checked_add(tick)?,
+            None => book.ask_price,
+        };
+        let price = limit.max(touch);
+        let price = match tick.filter(|t| t.is_positive()) {
+            Some(tick) => {
+                let rem = price.as_raw().rem_euclid(tick.as_raw());
+                if rem == 0 { price } else { FixedPoint8::from_raw(price.as_raw() - rem + tick.as_raw()) }
+            }
+            None => price,
+        };
+        price.is_positive().then_some(price)
+    }
+
+    fn breakdown(
+        &self,
+        event: &SpreadEvent,
+        buy: &TickerData,
+        sell: &TickerData,
+        long_mark: Option<&MarkPriceData>,
+        short_mark: Option<&MarkPriceData>,
+        passive: Option<Exchange>,
+    ) -> CostBreakdown {
+        let fee = |exchange: Exchange| {
+            if passive == Some(exchange) { self.maker_fee(exchange) } else { self.taker_fee(exchange) }
+        };
+        let fees = fee(event.long_ex)
+            .checked_add(fee(event.short_ex))
+            .and_then(|f| f.checked_add(f))
+            .unwrap_or(FixedPoint8::MAX);
+
+        // A resting passive leg does not walk the book
+        let slip = |exchange: Exchange, price, qty, book| {
+            if passive == Some(exchange) { FixedPoint8::ZERO } else { self.leg_slippage(price, qty, book) }
+        };
+        let slippage = slip(event.long_ex, buy.ask_price, buy.ask_qty, buy)
+            .checked_add(slip(event.short_ex, sell.bid_price, sell.bid_qty, sell))
+            .unwrap_or(FixedPoint8::MAX);
+
+        // Long pays its rate, short receives its rate, per settlement
+        let rate = |m: Option<&MarkPriceData>| m.map(|m| m.funding_rate).unwrap_or(FixedPoint8::ZERO);
+        let funding = rate(long_mark)
+            .checked_sub(rate(short_mark))
+            .and_then(|net| net.safe_mul(self.funding_periods))
+            .unwrap_or(FixedPoint8::ZERO);
+
+        let break_even = fees
+            .checked_add(slippage)
+            .and_then(|c| c.checked_add(funding))
+            .unwrap_or(FixedPoint8::MAX);
+
+        CostBreakdown {
+            fees,
+            slippage,
+            funding,
+            break_even,
+        }
+    }
+
+    /// Slippage for filling the reference notional against one top-of-book level
+    ///
+    /// Only level 1 is tracked: the part of the order beyond level 1 is assumed
+    /// to fill one book width (ask - bid) worse.
+    fn leg_slippage(&self, price: FixedPoint8, level_qty: FixedPoint8, book: &TickerData) -> FixedPoint8 {
+        let Some(qty) = self.reference_notional.safe_div(price) else {
+            return FixedPoint8::ZERO;
+        };
+        let excess = match qty.checked_sub(level_qty) {
+            Some(e) if e.is_positive() => e,
+            _ => return FixedPoint8::ZERO,
+        };
+        book.ask_price
+            .checked_sub(book.bid_price)
+            .and_then(|width| width.safe_div(price))
+            .and_then(|width| excess.safe_div(qty).and_then(|frac| width.safe_mul(frac)))
+            .unwrap_or(FixedPoint8::ZERO)
+    }
+}
+
+impl Default for CostModel {
+    fn default() -> Self {
+        Self::new(&CostConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    fn model(holding_hours: f64) -> CostModel {
+        CostModel::new(&CostConfig {
+            taker_fee_binance: 0.0005,
+            taker_fee_bybit: 0.00055,
+            reference_notional: 1_000.0,
+            holding_hours,
+            ..Default::default()
+        })
+    }
+
+    fn ticker(symbol: Symbol, bid: f64, ask: f64, qty: f64) -> TickerData {
+        TickerData::new(symbol, fp(bid), fp(qty), fp(ask), fp(qty), 1000)
+    }
+
+    fn event(symbol: Symbol) -> SpreadEvent {
+        SpreadEvent {
+            symbol,
+            spread: Bps::from_bps(30),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            timestamp: 1000,
+            spread_ticks: None,
+        }
+    }
+
+    #[test]
+    fn test_fees_only_with_deep_book() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let buy = ticker(sym, 99.9, 100.0, 100.0);
+        let sell = ticker(sym, 100.3, 100.4, 100.0);
+
+        let costs = model(0.0).break_even(&event(sym), &buy, &sell, None, None);
+        // 2 * (0.05% + 0.055%)
+        assert_eq!(costs.fees, fp(0.0021));
+        assert_eq!(costs.slippage, FixedPoint8::ZERO);
+        assert_eq!(costs.funding, FixedPoint8::ZERO);
+        assert_eq!(costs.break_even, fp(0.0021));
+    }
+
+    #[test]
+    fn test_slippage_beyond_top_level() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        // Need 10 units at 100, only 5 on the ask → half the order pays the 0.1% width
+        let buy = ticker(sym, 99.9, 100.0, 5.0);
+        let sell = ticker(sym, 100.3, 100.4, 100.0);
+
+        let costs = model(0.0).break_even(&event(sym), &buy, &sell, None, None);
+        assert_eq!(costs.slippage, fp(0.0005));
+    }
+
+    #[test]
+    fn test_funding_differential() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let buy = ticker(sym, 99.9, 100.0, 100.0);
+        let sell = ticker(sym, 100.3, 100.4, 100.0);
+        let mark = |rate: f64| MarkPriceData::new(sym, fp(100.0), fp(100.0), fp(rate), 0, 1000);
+
+        // Long pays 0.01%, short receives 0.03% → net credit 0.02% per 8h, held 16h
+        let costs = model(16.0).break_even(
+            &event(sym),
+            &buy,
+            &sell,
+            Some(&mark(0.0001)),
+            Some(&mark(0.0003)),
+        );
+        assert_eq!(costs.funding, fp(-0.0004));
+        assert_eq!(costs.break_even, fp(0.0017));
+    }
+
+    #[test]
+    fn test_passive_leg_with_maker_rebate() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        // Thin Binance ask: the taker leg would slip 0.05%
+        let buy = ticker(sym, 99.9, 100.0, 5.0);
+        let sell = ticker(sym, 100.3, 100.4, 100.0);
+        let mut costs = model(0.0);
+        costs.set_maker_fee(Exchange::Binance, fp(-0.0001));
+
+        // Binance passive: 2 * (-0.01% + 0.055%), no slippage
+        let passive = costs.break_even_passive(&event(sym), &buy, &sell, None, None, Exchange::Binance);
+        assert_eq!(passive.fees, fp(0.0009));
+        assert_eq!(passive.slippage, FixedPoint8::ZERO);
+        assert_eq!(passive.break_even, fp(0.0009));
+
+        let ticks = [Some(fp(0.01)), Some(fp(0.01))];
+        let edges = costs.net_edges(&event(sym), &buy, &sell, None, None, &ticks);
+        assert_eq!(edges.taker, Bps::from_bps(4));
+        assert_eq!(edges.passive, Bps::from_bps(21));
+        assert_eq!(edges.passive_ex, Exchange::Binance);
+        // Break-even bid 100.3 / 1.0009 = 100.2098 sits above the book: one tick under the ask
+        assert_eq!(edges.passive_price, Some(fp(99.99)));
+    }
+
+    #[test]
+    fn test_passive_price_limited_by_break_even() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let buy = ticker(sym, 99.9, 100.0, 100.0);
+        let sell = ticker(sym, 100.3, 100.4, 100.0);
+        // Break-even 0.5%: the bid must sit at or below 100.3 / 1.005 = 99.80099...
+        let be = fp(0.005);
+        assert_eq!(CostModel::passive_buy_price(&buy, sell.bid_price, be, Some(fp(0.01))), Some(fp(99.80)));
+        // Without a tick the raw limit is quoted
+        assert_eq!(
+            CostModel::passive_buy_price(&buy, sell.bid_price, be, None),
+            fp(100.3).safe_div(fp(1.005))
+        );
+        // Selling on Bybit against the Binance ask: 100 * 1.005 = 100.5, above the bid + tick
+        assert_eq!(CostModel::passive_sell_price(&sell, buy.ask_price, be, Some(fp(0.04))), Some(fp(100.52)));
+        // Cheap break-even: join the ask without a tick
+        assert_eq!(CostModel::passive_sell_price(&sell, buy.ask_price, fp(0.001), None), Some(fp(100.4)));
+    }
+}
@@ -0,0 +1,214 @@
+//! Message deduplication across redundant connections
+//!
+//! When the same symbols are subscribed on two connections to one exchange,
+//! every update arrives twice. Exchange update ids (bookTicker `u`, Bybit
+//! `cs`, aggTrade `a`, publicTrade `seq`) are monotonic per symbol, so the
+//! highest id seen per (exchange, symbol) is enough to drop duplicates and
+//! late stale copies. Updates without an id (0) always pass.
+//!
+//! The first-arrival feed and time are kept alongside the id, so each
+//! duplicate also reports which connection won the race and by how much.
+//!
+//! HFT: Flat pre-allocated arrays indexed by Symbol ID, no hashing.
+
+use crate::core::{Symbol, MAX_SYMBOLS};
+use crate::exchanges::{Exchange, ExchangeMessage};
+use std::cmp::Ordering;
+use std::time::{Duration, Instant};
+
+/// Outcome of checking a message against the last-seen cache
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Verdict {
+    /// First copy of this update, process it
+    Fresh,
+    /// Exact copy of the latest update, `winner` feed delivered it `lag` earlier
+    Duplicate { winner: usize, lag: Duration },
+    /// Older than the latest update (late copy or out of order)
+    Stale,
+}
+
+impl Verdict {
+    /// Returns true if message should be processed
+    #[inline(always)]
+    pub fn is_fresh(&self) -> bool {
+        matches!(self, Verdict::Fresh)
+    }
+}
+
+/// Last-seen update for one (exchange, symbol)
+#[derive(Debug, Clone, Copy, Default)]
+struct Slot {
+    /// Highest id seen
+    id: u64,
+    /// Nanoseconds since deduplicator epoch when `id` first arrived
+    first_seen_ns: u64,
+    /// Feed that delivered `id` first
+    feed: u16,
+}
+
+/// Last-seen id per (exchange, symbol)
+struct LastSeen {
+    slots: Box<[Slot]>,
+}
+
+impl LastSeen {
+    fn new() -> Self {
+        Self {
+            slots: vec![Slot::default(); MAX_SYMBOLS * Exchange::COUNT].into_boxed_slice(),
+        }
+    }
+
+    #[inline(always)]
+    fn check(&mut self, exchange: Exchange, symbol: Symbol, id: u64, feed: usize, now_ns: u64) -> Verdict {
+        if id == 0 {
+            return Verdict::Fresh;
+        }
+        let idx = symbol.as_raw() as usize * Exchange::COUNT + exchange.index();
+        let Some(slot) = self.slots.get_mut(idx) else {
+            return Verdict::Fresh;
+        };
+
+        match id.cmp(&slot.id) {
+            Ordering::Greater => {
+                *slot = Slot { id, first_seen_ns: now_ns, feed: feed as u16 };
+                Verdict::Fresh
+            }
+            Ordering::Equal => Verdict::Duplicate {
+                winner: slot.feed as usize,
+                lag: Duration::from_nanos(now_ns.saturating_sub(slot.first_seen_ns)),
+            },
+            Ordering::Less => Verdict::Stale,
+        }
+    }
+}
+
+/// Drops duplicate ticker/trade updates by exchange sequence
+pub struct Deduplicator {
+    tickers: LastSeen,
+    trades: LastSeen,
+    duplicates: u64,
+    epoch: Instant,
+}
+
+impl Deduplicator {
+    /// Create deduplicator with pre-allocated caches
+    pub fn new() -> Self {
+        Self {
+            tickers: LastSeen::new(),
+            trades: LastSeen::new(),
+            duplicates: 0,
+            epoch: Instant::now(),
+        }
+    }
+
+    /// Check message from `feed` (connection index) arriving at `now`
+    #[inline]
+    pub fn check(&mut self, feed: usize, msg: &ExchangeMessage, now: Instant) -> Verdict {
+        let now_ns = now.saturating_duration_since(self.epoch).as_nanos() as u64;
+        let verdict = match msg {
+            ExchangeMessage::Ticker(ex, t) => self.tickers.check(*ex, t.symbol, t.update_id, feed, now_ns),
+            ExchangeMessage::Trade(ex, t) => self.trades.check(*ex, t.symbol, t.trade_id, feed, now_ns),
+            _ => Verdict::Fresh,
+        };
+        if !verdict.is_fresh() {
+            self.duplicates += 1;
+        }
+        verdict
+    }
+
+    /// Returns true if message should be processed (first copy seen)
+    #[inline]
+    pub fn is_new(&mut self, msg: &ExchangeMessage) -> bool {
+        self.check(0, msg, Instant::now()).is_fresh()
+    }
+
+    /// Total duplicates dropped
+    #[inline(always)]
+    pub fn duplicates(&self) -> u64 {
+        self.duplicates
+    }
+}
+
+impl Default for Deduplicator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Side, TickerData, TradeData};
+    use crate::test_utils::init_test_registry;
+
+    fn ticker(symbol: Symbol, update_id: u64) -> TickerData {
+        TickerData::new(symbol, FixedPoint8::ONE, FixedPoint8::ONE, FixedPoint8::ONE, FixedPoint8::ONE, 0)
+            .with_update_id(update_id)
+    }
+
+    #[test]
+    fn test_drops_duplicate_ticker() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut dedup = Deduplicator::new();
+
+        let msg = ExchangeMessage::Ticker(Exchange::Binance, ticker(sym, 100));
+        assert!(dedup.is_new(&msg));
+        assert!(!dedup.is_new(&msg));
+
+        // Late stale copy is dropped too
+        assert!(!dedup.is_new(&ExchangeMessage::Ticker(Exchange::Binance, ticker(sym, 99))));
+        assert!(dedup.is_new(&ExchangeMessage::Ticker(Exchange::Binance, ticker(sym, 101))));
+        assert_eq!(dedup.duplicates(), 2);
+    }
+
+    #[test]
+    fn test_keys_are_independent() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let mut dedup = Deduplicator::new();
+
+        assert!(dedup.is_new(&ExchangeMessage::Ticker(Exchange::Binance, ticker(btc, 5))));
+        assert!(dedup.is_new(&ExchangeMessage::Ticker(Exchange::Bybit, ticker(btc, 5))));
+        assert!(dedup.is_new(&ExchangeMessage::Ticker(Exchange::Binance, ticker(eth, 5))));
+
+        let trade = TradeData::new(btc, FixedPoint8::ONE, FixedPoint8::ONE, 0, Side::Buy, false)
+            .with_trade_id(5);
+        assert!(dedup.is_new(&ExchangeMessage::Trade(Exchange::Binance, trade)));
+        assert!(!dedup.is_new(&ExchangeMessage::Trade(Exchange::Binance, trade)));
+    }
+
+    #[test]
+    fn test_race_winner_and_lag() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut dedup = Deduplicator::new();
+        let t0 = Instant::now();
+        let msg = ExchangeMessage::Ticker(Exchange::Binance, ticker(sym, 7));
+
+        assert_eq!(dedup.check(1, &msg, t0), Verdict::Fresh);
+        assert_eq!(
+            dedup.check(0, &msg, t0 + Duration::from_micros(250)),
+            Verdict::Duplicate { winner: 1, lag: Duration::from_micros(250) }
+        );
+        let old = ExchangeMessage::Ticker(Exchange::Binance, ticker(sym, 6));
+        assert_eq!(dedup.check(0, &old, t0), Verdict::Stale);
+    }
+
+    #[test]
+    fn test_unknown_id_passes() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let mut dedup = Deduplicator::new();
+        let msg = ExchangeMessage::Ticker(Exchange::Bybit, ticker(sym, 0));
+        assert!(dedup.is_new(&msg));
+        assert!(dedup.is_new(&msg));
+        assert!(dedup.is_new(&ExchangeMessage::Heartbeat));
+    }
+}
+
+// HFT Hot Path Checklist verified:
+// ✓ No allocation after construction (flat boxed slices)
+// ✓ No HashMap (array lookup by Symbol ID)
+// ✓ No panics (bounds-checked get_mut)
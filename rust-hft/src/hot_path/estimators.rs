@@ -0,0 +1,147 @@
+//! Rolling price estimators (Warm Path)
+//!
+//! Per-exchange volatility and VWAP over the same 2-minute window as the
+//! spread history. Fed from the trade stream; on startup the window can be
+//! pre-seeded from REST klines so estimates are available immediately.
+
+use crate::core::FixedPoint8;
+use crate::infrastructure::TimeWindowBuffer;
+use crate::rest::Kline;
+use std::time::{Duration, Instant};
+
+/// Rolling volatility / VWAP estimator for one symbol on one exchange
+#[derive(Debug, Clone)]
+pub struct PriceEstimator {
+    /// Trade prices (kline high/low/close when seeded)
+    prices: TimeWindowBuffer,
+    /// Traded notional (quote currency)
+    notional: TimeWindowBuffer,
+    /// Traded base quantity
+    volume: TimeWindowBuffer,
+}
+
+impl PriceEstimator {
+    /// Create empty estimator over `window`
+    pub fn new(window: Duration) -> Self {
+        Self {
+            prices: TimeWindowBuffer::new(window),
+            notional: TimeWindowBuffer::new(window),
+            volume: TimeWindowBuffer::new(window),
+        }
+    }
+
+    /// Record a live trade
+    #[inline]
+    pub fn record_trade(&mut self, price: FixedPoint8, quantity: FixedPoint8) {
+        self.prices.push(price);
+        if let Some(notional) = price.safe_mul(quantity) {
+            self.notional.push(notional);
+            self.volume.push(quantity);
+        }
+    }
+
+    /// Seed from a historical kline closed at `at`
+    ///
+    /// Klines must be seeded oldest-first and before live trades.
+    pub fn seed(&mut self, kline: &Kline, at: Instant) {
+        self.prices.push_at(kline.high, at);
+        self.prices.push_at(kline.low, at);
+        self.prices.push_at(kline.close, at);
+        if kline.volume.is_positive() {
+            self.notional.push_at(kline.quote_volume, at);
+            self.volume.push_at(kline.volume, at);
+        }
+    }
+
+    /// Heap allocated by the rolling windows (bytes)
+    pub fn heap_bytes(&self) -> usize {
+        self.prices.heap_bytes() + self.notional.heap_bytes() + self.volume.heap_bytes()
+    }
+
+    /// Whether any observation is inside the window
+    #[inline]
+    pub fn is_warm(&self) -> bool {
+        !self.prices.is_empty()
+    }
+
+    /// Volume-weighted average price (None without volume)
+    pub fn vwap(&mut self) -> Option<FixedPoint8> {
+        let volume = self.volume.sum();
+        if !volume.is_positive() {
+            return None;
+        }
+        self.notional.sum().safe_div(volume)
+    }
+
+    /// Relative price range over the window: (max - min) / min
+    ///
+    /// Returns ZERO until at least one price is observed.
+    pub fn volatility(&mut self) -> FixedPoint8 {
+        let (min, max) = self.prices.min_max();
+        if !min.is_positive() {
+            return FixedPoint8::ZERO;
+        }
+        max.checked_sub(min)
+            .and_then(|range| range.safe_div(min))
+            .unwrap_or(FixedPoint8::ZERO)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn fp(s: &str) -> FixedPoint8 {
+        FixedPoint8::parse_bytes(s.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_empty_estimator() {
+        let mut est = PriceEstimator::new(Duration::from_secs(120));
+        assert!(!est.is_warm());
+        assert_eq!(est.vwap(), None);
+        assert_eq!(est.volatility(), FixedPoint8::ZERO);
+    }
+
+    #[test]
+    fn test_live_trades() {
+        let mut est = PriceEstimator::new(Duration::from_secs(120));
+        est.record_trade(fp("100"), fp("1"));
+        est.record_trade(fp("102"), fp("3"));
+
+        // (100*1 + 102*3) / 4 = 101.5
+        assert_eq!(est.vwap(), Some(fp("101.5")));
+        // (102 - 100) / 100 = 2%
+        assert_eq!(est.volatility(), fp("0.02"));
+    }
+
+    #[test]
+    fn test_seeded_from_klines() {
+        let mut est = PriceEstimator::new(Duration::from_secs(120));
+        let now = Instant::now();
+        let kline = |high: &str, low: &str, volume: &str, quote: &str| Kline {
+            open_time: 0,
+            open: fp(low),
+            high: fp(high),
+            low: fp(low),
+            close: fp(high),
+            volume: fp(volume),
+            quote_volume: fp(quote),
+        };
+
+        // Older than the window: ignored
+        est.seed(&kline("500", "400", "1", "450"), now - Duration::from_secs(300));
+        assert!(!est.is_warm());
+
+        est.seed(&kline("101", "99", "2", "200"), now - Duration::from_secs(60));
+        est.seed(&kline("104", "100", "2", "204"), now);
+        assert!(est.is_warm());
+        assert_eq!(est.vwap(), Some(fp("101")));
+        // (104 - 99) / 99
+        assert_eq!(est.volatility(), fp("5").safe_div(fp("99")).unwrap());
+
+        // Live trades extend the seeded window
+        est.record_trade(fp("110"), fp("0"));
+        assert_eq!(est.volatility(), fp("11").safe_div(fp("99")).unwrap());
+    }
+}
@@ -0,0 +1,305 @@
+//! Sampled market data invariants
+//!
+//! Checking every parsed message for corruption costs more than the hot path
+//! can spare, but a silently crossed book or a symbol id pointing at the
+//! wrong slot is worse. Each feed owns an [`InvariantChecker`] that looks at
+//! 1 in N quotes and trades ([`crate::infrastructure::config::FeedTuning`]):
+//! registered symbol, positive prices and quantities, bid <= ask, and
+//! timestamps that never go back per symbol (compared with the previous
+//! sampled message, so regressions are still caught at low rates).
+//! Violations are counted per exchange and invariant in the shared
+//! [`InvariantCounters`] (served by /api/debug); the feed decides whether to
+//! keep the offending frame.
+
+use crate::core::{FixedPoint8, Symbol, TickerData, TradeData};
+use crate::exchanges::Exchange;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+
+/// Violated invariant
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Invariant {
+    /// Symbol id not in the registry
+    InvalidSymbol,
+    /// Zero or negative price or quantity
+    NonPositive,
+    /// Bid above ask
+    CrossedBook,
+    /// Timestamp older than the previous sampled one for the symbol
+    TimestampRegression,
+}
+
+impl Invariant {
+    pub const COUNT: usize = 4;
+    pub const ALL: [Invariant; Self::COUNT] = [Self::InvalidSymbol, Self::NonPositive, Self::CrossedBook, Self::TimestampRegression];
+
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+
+    pub const fn name(self) -> &'static str {
+        match self {
+            Self::InvalidSymbol => "invalidSymbol",
+            Self::NonPositive => "nonPositive",
+            Self::CrossedBook => "crossedBook",
+            Self::TimestampRegression => "timestampRegression",
+        }
+    }
+
+    /// Quarantine kind (see [`crate::infrastructure::unparsed::ParseFailure`])
+    pub const fn kind(self) -> &'static str {
+        match self {
+            Self::InvalidSymbol => "invariant/invalidSymbol",
+            Self::NonPositive => "invariant/nonPositive",
+            Self::CrossedBook => "invariant/crossedBook",
+            Self::TimestampRegression => "invariant/timestampRegression",
+        }
+    }
+}
+
+/// Violations of one invariant on one exchange
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvariantCount {
+    pub exchange: &'static str,
+    pub invariant: &'static str,
+    pub count: u64,
+}
+
+/// Invariant checks for API export
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct InvariantSnapshot {
+    /// Messages checked, all exchanges
+    pub checked: u64,
+    /// Non-zero counts, most violations first
+    pub violations: Vec<InvariantCount>,
+}
+
+/// Checked messages and violations, shared by every feed
+#[derive(Debug, Default)]
+pub struct InvariantCounters {
+    checked: [AtomicU64; Exchange::COUNT],
+    violations: [[AtomicU64; Invariant::COUNT]; Exchange::COUNT],
+}
+
+impl InvariantCounters {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Violations of `invariant` on `exchange`
+    pub fn violations(&self, exchange: Exchange, invariant: Invariant) -> u64 {
+        self.violations[exchange.index()][invariant.index()].load(Ordering::Relaxed)
+    }
+
+    pub fn snapshot(&self) -> InvariantSnapshot {
+        let mut violations: Vec<InvariantCount> = Exchange::ALL
+            .into_iter()
+            .flat_map(|exchange| Invariant::ALL.into_iter().map(move |invariant| (exchange, invariant)))
+            .map(|(exchange, invariant)| InvariantCount {
+                exchange: exchange.name(),
+                invariant: invariant.name(),
+                count: self.violations(exchange, invariant),
+            })
+            .filter(|c| c.count > 0)
+            .collect();
+        violations.sort_by_key(|c| std::cmp::Reverse(c.count));
+        InvariantSnapshot {
+            checked: self.checked.iter().map(|c| c.load(Ordering::Relaxed)).sum(),
+            violations,
+        }
+    }
+}
+
+/// Per-feed sampler and checker
+#[derive(Debug)]
+pub struct InvariantChecker {
+    exchange: Exchange,
+    /// Check 1 in N messages (0 = off)
+    every: u64,
+    /// Messages left before the next check
+    countdown: u64,
+    /// Last sampled timestamp per (symbol, is trade)
+    last_seen: HashMap<(Symbol, bool), u64>,
+    counters: Arc<InvariantCounters>,
+}
+
+impl InvariantChecker {
+    pub fn new(exchange: Exchange, every: u64) -> Self {
+        Self {
+            exchange,
+            every,
+            countdown: every,
+            last_seen: HashMap::new(),
+            counters: Arc::new(InvariantCounters::new()),
+        }
+    }
+
+    /// Change the sampling rate (0 = off)
+    pub fn set_every(&mut self, every: u64) {
+        self.every = every;
+        self.countdown = every;
+    }
+
+    /// Count into shared counters
+    pub fn set_counters(&mut self, counters: Arc<InvariantCounters>) {
+        self.counters = counters;
+    }
+
+    /// Whether this message is sampled (call once per market data message)
+    #[inline(always)]
+    pub fn due(&mut self) -> bool {
+        if self.every == 0 {
+            return false;
+        }
+        self.countdown -= 1;
+        if self.countdown > 0 {
+            return false;
+        }
+        self.countdown = self.every;
+        true
+    }
+
+    /// Check a full ticker
+    pub fn check_ticker(&mut self, ticker: &TickerData) -> Option<Invariant> {
+        let sizes = [Some(ticker.bid_qty), Some(ticker.ask_qty)];
+        self.check_quote(ticker.symbol, Some(ticker.bid_price), Some(ticker.ask_price), sizes, ticker.timestamp)
+    }
+
+    /// Check a quote whose fields may be partial (delta updates)
+    ///
+    /// A zero timestamp is unknown and skips the ordering check.
+    pub fn check_quote(
+        &mut self,
+        symbol: Symbol,
+        bid: Option<FixedPoint8>,
+        ask: Option<FixedPoint8>,
+        sizes: [Option<FixedPoint8>; 2],
+        timestamp: u64,
+    ) -> Option<Invariant> {
+        let mut present = [bid, ask, sizes[0], sizes[1]].into_iter().flatten();
+        let violation = if !registered(symbol) {
+            Some(Invariant::InvalidSymbol)
+        } else if present.any(|v| !v.is_positive()) {
+            Some(Invariant::NonPositive)
+        } else if bid.zip(ask).is_some_and(|(bid, ask)| bid > ask) {
+            Some(Invariant::CrossedBook)
+        } else if regressed(&mut self.last_seen, (symbol, false), timestamp) {
+            Some(Invariant::TimestampRegression)
+        } else {
+            None
+        };
+        self.count(violation)
+    }
+
+    /// Check a trade
+    pub fn check_trade(&mut self, trade: &TradeData) -> Option<Invariant> {
+        let violation = if !registered(trade.symbol) {
+            Some(Invariant::InvalidSymbol)
+        } else if !trade.price.is_positive() || !trade.quantity.is_positive() {
+            Some(Invariant::NonPositive)
+        } else if regressed(&mut self.last_seen, (trade.symbol, true), trade.timestamp) {
+            Some(Invariant::TimestampRegression)
+        } else {
+            None
+        };
+        self.count(violation)
+    }
+
+    fn count(&self, violation: Option<Invariant>) -> Option<Invariant> {
+        let exchange = self.exchange.index();
+        self.counters.checked[exchange].fetch_add(1, Ordering::Relaxed);
+        if let Some(invariant) = violation {
+            self.counters.violations[exchange][invariant.index()].fetch_add(1, Ordering::Relaxed);
+        }
+        violation
+    }
+}
+
+#[inline]
+fn registered(symbol: Symbol) -> bool {
+    symbol.is_valid() && symbol.name().is_some()
+}
+
+/// Record `timestamp` for `key`; true if older than the previous one
+#[inline]
+fn regressed(last: &mut HashMap<(Symbol, bool), u64>, key: (Symbol, bool), timestamp: u64) -> bool {
+    if timestamp == 0 {
+        return false;
+    }
+    let previous = last.insert(key, timestamp).unwrap_or(0);
+    if timestamp < previous {
+        // Keep the newest so one late frame counts once
+        last.insert(key, previous);
+        return true;
+    }
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Side;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(v: f64) -> FixedPoint8 {
+        FixedPoint8::from_f64(v).unwrap()
+    }
+
+    #[test]
+    fn test_sampled_checks_count_violations() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let counters = Arc::new(InvariantCounters::new());
+        let mut checker = InvariantChecker::new(Exchange::Bybit, 3);
+        checker.set_counters(counters.clone());
+
+        // 1 in 3 sampled
+        let sampled: Vec<bool> = (0..6).map(|_| checker.due()).collect();
+        assert_eq!(sampled, [false, false, true, false, false, true]);
+
+        let ticker = |bid, ask, timestamp| TickerData {
+            symbol: btc,
+            bid_price: fp(bid),
+            bid_qty: fp(1.0),
+            ask_price: fp(ask),
+            ask_qty: fp(2.0),
+            timestamp,
+            update_id: 0,
+        };
+        assert_eq!(checker.check_ticker(&ticker(100.0, 100.5, 2_000)), None);
+        assert_eq!(checker.check_ticker(&ticker(101.0, 100.5, 3_000)), Some(Invariant::CrossedBook));
+        assert_eq!(checker.check_ticker(&ticker(100.0, 100.5, 1_000)), Some(Invariant::TimestampRegression));
+        assert_eq!(checker.check_ticker(&ticker(100.0, 100.5, 3_000)), None);
+        assert_eq!(checker.check_ticker(&ticker(0.0, 100.5, 4_000)), Some(Invariant::NonPositive));
+        // Partial delta: only present fields, unknown time
+        assert_eq!(checker.check_quote(btc, Some(fp(99.0)), None, [Some(fp(1.0)), None], 0), None);
+        assert_eq!(checker.check_quote(Symbol::UNKNOWN, None, None, [None; 2], 5_000), Some(Invariant::InvalidSymbol));
+
+        let trade = TradeData {
+            symbol: btc,
+            price: fp(100.0),
+            quantity: fp(0.5),
+            timestamp: 2_500,
+            trade_id: 1,
+            side: Side::Buy,
+            is_buyer_maker: false,
+        };
+        assert_eq!(checker.check_trade(&trade), None);
+        assert_eq!(checker.check_trade(&TradeData { quantity: FixedPoint8::ZERO, ..trade }), Some(Invariant::NonPositive));
+
+        assert_eq!(counters.violations(Exchange::Bybit, Invariant::CrossedBook), 1);
+        let snapshot = counters.snapshot();
+        assert_eq!(snapshot.checked, 9);
+        assert_eq!(snapshot.violations.len(), 4);
+        assert_eq!(snapshot.violations.iter().map(|c| c.count).sum::<u64>(), 5);
+
+        // Off
+        checker.set_every(0);
+        assert!((0..10).all(|_| !checker.due()));
+    }
+}
@@ -0,0 +1,262 @@
+//! Venue lead-lag detection (Warm Path)
+//!
+//! Mid-price log returns per venue are bucketed into 5 ms bins by exchange
+//! timestamp and cross-correlated at lags up to 100 ms. Sums decay with a
+//! one-minute half-life, so the estimate follows the recent regime. A
+//! positive correlation between Binance moves and Bybit moves k bins later
+//! means Binance leads by k bins: take the lagging venue first, before it
+//! catches up. Venue clocks are assumed in sync; a skew between them shifts
+//! the measured lag by the same amount.
+//!
+//! Fixed-size arrays, no allocation on record.
+
+use crate::core::TickerData;
+use crate::exchanges::Exchange;
+
+/// Bin width (ms)
+pub const BIN_MS: u64 = 5;
+
+/// Longest lag examined (bins)
+pub const MAX_LAG_BINS: usize = 20;
+
+const BIN_NS: u64 = BIN_MS * 1_000_000;
+
+/// Bins kept open for late updates from the slower feed
+const SETTLE_BINS: u64 = 4;
+
+/// Return history per venue (must exceed SETTLE_BINS + MAX_LAG_BINS)
+const RING: usize = 32;
+
+/// Per-bin decay: half-life of one minute of bins
+const DECAY: f64 = 1.0 - std::f64::consts::LN_2 / (60_000 / BIN_MS) as f64;
+
+/// Decayed count of bins with a move before an estimate is reported
+const MIN_ACTIVE_BINS: f64 = 50.0;
+
+/// Weakest correlation that still names a leader
+const MIN_CORRELATION: f64 = 0.05;
+
+/// Which venue moves first, and by how much
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LeadLag {
+    /// Leading venue (None = moves are contemporaneous at bin resolution)
+    pub leader: Option<Exchange>,
+    /// Lead of `leader` (ms, bin resolution; 0 without a leader)
+    pub lag_ms: u64,
+    /// Correlation at the reported lag
+    pub correlation: f64,
+    /// Correlation at zero lag
+    pub contemporaneous: f64,
+    /// Decayed count of bins with a move on either venue
+    pub active_bins: f64,
+}
+
+/// Rolling cross-correlation of mid-price moves between the two venues
+#[derive(Debug, Clone)]
+pub struct LeadLagEstimator {
+    /// Last mid per venue (0 = none yet)
+    last_mid: [f64; Exchange::COUNT],
+    /// Summed log returns per venue and bin (indexed by bin % RING)
+    returns: [[f64; RING]; Exchange::COUNT],
+    /// Newest bin seen
+    head: Option<u64>,
+    /// Oldest bin still open; older bins are final
+    next_final: u64,
+    /// Σ binance[t-k]·bybit[t]: Binance leads by k bins
+    binance_leads: [f64; MAX_LAG_BINS + 1],
+    /// Σ bybit[t-k]·binance[t]: Bybit leads by k bins
+    bybit_leads: [f64; MAX_LAG_BINS + 1],
+    /// Σ return² per venue
+    variance: [f64; Exchange::COUNT],
+    active: f64,
+}
+
+impl Default for LeadLagEstimator {
+    fn default() -> Self {
+        Self {
+            last_mid: [0.0; Exchange::COUNT],
+            returns: [[0.0; RING]; Exchange::COUNT],
+            head: None,
+            next_final: 0,
+            binance_leads: [0.0; MAX_LAG_BINS + 1],
+            bybit_leads: [0.0; MAX_LAG_BINS + 1],
+            variance: [0.0; Exchange::COUNT],
+            active: 0.0,
+        }
+    }
+}
+
+impl LeadLagEstimator {
+    /// Record a quote from `exchange`
+    ///
+    /// Quotes for bins already final (more than SETTLE_BINS behind the
+    /// newest) only move the reference mid.
+    #[inline]
+    pub fn record(&mut self, exchange: Exchange, ticker: &TickerData) {
+        let mid = (ticker.bid_price.to_f64() + ticker.ask_price.to_f64()) / 2.0;
+        if ticker.timestamp == 0 || mid <= 0.0 {
+            return;
+        }
+        let venue = exchange.index();
+        let last = std::mem::replace(&mut self.last_mid[venue], mid);
+        let bin = ticker.timestamp / BIN_NS;
+        self.advance(bin);
+        if last > 0.0 && bin >= self.next_final && mid != last {
+            self.returns[venue][bin as usize % RING] += (mid / last).ln();
+        }
+    }
+
+    /// Move the newest bin to `bin`, finalizing bins that fall out of the settle window
+    fn advance(&mut self, bin: u64) {
+        let Some(head) = self.head else {
+            self.head = Some(bin);
+            self.next_final = bin;
+            return;
+        };
+        if bin <= head {
+            return;
+        }
+        if bin - head > RING as u64 {
+            // Long gap: settle what is pending, the rest are empty bins
+            while self.next_final <= head {
+                self.finalize();
+            }
+            self.decay(DECAY.powf((bin - self.next_final) as f64));
+            self.returns = [[0.0; RING]; Exchange::COUNT];
+            self.head = Some(bin);
+            self.next_final = bin;
+            return;
+        }
+        for next in head + 1..=bin {
+            // The slot's previous bin (next - RING) is final and past every lag
+            while self.next_final + SETTLE_BINS <= next {
+                self.finalize();
+            }
+            for returns in &mut self.returns {
+                returns[next as usize % RING] = 0.0;
+            }
+        }
+        self.head = Some(bin);
+    }
+
+    fn decay(&mut self, factor: f64) {
+        self.binance_leads.iter_mut().chain(&mut self.bybit_leads).chain(&mut self.variance).for_each(|s| *s *= factor);
+        self.active *= factor;
+    }
+
+    /// Fold the oldest open bin into the cross-products
+    fn finalize(&mut self) {
+        self.decay(DECAY);
+        let bin = self.next_final;
+        let [binance, bybit] = &self.returns;
+        let slot = bin as usize % RING;
+        let (a, b) = (binance[slot], bybit[slot]);
+        for k in 0..=MAX_LAG_BINS {
+            let lagged = bin.wrapping_sub(k as u64) as usize % RING;
+            self.binance_leads[k] += binance[lagged] * b;
+            self.bybit_leads[k] += bybit[lagged] * a;
+        }
+        self.variance[0] += a * a;
+        self.variance[1] += b * b;
+        if a != 0.0 || b != 0.0 {
+            self.active += 1.0;
+        }
+        self.next_final += 1;
+    }
+
+    /// Current estimate (None until both venues have moved enough)
+    pub fn estimate(&self) -> Option<LeadLag> {
+        let norm = (self.variance[0] * self.variance[1]).sqrt();
+        if self.active < MIN_ACTIVE_BINS || norm <= 0.0 {
+            return None;
+        }
+        let contemporaneous = self.binance_leads[0] / norm;
+        let mut best = LeadLag {
+            leader: None,
+            lag_ms: 0,
+            correlation: contemporaneous,
+            contemporaneous,
+            active_bins: self.active,
+        };
+        for (leader, sums) in [(Exchange::Binance, &self.binance_leads), (Exchange::Bybit, &self.bybit_leads)] {
+            for (k, sum) in sums.iter().enumerate().skip(1) {
+                let correlation = sum / norm;
+                if correlation > best.correlation && correlation >= MIN_CORRELATION {
+                    best.leader = Some(leader);
+                    best.lag_ms = k as u64 * BIN_MS;
+                    best.correlation = correlation;
+                }
+            }
+        }
+        Some(best)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Symbol};
+    use crate::test_utils::init_test_registry;
+
+    fn quote(mid: f64, ms: u64) -> TickerData {
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let fp = |v: f64| FixedPoint8::from_f64(v).unwrap();
+        TickerData::new(symbol, fp(mid - 0.5), fp(1.0), fp(mid + 0.5), fp(1.0), ms * 1_000_000)
+    }
+
+    /// Deterministic random walk: one move every 10 ms
+    fn walk(steps: usize) -> Vec<f64> {
+        let mut seed = 0x2545_f491_u64;
+        let mut mid = 1_000.0;
+        (0..steps)
+            .map(|_| {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                mid += if seed & 1 == 0 { 1.0 } else { -1.0 };
+                mid
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_lead_lag_follower() {
+        init_test_registry();
+        let mut estimator = LeadLagEstimator::default();
+        let path = walk(2_000);
+        // Bybit repeats every Binance move 20 ms later, slightly out of order
+        for (i, &mid) in path.iter().enumerate() {
+            let ms = 1_000_000 + i as u64 * 10;
+            estimator.record(Exchange::Binance, &quote(mid, ms));
+            if i >= 2 {
+                estimator.record(Exchange::Bybit, &quote(path[i - 2], ms + 1));
+            }
+        }
+        let lead = estimator.estimate().unwrap();
+        assert_eq!(lead.leader, Some(Exchange::Binance));
+        assert_eq!(lead.lag_ms, 20);
+        assert!(lead.correlation > 0.9, "{:?}", lead);
+        assert!(lead.contemporaneous.abs() < 0.2, "{:?}", lead);
+    }
+
+    #[test]
+    fn test_lead_lag_contemporaneous() {
+        init_test_registry();
+        let mut estimator = LeadLagEstimator::default();
+        assert_eq!(estimator.estimate(), None);
+        for (i, &mid) in walk(2_000).iter().enumerate() {
+            let ms = 1_000_000 + i as u64 * 10;
+            estimator.record(Exchange::Bybit, &quote(mid, ms));
+            estimator.record(Exchange::Binance, &quote(mid, ms + 1));
+        }
+        let lead = estimator.estimate().unwrap();
+        assert_eq!((lead.leader, lead.lag_ms), (None, 0));
+        assert!(lead.contemporaneous > 0.9, "{:?}", lead);
+
+        // A long silence decays the evidence without discarding it
+        let before = lead.active_bins;
+        estimator.record(Exchange::Binance, &quote(1_000.0, 1_000_000 + 30_000));
+        let after = estimator.estimate().map_or(0.0, |l| l.active_bins);
+        assert!(after < before && after > 0.0, "{} -> {}", before, after);
+    }
+}
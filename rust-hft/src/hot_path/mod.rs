@@ -4,12 +4,42 @@
 //! - Message routing
 //! - Spread calculations
 //! - Opportunity detection
+//! - Break-even cost estimation
+//! - Inventory rebalance (withdraw + transfer) opportunities
+//! - Rolling price volatility / VWAP
+//! - Time-of-day / weekday session analytics
+//! - Per-symbol threshold calibration
+//! - Opportunity survival (fill probability) estimation
+//! - Venue lead-lag detection
+//! - Duplicate suppression across redundant feeds
+//! - Quote sanity filters (flash-glitch guard)
+//! - Sampled market data invariant checks
 //! - Order execution logic
 
 pub mod routing;
 pub mod calculator;
 pub mod tracker;
+pub mod dedup;
+pub mod costs;
+pub mod estimators;
+pub mod sessions;
+pub mod thresholds;
+pub mod sanity;
+pub mod invariants;
+pub mod transfers;
+pub mod survival;
+pub mod leadlag;
 
 pub use routing::MessageRouter;
 pub use calculator::{SpreadCalculator, SpreadEvent};
-pub use tracker::{ThresholdTracker, ScreenerStats};
+pub use tracker::{BookAnomalies, QuotePair, ThresholdTracker, ScreenerStats, SymbolState, OPPORTUNITY_SPREAD};
+pub use dedup::{Deduplicator, Verdict};
+pub use costs::{CostBreakdown, CostModel, NetEdges};
+pub use estimators::PriceEstimator;
+pub use sessions::{SessionBucket, SessionStats};
+pub use thresholds::{ThresholdPolicy, ThresholdSource};
+pub use sanity::{QuoteRejection, QuoteSanity};
+pub use invariants::{Invariant, InvariantChecker, InvariantCounters, InvariantSnapshot};
+pub use transfers::{AssetTransfer, RebalanceOpportunity, TransferModel};
+pub use survival::{fill_probability, LatencyEstimator, QuoteTurnover};
+pub use leadlag::{LeadLag, LeadLagEstimator};
@@ -54,7 +54,7 @@ impl MessageRouter {
     /// * `handler` - Function to call when ticker data arrives
     ///
     /// # Example
-    /// ```
+    /// ```ignore
     /// router.register_ticker(Symbol::from_bytes(b"BTCUSDT").unwrap(), |sym, data| {
     ///     println!("Ticker for {:?}: bid={}, ask={}", sym, data.bid_price, data.ask_price);
     /// });
@@ -168,11 +168,10 @@ impl Default for MessageRouter {
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::{registry::SymbolRegistry, FixedPoint8};
-
+    use crate::test_utils::init_test_registry;
+    use crate::core::FixedPoint8;
 
     fn make_ticker(symbol: Symbol) -> TickerData {
         TickerData {
@@ -182,6 +181,7 @@ mod tests {
             bid_qty: FixedPoint8::ONE,
             ask_qty: FixedPoint8::ONE,
             timestamp: 1000,
+            update_id: 0,
         }
     }
 
@@ -191,6 +191,7 @@ mod tests {
             price: FixedPoint8::from_raw(100_000_000),
             quantity: FixedPoint8::ONE,
             timestamp: 1000,
+            trade_id: 0,
             side: crate::core::Side::Buy,
             is_buyer_maker: false,
         }
@@ -235,9 +236,6 @@ mod tests {
         let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
 
         static CALL_COUNT: std::sync::atomic::AtomicU64 = std::sync::atomic::AtomicU64::new(0);
-        fn handler(_sym: Symbol, _data: TickerData) {
-            CALL_COUNT.fetch_add(1, std::sync::atomic::Ordering::Relaxed);
-        }
 
         router.route_ticker(btc, make_ticker(btc));
         assert_eq!(CALL_COUNT.load(std::sync::atomic::Ordering::Relaxed), 0);
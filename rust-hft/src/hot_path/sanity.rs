@@ -0,0 +1,113 @@
+//! Quote sanity filters (flash-glitch guard)
+//!
+//! Exchanges occasionally push erroneous quotes: zero prices or a price 100x
+//! off (locked/crossed books are screened separately by the tracker). Such quotes are quarantined before they reach
+//! strategies or the spread history. A quote deviating from the last
+//! accepted mid is rejected until it persists for `reanchor_after`
+//! consecutive updates, so a genuine gap re-anchors instead of blocking the
+//! symbol forever.
+
+use crate::core::{FixedPoint8, TickerData};
+use crate::infrastructure::config::SanityConfig;
+
+/// Why a quote was quarantined
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum QuoteRejection {
+    /// Zero or negative bid/ask
+    NonPositive,
+    /// Bid above ask on the same venue
+    Crossed,
+    /// Bid equal to ask on the same venue
+    Locked,
+    /// Mid moved more than the allowed deviation since the last accepted quote
+    Deviation,
+}
+
+/// Quote sanity policy
+#[derive(Debug, Clone, Copy)]
+pub struct QuoteSanity {
+    enabled: bool,
+    /// Max relative mid move between consecutive quotes (fraction)
+    max_deviation: FixedPoint8,
+    /// Consecutive deviating quotes after which the new level is accepted
+    reanchor_after: u32,
+}
+
+impl Default for QuoteSanity {
+    fn default() -> Self {
+        Self::new(&SanityConfig::default())
+    }
+}
+
+impl QuoteSanity {
+    pub fn new(config: &SanityConfig) -> Self {
+        Self {
+            enabled: config.enabled,
+            max_deviation: FixedPoint8::from_f64(config.max_deviation_pct / 100.0).unwrap_or(FixedPoint8::MAX),
+            reanchor_after: config.reanchor_after,
+        }
+    }
+
+    /// Check quote against the last accepted mid on the same venue
+    ///
+    /// `streak` counts consecutive deviation rejections and is updated.
+    #[inline]
+    pub fn check(&self, ticker: &TickerData, last_mid: Option<FixedPoint8>, streak: &mut u32) -> Result<(), QuoteRejection> {
+        if !self.enabled {
+            return Ok(());
+        }
+        if !ticker.bid_price.is_positive() || !ticker.ask_price.is_positive() {
+            return Err(QuoteRejection::NonPositive);
+        }
+
+        let deviates = match (ticker.mid_price(), last_mid) {
+            (Some(mid), Some(last)) if last.is_positive() => mid
+                .checked_sub(last)
+                .and_then(|diff| diff.safe_div(last))
+                .and_then(|rel| rel.checked_abs())
+                .is_none_or(|rel| rel > self.max_deviation),
+            _ => false,
+        };
+        if deviates && *streak < self.reanchor_after {
+            *streak += 1;
+            return Err(QuoteRejection::Deviation);
+        }
+        *streak = 0;
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::test_utils::init_test_registry;
+
+    fn quote(bid: i64, ask: i64) -> TickerData {
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        TickerData::new(symbol, FixedPoint8::from_raw(bid), FixedPoint8::ONE, FixedPoint8::from_raw(ask), FixedPoint8::ONE, 0)
+    }
+
+    #[test]
+    fn test_rejections_and_reanchor() {
+        init_test_registry();
+        let sanity = QuoteSanity::new(&SanityConfig { enabled: true, max_deviation_pct: 10.0, reanchor_after: 2 });
+        let last = Some(FixedPoint8::from_raw(100 * FixedPoint8::SCALE));
+        let mut streak = 0;
+
+        assert_eq!(sanity.check(&quote(0, 100), last, &mut streak), Err(QuoteRejection::NonPositive));
+        assert_eq!(sanity.check(&quote(10_500_000_000, 10_600_000_000), last, &mut streak), Ok(()));
+        // No reference yet: anything well-formed passes
+        assert_eq!(sanity.check(&quote(1, 2), None, &mut streak), Ok(()));
+
+        // 100x glitch rejected twice, then accepted as the new level
+        let glitch = quote(10_000 * FixedPoint8::SCALE, 10_001 * FixedPoint8::SCALE);
+        assert_eq!(sanity.check(&glitch, last, &mut streak), Err(QuoteRejection::Deviation));
+        assert_eq!(sanity.check(&glitch, last, &mut streak), Err(QuoteRejection::Deviation));
+        assert_eq!(sanity.check(&glitch, last, &mut streak), Ok(()));
+        assert_eq!(streak, 0);
+
+        let disabled = QuoteSanity::new(&SanityConfig { enabled: false, ..Default::default() });
+        assert_eq!(disabled.check(&quote(0, 0), last, &mut streak), Ok(()));
+    }
+}
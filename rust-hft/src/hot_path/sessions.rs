@@ -0,0 +1,115 @@
+//! Time-of-day / weekday session analytics (Warm Path)
+//!
+//! Per-symbol opportunity counts and net spread (after break-even) bucketed
+//! by UTC hour-of-day and weekday, so operators can see when a symbol is
+//! worth trading. Fixed-size arrays, no allocation on record.
+
+use crate::core::FixedPoint8;
+
+/// Hours per day
+pub const HOURS: usize = 24;
+
+/// Days per week (index 0 = Monday)
+pub const WEEKDAYS: usize = 7;
+
+const NANOS_PER_HOUR: u64 = 3_600_000_000_000;
+
+/// Aggregates for one hour or weekday bucket
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionBucket {
+    /// Opportunities that started in this bucket
+    pub opportunities: u64,
+    /// Updates above threshold
+    pub samples: u64,
+    /// Sum of net spread over samples
+    pub net_sum: FixedPoint8,
+}
+
+impl SessionBucket {
+    /// Average net spread over samples (ZERO if empty)
+    pub fn average_net(&self) -> FixedPoint8 {
+        if self.samples == 0 {
+            return FixedPoint8::ZERO;
+        }
+        FixedPoint8::from_raw(self.net_sum.as_raw() / self.samples as i64)
+    }
+
+    #[inline]
+    fn record(&mut self, net: FixedPoint8, started: bool) {
+        self.opportunities += started as u64;
+        self.samples += 1;
+        self.net_sum = self.net_sum.checked_add(net).unwrap_or(self.net_sum);
+    }
+}
+
+/// Hour-of-day and weekday buckets for one symbol
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct SessionStats {
+    pub hours: [SessionBucket; HOURS],
+    pub weekdays: [SessionBucket; WEEKDAYS],
+}
+
+impl SessionStats {
+    /// Record an update above threshold at `timestamp` (nanoseconds since epoch)
+    ///
+    /// `started` marks the first update of an opportunity. Updates without a
+    /// timestamp (0) are ignored.
+    #[inline]
+    pub fn record(&mut self, timestamp: u64, net: FixedPoint8, started: bool) {
+        if timestamp == 0 {
+            return;
+        }
+        let (hour, weekday) = hour_and_weekday(timestamp);
+        self.hours[hour].record(net, started);
+        self.weekdays[weekday].record(net, started);
+    }
+
+    /// Total opportunities recorded
+    pub fn opportunities(&self) -> u64 {
+        self.hours.iter().map(|b| b.opportunities).sum()
+    }
+}
+
+/// UTC hour-of-day (0-23) and weekday (0 = Monday) for nanosecond timestamp
+#[inline]
+pub fn hour_and_weekday(timestamp: u64) -> (usize, usize) {
+    let hours = timestamp / NANOS_PER_HOUR;
+    let days = hours / HOURS as u64;
+    // 1970-01-01 was a Thursday (index 3)
+    ((hours % HOURS as u64) as usize, ((days + 3) % WEEKDAYS as u64) as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const HOUR: u64 = NANOS_PER_HOUR;
+
+    #[test]
+    fn test_hour_and_weekday() {
+        // Epoch: Thursday 00:00
+        assert_eq!(hour_and_weekday(1), (0, 3));
+        // 2024-01-01 was a Monday; 13:00 UTC
+        let monday = 19_723 * 24 * HOUR + 13 * HOUR;
+        assert_eq!(hour_and_weekday(monday), (13, 0));
+        // Sunday 23:00 follows
+        assert_eq!(hour_and_weekday(monday + 6 * 24 * HOUR + 10 * HOUR), (23, 6));
+    }
+
+    #[test]
+    fn test_buckets() {
+        let mut stats = SessionStats::default();
+        let at = 19_723 * 24 * HOUR + 13 * HOUR;
+        stats.record(at, FixedPoint8::from_raw(100), true);
+        stats.record(at + 1, FixedPoint8::from_raw(300), false);
+        stats.record(at + HOUR, FixedPoint8::from_raw(-50), true);
+        stats.record(0, FixedPoint8::from_raw(1_000), true);
+
+        assert_eq!(stats.hours[13].opportunities, 1);
+        assert_eq!(stats.hours[13].average_net().as_raw(), 200);
+        assert_eq!(stats.hours[14].average_net().as_raw(), -50);
+        assert_eq!(stats.weekdays[0].samples, 3);
+        assert_eq!(stats.opportunities(), 2);
+        assert_eq!(stats.hours[0], SessionBucket::default());
+    }
+}
@@ -0,0 +1,164 @@
+//! Opportunity survival estimation (Warm Path)
+//!
+//! A spread seen on our feed is already stale and our order needs another
+//! round trip to land, so a quote that turns over every few milliseconds
+//! is gone long before a 50 ms order arrives. Top-of-book price changes are
+//! modelled as a Poisson process per leg; the probability that neither leg
+//! moves during the order round trip is
+//!
+//! `P(fill) = exp(-(rate_buy + rate_sell) × latency)`
+//!
+//! Quote intervals use exchange timestamps, so estimates are deterministic
+//! under replay. Latency starts from a configured prior and follows
+//! measured order round trips once they are reported.
+
+use crate::core::{FixedPoint8, TickerData};
+use std::time::Duration;
+
+/// EWMA smoothing: each sample moves the mean by 1/8 of its deviation
+const EWMA_SHIFT: u32 = 3;
+
+#[inline]
+fn ewma(mean: u64, sample: u64) -> u64 {
+    mean - (mean >> EWMA_SHIFT) + (sample >> EWMA_SHIFT)
+}
+
+/// Mean interval between top-of-book price changes on one venue
+#[derive(Debug, Clone, Copy, Default)]
+pub struct QuoteTurnover {
+    /// Exchange timestamp of the last price change (ns)
+    last_change: Option<u64>,
+    /// Smoothed interval between price changes (ns, None until two changes)
+    mean_interval_ns: Option<u64>,
+}
+
+impl QuoteTurnover {
+    /// Record quote `next` following `prev` on the same venue
+    ///
+    /// Quantity-only updates are not turnover: the price still stands.
+    #[inline]
+    pub fn record(&mut self, prev: Option<&TickerData>, next: &TickerData) {
+        let changed = prev.is_none_or(|p| p.bid_price != next.bid_price || p.ask_price != next.ask_price);
+        if !changed {
+            return;
+        }
+        if let Some(last) = self.last_change {
+            // Out-of-order or same-timestamp updates carry no interval
+            if next.timestamp > last {
+                let interval = next.timestamp - last;
+                self.mean_interval_ns = Some(self.mean_interval_ns.map_or(interval, |mean| ewma(mean, interval)));
+            }
+        }
+        self.last_change = Some(self.last_change.map_or(next.timestamp, |last| last.max(next.timestamp)));
+    }
+
+    /// Smoothed interval between price changes (None until measured)
+    #[inline]
+    pub fn mean_interval(&self) -> Option<Duration> {
+        self.mean_interval_ns.map(Duration::from_nanos)
+    }
+
+    /// Price changes per second (None until measured)
+    #[inline]
+    pub fn rate(&self) -> Option<f64> {
+        self.mean_interval_ns.filter(|&ns| ns > 0).map(|ns| 1e9 / ns as f64)
+    }
+}
+
+/// Smoothed order round-trip latency
+#[derive(Debug, Clone, Copy)]
+pub struct LatencyEstimator {
+    mean_ns: u64,
+    samples: u64,
+}
+
+impl LatencyEstimator {
+    /// Estimator starting at `prior` until round trips are measured
+    pub fn new(prior: Duration) -> Self {
+        Self {
+            mean_ns: prior.as_nanos().min(u64::MAX as u128) as u64,
+            samples: 0,
+        }
+    }
+
+    /// Record a measured round trip (order sent to acknowledgement)
+    #[inline]
+    pub fn record(&mut self, round_trip: Duration) {
+        let sample = round_trip.as_nanos().min(u64::MAX as u128) as u64;
+        // The first measurement replaces the prior outright
+        self.mean_ns = if self.samples == 0 { sample } else { ewma(self.mean_ns, sample) };
+        self.samples += 1;
+    }
+
+    /// Current latency estimate
+    #[inline]
+    pub fn estimate(&self) -> Duration {
+        Duration::from_nanos(self.mean_ns)
+    }
+
+    /// Round trips measured so far (0 = prior in use)
+    #[inline]
+    pub fn samples(&self) -> u64 {
+        self.samples
+    }
+}
+
+/// Probability that neither leg's quote moves within `latency`
+///
+/// A leg without a turnover estimate contributes no hazard, so a fresh
+/// symbol scores 1.0 rather than blocking every decision during warmup.
+pub fn fill_probability(buy: &QuoteTurnover, sell: &QuoteTurnover, latency: Duration) -> FixedPoint8 {
+    let hazard = buy.rate().unwrap_or(0.0) + sell.rate().unwrap_or(0.0);
+    FixedPoint8::from_f64((-hazard * latency.as_secs_f64()).exp()).unwrap_or(FixedPoint8::ZERO)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::test_utils::init_test_registry;
+
+    fn quote(bid: i64, qty: i64, ms: u64) -> TickerData {
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let fp = |v: i64| FixedPoint8::from_raw(v * FixedPoint8::SCALE);
+        TickerData::new(symbol, fp(bid), fp(qty), fp(bid + 1), fp(qty), ms * 1_000_000)
+    }
+
+    #[test]
+    fn test_turnover_and_fill_probability() {
+        init_test_registry();
+        let mut buy = QuoteTurnover::default();
+        let sell = QuoteTurnover::default();
+        assert_eq!(fill_probability(&buy, &sell, Duration::from_millis(50)), FixedPoint8::ONE);
+
+        // Price changes every 100 ms; quantity-only updates are ignored
+        let mut prev: Option<TickerData> = None;
+        for (i, q) in [quote(100, 1, 0), quote(100, 2, 50), quote(101, 2, 100), quote(102, 2, 200)].iter().enumerate() {
+            buy.record(prev.as_ref(), q);
+            prev = Some(*q);
+            if i == 1 {
+                assert_eq!(buy.mean_interval(), None);
+            }
+        }
+        assert_eq!(buy.mean_interval(), Some(Duration::from_millis(100)));
+        assert_eq!(buy.rate(), Some(10.0));
+
+        // 10 changes/s over 100 ms: exp(-1)
+        let p = fill_probability(&buy, &sell, Duration::from_millis(100)).to_f64();
+        assert!((p - (-1.0f64).exp()).abs() < 1e-6, "{}", p);
+        // Slower orders are less likely to find the quote
+        assert!(fill_probability(&buy, &sell, Duration::from_millis(300)).to_f64() < p);
+    }
+
+    #[test]
+    fn test_latency_estimator() {
+        let mut latency = LatencyEstimator::new(Duration::from_millis(50));
+        assert_eq!((latency.estimate(), latency.samples()), (Duration::from_millis(50), 0));
+
+        latency.record(Duration::from_millis(8));
+        assert_eq!(latency.estimate(), Duration::from_millis(8));
+        latency.record(Duration::from_millis(16));
+        assert_eq!(latency.estimate(), Duration::from_millis(9));
+        assert_eq!(latency.samples(), 2);
+    }
+}
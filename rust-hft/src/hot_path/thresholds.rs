@@ -0,0 +1,266 @@
+//! Per-symbol threshold calibration (Warm Path)
+//!
+//! A single static threshold is too tight for illiquid alts and too loose
+//! for BTC. Each symbol keeps a 1-hour histogram of |spread| and its
+//! threshold is recalibrated to a high quantile (P99 by default) of that
+//! distribution. Manual overrides from config always win; until enough
+//! samples are collected the static default applies.
+//!
+//! The histogram uses fixed log-spaced buckets rotated in 5-minute slots:
+//! recording is O(log buckets) with no allocation.
+
+use crate::core::{Bps, FixedPoint8, Symbol};
+use crate::infrastructure::config::ThresholdConfig;
+use serde::Serialize;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Histogram buckets
+const BUCKETS: usize = 64;
+
+/// Rotating time slots
+const SLOTS: usize = 12;
+
+/// Calibration window (SLOTS × slot length)
+pub const CALIBRATION_WINDOW: Duration = Duration::from_secs(3600);
+
+/// Upper bound of the first bucket: 0.0001% (raw FixedPoint8)
+const FIRST_BOUND: i64 = 100;
+
+/// Bucket upper bounds, each 20% above the previous (~0.0001% .. ~9.8%)
+const BOUNDS: [i64; BUCKETS] = {
+    let mut bounds = [0i64; BUCKETS];
+    let mut i = 0;
+    let mut bound = FIRST_BOUND;
+    while i < BUCKETS {
+        bounds[i] = bound;
+        bound = bound * 6 / 5;
+        i += 1;
+    }
+    bounds
+};
+
+/// Rolling |spread| histogram over [`CALIBRATION_WINDOW`]
+#[derive(Debug, Clone)]
+pub struct SpreadHistogram {
+    counts: Box<[[u32; BUCKETS]; SLOTS]>,
+    slot_len: Duration,
+    started: Instant,
+    /// Absolute index of the current slot
+    current: u64,
+}
+
+impl SpreadHistogram {
+    /// Create empty histogram starting at `now`
+    pub fn new(now: Instant) -> Self {
+        Self {
+            counts: Box::new([[0; BUCKETS]; SLOTS]),
+            slot_len: CALIBRATION_WINDOW / SLOTS as u32,
+            started: now,
+            current: 0,
+        }
+    }
+
+    /// Heap allocated by the slot counts (bytes)
+    #[inline]
+    pub fn heap_bytes(&self) -> usize {
+        std::mem::size_of::<[[u32; BUCKETS]; SLOTS]>()
+    }
+
+    /// Current slot index; changes every slot length (recalibration epoch)
+    #[inline]
+    pub fn epoch(&self) -> u64 {
+        self.current
+    }
+
+    /// Rotate to the slot containing `now`, clearing expired slots
+    fn advance(&mut self, now: Instant) {
+        let slot = (now.saturating_duration_since(self.started).as_nanos() / self.slot_len.as_nanos()) as u64;
+        if slot <= self.current {
+            return;
+        }
+        let stale = (slot - self.current).min(SLOTS as u64);
+        for i in 1..=stale {
+            self.counts[((self.current + i) % SLOTS as u64) as usize] = [0; BUCKETS];
+        }
+        self.current = slot;
+    }
+
+    /// Record spread observed at `now`
+    #[inline]
+    pub fn record(&mut self, spread: Bps, now: Instant) {
+        self.advance(now);
+        let value = spread.checked_abs().unwrap_or(Bps::MAX).as_raw();
+        let bucket = BOUNDS.partition_point(|&bound| bound < value).min(BUCKETS - 1);
+        let count = &mut self.counts[(self.current % SLOTS as u64) as usize][bucket];
+        *count = count.saturating_add(1);
+    }
+
+    /// Upper bound of the bucket holding quantile `q` and the sample count
+    ///
+    /// None without samples in the window.
+    pub fn quantile(&mut self, q: f64, now: Instant) -> Option<(Bps, u64)> {
+        self.advance(now);
+        let mut merged = [0u64; BUCKETS];
+        for slot in self.counts.iter() {
+            for (total, &count) in merged.iter_mut().zip(slot) {
+                *total += count as u64;
+            }
+        }
+        let samples: u64 = merged.iter().sum();
+        if samples == 0 {
+            return None;
+        }
+        let target = ((q.clamp(0.0, 1.0) * samples as f64).ceil() as u64).max(1);
+        let mut seen = 0;
+        for (bucket, &count) in merged.iter().enumerate() {
+            seen += count;
+            if seen >= target {
+                return Some((Bps::from_raw(BOUNDS[bucket]), samples));
+            }
+        }
+        Some((Bps::from_raw(BOUNDS[BUCKETS - 1]), samples))
+    }
+}
+
+/// Where a symbol's threshold came from
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum ThresholdSource {
+    /// Static default (calibration disabled or not enough samples)
+    Static,
+    /// Quantile of the rolling spread distribution
+    Calibrated,
+    /// Manual override from config
+    Override,
+}
+
+impl ThresholdSource {
+    /// Lowercase name for serialization
+    pub const fn name(&self) -> &'static str {
+        match self {
+            Self::Static => "static",
+            Self::Calibrated => "calibrated",
+            Self::Override => "override",
+        }
+    }
+}
+
+/// Threshold resolution: override > calibrated > static default
+#[derive(Debug, Clone)]
+pub struct ThresholdPolicy {
+    default: Bps,
+    calibrate: bool,
+    quantile: f64,
+    min_samples: u64,
+    /// Overrides by symbol name (resolved only on recalibration)
+    overrides: HashMap<String, Bps>,
+    /// Minimum |spread| in ticks for a hit (ZERO = disabled)
+    min_spread_ticks: FixedPoint8,
+}
+
+impl Default for ThresholdPolicy {
+    fn default() -> Self {
+        Self::new(&ThresholdConfig::default(), DEFAULT_THRESHOLD)
+    }
+}
+
+/// Static default threshold: 25 bps (0.25%)
+pub const DEFAULT_THRESHOLD: Bps = Bps::from_bps(25);
+
+impl ThresholdPolicy {
+    /// Build policy from config with static `default` threshold
+    pub fn new(config: &ThresholdConfig, default: Bps) -> Self {
+        let fp = |v: f64| Bps::from_fraction_f64(v).unwrap_or(default);
+        Self {
+            default,
+            calibrate: config.calibrate,
+            quantile: config.quantile,
+            min_samples: config.min_samples,
+            overrides: config
+                .overrides
+                .iter()
+                .map(|(symbol, &threshold)| (symbol.to_ascii_uppercase(), fp(threshold)))
+                .collect(),
+            min_spread_ticks: FixedPoint8::from_f64(config.min_spread_ticks.max(0.0)).unwrap_or(FixedPoint8::ZERO),
+        }
+    }
+
+    /// Minimum |spread| in ticks for a hit / valid screener row (ZERO = disabled)
+    #[inline]
+    pub fn min_spread_ticks(&self) -> FixedPoint8 {
+        self.min_spread_ticks
+    }
+
+    /// Effective threshold for symbol given its spread distribution
+    pub fn resolve(&self, symbol: Symbol, histogram: &mut SpreadHistogram, now: Instant) -> (Bps, ThresholdSource) {
+        if let Some(&threshold) = self.overrides.get(symbol.as_str()) {
+            return (threshold, ThresholdSource::Override);
+        }
+        if self.calibrate {
+            if let Some((threshold, samples)) = histogram.quantile(self.quantile, now) {
+                if samples >= self.min_samples {
+                    return (threshold, ThresholdSource::Calibrated);
+                }
+            }
+        }
+        (self.default, ThresholdSource::Static)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_histogram_quantile_and_expiry() {
+        let t0 = Instant::now();
+        let mut hist = SpreadHistogram::new(t0);
+        assert_eq!(hist.quantile(0.99, t0), None);
+
+        // 99 small spreads (0.01%) and one large (1%), both signs
+        for i in 0..99 {
+            let spread = if i % 2 == 0 { 10_000 } else { -10_000 };
+            hist.record(Bps::from_raw(spread), t0);
+        }
+        hist.record(Bps::from_raw(1_000_000), t0);
+
+        let (p99, samples) = hist.quantile(0.99, t0).unwrap();
+        assert_eq!(samples, 100);
+        assert!(p99.as_raw() >= 10_000 && p99.as_raw() < 12_000, "{}", p99.as_raw());
+        let (max, _) = hist.quantile(1.0, t0).unwrap();
+        assert!(max.as_raw() >= 1_000_000 && max.as_raw() < 1_200_000);
+
+        // Everything expires after the window
+        assert_eq!(hist.quantile(0.99, t0 + CALIBRATION_WINDOW + Duration::from_secs(1)), None);
+        assert!(hist.epoch() > 0);
+    }
+
+    #[test]
+    fn test_policy_resolution() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let config = ThresholdConfig {
+            calibrate: true,
+            quantile: 0.99,
+            min_samples: 10,
+            overrides: HashMap::from([("btcusdt".to_string(), 0.001)]),
+            ..Default::default()
+        };
+        let policy = ThresholdPolicy::new(&config, DEFAULT_THRESHOLD);
+        let t0 = Instant::now();
+        let mut hist = SpreadHistogram::new(t0);
+
+        assert_eq!(policy.resolve(btc, &mut hist, t0), (Bps::from_bps(10), ThresholdSource::Override));
+        assert_eq!(policy.resolve(eth, &mut hist, t0), (DEFAULT_THRESHOLD, ThresholdSource::Static));
+
+        for _ in 0..10 {
+            hist.record(Bps::from_raw(50_000), t0);
+        }
+        let (threshold, source) = policy.resolve(eth, &mut hist, t0);
+        assert_eq!(source, ThresholdSource::Calibrated);
+        assert!(threshold.as_raw() >= 50_000 && threshold.as_raw() < 60_000);
+    }
+}
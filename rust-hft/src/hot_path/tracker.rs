@@ -2,84 +2,410 @@
 //!
 //! Tracks spread state and calculates statistics for the screener.
 //! Integrates SpreadCalculator and TimeWindowBuffer for 2-minute rolling window.
+//! Windows can be pre-seeded from REST klines to shorten the startup warm-up.
+//! Hit thresholds are calibrated per symbol (see [`super::thresholds`]).
+//! While a venue is down its last tickers are stale: no spreads are computed
+//! against them until that venue delivers a fresh ticker for the symbol.
+//! Likewise no spreads are computed for a symbol halted or in auction on
+//! either venue (see [`TradingStatus`]).
+//! Symbols without fresh tickers from both venues for the idle timeout are
+//! evicted: their windows are freed and the state is recreated on the next
+//! update. Tick sizes and trading status survive eviction.
 //!
 //! HFT: Uses pre-allocated array for O(1) symbol lookup, no Vec resize.
 
-use crate::core::{FixedPoint8, Symbol, TickerData, MAX_SYMBOLS};
+use crate::core::{
+    clock, Bps, BookState, FixedPoint8, LiquidationData, MarkPriceData, Symbol, TickerData, TradeData, TradingStatus,
+    MAX_SYMBOLS,
+};
 use crate::exchanges::Exchange;
-use crate::hot_path::{SpreadCalculator, SpreadEvent};
+use crate::hot_path::thresholds::{SpreadHistogram, ThresholdPolicy, ThresholdSource, DEFAULT_THRESHOLD};
+use crate::hot_path::{
+    fill_probability, CostModel, LeadLag, LeadLagEstimator, NetEdges, PriceEstimator, QuoteRejection, QuoteSanity, QuoteTurnover, RebalanceOpportunity, SessionStats,
+    SpreadCalculator, SpreadEvent, TransferModel,
+};
 use crate::infrastructure::TimeWindowBuffer;
-use std::time::Duration;
+use crate::rest::Kline;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Spread at which an update is treated as an opportunity (5 bps = 0.05%)
+pub const OPPORTUNITY_SPREAD: Bps = Bps::from_bps(5);
 
 /// Rolling window duration: 2 minutes
 const WINDOW_DURATION: Duration = Duration::from_secs(120);
 
+/// Spread percentile buckets: 1 bp wide over ±5% (wider spreads clamp to the edge)
+const SPREAD_QUANTILE_RANGE: FixedPoint8 = FixedPoint8::from_raw(5_000_000);
+const SPREAD_QUANTILE_WIDTH: FixedPoint8 = FixedPoint8::from_raw(10_000);
+
+/// Kline interval used for seeding (1 minute)
+const KLINE_INTERVAL_MS: u64 = 60_000;
+
+/// Map a wall-clock time (Unix millis) onto the monotonic clock
+///
+/// Future times clamp to `now`; None if the time predates the monotonic clock.
+fn instant_at(now: Instant, now_ms: u64, at_ms: u64) -> Option<Instant> {
+    now.checked_sub(Duration::from_millis(now_ms.saturating_sub(at_ms)))
+}
+
+/// Locked/crossed quote counts for one venue (excluded from spreads)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BookAnomalies {
+    /// Bid above ask
+    pub crossed: u64,
+    /// Bid equal to ask
+    pub locked: u64,
+}
+
 /// State for a single symbol
 #[derive(Debug, Clone)]
 pub struct SymbolState {
     pub symbol: Symbol,
-    pub last_binance: Option<TickerData>,
-    pub last_bybit: Option<TickerData>,
+    /// Latest ticker per exchange (indexed by ExchangeId)
+    pub tickers: [Option<TickerData>; Exchange::COUNT],
+
+    /// Latest mark/index price per exchange (PnL and liquidation reference)
+    pub marks: [Option<MarkPriceData>; Exchange::COUNT],
+
+    /// Local arrival of the latest ticker per exchange (indexed by Exchange::index)
+    pub updated_at: [Option<Instant>; Exchange::COUNT],
+
+    /// Price tick size per exchange (indexed by Exchange::index; None = unknown)
+    pub tick_sizes: [Option<FixedPoint8>; Exchange::COUNT],
+
+    /// Last ticker predates a venue outage (indexed by Exchange::index)
+    stale: [bool; Exchange::COUNT],
+
+    /// Venue trading status (indexed by Exchange::index)
+    pub trading_status: [TradingStatus; Exchange::COUNT],
+
+    /// Consecutive quarantined deviating quotes per exchange
+    quarantine_streak: [u32; Exchange::COUNT],
+
+    /// Locked/crossed quotes seen per exchange (indexed by Exchange::index)
+    pub book_anomalies: [BookAnomalies; Exchange::COUNT],
 
     /// Rolling history of spreads over 2-minute window
     pub history: TimeWindowBuffer,
 
+    /// Rolling liquidation notional (both exchanges) over 2-minute window
+    pub liquidations: TimeWindowBuffer,
+
+    /// Price volatility / VWAP per exchange (indexed by Exchange::index)
+    pub estimators: [PriceEstimator; Exchange::COUNT],
+
+    /// Top-of-book price turnover per exchange (indexed by Exchange::index)
+    pub turnover: [QuoteTurnover; Exchange::COUNT],
+
+    /// Cross-venue mid-price lead-lag
+    pub lead_lag: LeadLagEstimator,
+
     /// Number of times spread exceeded threshold
     pub hits: u64,
 
+    /// 1-hour |spread| distribution for threshold calibration
+    pub spread_histogram: SpreadHistogram,
+
+    /// Effective hit threshold and where it came from
+    pub threshold: Bps,
+    pub threshold_source: ThresholdSource,
+
+    /// Histogram epoch the threshold was resolved at (None = never)
+    threshold_epoch: Option<u64>,
+
+    /// Minimum |spread| in ticks for a hit (ZERO = no tick filter)
+    min_spread_ticks: FixedPoint8,
+
     /// Current active spread
-    pub current_spread: FixedPoint8,
+    pub current_spread: Bps,
+
+    /// Current spread in ticks (None until tick sizes are known)
+    pub current_spread_ticks: Option<FixedPoint8>,
+
+    /// Opportunity frequency / net spread by hour-of-day and weekday
+    pub sessions: SessionStats,
+
+    /// Last update was above OPPORTUNITY_SPREAD
+    in_opportunity: bool,
+
+    /// State creation (an idle venue that never quoted counts from here)
+    created: Instant,
 }
 
 impl SymbolState {
     pub fn new(symbol: Symbol) -> Self {
         Self {
             symbol,
-            last_binance: None,
-            last_bybit: None,
-            history: TimeWindowBuffer::new(WINDOW_DURATION),
+            tickers: [None; Exchange::COUNT],
+            marks: [None; Exchange::COUNT],
+            updated_at: [None; Exchange::COUNT],
+            tick_sizes: [None; Exchange::COUNT],
+            stale: [false; Exchange::COUNT],
+            trading_status: [TradingStatus::Trading; Exchange::COUNT],
+            quarantine_streak: [0; Exchange::COUNT],
+            book_anomalies: [BookAnomalies::default(); Exchange::COUNT],
+            history: TimeWindowBuffer::with_histogram(WINDOW_DURATION, SPREAD_QUANTILE_RANGE, SPREAD_QUANTILE_WIDTH),
+            liquidations: TimeWindowBuffer::new(WINDOW_DURATION),
+            estimators: std::array::from_fn(|_| PriceEstimator::new(WINDOW_DURATION)),
+            turnover: [QuoteTurnover::default(); Exchange::COUNT],
+            lead_lag: LeadLagEstimator::default(),
             hits: 0,
-            current_spread: FixedPoint8::ZERO,
+            spread_histogram: SpreadHistogram::new(Instant::now()),
+            threshold: DEFAULT_THRESHOLD,
+            threshold_source: ThresholdSource::Static,
+            threshold_epoch: None,
+            min_spread_ticks: FixedPoint8::ZERO,
+            current_spread: Bps::ZERO,
+            current_spread_ticks: None,
+            sessions: SessionStats::default(),
+            in_opportunity: false,
+            created: clock::now(),
         }
     }
 
+    /// Last time both venues were quoting: the older of the two latest tickers
+    pub fn last_active(&self) -> Instant {
+        self.updated_at.iter().map(|at| at.unwrap_or(self.created)).min().unwrap_or(self.created)
+    }
+
+    /// Estimated size including heap-allocated windows (bytes)
+    pub fn memory_bytes(&self) -> usize {
+        std::mem::size_of::<Self>()
+            + self.history.heap_bytes()
+            + self.liquidations.heap_bytes()
+            + self.estimators.iter().map(PriceEstimator::heap_bytes).sum::<usize>()
+            + self.spread_histogram.heap_bytes()
+    }
+
     /// Update state with new ticker and calculate spread
     pub fn update(&mut self, ticker: TickerData, exchange: Exchange) -> Option<SpreadEvent> {
-        match exchange {
-            Exchange::Binance => self.last_binance = Some(ticker),
-            Exchange::Bybit => self.last_bybit = Some(ticker),
+        let now = clock::now();
+        let slot = &mut self.tickers[exchange.index()];
+        self.turnover[exchange.index()].record(slot.as_ref(), &ticker);
+        self.lead_lag.record(exchange, &ticker);
+        *slot = Some(ticker);
+        self.updated_at[exchange.index()] = Some(now);
+
+        // Best pair across venues with fresh quotes (stale and suspended
+        // venues sit out; with two venues that leaves no pair)
+        let quotes = self.live_quotes();
+        let mut event = SpreadCalculator::calculate(self.symbol, &quotes)?;
+        event.spread_ticks = SpreadCalculator::spread_in_ticks(&event, &quotes, &self.tick_sizes);
+        self.current_spread = event.spread;
+        self.current_spread_ticks = event.spread_ticks;
+        self.history.push(event.spread.as_fraction());
+        self.spread_histogram.record(event.spread, now);
+
+        // Hit counting against the (possibly calibrated) threshold
+        if event.spread > self.threshold && !self.is_tick_noise(event.spread_ticks) {
+            self.hits += 1;
+        }
+        Some(event)
+    }
+
+    /// Latest tickers, without venues that are stale or not trading
+    #[inline]
+    fn live_quotes(&self) -> [Option<TickerData>; Exchange::COUNT] {
+        let mut quotes = self.tickers;
+        for exchange in Exchange::ALL {
+            let live = !self.stale[exchange.index()] && self.trading_status[exchange.index()] == TradingStatus::Trading;
+            if !live {
+                quotes[exchange.index()] = None;
+            }
         }
+        quotes
+    }
 
-        // If we have both tickers, calculate spread
-        if let (Some(binance), Some(bybit)) = (&self.last_binance, &self.last_bybit) {
-            if let Some(event) = SpreadCalculator::calculate(self.symbol, binance, bybit) {
-                self.current_spread = event.spread;
-                self.history.push(event.spread);
+    /// First venue whose last ticker is stale (None = all live)
+    #[inline]
+    pub fn stale_venue(&self) -> Option<Exchange> {
+        Exchange::ALL.into_iter().find(|e| self.stale[e.index()])
+    }
 
-                // Simple hit counting (threshold > 0.25%)
-                if event.spread.as_raw() > 250_000 {
-                    self.hits += 1;
-                }
+    /// First venue not in continuous trading, with its status (None = both trading)
+    #[inline]
+    pub fn suspended_venue(&self) -> Option<(Exchange, TradingStatus)> {
+        Exchange::ALL
+            .into_iter()
+            .map(|e| (e, self.trading_status[e.index()]))
+            .find(|&(_, status)| status != TradingStatus::Trading)
+    }
 
-                return Some(event);
+    /// Re-resolve threshold once per histogram epoch (every few minutes)
+    #[inline]
+    pub fn refresh_threshold(&mut self, policy: &ThresholdPolicy, now: Instant) {
+        self.min_spread_ticks = policy.min_spread_ticks();
+        if self.threshold_epoch == Some(self.spread_histogram.epoch()) {
+            return;
+        }
+        (self.threshold, self.threshold_source) = policy.resolve(self.symbol, &mut self.spread_histogram, now);
+        self.threshold_epoch = Some(self.spread_histogram.epoch());
+    }
+
+    /// Screen quote against the last accepted one on the same exchange
+    ///
+    /// Locked and crossed books are always rejected and counted, whether or
+    /// not sanity filters are enabled.
+    #[inline]
+    pub fn screen_quote(&mut self, ticker: &TickerData, exchange: Exchange, sanity: &QuoteSanity) -> Result<(), QuoteRejection> {
+        let anomalies = &mut self.book_anomalies[exchange.index()];
+        match ticker.book_state() {
+            BookState::Normal => {}
+            BookState::Locked => {
+                anomalies.locked += 1;
+                return Err(QuoteRejection::Locked);
             }
+            BookState::Crossed => {
+                anomalies.crossed += 1;
+                return Err(QuoteRejection::Crossed);
+            }
+        }
+        let last_mid = self.ticker(exchange).and_then(|t| t.mid_price());
+        sanity.check(ticker, last_mid, &mut self.quarantine_streak[exchange.index()])
+    }
+
+    /// Spread smaller than the minimum tick count (unknown tick sizes never are)
+    #[inline]
+    fn is_tick_noise(&self, spread_ticks: Option<FixedPoint8>) -> bool {
+        spread_ticks.is_some_and(|ticks| {
+            ticks.checked_abs().unwrap_or(FixedPoint8::MAX) < self.min_spread_ticks
+        })
+    }
+
+    /// Update latest mark price for exchange
+    #[inline]
+    pub fn update_mark(&mut self, mark: MarkPriceData, exchange: Exchange) {
+        self.marks[exchange.index()] = Some(mark);
+    }
+
+    /// Record liquidation notional into rolling window
+    #[inline]
+    pub fn record_liquidation(&mut self, liquidation: &LiquidationData) {
+        if let Some(notional) = liquidation.notional() {
+            self.liquidations.push(notional);
         }
+    }
+
+    /// Record trade into the exchange's price estimator
+    #[inline]
+    pub fn record_trade(&mut self, trade: &TradeData, exchange: Exchange) {
+        self.estimators[exchange.index()].record_trade(trade.price, trade.quantity);
+    }
+
+    /// Price estimator for exchange
+    #[inline]
+    pub fn estimator(&mut self, exchange: Exchange) -> &mut PriceEstimator {
+        &mut self.estimators[exchange.index()]
+    }
+
+    /// Highest relative price range across exchanges over the window
+    pub fn price_volatility(&mut self) -> FixedPoint8 {
+        self.estimators
+            .iter_mut()
+            .map(|e| e.volatility())
+            .max()
+            .unwrap_or(FixedPoint8::ZERO)
+    }
+
+    /// Seed exchange estimator from 1-minute klines (oldest first)
+    pub fn seed_klines(&mut self, exchange: Exchange, klines: &[Kline], now_ms: u64) {
+        let now = Instant::now();
+        let estimator = &mut self.estimators[exchange.index()];
+        for kline in klines {
+            let close_ms = (kline.open_time + KLINE_INTERVAL_MS).min(now_ms);
+            if let Some(at) = instant_at(now, now_ms, close_ms) {
+                estimator.seed(kline, at);
+            }
+        }
+    }
+
+    /// Seed spread history from minute closes present on both exchanges
+    ///
+    /// Closes are treated as zero-width quotes, so seeded spreads ignore the
+    /// bid/ask and only approximate the live (quote-based) history.
+    /// Returns the number of spreads seeded.
+    pub fn seed_spread(&mut self, binance: &[Kline], bybit: &[Kline], now_ms: u64) -> usize {
+        let now = Instant::now();
+        let mut seeded = 0;
+        for b in binance {
+            let Some(y) = bybit.iter().find(|y| y.open_time == b.open_time) else {
+                continue;
+            };
+            let close_ms = (b.open_time + KLINE_INTERVAL_MS).min(now_ms);
+            let Some(at) = instant_at(now, now_ms, close_ms) else {
+                continue;
+            };
+            let quote = |price| TickerData::new(self.symbol, price, FixedPoint8::ZERO, price, FixedPoint8::ZERO, close_ms);
+            let mut quotes = [None; Exchange::COUNT];
+            quotes[Exchange::Binance.index()] = Some(quote(b.close));
+            quotes[Exchange::Bybit.index()] = Some(quote(y.close));
+            if let Some(event) = SpreadCalculator::calculate(self.symbol, &quotes) {
+                self.history.push_at(event.spread.as_fraction(), at);
+                seeded += 1;
+            }
+        }
+        seeded
+    }
+
+    /// Latest mark price for exchange
+    #[inline]
+    pub fn mark(&self, exchange: Exchange) -> Option<&MarkPriceData> {
+        self.marks[exchange.index()].as_ref()
+    }
+
+    /// Latest ticker for exchange
+    #[inline]
+    pub fn ticker(&self, exchange: Exchange) -> Option<&TickerData> {
+        self.tickers[exchange.index()].as_ref()
+    }
+
+    /// Every exchange has sent a ticker
+    #[inline]
+    pub fn is_quoted(&self) -> bool {
+        self.tickers.iter().all(Option::is_some)
+    }
+
+    /// Latest ticker of every exchange (None until all have quoted)
+    pub fn quoted(&self) -> Option<[TickerData; Exchange::COUNT]> {
+        self.is_quoted().then(|| self.tickers.map(|t| t.expect("checked by is_quoted")))
+    }
+
+    /// Probability the `long_ex` ask and `short_ex` bid both survive `latency`
+    #[inline]
+    pub fn fill_probability(&self, long_ex: Exchange, short_ex: Exchange, latency: Duration) -> FixedPoint8 {
+        fill_probability(&self.turnover[long_ex.index()], &self.turnover[short_ex.index()], latency)
+    }
+
+    /// Spread range over 2-minute window: |min| + max
+    pub fn spread_range(&mut self) -> Bps {
+        let (min, max) = self.history.min_max();
+        min.checked_abs()
+            .and_then(|abs_min| abs_min.checked_add(max))
+            .map_or(Bps::ZERO, Bps::from_fraction)
+    }
 
-        None
+    /// P1 and P99 of the spread over the 2-minute window (None = no spreads yet)
+    pub fn spread_percentiles(&mut self) -> Option<(Bps, Bps)> {
+        let p1 = self.history.quantile(0.01)?;
+        let p99 = self.history.quantile(0.99)?;
+        Some((Bps::from_fraction(p1), Bps::from_fraction(p99)))
     }
 
     /// Get aggregated statistics for dashboard
     ///
     /// range2m = |min| + max (over 2-minute window)
+    /// robust_range = |P1| + P99 (same window, a few glitched quotes cannot move it)
     /// is_spread_na = true when min and max have the same sign (no arbitrage opportunity)
-    pub fn get_stats(&mut self) -> ScreenerStats {
+    /// edge_after_costs = best current spread - break-even (see CostModel)
+    pub fn get_stats(&mut self, costs: &CostModel) -> ScreenerStats {
         let (min, max) = self.history.min_max();
 
         // range2m = |min| + max
         let spread_range = min
             .checked_abs()
             .and_then(|abs_min| abs_min.checked_add(max))
-            .unwrap_or(FixedPoint8::ZERO);
+            .map_or(Bps::ZERO, Bps::from_fraction);
 
         // is_spread_na: true when min and max have same sign (no arbitrage)
         // Arbitrage opportunity exists when spreads cross zero (one exchange cheaper, other expensive)
@@ -87,31 +413,147 @@ impl SymbolState {
             || (min.is_negative() && max.is_negative())
             || (min.is_zero() && max.is_zero());
 
+        let (break_even, edge_after_costs) = self.edge(costs);
+        let percentiles = self.spread_percentiles();
+        let robust_range = percentiles
+            .and_then(|(p1, p99)| p1.checked_abs()?.checked_add(p99));
+
         ScreenerStats {
             symbol: self.symbol,
             current_spread: self.current_spread,
+            spread_ticks: self.current_spread_ticks,
+            break_even,
+            edge_after_costs,
             spread_range,
+            spread_p1: percentiles.map(|(p1, _)| p1),
+            spread_p99: percentiles.map(|(_, p99)| p99),
+            robust_range,
             hits: self.hits,
-            is_valid: self.last_binance.is_some() && self.last_bybit.is_some() && !is_spread_na,
+            threshold: self.threshold,
+            threshold_source: self.threshold_source,
+            liquidation_volume: self.liquidations.sum(),
+            price_volatility: self.price_volatility(),
+            stale_venue: self.stale_venue(),
+            suspended: self.suspended_venue(),
+            is_valid: self.is_quoted()
+                && self.stale_venue().is_none()
+                && self.suspended_venue().is_none()
+                && !is_spread_na
+                && !self.is_tick_noise(self.current_spread_ticks),
+        }
+    }
+
+    /// Bucket opportunity update by time of day (net of break-even)
+    fn record_session(&mut self, event: &SpreadEvent, costs: &CostModel) {
+        if event.spread <= OPPORTUNITY_SPREAD {
+            self.in_opportunity = false;
+            return;
         }
+        let (_, net) = self.edge(costs);
+        self.sessions.record(event.timestamp, net.as_fraction(), !self.in_opportunity);
+        self.in_opportunity = true;
+    }
+
+    /// Break-even and net edge for the best current direction
+    /// Returns (ZERO, ZERO) until two venues are quoting.
+    fn edge(&self, costs: &CostModel) -> (Bps, Bps) {
+        let Some(event) = SpreadCalculator::calculate(self.symbol, &self.tickers) else {
+            return (Bps::ZERO, Bps::ZERO);
+        };
+        let (Some(buy), Some(sell)) = (self.ticker(event.long_ex), self.ticker(event.short_ex)) else {
+            return (Bps::ZERO, Bps::ZERO);
+        };
+        let breakdown = costs.break_even(
+            &event,
+            buy,
+            sell,
+            self.mark(event.long_ex),
+            self.mark(event.short_ex),
+        );
+        let break_even = Bps::from_fraction(breakdown.break_even);
+        let edge = event.spread.checked_sub(break_even).unwrap_or(Bps::MIN);
+        (break_even, edge)
     }
 }
 
 /// Stats for API/Dashboard
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ScreenerStats {
     pub symbol: Symbol,
-    pub current_spread: FixedPoint8,
-    pub spread_range: FixedPoint8,
+    pub current_spread: Bps,
+    /// Current spread in ticks of the coarser venue (None = tick sizes unknown)
+    pub spread_ticks: Option<FixedPoint8>,
+    /// Spread needed to cover fees, slippage and funding
+    pub break_even: Bps,
+    /// Best current spread net of break-even (negative = loses money)
+    pub edge_after_costs: Bps,
+    pub spread_range: Bps,
+    /// Spread percentiles over the 2-minute window (None = no spreads yet)
+    pub spread_p1: Option<Bps>,
+    pub spread_p99: Option<Bps>,
+    /// |P1| + P99: outlier-robust counterpart of `spread_range`
+    pub robust_range: Option<Bps>,
     pub hits: u64,
+    /// Hit threshold (static, calibrated or override)
+    pub threshold: Bps,
+    pub threshold_source: ThresholdSource,
+    /// Liquidated notional over the 2-minute window (quote currency)
+    pub liquidation_volume: FixedPoint8,
+    /// Relative price range over the 2-minute window (max across exchanges)
+    pub price_volatility: FixedPoint8,
+    /// Venue whose ticker is stale after an outage (None = live)
+    pub stale_venue: Option<Exchange>,
+    /// Venue where the symbol is halted or in auction, and that status
+    pub suspended: Option<(Exchange, TradingStatus)>,
     pub is_valid: bool,
 }
 
+/// Latest tickers of one symbol on every venue
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct QuotePair {
+    pub symbol: Symbol,
+    /// Indexed by `Exchange::index`
+    pub tickers: [TickerData; Exchange::COUNT],
+    /// Local arrival of each ticker
+    pub updated_at: [Option<Instant>; Exchange::COUNT],
+}
+
+/// Facts kept for an evicted symbol (venue metadata, not market state)
+#[derive(Debug, Clone, Copy)]
+struct Retained {
+    tick_sizes: [Option<FixedPoint8>; Exchange::COUNT],
+    trading_status: [TradingStatus; Exchange::COUNT],
+}
+
+/// State for `symbol`, restoring what survived its eviction
+fn revive(retained: &mut HashMap<Symbol, Retained>, symbol: Symbol) -> SymbolState {
+    let mut state = SymbolState::new(symbol);
+    if let Some(kept) = retained.remove(&symbol) {
+        state.tick_sizes = kept.tick_sizes;
+        state.trading_status = kept.trading_status;
+    }
+    state
+}
+
 /// Global tracker holding all symbol states
 /// Pre-allocated array for O(1) lookup, no runtime allocation
 pub struct ThresholdTracker {
     /// States indexed by Symbol ID (pre-allocated)
     states: Vec<Option<SymbolState>>,
+    /// Break-even model for edge_after_costs
+    costs: CostModel,
+    /// Per-symbol hit threshold resolution
+    thresholds: ThresholdPolicy,
+    /// Malformed quote quarantine
+    sanity: QuoteSanity,
+    /// Withdrawal costs for inventory rebalance opportunities
+    transfers: TransferModel,
+    /// Venues with no connected feed (indexed by Exchange::index)
+    venue_down: [bool; Exchange::COUNT],
+    /// Metadata of evicted symbols, restored on re-creation
+    retained: HashMap<Symbol, Retained>,
+    /// Symbols evicted since startup
+    evictions: u64,
 }
 
 impl ThresholdTracker {
@@ -121,7 +563,85 @@ impl ThresholdTracker {
         for _ in 0..MAX_SYMBOLS {
             states.push(None);
         }
-        Self { states }
+        Self {
+            states,
+            costs: CostModel::default(),
+            thresholds: ThresholdPolicy::default(),
+            sanity: QuoteSanity::default(),
+            transfers: TransferModel::default(),
+            venue_down: [false; Exchange::COUNT],
+            retained: HashMap::new(),
+            evictions: 0,
+        }
+    }
+
+    /// Set break-even cost model
+    pub fn set_cost_model(&mut self, costs: CostModel) {
+        self.costs = costs;
+    }
+
+    /// Break-even cost model
+    pub fn cost_model_mut(&mut self) -> &mut CostModel {
+        &mut self.costs
+    }
+
+    /// Set inventory transfer model
+    pub fn set_transfer_model(&mut self, transfers: TransferModel) {
+        self.transfers = transfers;
+    }
+
+    /// Set threshold policy (takes effect on each symbol's next update)
+    pub fn set_threshold_policy(&mut self, policy: ThresholdPolicy) {
+        self.thresholds = policy;
+        for state in self.states.iter_mut().flatten() {
+            state.threshold_epoch = None;
+        }
+    }
+
+    /// Set quote sanity policy
+    pub fn set_quote_sanity(&mut self, sanity: QuoteSanity) {
+        self.sanity = sanity;
+    }
+
+    /// Number of symbols with state (capacity is MAX_SYMBOLS)
+    pub fn occupancy(&self) -> usize {
+        self.states.iter().filter(|s| s.is_some()).count()
+    }
+
+    /// Drop symbols whose venues have not both quoted within `idle`
+    ///
+    /// Returns the number evicted; their state is recreated on the next update.
+    pub fn evict_idle(&mut self, idle: Duration, now: Instant) -> usize {
+        let mut evicted = 0;
+        for slot in self.states.iter_mut() {
+            let Some(state) = slot.as_ref() else { continue };
+            if now.saturating_duration_since(state.last_active()) < idle {
+                continue;
+            }
+            self.retained.insert(state.symbol, Retained { tick_sizes: state.tick_sizes, trading_status: state.trading_status });
+            *slot = None;
+            evicted += 1;
+        }
+        self.evictions += evicted as u64;
+        evicted
+    }
+
+    /// Symbols evicted since startup
+    #[inline]
+    pub fn evictions(&self) -> u64 {
+        self.evictions
+    }
+
+    /// Estimated memory held by symbol states, including empty slots (bytes)
+    pub fn memory_bytes(&self) -> usize {
+        let slots = self.states.capacity() * std::mem::size_of::<Option<SymbolState>>();
+        let heap: usize = self
+            .states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .map(|s| s.memory_bytes() - std::mem::size_of::<SymbolState>())
+            .sum();
+        slots + heap
     }
 
     /// Update tracker with new ticker (hot path)
@@ -135,19 +655,249 @@ impl ThresholdTracker {
         }
 
         // Get or create state
-        let state = self.states[id].get_or_insert_with(|| SymbolState::new(ticker.symbol));
+        let state = self.states[id].get_or_insert_with(|| revive(&mut self.retained, ticker.symbol));
+
+        state.refresh_threshold(&self.thresholds, clock::now());
+        // Tickers still queued from a dead connection do not count as fresh
+        if !self.venue_down[exchange.index()] {
+            state.stale[exchange.index()] = false;
+        }
+        let event = state.update(ticker, exchange)?;
+        state.record_session(&event, &self.costs);
+        Some(event)
+    }
+
+    /// Mark a venue down (all its tickers stale) or back up
+    ///
+    /// Tickers stay stale after recovery until the venue sends a fresh one
+    /// for the symbol. Returns the number of symbols with a ticker from it.
+    pub fn set_venue_down(&mut self, exchange: Exchange, down: bool) -> usize {
+        self.venue_down[exchange.index()] = down;
+        let mut affected = 0;
+        for state in self.states.iter_mut().filter_map(|s| s.as_mut()) {
+            let has_ticker = state.tickers[exchange.index()].is_some();
+            if down && has_ticker {
+                state.stale[exchange.index()] = true;
+            }
+            affected += has_ticker as usize;
+        }
+        affected
+    }
+
+    /// Venue currently has no connected feed
+    #[inline]
+    pub fn is_venue_down(&self, exchange: Exchange) -> bool {
+        self.venue_down[exchange.index()]
+    }
+
+    /// Set the trading status of `symbol` on `exchange`; true if it changed
+    pub fn set_trading_status(&mut self, symbol: Symbol, exchange: Exchange, status: TradingStatus) -> bool {
+        let Some(state) = self.state_or_insert(symbol) else {
+            return false;
+        };
+        std::mem::replace(&mut state.trading_status[exchange.index()], status) != status
+    }
 
-        state.update(ticker, exchange)
+    /// Venue where `symbol` is halted or in auction (None = trading on both)
+    pub fn suspension(&self, symbol: Symbol) -> Option<(Exchange, TradingStatus)> {
+        self.states
+            .get(symbol.as_raw() as usize)
+            .and_then(|s| s.as_ref())
+            .and_then(|s| s.suspended_venue())
+    }
+
+    /// Screen ticker before it reaches strategies and the spread history
+    ///
+    /// Rejected quotes leave no trace in the tracker (quarantined).
+    pub fn screen_quote(&mut self, ticker: &TickerData, exchange: Exchange) -> Result<(), QuoteRejection> {
+        let sanity = self.sanity;
+        let Some(state) = self.state_or_insert(ticker.symbol) else {
+            return Ok(());
+        };
+        state.screen_quote(ticker, exchange, &sanity)
+    }
+
+    /// Update mark price for symbol (warm path)
+    /// O(1) array access by Symbol ID
+    pub fn update_mark_price(&mut self, mark: MarkPriceData, exchange: Exchange) {
+        let id = mark.symbol.as_raw() as usize;
+        if id >= MAX_SYMBOLS {
+            return;
+        }
+
+        let state = self.states[id].get_or_insert_with(|| revive(&mut self.retained, mark.symbol));
+        state.update_mark(mark, exchange);
+    }
+
+    /// Record liquidation for symbol (warm path)
+    /// O(1) array access by Symbol ID
+    pub fn record_liquidation(&mut self, liquidation: LiquidationData) {
+        let id = liquidation.symbol.as_raw() as usize;
+        if id >= MAX_SYMBOLS {
+            return;
+        }
+
+        let state = self.states[id].get_or_insert_with(|| revive(&mut self.retained, liquidation.symbol));
+        state.record_liquidation(&liquidation);
+    }
+
+    /// Record trade for symbol (warm path)
+    /// O(1) array access by Symbol ID
+    pub fn record_trade(&mut self, trade: &TradeData, exchange: Exchange) {
+        let id = trade.symbol.as_raw() as usize;
+        if id >= MAX_SYMBOLS {
+            return;
+        }
+
+        let state = self.states[id].get_or_insert_with(|| revive(&mut self.retained, trade.symbol));
+        state.record_trade(trade, exchange);
+    }
+
+    /// Get or create state for symbol (cold path: warm-up seeding)
+    pub fn state_or_insert(&mut self, symbol: Symbol) -> Option<&mut SymbolState> {
+        let slot = self.states.get_mut(symbol.as_raw() as usize)?;
+        Some(slot.get_or_insert_with(|| revive(&mut self.retained, symbol)))
+    }
+
+    /// Latest accepted top of book for symbol on exchange
+    pub fn ticker(&self, symbol: Symbol, exchange: Exchange) -> Option<TickerData> {
+        self.states
+            .get(symbol.as_raw() as usize)?
+            .as_ref()?
+            .ticker(exchange)
+            .copied()
+    }
+
+    /// Net edges of a spread event against the current books (None without both tickers)
+    pub fn net_edges(&self, event: &SpreadEvent) -> Option<NetEdges> {
+        let state = self.states.get(event.symbol.as_raw() as usize)?.as_ref()?;
+        let buy = state.ticker(event.long_ex)?;
+        let sell = state.ticker(event.short_ex)?;
+        Some(self.costs.net_edges(
+            event,
+            buy,
+            sell,
+            state.mark(event.long_ex),
+            state.mark(event.short_ex),
+            &state.tick_sizes,
+        ))
+    }
+
+    /// Latest mark price for symbol on exchange
+    pub fn mark_price(&self, symbol: Symbol, exchange: Exchange) -> Option<MarkPriceData> {
+        self.states
+            .get(symbol.as_raw() as usize)?
+            .as_ref()?
+            .mark(exchange)
+            .copied()
+    }
+
+    /// Mutable state for symbol (None until first update)
+    pub fn state_mut(&mut self, symbol: Symbol) -> Option<&mut SymbolState> {
+        self.states.get_mut(symbol.as_raw() as usize)?.as_mut()
+    }
+
+    /// Set price tick size for symbol on exchange (cold path: instruments refresh)
+    pub fn set_tick_size(&mut self, symbol: Symbol, exchange: Exchange, tick: FixedPoint8) {
+        if let Some(state) = self.state_or_insert(symbol) {
+            state.tick_sizes[exchange.index()] = Some(tick);
+        }
+    }
+
+    /// Locked/crossed quote counts per exchange (zero before the first update)
+    pub fn book_anomalies(&self, symbol: Symbol) -> [BookAnomalies; Exchange::COUNT] {
+        self.states
+            .get(symbol.as_raw() as usize)
+            .and_then(|s| s.as_ref())
+            .map_or([BookAnomalies::default(); Exchange::COUNT], |s| s.book_anomalies)
+    }
+
+    /// Local arrival of the latest ticker per exchange (None before the first)
+    pub fn updated_at(&self, symbol: Symbol) -> [Option<Instant>; Exchange::COUNT] {
+        self.states
+            .get(symbol.as_raw() as usize)
+            .and_then(|s| s.as_ref())
+            .map_or([None; Exchange::COUNT], |s| s.updated_at)
+    }
+
+    /// Latest ticker of every symbol on every venue
+    pub fn latest_tickers(&self) -> Vec<(Exchange, TickerData)> {
+        self.states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .flat_map(|s| Exchange::ALL.into_iter().filter_map(|e| Some((e, *s.ticker(e)?))))
+            .collect()
+    }
+
+    /// Session analytics for all symbols with at least one opportunity
+    pub fn session_stats(&self) -> Vec<(Symbol, SessionStats)> {
+        self.states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .filter(|s| s.sessions.opportunities() > 0)
+            .map(|s| (s.symbol, s.sessions))
+            .collect()
+    }
+
+    /// Lead-lag estimates for all symbols with enough moves, strongest first
+    pub fn lead_lag(&self) -> Vec<(Symbol, LeadLag)> {
+        let mut estimates: Vec<(Symbol, LeadLag)> = self
+            .states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .filter_map(|s| s.lead_lag.estimate().map(|l| (s.symbol, l)))
+            .collect();
+        estimates.sort_by(|a, b| b.1.correlation.total_cmp(&a.1.correlation));
+        estimates
+    }
+
+    /// Latest tickers of every symbol quoted on both venues
+    ///
+    /// All symbols are read in one pass over the tracker, so under a single
+    /// lock the pairs form a time-consistent cross-section.
+    pub fn quote_pairs(&self) -> Vec<QuotePair> {
+        self.states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .filter_map(|s| {
+                Some(QuotePair {
+                    symbol: s.symbol,
+                    tickers: s.quoted()?,
+                    updated_at: s.updated_at,
+                })
+            })
+            .collect()
+    }
+
+    /// Inventory rebalance opportunities, best net edge first
+    ///
+    /// Evaluated on demand from the latest books; only symbols whose base
+    /// asset has transfer parameters and data from at least two exchanges.
+    pub fn rebalance_opportunities(&self) -> Vec<RebalanceOpportunity> {
+        if self.transfers.is_empty() {
+            return Vec::new();
+        }
+        let mut opportunities: Vec<RebalanceOpportunity> = self
+            .states
+            .iter()
+            .filter_map(|s| s.as_ref())
+            .filter_map(|s| {
+                self.transfers.evaluate(s.symbol, &s.tickers, &self.costs)
+            })
+            .collect();
+        opportunities.sort_by_key(|opp| std::cmp::Reverse(opp.net_edge));
+        opportunities
     }
 
     /// Get stats for all active symbols
     /// Filter: only symbols with data from BOTH exchanges (AND logic)
     pub fn get_all_stats(&mut self) -> Vec<ScreenerStats> {
+        let costs = &self.costs;
         self.states
             .iter_mut()
             .filter_map(|s| s.as_mut())
-            .filter(|s| s.last_binance.is_some() && s.last_bybit.is_some()) // AND logic
-            .map(|s| s.get_stats())
+            .filter(|s| s.is_quoted()) // AND logic
+            .map(|s| s.get_stats(costs))
             .collect()
     }
 }
@@ -159,11 +909,11 @@ impl Default for ThresholdTracker {
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::infrastructure::config::{SanityConfig, ThresholdConfig};
+    use crate::test_utils::init_test_registry;
+    use std::collections::HashMap;
 
     fn make_ticker(symbol: Symbol, price: i64) -> TickerData {
         TickerData {
@@ -173,6 +923,7 @@ mod tests {
             bid_qty: FixedPoint8::ONE,
             ask_qty: FixedPoint8::ONE,
             timestamp: 1000,
+            update_id: 0,
         }
     }
 
@@ -182,9 +933,11 @@ mod tests {
         let mut tracker = ThresholdTracker::new();
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
 
+        assert_eq!(tracker.occupancy(), 0);
         tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
         let stats = tracker.get_all_stats();
         assert_eq!(stats.len(), 0);
+        assert_eq!(tracker.occupancy(), 1);
 
         tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
         assert!(tracker
@@ -192,6 +945,276 @@ mod tests {
             .is_some());
     }
 
+    #[test]
+    fn test_venue_down_suppresses_spreads() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
+
+        assert_eq!(tracker.set_venue_down(Exchange::Bybit, true), 1);
+        assert!(tracker.is_venue_down(Exchange::Bybit));
+        assert!(tracker.update(make_ticker(sym, 99_000_000), Exchange::Binance).is_none());
+        // A ticker still queued from the dead connection is not fresh
+        assert!(tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit).is_none());
+        let stats = tracker.get_all_stats();
+        assert_eq!(stats[0].stale_venue, Some(Exchange::Bybit));
+        assert!(!stats[0].is_valid);
+
+        // Back up: stale until Bybit sends a fresh ticker
+        tracker.set_venue_down(Exchange::Bybit, false);
+        assert!(tracker.update(make_ticker(sym, 99_000_000), Exchange::Binance).is_none());
+        assert!(tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit).is_some());
+        assert_eq!(tracker.get_all_stats()[0].stale_venue, None);
+    }
+
+    #[test]
+    fn test_halted_symbol_suppresses_spreads() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
+
+        assert!(tracker.set_trading_status(sym, Exchange::Bybit, TradingStatus::Auction));
+        assert!(!tracker.set_trading_status(sym, Exchange::Bybit, TradingStatus::Auction));
+        assert_eq!(tracker.suspension(sym), Some((Exchange::Bybit, TradingStatus::Auction)));
+        assert!(tracker.update(make_ticker(sym, 99_000_000), Exchange::Binance).is_none());
+        let stats = tracker.get_all_stats();
+        assert_eq!(stats[0].suspended, Some((Exchange::Bybit, TradingStatus::Auction)));
+        assert!(!stats[0].is_valid);
+
+        assert!(tracker.set_trading_status(sym, Exchange::Bybit, TradingStatus::Trading));
+        assert_eq!(tracker.suspension(sym), None);
+        assert!(tracker.update(make_ticker(sym, 99_000_000), Exchange::Binance).is_some());
+    }
+
+    #[test]
+    fn test_idle_symbols_evicted_and_recreated() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        tracker.update(make_ticker(btc, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(btc, 101_000_000), Exchange::Bybit);
+        // ETH only quotes on one venue
+        tracker.update(make_ticker(eth, 100_000_000), Exchange::Binance);
+        tracker.set_tick_size(eth, Exchange::Binance, FixedPoint8::from_raw(1_000_000));
+        tracker.set_trading_status(eth, Exchange::Bybit, TradingStatus::Halted);
+        let slots = MAX_SYMBOLS * std::mem::size_of::<Option<SymbolState>>();
+        let before = tracker.memory_bytes();
+        assert!(before > slots + 2 * tracker.states[btc.as_raw() as usize].as_ref().unwrap().spread_histogram.heap_bytes());
+
+        let idle = Duration::from_secs(600);
+        let now = clock::now();
+        assert_eq!(tracker.evict_idle(idle, now), 0);
+        tracker.state_mut(eth).unwrap().created = now - idle;
+        assert_eq!(tracker.evict_idle(idle, now), 1);
+        assert_eq!((tracker.occupancy(), tracker.evictions()), (1, 1));
+        assert!(tracker.memory_bytes() < before);
+
+        // Recreated lazily, venue metadata restored
+        tracker.update(make_ticker(eth, 100_000_000), Exchange::Binance);
+        let state = tracker.state_mut(eth).unwrap();
+        assert_eq!(state.tick_sizes[Exchange::Binance.index()], Some(FixedPoint8::from_raw(1_000_000)));
+        assert_eq!(tracker.suspension(eth), Some((Exchange::Bybit, TradingStatus::Halted)));
+        assert_eq!(tracker.evict_idle(idle, clock::now() + idle), 2);
+        assert_eq!(tracker.occupancy(), 0);
+    }
+
+    #[test]
+    fn test_tracker_mark_price() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        assert!(tracker.mark_price(sym, Exchange::Binance).is_none());
+
+        let mark = MarkPriceData::new(
+            sym,
+            FixedPoint8::from_raw(100_000_000),
+            FixedPoint8::from_raw(99_900_000),
+            FixedPoint8::ZERO,
+            0,
+            1000,
+        );
+        tracker.update_mark_price(mark, Exchange::Bybit);
+
+        assert_eq!(tracker.mark_price(sym, Exchange::Bybit), Some(mark));
+        assert!(tracker.mark_price(sym, Exchange::Binance).is_none());
+        // Mark alone does not make a symbol visible in the screener
+        assert_eq!(tracker.get_all_stats().len(), 0);
+    }
+
+    #[test]
+    fn test_liquidation_volume() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(sym, 100_100_000), Exchange::Bybit);
+
+        let liq = |price: i64, qty: i64| {
+            LiquidationData::new(
+                sym,
+                FixedPoint8::from_raw(price * FixedPoint8::SCALE),
+                FixedPoint8::from_raw(qty * FixedPoint8::SCALE),
+                1000,
+                crate::core::Side::Sell,
+            )
+        };
+        tracker.record_liquidation(liq(100, 2));
+        tracker.record_liquidation(liq(50, 1));
+
+        let stats = tracker.get_all_stats();
+        assert_eq!(stats.len(), 1);
+        assert_eq!(stats[0].liquidation_volume.as_raw(), 250 * FixedPoint8::SCALE);
+    }
+
+    #[test]
+    fn test_edge_after_costs() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        // Binance ask 1.0000001, Bybit bid 1.01 → ~1% gross
+        tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
+
+        let stats = tracker.get_all_stats();
+        assert_eq!(stats.len(), 1);
+        assert!(stats[0].break_even.is_positive());
+        assert_eq!(
+            stats[0].edge_after_costs,
+            stats[0].current_spread.checked_sub(stats[0].break_even).unwrap()
+        );
+    }
+
+    #[test]
+    fn test_session_stats() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        // 2024-01-01 (Monday) 13:00 UTC
+        let at = (19_723 * 24 + 13) * 3_600_000_000_000;
+        let ticker = |bid: i64, ask: i64| TickerData::new(
+            sym,
+            FixedPoint8::from_raw(bid),
+            FixedPoint8::ONE,
+            FixedPoint8::from_raw(ask),
+            FixedPoint8::ONE,
+            at,
+        );
+
+        // Bybit bid 1% above Binance ask: one opportunity, two samples
+        tracker.update(ticker(100_000_000, 100_000_000), Exchange::Binance);
+        tracker.update(ticker(101_000_000, 101_000_000), Exchange::Bybit);
+        tracker.update(ticker(101_000_000, 101_000_000), Exchange::Bybit);
+        // Back inside the threshold, then a second opportunity
+        tracker.update(ticker(100_000_000, 100_000_000), Exchange::Bybit);
+        tracker.update(ticker(101_000_000, 101_000_000), Exchange::Bybit);
+
+        let sessions = tracker.session_stats();
+        assert_eq!(sessions.len(), 1);
+        let stats = sessions[0].1;
+        assert_eq!(stats.hours[13].opportunities, 2);
+        assert_eq!(stats.hours[13].samples, 3);
+        assert_eq!(stats.weekdays[0].opportunities, 2);
+        assert!(stats.hours[13].average_net().is_positive());
+    }
+
+    #[test]
+    fn test_threshold_override_counts_hits() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let config = ThresholdConfig {
+            overrides: HashMap::from([("BTCUSDT".to_string(), 0.02)]),
+            ..Default::default()
+        };
+        tracker.set_threshold_policy(ThresholdPolicy::new(&config, DEFAULT_THRESHOLD));
+
+        // ~1% spread: above the 0.25% default, below the 2% override
+        tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+        tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
+
+        let stats = tracker.get_all_stats();
+        assert_eq!(stats[0].hits, 0);
+        assert_eq!(stats[0].threshold, Bps::from_bps(200));
+        assert_eq!(stats[0].threshold_source, ThresholdSource::Override);
+    }
+
+    #[test]
+    fn test_tick_noise_filter() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let (coarse, fine) = (Symbol::from_bytes(b"DOGEUSDT").unwrap(), Symbol::from_bytes(b"ADAUSDT").unwrap());
+        let config = ThresholdConfig { min_spread_ticks: 3.0, ..Default::default() };
+        tracker.set_threshold_policy(ThresholdPolicy::new(&config, DEFAULT_THRESHOLD));
+        for exchange in Exchange::ALL {
+            tracker.set_tick_size(coarse, exchange, FixedPoint8::from_raw(1_000_000)); // 0.01
+            tracker.set_tick_size(fine, exchange, FixedPoint8::from_raw(10_000)); // 0.0001
+        }
+
+        // ~1% spread on a 1.00 price: one 0.01 tick, ~100 ticks of 0.0001
+        for sym in [coarse, fine] {
+            tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
+            tracker.update(make_ticker(sym, 101_000_000), Exchange::Bybit);
+        }
+
+        let stats = tracker.get_all_stats();
+        let row = |sym| stats.iter().find(|s| s.symbol == sym).unwrap();
+        assert!(row(coarse).spread_ticks.unwrap() < FixedPoint8::ONE);
+        assert_eq!(row(coarse).hits, 0);
+        assert!(!row(coarse).is_valid);
+        assert!(row(fine).spread_ticks.unwrap() > FixedPoint8::from_raw(99 * FixedPoint8::SCALE));
+        assert_eq!(row(fine).hits, 1);
+    }
+
+    #[test]
+    fn test_screen_quote_quarantines_glitch() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"SOLUSDT").unwrap();
+        let good = make_ticker(sym, 100 * FixedPoint8::SCALE);
+        assert_eq!(tracker.screen_quote(&good, Exchange::Binance), Ok(()));
+        tracker.update(good, Exchange::Binance);
+
+        // 100x off on the same venue is quarantined, other venue unaffected
+        let glitch = make_ticker(sym, 10_000 * FixedPoint8::SCALE);
+        assert_eq!(tracker.screen_quote(&glitch, Exchange::Binance), Err(QuoteRejection::Deviation));
+        assert_eq!(tracker.screen_quote(&glitch, Exchange::Bybit), Ok(()));
+        assert_eq!(
+            tracker.screen_quote(&make_ticker(sym, 0), Exchange::Bybit),
+            Err(QuoteRejection::NonPositive)
+        );
+    }
+
+    #[test]
+    fn test_locked_and_crossed_books_counted_and_excluded() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        tracker.set_quote_sanity(QuoteSanity::new(&SanityConfig { enabled: false, ..Default::default() }));
+        let sym = Symbol::from_bytes(b"BNBUSDT").unwrap();
+        let mut locked = make_ticker(sym, 100 * FixedPoint8::SCALE);
+        locked.ask_price = locked.bid_price;
+        let mut crossed = locked;
+        crossed.bid_price = FixedPoint8::from_raw(101 * FixedPoint8::SCALE);
+
+        // Rejected even with sanity filters disabled
+        assert_eq!(tracker.screen_quote(&locked, Exchange::Binance), Err(QuoteRejection::Locked));
+        assert_eq!(tracker.screen_quote(&crossed, Exchange::Bybit), Err(QuoteRejection::Crossed));
+        assert_eq!(tracker.screen_quote(&crossed, Exchange::Bybit), Err(QuoteRejection::Crossed));
+        assert_eq!(
+            tracker.book_anomalies(sym),
+            [BookAnomalies { crossed: 0, locked: 1 }, BookAnomalies { crossed: 2, locked: 0 }]
+        );
+        // Nothing stored, so no spread can be computed from them
+        let state = tracker.state_mut(sym).unwrap();
+        assert!(state.tickers.iter().all(Option::is_none));
+    }
+
     #[test]
     fn test_tracker_preallocated() {
         let tracker = ThresholdTracker::new();
@@ -206,10 +1229,9 @@ mod tests {
         state.history.push(FixedPoint8::from_raw(100_000));
 
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
-        state.last_binance = Some(make_ticker(sym, 100_000_000));
-        state.last_bybit = Some(make_ticker(sym, 100_100_000));
+        state.tickers = [Some(make_ticker(sym, 100_000_000)), Some(make_ticker(sym, 100_100_000))];
 
-        let stats = state.get_stats();
+        let stats = state.get_stats(&CostModel::default());
         assert_eq!(stats.spread_range.as_raw(), 150_000);
         assert!(stats.is_valid);
     }
@@ -222,10 +1244,9 @@ mod tests {
         state.history.push(FixedPoint8::from_raw(100_000));
 
         let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
-        state.last_binance = Some(make_ticker(sym, 100_000_000));
-        state.last_bybit = Some(make_ticker(sym, 100_100_000));
+        state.tickers = [Some(make_ticker(sym, 100_000_000)), Some(make_ticker(sym, 100_100_000))];
 
-        let stats = state.get_stats();
+        let stats = state.get_stats(&CostModel::default());
         assert!(!stats.is_valid);
     }
 
@@ -238,6 +1259,8 @@ mod tests {
         tracker.update(make_ticker(sym, 100_000_000), Exchange::Binance);
         let stats = tracker.get_all_stats();
         assert_eq!(stats.len(), 0);
+        let [binance, bybit] = tracker.updated_at(sym);
+        assert!(binance.is_some() && bybit.is_none());
 
         let sym2 = Symbol::from_bytes(b"ETHUSDT").unwrap();
         tracker.update(make_ticker(sym2, 100_000_000), Exchange::Bybit);
@@ -248,6 +1271,65 @@ mod tests {
         let stats = tracker.get_all_stats();
         assert_eq!(stats.len(), 1);
     }
+
+    fn make_kline(open_time: u64, close: i64) -> Kline {
+        let close = FixedPoint8::from_raw(close);
+        Kline {
+            open_time,
+            open: close,
+            high: close,
+            low: close,
+            close,
+            volume: FixedPoint8::ONE,
+            quote_volume: close,
+        }
+    }
+
+    #[test]
+    fn test_seed_klines_warms_estimators() {
+        init_test_registry();
+        let mut tracker = ThresholdTracker::new();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let now_ms = 10_000_000;
+
+        let state = tracker.state_or_insert(sym).unwrap();
+        assert_eq!(state.price_volatility(), FixedPoint8::ZERO);
+
+        // Three 1-minute klines; the oldest closed 3 minutes ago (outside the window)
+        let klines = [
+            make_kline(now_ms - 240_000, 200 * 100_000_000),
+            make_kline(now_ms - 120_000, 100 * 100_000_000),
+            make_kline(now_ms - 60_000, 102 * 100_000_000),
+        ];
+        state.seed_klines(Exchange::Binance, &klines, now_ms);
+
+        assert!(state.estimator(Exchange::Binance).is_warm());
+        assert!(!state.estimator(Exchange::Bybit).is_warm());
+        assert_eq!(state.price_volatility(), FixedPoint8::from_raw(2_000_000)); // 2%
+        assert_eq!(
+            state.estimator(Exchange::Binance).vwap(),
+            Some(FixedPoint8::from_raw(101 * 100_000_000))
+        );
+    }
+
+    #[test]
+    fn test_seed_spread_aligned_closes() {
+        init_test_registry();
+        let mut state = SymbolState::new(Symbol::from_bytes(b"BTCUSDT").unwrap());
+        let now_ms = 10_000_000;
+
+        let binance = [
+            make_kline(now_ms - 120_000, 100 * 100_000_000),
+            make_kline(now_ms - 60_000, 100 * 100_000_000),
+        ];
+        // Only the last minute overlaps
+        let bybit = [make_kline(now_ms - 60_000, 101 * 100_000_000)];
+
+        assert_eq!(state.seed_spread(&binance, &bybit, now_ms), 1);
+        assert_eq!(state.history.len(), 1);
+        let one_pct = FixedPoint8::from_raw(1_000_000);
+        assert_eq!(state.history.min_max(), (one_pct, one_pct));
+    }
 }
 
 // HFT Hot Path Checklist verified:
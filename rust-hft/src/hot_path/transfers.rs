@@ -0,0 +1,167 @@
+//! Inventory rebalance opportunities (Warm Path)
+//!
+//! True cross-exchange arbitrage moves inventory: buy on the cheap venue,
+//! withdraw the asset and sell it on the expensive one. Unlike the instant
+//! perp-vs-perp spread this edge must also pay the withdrawal fee and stay
+//! open for the transfer time, so it is a slower opportunity class that is
+//! evaluated separately from the spread tracker's hit counting.
+//!
+//! Only assets with configured transfer parameters are evaluated.
+
+use crate::core::{Bps, FixedPoint8, Symbol, TickerData};
+use crate::exchanges::Exchange;
+use crate::hot_path::{CostModel, SpreadCalculator};
+use crate::infrastructure::config::TransferConfig;
+use std::collections::HashMap;
+use std::time::Duration;
+
+/// Withdrawal cost and time for one asset
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct AssetTransfer {
+    /// Withdrawal fee in asset units
+    pub fee: FixedPoint8,
+    /// Expected time until the deposit is credited
+    pub duration: Duration,
+}
+
+/// Rebalance opportunity for one symbol
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RebalanceOpportunity {
+    pub symbol: Symbol,
+    /// Venue the inventory is bought on and withdrawn from
+    pub buy_ex: Exchange,
+    /// Venue the inventory is deposited to and sold on
+    pub sell_ex: Exchange,
+    /// Gross spread (best direction)
+    pub spread: Bps,
+    /// Taker fees, one fill per venue
+    pub trading_fees: Bps,
+    /// Withdrawal fee relative to the reference notional
+    pub transfer_cost: Bps,
+    /// spread - trading fees - transfer cost
+    pub net_edge: Bps,
+    pub transfer_time: Duration,
+}
+
+/// Transfer parameters by base asset
+#[derive(Debug, Clone, Default)]
+pub struct TransferModel {
+    assets: HashMap<String, AssetTransfer>,
+}
+
+impl TransferModel {
+    /// Build model from config (cold path)
+    pub fn new(config: &TransferConfig) -> Self {
+        Self {
+            assets: config
+                .assets
+                .iter()
+                .map(|(asset, transfer)| {
+                    (
+                        asset.to_ascii_uppercase(),
+                        AssetTransfer {
+                            fee: FixedPoint8::from_f64(transfer.withdrawal_fee.max(0.0)).unwrap_or(FixedPoint8::MAX),
+                            duration: Duration::from_secs_f64(transfer.minutes.max(0.0) * 60.0),
+                        },
+                    )
+                })
+                .collect(),
+        }
+    }
+
+    /// No asset configured
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.assets.is_empty()
+    }
+
+    /// Transfer parameters for the base asset of `symbol` (USDT pairs)
+    pub fn asset(&self, symbol: Symbol) -> Option<&AssetTransfer> {
+        let base = symbol.as_str().strip_suffix("USDT").filter(|b| !b.is_empty())?;
+        self.assets.get(base)
+    }
+
+    /// Evaluate the rebalance trade for the best current direction
+    ///
+    /// None if the asset has no transfer parameters or fewer than two
+    /// venues quote. `tickers` is indexed by ExchangeId.
+    pub fn evaluate(
+        &self,
+        symbol: Symbol,
+        tickers: &[Option<TickerData>; Exchange::COUNT],
+        costs: &CostModel,
+    ) -> Option<RebalanceOpportunity> {
+        let transfer = self.asset(symbol)?;
+        let event = SpreadCalculator::calculate(symbol, tickers)?;
+        let buy = tickers[event.long_ex.index()].as_ref()?;
+
+        // Fee in asset units, as a fraction of the reference notional
+        let transfer_cost = transfer
+            .fee
+            .safe_mul(buy.ask_price)?
+            .safe_div(costs.reference_notional())
+            .map_or(Bps::MAX, Bps::from_fraction);
+        let trading_fees = costs
+            .taker_fee(event.long_ex)
+            .checked_add(costs.taker_fee(event.short_ex))
+            .map_or(Bps::MAX, Bps::from_fraction);
+        let net_edge = event
+            .spread
+            .checked_sub(trading_fees)
+            .and_then(|net| net.checked_sub(transfer_cost))
+            .unwrap_or(Bps::MIN);
+
+        Some(RebalanceOpportunity {
+            symbol,
+            buy_ex: event.long_ex,
+            sell_ex: event.short_ex,
+            spread: event.spread,
+            trading_fees,
+            transfer_cost,
+            net_edge,
+            transfer_time: transfer.duration,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::{AssetTransferConfig, CostConfig};
+    use crate::test_utils::init_test_registry;
+
+    fn ticker(symbol: Symbol, bid: i64, ask: i64) -> TickerData {
+        let price = |p: i64| FixedPoint8::from_raw(p * FixedPoint8::SCALE);
+        TickerData::new(symbol, price(bid), FixedPoint8::ONE, price(ask), FixedPoint8::ONE, 0)
+    }
+
+    #[test]
+    fn test_rebalance_edge() {
+        init_test_registry();
+        let sol = Symbol::from_bytes(b"SOLUSDT").unwrap();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let model = TransferModel::new(&TransferConfig {
+            assets: HashMap::from([("sol".to_string(), AssetTransferConfig { withdrawal_fee: 0.01, minutes: 2.0 })]),
+        });
+        let costs = CostModel::new(&CostConfig {
+            taker_fee_binance: 0.0005,
+            taker_fee_bybit: 0.0005,
+            reference_notional: 1_000.0,
+            ..Default::default()
+        });
+
+        // Buy Binance at 100, sell Bybit at 101: 1% gross
+        let opp = model.evaluate(sol, &[Some(ticker(sol, 99, 100)), Some(ticker(sol, 101, 102))], &costs).unwrap();
+        assert_eq!((opp.buy_ex, opp.sell_ex), (Exchange::Binance, Exchange::Bybit));
+        assert_eq!(opp.spread, Bps::from_bps(100));
+        assert_eq!(opp.trading_fees, Bps::from_bps(10));
+        // 0.01 SOL x 100 USDT on 1000 USDT notional
+        assert_eq!(opp.transfer_cost, Bps::from_bps(10));
+        assert_eq!(opp.net_edge, Bps::from_bps(80));
+        assert_eq!(opp.transfer_time, Duration::from_secs(120));
+
+        // No transfer parameters: not evaluated
+        assert!(model.asset(btc).is_none());
+        assert_eq!(model.evaluate(btc, &[Some(ticker(btc, 99, 100)), Some(ticker(btc, 101, 102))], &costs), None);
+    }
+}
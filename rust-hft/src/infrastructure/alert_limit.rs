@@ -0,0 +1,213 @@
+//! Alert rate limiting (Cold Path)
+//!
+//! During an incident every alert source fires at once and channels become
+//! unreadable. Each channel (the alert log, each webhook target) gets a
+//! token bucket, and all channels share a global one; an alert is sent only
+//! if both have a token. Suppressed alerts are counted per channel and kind
+//! ("opened", "closed", ...): the next alert of that kind carries the count,
+//! and counts left over after a quiet `summary` interval are handed out by
+//! [`AlertLimiter::take_summaries`] as "37 similar alerts suppressed" lines.
+
+use crate::infrastructure::config::AlertRateLimitConfig;
+use parking_lot::Mutex;
+use std::collections::HashMap;
+use std::time::{Duration, Instant};
+
+/// Token bucket refilled continuously up to its burst
+#[derive(Debug, Clone)]
+pub struct TokenBucket {
+    capacity: f64,
+    per_sec: f64,
+    tokens: f64,
+    updated: Instant,
+}
+
+impl TokenBucket {
+    /// `per_minute` sustained, up to `burst` at once (starts full)
+    pub fn new(per_minute: u32, burst: u32, now: Instant) -> Self {
+        let capacity = f64::from(burst.max(1));
+        Self {
+            capacity,
+            per_sec: f64::from(per_minute) / 60.0,
+            tokens: capacity,
+            updated: now,
+        }
+    }
+
+    fn refill(&mut self, now: Instant) {
+        let elapsed = now.saturating_duration_since(self.updated).as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.per_sec).min(self.capacity);
+        self.updated = now;
+    }
+
+    /// A token is available at `now`
+    pub fn ready(&mut self, now: Instant) -> bool {
+        self.refill(now);
+        self.tokens >= 1.0
+    }
+
+    /// Take a token (call after [`Self::ready`])
+    pub fn take(&mut self) {
+        self.tokens -= 1.0;
+    }
+}
+
+/// Decision for one alert
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Admission {
+    /// Send it; `suppressed` similar alerts were dropped since the last one sent
+    Send { suppressed: u64 },
+    Suppressed,
+}
+
+/// Suppressed alerts of one kind not yet reported
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SuppressedSummary {
+    pub kind: &'static str,
+    pub count: u64,
+    /// First suppression of the run
+    pub since: Instant,
+}
+
+#[derive(Debug)]
+struct Pending {
+    count: u64,
+    since: Instant,
+}
+
+#[derive(Debug)]
+struct Limits {
+    global: TokenBucket,
+    channels: HashMap<String, TokenBucket>,
+    pending: HashMap<(String, &'static str), Pending>,
+    suppressed: u64,
+}
+
+/// Per-channel and global alert budgets, shared by every channel
+#[derive(Debug)]
+pub struct AlertLimiter {
+    config: AlertRateLimitConfig,
+    summary: Duration,
+    inner: Mutex<Limits>,
+}
+
+impl AlertLimiter {
+    pub fn new(config: &AlertRateLimitConfig) -> Self {
+        Self {
+            config: config.clone(),
+            summary: Duration::from_secs(config.summary_secs),
+            inner: Mutex::new(Limits {
+                global: TokenBucket::new(config.global_per_minute, config.global_burst, Instant::now()),
+                channels: HashMap::new(),
+                pending: HashMap::new(),
+                suppressed: 0,
+            }),
+        }
+    }
+
+    /// Quiet time after which leftover suppressed counts are summarized
+    #[inline]
+    pub fn summary_interval(&self) -> Duration {
+        self.summary
+    }
+
+    /// Decide whether an alert of `kind` goes out on `channel` at `now`
+    pub fn admit(&self, channel: &str, kind: &'static str, now: Instant) -> Admission {
+        if !self.config.enabled {
+            return Admission::Send { suppressed: 0 };
+        }
+        let mut inner = self.inner.lock();
+        let limits = &mut *inner;
+        let bucket = limits
+            .channels
+            .entry(channel.to_string())
+            .or_insert_with(|| TokenBucket::new(self.config.per_channel_per_minute, self.config.burst, now));
+        if bucket.ready(now) && limits.global.ready(now) {
+            bucket.take();
+            limits.global.take();
+            let suppressed = limits.pending.remove(&(channel.to_string(), kind)).map_or(0, |p| p.count);
+            return Admission::Send { suppressed };
+        }
+        limits.suppressed += 1;
+        limits
+            .pending
+            .entry((channel.to_string(), kind))
+            .or_insert(Pending { count: 0, since: now })
+            .count += 1;
+        Admission::Suppressed
+    }
+
+    /// Suppressed counts on `channel` older than the summary interval (reset)
+    ///
+    /// Summaries bypass the buckets; there is at most one per kind per interval.
+    pub fn take_summaries(&self, channel: &str, now: Instant) -> Vec<SuppressedSummary> {
+        let mut inner = self.inner.lock();
+        let mut summaries = Vec::new();
+        inner.pending.retain(|(ch, kind), pending| {
+            if ch != channel || now.saturating_duration_since(pending.since) < self.summary {
+                return true;
+            }
+            summaries.push(SuppressedSummary { kind, count: pending.count, since: pending.since });
+            false
+        });
+        summaries.sort_by_key(|s| s.kind);
+        summaries
+    }
+
+    /// Alerts suppressed since startup, all channels
+    pub fn suppressed_total(&self) -> u64 {
+        self.inner.lock().suppressed
+    }
+}
+
+impl Default for AlertLimiter {
+    fn default() -> Self {
+        Self::new(&AlertRateLimitConfig::default())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_channel_and_global_budgets_with_summaries() {
+        let config = AlertRateLimitConfig {
+            enabled: true,
+            per_channel_per_minute: 60,
+            burst: 2,
+            global_per_minute: 120,
+            global_burst: 3,
+            summary_secs: 10,
+        };
+        let limiter = AlertLimiter::new(&config);
+        let t0 = Instant::now();
+        let secs = Duration::from_secs;
+        let send = Admission::Send { suppressed: 0 };
+
+        // Burst of 2 per channel, then suppressed
+        assert_eq!(limiter.admit("log", "opened", t0), send);
+        assert_eq!(limiter.admit("log", "opened", t0), send);
+        assert_eq!(limiter.admit("log", "opened", t0), Admission::Suppressed);
+        assert_eq!(limiter.admit("log", "closed", t0), Admission::Suppressed);
+        // Global burst of 3: the other channel gets one
+        assert_eq!(limiter.admit("webhook", "opened", t0), send);
+        assert_eq!(limiter.admit("webhook", "opened", t0), Admission::Suppressed);
+        assert_eq!(limiter.suppressed_total(), 3);
+
+        // One token per second: the next opened alert carries the count
+        assert_eq!(limiter.admit("log", "opened", t0 + secs(1)), Admission::Send { suppressed: 1 });
+        assert_eq!(limiter.take_summaries("log", t0 + secs(5)), vec![]);
+        assert_eq!(
+            limiter.take_summaries("log", t0 + secs(10)),
+            vec![SuppressedSummary { kind: "closed", count: 1, since: t0 }]
+        );
+        assert_eq!(limiter.take_summaries("log", t0 + secs(20)), vec![]);
+        assert_eq!(limiter.take_summaries("webhook", t0 + secs(20)).len(), 1);
+
+        let off = AlertLimiter::new(&AlertRateLimitConfig { enabled: false, ..config });
+        for _ in 0..10 {
+            assert_eq!(off.admit("log", "opened", t0), send);
+        }
+    }
+}
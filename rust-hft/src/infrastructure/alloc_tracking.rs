@@ -0,0 +1,184 @@
+//! Allocation tracking (feature = "alloc-tracking")
+//!
+//! Global allocator wrapper that counts allocations per subsystem label and
+//! per thread. Hot path functions mark themselves with [`hot_path_guard!`];
+//! in debug builds a guard panics if the function allocated, so any test
+//! exercising it fails. Release builds count violations instead.
+//!
+//! Without the feature the macros expand to nothing and the system
+//! allocator is used directly.
+
+use std::alloc::{GlobalAlloc, Layout, System};
+use std::cell::Cell;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+/// Subsystem label attributed to allocations on the current thread
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum Subsystem {
+    Other = 0,
+    Ws,
+    Parsing,
+    Engine,
+    Tracker,
+    Api,
+}
+
+impl Subsystem {
+    /// All labels, in counter index order
+    pub const ALL: [Subsystem; 6] = [
+        Subsystem::Other,
+        Subsystem::Ws,
+        Subsystem::Parsing,
+        Subsystem::Engine,
+        Subsystem::Tracker,
+        Subsystem::Api,
+    ];
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Subsystem::Other => "other",
+            Subsystem::Ws => "ws",
+            Subsystem::Parsing => "parsing",
+            Subsystem::Engine => "engine",
+            Subsystem::Tracker => "tracker",
+            Subsystem::Api => "api",
+        }
+    }
+}
+
+const LABELS: usize = Subsystem::ALL.len();
+
+static COUNTS: [AtomicU64; LABELS] = [const { AtomicU64::new(0) }; LABELS];
+static HOT_PATH_VIOLATIONS: AtomicU64 = AtomicU64::new(0);
+
+thread_local! {
+    static LABEL: Cell<Subsystem> = const { Cell::new(Subsystem::Other) };
+    static THREAD_ALLOCS: Cell<u64> = const { Cell::new(0) };
+}
+
+/// Counting wrapper around the system allocator
+pub struct TrackingAllocator;
+
+#[inline(always)]
+fn count() {
+    // try_with: TLS may already be torn down during thread exit
+    let label = LABEL.try_with(Cell::get).unwrap_or(Subsystem::Other);
+    COUNTS[label as usize].fetch_add(1, Ordering::Relaxed);
+    let _ = THREAD_ALLOCS.try_with(|c| c.set(c.get() + 1));
+}
+
+unsafe impl GlobalAlloc for TrackingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc(layout)
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        count();
+        System.alloc_zeroed(layout)
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout)
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        count();
+        System.realloc(ptr, layout, new_size)
+    }
+}
+
+/// Allocations made so far by the current thread
+#[inline]
+pub fn thread_allocations() -> u64 {
+    THREAD_ALLOCS.with(Cell::get)
+}
+
+/// Allocation counts per subsystem label
+pub fn snapshot() -> [(Subsystem, u64); LABELS] {
+    Subsystem::ALL.map(|s| (s, COUNTS[s as usize].load(Ordering::Relaxed)))
+}
+
+/// Hot path guards that observed an allocation (release builds)
+pub fn hot_path_violations() -> u64 {
+    HOT_PATH_VIOLATIONS.load(Ordering::Relaxed)
+}
+
+/// Attribute allocations on this thread to `subsystem` until the guard drops
+pub fn scope(subsystem: Subsystem) -> ScopeGuard {
+    ScopeGuard {
+        previous: LABEL.with(|l| l.replace(subsystem)),
+    }
+}
+
+/// Restores the previous subsystem label on drop
+pub struct ScopeGuard {
+    previous: Subsystem,
+}
+
+impl Drop for ScopeGuard {
+    fn drop(&mut self) {
+        LABEL.with(|l| l.set(self.previous));
+    }
+}
+
+/// Zero-allocation check for a hot path function (see [`hot_path_guard!`])
+pub struct HotPathGuard {
+    name: &'static str,
+    start: u64,
+}
+
+impl HotPathGuard {
+    #[inline]
+    pub fn enter(name: &'static str) -> Self {
+        Self {
+            name,
+            start: thread_allocations(),
+        }
+    }
+}
+
+impl Drop for HotPathGuard {
+    fn drop(&mut self) {
+        let allocated = thread_allocations() - self.start;
+        if allocated == 0 || std::thread::panicking() {
+            return;
+        }
+        HOT_PATH_VIOLATIONS.fetch_add(1, Ordering::Relaxed);
+        if cfg!(debug_assertions) {
+            panic!("hot path `{}` allocated {} time(s)", self.name, allocated);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_scope_attribution() {
+        let before = COUNTS[Subsystem::Api as usize].load(Ordering::Relaxed);
+        {
+            let _scope = scope(Subsystem::Api);
+            let v: Vec<u64> = Vec::with_capacity(16);
+            std::hint::black_box(v);
+        }
+        let after = COUNTS[Subsystem::Api as usize].load(Ordering::Relaxed);
+        assert!(after > before);
+        assert_eq!(LABEL.with(Cell::get), Subsystem::Other);
+    }
+
+    #[test]
+    fn test_hot_path_guard_passes_without_allocation() {
+        let _guard = HotPathGuard::enter("test::noop");
+        std::hint::black_box(1 + 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "hot path `test::alloc` allocated")]
+    fn test_hot_path_guard_panics_on_allocation() {
+        let _guard = HotPathGuard::enter("test::alloc");
+        std::hint::black_box(Box::new(42u64));
+    }
+}
@@ -2,24 +2,56 @@
 //!
 //! Serves dashboard static files and provides REST API for screener stats.
 //! Accesses ThresholdTracker via shared state.
+//!
+//! Access control: an optional client IP allowlist applies to every route
+//! (loopback is always allowed, so the local watchdog probe of
+//! `/api/health/live` keeps working), and control endpoints (pause, resume,
+//! shutdown, per-symbol execution, `/api/order`, `/api/flatten`) and the
+//! `/api/debug*` introspection endpoints require a bearer token. Without a
+//! configured token those endpoints are refused rather than left open.
 
 use axum::{
-    extract::State,
-    routing::get,
+    extract::ws::{Message, WebSocket, WebSocketUpgrade},
+    extract::{ConnectInfo, Path, Query, Request, State},
+    http::{header, HeaderMap, HeaderValue, Method, StatusCode},
+    middleware::{self, Next},
+    response::{Html, IntoResponse, Response},
+    routing::{get, post},
     Json, Router,
 };
-use serde::Serialize;
-use std::net::SocketAddr;
+use serde::{Deserialize, Serialize};
+use std::net::{IpAddr, SocketAddr};
 use std::sync::Arc;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
-use tower_http::cors::CorsLayer;
+use tokio::time::MissedTickBehavior;
+use tower_http::cors::{AllowOrigin, CorsLayer};
 use tower_http::services::ServeDir;
 
-use crate::hot_path::{ScreenerStats, ThresholdTracker};
-use crate::infrastructure::metrics::MetricsCollector;
+use crate::engine::journal::{Heatmap, HEATMAP_RETENTION_HOURS};
+use crate::engine::{AttemptOutcome, EngineControl, ExecutionAttempt, FlattenLeg, FlattenReport, FlattenTarget, LiveOutcome, OrderRequest, PauseStatus, OpportunityJournal, OpportunityRecord, SizeConstraint};
+use crate::core::Side;
+use crate::hot_path::{InvariantSnapshot, LeadLag, RebalanceOpportunity, ScreenerStats, SessionBucket, SessionStats, ThresholdSource, ThresholdTracker};
+use crate::core::{FixedPoint8, Symbol, SymbolRegistry, MAX_SYMBOLS};
+use crate::exchanges::Exchange;
+use crate::infrastructure::metrics::{
+    ConnectionBufferSnapshot, ConnectionSnapshot, FeedRaceSnapshot, ExecutionQualitySnapshot, MetricsCollector, OrderLatencySnapshot, PnlAttributionSnapshot, PoolSnapshot,
+    QueueSnapshot, RecorderSnapshot, TaskSnapshot,
+};
 use crate::infrastructure::config::ApiConfig;
+use crate::infrastructure::health::ExchangeHealth;
+use crate::infrastructure::startup::StartupSnapshot;
+use crate::infrastructure::history::{EpisodeRow, ExecutionRow, HistoryQuery, HistoryStore};
+use crate::infrastructure::json_writer::WriteJson;
+use crate::infrastructure::openapi;
+use crate::infrastructure::presence::{SubscriptionState, SymbolPresence};
+use crate::infrastructure::market_snapshot::{MarketSnapshot, MarketSnapshotter};
+use crate::infrastructure::metrics_history::{self, MetricsHistory, MetricsPoint, Resolution};
+use parking_lot::Mutex;
+use crate::infrastructure::screener_cache::ScreenerCache;
+use crate::infrastructure::snapshot_diff::SnapshotDiffer;
+use crate::infrastructure::unparsed::UnparsedSnapshot;
 use crate::HftError;
-use std::path::PathBuf;
 
 /// System status information
 #[derive(Debug, Serialize)]
@@ -30,6 +62,86 @@ pub struct SystemStatusDto {
     pub active_symbols: usize,
     pub binance_connected: bool,
     pub bybit_connected: bool,
+    /// Orders suppressed by cooldown / rate limit / focus / warm-up / pause / disabled, delisted or halted symbol / risk / peer / standby / private stream / read-only
+    pub suppressed_cooldown: u64,
+    pub suppressed_rate_limit: u64,
+    pub suppressed_unfocused: u64,
+    pub suppressed_warmup: u64,
+    pub suppressed_paused: u64,
+    pub suppressed_disabled: u64,
+    pub suppressed_delisted: u64,
+    pub suppressed_halted: u64,
+    pub suppressed_peer: u64,
+    pub suppressed_standby: u64,
+    pub suppressed_private_stream: u64,
+    pub suppressed_risk: u64,
+    pub suppressed_read_only: u64,
+    /// Quotes quarantined by sanity filters
+    pub quarantined_quotes: u64,
+    /// Locked/crossed quotes rejected (excluded from spreads)
+    pub locked_books: u64,
+    pub crossed_books: u64,
+    /// Symbols in focus (auto-focus mode)
+    pub focused_symbols: u64,
+    /// Feed task restarts, and feeds the restart policy gave up on
+    pub task_restarts: u64,
+    pub failed_tasks: u64,
+    /// Engine loop iterations over the stall budget
+    pub engine_stalls: u64,
+}
+
+/// Startup warm-up state
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WarmupDto {
+    /// Execution disabled for every symbol
+    pub active: bool,
+    pub remaining_secs: u64,
+    /// Symbols with enough live samples to execute
+    pub warm_symbols: u64,
+}
+
+/// Operator pause state (/api/pause, /api/resume, /api/health)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PauseDto {
+    /// Execution disabled for every strategy
+    pub paused: bool,
+    /// Spread tracking frozen as well
+    pub tracker_paused: bool,
+    /// Seconds since the pause started (None = running)
+    pub paused_for_secs: Option<u64>,
+}
+
+impl From<PauseStatus> for PauseDto {
+    fn from(status: PauseStatus) -> Self {
+        Self {
+            paused: status.paused,
+            tracker_paused: status.tracker_paused,
+            paused_for_secs: status.paused_for.map(|d| d.as_secs()),
+        }
+    }
+}
+
+/// Process health (/api/health)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HealthDto {
+    /// Startup complete, connected, not paused and no failed feed task
+    pub ready: bool,
+    /// Startup readiness stages
+    pub startup: StartupSnapshot,
+    pub uptime_seconds: u64,
+    pub binance_connected: bool,
+    pub bybit_connected: bool,
+    pub warmup: WarmupDto,
+    pub pause: PauseDto,
+    /// No execution engine: the process cannot send orders
+    pub read_only: bool,
+    /// Supervised feed tasks
+    pub tasks: Vec<TaskSnapshot>,
+    /// Reconnect circuit per exchange (open = reconnects held after repeated connect failures)
+    pub circuits: Vec<ExchangeHealth>,
 }
 
 /// DTO for screener stats (matches store.js expectation)
@@ -38,9 +150,28 @@ pub struct SystemStatusDto {
 pub struct ScreenerDto {
     pub symbol: String,
     pub current_spread: f64,
+    /// Current spread in ticks (None until tick sizes are known)
+    pub spread_ticks: Option<f64>,
+    pub break_even: f64,
+    pub edge_after_costs: f64,
     pub spread_range: f64,
+    /// Spread P1 / P99 over the 2-minute window (None = no spreads yet)
+    pub spread_p1: Option<f64>,
+    pub spread_p99: Option<f64>,
+    /// |P1| + P99: `spread_range` without the outliers
+    pub robust_range: Option<f64>,
     pub hits: u64,
+    pub threshold: f64,
+    pub threshold_source: ThresholdSource,
     pub est_half_life: f64,
+    pub liquidation_volume: f64,
+    pub price_volatility: f64,
+    /// Venue down or not yet fresh since its outage (spread not computed)
+    pub stale_venue: Option<String>,
+    /// Venue where the symbol is halted or in auction (spread not computed)
+    pub suspended_venue: Option<String>,
+    /// "halted" or "auction" on `suspended_venue`
+    pub suspended_status: Option<String>,
     pub is_spread_na: bool,
 }
 
@@ -56,53 +187,781 @@ impl From<ScreenerStats> for ScreenerDto {
     fn from(stats: ScreenerStats) -> Self {
         Self {
             symbol: stats.symbol.as_str().to_string(),
-            current_spread: stats.current_spread.to_f64(),
-            spread_range: stats.spread_range.to_f64(),
+            current_spread: stats.current_spread.fraction_f64(),
+            spread_ticks: stats.spread_ticks.map(|t| t.to_f64()),
+            break_even: stats.break_even.fraction_f64(),
+            edge_after_costs: stats.edge_after_costs.fraction_f64(),
+            spread_range: stats.spread_range.fraction_f64(),
+            spread_p1: stats.spread_p1.map(|b| b.fraction_f64()),
+            spread_p99: stats.spread_p99.map(|b| b.fraction_f64()),
+            robust_range: stats.robust_range.map(|b| b.fraction_f64()),
             hits: stats.hits,
+            threshold: stats.threshold.fraction_f64(),
+            threshold_source: stats.threshold_source,
             est_half_life: 0.0, // TODO: Implement half-life calculation
+            liquidation_volume: stats.liquidation_volume.to_f64(),
+            price_volatility: stats.price_volatility.to_f64(),
+            stale_venue: stats.stale_venue.map(|e| e.name().to_string()),
+            suspended_venue: stats.suspended.map(|(e, _)| e.name().to_string()),
+            suspended_status: stats.suspended.map(|(_, s)| s.name().to_string()),
             is_spread_na: !stats.is_valid,
         }
     }
 }
 
+/// Journaled opportunity with chosen size and every sizing cap
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OpportunityDto {
+    pub symbol: String,
+    pub spread: f64,
+    pub long_exchange: String,
+    pub short_exchange: String,
+    pub timestamp: u64,
+    pub quantity: f64,
+    pub constraint: SizeConstraint,
+    pub liquidity_cap: f64,
+    pub balance_cap: f64,
+    pub notional_cap: f64,
+    pub volatility_cap: f64,
+    pub fill_probability: f64,
+    /// Spread net of all-taker costs (fraction)
+    pub net_edge: Option<f64>,
+    /// Spread net of costs with the `passive_exchange` leg quoted as maker
+    pub passive_net_edge: Option<f64>,
+    pub passive_exchange: Option<String>,
+    /// Break-even limit price for the passive leg
+    pub passive_price: Option<f64>,
+}
+
+impl From<&OpportunityRecord> for OpportunityDto {
+    fn from(record: &OpportunityRecord) -> Self {
+        Self {
+            symbol: record.symbol.as_str().to_string(),
+            spread: record.spread.fraction_f64(),
+            long_exchange: record.long_ex.name().to_string(),
+            short_exchange: record.short_ex.name().to_string(),
+            timestamp: record.timestamp,
+            quantity: record.size.quantity.to_f64(),
+            constraint: record.size.constraint,
+            liquidity_cap: record.size.liquidity_cap.to_f64(),
+            balance_cap: record.size.balance_cap.to_f64(),
+            notional_cap: record.size.notional_cap.to_f64(),
+            volatility_cap: record.size.volatility_cap.to_f64(),
+            fill_probability: record.size.fill_probability.to_f64(),
+            net_edge: record.edges.map(|e| e.taker.fraction_f64()),
+            passive_net_edge: record.edges.map(|e| e.passive.fraction_f64()),
+            passive_exchange: record.edges.map(|e| e.passive_ex.name().to_string()),
+            passive_price: record.edges.and_then(|e| e.passive_price).map(|p| p.to_f64()),
+        }
+    }
+}
+
+/// Leg of an execution attempt (times from the decision, ms)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegTimingDto {
+    pub exchange: &'static str,
+    pub side: &'static str,
+    pub sent_ms: f64,
+    /// None = never acknowledged
+    pub acked_ms: Option<f64>,
+}
+
+/// Journaled execution attempt with its timing breakdown
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionAttemptDto {
+    pub symbol: String,
+    pub timestamp: u64,
+    /// "completed" or "aborted" (execution budget exceeded)
+    pub outcome: &'static str,
+    /// Decision to last ack (completed) or abort (ms)
+    pub elapsed_ms: f64,
+    /// Legs sent, by venue
+    pub legs: Vec<LegTimingDto>,
+}
+
+impl From<&ExecutionAttempt> for ExecutionAttemptDto {
+    fn from(attempt: &ExecutionAttempt) -> Self {
+        let ms = |d: Duration| d.as_secs_f64() * 1_000.0;
+        Self {
+            symbol: attempt.symbol.as_str().to_string(),
+            timestamp: attempt.timestamp,
+            outcome: match attempt.outcome {
+                AttemptOutcome::Completed => "completed",
+                AttemptOutcome::Aborted => "aborted",
+            },
+            elapsed_ms: ms(attempt.elapsed),
+            legs: Exchange::ALL
+                .into_iter()
+                .filter_map(|exchange| {
+                    attempt.legs[exchange.index()].map(|leg| LegTimingDto {
+                        exchange: exchange.name(),
+                        side: match leg.side {
+                            Side::Buy => "buy",
+                            Side::Sell => "sell",
+                        },
+                        sent_ms: ms(leg.sent),
+                        acked_ms: leg.acked.map(ms),
+                    })
+                })
+                .collect(),
+        }
+    }
+}
+
+/// Opportunity hits per symbol and time bucket (row per symbol, most active first)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapDto {
+    /// Start of the first bucket (ms since epoch)
+    pub start_ms: u64,
+    pub bucket_ms: u64,
+    pub symbols: Vec<String>,
+    /// `counts[row][bucket]`, oldest bucket first
+    pub counts: Vec<Vec<u32>>,
+}
+
+impl From<Heatmap> for HeatmapDto {
+    fn from(map: Heatmap) -> Self {
+        Self {
+            start_ms: map.start / 1_000_000,
+            bucket_ms: map.bucket / 1_000_000,
+            symbols: map.symbols.iter().map(|s| s.as_str().to_string()).collect(),
+            counts: map.counts,
+        }
+    }
+}
+
+/// Session bucket (one hour-of-day or weekday)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionBucketDto {
+    pub opportunities: u64,
+    pub samples: u64,
+    pub avg_net_spread: f64,
+}
+
+impl From<&SessionBucket> for SessionBucketDto {
+    fn from(bucket: &SessionBucket) -> Self {
+        Self {
+            opportunities: bucket.opportunities,
+            samples: bucket.samples,
+            avg_net_spread: bucket.average_net().to_f64(),
+        }
+    }
+}
+
+/// Per-symbol session analytics (UTC hours 0-23, weekdays Monday-first)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SessionStatsDto {
+    pub symbol: String,
+    pub hours: Vec<SessionBucketDto>,
+    pub weekdays: Vec<SessionBucketDto>,
+}
+
+impl SessionStatsDto {
+    fn new(symbol: &str, stats: &SessionStats) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            hours: stats.hours.iter().map(SessionBucketDto::from).collect(),
+            weekdays: stats.weekdays.iter().map(SessionBucketDto::from).collect(),
+        }
+    }
+}
+
+/// Which venue's mid moves first for one symbol
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LeadLagDto {
+    pub symbol: String,
+    /// Leading venue (None = contemporaneous at bin resolution)
+    pub leader: Option<String>,
+    pub lag_ms: u64,
+    /// Correlation of mid moves at `lag_ms`
+    pub correlation: f64,
+    /// Correlation at zero lag
+    pub contemporaneous: f64,
+    /// Decayed count of 5 ms bins with a move
+    pub active_bins: f64,
+}
+
+impl LeadLagDto {
+    fn new(symbol: &str, lead: &LeadLag) -> Self {
+        Self {
+            symbol: symbol.to_string(),
+            leader: lead.leader.map(|e| e.name().to_string()),
+            lag_ms: lead.lag_ms,
+            correlation: lead.correlation,
+            contemporaneous: lead.contemporaneous,
+            active_bins: lead.active_bins,
+        }
+    }
+}
+
+/// One venue's quote in a market snapshot
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotQuoteDto {
+    pub exchange: &'static str,
+    pub bid: f64,
+    pub bid_qty: f64,
+    pub ask: f64,
+    pub ask_qty: f64,
+    /// Exchange timestamp of the quote
+    pub exchange_ms: u64,
+    /// Time since the quote arrived, at capture
+    pub age_ms: f64,
+}
+
+/// Quotes of one symbol on every venue
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SnapshotPairDto {
+    pub symbol: String,
+    pub quotes: Vec<SnapshotQuoteDto>,
+}
+
+/// All quote pairs captured at one snapshotter tick
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MarketSnapshotDto {
+    pub tick: u64,
+    pub taken_ms: u64,
+    pub pairs: Vec<SnapshotPairDto>,
+}
+
+impl From<&MarketSnapshot> for MarketSnapshotDto {
+    fn from(snapshot: &MarketSnapshot) -> Self {
+        let pairs = snapshot
+            .pairs
+            .iter()
+            .zip(&snapshot.ages)
+            .map(|(pair, ages)| SnapshotPairDto {
+                symbol: pair.symbol.as_str().to_string(),
+                quotes: Exchange::ALL
+                    .into_iter()
+                    .map(|exchange| {
+                        let ticker = &pair.tickers[exchange.index()];
+                        SnapshotQuoteDto {
+                            exchange: exchange.name(),
+                            bid: ticker.bid_price.to_f64(),
+                            bid_qty: ticker.bid_qty.to_f64(),
+                            ask: ticker.ask_price.to_f64(),
+                            ask_qty: ticker.ask_qty.to_f64(),
+                            exchange_ms: ticker.timestamp / 1_000_000,
+                            age_ms: ages[exchange.index()].as_secs_f64() * 1e3,
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+        Self { tick: snapshot.tick, taken_ms: snapshot.taken_ms, pairs }
+    }
+}
+
+/// Inventory rebalance opportunity (fractions, like ScreenerDto)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RebalanceDto {
+    pub symbol: String,
+    pub buy_exchange: String,
+    pub sell_exchange: String,
+    pub spread: f64,
+    pub trading_fees: f64,
+    pub transfer_cost: f64,
+    pub net_edge: f64,
+    pub transfer_secs: u64,
+}
+
+impl From<&RebalanceOpportunity> for RebalanceDto {
+    fn from(opp: &RebalanceOpportunity) -> Self {
+        Self {
+            symbol: opp.symbol.as_str().to_string(),
+            buy_exchange: opp.buy_ex.name().to_string(),
+            sell_exchange: opp.sell_ex.name().to_string(),
+            spread: opp.spread.fraction_f64(),
+            trading_fees: opp.trading_fees.fraction_f64(),
+            transfer_cost: opp.transfer_cost.fraction_f64(),
+            net_edge: opp.net_edge.fraction_f64(),
+            transfer_secs: opp.transfer_time.as_secs(),
+        }
+    }
+}
+
+/// Pause options (/api/pause?tracker=true)
+#[derive(Debug, Deserialize)]
+pub struct PauseQuery {
+    /// Also freeze spread tracking
+    #[serde(default)]
+    pub tracker: bool,
+}
+
+/// Heatmap window (/api/heatmap?hours=6&bucketMinutes=15)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HeatmapQuery {
+    /// Hours back from now (default 24, at most the retention)
+    pub hours: Option<u64>,
+    /// Bucket width, rounded up to 5 minutes (default 15)
+    pub bucket_minutes: Option<u64>,
+}
+
+/// Metrics history window (/api/metrics/history?resolution=1m&since=1700000000000)
+#[derive(Debug, Deserialize)]
+pub struct MetricsHistoryQuery {
+    /// "1s" (last hour, default) or "1m" (last 24 hours)
+    #[serde(default)]
+    pub resolution: Resolution,
+    /// Oldest point, milliseconds since epoch
+    pub since: Option<u64>,
+}
+
+/// Execution toggle (/api/symbols/{symbol}/execution?enabled=false)
+#[derive(Debug, Deserialize)]
+pub struct SymbolExecutionQuery {
+    pub enabled: bool,
+}
+
+/// Manual order (/api/order)
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualOrderBody {
+    /// "binance" or "bybit"
+    pub venue: String,
+    pub symbol: String,
+    /// "buy" or "sell"
+    pub side: String,
+    /// "limit" or "market"
+    #[serde(alias = "type")]
+    pub order_type: String,
+    /// Limit price (required for limit orders, absent for market orders)
+    pub price: Option<f64>,
+    pub qty: f64,
+}
+
+impl ManualOrderBody {
+    /// Order to route, or None if any field is invalid
+    fn to_order(&self, symbol: Symbol) -> Option<OrderRequest> {
+        let exchange = Exchange::from_name(&self.venue)?;
+        let side = Side::from_bytes(self.side.to_ascii_lowercase().as_bytes())?;
+        let quantity = FixedPoint8::from_f64(self.qty).filter(|q| q.as_raw() > 0)?;
+        let price = match (self.order_type.to_ascii_lowercase().as_str(), self.price) {
+            ("limit", Some(price)) => Some(FixedPoint8::from_f64(price).filter(|p| p.as_raw() > 0)?),
+            ("market", None) => None,
+            _ => return None,
+        };
+        Some(OrderRequest { exchange, symbol, side, quantity, price })
+    }
+}
+
+/// Outcome of a manual order
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ManualOrderDto {
+    /// "submitted" or "suppressed"
+    pub status: &'static str,
+    /// Why the order was not sent (e.g. "paused", "halted")
+    pub reason: Option<&'static str>,
+}
+
+impl From<LiveOutcome> for ManualOrderDto {
+    fn from(outcome: LiveOutcome) -> Self {
+        match outcome {
+            LiveOutcome::Submitted => Self { status: "submitted", reason: None },
+            LiveOutcome::Suppressed(reason) => Self { status: "suppressed", reason: Some(reason) },
+        }
+    }
+}
+
+/// One venue position in a flatten report
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlattenLegDto {
+    pub exchange: &'static str,
+    pub symbol: String,
+    /// Signed net quantity before flattening (long > 0)
+    pub initial_qty: f64,
+    /// Signed net quantity at the last check (0 = closed)
+    pub remaining_qty: f64,
+    /// Remaining quantity reported by the venue (not derived from fills)
+    pub confirmed: bool,
+    /// Close orders sent
+    pub orders: u32,
+}
+
+impl From<&FlattenLeg> for FlattenLegDto {
+    fn from(leg: &FlattenLeg) -> Self {
+        Self {
+            exchange: leg.exchange.name(),
+            symbol: leg.symbol.as_str().to_string(),
+            initial_qty: leg.initial.to_f64(),
+            remaining_qty: leg.remaining.to_f64(),
+            confirmed: leg.confirmed,
+            orders: leg.orders,
+        }
+    }
+}
+
+/// Final report of a flatten command (/api/flatten/{symbol|all})
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FlattenDto {
+    /// Symbol, or "all"
+    pub target: &'static str,
+    /// "verified" (venues confirm every position closed), "unverified"
+    /// (closed by fills only) or "incomplete" (positions left open)
+    pub status: &'static str,
+    /// Rounds of close orders sent
+    pub attempts: u32,
+    pub elapsed_ms: f64,
+    pub legs: Vec<FlattenLegDto>,
+}
+
+impl From<FlattenReport> for FlattenDto {
+    fn from(report: FlattenReport) -> Self {
+        Self {
+            target: report.target.name(),
+            status: report.status.name(),
+            attempts: report.attempts,
+            elapsed_ms: report.elapsed.as_secs_f64() * 1e3,
+            legs: report.legs.iter().map(FlattenLegDto::from).collect(),
+        }
+    }
+}
+
+/// Optional symbol filter
+#[derive(Debug, Deserialize)]
+pub struct SymbolQuery {
+    pub symbol: Option<String>,
+}
+
+/// Presence of one symbol on one venue
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenuePresenceDto {
+    pub exchange: &'static str,
+    /// Listed with enough volume at discovery
+    pub listed: bool,
+    /// Milliseconds since the last ticker (None = never updated)
+    pub last_update_age_ms: Option<u64>,
+    /// Locked/crossed quotes rejected from this venue
+    pub locked_books: u64,
+    pub crossed_books: u64,
+}
+
+/// Registry entry with per-venue availability (/api/symbols)
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolPresenceDto {
+    pub id: u32,
+    pub symbol: String,
+    pub subscription: SubscriptionState,
+    /// Orders on this symbol may execute (see /api/symbols/{symbol}/execution)
+    pub execution_enabled: bool,
+    pub venues: Vec<VenuePresenceDto>,
+}
+
+/// Execution eligibility of one symbol
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SymbolExecutionDto {
+    pub symbol: String,
+    pub execution_enabled: bool,
+}
+
+/// One book level with notional accumulated from the touch
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookLevelDto {
+    pub price: f64,
+    pub qty: f64,
+    pub cum_notional: f64,
+}
+
+/// Book levels on one venue
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueBookDto {
+    pub exchange: &'static str,
+    pub bids: Vec<BookLevelDto>,
+    pub asks: Vec<BookLevelDto>,
+}
+
+/// Depth snapshot for both venues (/api/book/:symbol)
+///
+/// Only top of book is tracked, so each side has at most one level.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BookDto {
+    pub symbol: String,
+    pub venues: Vec<VenueBookDto>,
+}
+
+/// Levels ordered from the touch with running notional
+fn book_levels(levels: impl IntoIterator<Item = (FixedPoint8, FixedPoint8)>) -> Vec<BookLevelDto> {
+    let mut cum_notional = 0.0;
+    levels
+        .into_iter()
+        .map(|(price, qty)| {
+            cum_notional += price.to_f64() * qty.to_f64();
+            BookLevelDto { price: price.to_f64(), qty: qty.to_f64(), cum_notional }
+        })
+        .collect()
+}
+
+/// Tracker slot occupancy
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrackerOccupancyDto {
+    pub symbols: usize,
+    pub capacity: usize,
+    /// Estimated tracker memory, including empty slots (bytes)
+    pub memory_bytes: usize,
+    /// Idle symbols evicted since startup
+    pub evictions: u64,
+}
+
+/// Heap and queue-depth introspection for slow-consumer/leak diagnosis
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DebugDto {
+    pub pools: Vec<PoolSnapshot>,
+    pub queues: Vec<QueueSnapshot>,
+    pub tracker: TrackerOccupancyDto,
+    pub connections: Vec<ConnectionBufferSnapshot>,
+    /// History writer throughput
+    pub recorder: RecorderSnapshot,
+    /// Sampled market data invariant violations
+    pub invariants: InvariantSnapshot,
+}
+
+/// Max journal entries returned by /api/opportunities
+const OPPORTUNITY_LIMIT: usize = 200;
+
 /// Shared application state
 #[derive(Clone)]
 pub struct AppState {
     pub tracker: Arc<RwLock<ThresholdTracker>>,
     pub metrics: Arc<MetricsCollector>,
+    pub journal: Arc<RwLock<OpportunityJournal>>,
+    /// Historical opportunity database (None = disabled)
+    pub history: Option<Arc<RwLock<HistoryStore>>>,
+    /// Venue listings and subscription state
+    pub presence: Arc<SymbolPresence>,
+    /// Engine pause switch
+    pub control: Arc<EngineControl>,
+    /// Screener stream push interval
+    pub stream_interval: Duration,
+    /// Full screener snapshot every N stream updates
+    pub stream_resync_every: u32,
+    /// Serialized /api/screener/stats response (refreshed at most every stream interval)
+    pub screener: Arc<ScreenerCache>,
+    /// Time-consistent quote pairs, captured every snapshot interval
+    pub snapshots: Arc<MarketSnapshotter>,
+    /// Message rate and latency trends, sampled every second
+    pub metrics_history: Arc<Mutex<MetricsHistory>>,
+}
+
+/// Client allowlist and control token
+#[derive(Debug, Clone, Default)]
+struct ApiGuard {
+    token: Option<Arc<str>>,
+    allowed_ips: Arc<[IpAddr]>,
+}
+
+impl ApiGuard {
+    fn new(config: &ApiConfig) -> Self {
+        Self {
+            token: config.auth_token().map(Arc::from),
+            allowed_ips: config.allowed_ips.iter().map(|ip| ip.to_canonical()).collect(),
+        }
+    }
+
+    /// Client may reach the API (IPv4-mapped IPv6 matches its IPv4 entry)
+    ///
+    /// Loopback is always allowed: the watchdog probes the API locally.
+    fn allows(&self, ip: IpAddr) -> bool {
+        let ip = ip.to_canonical();
+        self.allowed_ips.is_empty() || ip.is_loopback() || self.allowed_ips.contains(&ip)
+    }
+
+    /// Request carries the control token
+    fn authorized(&self, headers: &HeaderMap) -> bool {
+        let Some(token) = &self.token else {
+            return false;
+        };
+        headers
+            .get(header::AUTHORIZATION)
+            .and_then(|value| value.to_str().ok())
+            .and_then(|value| value.strip_prefix("Bearer "))
+            .is_some_and(|presented| constant_time_eq(presented.as_bytes(), token.as_bytes()))
+    }
+}
+
+/// Comparison time independent of where the inputs differ
+fn constant_time_eq(a: &[u8], b: &[u8]) -> bool {
+    a.len() == b.len() && a.iter().zip(b).fold(0u8, |diff, (x, y)| diff | (x ^ y)) == 0
+}
+
+/// Reject clients outside the allowlist
+async fn require_allowed_ip(
+    State(guard): State<ApiGuard>,
+    ConnectInfo(addr): ConnectInfo<SocketAddr>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !guard.allows(addr.ip()) {
+        tracing::warn!("API request from {} rejected: not in allowlist", addr.ip());
+        return StatusCode::FORBIDDEN.into_response();
+    }
+    next.run(request).await
+}
+
+/// Reject control and debug requests without the bearer token
+async fn require_token(
+    State(guard): State<ApiGuard>,
+    request: Request,
+    next: Next,
+) -> Response {
+    if !guard.authorized(request.headers()) {
+        tracing::warn!("Unauthorized {} {} rejected", request.method(), request.uri().path());
+        return StatusCode::UNAUTHORIZED.into_response();
+    }
+    next.run(request).await
+}
+
+/// CORS policy: same origin only by default, `"*"` allows any origin
+fn cors_layer(origins: &[String]) -> CorsLayer {
+    if origins.iter().any(|origin| origin == "*") {
+        return CorsLayer::permissive();
+    }
+    let origins: Vec<HeaderValue> = origins
+        .iter()
+        .filter_map(|origin| match HeaderValue::from_str(origin) {
+            Ok(value) => Some(value),
+            Err(_) => {
+                tracing::warn!("Ignoring invalid CORS origin {:?}", origin);
+                None
+            }
+        })
+        .collect();
+    CorsLayer::new()
+        .allow_origin(AllowOrigin::list(origins))
+        .allow_methods([Method::GET, Method::POST])
+        .allow_headers([header::AUTHORIZATION, header::CONTENT_TYPE])
+}
+
+/// Serve dashboard files from `static_path`, or the embedded copy when it
+/// does not exist (`embed-frontend` feature)
+fn with_static_files(app: Router<AppState>, static_path: &std::path::Path) -> Router<AppState> {
+    #[cfg(feature = "embed-frontend")]
+    if !static_path.is_dir() {
+        tracing::info!("Serving {} embedded dashboard files", crate::infrastructure::embedded::len());
+        return app.fallback(crate::infrastructure::embedded::serve);
+    }
+    tracing::info!("Serving dashboard from {}", static_path.display());
+    app.fallback_service(ServeDir::new(static_path))
+}
+
+/// Control endpoints behind the bearer token
+fn control_routes(guard: ApiGuard) -> Router<AppState> {
+    Router::new()
+        .route("/api/pause", post(pause_engine))
+        .route("/api/resume", post(resume_engine))
+        .route("/api/shutdown", post(shutdown_engine))
+        .route("/api/symbols/:symbol/execution", post(set_symbol_execution))
+        .route("/api/order", post(place_manual_order))
+        .route("/api/flatten/:target", post(flatten_positions))
+        .route_layer(middleware::from_fn_with_state(guard, require_token))
+}
+
+/// Introspection endpoints behind the bearer token (raw frames, internals)
+fn debug_routes(guard: ApiGuard) -> Router<AppState> {
+    Router::new()
+        .route("/api/debug", get(get_debug))
+        .route("/api/debug/unparsed", get(get_unparsed))
+        .route_layer(middleware::from_fn_with_state(guard, require_token))
 }
 
 /// Start the API server
 pub async fn start_server(
     tracker: Arc<RwLock<ThresholdTracker>>,
     metrics: Arc<MetricsCollector>,
+    journal: Arc<RwLock<OpportunityJournal>>,
+    history: Option<Arc<RwLock<HistoryStore>>>,
+    presence: Arc<SymbolPresence>,
+    control: Arc<EngineControl>,
     api_config: &ApiConfig
 ) -> Result<(), HftError> {
-    let state = AppState { tracker, metrics };
+    let stream_interval = Duration::from_millis(api_config.stream_interval_ms.max(1));
+    let state = AppState {
+        tracker,
+        metrics,
+        journal,
+        history,
+        presence,
+        control,
+        stream_interval,
+        stream_resync_every: api_config.stream_resync_every,
+        screener: Arc::new(ScreenerCache::new(stream_interval)),
+        snapshots: Arc::new(MarketSnapshotter::new(Duration::from_millis(api_config.snapshot_interval_ms))),
+        metrics_history: Arc::new(Mutex::new(MetricsHistory::new())),
+    };
+    tokio::spawn(state.snapshots.clone().run(state.tracker.clone()));
+    tokio::spawn(metrics_history::run(state.metrics_history.clone(), state.metrics.clone(), Duration::from_secs(1)));
 
-    // Static files service from config
-    let static_files = ServeDir::new(&api_config.static_path);
+    let guard = ApiGuard::new(api_config);
+    if guard.token.is_none() {
+        tracing::warn!("No API token configured (HFT_API_TOKEN): control and debug endpoints disabled");
+    }
 
     let app = Router::new()
         // API Endpoints
         .route("/api/dashboard/stats", get(get_dashboard_stats))
         .route("/api/screener/stats", get(get_screener_stats))
-        
-        // Static files fallback
-        .fallback_service(static_files)
-        
+        .route("/api/screener/ws", get(screener_stream))
+        .route("/api/metrics/feeds", get(get_feed_race_stats))
+        .route("/api/metrics/connections", get(get_connection_stats))
+        .route("/api/metrics/history", get(get_metrics_history))
+        .route("/api/latency", get(get_order_latency))
+        .route("/api/execution-quality", get(get_execution_quality))
+        .route("/api/pnl/attribution", get(get_pnl_attribution))
+        .route("/api/snapshot", get(get_market_snapshot))
+        .route("/api/opportunities", get(get_opportunities))
+        .route("/api/execution-attempts", get(get_execution_attempts))
+        .route("/api/heatmap", get(get_heatmap))
+        .route("/api/sessions", get(get_sessions))
+        .route("/api/lead-lag", get(get_lead_lag))
+        .route("/api/rebalance", get(get_rebalance))
+        .route("/api/symbols", get(get_symbols))
+        .route("/api/book/:symbol", get(get_book))
+        .route("/api/history/episodes", get(get_history_episodes))
+        .route("/api/history/executions", get(get_history_executions))
+        .route("/api/health", get(get_health))
+        .route("/api/health/live", get(get_live))
+        .route("/api/openapi.json", get(get_openapi))
+        .route("/api/docs", get(get_docs))
+        .merge(control_routes(guard.clone()))
+        .merge(debug_routes(guard.clone()));
+
+    // Static files fallback
+    let app = with_static_files(app, &api_config.static_path)
         // Middleware
-        .layer(CorsLayer::permissive())
+        .layer(middleware::from_fn_with_state(guard, require_allowed_ip))
+        .layer(cors_layer(&api_config.cors_origins))
         .with_state(state);
 
     let addr = SocketAddr::from(([0, 0, 0, 0], api_config.port));
     tracing::info!("API Server listening on {}", addr);
 
     let listener = tokio::net::TcpListener::bind(addr).await
-        .map_err(|e| HftError::Io(e))?;
+        .map_err(HftError::Io)?;
         
-    axum::serve(listener, app).await
-        .map_err(|e| HftError::Io(e))?;
+    axum::serve(listener, app.into_make_service_with_connect_info::<SocketAddr>()).await
+        .map_err(HftError::Io)?;
 
     Ok(())
 }
@@ -138,6 +997,26 @@ async fn get_dashboard_stats(
         active_symbols,
         binance_connected: metrics_snapshot.binance_connected,
         bybit_connected: metrics_snapshot.bybit_connected,
+        suppressed_cooldown: metrics_snapshot.suppressed_cooldown,
+        suppressed_rate_limit: metrics_snapshot.suppressed_rate_limit,
+        suppressed_unfocused: metrics_snapshot.suppressed_unfocused,
+        suppressed_warmup: metrics_snapshot.suppressed_warmup,
+        suppressed_paused: metrics_snapshot.suppressed_paused,
+        suppressed_disabled: metrics_snapshot.suppressed_disabled,
+        suppressed_delisted: metrics_snapshot.suppressed_delisted,
+        suppressed_halted: metrics_snapshot.suppressed_halted,
+        suppressed_peer: metrics_snapshot.suppressed_peer,
+        suppressed_standby: metrics_snapshot.suppressed_standby,
+        suppressed_private_stream: metrics_snapshot.suppressed_private_stream,
+        suppressed_risk: metrics_snapshot.suppressed_risk,
+        suppressed_read_only: metrics_snapshot.suppressed_read_only,
+        quarantined_quotes: metrics_snapshot.quarantined_quotes,
+        locked_books: metrics_snapshot.locked_books,
+        crossed_books: metrics_snapshot.crossed_books,
+        focused_symbols: metrics_snapshot.focused_symbols,
+        task_restarts: metrics_snapshot.task_restarts,
+        failed_tasks: metrics_snapshot.failed_tasks,
+        engine_stalls: metrics_snapshot.engine_stalls,
     };
     
     Json(DashboardDto {
@@ -147,18 +1026,519 @@ async fn get_dashboard_stats(
 }
 
 /// Handler for /api/screener/stats
-/// Returns screener data only (backward compatibility)
+/// Returns screener data only (backward compatibility), served as cached
+/// JSON bytes rebuilt at most once per stream interval
 async fn get_screener_stats(
     State(state): State<AppState>
-) -> Json<Vec<ScreenerDto>> {
-    // Note: Using write lock because get_all_stats needs to evict old entries
-    let mut tracker = state.tracker.write().await;
-    let stats = tracker.get_all_stats();
-    
-    let dtos: Vec<ScreenerDto> = stats
-        .into_iter()
-        .map(ScreenerDto::from)
+) -> Response {
+    let body = state.screener.get(&state.tracker).await;
+    ([(header::CONTENT_TYPE, HeaderValue::from_static("application/json"))], body).into_response()
+}
+
+/// Handler for /api/screener/ws
+/// Pushes a full screener snapshot, then only changed rows every interval
+async fn screener_stream(
+    ws: WebSocketUpgrade,
+    State(state): State<AppState>
+) -> Response {
+    ws.on_upgrade(move |socket| stream_screener(socket, state))
+}
+
+/// Per-client screener stream loop
+///
+/// Sending the text `resync` forces a full snapshot on the next push.
+async fn stream_screener(mut socket: WebSocket, state: AppState) {
+    let mut differ = SnapshotDiffer::new(state.stream_resync_every);
+    let mut interval = tokio::time::interval(state.stream_interval);
+    interval.set_missed_tick_behavior(MissedTickBehavior::Skip);
+    let mut buf = Vec::with_capacity(4096);
+
+    loop {
+        tokio::select! {
+            _ = interval.tick() => {}
+            msg = socket.recv() => match msg {
+                Some(Ok(Message::Text(text))) if text == "resync" => differ.reset(),
+                Some(Ok(Message::Close(_))) | Some(Err(_)) | None => break,
+                Some(Ok(_)) => continue,
+            },
+        }
+
+        // Write lock: get_all_stats evicts stale entries (cold path)
+        let stats = state.tracker.write().await.get_all_stats();
+        let update = differ.diff(stats);
+        if update.is_empty() {
+            continue;
+        }
+
+        buf.clear();
+        update.write_json(&mut buf);
+        let text = String::from_utf8_lossy(&buf).into_owned();
+        if socket.send(Message::Text(text)).await.is_err() {
+            break;
+        }
+    }
+}
+
+/// Handler for /api/metrics/feeds
+/// Returns A/B race win rates and lag distribution per connection
+async fn get_feed_race_stats(
+    State(state): State<AppState>
+) -> Json<Vec<FeedRaceSnapshot>> {
+    Json(state.metrics.feed_race().snapshot())
+}
+
+/// Handler for /api/metrics/history
+/// Returns message-rate and latency points at 1s (last hour) or 1m (last day) resolution
+async fn get_metrics_history(
+    State(state): State<AppState>,
+    Query(query): Query<MetricsHistoryQuery>
+) -> Json<Vec<MetricsPoint>> {
+    Json(state.metrics_history.lock().points(query.resolution, query.since.unwrap_or(0)))
+}
+
+/// Handler for /api/latency
+/// Returns order-ack round-trip percentiles per measured venue
+async fn get_order_latency(
+    State(state): State<AppState>
+) -> Json<Vec<OrderLatencySnapshot>> {
+    Json(state.metrics.order_latency())
+}
+
+/// Handler for /api/snapshot
+/// Returns all quote pairs as of the last snapshotter tick
+async fn get_market_snapshot(
+    State(state): State<AppState>
+) -> Json<MarketSnapshotDto> {
+    Json(MarketSnapshotDto::from(state.snapshots.latest().as_ref()))
+}
+
+/// Handler for /api/execution-quality
+/// Returns fill slippage vs decision price per venue and day, plus the latest fills
+async fn get_execution_quality(
+    State(state): State<AppState>
+) -> Json<ExecutionQualitySnapshot> {
+    Json(state.metrics.execution_quality())
+}
+
+/// Handler for /api/pnl/attribution
+/// Returns realized PnL and estimated fees per strategy, symbol and recent episode
+async fn get_pnl_attribution(
+    State(state): State<AppState>
+) -> Json<PnlAttributionSnapshot> {
+    Json(state.metrics.pnl_attribution())
+}
+
+/// Handler for /api/metrics/connections
+/// Returns message rate, gaps and exchange latency per connection
+async fn get_connection_stats(
+    State(state): State<AppState>
+) -> Json<Vec<ConnectionSnapshot>> {
+    Json(state.metrics.connections().snapshot(&state.metrics.feed_race().labels()))
+}
+
+/// Handler for /api/opportunities
+/// Returns recent journaled opportunities (newest first) with sizing decision
+async fn get_opportunities(
+    State(state): State<AppState>
+) -> Json<Vec<OpportunityDto>> {
+    let journal = state.journal.read().await;
+    Json(journal.recent(OPPORTUNITY_LIMIT).map(OpportunityDto::from).collect())
+}
+
+/// Handler for /api/execution-attempts
+/// Returns recent execution attempts (newest first) with per-leg timing
+async fn get_execution_attempts(
+    State(state): State<AppState>
+) -> Json<Vec<ExecutionAttemptDto>> {
+    let journal = state.journal.read().await;
+    Json(journal.recent_attempts(OPPORTUNITY_LIMIT).map(ExecutionAttemptDto::from).collect())
+}
+
+/// Handler for /api/heatmap
+/// Returns opportunity hit counts as a (symbol × time bucket) matrix over the last `hours`
+async fn get_heatmap(
+    State(state): State<AppState>,
+    Query(query): Query<HeatmapQuery>
+) -> Json<HeatmapDto> {
+    let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_nanos() as u64;
+    let hours = query.hours.unwrap_or(HEATMAP_RETENTION_HOURS);
+    let bucket = query.bucket_minutes.unwrap_or(15) * 60 * 1_000_000_000;
+    let journal = state.journal.read().await;
+    Json(journal.heatmap(now, hours, bucket).into())
+}
+
+/// Handler for /api/sessions
+/// Returns opportunity frequency and average net spread by hour-of-day and
+/// weekday per symbol (optionally filtered by `symbol`)
+async fn get_sessions(
+    State(state): State<AppState>,
+    Query(query): Query<SymbolQuery>
+) -> Json<Vec<SessionStatsDto>> {
+    let tracker = state.tracker.read().await;
+    Json(
+        tracker
+            .session_stats()
+            .iter()
+            .filter(|(symbol, _)| {
+                query.symbol.as_deref().is_none_or(|s| s.eq_ignore_ascii_case(symbol.as_str()))
+            })
+            .map(|(symbol, stats)| SessionStatsDto::new(symbol.as_str(), stats))
+            .collect(),
+    )
+}
+
+/// Handler for /api/lead-lag
+/// Returns which venue leads mid-price moves and by how many milliseconds,
+/// strongest correlation first (optionally filtered by `symbol`)
+async fn get_lead_lag(
+    State(state): State<AppState>,
+    Query(query): Query<SymbolQuery>
+) -> Json<Vec<LeadLagDto>> {
+    let tracker = state.tracker.read().await;
+    Json(
+        tracker
+            .lead_lag()
+            .iter()
+            .filter(|(symbol, _)| {
+                query.symbol.as_deref().is_none_or(|s| s.eq_ignore_ascii_case(symbol.as_str()))
+            })
+            .map(|(symbol, lead)| LeadLagDto::new(symbol.as_str(), lead))
+            .collect(),
+    )
+}
+
+/// Handler for /api/rebalance
+/// Inventory rebalance opportunities (withdraw + transfer), best net edge first
+async fn get_rebalance(
+    State(state): State<AppState>
+) -> Json<Vec<RebalanceDto>> {
+    let tracker = state.tracker.read().await;
+    Json(tracker.rebalance_opportunities().iter().map(RebalanceDto::from).collect())
+}
+
+/// Handler for /api/symbols
+/// Returns every registered symbol with per-venue listing, subscription
+/// state, last-update age and locked/crossed counts (optionally filtered by `symbol`)
+async fn get_symbols(
+    State(state): State<AppState>,
+    Query(query): Query<SymbolQuery>
+) -> Json<Vec<SymbolPresenceDto>> {
+    let Some(registry) = SymbolRegistry::try_global() else {
+        return Json(Vec::new());
+    };
+    let now = Instant::now();
+    let tracker = state.tracker.read().await;
+    Json(
+        registry
+            .symbols()
+            .filter(|(_, name)| query.symbol.as_deref().is_none_or(|s| s.eq_ignore_ascii_case(name)))
+            .map(|(symbol, name)| {
+                let listed = state.presence.listed(symbol);
+                let updated_at = tracker.updated_at(symbol);
+                let anomalies = tracker.book_anomalies(symbol);
+                let venues = Exchange::ALL
+                    .iter()
+                    .map(|exchange| VenuePresenceDto {
+                        exchange: exchange.name(),
+                        listed: listed[exchange.index()],
+                        last_update_age_ms: updated_at[exchange.index()]
+                            .map(|at| now.saturating_duration_since(at).as_millis() as u64),
+                        locked_books: anomalies[exchange.index()].locked,
+                        crossed_books: anomalies[exchange.index()].crossed,
+                    })
+                    .collect();
+                SymbolPresenceDto {
+                    id: symbol.as_raw(),
+                    symbol: name.to_string(),
+                    subscription: state.presence.state(symbol),
+                    execution_enabled: state.control.is_symbol_enabled(name),
+                    venues,
+                }
+            })
+            .collect(),
+    )
+}
+
+/// Handler for /api/history/episodes
+/// Returns stored opportunity episodes (newest first) filtered by
+/// `symbol`, `from`/`to` (ms since epoch), `minSpread` and `limit`
+async fn get_history_episodes(
+    State(state): State<AppState>,
+    Query(query): Query<HistoryQuery>
+) -> Result<Json<Vec<EpisodeRow>>, StatusCode> {
+    let history = state.history.as_ref().ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    Ok(Json(history.read().await.episodes(&query)))
+}
+
+/// Handler for /api/history/executions
+/// Returns stored executions (newest first) filtered by `symbol`, `from`/`to` and `limit`
+async fn get_history_executions(
+    State(state): State<AppState>,
+    Query(query): Query<HistoryQuery>
+) -> Result<Json<Vec<ExecutionRow>>, StatusCode> {
+    let history = state.history.as_ref().ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    Ok(Json(history.read().await.executions(&query)))
+}
+
+/// Handler for /api/book/:symbol
+/// Returns tracked book levels per venue with cumulative notional (404 if unknown)
+async fn get_book(
+    State(state): State<AppState>,
+    Path(symbol): Path<String>
+) -> Result<Json<BookDto>, StatusCode> {
+    let symbol = Symbol::from_bytes(symbol.to_ascii_uppercase().as_bytes()).ok_or(StatusCode::NOT_FOUND)?;
+    let tracker = state.tracker.read().await;
+    let venues = Exchange::ALL
+        .iter()
+        .map(|&exchange| {
+            let ticker = tracker.ticker(symbol, exchange);
+            VenueBookDto {
+                exchange: exchange.name(),
+                bids: book_levels(ticker.map(|t| (t.bid_price, t.bid_qty))),
+                asks: book_levels(ticker.map(|t| (t.ask_price, t.ask_qty))),
+            }
+        })
         .collect();
-        
-    Json(dtos)
+    Ok(Json(BookDto { symbol: symbol.as_str().to_string(), venues }))
+}
+
+/// Handler for /api/health
+/// Returns startup stages, connection, warm-up and feed task state
+async fn get_health(
+    State(state): State<AppState>
+) -> Json<HealthDto> {
+    let snapshot = state.metrics.snapshot();
+    let pause = state.control.status();
+    let startup = state.metrics.startup().snapshot();
+    Json(HealthDto {
+        ready: startup.ready && state.metrics.is_connected() && !snapshot.warmup_active && !pause.paused && snapshot.failed_tasks == 0,
+        startup,
+        uptime_seconds: snapshot.uptime_seconds,
+        binance_connected: snapshot.binance_connected,
+        bybit_connected: snapshot.bybit_connected,
+        warmup: WarmupDto {
+            active: snapshot.warmup_active,
+            remaining_secs: snapshot.warmup_remaining_secs,
+            warm_symbols: snapshot.warm_symbols,
+        },
+        pause: pause.into(),
+        read_only: snapshot.read_only,
+        tasks: state.metrics.tasks().snapshot(),
+        circuits: state.metrics.circuits().health(Instant::now()),
+    })
+}
+
+/// Handler for POST /api/pause
+/// Stops execution (and spread tracking with `tracker=true`); feeds stay connected
+async fn pause_engine(
+    State(state): State<AppState>,
+    Query(query): Query<PauseQuery>
+) -> Json<PauseDto> {
+    state.control.pause(query.tracker);
+    tracing::warn!("Engine paused via API (tracker paused: {})", query.tracker);
+    Json(state.control.status().into())
+}
+
+/// Handler for POST /api/resume
+async fn resume_engine(
+    State(state): State<AppState>
+) -> Json<PauseDto> {
+    state.control.resume();
+    tracing::warn!("Engine resumed via API");
+    Json(state.control.status().into())
+}
+
+/// Handler for POST /api/symbols/:symbol/execution
+/// Enables or disables execution on one registered symbol (404 if unknown)
+async fn set_symbol_execution(
+    State(state): State<AppState>,
+    Path(symbol): Path<String>,
+    Query(query): Query<SymbolExecutionQuery>
+) -> Result<Json<SymbolExecutionDto>, StatusCode> {
+    let symbol = Symbol::from_bytes(symbol.to_ascii_uppercase().as_bytes()).ok_or(StatusCode::NOT_FOUND)?;
+    state.control.set_symbol_enabled(symbol.as_str(), query.enabled);
+    tracing::warn!("Execution {} for {} via API", if query.enabled { "enabled" } else { "disabled" }, symbol.as_str());
+    Ok(Json(SymbolExecutionDto {
+        symbol: symbol.as_str().to_string(),
+        execution_enabled: state.control.is_symbol_enabled(symbol.as_str()),
+    }))
+}
+
+/// Handler for POST /api/order
+/// Routes an operator order through the engine's live gates: 400 if the
+/// order is malformed, 404 if the symbol is unknown, 503 if the engine
+/// loop does not answer
+async fn place_manual_order(
+    State(state): State<AppState>,
+    Json(body): Json<ManualOrderBody>
+) -> Result<Json<ManualOrderDto>, StatusCode> {
+    let symbol = Symbol::from_bytes(body.symbol.to_ascii_uppercase().as_bytes()).ok_or(StatusCode::NOT_FOUND)?;
+    let order = body.to_order(symbol).ok_or(StatusCode::BAD_REQUEST)?;
+    tracing::warn!("Manual {:?} {} on {:?} via API", order.side, symbol.as_str(), order.exchange);
+    let outcome = state.control.orders().submit(order).await.ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    Ok(Json(outcome.into()))
+}
+
+/// Handler for POST /api/flatten/:target
+/// Market-closes positions on one symbol, or on every symbol with `all`,
+/// and reports what is still open after the retries (404 if the symbol is
+/// unknown, 503 if the engine loop does not answer)
+async fn flatten_positions(
+    State(state): State<AppState>,
+    Path(target): Path<String>
+) -> Result<Json<FlattenDto>, StatusCode> {
+    let target = if target.eq_ignore_ascii_case("all") {
+        FlattenTarget::All
+    } else {
+        FlattenTarget::Symbol(Symbol::from_bytes(target.to_ascii_uppercase().as_bytes()).ok_or(StatusCode::NOT_FOUND)?)
+    };
+    tracing::warn!("Flatten {} via API", target.name());
+    let report = state.control.orders().flatten(target).await.ok_or(StatusCode::SERVICE_UNAVAILABLE)?;
+    Ok(Json(report.into()))
+}
+
+/// Handler for POST /api/shutdown
+/// Stops the engine loop; the session report is written before exit
+async fn shutdown_engine(
+    State(state): State<AppState>
+) -> StatusCode {
+    tracing::warn!("Shutdown requested via API");
+    state.control.request_shutdown();
+    StatusCode::ACCEPTED
+}
+
+/// Handler for /api/health/live
+/// Liveness probe (watchdog)
+async fn get_live() -> &'static str {
+    "ok"
+}
+
+/// Handler for /api/openapi.json
+async fn get_openapi() -> Json<serde_json::Value> {
+    Json(openapi::document())
+}
+
+/// Handler for /api/docs
+/// Swagger UI over /api/openapi.json
+async fn get_docs() -> Html<&'static str> {
+    Html(openapi::SWAGGER_UI)
+}
+
+/// Handler for /api/debug
+/// Returns pool occupancy, channel depths, tracker occupancy and memory, connection buffers
+/// and history writer throughput
+async fn get_debug(
+    State(state): State<AppState>
+) -> Json<DebugDto> {
+    let tracker = {
+        let tracker = state.tracker.read().await;
+        TrackerOccupancyDto {
+            symbols: tracker.occupancy(),
+            capacity: MAX_SYMBOLS,
+            memory_bytes: tracker.memory_bytes(),
+            evictions: tracker.evictions(),
+        }
+    };
+    let debug = state.metrics.debug();
+    Json(DebugDto {
+        pools: debug.pools(),
+        queues: debug.queues(),
+        tracker,
+        connections: debug.connections(&state.metrics.feed_race().labels()),
+        recorder: debug.recorder(state.metrics.snapshot().uptime_seconds),
+        invariants: debug.invariants().snapshot(),
+    })
+}
+
+/// Handler for /api/debug/unparsed
+/// Returns parse failures per exchange and message type, with the last raw
+/// frames that failed
+async fn get_unparsed(State(state): State<AppState>) -> Json<UnparsedSnapshot> {
+    Json(state.metrics.debug().unparsed().snapshot())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{body::Body, extract::connect_info::MockConnectInfo};
+    use tower::ServiceExt;
+
+    async fn status(app: &Router, from: [u8; 4], token: Option<&str>) -> StatusCode {
+        let mut request = Request::post("/api/pause");
+        if let Some(token) = token {
+            request = request.header(header::AUTHORIZATION, format!("Bearer {}", token));
+        }
+        let app = app.clone().layer(MockConnectInfo(SocketAddr::from((from, 40_000))));
+        app.oneshot(request.body(Body::empty()).unwrap()).await.unwrap().status()
+    }
+
+    #[tokio::test]
+    async fn test_api_guard() {
+        let config = ApiConfig {
+            auth_token: Some("s3cret".to_string()),
+            allowed_ips: vec!["10.0.0.5".parse().unwrap()],
+            ..Default::default()
+        };
+        let guard = ApiGuard::new(&config);
+        // IPv4-mapped IPv6 clients match their IPv4 entry
+        assert!(guard.allows("::ffff:10.0.0.5".parse().unwrap()));
+
+        let app = Router::new()
+            .route("/api/pause", post(|| async { "paused" }))
+            .route_layer(middleware::from_fn_with_state(guard.clone(), require_token))
+            .layer(middleware::from_fn_with_state(guard, require_allowed_ip));
+
+        assert_eq!(status(&app, [10, 0, 0, 5], Some("s3cret")).await, StatusCode::OK);
+        assert_eq!(status(&app, [10, 0, 0, 5], Some("wrong")).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(&app, [10, 0, 0, 5], None).await, StatusCode::UNAUTHORIZED);
+        assert_eq!(status(&app, [10, 0, 0, 6], Some("s3cret")).await, StatusCode::FORBIDDEN);
+        // Loopback outside the allowlist still reaches the API, control still needs the token
+        assert_eq!(status(&app, [127, 0, 0, 1], Some("s3cret")).await, StatusCode::OK);
+        assert_eq!(status(&app, [127, 0, 0, 1], None).await, StatusCode::UNAUTHORIZED);
+
+        // The watchdog's liveness probe is not locked out by an allowlist without loopback
+        let guard = ApiGuard::new(&config);
+        assert!(guard.allows("::1".parse().unwrap()) && guard.allows("::ffff:127.0.0.1".parse().unwrap()));
+        let live = Router::new()
+            .route("/api/health/live", get(|| async { "ok" }))
+            .layer(middleware::from_fn_with_state(guard, require_allowed_ip));
+        for (from, expected) in [([127, 0, 0, 1], StatusCode::OK), ([10, 0, 0, 6], StatusCode::FORBIDDEN)] {
+            let app = live.clone().layer(MockConnectInfo(SocketAddr::from((from, 40_000))));
+            let request = Request::get("/api/health/live").body(Body::empty()).unwrap();
+            assert_eq!(app.oneshot(request).await.unwrap().status(), expected);
+        }
+
+        // No token configured: control endpoints are refused
+        let open = ApiGuard::default();
+        assert!(open.allows("192.0.2.1".parse().unwrap()));
+        assert!(!open.authorized(&HeaderMap::new()));
+    }
+
+    #[test]
+    fn test_manual_order_body() {
+        crate::test_utils::init_test_registry();
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let body: ManualOrderBody = serde_json::from_str(
+            r#"{"venue":"Bybit","symbol":"btcusdt","side":"SELL","type":"limit","price":101.5,"qty":0.25}"#
+        ).unwrap();
+        let order = body.to_order(symbol).unwrap();
+        assert_eq!((order.exchange, order.side), (Exchange::Bybit, Side::Sell));
+        assert_eq!(order.price, FixedPoint8::from_f64(101.5));
+        assert_eq!(order.quantity, FixedPoint8::from_f64(0.25).unwrap());
+
+        let invalid = |f: fn(&mut ManualOrderBody)| {
+            let mut body: ManualOrderBody = serde_json::from_str(
+                r#"{"venue":"binance","symbol":"BTCUSDT","side":"buy","orderType":"market","price":null,"qty":1}"#
+            ).unwrap();
+            assert!(body.to_order(symbol).is_some());
+            f(&mut body);
+            body.to_order(symbol).is_none()
+        };
+        assert!(invalid(|b| b.venue = "okx".into()));
+        assert!(invalid(|b| b.side = "short".into()));
+        assert!(invalid(|b| b.qty = 0.0));
+        assert!(invalid(|b| b.price = Some(100.0)));
+        assert!(invalid(|b| b.order_type = "limit".into()));
+        assert!(invalid(|b| b.order_type = "stop".into()));
+    }
 }
@@ -3,8 +3,12 @@
 //! Loads configuration from config.toml at startup.
 //! All values are configurable to avoid hardcoded constants.
 
+use crate::core::Bps;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr};
 use std::path::PathBuf;
+use std::time::Duration;
 
 /// HFT Configuration
 ///
@@ -12,42 +16,1533 @@ use std::path::PathBuf;
 /// to avoid hardcoded values throughout the codebase.
 #[derive(Debug, Clone, Default, Deserialize, Serialize)]
 pub struct Config {
+    /// Master switch: never send orders (see [`Config::trading_permit`])
+    #[serde(default)]
+    pub read_only: bool,
+
     /// HFT-specific settings
     #[serde(default)]
-    pub hft: HftConfig,
+    pub hft: HftConfig,
+
+    /// API server settings
+    #[serde(default)]
+    pub api: ApiConfig,
+
+    /// Strategy plugin selection
+    #[serde(default)]
+    pub strategies: StrategyConfig,
+
+    /// Outbound network settings (WS/REST source address)
+    #[serde(default)]
+    pub network: NetworkConfig,
+
+    /// Opportunity sizing model
+    #[serde(default)]
+    pub sizing: SizingConfig,
+
+    /// Break-even cost model
+    #[serde(default)]
+    pub costs: CostConfig,
+
+    /// Execution throttling
+    #[serde(default)]
+    pub execution: ExecutionConfig,
+
+    /// Startup warm-up from REST klines
+    #[serde(default)]
+    pub warmup: WarmupConfig,
+
+    /// Top-N symbol auto-focus
+    #[serde(default)]
+    pub focus: FocusConfig,
+
+    /// Delisting detection from instruments metadata
+    #[serde(default)]
+    pub delisting: DelistingConfig,
+
+    /// Historical opportunity database
+    #[serde(default)]
+    pub history: HistoryConfig,
+
+    /// systemd watchdog (active only under `Type=notify`)
+    #[serde(default)]
+    pub watchdog: WatchdogConfig,
+
+    /// Per-symbol hit threshold calibration and overrides
+    #[serde(default)]
+    pub thresholds: ThresholdConfig,
+
+    /// Quote sanity filters (flash-glitch guard)
+    #[serde(default)]
+    pub sanity: SanityConfig,
+
+    /// Feed task restart policy
+    #[serde(default)]
+    pub supervision: SupervisionConfig,
+
+    /// Inventory transfer costs for rebalance opportunities
+    #[serde(default)]
+    pub transfers: TransferConfig,
+
+    /// USDT/USDC/USD conversion factors
+    #[serde(default)]
+    pub rates: RatesConfig,
+
+    /// End-of-run session report
+    #[serde(default)]
+    pub report: ReportConfig,
+
+    /// Opportunity alert conflation
+    #[serde(default)]
+    pub alerts: AlertConfig,
+
+    /// Cold-start symbol priority from historical opportunity rates
+    #[serde(default)]
+    pub priority: PriorityConfig,
+
+    /// Per-symbol leverage and margin mode on each venue
+    #[serde(default)]
+    pub leverage: LeverageConfig,
+
+    /// Peer instance in another region (leg ownership, ticker sharing)
+    #[serde(default)]
+    pub peer: PeerConfig,
+
+    /// Hot standby: state replication and failover between two instances
+    #[serde(default)]
+    pub standby: StandbyConfig,
+}
+
+/// Proof that the process may send orders
+///
+/// Only obtainable from [`Config::trading_permit`], which refuses in
+/// read-only mode. Anything able to place orders (execution engine, trading
+/// REST clients) requires one to be constructed.
+#[derive(Debug)]
+pub struct TradingPermit {
+    _private: (),
+}
+
+/// HFT trading configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HftConfig {
+    /// Minimum 24h volume to consider a symbol liquid (in USDT)
+    #[serde(default = "default_min_volume")]
+    pub min_volume_24h: f64,
+
+    /// Opportunity threshold as a raw FixedPoint8 fraction (see [`Bps`])
+    /// 250_000 = 0.0025 = 25 bps = 0.25% spread between exchanges
+    #[serde(default = "default_threshold")]
+    pub opportunity_threshold_bps: i64,
+
+    /// Rolling window duration in seconds for spread history
+    #[serde(default = "default_window_seconds")]
+    pub window_seconds: u64,
+
+    /// Feed → engine channel capacity (messages)
+    #[serde(default = "default_engine_queue_capacity")]
+    pub engine_queue_capacity: usize,
+
+    /// WebSocket read buffer per connection (bytes)
+    #[serde(default = "default_read_buffer_bytes")]
+    pub read_buffer_bytes: usize,
+
+    /// Receive timeout between keepalive checks (milliseconds, Bybit)
+    #[serde(default = "default_recv_timeout_ms")]
+    pub recv_timeout_ms: u64,
+
+    /// Inactivity before an application-level ping (seconds, Bybit)
+    #[serde(default = "default_ping_interval_secs")]
+    pub ping_interval_secs: u64,
+
+    /// Coarse clock refresh interval (ms, see core::clock)
+    #[serde(default = "default_clock_tick_ms")]
+    pub clock_tick_ms: u64,
+
+    /// Fine timestamps from the TSC (x86_64 with invariant TSC only)
+    #[serde(default)]
+    pub tsc_clock: bool,
+
+    /// Engine loop iteration budget (ms); slower iterations are logged as
+    /// stalls (0 = off)
+    #[serde(default = "default_stall_budget_ms")]
+    pub stall_budget_ms: u64,
+
+    /// Symbol state is dropped after this long without fresh tickers from
+    /// both venues, and recreated on the next update (seconds, 0 = never)
+    #[serde(default = "default_idle_evict_secs")]
+    pub idle_evict_secs: u64,
+
+    /// Check frame structure (balanced braces, terminated strings) before
+    /// field extraction; rejected frames go to the parse quarantine
+    #[serde(default)]
+    pub validate_frames: bool,
+
+    /// Longest frame accepted when `validate_frames` is on (bytes)
+    #[serde(default = "default_max_frame_bytes")]
+    pub max_frame_bytes: usize,
+
+    /// Check market data invariants (bid <= ask, positive prices, monotonic
+    /// timestamps, registered symbol) on 1 in N messages per feed (0 = off)
+    #[serde(default = "default_assert_sample_every")]
+    pub assert_sample_every: u64,
+
+    /// Keep frames violating an invariant in the parse quarantine
+    #[serde(default)]
+    pub assert_capture_frames: bool,
+}
+
+/// API server configuration
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ApiConfig {
+    /// Port for HTTP API server
+    #[serde(default = "default_api_port")]
+    pub port: u16,
+
+    /// Path to static files (frontend)
+    ///
+    /// With the `embed-frontend` feature, embedded assets are served when
+    /// this directory does not exist.
+    #[serde(default = "default_static_path")]
+    pub static_path: PathBuf,
+
+    /// Screener stream push interval (milliseconds)
+    #[serde(default = "default_stream_interval_ms")]
+    pub stream_interval_ms: u64,
+
+    /// Full screener snapshot re-sent every N stream updates
+    #[serde(default = "default_stream_resync_every")]
+    pub stream_resync_every: u32,
+
+    /// Market snapshot tick (milliseconds): all quote pairs captured at once
+    #[serde(default = "default_snapshot_interval_ms")]
+    pub snapshot_interval_ms: u64,
+
+    /// Bearer token required by control endpoints (pause, resume, shutdown)
+    ///
+    /// `HFT_API_TOKEN` overrides this value; keep secrets out of the file
+    /// where possible. Without a token control endpoints are refused.
+    #[serde(default)]
+    pub auth_token: Option<String>,
+
+    /// Client addresses allowed to reach the API (empty = any; loopback always)
+    #[serde(default)]
+    pub allowed_ips: Vec<IpAddr>,
+
+    /// Origins allowed by CORS (empty = same origin only, `"*"` = any)
+    #[serde(default)]
+    pub cors_origins: Vec<String>,
+}
+
+impl ApiConfig {
+    /// Control token: `HFT_API_TOKEN`, then config (None if neither is set)
+    pub fn auth_token(&self) -> Option<String> {
+        std::env::var("HFT_API_TOKEN")
+            .ok()
+            .or_else(|| self.auth_token.clone())
+            .filter(|token| !token.is_empty())
+    }
+}
+
+/// Strategy plugin configuration
+///
+/// Names refer to strategies registered with the plugin registry
+/// (requires the `plugins` feature).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct StrategyConfig {
+    /// Strategies to run, in dispatch order
+    #[serde(default)]
+    pub enabled: Vec<String>,
+
+    /// Per-strategy parameters (`[strategies.params.<name>]`)
+    #[serde(default)]
+    pub params: HashMap<String, toml::Table>,
+
+    /// Named instances with their own capital and limits
+    /// (`[[strategies.instances]]`); one plugin may run several times
+    #[serde(default)]
+    pub instances: Vec<StrategyInstanceConfig>,
+}
+
+/// One strategy instance with a capital partition (see engine::allocator)
+///
+/// The sum of `capital` over all instances may not exceed
+/// `sizing.account_balance`.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct StrategyInstanceConfig {
+    /// Instance name (unique; used in logs)
+    pub name: String,
+
+    /// Registered strategy plugin to build
+    pub plugin: String,
+
+    /// Plugin parameters
+    #[serde(default)]
+    pub params: toml::Table,
+
+    /// Capital allocated to the instance (quote currency): cap on the
+    /// notional of its open positions
+    pub capital: f64,
+
+    /// Minimum spread passed to the instance (fraction, e.g. 0.001 = 0.1%; None = every update)
+    #[serde(default)]
+    pub min_spread: Option<f64>,
+
+    /// Largest single order notional (None = capital)
+    #[serde(default)]
+    pub max_order_notional: Option<f64>,
+
+    /// Largest open notional per symbol and venue (None = capital)
+    #[serde(default)]
+    pub max_symbol_notional: Option<f64>,
+
+    /// Symbols the instance trades and sees spreads for (empty = all)
+    #[serde(default)]
+    pub symbols: Vec<String>,
+}
+
+/// Outbound network configuration
+///
+/// Binds outbound WS/REST sockets to a local address so traffic leaves via a
+/// specific uplink. When any bind address is set, only remote addresses of a
+/// family with a bind address are used (a v4-only bind never silently falls
+/// back to an unbound v6 route).
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct NetworkConfig {
+    /// Local IPv4 address for outbound connections
+    #[serde(default)]
+    pub bind_ipv4: Option<Ipv4Addr>,
+
+    /// Local IPv6 address for outbound connections
+    #[serde(default)]
+    pub bind_ipv6: Option<Ipv6Addr>,
+
+    /// Try IPv6 remote addresses before IPv4
+    #[serde(default)]
+    pub prefer_ipv6: bool,
+}
+
+impl NetworkConfig {
+    /// Returns true if any source address is configured
+    #[inline]
+    pub fn is_bound(&self) -> bool {
+        self.bind_ipv4.is_some() || self.bind_ipv6.is_some()
+    }
+
+    /// Local address (port 0) to bind when connecting to remote
+    pub fn local_addr_for(&self, remote: &SocketAddr) -> Option<SocketAddr> {
+        let ip = match remote {
+            SocketAddr::V4(_) => IpAddr::V4(self.bind_ipv4?),
+            SocketAddr::V6(_) => IpAddr::V6(self.bind_ipv6?),
+        };
+        Some(SocketAddr::new(ip, 0))
+    }
+
+    /// Filter and order resolved remote addresses for connection attempts
+    pub fn candidates(&self, resolved: impl IntoIterator<Item = SocketAddr>) -> Vec<SocketAddr> {
+        let mut addrs: Vec<SocketAddr> = resolved
+            .into_iter()
+            .filter(|a| !self.is_bound() || self.local_addr_for(a).is_some())
+            .collect();
+        // Stable sort keeps resolver order within each family
+        addrs.sort_by_key(|a| a.is_ipv6() != self.prefer_ipv6);
+        addrs
+    }
+
+    /// Single local address for REST clients (one address per client)
+    pub fn rest_local_address(&self) -> Option<IpAddr> {
+        let v4 = self.bind_ipv4.map(IpAddr::V4);
+        let v6 = self.bind_ipv6.map(IpAddr::V6);
+        if self.prefer_ipv6 {
+            v6.or(v4)
+        } else {
+            v4.or(v6)
+        }
+    }
+}
+
+/// Opportunity sizing configuration (see engine::sizing)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SizingConfig {
+    /// Max fraction of top-of-book quantity to take on either leg
+    #[serde(default = "default_max_participation")]
+    pub max_participation: f64,
+
+    /// Max fraction of account balance per trade
+    #[serde(default = "default_balance_fraction")]
+    pub balance_fraction: f64,
+
+    /// Hard per-trade notional limit (USDT)
+    #[serde(default = "default_max_notional")]
+    pub max_notional: f64,
+
+    /// Opportunities sized below this notional are skipped (USDT)
+    #[serde(default = "default_min_notional")]
+    pub min_notional: f64,
+
+    /// Spread volatility (2m range) above which size is scaled down
+    #[serde(default = "default_target_volatility")]
+    pub target_volatility: f64,
+
+    /// Available quote balance per venue (USDT)
+    #[serde(default = "default_account_balance")]
+    pub account_balance: f64,
+
+    /// Opportunities less likely than this to survive the order round trip
+    /// are not traded (0 = disabled)
+    #[serde(default)]
+    pub min_fill_probability: f64,
+
+    /// Order round-trip latency assumed until round trips are measured (ms)
+    #[serde(default = "default_order_latency_ms")]
+    pub order_latency_ms: f64,
+}
+
+impl Default for SizingConfig {
+    fn default() -> Self {
+        Self {
+            max_participation: default_max_participation(),
+            balance_fraction: default_balance_fraction(),
+            max_notional: default_max_notional(),
+            min_notional: default_min_notional(),
+            target_volatility: default_target_volatility(),
+            account_balance: default_account_balance(),
+            min_fill_probability: 0.0,
+            order_latency_ms: default_order_latency_ms(),
+        }
+    }
+}
+
+fn default_max_participation() -> f64 {
+    0.1
+}
+
+fn default_balance_fraction() -> f64 {
+    0.25
+}
+
+fn default_max_notional() -> f64 {
+    1_000.0
+}
+
+fn default_min_notional() -> f64 {
+    10.0
+}
+
+fn default_target_volatility() -> f64 {
+    0.005 // 0.5% spread range
+}
+
+fn default_account_balance() -> f64 {
+    10_000.0
+}
+
+fn default_order_latency_ms() -> f64 {
+    50.0
+}
+
+/// Break-even cost configuration (see hot_path::costs)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct CostConfig {
+    /// Binance taker fee (fraction)
+    #[serde(default = "default_taker_fee_binance")]
+    pub taker_fee_binance: f64,
+
+    /// Bybit taker fee (fraction)
+    #[serde(default = "default_taker_fee_bybit")]
+    pub taker_fee_bybit: f64,
+
+    /// Binance maker fee for passive legs (fraction, negative = rebate)
+    #[serde(default = "default_maker_fee_binance")]
+    pub maker_fee_binance: f64,
+
+    /// Bybit maker fee for passive legs (fraction, negative = rebate)
+    #[serde(default = "default_maker_fee_bybit")]
+    pub maker_fee_bybit: f64,
+
+    /// Order notional used to estimate slippage from depth (USDT)
+    #[serde(default = "default_reference_notional")]
+    pub reference_notional: f64,
+
+    /// Expected holding time for funding differential (hours)
+    #[serde(default = "default_holding_hours")]
+    pub holding_hours: f64,
+
+    /// Replace taker and maker fees with the account's detected fee tier
+    /// (needs `BINANCE_API_KEY`/`_SECRET`, `BYBIT_API_KEY`/`_SECRET`)
+    #[serde(default = "default_detect_fees")]
+    pub detect_fees: bool,
+
+    /// Fee tier refresh interval (seconds)
+    #[serde(default = "default_fee_refresh_secs")]
+    pub fee_refresh_secs: u64,
+}
+
+impl Default for CostConfig {
+    fn default() -> Self {
+        Self {
+            taker_fee_binance: default_taker_fee_binance(),
+            taker_fee_bybit: default_taker_fee_bybit(),
+            maker_fee_binance: default_maker_fee_binance(),
+            maker_fee_bybit: default_maker_fee_bybit(),
+            reference_notional: default_reference_notional(),
+            holding_hours: default_holding_hours(),
+            detect_fees: default_detect_fees(),
+            fee_refresh_secs: default_fee_refresh_secs(),
+        }
+    }
+}
+
+fn default_taker_fee_binance() -> f64 {
+    0.0005 // 0.05% VIP0 USDT-M
+}
+
+fn default_taker_fee_bybit() -> f64 {
+    0.00055 // 0.055% VIP0 linear
+}
+
+fn default_maker_fee_binance() -> f64 {
+    0.0002 // 0.02% VIP0 USDT-M
+}
+
+fn default_maker_fee_bybit() -> f64 {
+    0.0002 // 0.02% VIP0 linear
+}
+
+fn default_reference_notional() -> f64 {
+    1_000.0
+}
+
+fn default_holding_hours() -> f64 {
+    8.0
+}
+
+fn default_detect_fees() -> bool {
+    true
+}
+
+fn default_fee_refresh_secs() -> u64 {
+    3_600
+}
+
+/// Inventory transfer costs per base asset (see hot_path::transfers)
+///
+/// Assets without an entry are not evaluated as rebalance opportunities.
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct TransferConfig {
+    /// Transfer parameters by base asset ("SOL", "ETH", ...)
+    #[serde(default)]
+    pub assets: HashMap<String, AssetTransferConfig>,
+}
+
+/// Withdrawal cost and time for one asset
+#[derive(Debug, Clone, Copy, Deserialize, Serialize)]
+pub struct AssetTransferConfig {
+    /// Withdrawal fee in asset units
+    pub withdrawal_fee: f64,
+    /// Expected time until the deposit is credited (minutes)
+    pub minutes: f64,
+}
+
+/// Quote currency conversion (see core::rates)
+///
+/// USDC is priced from the USDCUSDT spot books; USD has no liquid pair on
+/// the venues and is pinned at `usd_rate`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RatesConfig {
+    /// Refresh USDC from the spot books (false = every factor stays at par)
+    #[serde(default = "default_rates_enabled")]
+    pub enabled: bool,
+
+    /// Interval between rate refreshes (seconds)
+    #[serde(default = "default_rates_refresh_secs")]
+    pub refresh_secs: u64,
+
+    /// Reject fetched rates further than this from par (fraction, 0.05 = 5%)
+    #[serde(default = "default_rates_max_depeg")]
+    pub max_depeg: f64,
+
+    /// USDT per USD
+    #[serde(default = "default_usd_rate")]
+    pub usd_rate: f64,
+}
+
+impl Default for RatesConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_rates_enabled(),
+            refresh_secs: default_rates_refresh_secs(),
+            max_depeg: default_rates_max_depeg(),
+            usd_rate: default_usd_rate(),
+        }
+    }
+}
+
+fn default_rates_enabled() -> bool {
+    true
+}
+
+fn default_rates_refresh_secs() -> u64 {
+    300
+}
+
+fn default_rates_max_depeg() -> f64 {
+    0.05
+}
+
+fn default_usd_rate() -> f64 {
+    1.0
+}
+
+/// Execution throttling configuration (see engine::throttle)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ExecutionConfig {
+    /// Minimum time between execution attempts on the same symbol and venue (ms)
+    #[serde(default = "default_cooldown_ms")]
+    pub cooldown_ms: u64,
+
+    /// Max execution attempts per symbol and venue per rolling minute
+    #[serde(default = "default_max_trades_per_minute")]
+    pub max_trades_per_minute: u32,
+
+    /// Per-symbol cooldown overrides (ms), e.g. `BTCUSDT = 2000`
+    #[serde(default)]
+    pub symbol_cooldown_ms: HashMap<String, u64>,
+
+    /// Only these symbols may execute (empty = every symbol); screening
+    /// covers all symbols either way. Toggled at runtime via the API.
+    #[serde(default)]
+    pub enabled_symbols: Vec<String>,
+
+    /// Symbols never executed (wins over `enabled_symbols`)
+    #[serde(default)]
+    pub disabled_symbols: Vec<String>,
+
+    /// Paper-fill every order alongside the live path (also in read-only
+    /// mode) and write a daily hypothetical vs realized report to `report.dir`
+    #[serde(default)]
+    pub shadow: bool,
+
+    /// Safety action on a venue whose private user-data stream is lost
+    #[serde(default)]
+    pub private_stream_loss: PrivateStreamAction,
+
+    /// Both legs of an opportunity must be acknowledged this long after
+    /// the decision (ms); otherwise outstanding legs are cancelled
+    #[serde(default = "default_leg_budget_ms")]
+    pub leg_budget_ms: u64,
+
+    /// Simulated venue acknowledgement delay for paper orders (ms)
+    #[serde(default = "default_paper_ack_ms")]
+    pub paper_ack_ms: u64,
+
+    /// Flatten (panic close): rounds of re-sent residual closes after the
+    /// first, and the wait for fills before each position check (ms)
+    #[serde(default = "default_flatten_retries")]
+    pub flatten_retries: u32,
+    #[serde(default = "default_flatten_retry_ms")]
+    pub flatten_retry_ms: u64,
+}
+
+/// What to do when a venue's private stream (fills) is lost
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum PrivateStreamAction {
+    /// Block new orders on the venue until the stream is back
+    Freeze,
+    /// Freeze and cancel every open order on the venue
+    #[default]
+    CancelAll,
+}
+
+impl Default for ExecutionConfig {
+    fn default() -> Self {
+        Self {
+            cooldown_ms: default_cooldown_ms(),
+            max_trades_per_minute: default_max_trades_per_minute(),
+            symbol_cooldown_ms: HashMap::new(),
+            enabled_symbols: Vec::new(),
+            disabled_symbols: Vec::new(),
+            shadow: false,
+            private_stream_loss: PrivateStreamAction::default(),
+            leg_budget_ms: default_leg_budget_ms(),
+            paper_ack_ms: default_paper_ack_ms(),
+            flatten_retries: default_flatten_retries(),
+            flatten_retry_ms: default_flatten_retry_ms(),
+        }
+    }
+}
+
+fn default_cooldown_ms() -> u64 {
+    5_000
+}
+
+fn default_max_trades_per_minute() -> u32 {
+    6
+}
+
+fn default_leg_budget_ms() -> u64 {
+    150
+}
+
+fn default_paper_ack_ms() -> u64 {
+    20
+}
+
+fn default_flatten_retries() -> u32 {
+    3
+}
+
+fn default_flatten_retry_ms() -> u64 {
+    500
+}
+
+/// Startup warm-up configuration
+///
+/// Seeds the rolling windows from recent 1-minute klines so volatility and
+/// VWAP estimates are available before the first full window of live data.
+/// Execution stays disabled for `gate_secs` after the engine starts and for
+/// symbols with fewer than `gate_min_samples` live spread updates.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WarmupConfig {
+    /// Fetch klines at startup
+    #[serde(default = "default_warmup_enabled")]
+    pub enabled: bool,
+
+    /// 1-minute klines fetched per symbol and exchange
+    #[serde(default = "default_warmup_klines")]
+    pub klines: u16,
+
+    /// Also seed spread history from minute closes (mid-based approximation)
+    #[serde(default)]
+    pub seed_spread: bool,
+
+    /// Concurrent kline requests
+    #[serde(default = "default_warmup_concurrency")]
+    pub concurrency: usize,
+
+    /// Execution disabled for this long after startup (seconds, 0 = no delay)
+    #[serde(default = "default_warmup_gate_secs")]
+    pub gate_secs: u64,
+
+    /// Live spread updates a symbol needs before it may execute
+    #[serde(default = "default_warmup_gate_min_samples")]
+    pub gate_min_samples: u32,
+}
+
+impl Default for WarmupConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_warmup_enabled(),
+            klines: default_warmup_klines(),
+            seed_spread: false,
+            concurrency: default_warmup_concurrency(),
+            gate_secs: default_warmup_gate_secs(),
+            gate_min_samples: default_warmup_gate_min_samples(),
+        }
+    }
+}
+
+fn default_warmup_enabled() -> bool {
+    true
+}
+
+fn default_warmup_klines() -> u16 {
+    3
+}
+
+fn default_warmup_concurrency() -> usize {
+    8
+}
+
+fn default_warmup_gate_secs() -> u64 {
+    120
+}
+
+fn default_warmup_gate_min_samples() -> u32 {
+    50
+}
+
+/// Top-N auto-focus configuration (see engine::focus)
+///
+/// When enabled, every symbol keeps its ticker streams and only the top-N by
+/// opportunity score get trade/depth streams and may execute.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct FocusConfig {
+    /// Enable auto-focus (disabled = no upgrades, every symbol may execute)
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Number of focused symbols
+    #[serde(default = "default_focus_top_n")]
+    pub top_n: usize,
+
+    /// Ranks beyond top_n a focused symbol may drop to before demotion
+    #[serde(default = "default_focus_exit_margin")]
+    pub exit_margin: usize,
+
+    /// Minimum time a symbol stays focused (seconds)
+    #[serde(default = "default_focus_min_dwell_secs")]
+    pub min_dwell_secs: u64,
+
+    /// Interval between re-rankings (seconds)
+    #[serde(default = "default_focus_rescore_secs")]
+    pub rescore_secs: u64,
+}
+
+impl Default for FocusConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            top_n: default_focus_top_n(),
+            exit_margin: default_focus_exit_margin(),
+            min_dwell_secs: default_focus_min_dwell_secs(),
+            rescore_secs: default_focus_rescore_secs(),
+        }
+    }
+}
+
+fn default_focus_top_n() -> usize {
+    20
+}
+
+fn default_focus_exit_margin() -> usize {
+    10
+}
+
+fn default_focus_min_dwell_secs() -> u64 {
+    60
+}
+
+fn default_focus_rescore_secs() -> u64 {
+    10
+}
+
+/// Delisting detection configuration
+///
+/// Instruments metadata is refreshed periodically; symbols in a wind-down
+/// status or with delivery inside the horizon are unsubscribed and flattened.
+/// The same refresh reports halts and auctions (execution suspended).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct DelistingConfig {
+    /// Enable periodic instruments refresh
+    #[serde(default = "default_delisting_enabled")]
+    pub enabled: bool,
+
+    /// Interval between instruments refreshes (seconds)
+    #[serde(default = "default_delisting_refresh_secs")]
+    pub refresh_secs: u64,
+
+    /// Act on delistings scheduled within this many hours
+    #[serde(default = "default_delisting_horizon_hours")]
+    pub horizon_hours: u64,
+}
+
+impl Default for DelistingConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_delisting_enabled(),
+            refresh_secs: default_delisting_refresh_secs(),
+            horizon_hours: default_delisting_horizon_hours(),
+        }
+    }
+}
+
+fn default_delisting_enabled() -> bool {
+    true
+}
+
+fn default_delisting_refresh_secs() -> u64 {
+    300
+}
+
+fn default_delisting_horizon_hours() -> u64 {
+    72
+}
+
+/// Historical opportunity database
+///
+/// Completed opportunity episodes and executions are appended to tables
+/// under `path` and served by the /api/history endpoints.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HistoryConfig {
+    /// Enable the history sink
+    #[serde(default)]
+    pub enabled: bool,
+
+    /// Data directory for history tables
+    #[serde(default = "default_history_path")]
+    pub path: PathBuf,
+
+    /// Rotate a table file once it reaches this size (bytes, 0 = never)
+    #[serde(default = "default_history_max_file_bytes")]
+    pub max_file_bytes: u64,
+
+    /// Rotated (zstd-compressed) files kept per table (older ones are deleted)
+    #[serde(default = "default_history_retain_files")]
+    pub retain_files: usize,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            path: default_history_path(),
+            max_file_bytes: default_history_max_file_bytes(),
+            retain_files: default_history_retain_files(),
+        }
+    }
+}
+
+fn default_history_path() -> PathBuf {
+    PathBuf::from("./data/history")
+}
+
+fn default_history_max_file_bytes() -> u64 {
+    64 * 1024 * 1024
+}
+
+fn default_history_retain_files() -> usize {
+    8
+}
+
+/// systemd watchdog configuration
+///
+/// `WATCHDOG=1` is sent only while the engine loop, tracker and API all
+/// make progress within `stall_secs`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WatchdogConfig {
+    /// Enable watchdog pings when `$NOTIFY_SOCKET` is set
+    #[serde(default = "default_watchdog_enabled")]
+    pub enabled: bool,
+
+    /// Seconds without progress before a task counts as stuck
+    #[serde(default = "default_watchdog_stall_secs")]
+    pub stall_secs: u64,
+}
+
+impl Default for WatchdogConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_watchdog_enabled(),
+            stall_secs: default_watchdog_stall_secs(),
+        }
+    }
+}
+
+fn default_watchdog_enabled() -> bool {
+    true
+}
+
+fn default_watchdog_stall_secs() -> u64 {
+    30
+}
+
+/// Session report written when the engine stops (see engine::report)
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ReportConfig {
+    /// Write the report file (it is always logged)
+    #[serde(default = "default_report_enabled")]
+    pub enabled: bool,
+
+    /// Directory for `session-<unix_ms>.txt` files
+    #[serde(default = "default_report_dir")]
+    pub dir: PathBuf,
+}
+
+impl Default for ReportConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_report_enabled(),
+            dir: default_report_dir(),
+        }
+    }
+}
+
+fn default_report_enabled() -> bool {
+    true
+}
+
+fn default_report_dir() -> PathBuf {
+    PathBuf::from("logs")
+}
+
+/// Opportunity alert conflation (see engine::alerts)
+///
+/// One alert per opportunity episode, then at most one update every
+/// `update_secs`, then a summary when the episode closes. Openings and
+/// closes are also POSTed to every entry of `webhooks` (see
+/// infrastructure::webhook).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlertConfig {
+    #[serde(default = "default_alert_update_secs")]
+    pub update_secs: u64,
+
+    #[serde(default)]
+    pub webhooks: Vec<WebhookConfig>,
+
+    /// Per-request timeout of webhook deliveries
+    #[serde(default = "default_webhook_timeout_ms")]
+    pub webhook_timeout_ms: u64,
+
+    /// Per-channel and global alert rate limits
+    #[serde(default)]
+    pub rate_limit: AlertRateLimitConfig,
+}
+
+impl Default for AlertConfig {
+    fn default() -> Self {
+        Self {
+            update_secs: default_alert_update_secs(),
+            webhooks: Vec::new(),
+            webhook_timeout_ms: default_webhook_timeout_ms(),
+            rate_limit: AlertRateLimitConfig::default(),
+        }
+    }
+}
+
+/// Alert token buckets (see infrastructure::alert_limit)
+///
+/// Each channel (alert log, each webhook) may send `per_channel_per_minute`
+/// alerts with bursts of `burst`; all channels together are capped by the
+/// global bucket. Suppressed alerts are summarized after `summary_secs`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct AlertRateLimitConfig {
+    #[serde(default = "default_alert_limit_enabled")]
+    pub enabled: bool,
+
+    #[serde(default = "default_alert_per_channel_per_minute")]
+    pub per_channel_per_minute: u32,
+
+    #[serde(default = "default_alert_burst")]
+    pub burst: u32,
+
+    #[serde(default = "default_alert_global_per_minute")]
+    pub global_per_minute: u32,
+
+    #[serde(default = "default_alert_global_burst")]
+    pub global_burst: u32,
+
+    #[serde(default = "default_alert_summary_secs")]
+    pub summary_secs: u64,
+}
+
+impl Default for AlertRateLimitConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_alert_limit_enabled(),
+            per_channel_per_minute: default_alert_per_channel_per_minute(),
+            burst: default_alert_burst(),
+            global_per_minute: default_alert_global_per_minute(),
+            global_burst: default_alert_global_burst(),
+            summary_secs: default_alert_summary_secs(),
+        }
+    }
+}
+
+fn default_alert_limit_enabled() -> bool {
+    true
+}
+
+fn default_alert_per_channel_per_minute() -> u32 {
+    20
+}
+
+fn default_alert_burst() -> u32 {
+    5
+}
+
+fn default_alert_global_per_minute() -> u32 {
+    60
+}
+
+fn default_alert_global_burst() -> u32 {
+    15
+}
+
+fn default_alert_summary_secs() -> u64 {
+    60
+}
+
+fn default_alert_update_secs() -> u64 {
+    30
+}
+
+fn default_webhook_timeout_ms() -> u64 {
+    5_000
+}
+
+/// Opportunity webhook endpoint
+///
+/// The signing secret is read from the environment variable named by
+/// `secret_env`, never from config.toml.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct WebhookConfig {
+    pub url: String,
+
+    /// Environment variable holding the HMAC secret (None = unsigned)
+    #[serde(default)]
+    pub secret_env: Option<String>,
 
-    /// API server settings
+    /// Retries after a failed delivery
+    #[serde(default = "default_webhook_max_retries")]
+    pub max_retries: u32,
+
+    /// Delay before the first retry, doubled per retry
+    #[serde(default = "default_webhook_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+}
+
+fn default_webhook_max_retries() -> u32 {
+    3
+}
+
+fn default_webhook_initial_backoff_ms() -> u64 {
+    500
+}
+
+/// Leverage and margin mode management (see rest::leverage)
+///
+/// At startup and every `refresh_secs` the listed symbols are set to their
+/// margin mode and leverage on each venue with credentials, then read back;
+/// a venue reporting different settings raises an alert. Needs a trading
+/// permit (skipped in read-only mode).
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LeverageConfig {
     #[serde(default)]
-    pub api: ApiConfig,
+    pub enabled: bool,
+
+    #[serde(default = "default_leverage_refresh_secs")]
+    pub refresh_secs: u64,
+
+    /// Defaults for symbols that do not set their own
+    #[serde(default)]
+    pub margin_mode: MarginMode,
+    #[serde(default = "default_leverage")]
+    pub leverage: u32,
+
+    /// Managed symbols, e.g. `BTCUSDT = { leverage = 10, margin_mode = "cross" }`
+    #[serde(default)]
+    pub symbols: HashMap<String, SymbolLeverage>,
 }
 
-/// HFT trading configuration
+/// Leverage settings of one symbol (unset fields use the defaults)
+#[derive(Debug, Clone, Default, Deserialize, Serialize)]
+pub struct SymbolLeverage {
+    pub margin_mode: Option<MarginMode>,
+    pub leverage: Option<u32>,
+    /// Only on this venue ("binance" or "bybit"; None = both)
+    pub exchange: Option<String>,
+}
+
+/// Position margin mode
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum MarginMode {
+    /// Margin ring-fenced per position
+    #[default]
+    Isolated,
+    /// Margin shared across the account
+    Cross,
+}
+
+impl Default for LeverageConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            refresh_secs: default_leverage_refresh_secs(),
+            margin_mode: MarginMode::default(),
+            leverage: default_leverage(),
+            symbols: HashMap::new(),
+        }
+    }
+}
+
+fn default_leverage_refresh_secs() -> u64 {
+    3_600
+}
+
+fn default_leverage() -> u32 {
+    1
+}
+
+/// Multi-region peer coordination (see infrastructure::peer, engine::peer)
+///
+/// Two instances, each near one venue, share their local tickers over a TCP
+/// link and each executes only the legs on its own `venues`. One side sets
+/// `listen`, the other `connect`. While the link is down (no frame within
+/// `timeout_ms`) neither side can know what the other is doing, so both stop
+/// executing until it is back. If both claim a venue, the lower `node_id`
+/// keeps it. Both sides must set the same secret in the environment
+/// variable named by `secret_env`; a peer without it is rejected.
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct HftConfig {
-    /// Minimum 24h volume to consider a symbol liquid (in USDT)
-    #[serde(default = "default_min_volume")]
-    pub min_volume_24h: f64,
+pub struct PeerConfig {
+    #[serde(default)]
+    pub enabled: bool,
 
-    /// Opportunity threshold in basis points (FixedPoint8 raw value)
-    /// 250_000 = 0.25% spread between exchanges
-    #[serde(default = "default_threshold")]
-    pub opportunity_threshold_bps: i64,
+    /// Unique name of this instance, e.g. "tokyo"
+    #[serde(default)]
+    pub node_id: String,
 
-    /// Rolling window duration in seconds for spread history
-    #[serde(default = "default_window_seconds")]
-    pub window_seconds: u64,
+    /// Venues whose legs this instance executes, e.g. ["binance"]
+    #[serde(default)]
+    pub venues: Vec<String>,
+
+    /// Accept the peer on this address, e.g. "0.0.0.0:9400"
+    #[serde(default)]
+    pub listen: Option<String>,
+
+    /// Dial the peer at this address
+    #[serde(default)]
+    pub connect: Option<String>,
+
+    /// Environment variable holding the link's shared secret
+    #[serde(default = "default_peer_secret_env")]
+    pub secret_env: String,
+
+    #[serde(default = "default_peer_heartbeat_ms")]
+    pub heartbeat_ms: u64,
+
+    #[serde(default = "default_peer_timeout_ms")]
+    pub timeout_ms: u64,
+
+    /// Delay between dial attempts
+    #[serde(default = "default_peer_reconnect_ms")]
+    pub reconnect_ms: u64,
 }
 
-/// API server configuration
+impl Default for PeerConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            node_id: String::new(),
+            venues: Vec::new(),
+            listen: None,
+            connect: None,
+            secret_env: default_peer_secret_env(),
+            heartbeat_ms: default_peer_heartbeat_ms(),
+            timeout_ms: default_peer_timeout_ms(),
+            reconnect_ms: default_peer_reconnect_ms(),
+        }
+    }
+}
+
+fn default_peer_secret_env() -> String {
+    "HFT_PEER_SECRET".to_string()
+}
+
+fn default_peer_heartbeat_ms() -> u64 {
+    500
+}
+
+fn default_peer_timeout_ms() -> u64 {
+    3_000
+}
+
+fn default_peer_reconnect_ms() -> u64 {
+    1_000
+}
+
+/// Hot-standby failover (see infrastructure::standby, engine::failover)
+///
+/// The active instance streams its positions, open order legs and latest
+/// tickers to a passive one, which takes over execution once the active
+/// side has been silent for `takeover_ms`. The active side only trades while
+/// its lease (the standby's last heartbeat, valid `lease_ms`) holds, so it
+/// stops before the standby can promote itself; `takeover_ms` must exceed
+/// `lease_ms`. A configured primary waits up to `takeover_ms` at startup to
+/// hear the other instance before trading. One side sets `listen`, the other
+/// `connect`; both authenticate with the secret named by `secret_env`.
 #[derive(Debug, Clone, Deserialize, Serialize)]
-pub struct ApiConfig {
-    /// Port for HTTP API server
-    #[serde(default = "default_api_port")]
-    pub port: u16,
+pub struct StandbyConfig {
+    #[serde(default)]
+    pub enabled: bool,
 
-    /// Path to static files (frontend)
-    #[serde(default = "default_static_path")]
-    pub static_path: PathBuf,
+    /// Role at startup; a restarted former primary should start as standby
+    #[serde(default)]
+    pub role: FailoverRole,
+
+    /// Accept the other instance on this address, e.g. "0.0.0.0:9401"
+    #[serde(default)]
+    pub listen: Option<String>,
+
+    /// Dial the other instance at this address
+    #[serde(default)]
+    pub connect: Option<String>,
+
+    /// Environment variable holding the link's shared secret
+    #[serde(default = "default_standby_secret_env")]
+    pub secret_env: String,
+
+    #[serde(default = "default_standby_heartbeat_ms")]
+    pub heartbeat_ms: u64,
+
+    /// The active side stops trading this long after the standby's last frame
+    #[serde(default = "default_standby_lease_ms")]
+    pub lease_ms: u64,
+
+    /// The standby takes over this long after the active side's last frame
+    #[serde(default = "default_standby_takeover_ms")]
+    pub takeover_ms: u64,
+
+    /// Full state snapshot interval (fills are streamed in between)
+    #[serde(default = "default_standby_snapshot_ms")]
+    pub snapshot_ms: u64,
+
+    /// Delay between dial attempts
+    #[serde(default = "default_peer_reconnect_ms")]
+    pub reconnect_ms: u64,
+}
+
+/// Failover role of an instance
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FailoverRole {
+    /// Trades and replicates its state
+    #[default]
+    Primary,
+    /// Mirrors the primary's state and takes over when it goes silent
+    Standby,
+}
+
+impl Default for StandbyConfig {
+    fn default() -> Self {
+        Self {
+            enabled: false,
+            role: FailoverRole::default(),
+            listen: None,
+            connect: None,
+            secret_env: default_standby_secret_env(),
+            heartbeat_ms: default_standby_heartbeat_ms(),
+            lease_ms: default_standby_lease_ms(),
+            takeover_ms: default_standby_takeover_ms(),
+            snapshot_ms: default_standby_snapshot_ms(),
+            reconnect_ms: default_peer_reconnect_ms(),
+        }
+    }
+}
+
+fn default_standby_secret_env() -> String {
+    "HFT_STANDBY_SECRET".to_string()
+}
+
+fn default_standby_heartbeat_ms() -> u64 {
+    200
+}
+
+fn default_standby_lease_ms() -> u64 {
+    1_000
+}
+
+fn default_standby_takeover_ms() -> u64 {
+    3_000
+}
+
+fn default_standby_snapshot_ms() -> u64 {
+    1_000
+}
+
+/// Cold-start symbol priority (see infrastructure::symbol_priority)
+///
+/// Symbols are ranked by their persisted opportunity rate before the
+/// subscription cut; the rates are updated when the engine stops.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct PriorityConfig {
+    #[serde(default = "default_priority_enabled")]
+    pub enabled: bool,
+
+    /// Rates file, rewritten at shutdown
+    #[serde(default = "default_priority_path")]
+    pub path: PathBuf,
+}
+
+impl Default for PriorityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_priority_enabled(),
+            path: default_priority_path(),
+        }
+    }
+}
+
+fn default_priority_enabled() -> bool {
+    true
+}
+
+fn default_priority_path() -> PathBuf {
+    PathBuf::from("./data/symbol_priority.json")
+}
+
+/// Per-symbol threshold calibration (see hot_path::thresholds)
+///
+/// Thresholds resolve as: override > calibrated quantile of the 1-hour
+/// |spread| distribution > static `hft.opportunity_threshold_bps`.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct ThresholdConfig {
+    /// Calibrate thresholds from the rolling spread distribution
+    #[serde(default = "default_threshold_calibrate")]
+    pub calibrate: bool,
+
+    /// Quantile of |spread| used as threshold (0.99 = P99)
+    #[serde(default = "default_threshold_quantile")]
+    pub quantile: f64,
+
+    /// Samples in the window before the calibrated value is used
+    #[serde(default = "default_threshold_min_samples")]
+    pub min_samples: u64,
+
+    /// Manual thresholds by symbol (fraction, e.g. 0.001 = 0.1%)
+    #[serde(default)]
+    pub overrides: HashMap<String, f64>,
+
+    /// Minimum spread in price ticks for a hit / valid screener row
+    /// (filters tick-noise on low-priced assets; 0 = disabled)
+    #[serde(default)]
+    pub min_spread_ticks: f64,
+}
+
+impl Default for ThresholdConfig {
+    fn default() -> Self {
+        Self {
+            calibrate: default_threshold_calibrate(),
+            quantile: default_threshold_quantile(),
+            min_samples: default_threshold_min_samples(),
+            overrides: HashMap::new(),
+            min_spread_ticks: 0.0,
+        }
+    }
+}
+
+fn default_threshold_calibrate() -> bool {
+    true
+}
+
+fn default_threshold_quantile() -> f64 {
+    0.99
+}
+
+fn default_threshold_min_samples() -> u64 {
+    1000
+}
+
+/// Quote sanity filters (see hot_path::sanity)
+///
+/// Quotes with zero/negative prices, crossed books or a mid more than
+/// `max_deviation_pct` away from the last accepted one are quarantined.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SanityConfig {
+    /// Quarantine malformed quotes
+    #[serde(default = "default_sanity_enabled")]
+    pub enabled: bool,
+
+    /// Max mid move between consecutive quotes on a venue (percent)
+    #[serde(default = "default_sanity_max_deviation_pct")]
+    pub max_deviation_pct: f64,
+
+    /// Consecutive deviating quotes after which the new level is accepted
+    #[serde(default = "default_sanity_reanchor_after")]
+    pub reanchor_after: u32,
+}
+
+impl Default for SanityConfig {
+    fn default() -> Self {
+        Self {
+            enabled: default_sanity_enabled(),
+            max_deviation_pct: default_sanity_max_deviation_pct(),
+            reanchor_after: default_sanity_reanchor_after(),
+        }
+    }
+}
+
+fn default_sanity_enabled() -> bool {
+    true
+}
+
+fn default_sanity_max_deviation_pct() -> f64 {
+    10.0
+}
+
+fn default_sanity_reanchor_after() -> u32 {
+    5
+}
+
+/// Feed task restart policy (see engine::supervisor)
+///
+/// A feed task that exits (connection closed, connect/subscribe failure or
+/// panic) is rebuilt and restarted after an exponential backoff. A run that
+/// lasted at least `max_backoff_ms` resets the backoff.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct SupervisionConfig {
+    /// Consecutive restarts before giving up (None = unlimited, 0 = never restart)
+    #[serde(default)]
+    pub max_restarts: Option<u32>,
+
+    /// Delay before the first restart (milliseconds)
+    #[serde(default = "default_initial_backoff_ms")]
+    pub initial_backoff_ms: u64,
+
+    /// Backoff cap (milliseconds)
+    #[serde(default = "default_max_backoff_ms")]
+    pub max_backoff_ms: u64,
+
+    /// Inbound message rate limit per connection (None = unlimited)
+    ///
+    /// Beyond it tickers and mark prices are conflated to the latest per
+    /// symbol and other market data is dropped (see engine::flood).
+    #[serde(default)]
+    pub max_messages_per_sec: Option<u32>,
+
+    /// Connections per venue, redundant feeds and symbol shards included
+    ///
+    /// Symbols beyond one connection's stream cap are sharded across extra
+    /// connections; a symbol list needing more than this is refused at start.
+    #[serde(default = "default_max_connections_per_venue")]
+    pub max_connections_per_venue: usize,
+
+    /// Consecutive connect failures of a venue before its reconnect circuit opens
+    ///
+    /// The circuit is shared by all feeds of the venue; while open no feed
+    /// of it reconnects (see ws::circuit_breaker).
+    #[serde(default = "default_circuit_failure_threshold")]
+    pub circuit_failure_threshold: u32,
+
+    /// Time an open reconnect circuit waits before one probe connect (seconds)
+    #[serde(default = "default_circuit_open_secs")]
+    pub circuit_open_secs: u64,
+}
+
+impl Default for SupervisionConfig {
+    fn default() -> Self {
+        Self {
+            max_restarts: None,
+            initial_backoff_ms: default_initial_backoff_ms(),
+            max_backoff_ms: default_max_backoff_ms(),
+            max_messages_per_sec: None,
+            max_connections_per_venue: default_max_connections_per_venue(),
+            circuit_failure_threshold: default_circuit_failure_threshold(),
+            circuit_open_secs: default_circuit_open_secs(),
+        }
+    }
+}
+
+fn default_initial_backoff_ms() -> u64 {
+    500
+}
+
+fn default_max_backoff_ms() -> u64 {
+    30_000
+}
+
+fn default_max_connections_per_venue() -> usize {
+    4
+}
+
+fn default_circuit_failure_threshold() -> u32 {
+    5
+}
+
+fn default_circuit_open_secs() -> u64 {
+    60
 }
 
 impl Default for HftConfig {
@@ -56,15 +1551,70 @@ impl Default for HftConfig {
             min_volume_24h: default_min_volume(),
             opportunity_threshold_bps: default_threshold(),
             window_seconds: default_window_seconds(),
+            engine_queue_capacity: default_engine_queue_capacity(),
+            read_buffer_bytes: default_read_buffer_bytes(),
+            recv_timeout_ms: default_recv_timeout_ms(),
+            ping_interval_secs: default_ping_interval_secs(),
+            clock_tick_ms: default_clock_tick_ms(),
+            tsc_clock: false,
+            stall_budget_ms: default_stall_budget_ms(),
+            idle_evict_secs: default_idle_evict_secs(),
+            validate_frames: false,
+            max_frame_bytes: default_max_frame_bytes(),
+            assert_sample_every: default_assert_sample_every(),
+            assert_capture_frames: false,
+        }
+    }
+}
+
+impl HftConfig {
+    /// Per-connection tuning handed to exchange clients
+    pub fn feed_tuning(&self) -> FeedTuning {
+        FeedTuning {
+            read_buffer_bytes: self.read_buffer_bytes,
+            recv_timeout: Duration::from_millis(self.recv_timeout_ms.max(1)),
+            ping_interval: Duration::from_secs(self.ping_interval_secs.max(1)),
+            max_frame_bytes: if self.validate_frames { self.max_frame_bytes.max(1) } else { 0 },
+            assert_sample_every: self.assert_sample_every,
+            capture_violations: self.assert_capture_frames,
         }
     }
 }
 
+/// WebSocket client tuning (see [`HftConfig::feed_tuning`])
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FeedTuning {
+    /// Read buffer per connection (bytes)
+    pub read_buffer_bytes: usize,
+    /// Receive timeout between keepalive checks
+    pub recv_timeout: Duration,
+    /// Inactivity before an application-level ping
+    pub ping_interval: Duration,
+    /// Longest frame passing structural validation (0 = validation off)
+    pub max_frame_bytes: usize,
+    /// Invariant check on 1 in N market data messages (0 = off)
+    pub assert_sample_every: u64,
+    /// Quarantine frames violating an invariant
+    pub capture_violations: bool,
+}
+
+impl Default for FeedTuning {
+    fn default() -> Self {
+        HftConfig::default().feed_tuning()
+    }
+}
+
 impl Default for ApiConfig {
     fn default() -> Self {
         Self {
             port: default_api_port(),
             static_path: default_static_path(),
+            stream_interval_ms: default_stream_interval_ms(),
+            stream_resync_every: default_stream_resync_every(),
+            snapshot_interval_ms: default_snapshot_interval_ms(),
+            auth_token: None,
+            allowed_ips: Vec::new(),
+            cors_origins: Vec::new(),
         }
     }
 }
@@ -81,12 +1631,60 @@ fn default_window_seconds() -> u64 {
     120 // 2 minutes
 }
 
+fn default_engine_queue_capacity() -> usize {
+    1024
+}
+
+fn default_read_buffer_bytes() -> usize {
+    64 * 1024
+}
+
+fn default_recv_timeout_ms() -> u64 {
+    5_000
+}
+
+fn default_ping_interval_secs() -> u64 {
+    20
+}
+
+fn default_max_frame_bytes() -> usize {
+    64 * 1024
+}
+
+fn default_assert_sample_every() -> u64 {
+    1000
+}
+
+fn default_stall_budget_ms() -> u64 {
+    50
+}
+
+fn default_idle_evict_secs() -> u64 {
+    600
+}
+
+fn default_clock_tick_ms() -> u64 {
+    1
+}
+
 fn default_api_port() -> u16 {
     5000
 }
 
 fn default_static_path() -> PathBuf {
-    PathBuf::from("/root/arbitrageR/reference/frontend")
+    PathBuf::from("./reference/frontend")
+}
+
+fn default_stream_interval_ms() -> u64 {
+    250
+}
+
+fn default_stream_resync_every() -> u32 {
+    120
+}
+
+fn default_snapshot_interval_ms() -> u64 {
+    1000
 }
 
 impl Config {
@@ -113,10 +1711,15 @@ impl Config {
         }
     }
 
-    /// Get opportunity threshold as FixedPoint8 raw value
+    /// Static opportunity threshold
     #[inline(always)]
-    pub fn opportunity_threshold_raw(&self) -> i64 {
-        self.hft.opportunity_threshold_bps
+    pub fn opportunity_threshold(&self) -> Bps {
+        Bps::from_raw(self.hft.opportunity_threshold_bps)
+    }
+
+    /// Trading permit (None in read-only mode)
+    pub fn trading_permit(&self) -> Option<TradingPermit> {
+        (!self.read_only).then_some(TradingPermit { _private: () })
     }
 }
 
@@ -159,13 +1762,88 @@ mod tests {
         assert_eq!(config.api.port, 5000);
         assert_eq!(
             config.api.static_path,
-            PathBuf::from("/root/arbitrageR/reference/frontend")
+            PathBuf::from("./reference/frontend")
         );
     }
 
     #[test]
-    fn test_opportunity_threshold_raw() {
+    fn test_read_only_withholds_trading_permit() {
+        assert!(Config::default().trading_permit().is_some());
+        let config: Config = toml::from_str("read_only = true").unwrap();
+        assert!(config.trading_permit().is_none());
+    }
+
+    #[test]
+    fn test_strategy_config() {
+        let config: Config = toml::from_str(
+            r#"
+            [strategies]
+            enabled = ["basis"]
+            [strategies.params.basis]
+            threshold = 0.003
+            "#,
+        )
+        .unwrap();
+        assert_eq!(config.strategies.enabled, vec!["basis".to_string()]);
+        assert!(config.strategies.params["basis"].contains_key("threshold"));
+
+        assert!(Config::default().strategies.enabled.is_empty());
+    }
+
+    #[test]
+    fn test_network_config_unbound() {
+        let net = NetworkConfig::default();
+        let v4: SocketAddr = "1.2.3.4:443".parse().unwrap();
+        let v6: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+
+        assert!(!net.is_bound());
+        assert_eq!(net.candidates([v6, v4]), vec![v4, v6]);
+        assert!(net.local_addr_for(&v4).is_none());
+        assert!(net.rest_local_address().is_none());
+    }
+
+    #[test]
+    fn test_network_config_bound_family() {
+        let net: NetworkConfig = toml::from_str(r#"bind_ipv4 = "10.0.0.2""#).unwrap();
+        let v4: SocketAddr = "1.2.3.4:443".parse().unwrap();
+        let v6: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+
+        // v6 has no bind address, so it is skipped
+        assert_eq!(net.candidates([v6, v4]), vec![v4]);
+        assert_eq!(net.local_addr_for(&v4), Some("10.0.0.2:0".parse().unwrap()));
+        assert_eq!(net.rest_local_address(), Some("10.0.0.2".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_network_config_dual_stack_prefer_v6() {
+        let net = NetworkConfig {
+            bind_ipv4: Some("10.0.0.2".parse().unwrap()),
+            bind_ipv6: Some("2001:db8::2".parse().unwrap()),
+            prefer_ipv6: true,
+        };
+        let v4: SocketAddr = "1.2.3.4:443".parse().unwrap();
+        let v6: SocketAddr = "[2001:db8::1]:443".parse().unwrap();
+
+        assert_eq!(net.candidates([v4, v6]), vec![v6, v4]);
+        assert_eq!(net.local_addr_for(&v6), Some("[2001:db8::2]:0".parse().unwrap()));
+        assert_eq!(net.rest_local_address(), Some("2001:db8::2".parse().unwrap()));
+    }
+
+    #[test]
+    fn test_feed_tuning() {
+        let config: Config = toml::from_str("[hft]\nengine_queue_capacity = 8192\nrecv_timeout_ms = 2000").unwrap();
+        assert_eq!(config.hft.engine_queue_capacity, 8192);
+        let tuning = config.hft.feed_tuning();
+        assert_eq!(tuning.recv_timeout, Duration::from_secs(2));
+        assert_eq!(tuning.read_buffer_bytes, 64 * 1024);
+        assert_eq!(tuning.ping_interval, Duration::from_secs(20));
+        assert_eq!(tuning.max_frame_bytes, 0);
+        assert_eq!((tuning.assert_sample_every, tuning.capture_violations), (1000, false));
+    }
+
+    #[test]
+    fn test_opportunity_threshold() {
         let config = Config::default();
-        assert_eq!(config.opportunity_threshold_raw(), 250_000);
+        assert_eq!(config.opportunity_threshold(), Bps::from_bps(25));
     }
 }
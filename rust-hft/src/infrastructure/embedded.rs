@@ -0,0 +1,66 @@
+//! Dashboard assets compiled into the binary (Cold Path)
+//!
+//! Enabled by the `embed-frontend` feature; `build.rs` generates the asset
+//! table. The API server serves these when the configured `static_path`
+//! does not exist, so a deployment is a single binary while a checkout
+//! still serves the files on disk for frontend development.
+
+use axum::http::{header, StatusCode, Uri};
+use axum::response::{IntoResponse, Response};
+
+/// (path relative to the frontend root, contents), sorted by path
+static ASSETS: &[(&str, &[u8])] = include!(concat!(env!("OUT_DIR"), "/frontend_assets.rs"));
+
+/// Embedded asset at `path` (leading `/` ignored, empty = index.html)
+pub fn asset(path: &str) -> Option<&'static [u8]> {
+    let path = path.trim_start_matches('/');
+    let path = if path.is_empty() { "index.html" } else { path };
+    ASSETS
+        .binary_search_by(|(name, _)| (*name).cmp(path))
+        .ok()
+        .map(|i| ASSETS[i].1)
+}
+
+/// Number of embedded files
+pub fn len() -> usize {
+    ASSETS.len()
+}
+
+/// Content type from the file extension
+fn content_type(path: &str) -> &'static str {
+    match path.rsplit_once('.').map(|(_, ext)| ext) {
+        Some("html") => "text/html; charset=utf-8",
+        Some("js" | "mjs") => "text/javascript; charset=utf-8",
+        Some("css") => "text/css; charset=utf-8",
+        Some("json") => "application/json",
+        Some("svg") => "image/svg+xml",
+        Some("png") => "image/png",
+        Some("ico") => "image/x-icon",
+        Some("woff2") => "font/woff2",
+        _ => "application/octet-stream",
+    }
+}
+
+/// Fallback handler serving embedded assets
+pub async fn serve(uri: Uri) -> Response {
+    match asset(uri.path()) {
+        Some(body) => {
+            let path = if uri.path() == "/" { "index.html" } else { uri.path() };
+            ([(header::CONTENT_TYPE, content_type(path))], body).into_response()
+        }
+        None => StatusCode::NOT_FOUND.into_response(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_embedded_assets() {
+        assert!(ASSETS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert!(asset("/css/cyberpunk.css").is_some_and(|css| !css.is_empty()));
+        assert_eq!(content_type("/js/store.js"), "text/javascript; charset=utf-8");
+        assert!(asset("/missing.js").is_none());
+    }
+}
@@ -0,0 +1,345 @@
+//! Historical spread export (Cold Path)
+//!
+//! `rust-hft export` turns the history tables into analysis-ready files so
+//! the JSONL layout, rotation scheme and fixed-point conventions stay
+//! internal. Episodes are read without opening the tables for writing (a
+//! running instance may own them), filtered by symbol and start time, and
+//! written oldest first as CSV or, built with the `parquet-export` feature,
+//! Parquet. Spreads are exported in basis points and times both as Unix
+//! milliseconds and RFC 3339 (CSV) or UTC timestamps (Parquet). The command
+//! ends with an `Exported N episodes` line on stderr, so `--output -` leaves
+//! stdout to the data.
+
+use crate::infrastructure::config::HistoryConfig;
+use crate::infrastructure::history::{self, EpisodeRow, HistoryQuery};
+use crate::{HftError, Result};
+use std::fs::File;
+use std::io::{self, BufWriter, Write};
+use std::path::PathBuf;
+use time::format_description::well_known::Rfc3339;
+use time::macros::format_description;
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time};
+
+pub const USAGE: &str = "\
+Usage: rust-hft export [--symbol SYMBOL] [--from TIME] [--to TIME]
+                       [--format csv|parquet] [--dir DIR] [--output FILE]
+
+  --symbol   Only this symbol (default: all)
+  --from     Episodes starting at or after TIME
+  --to       Episodes starting at or before TIME
+             TIME is Unix milliseconds, RFC 3339 or YYYY-MM-DD (midnight UTC)
+  --format   csv (default) or parquet (needs the parquet-export feature)
+  --dir      History directory (default: [history] path from the config)
+  --output   Output file, '-' for stdout (default: SYMBOL.csv / episodes.csv)";
+
+const PARQUET_UNAVAILABLE: &str = "parquet export requires building with --features parquet-export";
+
+/// CSV header, in column order of [`write_csv`]
+const CSV_HEADER: &str =
+    "symbol,long_exchange,short_exchange,start_time,end_time,start_ms,end_ms,duration_ms,peak_spread_bps,updates,aborted";
+
+/// Output file format
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExportFormat {
+    Csv,
+    Parquet,
+}
+
+impl ExportFormat {
+    pub fn from_name(name: &str) -> Option<Self> {
+        match name.to_ascii_lowercase().as_str() {
+            "csv" => Some(Self::Csv),
+            "parquet" => Some(Self::Parquet),
+            _ => None,
+        }
+    }
+
+    /// Compiled into this build
+    pub const fn is_available(self) -> bool {
+        matches!(self, Self::Csv) || cfg!(feature = "parquet-export")
+    }
+
+    pub const fn extension(self) -> &'static str {
+        match self {
+            Self::Csv => "csv",
+            Self::Parquet => "parquet",
+        }
+    }
+}
+
+/// Parsed `export` command line
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportArgs {
+    pub symbol: Option<String>,
+    /// Milliseconds since epoch (inclusive)
+    pub from_ms: Option<u64>,
+    pub to_ms: Option<u64>,
+    pub format: ExportFormat,
+    /// History directory (None = from config)
+    pub dir: Option<PathBuf>,
+    /// Output file (None = derived from symbol and format, "-" = stdout)
+    pub output: Option<PathBuf>,
+}
+
+impl ExportArgs {
+    /// Parse the arguments following `export`
+    pub fn parse<I: IntoIterator<Item = String>>(args: I) -> Result<Self> {
+        let mut parsed = Self {
+            symbol: None,
+            from_ms: None,
+            to_ms: None,
+            format: ExportFormat::Csv,
+            dir: None,
+            output: None,
+        };
+        let mut args = args.into_iter();
+        while let Some(flag) = args.next() {
+            let mut value = || args.next().ok_or_else(|| usage_error(format!("{} needs a value", flag)));
+            match flag.as_str() {
+                "--symbol" => parsed.symbol = Some(value()?.to_ascii_uppercase()),
+                "--from" => parsed.from_ms = Some(parse_time(&value()?)?),
+                "--to" => parsed.to_ms = Some(parse_time(&value()?)?),
+                "--format" => {
+                    let name = value()?;
+                    parsed.format = ExportFormat::from_name(&name).ok_or_else(|| usage_error(format!("unknown format {}", name)))?;
+                }
+                "--dir" => parsed.dir = Some(PathBuf::from(value()?)),
+                "--output" => parsed.output = Some(PathBuf::from(value()?)),
+                other => return Err(usage_error(format!("unknown argument {}", other))),
+            }
+        }
+        if !parsed.format.is_available() {
+            return Err(HftError::Config(PARQUET_UNAVAILABLE.to_string()));
+        }
+        if let (Some(from), Some(to)) = (parsed.from_ms, parsed.to_ms) {
+            if from > to {
+                return Err(usage_error("--from is after --to".to_string()));
+            }
+        }
+        Ok(parsed)
+    }
+
+    fn query(&self) -> HistoryQuery {
+        HistoryQuery {
+            symbol: self.symbol.clone(),
+            from: self.from_ms,
+            to: self.to_ms,
+            ..Default::default()
+        }
+    }
+
+    fn output_path(&self) -> PathBuf {
+        self.output.clone().unwrap_or_else(|| {
+            let stem = self.symbol.as_deref().unwrap_or("episodes");
+            PathBuf::from(format!("{}.{}", stem, self.format.extension()))
+        })
+    }
+}
+
+fn usage_error(message: String) -> HftError {
+    HftError::Config(format!("{}\n\n{}", message, USAGE))
+}
+
+/// Unix milliseconds, RFC 3339, or a date (midnight UTC)
+fn parse_time(value: &str) -> Result<u64> {
+    if let Ok(ms) = value.parse::<u64>() {
+        return Ok(ms);
+    }
+    let at = OffsetDateTime::parse(value, &Rfc3339)
+        .or_else(|_| {
+            Date::parse(value, format_description!("[year]-[month]-[day]"))
+                .map(|date| PrimitiveDateTime::new(date, Time::MIDNIGHT).assume_utc())
+        })
+        .map_err(|_| usage_error(format!("invalid time {}", value)))?;
+    u64::try_from(at.unix_timestamp_nanos() / 1_000_000).map_err(|_| usage_error(format!("time before 1970: {}", value)))
+}
+
+fn rfc3339(ms: u64) -> String {
+    OffsetDateTime::from_unix_timestamp_nanos(i128::from(ms) * 1_000_000)
+        .ok()
+        .and_then(|t| t.format(&Rfc3339).ok())
+        .unwrap_or_default()
+}
+
+/// Peak spread in bps, at FixedPoint8 precision (stored as a float fraction)
+fn spread_bps(row: &EpisodeRow) -> f64 {
+    (row.peak_spread * 1e8).round() / 1e4
+}
+
+/// Stored episodes matching `args`, oldest first
+pub fn select(args: &ExportArgs, history: &HistoryConfig) -> Result<Vec<EpisodeRow>> {
+    let dir = args.dir.as_ref().unwrap_or(&history.path);
+    if !dir.is_dir() {
+        return Err(HftError::Config(format!("history directory {} does not exist", dir.display())));
+    }
+    let query = args.query();
+    let mut rows = history::load_episodes(dir)?;
+    rows.retain(|row| query.matches(&row.symbol, row.start_ms));
+    rows.sort_by_key(|row| row.start_ms);
+    Ok(rows)
+}
+
+/// Run the export, returning the number of episodes written
+pub fn run(args: &ExportArgs, history: &HistoryConfig) -> Result<usize> {
+    let rows = select(args, history)?;
+    let path = args.output_path();
+    let out: Box<dyn Write + Send> = if path.as_os_str() == "-" {
+        Box::new(io::stdout())
+    } else {
+        Box::new(BufWriter::new(File::create(&path)?))
+    };
+    match args.format {
+        ExportFormat::Csv => write_csv(&rows, out)?,
+        ExportFormat::Parquet => write_parquet(&rows, out)?,
+    }
+    Ok(rows.len())
+}
+
+/// Write episodes as CSV with a header row
+pub fn write_csv<W: Write>(rows: &[EpisodeRow], mut out: W) -> io::Result<()> {
+    writeln!(out, "{}", CSV_HEADER)?;
+    for row in rows {
+        writeln!(
+            out,
+            "{},{},{},{},{},{},{},{},{},{},{}",
+            row.symbol,
+            row.long_exchange,
+            row.short_exchange,
+            rfc3339(row.start_ms),
+            rfc3339(row.end_ms),
+            row.start_ms,
+            row.end_ms,
+            row.duration_ms,
+            spread_bps(row),
+            row.updates,
+            row.aborted,
+        )?;
+    }
+    out.flush()
+}
+
+#[cfg(not(feature = "parquet-export"))]
+fn write_parquet<W: Write + Send>(_rows: &[EpisodeRow], _out: W) -> Result<()> {
+    Err(HftError::Config(PARQUET_UNAVAILABLE.to_string()))
+}
+
+/// Write episodes as a single-row-group Parquet file (same columns as CSV;
+/// times as UTC millisecond timestamps)
+#[cfg(feature = "parquet-export")]
+fn write_parquet<W: Write + Send>(rows: &[EpisodeRow], out: W) -> Result<()> {
+    use parquet::data_type::{BoolType, ByteArray, ByteArrayType, DoubleType, Int64Type};
+    use parquet::file::properties::WriterProperties;
+    use parquet::file::writer::SerializedFileWriter;
+    use parquet::schema::parser::parse_message_type;
+    use std::sync::Arc;
+
+    const SCHEMA: &str = "
+        message episode {
+            REQUIRED BYTE_ARRAY symbol (UTF8);
+            REQUIRED BYTE_ARRAY long_exchange (UTF8);
+            REQUIRED BYTE_ARRAY short_exchange (UTF8);
+            REQUIRED INT64 start_time (TIMESTAMP(MILLIS,true));
+            REQUIRED INT64 end_time (TIMESTAMP(MILLIS,true));
+            REQUIRED INT64 duration_ms;
+            REQUIRED DOUBLE peak_spread_bps;
+            REQUIRED INT64 updates;
+            REQUIRED BOOLEAN aborted;
+        }";
+
+    let err = |e: parquet::errors::ParquetError| HftError::Io(io::Error::other(e));
+    let strings = |f: fn(&EpisodeRow) -> &str| rows.iter().map(|r| ByteArray::from(f(r))).collect::<Vec<_>>();
+    let ints = |f: fn(&EpisodeRow) -> u64| rows.iter().map(|r| f(r) as i64).collect::<Vec<_>>();
+
+    let schema = Arc::new(parse_message_type(SCHEMA).map_err(err)?);
+    let mut writer = SerializedFileWriter::new(out, schema, Arc::new(WriterProperties::builder().build())).map_err(err)?;
+    let mut group = writer.next_row_group().map_err(err)?;
+    let mut column = 0;
+    while let Some(mut col) = group.next_column().map_err(err)? {
+        match column {
+            0 => col.typed::<ByteArrayType>().write_batch(&strings(|r| &r.symbol), None, None),
+            1 => col.typed::<ByteArrayType>().write_batch(&strings(|r| &r.long_exchange), None, None),
+            2 => col.typed::<ByteArrayType>().write_batch(&strings(|r| &r.short_exchange), None, None),
+            3 => col.typed::<Int64Type>().write_batch(&ints(|r| r.start_ms), None, None),
+            4 => col.typed::<Int64Type>().write_batch(&ints(|r| r.end_ms), None, None),
+            5 => col.typed::<Int64Type>().write_batch(&ints(|r| r.duration_ms), None, None),
+            6 => {
+                let bps: Vec<f64> = rows.iter().map(spread_bps).collect();
+                col.typed::<DoubleType>().write_batch(&bps, None, None)
+            }
+            7 => col.typed::<Int64Type>().write_batch(&ints(|r| r.updates), None, None),
+            _ => {
+                let aborted: Vec<bool> = rows.iter().map(|r| r.aborted).collect();
+                col.typed::<BoolType>().write_batch(&aborted, None, None)
+            }
+        }
+        .map_err(err)?;
+        col.close().map_err(err)?;
+        column += 1;
+    }
+    group.close().map_err(err)?;
+    writer.close().map_err(err)?;
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::history::{HistoryRow, HistoryStore};
+    use std::fs;
+
+    fn row(symbol: &str, start_ms: u64) -> EpisodeRow {
+        EpisodeRow {
+            id: 0,
+            symbol: symbol.to_string(),
+            long_exchange: "binance".to_string(),
+            short_exchange: "bybit".to_string(),
+            start_ms,
+            end_ms: start_ms + 1_500,
+            duration_ms: 1_500,
+            peak_spread: 0.0025,
+            updates: 4,
+            aborted: false,
+        }
+    }
+
+    #[test]
+    fn test_export_selects_and_writes_csv() {
+        let args = ExportArgs::parse(
+            ["--symbol", "btcusdt", "--from", "2024-01-01", "--to", "2024-01-01T00:00:10Z", "--format", "csv"]
+                .map(String::from),
+        )
+        .unwrap();
+        assert_eq!(args.symbol.as_deref(), Some("BTCUSDT"));
+        assert_eq!((args.from_ms, args.to_ms), (Some(1_704_067_200_000), Some(1_704_067_210_000)));
+        assert_eq!(args.output_path(), PathBuf::from("BTCUSDT.csv"));
+        assert!(ExportArgs::parse(["--format", "xlsx"].map(String::from)).is_err());
+        assert!(ExportArgs::parse(["--from", "5", "--to", "4"].map(String::from)).is_err());
+
+        let dir = std::env::temp_dir().join(format!("rust-hft-export-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        let history = HistoryConfig { path: dir.clone(), ..Default::default() };
+        let (_store, mut writer) = HistoryStore::open(&history).unwrap();
+        let t0 = 1_704_067_200_000;
+        for episode in [row("BTCUSDT", t0 + 5_000), row("ETHUSDT", t0 + 1_000), row("BTCUSDT", t0 + 1_000), row("BTCUSDT", t0 + 60_000)] {
+            writer.append(&HistoryRow::Episode(episode)).unwrap();
+        }
+        drop(writer);
+
+        let args = ExportArgs { dir: Some(dir.clone()), ..args };
+        let rows = select(&args, &HistoryConfig::default()).unwrap();
+        assert_eq!(rows.iter().map(|r| r.start_ms - t0).collect::<Vec<_>>(), vec![1_000, 5_000]);
+
+        let mut csv = Vec::new();
+        write_csv(&rows, &mut csv).unwrap();
+        let csv = String::from_utf8(csv).unwrap();
+        let lines: Vec<&str> = csv.lines().collect();
+        assert_eq!(lines[0], CSV_HEADER);
+        assert_eq!(
+            lines[1],
+            "BTCUSDT,binance,bybit,2024-01-01T00:00:01Z,2024-01-01T00:00:02.5Z,1704067201000,1704067202500,1500,25,4,false"
+        );
+        assert_eq!(lines.len(), 3);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
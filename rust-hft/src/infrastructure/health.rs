@@ -1,3 +1,15 @@
-//! Placeholder for health monitoring
+//! System health reporting
 //!
-//! Will implement system health checks and alerting
+//! Per-exchange reconnect circuit breaker status (see
+//! [`crate::ws::circuit_breaker`]), served in `/api/health`.
+
+use crate::ws::BreakerStatus;
+use serde::Serialize;
+
+/// Reconnect health of a single exchange
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExchangeHealth {
+    pub exchange: &'static str,
+    pub circuit: BreakerStatus,
+}
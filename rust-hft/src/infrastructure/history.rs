@@ -0,0 +1,724 @@
+//! Historical opportunity store (Cold Path)
+//!
+//! Embedded append-only tables of completed opportunity episodes and
+//! executions, one JSON row per line (`episodes.jsonl`, `executions.jsonl`)
+//! under a data directory. Retained rows are loaded on open and indexed by
+//! time and symbol for the /api/history queries (symbol, time range, minimum
+//! spread). Episode ids continue after the highest stored one, so fills
+//! from different runs never attribute to the same id.
+//!
+//! The engine never touches the files: it sends [`HistoryEvent`]s over a
+//! channel (dropping them if the writer lags) and [`run_writer`] appends them
+//! with a [`HistoryWriter`] on a blocking thread, then adds the rows to the
+//! shared [`HistoryStore`]. Table files rotate by size into zstd-compressed
+//! segments (`episodes.jsonl.1.zst` is the newest) and only `retain_files`
+//! segments are kept per table; rows of a deleted segment leave the store too.
+
+use crate::core::Side;
+use crate::engine::episodes::{Episode, HistoryEvent};
+use crate::engine::Fill;
+use crate::infrastructure::config::HistoryConfig;
+use crate::infrastructure::metrics::MetricsCollector;
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeSet, HashMap, VecDeque};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, BufRead, BufReader, Read, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+use tokio::sync::{mpsc, RwLock};
+
+const EPISODES_FILE: &str = "episodes.jsonl";
+const EXECUTIONS_FILE: &str = "executions.jsonl";
+
+/// Capacity of the engine → writer channel
+pub const HISTORY_QUEUE_CAPACITY: usize = 1024;
+
+/// Default max rows returned per query
+pub const DEFAULT_QUERY_LIMIT: usize = 500;
+
+/// Stored opportunity episode
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodeRow {
+    /// Episode id, unique across runs on the same store (0 in older rows)
+    #[serde(default)]
+    pub id: u64,
+    pub symbol: String,
+    pub long_exchange: String,
+    pub short_exchange: String,
+    /// Milliseconds since epoch
+    pub start_ms: u64,
+    pub end_ms: u64,
+    pub duration_ms: u64,
+    pub peak_spread: f64,
+    pub updates: u64,
+    /// An execution attempt ran out of its budget (absent in older rows)
+    #[serde(default)]
+    pub aborted: bool,
+}
+
+impl From<&Episode> for EpisodeRow {
+    fn from(episode: &Episode) -> Self {
+        let start_ms = episode.start / 1_000_000;
+        let end_ms = episode.end / 1_000_000;
+        Self {
+            id: episode.id,
+            symbol: episode.symbol.as_str().to_string(),
+            long_exchange: episode.long_ex.name().to_string(),
+            short_exchange: episode.short_ex.name().to_string(),
+            start_ms,
+            end_ms,
+            duration_ms: end_ms.saturating_sub(start_ms),
+            peak_spread: episode.peak_spread.fraction_f64(),
+            updates: episode.updates,
+            aborted: episode.aborted,
+        }
+    }
+}
+
+/// Stored execution (fill)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionRow {
+    pub exchange: String,
+    pub symbol: String,
+    pub side: String,
+    pub price: f64,
+    pub quantity: f64,
+    /// Milliseconds since epoch
+    pub timestamp_ms: u64,
+    /// Strategy instance the fill is attributed to (absent in older rows)
+    #[serde(default)]
+    pub strategy: Option<String>,
+    /// Opportunity episode open when the order was decided
+    #[serde(default)]
+    pub episode_id: Option<u64>,
+}
+
+impl ExecutionRow {
+    /// Attach the fill's attribution
+    pub fn tagged(mut self, strategy: Option<String>, episode_id: Option<u64>) -> Self {
+        self.strategy = strategy;
+        self.episode_id = episode_id;
+        self
+    }
+}
+
+impl From<&Fill> for ExecutionRow {
+    fn from(fill: &Fill) -> Self {
+        Self {
+            exchange: fill.exchange.name().to_string(),
+            symbol: fill.symbol.as_str().to_string(),
+            side: match fill.side {
+                Side::Buy => "buy",
+                Side::Sell => "sell",
+            }
+            .to_string(),
+            price: fill.price.to_f64(),
+            quantity: fill.quantity.to_f64(),
+            timestamp_ms: fill.timestamp / 1_000_000,
+            strategy: None,
+            episode_id: None,
+        }
+    }
+}
+
+/// Query filters (all optional; times in milliseconds since epoch)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryQuery {
+    pub symbol: Option<String>,
+    pub from: Option<u64>,
+    pub to: Option<u64>,
+    /// Minimum peak spread (episodes only)
+    pub min_spread: Option<f64>,
+    pub limit: Option<usize>,
+}
+
+impl HistoryQuery {
+    pub(crate) fn matches(&self, symbol: &str, timestamp_ms: u64) -> bool {
+        self.symbol.as_deref().is_none_or(|s| s.eq_ignore_ascii_case(symbol))
+            && self.from.is_none_or(|from| timestamp_ms >= from)
+            && self.to.is_none_or(|to| timestamp_ms <= to)
+    }
+
+    fn limit(&self) -> usize {
+        self.limit.unwrap_or(DEFAULT_QUERY_LIMIT)
+    }
+}
+
+/// Row of either table
+#[derive(Debug, Clone, PartialEq)]
+pub enum HistoryRow {
+    Episode(EpisodeRow),
+    Execution(ExecutionRow),
+}
+
+impl From<&HistoryEvent> for HistoryRow {
+    fn from(event: &HistoryEvent) -> Self {
+        match event {
+            HistoryEvent::Episode(episode) => Self::Episode(episode.into()),
+            HistoryEvent::Execution { fill, strategy, episode } => {
+                Self::Execution(ExecutionRow::from(fill).tagged(strategy.clone(), *episode))
+            }
+        }
+    }
+}
+
+/// Path of the `n`-th rotated segment (1 = newest)
+fn rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}.zst", n));
+    PathBuf::from(name)
+}
+
+/// Uncompressed rotated file written before segments were compressed
+fn legacy_rotated_path(path: &Path, n: usize) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".{}", n));
+    PathBuf::from(name)
+}
+
+/// Rotated segments on disk, oldest first
+fn segments(path: &Path) -> Vec<PathBuf> {
+    let rotated = (1..).take_while(|&n| rotated_path(path, n).exists() || legacy_rotated_path(path, n).exists()).count();
+    (1..=rotated)
+        .rev()
+        .map(|n| match rotated_path(path, n) {
+            zst if zst.exists() => zst,
+            _ => legacy_rotated_path(path, n),
+        })
+        .collect()
+}
+
+/// Rows of one file (zstd if it ends in `.zst`), skipping malformed lines
+fn read_rows<T: for<'de> Deserialize<'de>>(source: &Path) -> io::Result<Vec<T>> {
+    let file = File::open(source)?;
+    let reader: Box<dyn Read> = if source.extension().is_some_and(|ext| ext == "zst") {
+        Box::new(zstd::Decoder::new(file)?)
+    } else {
+        Box::new(file)
+    };
+    let mut rows = Vec::new();
+    for (i, line) in BufReader::new(reader).lines().enumerate() {
+        match serde_json::from_str(&line?) {
+            Ok(row) => rows.push(row),
+            Err(e) => tracing::warn!("Skipping {} line {}: {}", source.display(), i + 1, e),
+        }
+    }
+    Ok(rows)
+}
+
+/// Rows of a table, segments oldest first, with the row count of each segment
+fn load_rows<T: for<'de> Deserialize<'de>>(path: &Path) -> io::Result<(Vec<T>, VecDeque<usize>, usize)> {
+    let mut rows = Vec::new();
+    let mut counts = VecDeque::new();
+    for segment in segments(path) {
+        let before = rows.len();
+        rows.extend(read_rows(&segment)?);
+        counts.push_back(rows.len() - before);
+    }
+    let before = rows.len();
+    if path.exists() {
+        rows.extend(read_rows(path)?);
+    }
+    let current = rows.len() - before;
+    Ok((rows, counts, current))
+}
+
+/// Episodes stored under `dir`, oldest first, without opening the tables
+/// for writing (offline export)
+pub fn load_episodes(dir: &Path) -> io::Result<Vec<EpisodeRow>> {
+    load_rows(&dir.join(EPISODES_FILE)).map(|(rows, ..)| rows)
+}
+
+/// Compress `from` into `to` and delete `from`
+fn compress(from: &Path, to: &Path) -> io::Result<()> {
+    let mut partial = to.as_os_str().to_owned();
+    partial.push(".tmp");
+    let partial = PathBuf::from(partial);
+    zstd::stream::copy_encode(File::open(from)?, File::create(&partial)?, zstd::DEFAULT_COMPRESSION_LEVEL)?;
+    fs::rename(&partial, to)?;
+    fs::remove_file(from)
+}
+
+/// One JSONL table file, rotated by size into compressed segments
+#[derive(Debug)]
+struct Table {
+    path: PathBuf,
+    file: File,
+    /// Current file size (bytes)
+    size: u64,
+    /// Rows in the current file
+    rows: usize,
+    /// Rows per retained segment, oldest first
+    segments: VecDeque<usize>,
+}
+
+impl Table {
+    /// Delete segments beyond `retain`, compress legacy ones, load rows and open for append
+    fn open<T: for<'de> Deserialize<'de>>(path: PathBuf, retain: usize) -> io::Result<(Vec<T>, Self)> {
+        let mut n = retain + 1;
+        while rotated_path(&path, n).exists() || legacy_rotated_path(&path, n).exists() {
+            for stale in [rotated_path(&path, n), legacy_rotated_path(&path, n)] {
+                if stale.exists() {
+                    fs::remove_file(stale)?;
+                }
+            }
+            n += 1;
+        }
+        for n in 1..=retain {
+            let legacy = legacy_rotated_path(&path, n);
+            if legacy.exists() && !rotated_path(&path, n).exists() {
+                compress(&legacy, &rotated_path(&path, n))?;
+            }
+        }
+        let (rows, segments, current) = load_rows(&path)?;
+        let file = OpenOptions::new().create(true).append(true).open(&path)?;
+        let size = file.metadata()?.len();
+        Ok((rows, Self { path, file, size, rows: current, segments }))
+    }
+
+    /// Append row, returning bytes written
+    fn append<T: Serialize>(&mut self, row: &T) -> io::Result<u64> {
+        let mut line = serde_json::to_vec(row).map_err(io::Error::other)?;
+        line.push(b'\n');
+        self.file.write_all(&line)?;
+        self.size += line.len() as u64;
+        self.rows += 1;
+        Ok(line.len() as u64)
+    }
+
+    /// Shift segments, compress the current file into `.1.zst` and start a new one
+    ///
+    /// Returns the number of rows deleted with the oldest segment (or with
+    /// the current file when no segment is retained).
+    fn rotate(&mut self, retain: usize) -> io::Result<usize> {
+        let expired = if retain == 0 {
+            fs::remove_file(&self.path)?;
+            self.rows
+        } else {
+            let oldest = rotated_path(&self.path, retain);
+            let expired = if self.segments.len() >= retain { self.segments.pop_front().unwrap_or(0) } else { 0 };
+            if oldest.exists() {
+                fs::remove_file(oldest)?;
+            }
+            for n in (1..retain).rev() {
+                let from = rotated_path(&self.path, n);
+                if from.exists() {
+                    fs::rename(from, rotated_path(&self.path, n + 1))?;
+                }
+            }
+            compress(&self.path, &rotated_path(&self.path, 1))?;
+            self.segments.push_back(self.rows);
+            expired
+        };
+        self.file = OpenOptions::new().create(true).append(true).open(&self.path)?;
+        self.size = 0;
+        self.rows = 0;
+        tracing::info!("Rotated {}", self.path.display());
+        Ok(expired)
+    }
+}
+
+/// Outcome of appending a row
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Appended {
+    pub bytes: u64,
+    /// Oldest rows of the table deleted by retention
+    pub expired: usize,
+}
+
+/// Appends rows to the table files (blocking I/O, see [`run_writer`])
+#[derive(Debug)]
+pub struct HistoryWriter {
+    episodes: Table,
+    executions: Table,
+    /// Rotate a table at this size (0 = never)
+    max_file_bytes: u64,
+    /// Rotated segments kept per table
+    retain_files: usize,
+}
+
+impl HistoryWriter {
+    /// Append row to its table, rotating the table once full
+    pub fn append(&mut self, row: &HistoryRow) -> io::Result<Appended> {
+        let table = match row {
+            HistoryRow::Episode(_) => &mut self.episodes,
+            HistoryRow::Execution(_) => &mut self.executions,
+        };
+        let bytes = match row {
+            HistoryRow::Episode(row) => table.append(row)?,
+            HistoryRow::Execution(row) => table.append(row)?,
+        };
+        let expired = if self.max_file_bytes > 0 && table.size >= self.max_file_bytes {
+            table.rotate(self.retain_files)?
+        } else {
+            0
+        };
+        Ok(Appended { bytes, expired })
+    }
+}
+
+/// Row indexed by symbol and time
+trait Keyed {
+    /// Symbol and timestamp (milliseconds since epoch) of the row
+    fn key(&self) -> (&str, u64);
+}
+
+impl Keyed for EpisodeRow {
+    fn key(&self) -> (&str, u64) {
+        (&self.symbol, self.start_ms)
+    }
+}
+
+impl Keyed for ExecutionRow {
+    fn key(&self) -> (&str, u64) {
+        (&self.symbol, self.timestamp_ms)
+    }
+}
+
+/// Retained rows of one table with time and symbol indexes
+#[derive(Debug)]
+struct Rows<T> {
+    rows: VecDeque<T>,
+    /// Sequence number of `rows[0]`
+    first: u64,
+    /// (timestamp, sequence) of every row
+    by_time: BTreeSet<(u64, u64)>,
+    /// (timestamp, sequence) per upper-case symbol
+    by_symbol: HashMap<String, BTreeSet<(u64, u64)>>,
+}
+
+impl<T: Keyed> Rows<T> {
+    fn new(rows: Vec<T>) -> Self {
+        let mut indexed = Self {
+            rows: VecDeque::with_capacity(rows.len()),
+            first: 0,
+            by_time: BTreeSet::new(),
+            by_symbol: HashMap::new(),
+        };
+        rows.into_iter().for_each(|row| indexed.push(row));
+        indexed
+    }
+
+    fn push(&mut self, row: T) {
+        let seq = self.first + self.rows.len() as u64;
+        let (symbol, timestamp) = row.key();
+        self.by_time.insert((timestamp, seq));
+        self.by_symbol.entry(symbol.to_ascii_uppercase()).or_default().insert((timestamp, seq));
+        self.rows.push_back(row);
+    }
+
+    /// Drop the `count` oldest rows
+    fn expire(&mut self, count: usize) {
+        for _ in 0..count {
+            let Some(row) = self.rows.pop_front() else {
+                break;
+            };
+            let (symbol, timestamp) = row.key();
+            let entry = (timestamp, self.first);
+            self.by_time.remove(&entry);
+            let symbol = symbol.to_ascii_uppercase();
+            if let Some(index) = self.by_symbol.get_mut(&symbol) {
+                index.remove(&entry);
+                if index.is_empty() {
+                    self.by_symbol.remove(&symbol);
+                }
+            }
+            self.first += 1;
+        }
+    }
+
+    /// Rows in the query's symbol and time range, newest first
+    fn range<'a>(&'a self, query: &HistoryQuery) -> impl Iterator<Item = &'a T> + 'a {
+        let (from, to) = (query.from.unwrap_or(0), query.to.unwrap_or(u64::MAX));
+        let index = match &query.symbol {
+            _ if from > to => None,
+            Some(symbol) => self.by_symbol.get(&symbol.to_ascii_uppercase()),
+            None => Some(&self.by_time),
+        };
+        index
+            .into_iter()
+            .flat_map(move |index| index.range((from, 0)..=(to, u64::MAX)).rev())
+            .map(move |&(_, seq)| &self.rows[(seq - self.first) as usize])
+    }
+}
+
+/// Rows of the episode and execution tables, queried by the API
+///
+/// Only rows of the retained files are held (bounded by `max_file_bytes`
+/// and `retain_files`), indexed by time and symbol so a query walks just
+/// the matching range.
+#[derive(Debug)]
+pub struct HistoryStore {
+    episodes: Rows<EpisodeRow>,
+    executions: Rows<ExecutionRow>,
+    /// Highest episode id seen in either table
+    last_episode_id: u64,
+}
+
+impl HistoryStore {
+    /// Open (or create) the tables under `config.path`
+    ///
+    /// Segments beyond `retain_files` are deleted first; returns the rows
+    /// of the rest and the writer appending to the tables.
+    pub fn open(config: &HistoryConfig) -> io::Result<(Self, HistoryWriter)> {
+        let dir = &config.path;
+        fs::create_dir_all(dir)?;
+        let (episodes, episodes_table) = Table::open(dir.join(EPISODES_FILE), config.retain_files)?;
+        let (executions, executions_table) = Table::open(dir.join(EXECUTIONS_FILE), config.retain_files)?;
+        tracing::info!(
+            "History store {}: {} episodes, {} executions",
+            dir.display(),
+            episodes.len(),
+            executions.len()
+        );
+        let last_episode_id = episodes
+            .iter()
+            .map(|row: &EpisodeRow| row.id)
+            .chain(executions.iter().filter_map(|row: &ExecutionRow| row.episode_id))
+            .max()
+            .unwrap_or(0);
+        let store = Self {
+            episodes: Rows::new(episodes),
+            executions: Rows::new(executions),
+            last_episode_id,
+        };
+        let writer = HistoryWriter {
+            episodes: episodes_table,
+            executions: executions_table,
+            max_file_bytes: config.max_file_bytes,
+            retain_files: config.retain_files,
+        };
+        Ok((store, writer))
+    }
+
+    /// Highest stored episode id (0 if none); the engine continues after it
+    /// so ids stay unique across runs
+    pub fn last_episode_id(&self) -> u64 {
+        self.last_episode_id
+    }
+
+    /// Add a row the writer appended, dropping the `expired` oldest rows of its table
+    pub fn insert(&mut self, row: HistoryRow, expired: usize) {
+        match row {
+            HistoryRow::Episode(row) => {
+                self.last_episode_id = self.last_episode_id.max(row.id);
+                self.episodes.push(row);
+                self.episodes.expire(expired);
+            }
+            HistoryRow::Execution(row) => {
+                self.last_episode_id = self.last_episode_id.max(row.episode_id.unwrap_or(0));
+                self.executions.push(row);
+                self.executions.expire(expired);
+            }
+        }
+    }
+
+    /// Episodes matching query (by start time), newest first
+    pub fn episodes(&self, query: &HistoryQuery) -> Vec<EpisodeRow> {
+        self.episodes
+            .range(query)
+            .filter(|row| query.min_spread.is_none_or(|min| row.peak_spread >= min))
+            .take(query.limit())
+            .cloned()
+            .collect()
+    }
+
+    /// Executions matching query, newest first (`min_spread` is ignored)
+    pub fn executions(&self, query: &HistoryQuery) -> Vec<ExecutionRow> {
+        self.executions.range(query).take(query.limit()).cloned().collect()
+    }
+}
+
+/// Append history events from the engine until the channel closes
+///
+/// Files are written on a blocking thread without holding the store lock;
+/// the store is only locked to add the row. Queue depth and throughput go
+/// to the debug metrics.
+pub async fn run_writer(
+    store: Arc<RwLock<HistoryStore>>,
+    mut writer: HistoryWriter,
+    mut rx: mpsc::Receiver<HistoryEvent>,
+    metrics: Arc<MetricsCollector>,
+) {
+    while let Some(event) = rx.recv().await {
+        metrics.debug().record_history_depth(rx.len());
+        let row = HistoryRow::from(&event);
+        let written = tokio::task::spawn_blocking(move || {
+            let result = writer.append(&row);
+            (writer, row, result)
+        })
+        .await;
+        let (returned, row, result) = match written {
+            Ok(written) => written,
+            Err(e) => {
+                tracing::error!("History writer failed, history disabled: {}", e);
+                return;
+            }
+        };
+        writer = returned;
+        match result {
+            Ok(appended) => {
+                store.write().await.insert(row, appended.expired);
+                metrics.debug().record_history_write(appended.bytes);
+            }
+            Err(e) => tracing::warn!("Failed to write history {:?}: {}", event, e),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Bps, FixedPoint8, Symbol};
+    use crate::exchanges::Exchange;
+    use crate::test_utils::init_test_registry;
+
+    fn episode(symbol: &str, start_ms: u64, peak: i64) -> Episode {
+        Episode {
+            id: start_ms / 1_000,
+            symbol: Symbol::from_bytes(symbol.as_bytes()).unwrap(),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            start: start_ms * 1_000_000,
+            end: (start_ms + 500) * 1_000_000,
+            peak_spread: Bps::from_raw(peak),
+            updates: 3,
+            aborted: false,
+        }
+    }
+
+    fn config(dir: &Path, max_file_bytes: u64, retain_files: usize) -> HistoryConfig {
+        HistoryConfig {
+            enabled: true,
+            path: dir.to_path_buf(),
+            max_file_bytes,
+            retain_files,
+        }
+    }
+
+    /// Write row and add it to the store, as [`run_writer`] does
+    fn append(store: &mut HistoryStore, writer: &mut HistoryWriter, row: HistoryRow) -> u64 {
+        let appended = writer.append(&row).unwrap();
+        store.insert(row, appended.expired);
+        appended.bytes
+    }
+
+    #[test]
+    fn test_store_query_and_reopen() {
+        init_test_registry();
+        let dir = std::env::temp_dir().join(format!("rust-hft-history-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        let (mut store, mut writer) = HistoryStore::open(&config(&dir, 0, 0)).unwrap();
+        for (symbol, start, peak) in [("BTCUSDT", 1_000, 60_000), ("ETHUSDT", 2_000, 200_000), ("BTCUSDT", 3_000, 100_000)] {
+            append(&mut store, &mut writer, HistoryRow::Episode((&episode(symbol, start, peak)).into()));
+        }
+        let fill = ExecutionRow::from(&Fill {
+            exchange: Exchange::Bybit,
+            symbol: Symbol::from_bytes(b"BTCUSDT").unwrap(),
+            side: Side::Sell,
+            price: FixedPoint8::ONE,
+            quantity: FixedPoint8::ONE,
+            timestamp: 3_100_000_000,
+        })
+        .tagged(Some("arb-main".into()), Some(3));
+        append(&mut store, &mut writer, HistoryRow::Execution(fill));
+        assert_eq!(store.episodes(&HistoryQuery::default()).len(), 3);
+        drop((store, writer));
+
+        let (store, _writer) = HistoryStore::open(&config(&dir, 0, 0)).unwrap();
+        let all = store.episodes(&HistoryQuery::default());
+        assert_eq!(all.len(), 3);
+        assert_eq!(all[0].start_ms, 3_000);
+        assert_eq!((all[0].id, all[0].duration_ms), (3, 500));
+
+        let btc = HistoryQuery { symbol: Some("btcusdt".into()), ..Default::default() };
+        assert_eq!(store.episodes(&btc).len(), 2);
+
+        let ranged = HistoryQuery { from: Some(1_500), to: Some(2_500), ..Default::default() };
+        assert_eq!(store.episodes(&ranged)[0].symbol, "ETHUSDT");
+
+        let wide = HistoryQuery { min_spread: Some(0.001), limit: Some(1), ..Default::default() };
+        assert_eq!(store.episodes(&wide)[0].symbol, "BTCUSDT");
+
+        let empty = HistoryQuery { from: Some(2_500), to: Some(1_500), ..Default::default() };
+        assert!(store.episodes(&empty).is_empty());
+        let unknown = HistoryQuery { symbol: Some("SOLUSDT".into()), ..Default::default() };
+        assert!(store.episodes(&unknown).is_empty());
+
+        // Ids continue after the stored ones
+        assert_eq!(store.last_episode_id(), 3);
+
+        let fills = store.executions(&btc);
+        assert_eq!((fills[0].side.as_str(), fills[0].timestamp_ms), ("sell", 3_100));
+        assert_eq!((fills[0].strategy.as_deref(), fills[0].episode_id), (Some("arb-main"), Some(3)));
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_rotation_and_retention() {
+        init_test_registry();
+        let dir = std::env::temp_dir().join(format!("rust-hft-history-rotate-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+
+        // Every row exceeds the limit, so each append rotates
+        let (mut store, mut writer) = HistoryStore::open(&config(&dir, 1, 2)).unwrap();
+        for i in 0..4 {
+            let row = HistoryRow::Episode((&episode("BTCUSDT", 1_000 * (i + 1), 60_000)).into());
+            assert!(append(&mut store, &mut writer, row) > 0);
+        }
+        // Rows of deleted segments left the live store and its indexes too
+        let starts: Vec<_> = store.episodes(&HistoryQuery::default()).iter().map(|e| e.start_ms).collect();
+        assert_eq!(starts, vec![4_000, 3_000]);
+        let btc = HistoryQuery { symbol: Some("BTCUSDT".into()), ..Default::default() };
+        assert_eq!(store.episodes(&btc).len(), 2);
+        drop((store, writer));
+
+        let table = dir.join(EPISODES_FILE);
+        assert_eq!(fs::metadata(&table).unwrap().len(), 0);
+        assert!(rotated_path(&table, 2).exists());
+        assert!(!rotated_path(&table, 3).exists());
+        assert!(!legacy_rotated_path(&table, 1).exists());
+
+        // Segments are zstd-compressed
+        let mut magic = [0u8; 4];
+        File::open(rotated_path(&table, 1)).unwrap().read_exact(&mut magic).unwrap();
+        assert_eq!(magic, [0x28, 0xb5, 0x2f, 0xfd]);
+
+        // Retained rows reload newest first
+        let (store, _writer) = HistoryStore::open(&config(&dir, 1, 2)).unwrap();
+        let starts: Vec<_> = store.episodes(&HistoryQuery::default()).iter().map(|e| e.start_ms).collect();
+        assert_eq!(starts, vec![4_000, 3_000]);
+
+        // Lowering retention prunes older segments before loading
+        let (store, _writer) = HistoryStore::open(&config(&dir, 1, 1)).unwrap();
+        assert!(!rotated_path(&table, 2).exists());
+        assert_eq!(store.episodes(&HistoryQuery::default()).len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_legacy_rotated_files_compressed_on_open() {
+        init_test_registry();
+        let dir = std::env::temp_dir().join(format!("rust-hft-history-legacy-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+
+        let table = dir.join(EPISODES_FILE);
+        let row = EpisodeRow::from(&episode("BTCUSDT", 1_000, 60_000));
+        fs::write(legacy_rotated_path(&table, 1), format!("{}\n", serde_json::to_string(&row).unwrap())).unwrap();
+
+        let (store, _writer) = HistoryStore::open(&config(&dir, 0, 2)).unwrap();
+        assert!(!legacy_rotated_path(&table, 1).exists());
+        assert!(rotated_path(&table, 1).exists());
+        assert_eq!(store.episodes(&HistoryQuery::default()), vec![row]);
+        assert_eq!(load_episodes(&dir).unwrap().len(), 1);
+
+        fs::remove_dir_all(&dir).unwrap();
+    }
+}
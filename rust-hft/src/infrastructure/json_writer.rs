@@ -0,0 +1,315 @@
+//! Allocation-free JSON encoding for broadcast types
+//!
+//! Push endpoints and publishers send hundreds of updates per second;
+//! going through serde_json builds a `String` (and DTO) per message. These
+//! encoders write compact JSON straight into a caller-provided `Vec<u8>`,
+//! normally taken from a [`JsonBufferPool`] and returned after sending.
+//!
+//! Field names and shapes match the REST DTOs (camelCase). FixedPoint8
+//! values are written as exact decimal numbers (no f64 round-trip).
+
+use crate::core::{Bps, FixedPoint8, TickerData};
+use crate::hot_path::{ScreenerStats, SpreadEvent};
+use crate::infrastructure::pool::ObjectPool;
+
+/// Types that encode themselves as compact JSON
+pub trait WriteJson {
+    /// Append JSON to `out` (does not clear it)
+    fn write_json(&self, out: &mut Vec<u8>);
+}
+
+/// Pool of reusable JSON output buffers
+pub type JsonBufferPool = ObjectPool<Vec<u8>>;
+
+impl JsonBufferPool {
+    /// Create a pool of empty buffers with `buffer_capacity` reserved
+    pub fn with_json_capacity(pool_capacity: usize, buffer_capacity: usize) -> Self {
+        Self::with_capacity(pool_capacity, move || Vec::with_capacity(buffer_capacity))
+    }
+
+    /// Encode `value` into a pooled buffer (falls back to a new buffer if empty)
+    ///
+    /// Return the buffer with `release` once sent.
+    #[inline]
+    pub fn encode<T: WriteJson>(&self, value: &T) -> Vec<u8> {
+        let mut buf = self.acquire().unwrap_or_else(|| self.create_new());
+        buf.clear();
+        value.write_json(&mut buf);
+        buf
+    }
+}
+
+/// Compact JSON object writer
+struct ObjectWriter<'a> {
+    out: &'a mut Vec<u8>,
+    first: bool,
+}
+
+impl<'a> ObjectWriter<'a> {
+    #[inline]
+    fn begin(out: &'a mut Vec<u8>) -> Self {
+        out.push(b'{');
+        Self { out, first: true }
+    }
+
+    #[inline]
+    fn key(&mut self, key: &str) {
+        if !self.first {
+            self.out.push(b',');
+        }
+        self.first = false;
+        write_str(self.out, key);
+        self.out.push(b':');
+    }
+
+    #[inline]
+    fn str(&mut self, key: &str, value: &str) {
+        self.key(key);
+        write_str(self.out, value);
+    }
+
+    #[inline]
+    fn fixed(&mut self, key: &str, value: FixedPoint8) {
+        self.key(key);
+        let mut buf = [0u8; 32];
+        let len = value.write_to_buffer(&mut buf);
+        self.out.extend_from_slice(&buf[..len]);
+    }
+
+    /// Spreads are written as fractions, like the dashboard DTOs
+    #[inline]
+    fn bps(&mut self, key: &str, value: Bps) {
+        self.fixed(key, value.as_fraction());
+    }
+
+    #[inline]
+    fn opt_bps(&mut self, key: &str, value: Option<Bps>) {
+        self.opt_fixed(key, value.map(|v| v.as_fraction()));
+    }
+
+    #[inline]
+    fn opt_fixed(&mut self, key: &str, value: Option<FixedPoint8>) {
+        match value {
+            Some(value) => self.fixed(key, value),
+            None => {
+                self.key(key);
+                self.out.extend_from_slice(b"null");
+            }
+        }
+    }
+
+    #[inline]
+    fn opt_str(&mut self, key: &str, value: Option<&str>) {
+        match value {
+            Some(value) => self.str(key, value),
+            None => {
+                self.key(key);
+                self.out.extend_from_slice(b"null");
+            }
+        }
+    }
+
+    #[inline]
+    fn u64(&mut self, key: &str, value: u64) {
+        self.key(key);
+        write_u64(self.out, value);
+    }
+
+    #[inline]
+    fn bool(&mut self, key: &str, value: bool) {
+        self.key(key);
+        self.out.extend_from_slice(if value { b"true" } else { b"false" });
+    }
+
+    #[inline]
+    fn end(self) {
+        self.out.push(b'}');
+    }
+}
+
+/// Write JSON string literal (escapes quotes, backslashes and control bytes)
+pub(crate) fn write_str(out: &mut Vec<u8>, value: &str) {
+    const HEX: &[u8; 16] = b"0123456789abcdef";
+    out.push(b'"');
+    for &b in value.as_bytes() {
+        match b {
+            b'"' => out.extend_from_slice(b"\\\""),
+            b'\\' => out.extend_from_slice(b"\\\\"),
+            0..=0x1f => out.extend_from_slice(&[b'\\', b'u', b'0', b'0', HEX[(b >> 4) as usize], HEX[(b & 0xf) as usize]]),
+            _ => out.push(b),
+        }
+    }
+    out.push(b'"');
+}
+
+/// Write unsigned integer in decimal
+fn write_u64(out: &mut Vec<u8>, mut value: u64) {
+    let mut buf = [0u8; 20];
+    let mut pos = buf.len();
+    loop {
+        pos -= 1;
+        buf[pos] = b'0' + (value % 10) as u8;
+        value /= 10;
+        if value == 0 {
+            break;
+        }
+    }
+    out.extend_from_slice(&buf[pos..]);
+}
+
+impl WriteJson for TickerData {
+    fn write_json(&self, out: &mut Vec<u8>) {
+        let mut w = ObjectWriter::begin(out);
+        w.str("symbol", self.symbol.as_str());
+        w.fixed("bidPrice", self.bid_price);
+        w.fixed("bidQty", self.bid_qty);
+        w.fixed("askPrice", self.ask_price);
+        w.fixed("askQty", self.ask_qty);
+        w.u64("timestamp", self.timestamp);
+        w.u64("updateId", self.update_id);
+        w.end();
+    }
+}
+
+impl WriteJson for SpreadEvent {
+    fn write_json(&self, out: &mut Vec<u8>) {
+        let mut w = ObjectWriter::begin(out);
+        w.str("symbol", self.symbol.as_str());
+        w.bps("spread", self.spread);
+        w.str("longEx", self.long_ex.name());
+        w.str("shortEx", self.short_ex.name());
+        w.u64("timestamp", self.timestamp);
+        w.opt_fixed("spreadTicks", self.spread_ticks);
+        w.end();
+    }
+}
+
+/// Same shape as the dashboard `ScreenerDto`
+impl WriteJson for ScreenerStats {
+    fn write_json(&self, out: &mut Vec<u8>) {
+        let mut w = ObjectWriter::begin(out);
+        w.str("symbol", self.symbol.as_str());
+        w.bps("currentSpread", self.current_spread);
+        w.opt_fixed("spreadTicks", self.spread_ticks);
+        w.bps("breakEven", self.break_even);
+        w.bps("edgeAfterCosts", self.edge_after_costs);
+        w.bps("spreadRange", self.spread_range);
+        w.opt_bps("spreadP1", self.spread_p1);
+        w.opt_bps("spreadP99", self.spread_p99);
+        w.opt_bps("robustRange", self.robust_range);
+        w.u64("hits", self.hits);
+        w.bps("threshold", self.threshold);
+        w.str("thresholdSource", self.threshold_source.name());
+        w.u64("estHalfLife", 0);
+        w.fixed("liquidationVolume", self.liquidation_volume);
+        w.fixed("priceVolatility", self.price_volatility);
+        w.opt_str("staleVenue", self.stale_venue.map(|e| e.name()));
+        w.opt_str("suspendedVenue", self.suspended.map(|(e, _)| e.name()));
+        w.opt_str("suspendedStatus", self.suspended.map(|(_, s)| s.name()));
+        w.bool("isSpreadNa", !self.is_valid);
+        w.end();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Symbol, TradingStatus};
+    use crate::exchanges::Exchange;
+    use crate::hot_path::ThresholdSource;
+    use crate::infrastructure::api::ScreenerDto;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(s: &str) -> FixedPoint8 {
+        FixedPoint8::parse_bytes(s.as_bytes()).unwrap()
+    }
+
+    fn bps(s: &str) -> Bps {
+        Bps::from_fraction(fp(s))
+    }
+
+    #[test]
+    fn test_ticker_and_spread_json() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = TickerData::new(sym, fp("50000.5"), fp("1.25"), fp("50001"), fp("0.001"), 1_700_000_000_000);
+
+        let mut out = Vec::new();
+        ticker.write_json(&mut out);
+        assert_eq!(
+            std::str::from_utf8(&out).unwrap(),
+            r#"{"symbol":"BTCUSDT","bidPrice":50000.50000000,"bidQty":1.25000000,"askPrice":50001.00000000,"askQty":0.00100000,"timestamp":1700000000000,"updateId":0}"#
+        );
+
+        let event = SpreadEvent {
+            symbol: sym,
+            spread: bps("-0.0025"),
+            long_ex: Exchange::Bybit,
+            short_ex: Exchange::Binance,
+            timestamp: 0,
+            spread_ticks: Some(fp("3")),
+        };
+        out.clear();
+        event.write_json(&mut out);
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["spread"], -0.0025);
+        assert_eq!(value["longEx"], "bybit");
+        assert_eq!(value["timestamp"], 0);
+        assert_eq!(value["spreadTicks"], 3.0);
+    }
+
+    #[test]
+    fn test_screener_matches_dto() {
+        init_test_registry();
+        let stats = ScreenerStats {
+            symbol: Symbol::from_bytes(b"ETHUSDT").unwrap(),
+            current_spread: bps("0.0012"),
+            spread_ticks: None,
+            break_even: bps("0.0011"),
+            edge_after_costs: bps("0.0001"),
+            spread_range: bps("0.003"),
+            spread_p1: Some(bps("-0.0004")),
+            spread_p99: None,
+            robust_range: Some(bps("0.0021")),
+            hits: 7,
+            threshold: bps("0.0025"),
+            threshold_source: ThresholdSource::Calibrated,
+            liquidation_volume: fp("125000"),
+            price_volatility: fp("0.02"),
+            stale_venue: Some(Exchange::Bybit),
+            suspended: Some((Exchange::Binance, TradingStatus::Halted)),
+            is_valid: true,
+        };
+
+        let pool = JsonBufferPool::with_json_capacity(2, 256);
+        let buf = pool.encode(&stats);
+        let manual: serde_json::Value = serde_json::from_slice(&buf).unwrap();
+        let dto = serde_json::to_value(ScreenerDto::from(stats)).unwrap();
+
+        // Same keys and values (DTO goes through f64, so compare approximately)
+        let (manual, dto) = (manual.as_object().unwrap(), dto.as_object().unwrap());
+        assert_eq!(manual.keys().collect::<Vec<_>>(), dto.keys().collect::<Vec<_>>());
+        for (key, value) in dto {
+            match value.as_f64() {
+                Some(expected) => assert!((manual[key].as_f64().unwrap() - expected).abs() < 1e-12, "{key}"),
+                None => assert_eq!(&manual[key], value, "{key}"),
+            }
+        }
+
+        // Buffer is reused after release
+        pool.release(buf).unwrap();
+        let again = pool.encode(&stats);
+        assert!(again.capacity() >= 256);
+        assert_eq!(pool.len(), 1);
+    }
+
+    #[test]
+    fn test_string_escaping() {
+        let mut out = Vec::new();
+        write_str(&mut out, "a\"b\\c\n");
+        assert_eq!(out, br#""a\"b\\c\u000a""#);
+        out.clear();
+        write_u64(&mut out, u64::MAX);
+        assert_eq!(out, u64::MAX.to_string().as_bytes());
+    }
+}
@@ -0,0 +1,200 @@
+//! Shared-secret authentication of instance links (Cold Path)
+//!
+//! The peer and standby links carry tickers, leg ownership and failover
+//! roles, so anything able to reach their port could steer execution. Both
+//! instances hold the same secret, read from the environment. On connect
+//! each side sends a random nonce line; every line after that is
+//! `<hex HMAC-SHA256> <frame>`, signed over the sender's nonce, the
+//! receiver's nonce, a per-direction sequence number and the frame. A line
+//! failing verification drops the connection, so forged, replayed,
+//! reordered or reflected frames (including the first `hello` or
+//! heartbeat) never reach the engine.
+
+use crate::rest::RequestSigner;
+use crate::{HftError, Result};
+use serde::Serialize;
+use std::io;
+use std::time::Duration;
+use tokio::io::{AsyncBufReadExt, AsyncWriteExt, BufReader, Lines};
+use tokio::net::tcp::{OwnedReadHalf, OwnedWriteHalf};
+use tokio::net::TcpStream;
+
+/// Hex length of a connection nonce
+const NONCE_LEN: usize = 32;
+
+/// Secret shared by both ends of a link
+#[derive(Debug, Clone)]
+pub struct LinkSecret {
+    signer: RequestSigner,
+}
+
+impl LinkSecret {
+    pub fn new(secret: impl Into<String>) -> Self {
+        Self { signer: RequestSigner::new(String::new(), secret) }
+    }
+
+    /// Secret from environment variable `var` (an unset or empty one is an error)
+    pub fn from_env(var: &str) -> Result<Self> {
+        let secret = std::env::var(var)
+            .ok()
+            .filter(|s| !s.is_empty())
+            .ok_or_else(|| HftError::Config(format!("Link secret {} is not set", var)))?;
+        Ok(Self::new(secret))
+    }
+}
+
+/// Exchange nonces on a fresh connection
+///
+/// Fails if the other side sends no well-formed nonce within `timeout`.
+pub async fn handshake(stream: TcpStream, secret: &LinkSecret, timeout: Duration) -> io::Result<(LinkReader, LinkWriter)> {
+    let (read, mut write) = stream.into_split();
+    let mut lines = BufReader::new(read).lines();
+    let local = uuid::Uuid::new_v4().simple().to_string();
+    write.write_all(format!("{}\n", local).as_bytes()).await?;
+
+    let remote = tokio::time::timeout(timeout, lines.next_line())
+        .await
+        .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "no nonce from the other side"))??
+        .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "closed before the nonce"))?;
+    if remote.len() != NONCE_LEN || !remote.bytes().all(|b| b.is_ascii_hexdigit()) || remote == local {
+        return Err(io::Error::new(io::ErrorKind::PermissionDenied, "bad nonce"));
+    }
+
+    let reader = LinkReader {
+        lines,
+        signer: secret.signer.clone(),
+        context: format!("{}:{}", remote, local),
+        seq: 0,
+    };
+    let writer = LinkWriter {
+        write,
+        signer: secret.signer.clone(),
+        context: format!("{}:{}", local, remote),
+        seq: 0,
+    };
+    Ok((reader, writer))
+}
+
+/// Receiving half: yields verified frames only
+pub struct LinkReader {
+    lines: Lines<BufReader<OwnedReadHalf>>,
+    signer: RequestSigner,
+    /// Sender nonce, then ours
+    context: String,
+    seq: u64,
+}
+
+impl LinkReader {
+    /// Next verified frame (None once the other side closes)
+    ///
+    /// Cancel-safe: a line is only consumed when it has been read whole.
+    pub async fn next_frame(&mut self) -> io::Result<Option<String>> {
+        let Some(line) = self.lines.next_line().await? else {
+            return Ok(None);
+        };
+        let frame = line
+            .split_once(' ')
+            .filter(|(signature, frame)| self.signer.verify(&signed_payload(&self.context, self.seq, frame), signature))
+            .map(|(_, frame)| frame.to_string())
+            .ok_or_else(|| io::Error::new(io::ErrorKind::PermissionDenied, "unauthenticated frame"))?;
+        self.seq += 1;
+        Ok(Some(frame))
+    }
+}
+
+/// Sending half: signs every frame
+pub struct LinkWriter {
+    write: OwnedWriteHalf,
+    signer: RequestSigner,
+    /// Our nonce, then the receiver's
+    context: String,
+    seq: u64,
+}
+
+impl LinkWriter {
+    /// Send `frame` as signed JSON
+    pub async fn send<T: Serialize>(&mut self, frame: &T) -> io::Result<()> {
+        let json = serde_json::to_string(frame).map_err(io::Error::other)?;
+        let signature = self.signer.sign(&signed_payload(&self.context, self.seq, &json));
+        self.seq += 1;
+        self.write.write_all(format!("{} {}\n", signature, json).as_bytes()).await
+    }
+}
+
+fn signed_payload(context: &str, seq: u64, frame: &str) -> String {
+    format!("{}:{}:{}", context, seq, frame)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    async fn pair(client_secret: &str) -> (io::Result<(LinkReader, LinkWriter)>, io::Result<(LinkReader, LinkWriter)>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let timeout = Duration::from_secs(1);
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            handshake(stream, &LinkSecret::new("s3cret"), timeout).await
+        });
+        let client = handshake(TcpStream::connect(addr).await.unwrap(), &LinkSecret::new(client_secret), timeout).await;
+        (server.await.unwrap(), client)
+    }
+
+    #[tokio::test]
+    async fn test_frames_verified_in_order() {
+        let (server, client) = pair("s3cret").await;
+        let (mut server_rx, _server_tx) = server.unwrap();
+        let (_client_rx, mut client_tx) = client.unwrap();
+
+        client_tx.send(&"hello").await.unwrap();
+        client_tx.send(&42).await.unwrap();
+        assert_eq!(server_rx.next_frame().await.unwrap().as_deref(), Some(r#""hello""#));
+        assert_eq!(server_rx.next_frame().await.unwrap().as_deref(), Some("42"));
+    }
+
+    #[tokio::test]
+    async fn test_wrong_secret_rejected() {
+        let (server, client) = pair("guess").await;
+        let (mut server_rx, _server_tx) = server.unwrap();
+        let (_client_rx, mut client_tx) = client.unwrap();
+
+        client_tx.send(&"hello").await.unwrap();
+        let err = server_rx.next_frame().await.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::PermissionDenied);
+    }
+
+    #[tokio::test]
+    async fn test_plain_and_replayed_lines_rejected() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let secret = LinkSecret::new("s3cret");
+        let server = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let (mut reader, _writer) = handshake(stream, &secret, Duration::from_secs(1)).await.unwrap();
+            let mut frames = Vec::new();
+            let result = loop {
+                match reader.next_frame().await {
+                    Ok(Some(frame)) => frames.push(frame),
+                    other => break other,
+                }
+            };
+            (frames, result)
+        });
+
+        // Raw client: valid nonce, one correctly signed frame, then a replay of it
+        let mut stream = TcpStream::connect(addr).await.unwrap();
+        let mut lines = BufReader::new(&mut stream).lines();
+        let remote = lines.next_line().await.unwrap().unwrap();
+        let local = "0123456789abcdef0123456789abcdef";
+        let json = r#"{"type":"heartbeat"}"#;
+        let signature = LinkSecret::new("s3cret").signer.sign(&signed_payload(&format!("{}:{}", local, remote), 0, json));
+        let signed = format!("{} {}\n", signature, json);
+        stream.write_all(format!("{}\n{}{}", local, signed, signed).as_bytes()).await.unwrap();
+
+        let (frames, result) = server.await.unwrap();
+        assert_eq!(frames, vec![json.to_string()]);
+        assert_eq!(result.unwrap_err().kind(), io::ErrorKind::PermissionDenied);
+    }
+}
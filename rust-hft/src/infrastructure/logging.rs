@@ -9,7 +9,6 @@
 
 use std::fs;
 use std::path::Path;
-use tracing::Level;
 use tracing_appender::non_blocking::{NonBlocking, WorkerGuard};
 use tracing_appender::rolling::{RollingFileAppender, Rotation};
 use tracing_subscriber::{
@@ -0,0 +1,141 @@
+//! Time-consistent market snapshots (Cold Path)
+//!
+//! Correlation and portfolio analytics need every symbol's quotes as of the
+//! same moment; reading symbols one by one under separate locks skews them
+//! by however long the engine ran in between. The snapshotter takes all
+//! quote pairs under a single tracker read lock once per tick and publishes
+//! the result, so every consumer of a tick sees the same cross-section.
+//! Each quote carries its age at capture for staleness filtering.
+
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::{watch, RwLock};
+
+use crate::core::clock;
+use crate::exchanges::Exchange;
+use crate::hot_path::{QuotePair, ThresholdTracker};
+
+/// All quote pairs as of one snapshotter tick
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MarketSnapshot {
+    /// Snapshotter tick (0 = nothing captured yet)
+    pub tick: u64,
+    /// Capture time (ms since epoch)
+    pub taken_ms: u64,
+    pub pairs: Vec<QuotePair>,
+    /// Age of each quote at capture, indexed like `pairs` then `Exchange::index`
+    pub ages: Vec<[Duration; Exchange::COUNT]>,
+}
+
+impl MarketSnapshot {
+    /// Capture every quote pair of `tracker` at `now`
+    pub fn capture(tracker: &ThresholdTracker, tick: u64, now: Instant, taken_ms: u64) -> Self {
+        let pairs = tracker.quote_pairs();
+        let ages = pairs
+            .iter()
+            .map(|pair| pair.updated_at.map(|at| at.map_or(Duration::ZERO, |at| now.saturating_duration_since(at))))
+            .collect();
+        Self { tick, taken_ms, pairs, ages }
+    }
+}
+
+/// Periodic snapshot publisher
+#[derive(Debug)]
+pub struct MarketSnapshotter {
+    interval: Duration,
+    tx: watch::Sender<Arc<MarketSnapshot>>,
+}
+
+impl MarketSnapshotter {
+    /// Snapshotter capturing every `interval` once [`run`](Self::run) is spawned
+    pub fn new(interval: Duration) -> Self {
+        Self {
+            interval: interval.max(Duration::from_millis(1)),
+            tx: watch::Sender::new(Arc::new(MarketSnapshot::default())),
+        }
+    }
+
+    /// Snapshot of the last tick
+    pub fn latest(&self) -> Arc<MarketSnapshot> {
+        self.tx.borrow().clone()
+    }
+
+    /// Receiver notified on every tick
+    pub fn subscribe(&self) -> watch::Receiver<Arc<MarketSnapshot>> {
+        self.tx.subscribe()
+    }
+
+    /// Capture one tick now and publish it
+    pub async fn capture(&self, tracker: &RwLock<ThresholdTracker>) -> Arc<MarketSnapshot> {
+        let tick = self.tx.borrow().tick + 1;
+        let snapshot = {
+            let tracker = tracker.read().await;
+            Arc::new(MarketSnapshot::capture(&tracker, tick, Instant::now(), clock::coarse_unix_ms()))
+        };
+        self.tx.send_replace(snapshot.clone());
+        snapshot
+    }
+
+    /// Capture every interval
+    pub async fn run(self: Arc<Self>, tracker: Arc<RwLock<ThresholdTracker>>) {
+        let mut interval = tokio::time::interval(self.interval);
+        interval.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Skip);
+        loop {
+            interval.tick().await;
+            self.capture(&tracker).await;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Symbol, TickerData};
+
+    fn ticker(symbol: Symbol, price: f64) -> TickerData {
+        let price = FixedPoint8::from_f64(price).unwrap();
+        TickerData::new(symbol, price, FixedPoint8::ONE, price, FixedPoint8::ONE, 1_000)
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_pairs_one_tick() {
+        crate::test_utils::init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let sol = Symbol::from_bytes(b"SOLUSDT").unwrap();
+        let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+        {
+            let mut tracker = tracker.write().await;
+            for (symbol, price) in [(btc, 100.0), (eth, 10.0)] {
+                tracker.update(ticker(symbol, price), Exchange::Binance);
+                tracker.update(ticker(symbol, price + 0.1), Exchange::Bybit);
+            }
+            // Single-venue symbols have no pair
+            tracker.update(ticker(sol, 1.0), Exchange::Binance);
+        }
+
+        let snapshotter = MarketSnapshotter::new(Duration::from_millis(10));
+        assert_eq!(snapshotter.latest().tick, 0);
+        let mut rx = snapshotter.subscribe();
+        let snapshot = snapshotter.capture(&tracker).await;
+        assert!(rx.has_changed().unwrap());
+        assert_eq!(rx.borrow_and_update().tick, 1);
+
+        let mut symbols: Vec<Symbol> = snapshot.pairs.iter().map(|p| p.symbol).collect();
+        symbols.sort_unstable();
+        let mut expected = vec![btc, eth];
+        expected.sort_unstable();
+        assert_eq!(symbols, expected);
+        assert_eq!(snapshot.ages.len(), 2);
+        let btc_pair = snapshot.pairs.iter().find(|p| p.symbol == btc).unwrap();
+        assert_eq!(btc_pair.tickers[Exchange::Bybit.index()].bid_price, FixedPoint8::from_f64(100.1).unwrap());
+
+        // Later ticks see later quotes; the published one stays consistent
+        tracker.write().await.update(ticker(btc, 101.0), Exchange::Binance);
+        assert_eq!(snapshotter.latest().tick, 1);
+        let next = snapshotter.capture(&tracker).await;
+        assert_eq!(next.tick, 2);
+        let btc_pair = next.pairs.iter().find(|p| p.symbol == btc).unwrap();
+        assert_eq!(btc_pair.tickers[Exchange::Binance.index()].bid_price, FixedPoint8::from_f64(101.0).unwrap());
+    }
+}
@@ -3,8 +3,843 @@
 //! Lock-free metrics counters using atomic operations.
 //! Collected in hot path, exported via API in cold path.
 
+use super::pool::PoolOccupancy;
+use super::startup::StartupProgress;
+use super::unparsed::ParseQuarantine;
+use crate::core::clock;
+use crate::hot_path::InvariantCounters;
+use crate::ws::{Lane, LaneStats, ReconnectCircuits, ServerPingStats};
+use parking_lot::RwLock;
+use serde::Serialize;
 use std::sync::atomic::{AtomicU64, Ordering};
-use std::time::{Duration, Instant, SystemTime};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Maximum number of tracked feeds (connections)
+pub const MAX_FEEDS: usize = 8;
+
+/// Upper bounds (microseconds) of lag histogram buckets; last bucket is open-ended
+pub const LAG_BUCKETS_US: [u64; 6] = [100, 500, 1_000, 5_000, 10_000, 50_000];
+
+const LAG_BUCKET_COUNT: usize = LAG_BUCKETS_US.len() + 1;
+
+/// A/B feed race statistics for redundant connections
+///
+/// Each duplicate update credits a win to the feed that delivered it first
+/// and a loss (with lag) to the feed that delivered the copy.
+pub struct FeedRaceStats {
+    /// Feed labels (cold path, set once at startup)
+    labels: RwLock<Vec<String>>,
+    wins: [AtomicU64; MAX_FEEDS],
+    losses: [AtomicU64; MAX_FEEDS],
+    /// Sum of loss lag in nanoseconds (for mean)
+    lag_sum_ns: [AtomicU64; MAX_FEEDS],
+    /// Loss lag histogram per feed
+    lag_buckets: [[AtomicU64; LAG_BUCKET_COUNT]; MAX_FEEDS],
+}
+
+/// Per-feed race snapshot for API export
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FeedRaceSnapshot {
+    pub feed: usize,
+    pub label: String,
+    pub wins: u64,
+    pub losses: u64,
+    /// wins / (wins + losses), 0 if no races
+    pub win_rate: f64,
+    /// Mean lag behind the winner when this feed lost (microseconds)
+    pub mean_lag_us: f64,
+    /// Loss lag counts per bucket (bounds in LAG_BUCKETS_US, last is overflow)
+    pub lag_histogram: Vec<u64>,
+}
+
+impl FeedRaceStats {
+    /// Create empty stats
+    pub fn new() -> Self {
+        Self {
+            labels: RwLock::new(Vec::new()),
+            wins: std::array::from_fn(|_| AtomicU64::new(0)),
+            losses: std::array::from_fn(|_| AtomicU64::new(0)),
+            lag_sum_ns: std::array::from_fn(|_| AtomicU64::new(0)),
+            lag_buckets: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU64::new(0))),
+        }
+    }
+
+    /// Set feed labels (index = feed id)
+    pub fn set_labels(&self, labels: Vec<String>) {
+        *self.labels.write() = labels;
+    }
+
+    /// Feed labels (index = feed id)
+    pub fn labels(&self) -> Vec<String> {
+        self.labels.read().clone()
+    }
+
+    /// Record race outcome: `winner` delivered first, `loser` was `lag` behind
+    #[inline]
+    pub fn record(&self, winner: usize, loser: usize, lag: Duration) {
+        // Same feed delivering twice is not a race
+        if winner == loser || winner >= MAX_FEEDS || loser >= MAX_FEEDS {
+            return;
+        }
+        self.wins[winner].fetch_add(1, Ordering::Relaxed);
+        self.losses[loser].fetch_add(1, Ordering::Relaxed);
+        self.lag_sum_ns[loser].fetch_add(lag.as_nanos() as u64, Ordering::Relaxed);
+
+        let lag_us = lag.as_micros() as u64;
+        let bucket = LAG_BUCKETS_US
+            .iter()
+            .position(|&bound| lag_us < bound)
+            .unwrap_or(LAG_BUCKETS_US.len());
+        self.lag_buckets[loser][bucket].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Snapshot of feeds that have a label or any race recorded
+    pub fn snapshot(&self) -> Vec<FeedRaceSnapshot> {
+        let labels = self.labels.read();
+        (0..MAX_FEEDS)
+            .filter_map(|feed| {
+                let wins = self.wins[feed].load(Ordering::Relaxed);
+                let losses = self.losses[feed].load(Ordering::Relaxed);
+                let label = labels.get(feed).cloned();
+                if label.is_none() && wins == 0 && losses == 0 {
+                    return None;
+                }
+
+                let races = wins + losses;
+                let lag_sum = self.lag_sum_ns[feed].load(Ordering::Relaxed);
+                Some(FeedRaceSnapshot {
+                    feed,
+                    label: label.unwrap_or_else(|| format!("feed#{}", feed)),
+                    wins,
+                    losses,
+                    win_rate: if races > 0 { wins as f64 / races as f64 } else { 0.0 },
+                    mean_lag_us: if losses > 0 { lag_sum as f64 / losses as f64 / 1_000.0 } else { 0.0 },
+                    lag_histogram: self.lag_buckets[feed]
+                        .iter()
+                        .map(|b| b.load(Ordering::Relaxed))
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for FeedRaceStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Lifecycle state of a supervised feed task
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum TaskState {
+    /// Connecting and subscribing
+    Starting,
+    /// Delivering messages
+    Running,
+    /// Exited; waiting out the restart backoff
+    Restarting,
+    /// Exited and the restart policy gave up
+    Failed,
+    /// Closed gracefully without restart, or the engine stopped
+    Stopped,
+}
+
+/// Per-feed task status for API export
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TaskSnapshot {
+    pub feed: usize,
+    pub label: String,
+    pub state: TaskState,
+    /// Restarts since startup
+    pub restarts: u64,
+    /// Reason for the last exit (None if never exited)
+    pub last_error: Option<String>,
+}
+
+/// Supervised feed task status (cold path, updated on state changes only)
+#[derive(Default)]
+pub struct FeedTaskStats {
+    tasks: RwLock<Vec<TaskSnapshot>>,
+}
+
+impl FeedTaskStats {
+    /// Create empty stats
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Register feeds in `Starting` state (index = feed id)
+    pub fn register(&self, labels: &[String]) {
+        *self.tasks.write() = labels
+            .iter()
+            .enumerate()
+            .map(|(feed, label)| TaskSnapshot {
+                feed,
+                label: label.clone(),
+                state: TaskState::Starting,
+                restarts: 0,
+                last_error: None,
+            })
+            .collect();
+    }
+
+    /// Update task state
+    pub fn set_state(&self, feed: usize, state: TaskState) {
+        if let Some(task) = self.tasks.write().get_mut(feed) {
+            task.state = state;
+        }
+    }
+
+    /// Record a task exit with its reason
+    pub fn record_exit(&self, feed: usize, reason: String) {
+        if let Some(task) = self.tasks.write().get_mut(feed) {
+            task.last_error = Some(reason);
+        }
+    }
+
+    /// Record a restart
+    pub fn record_restart(&self, feed: usize) {
+        if let Some(task) = self.tasks.write().get_mut(feed) {
+            task.restarts += 1;
+        }
+    }
+
+    /// Total restarts across feeds
+    pub fn restarts(&self) -> u64 {
+        self.tasks.read().iter().map(|t| t.restarts).sum()
+    }
+
+    /// Every registered feed is delivering messages (false before registration)
+    pub fn all_running(&self) -> bool {
+        let tasks = self.tasks.read();
+        !tasks.is_empty() && tasks.iter().all(|t| t.state == TaskState::Running)
+    }
+
+    /// Feeds the restart policy gave up on
+    pub fn failed(&self) -> u64 {
+        self.tasks.read().iter().filter(|t| t.state == TaskState::Failed).count() as u64
+    }
+
+    /// Status of every registered feed
+    pub fn snapshot(&self) -> Vec<TaskSnapshot> {
+        self.tasks.read().clone()
+    }
+}
+
+/// Per-connection delivery statistics
+///
+/// Recorded by each feed task before deduplication, so every connection is
+/// measured on everything it delivered: gaps and latency can be attributed
+/// to one connection even when a redundant one covers for it.
+pub struct ConnectionStats {
+    /// Reference for arrival offsets
+    started: Instant,
+    messages: [AtomicU64; MAX_FEEDS],
+    /// Last arrival (ns since `started`, 0 = none yet)
+    last_arrival_ns: [AtomicU64; MAX_FEEDS],
+    /// Longest silence between consecutive messages (ns)
+    max_gap_ns: [AtomicU64; MAX_FEEDS],
+    /// Exchange event time → local receive, over messages carrying a timestamp
+    latency_sum_ns: [AtomicU64; MAX_FEEDS],
+    latency_samples: [AtomicU64; MAX_FEEDS],
+    max_latency_ns: [AtomicU64; MAX_FEEDS],
+    /// Flood protection: updates replaced by a newer one while held back
+    conflated: [AtomicU64; MAX_FEEDS],
+    /// Flood protection: messages dropped over the rate limit
+    dropped: [AtomicU64; MAX_FEEDS],
+}
+
+/// Per-connection delivery snapshot for API export
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionSnapshot {
+    pub connection: usize,
+    pub label: String,
+    pub messages: u64,
+    /// Longest silence between consecutive messages (milliseconds)
+    pub max_gap_ms: f64,
+    /// Mean exchange → receive latency (milliseconds, includes clock skew)
+    pub mean_latency_ms: f64,
+    pub max_latency_ms: f64,
+    /// Updates conflated and messages dropped by flood protection
+    pub conflated: u64,
+    pub dropped: u64,
+}
+
+impl ConnectionStats {
+    /// Create empty stats
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            messages: std::array::from_fn(|_| AtomicU64::new(0)),
+            last_arrival_ns: std::array::from_fn(|_| AtomicU64::new(0)),
+            max_gap_ns: std::array::from_fn(|_| AtomicU64::new(0)),
+            latency_sum_ns: std::array::from_fn(|_| AtomicU64::new(0)),
+            latency_samples: std::array::from_fn(|_| AtomicU64::new(0)),
+            max_latency_ns: std::array::from_fn(|_| AtomicU64::new(0)),
+            conflated: std::array::from_fn(|_| AtomicU64::new(0)),
+            dropped: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// Record a message on `connection` arriving at `arrived`
+    ///
+    /// `latency` is exchange event time → receive (None if the message has
+    /// no exchange timestamp). Each connection has a single writer task.
+    #[inline]
+    pub fn record(&self, connection: usize, arrived: Instant, latency: Option<Duration>) {
+        if connection >= MAX_FEEDS {
+            return;
+        }
+        self.messages[connection].fetch_add(1, Ordering::Relaxed);
+
+        // +1 keeps a message at the very start distinguishable from "none yet"
+        let at = arrived.saturating_duration_since(self.started).as_nanos() as u64 + 1;
+        let last = self.last_arrival_ns[connection].swap(at, Ordering::Relaxed);
+        if last != 0 {
+            self.max_gap_ns[connection].fetch_max(at.saturating_sub(last), Ordering::Relaxed);
+        }
+
+        if let Some(latency) = latency {
+            let ns = latency.as_nanos() as u64;
+            self.latency_sum_ns[connection].fetch_add(ns, Ordering::Relaxed);
+            self.latency_samples[connection].fetch_add(1, Ordering::Relaxed);
+            self.max_latency_ns[connection].fetch_max(ns, Ordering::Relaxed);
+        }
+    }
+
+    /// Record an update on `connection` conflated by flood protection
+    #[inline]
+    pub fn record_conflated(&self, connection: usize) {
+        if connection < MAX_FEEDS {
+            self.conflated[connection].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Record a message on `connection` dropped by flood protection
+    #[inline]
+    pub fn record_dropped(&self, connection: usize) {
+        if connection < MAX_FEEDS {
+            self.dropped[connection].fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    /// Snapshot of labelled connections and connections with messages
+    pub fn snapshot(&self, labels: &[String]) -> Vec<ConnectionSnapshot> {
+        let ms = |ns: u64| ns as f64 / 1_000_000.0;
+        (0..MAX_FEEDS)
+            .filter_map(|connection| {
+                let messages = self.messages[connection].load(Ordering::Relaxed);
+                let label = labels.get(connection).cloned();
+                if label.is_none() && messages == 0 {
+                    return None;
+                }
+                let samples = self.latency_samples[connection].load(Ordering::Relaxed);
+                let latency_sum = self.latency_sum_ns[connection].load(Ordering::Relaxed);
+                Some(ConnectionSnapshot {
+                    connection,
+                    label: label.unwrap_or_else(|| format!("feed#{}", connection)),
+                    messages,
+                    max_gap_ms: ms(self.max_gap_ns[connection].load(Ordering::Relaxed)),
+                    mean_latency_ms: if samples > 0 { ms(latency_sum) / samples as f64 } else { 0.0 },
+                    max_latency_ms: ms(self.max_latency_ns[connection].load(Ordering::Relaxed)),
+                    conflated: self.conflated[connection].load(Ordering::Relaxed),
+                    dropped: self.dropped[connection].load(Ordering::Relaxed),
+                })
+            })
+            .collect()
+    }
+}
+
+impl Default for ConnectionStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Power-of-two latency buckets: bucket i holds durations below 2^i ns
+/// (the last bucket, ~9 minutes, is open-ended)
+pub const LATENCY_BUCKET_COUNT: usize = 40;
+
+/// Lock-free latency histogram with power-of-two buckets
+///
+/// Percentiles are bucket upper bounds, so they overstate by at most 2x;
+/// enough to tell microseconds from milliseconds in a run summary.
+pub struct LatencyHistogram {
+    buckets: [AtomicU64; LATENCY_BUCKET_COUNT],
+}
+
+impl LatencyHistogram {
+    /// Create empty histogram
+    pub fn new() -> Self {
+        Self {
+            buckets: std::array::from_fn(|_| AtomicU64::new(0)),
+        }
+    }
+
+    /// Record one sample
+    #[inline]
+    pub fn record(&self, latency: Duration) {
+        let ns = latency.as_nanos().min(u64::MAX as u128) as u64;
+        let bucket = (u64::BITS - ns.leading_zeros()) as usize;
+        self.buckets[bucket.min(LATENCY_BUCKET_COUNT - 1)].fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Samples recorded
+    pub fn count(&self) -> u64 {
+        self.buckets.iter().map(|b| b.load(Ordering::Relaxed)).sum()
+    }
+
+    /// Upper bound of the bucket holding quantile `q` (None if empty)
+    pub fn percentile(&self, q: f64) -> Option<Duration> {
+        histogram_percentile(&self.counts(), q)
+    }
+
+    /// Sample count per bucket (diff two reads for a windowed histogram)
+    pub fn counts(&self) -> [u64; LATENCY_BUCKET_COUNT] {
+        std::array::from_fn(|i| self.buckets[i].load(Ordering::Relaxed))
+    }
+}
+
+/// Quantile `q` of [`LatencyHistogram::counts`] (None if empty)
+pub fn histogram_percentile(counts: &[u64; LATENCY_BUCKET_COUNT], q: f64) -> Option<Duration> {
+    let total: u64 = counts.iter().sum();
+    if total == 0 {
+        return None;
+    }
+    let rank = ((q.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+    let mut seen = 0;
+    for (i, count) in counts.iter().enumerate() {
+        seen += count;
+        if seen >= rank {
+            return Some(Duration::from_nanos(1u64 << i));
+        }
+    }
+    Some(Duration::from_nanos(1u64 << (LATENCY_BUCKET_COUNT - 1)))
+}
+
+impl Default for LatencyHistogram {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Order acknowledgement round trips of one venue (see engine::latency)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct OrderLatencySnapshot {
+    pub exchange: &'static str,
+    pub samples: u64,
+    pub p50_ms: f64,
+    pub p90_ms: f64,
+    pub p99_ms: f64,
+    /// Smoothed round trip used for venue scoring
+    pub estimate_ms: f64,
+}
+
+/// Slippage of one venue over one UTC day (see engine::fill_quality)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct VenueQualitySnapshot {
+    pub date: String,
+    pub exchange: &'static str,
+    pub fills: u64,
+    pub notional: f64,
+    /// Notional-weighted averages; positive = adverse
+    pub avg_vs_mid_bps: f64,
+    pub avg_vs_touch_bps: f64,
+    /// None until a limit order filled
+    pub avg_vs_submitted_bps: Option<f64>,
+    pub worst_vs_mid_bps: f64,
+}
+
+/// Slippage of one fill against its decision quote
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LegSlippageSnapshot {
+    pub timestamp_ms: u64,
+    pub exchange: &'static str,
+    pub symbol: String,
+    pub side: &'static str,
+    pub decision_mid: f64,
+    pub decision_touch: f64,
+    pub submitted_price: Option<f64>,
+    pub fill_price: f64,
+    pub quantity: f64,
+    pub vs_mid_bps: f64,
+    pub vs_touch_bps: f64,
+    pub vs_submitted_bps: Option<f64>,
+}
+
+/// Daily per-venue slippage and the latest scored fills
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExecutionQualitySnapshot {
+    /// Oldest day first, venues without fills omitted
+    pub days: Vec<VenueQualitySnapshot>,
+    /// Newest fill first
+    pub recent: Vec<LegSlippageSnapshot>,
+}
+
+/// Fills, notional and PnL of one attribution bucket (USDT)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlBucketSnapshot {
+    /// Strategy instance ("manual", "flatten", "unattributed") or symbol
+    pub name: String,
+    pub fills: u64,
+    pub notional: f64,
+    pub realized_pnl: f64,
+    /// Estimated at the configured taker rate
+    pub fees: f64,
+    pub net_pnl: f64,
+}
+
+/// PnL of fills tagged with one opportunity episode (USDT)
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct EpisodePnlSnapshot {
+    pub id: u64,
+    pub symbol: String,
+    pub fills: u64,
+    pub notional: f64,
+    pub realized_pnl: f64,
+    pub fees: f64,
+    pub net_pnl: f64,
+}
+
+/// Realized PnL and fees per strategy, symbol and recent episode
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PnlAttributionSnapshot {
+    /// Highest net PnL first
+    pub strategies: Vec<PnlBucketSnapshot>,
+    /// Highest net PnL first
+    pub symbols: Vec<PnlBucketSnapshot>,
+    /// Newest episode first
+    pub episodes: Vec<EpisodePnlSnapshot>,
+}
+
+/// Queue, pool and buffer gauges for slow-consumer and leak diagnostics
+///
+/// Gauges are plain stores from the warm path; pools register once at startup.
+pub struct DebugStats {
+    /// Engine channel capacity (set once at startup)
+    queue_capacity: AtomicU64,
+    /// Engine channel depth at last receive
+    queue_depth: AtomicU64,
+    /// Highest engine channel depth seen
+    queue_high_water: AtomicU64,
+    /// History writer channel capacity, depth at last receive and high water
+    history_capacity: AtomicU64,
+    history_depth: AtomicU64,
+    history_high_water: AtomicU64,
+    /// History rows/bytes appended and events dropped because the writer lagged
+    history_rows: AtomicU64,
+    history_bytes: AtomicU64,
+    history_dropped: AtomicU64,
+    /// Read buffer capacity per feed (bytes)
+    read_buffer_bytes: [AtomicU64; MAX_FEEDS],
+    /// Largest frame received per feed (bytes)
+    max_frame_bytes: [AtomicU64; MAX_FEEDS],
+    /// Outbound frames written per feed and lane, with mean/max queue delay (ns)
+    send_frames: [[AtomicU64; Lane::COUNT]; MAX_FEEDS],
+    send_mean_delay_ns: [[AtomicU64; Lane::COUNT]; MAX_FEEDS],
+    send_max_delay_ns: [[AtomicU64; Lane::COUNT]; MAX_FEEDS],
+    /// Server pings per feed: count, last (Unix ms), last and longest gap (ms)
+    server_pings: [AtomicU64; MAX_FEEDS],
+    server_ping_last_ms: [AtomicU64; MAX_FEEDS],
+    server_ping_interval_ms: [AtomicU64; MAX_FEEDS],
+    server_ping_max_interval_ms: [AtomicU64; MAX_FEEDS],
+    /// Registered object pools (cold path)
+    pools: RwLock<Vec<(&'static str, Arc<dyn PoolOccupancy>)>>,
+    /// Frames that failed to parse, shared with the feeds
+    unparsed: Arc<ParseQuarantine>,
+    /// Sampled invariant violations, shared with the feeds
+    invariants: Arc<InvariantCounters>,
+}
+
+/// Channel depth snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueueSnapshot {
+    pub name: &'static str,
+    pub depth: u64,
+    pub high_water: u64,
+    pub capacity: u64,
+}
+
+/// History writer throughput snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RecorderSnapshot {
+    pub rows_written: u64,
+    pub bytes_written: u64,
+    /// Average since startup
+    pub bytes_per_sec: f64,
+    /// Events dropped because the writer queue was full
+    pub dropped: u64,
+}
+
+/// Pool occupancy snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PoolSnapshot {
+    pub name: &'static str,
+    /// Objects available (capacity - available = in use)
+    pub available: usize,
+    pub capacity: usize,
+}
+
+/// Per-connection buffer snapshot
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ConnectionBufferSnapshot {
+    pub feed: usize,
+    pub label: String,
+    pub read_buffer_bytes: u64,
+    pub max_frame_bytes: u64,
+    /// Outbound queue per lane (current connection)
+    pub send_lanes: Vec<SendLaneSnapshot>,
+    /// Protocol pings from the server (current connection)
+    pub server_pings: ServerPingSnapshot,
+}
+
+/// Server ping cadence of one connection
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ServerPingSnapshot {
+    /// Pings received and answered
+    pub received: u64,
+    /// Last ping (Unix millis, None = none yet)
+    pub last_ms: Option<u64>,
+    /// Gap between the last two pings (None = fewer than two)
+    pub last_interval_ms: Option<u64>,
+    pub max_interval_ms: Option<u64>,
+}
+
+/// Outbound frames written from one lane and their queue delay
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SendLaneSnapshot {
+    pub lane: &'static str,
+    pub frames: u64,
+    pub mean_delay_us: f64,
+    pub max_delay_us: f64,
+}
+
+impl DebugStats {
+    /// Create empty stats
+    pub fn new() -> Self {
+        Self {
+            queue_capacity: AtomicU64::new(0),
+            queue_depth: AtomicU64::new(0),
+            queue_high_water: AtomicU64::new(0),
+            history_capacity: AtomicU64::new(0),
+            history_depth: AtomicU64::new(0),
+            history_high_water: AtomicU64::new(0),
+            history_rows: AtomicU64::new(0),
+            history_bytes: AtomicU64::new(0),
+            history_dropped: AtomicU64::new(0),
+            read_buffer_bytes: std::array::from_fn(|_| AtomicU64::new(0)),
+            max_frame_bytes: std::array::from_fn(|_| AtomicU64::new(0)),
+            send_frames: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU64::new(0))),
+            send_mean_delay_ns: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU64::new(0))),
+            send_max_delay_ns: std::array::from_fn(|_| std::array::from_fn(|_| AtomicU64::new(0))),
+            server_pings: std::array::from_fn(|_| AtomicU64::new(0)),
+            server_ping_last_ms: std::array::from_fn(|_| AtomicU64::new(0)),
+            server_ping_interval_ms: std::array::from_fn(|_| AtomicU64::new(0)),
+            server_ping_max_interval_ms: std::array::from_fn(|_| AtomicU64::new(0)),
+            pools: RwLock::new(Vec::new()),
+            unparsed: Arc::new(ParseQuarantine::default()),
+            invariants: Arc::new(InvariantCounters::new()),
+        }
+    }
+
+    /// Set engine channel capacity
+    pub fn set_queue_capacity(&self, capacity: usize) {
+        self.queue_capacity.store(capacity as u64, Ordering::Relaxed);
+    }
+
+    /// Record engine channel depth (messages waiting)
+    #[inline]
+    pub fn record_queue_depth(&self, depth: usize) {
+        self.queue_depth.store(depth as u64, Ordering::Relaxed);
+        self.queue_high_water.fetch_max(depth as u64, Ordering::Relaxed);
+    }
+
+    /// Set history writer channel capacity
+    pub fn set_history_capacity(&self, capacity: usize) {
+        self.history_capacity.store(capacity as u64, Ordering::Relaxed);
+    }
+
+    /// Record history writer channel depth (events waiting)
+    #[inline]
+    pub fn record_history_depth(&self, depth: usize) {
+        self.history_depth.store(depth as u64, Ordering::Relaxed);
+        self.history_high_water.fetch_max(depth as u64, Ordering::Relaxed);
+    }
+
+    /// Record a history row appended (`bytes` written)
+    #[inline]
+    pub fn record_history_write(&self, bytes: u64) {
+        self.history_rows.fetch_add(1, Ordering::Relaxed);
+        self.history_bytes.fetch_add(bytes, Ordering::Relaxed);
+    }
+
+    /// Record a history event dropped because the writer lagged
+    #[inline]
+    pub fn record_history_dropped(&self) {
+        self.history_dropped.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record buffer sizes of feed `feed`
+    #[inline]
+    pub fn record_buffers(&self, feed: usize, read_buffer: usize, max_frame: usize) {
+        if feed >= MAX_FEEDS {
+            return;
+        }
+        self.read_buffer_bytes[feed].store(read_buffer as u64, Ordering::Relaxed);
+        self.max_frame_bytes[feed].store(max_frame as u64, Ordering::Relaxed);
+    }
+
+    /// Record outbound lane totals of feed `feed` (current connection)
+    #[inline]
+    pub fn record_send_lanes(&self, feed: usize, lanes: &[LaneStats; Lane::COUNT]) {
+        if feed >= MAX_FEEDS {
+            return;
+        }
+        for (i, stats) in lanes.iter().enumerate() {
+            self.send_frames[feed][i].store(stats.frames, Ordering::Relaxed);
+            self.send_mean_delay_ns[feed][i].store(stats.mean_delay().as_nanos() as u64, Ordering::Relaxed);
+            self.send_max_delay_ns[feed][i].store(stats.max_delay_ns, Ordering::Relaxed);
+        }
+    }
+
+    /// Record server ping cadence of feed `feed` (current connection)
+    #[inline]
+    pub fn record_server_pings(&self, feed: usize, pings: &ServerPingStats) {
+        if feed >= MAX_FEEDS {
+            return;
+        }
+        self.server_pings[feed].store(pings.received, Ordering::Relaxed);
+        self.server_ping_last_ms[feed].store(pings.last_ms, Ordering::Relaxed);
+        self.server_ping_interval_ms[feed].store(pings.last_interval_ms, Ordering::Relaxed);
+        self.server_ping_max_interval_ms[feed].store(pings.max_interval_ms, Ordering::Relaxed);
+    }
+
+    /// Register a pool for occupancy reporting
+    pub fn register_pool(&self, name: &'static str, pool: Arc<dyn PoolOccupancy>) {
+        self.pools.write().push((name, pool));
+    }
+
+    /// Parse-error quarantine (handed to feeds with `with_quarantine`)
+    pub fn unparsed(&self) -> &Arc<ParseQuarantine> {
+        &self.unparsed
+    }
+
+    /// Invariant violation counters (handed to feeds with `with_invariants`)
+    pub fn invariants(&self) -> &Arc<InvariantCounters> {
+        &self.invariants
+    }
+
+    /// Engine and history writer channel snapshot
+    pub fn queues(&self) -> Vec<QueueSnapshot> {
+        vec![
+            QueueSnapshot {
+                name: "engine",
+                depth: self.queue_depth.load(Ordering::Relaxed),
+                high_water: self.queue_high_water.load(Ordering::Relaxed),
+                capacity: self.queue_capacity.load(Ordering::Relaxed),
+            },
+            QueueSnapshot {
+                name: "history",
+                depth: self.history_depth.load(Ordering::Relaxed),
+                high_water: self.history_high_water.load(Ordering::Relaxed),
+                capacity: self.history_capacity.load(Ordering::Relaxed),
+            },
+        ]
+    }
+
+    /// History writer throughput over `uptime_seconds`
+    pub fn recorder(&self, uptime_seconds: u64) -> RecorderSnapshot {
+        let bytes_written = self.history_bytes.load(Ordering::Relaxed);
+        RecorderSnapshot {
+            rows_written: self.history_rows.load(Ordering::Relaxed),
+            bytes_written,
+            bytes_per_sec: bytes_written as f64 / uptime_seconds.max(1) as f64,
+            dropped: self.history_dropped.load(Ordering::Relaxed),
+        }
+    }
+
+    /// Registered pool snapshot
+    pub fn pools(&self) -> Vec<PoolSnapshot> {
+        self.pools
+            .read()
+            .iter()
+            .map(|(name, pool)| PoolSnapshot {
+                name,
+                available: pool.len(),
+                capacity: pool.capacity(),
+            })
+            .collect()
+    }
+
+    /// Buffer snapshot of labelled feeds and feeds with recorded buffers
+    pub fn connections(&self, labels: &[String]) -> Vec<ConnectionBufferSnapshot> {
+        (0..MAX_FEEDS)
+            .filter_map(|feed| {
+                let read_buffer_bytes = self.read_buffer_bytes[feed].load(Ordering::Relaxed);
+                let max_frame_bytes = self.max_frame_bytes[feed].load(Ordering::Relaxed);
+                let label = labels.get(feed).cloned();
+                if label.is_none() && read_buffer_bytes == 0 && max_frame_bytes == 0 {
+                    return None;
+                }
+                Some(ConnectionBufferSnapshot {
+                    feed,
+                    label: label.unwrap_or_else(|| format!("feed#{}", feed)),
+                    read_buffer_bytes,
+                    max_frame_bytes,
+                    send_lanes: Lane::ALL
+                        .iter()
+                        .map(|lane| {
+                            let i = lane.index();
+                            SendLaneSnapshot {
+                                lane: lane.name(),
+                                frames: self.send_frames[feed][i].load(Ordering::Relaxed),
+                                mean_delay_us: self.send_mean_delay_ns[feed][i].load(Ordering::Relaxed) as f64 / 1e3,
+                                max_delay_us: self.send_max_delay_ns[feed][i].load(Ordering::Relaxed) as f64 / 1e3,
+                            }
+                        })
+                        .collect(),
+                    server_pings: ServerPingSnapshot {
+                        received: self.server_pings[feed].load(Ordering::Relaxed),
+                        last_ms: nonzero(&self.server_ping_last_ms[feed]),
+                        last_interval_ms: nonzero(&self.server_ping_interval_ms[feed]),
+                        max_interval_ms: nonzero(&self.server_ping_max_interval_ms[feed]),
+                    },
+                })
+            })
+            .collect()
+    }
+}
+
+/// Gauge value, None while unset
+#[inline]
+fn nonzero(gauge: &AtomicU64) -> Option<u64> {
+    Some(gauge.load(Ordering::Relaxed)).filter(|&v| v != 0)
+}
+
+impl Default for DebugStats {
+    fn default() -> Self {
+        Self::new()
+    }
+}
 
 /// System metrics collector
 ///
@@ -17,6 +852,53 @@ pub struct MetricsCollector {
     bybit_messages: AtomicU64,
     /// Total messages processed
     total_messages: AtomicU64,
+    /// Duplicate messages dropped (redundant connections)
+    duplicate_messages: AtomicU64,
+    /// Orders suppressed by symbol cooldown
+    suppressed_cooldown: AtomicU64,
+    /// Orders suppressed by trades-per-minute limit
+    suppressed_rate_limit: AtomicU64,
+    /// Orders suppressed because the symbol is not focused
+    suppressed_unfocused: AtomicU64,
+    /// Symbols currently in focus (auto-focus mode)
+    focused_symbols: AtomicU64,
+    /// Orders suppressed during startup warm-up
+    suppressed_warmup: AtomicU64,
+    /// Orders suppressed while the engine is paused
+    suppressed_paused: AtomicU64,
+    /// Orders suppressed because execution is disabled for the symbol
+    suppressed_disabled: AtomicU64,
+    /// Orders rejected because the symbol is being delisted
+    suppressed_delisted: AtomicU64,
+    /// Orders suppressed while the symbol is halted or in auction
+    suppressed_halted: AtomicU64,
+    /// Orders suppressed because a peer instance owns the leg or the peer was lost
+    suppressed_peer: AtomicU64,
+    /// Orders suppressed while standby or after the failover lease expired
+    suppressed_standby: AtomicU64,
+    /// Orders suppressed while the venue's private stream is down
+    suppressed_private_stream: AtomicU64,
+    /// Orders rejected by a strategy instance's capital or risk limits
+    suppressed_risk: AtomicU64,
+    /// Orders dropped in read-only mode, and the mode itself (1 = read-only)
+    suppressed_read_only: AtomicU64,
+    read_only: AtomicU64,
+    /// Startup warm-up state (1 = execution disabled), seconds left, warm symbols
+    warmup_active: AtomicU64,
+    warmup_remaining_secs: AtomicU64,
+    warm_symbols: AtomicU64,
+    /// Quotes quarantined by sanity filters
+    quarantined_quotes: AtomicU64,
+    /// Locked/crossed quotes rejected (all symbols and venues)
+    locked_books: AtomicU64,
+    crossed_books: AtomicU64,
+    /// Error messages received from exchanges
+    exchange_errors: AtomicU64,
+    /// Engine loop iterations over the stall budget, and the longest (us)
+    engine_stalls: AtomicU64,
+    max_stall_us: AtomicU64,
+    /// Orders accepted by the execution engine (including flatten orders)
+    orders_submitted: AtomicU64,
     /// Binance connection status (0 = disconnected, 1 = connected)
     binance_connected: AtomicU64,
     /// Bybit connection status (0 = disconnected, 1 = connected)
@@ -25,6 +907,25 @@ pub struct MetricsCollector {
     last_message_time: AtomicU64,
     /// Start time for uptime calculation
     start_time: Instant,
+    /// A/B race statistics across redundant feeds
+    feed_race: FeedRaceStats,
+    /// Queue/pool/buffer gauges for /api/debug
+    debug: DebugStats,
+    /// Supervised feed task status
+    tasks: FeedTaskStats,
+    /// Per-exchange reconnect circuit breakers of the feed supervisor
+    circuits: ReconnectCircuits,
+    /// Startup readiness stages
+    startup: StartupProgress,
+    /// Per-connection message counts, gaps and latency
+    connections: ConnectionStats,
+    /// Receive → processed by the engine (queue wait + tracker update)
+    processing: LatencyHistogram,
+    /// Order round trips per measured venue (published by the engine)
+    order_latency: RwLock<Vec<OrderLatencySnapshot>>,
+    /// Fill slippage statistics (published by the engine)
+    execution_quality: RwLock<ExecutionQualitySnapshot>,
+    pnl_attribution: RwLock<PnlAttributionSnapshot>,
 }
 
 /// Metrics snapshot for API export
@@ -33,8 +934,38 @@ pub struct MetricsSnapshot {
     pub binance_messages: u64,
     pub bybit_messages: u64,
     pub total_messages: u64,
+    pub duplicate_messages: u64,
+    pub suppressed_cooldown: u64,
+    pub suppressed_rate_limit: u64,
+    pub suppressed_unfocused: u64,
+    pub focused_symbols: u64,
+    pub suppressed_warmup: u64,
+    pub suppressed_paused: u64,
+    pub suppressed_disabled: u64,
+    pub suppressed_delisted: u64,
+    pub suppressed_halted: u64,
+    pub suppressed_peer: u64,
+    pub suppressed_standby: u64,
+    pub suppressed_private_stream: u64,
+    pub suppressed_risk: u64,
+    pub suppressed_read_only: u64,
+    pub read_only: bool,
+    pub warmup_active: bool,
+    pub warmup_remaining_secs: u64,
+    pub warm_symbols: u64,
+    pub quarantined_quotes: u64,
+    pub locked_books: u64,
+    pub crossed_books: u64,
+    pub exchange_errors: u64,
+    /// Engine loop iterations over the stall budget, and the longest (us)
+    pub engine_stalls: u64,
+    pub max_stall_us: u64,
+    pub orders_submitted: u64,
     pub binance_connected: bool,
     pub bybit_connected: bool,
+    /// Feed task restarts, and feeds the restart policy gave up on
+    pub task_restarts: u64,
+    pub failed_tasks: u64,
     pub message_rate: f64, // messages per second
     pub uptime_seconds: u64,
 }
@@ -46,10 +977,46 @@ impl MetricsCollector {
             binance_messages: AtomicU64::new(0),
             bybit_messages: AtomicU64::new(0),
             total_messages: AtomicU64::new(0),
+            duplicate_messages: AtomicU64::new(0),
+            suppressed_cooldown: AtomicU64::new(0),
+            suppressed_rate_limit: AtomicU64::new(0),
+            suppressed_unfocused: AtomicU64::new(0),
+            focused_symbols: AtomicU64::new(0),
+            suppressed_warmup: AtomicU64::new(0),
+            suppressed_paused: AtomicU64::new(0),
+            suppressed_disabled: AtomicU64::new(0),
+            suppressed_delisted: AtomicU64::new(0),
+            suppressed_halted: AtomicU64::new(0),
+            suppressed_peer: AtomicU64::new(0),
+            suppressed_standby: AtomicU64::new(0),
+            suppressed_private_stream: AtomicU64::new(0),
+            suppressed_risk: AtomicU64::new(0),
+            suppressed_read_only: AtomicU64::new(0),
+            read_only: AtomicU64::new(0),
+            warmup_active: AtomicU64::new(0),
+            warmup_remaining_secs: AtomicU64::new(0),
+            warm_symbols: AtomicU64::new(0),
+            quarantined_quotes: AtomicU64::new(0),
+            locked_books: AtomicU64::new(0),
+            crossed_books: AtomicU64::new(0),
+            exchange_errors: AtomicU64::new(0),
+            engine_stalls: AtomicU64::new(0),
+            max_stall_us: AtomicU64::new(0),
+            orders_submitted: AtomicU64::new(0),
             binance_connected: AtomicU64::new(0),
             bybit_connected: AtomicU64::new(0),
             last_message_time: AtomicU64::new(0),
             start_time: Instant::now(),
+            feed_race: FeedRaceStats::new(),
+            debug: DebugStats::new(),
+            tasks: FeedTaskStats::new(),
+            circuits: ReconnectCircuits::default(),
+            startup: StartupProgress::new(),
+            connections: ConnectionStats::new(),
+            processing: LatencyHistogram::new(),
+            order_latency: RwLock::new(Vec::new()),
+            execution_quality: RwLock::new(ExecutionQualitySnapshot::default()),
+            pnl_attribution: RwLock::new(PnlAttributionSnapshot::default()),
         }
     }
 
@@ -69,14 +1036,221 @@ impl MetricsCollector {
         self.update_last_message_time();
     }
 
+    /// Record a duplicate message dropped by deduplication
+    #[inline]
+    pub fn record_duplicate(&self) {
+        self.duplicate_messages.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed by symbol cooldown
+    #[inline]
+    pub fn record_suppressed_cooldown(&self) {
+        self.suppressed_cooldown.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed by trades-per-minute limit
+    #[inline]
+    pub fn record_suppressed_rate_limit(&self) {
+        self.suppressed_rate_limit.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed because the symbol is not focused
+    #[inline]
+    pub fn record_suppressed_unfocused(&self) {
+        self.suppressed_unfocused.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Set number of focused symbols
+    pub fn set_focused_symbols(&self, count: usize) {
+        self.focused_symbols.store(count as u64, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed during startup warm-up
+    #[inline]
+    pub fn record_suppressed_warmup(&self) {
+        self.suppressed_warmup.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed while the engine is paused
+    #[inline]
+    pub fn record_suppressed_paused(&self) {
+        self.suppressed_paused.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed because the symbol may not execute
+    #[inline]
+    pub fn record_suppressed_disabled(&self) {
+        self.suppressed_disabled.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order rejected for a delisting symbol
+    #[inline]
+    pub fn record_suppressed_delisted(&self) {
+        self.suppressed_delisted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed for a halted symbol
+    #[inline]
+    pub fn record_suppressed_halted(&self) {
+        self.suppressed_halted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed by leg ownership across instances
+    #[inline]
+    pub fn record_suppressed_peer(&self) {
+        self.suppressed_peer.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed by the failover standby state
+    #[inline]
+    pub fn record_suppressed_standby(&self) {
+        self.suppressed_standby.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order suppressed by a lost private stream
+    #[inline]
+    pub fn record_suppressed_private_stream(&self) {
+        self.suppressed_private_stream.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order rejected by its strategy's capital allocation
+    #[inline]
+    pub fn record_suppressed_risk(&self) {
+        self.suppressed_risk.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an order dropped in read-only mode
+    #[inline]
+    pub fn record_suppressed_read_only(&self) {
+        self.suppressed_read_only.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Set read-only mode (no execution engine)
+    pub fn set_read_only(&self, read_only: bool) {
+        self.read_only.store(read_only as u64, Ordering::Relaxed);
+    }
+
+    /// Set startup warm-up state
+    #[inline]
+    pub fn set_warmup(&self, active: bool, remaining_secs: u64, warm_symbols: usize) {
+        self.warmup_active.store(active as u64, Ordering::Relaxed);
+        self.warmup_remaining_secs.store(remaining_secs, Ordering::Relaxed);
+        self.warm_symbols.store(warm_symbols as u64, Ordering::Relaxed);
+    }
+
+    /// Record a quote quarantined by sanity filters
+    #[inline]
+    pub fn record_quarantined(&self) {
+        self.quarantined_quotes.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a locked (bid == ask) quote
+    #[inline]
+    pub fn record_locked_book(&self) {
+        self.locked_books.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record a crossed (bid > ask) quote
+    #[inline]
+    pub fn record_crossed_book(&self) {
+        self.crossed_books.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an error message from an exchange
+    #[inline]
+    pub fn record_exchange_error(&self) {
+        self.exchange_errors.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Record an engine loop iteration over the stall budget
+    #[inline]
+    pub fn record_engine_stall(&self, duration: Duration) {
+        self.engine_stalls.fetch_add(1, Ordering::Relaxed);
+        self.max_stall_us.fetch_max(duration.as_micros() as u64, Ordering::Relaxed);
+    }
+
+    /// Record an order accepted by the execution engine
+    #[inline]
+    pub fn record_order_submitted(&self) {
+        self.orders_submitted.fetch_add(1, Ordering::Relaxed);
+    }
+
+    /// Feed race statistics
+    #[inline(always)]
+    pub fn feed_race(&self) -> &FeedRaceStats {
+        &self.feed_race
+    }
+
+    /// Queue, pool and buffer gauges
+    #[inline(always)]
+    pub fn debug(&self) -> &DebugStats {
+        &self.debug
+    }
+
+    /// Supervised feed task status
+    #[inline(always)]
+    pub fn tasks(&self) -> &FeedTaskStats {
+        &self.tasks
+    }
+
+    /// Per-exchange reconnect circuit breakers
+    #[inline(always)]
+    pub fn circuits(&self) -> &ReconnectCircuits {
+        &self.circuits
+    }
+
+    /// Startup readiness stages
+    #[inline(always)]
+    pub fn startup(&self) -> &StartupProgress {
+        &self.startup
+    }
+
+    /// Per-connection delivery statistics
+    #[inline(always)]
+    pub fn connections(&self) -> &ConnectionStats {
+        &self.connections
+    }
+
+    /// Message processing latency
+    #[inline(always)]
+    pub fn processing(&self) -> &LatencyHistogram {
+        &self.processing
+    }
+
+    /// Replace the per-venue order round trips
+    pub fn set_order_latency(&self, venues: Vec<OrderLatencySnapshot>) {
+        *self.order_latency.write() = venues;
+    }
+
+    /// Order round trips per measured venue
+    pub fn order_latency(&self) -> Vec<OrderLatencySnapshot> {
+        self.order_latency.read().clone()
+    }
+
+    /// Replace the fill slippage statistics
+    pub fn set_execution_quality(&self, quality: ExecutionQualitySnapshot) {
+        *self.execution_quality.write() = quality;
+    }
+
+    /// Fill slippage per venue and day
+    pub fn execution_quality(&self) -> ExecutionQualitySnapshot {
+        self.execution_quality.read().clone()
+    }
+
+    /// Replace the PnL attribution report
+    pub fn set_pnl_attribution(&self, attribution: PnlAttributionSnapshot) {
+        *self.pnl_attribution.write() = attribution;
+    }
+
+    /// Realized PnL and fees per strategy, symbol and episode
+    pub fn pnl_attribution(&self) -> PnlAttributionSnapshot {
+        self.pnl_attribution.read().clone()
+    }
+
     /// Update last message timestamp
     #[inline]
     fn update_last_message_time(&self) {
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
-        self.last_message_time.store(now, Ordering::Relaxed);
+        self.last_message_time.store(clock::coarse_unix_ms(), Ordering::Relaxed);
     }
 
     /// Set Binance connection status
@@ -108,8 +1282,36 @@ impl MetricsCollector {
             binance_messages: binance_msgs,
             bybit_messages: bybit_msgs,
             total_messages: total,
+            duplicate_messages: self.duplicate_messages.load(Ordering::Relaxed),
+            suppressed_cooldown: self.suppressed_cooldown.load(Ordering::Relaxed),
+            suppressed_rate_limit: self.suppressed_rate_limit.load(Ordering::Relaxed),
+            suppressed_unfocused: self.suppressed_unfocused.load(Ordering::Relaxed),
+            focused_symbols: self.focused_symbols.load(Ordering::Relaxed),
+            suppressed_warmup: self.suppressed_warmup.load(Ordering::Relaxed),
+            suppressed_paused: self.suppressed_paused.load(Ordering::Relaxed),
+            suppressed_disabled: self.suppressed_disabled.load(Ordering::Relaxed),
+            suppressed_delisted: self.suppressed_delisted.load(Ordering::Relaxed),
+            suppressed_halted: self.suppressed_halted.load(Ordering::Relaxed),
+            suppressed_peer: self.suppressed_peer.load(Ordering::Relaxed),
+            suppressed_standby: self.suppressed_standby.load(Ordering::Relaxed),
+            suppressed_private_stream: self.suppressed_private_stream.load(Ordering::Relaxed),
+            suppressed_risk: self.suppressed_risk.load(Ordering::Relaxed),
+            suppressed_read_only: self.suppressed_read_only.load(Ordering::Relaxed),
+            read_only: self.read_only.load(Ordering::Relaxed) == 1,
+            warmup_active: self.warmup_active.load(Ordering::Relaxed) != 0,
+            warmup_remaining_secs: self.warmup_remaining_secs.load(Ordering::Relaxed),
+            warm_symbols: self.warm_symbols.load(Ordering::Relaxed),
+            quarantined_quotes: self.quarantined_quotes.load(Ordering::Relaxed),
+            locked_books: self.locked_books.load(Ordering::Relaxed),
+            crossed_books: self.crossed_books.load(Ordering::Relaxed),
+            exchange_errors: self.exchange_errors.load(Ordering::Relaxed),
+            engine_stalls: self.engine_stalls.load(Ordering::Relaxed),
+            max_stall_us: self.max_stall_us.load(Ordering::Relaxed),
+            orders_submitted: self.orders_submitted.load(Ordering::Relaxed),
             binance_connected: self.binance_connected.load(Ordering::Relaxed) != 0,
             bybit_connected: self.bybit_connected.load(Ordering::Relaxed) != 0,
+            task_restarts: self.tasks.restarts(),
+            failed_tasks: self.tasks.failed(),
             message_rate: rate,
             uptime_seconds: uptime,
         }
@@ -129,12 +1331,7 @@ impl MetricsCollector {
             return 10000; // No messages yet
         }
 
-        let now = SystemTime::now()
-            .duration_since(SystemTime::UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_millis() as u64;
-
-        now.saturating_sub(last).min(10000)
+        clock::coarse_unix_ms().saturating_sub(last).min(10000)
     }
 }
 
@@ -168,7 +1365,10 @@ mod tests {
         collector.record_binance_message();
         collector.record_bybit_message();
 
+        collector.record_duplicate();
+
         let snapshot = collector.snapshot();
+        assert_eq!(snapshot.duplicate_messages, 1);
         assert_eq!(snapshot.binance_messages, 2);
         assert_eq!(snapshot.bybit_messages, 1);
         assert_eq!(snapshot.total_messages, 3);
@@ -187,6 +1387,45 @@ mod tests {
         assert!(collector.is_connected());
     }
 
+    #[test]
+    fn test_connection_stats() {
+        let stats = ConnectionStats::new();
+        let t0 = Instant::now();
+        stats.record(1, t0, Some(Duration::from_millis(4)));
+        stats.record(1, t0 + Duration::from_millis(250), None);
+        stats.record(1, t0 + Duration::from_millis(300), Some(Duration::from_millis(8)));
+        stats.record(MAX_FEEDS, t0, None);
+        stats.record_conflated(1);
+        stats.record_dropped(1);
+        stats.record_dropped(MAX_FEEDS);
+
+        let snapshot = stats.snapshot(&["binance#0".to_string()]);
+        assert_eq!(snapshot.len(), 2);
+        assert_eq!((snapshot[0].label.as_str(), snapshot[0].messages), ("binance#0", 0));
+        let conn = &snapshot[1];
+        assert_eq!((conn.connection, conn.label.as_str(), conn.messages), (1, "feed#1", 3));
+        assert_eq!(conn.max_gap_ms, 250.0);
+        assert_eq!(conn.mean_latency_ms, 6.0);
+        assert_eq!(conn.max_latency_ms, 8.0);
+        assert_eq!((conn.conflated, conn.dropped), (1, 1));
+    }
+
+    #[test]
+    fn test_latency_histogram() {
+        let hist = LatencyHistogram::new();
+        assert_eq!(hist.percentile(0.99), None);
+
+        // 98 fast samples (~1 us), 2 slow (~1 ms)
+        for _ in 0..98 {
+            hist.record(Duration::from_nanos(900));
+        }
+        hist.record(Duration::from_micros(900));
+        hist.record(Duration::from_micros(900));
+        assert_eq!(hist.count(), 100);
+        assert_eq!(hist.percentile(0.5), Some(Duration::from_nanos(1024)));
+        assert_eq!(hist.percentile(0.99), Some(Duration::from_nanos(1 << 20)));
+    }
+
     #[test]
     fn test_latency_no_messages() {
         let collector = MetricsCollector::new();
@@ -207,4 +1446,67 @@ mod tests {
         assert!(snapshot.message_rate >= 0.0);
         assert_eq!(snapshot.total_messages, 100);
     }
+
+    #[test]
+    fn test_feed_race_stats() {
+        let race = FeedRaceStats::new();
+        race.set_labels(vec!["binance#0".to_string(), "binance#1".to_string()]);
+
+        race.record(0, 1, Duration::from_micros(50));
+        race.record(0, 1, Duration::from_micros(2_000));
+        race.record(1, 0, Duration::from_micros(700));
+        race.record(1, 1, Duration::from_micros(10)); // not a race
+
+        let snap = race.snapshot();
+        assert_eq!(snap.len(), 2);
+        assert_eq!(snap[0].label, "binance#0");
+        assert_eq!(snap[0].wins, 2);
+        assert_eq!(snap[0].losses, 1);
+        assert!((snap[0].win_rate - 2.0 / 3.0).abs() < 1e-9);
+        assert_eq!(snap[0].lag_histogram[2], 1); // 500..1000us
+
+        assert_eq!(snap[1].losses, 2);
+        assert!((snap[1].mean_lag_us - 1_025.0).abs() < 1e-9);
+        assert_eq!(snap[1].lag_histogram[0], 1); // <100us
+        assert_eq!(snap[1].lag_histogram[3], 1); // 1..5ms
+    }
+
+    #[test]
+    fn test_debug_stats() {
+        use crate::infrastructure::pool::ObjectPool;
+
+        let debug = DebugStats::new();
+        debug.set_queue_capacity(1024);
+        debug.record_queue_depth(10);
+        debug.record_queue_depth(3);
+        let queues = debug.queues();
+        assert_eq!(queues[0].depth, 3);
+        assert_eq!(queues[0].high_water, 10);
+        assert_eq!(queues[0].capacity, 1024);
+
+        let pool = Arc::new(ObjectPool::with_capacity(8, || 0u64));
+        let _taken = pool.acquire();
+        debug.register_pool("test", pool);
+        let pools = debug.pools();
+        assert_eq!(pools[0].available, 7);
+        assert_eq!(pools[0].capacity, 8);
+
+        debug.record_buffers(1, 65_536, 900);
+        debug.record_buffers(MAX_FEEDS, 1, 1); // out of range, ignored
+        let conns = debug.connections(&["bybit#0".to_string()]);
+        assert_eq!(conns.len(), 2);
+        assert_eq!(conns[0].label, "bybit#0");
+        assert_eq!(conns[1].label, "feed#1");
+        assert_eq!(conns[1].max_frame_bytes, 900);
+
+        let mut lanes = [LaneStats::default(); Lane::COUNT];
+        lanes[Lane::Bulk.index()] = LaneStats {
+            frames: 4,
+            total_delay_ns: 8_000,
+            max_delay_ns: 5_000,
+        };
+        debug.record_send_lanes(1, &lanes);
+        let bulk = &debug.connections(&[])[0].send_lanes[Lane::Bulk.index()];
+        assert_eq!((bulk.lane, bulk.frames, bulk.mean_delay_us, bulk.max_delay_us), ("bulk", 4, 2.0, 5.0));
+    }
 }
@@ -0,0 +1,273 @@
+//! In-process metrics history (Cold Path)
+//!
+//! [`MetricsSnapshot`](super::metrics::MetricsSnapshot) is point-in-time
+//! only. The history samples the collector once a second and keeps two
+//! rings: 1-second points for the last hour and 1-minute points for the
+//! last day. Counters are stored as per-interval rates and processing
+//! latency as percentiles of the interval alone (histogram deltas), so a
+//! minute point is computed from the whole minute, not averaged from its
+//! seconds.
+
+use parking_lot::Mutex;
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+use crate::core::clock;
+use crate::infrastructure::metrics::{histogram_percentile, MetricsCollector, LATENCY_BUCKET_COUNT};
+
+/// 1-second points kept (1 hour)
+const SECONDS_KEPT: usize = 3_600;
+
+/// 1-minute points kept (24 hours)
+const MINUTES_KEPT: usize = 1_440;
+
+const MINUTE_MS: u64 = 60_000;
+
+/// History resolution
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+pub enum Resolution {
+    #[default]
+    #[serde(rename = "1s")]
+    Second,
+    #[serde(rename = "1m")]
+    Minute,
+}
+
+/// Metrics over one interval
+#[derive(Debug, Clone, Copy, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsPoint {
+    /// Interval end (1s) or start (1m), ms since epoch
+    pub timestamp_ms: u64,
+    /// Messages per second
+    pub message_rate: f64,
+    pub binance_rate: f64,
+    pub bybit_rate: f64,
+    pub duplicate_rate: f64,
+    /// Receive → processed percentiles of the interval (None = no messages)
+    pub processing_p50_us: Option<f64>,
+    pub processing_p99_us: Option<f64>,
+    /// Counts during the interval
+    pub orders: u64,
+    pub exchange_errors: u64,
+    pub quarantined: u64,
+}
+
+/// Cumulative counters read from the collector
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Counters {
+    binance: u64,
+    bybit: u64,
+    duplicates: u64,
+    orders: u64,
+    errors: u64,
+    quarantined: u64,
+    processing: [u64; LATENCY_BUCKET_COUNT],
+}
+
+impl Default for Counters {
+    fn default() -> Self {
+        Self {
+            binance: 0,
+            bybit: 0,
+            duplicates: 0,
+            orders: 0,
+            errors: 0,
+            quarantined: 0,
+            processing: [0; LATENCY_BUCKET_COUNT],
+        }
+    }
+}
+
+impl Counters {
+    fn read(metrics: &MetricsCollector) -> Self {
+        let snapshot = metrics.snapshot();
+        Self {
+            binance: snapshot.binance_messages,
+            bybit: snapshot.bybit_messages,
+            duplicates: snapshot.duplicate_messages,
+            orders: snapshot.orders_submitted,
+            errors: snapshot.exchange_errors,
+            quarantined: snapshot.quarantined_quotes,
+            processing: metrics.processing().counts(),
+        }
+    }
+
+    /// Growth since `earlier` (saturating: counters never go back)
+    fn since(&self, earlier: &Self) -> Self {
+        Self {
+            binance: self.binance.saturating_sub(earlier.binance),
+            bybit: self.bybit.saturating_sub(earlier.bybit),
+            duplicates: self.duplicates.saturating_sub(earlier.duplicates),
+            orders: self.orders.saturating_sub(earlier.orders),
+            errors: self.errors.saturating_sub(earlier.errors),
+            quarantined: self.quarantined.saturating_sub(earlier.quarantined),
+            processing: std::array::from_fn(|i| self.processing[i].saturating_sub(earlier.processing[i])),
+        }
+    }
+
+    fn add(&mut self, delta: &Self) {
+        self.binance += delta.binance;
+        self.bybit += delta.bybit;
+        self.duplicates += delta.duplicates;
+        self.orders += delta.orders;
+        self.errors += delta.errors;
+        self.quarantined += delta.quarantined;
+        for (total, add) in self.processing.iter_mut().zip(&delta.processing) {
+            *total += add;
+        }
+    }
+
+    fn point(&self, timestamp_ms: u64, elapsed: Duration) -> MetricsPoint {
+        let secs = elapsed.as_secs_f64().max(1e-3);
+        let us = |q| histogram_percentile(&self.processing, q).map(|d| d.as_secs_f64() * 1e6);
+        MetricsPoint {
+            timestamp_ms,
+            message_rate: (self.binance + self.bybit) as f64 / secs,
+            binance_rate: self.binance as f64 / secs,
+            bybit_rate: self.bybit as f64 / secs,
+            duplicate_rate: self.duplicates as f64 / secs,
+            processing_p50_us: us(0.50),
+            processing_p99_us: us(0.99),
+            orders: self.orders,
+            exchange_errors: self.errors,
+            quarantined: self.quarantined,
+        }
+    }
+}
+
+/// Downsampled metric rings
+#[derive(Debug, Default)]
+pub struct MetricsHistory {
+    /// Previous sample (None before the first)
+    last: Option<(Instant, Counters)>,
+    seconds: VecDeque<MetricsPoint>,
+    minutes: VecDeque<MetricsPoint>,
+    /// Minute being accumulated: (start ms, growth, elapsed)
+    minute: Option<(u64, Counters, Duration)>,
+}
+
+impl MetricsHistory {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sample `metrics` at `now` (wall clock `now_ms`)
+    pub fn sample(&mut self, metrics: &MetricsCollector, now: Instant, now_ms: u64) {
+        self.record(Counters::read(metrics), now, now_ms);
+    }
+
+    fn record(&mut self, counters: Counters, now: Instant, now_ms: u64) {
+        let Some((then, previous)) = self.last.replace((now, counters)) else {
+            return;
+        };
+        let elapsed = now.saturating_duration_since(then);
+        let delta = counters.since(&previous);
+        push_bounded(&mut self.seconds, delta.point(now_ms, elapsed), SECONDS_KEPT);
+
+        let minute_start = now_ms / MINUTE_MS * MINUTE_MS;
+        match &mut self.minute {
+            Some((start, growth, span)) if *start == minute_start => {
+                growth.add(&delta);
+                *span += elapsed;
+            }
+            finished => {
+                if let Some((start, growth, span)) = finished.take() {
+                    push_bounded(&mut self.minutes, growth.point(start, span), MINUTES_KEPT);
+                }
+                *finished = Some((minute_start, delta, elapsed));
+            }
+        }
+    }
+
+    /// Points at `resolution`, oldest first, from `since_ms` on
+    ///
+    /// The 1-minute series ends with the minute in progress.
+    pub fn points(&self, resolution: Resolution, since_ms: u64) -> Vec<MetricsPoint> {
+        match resolution {
+            Resolution::Second => self.seconds.iter().filter(|p| p.timestamp_ms >= since_ms).copied().collect(),
+            Resolution::Minute => self
+                .minutes
+                .iter()
+                .copied()
+                .chain(self.minute.map(|(start, growth, span)| growth.point(start, span)))
+                .filter(|p| p.timestamp_ms >= since_ms)
+                .collect(),
+        }
+    }
+}
+
+fn push_bounded(ring: &mut VecDeque<MetricsPoint>, point: MetricsPoint, capacity: usize) {
+    if ring.len() == capacity {
+        ring.pop_front();
+    }
+    ring.push_back(point);
+}
+
+/// Sample `metrics` into `history` every `interval`
+pub async fn run(history: Arc<Mutex<MetricsHistory>>, metrics: Arc<MetricsCollector>, interval: Duration) {
+    let mut ticker = tokio::time::interval(interval);
+    ticker.set_missed_tick_behavior(tokio::time::MissedTickBehavior::Delay);
+    loop {
+        ticker.tick().await;
+        history.lock().sample(&metrics, Instant::now(), clock::coarse_unix_ms());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const T0: u64 = 1_700_000_040_000;
+
+    fn counters(binance: u64, orders: u64, slow: u64) -> Counters {
+        let mut counters = Counters { binance, bybit: binance / 2, orders, ..Default::default() };
+        // Fast messages in the ~1 µs bucket, slow ones in the ~1 ms bucket
+        counters.processing[10] = binance;
+        counters.processing[20] = slow;
+        counters
+    }
+
+    #[test]
+    fn test_second_points_are_interval_rates() {
+        let mut history = MetricsHistory::new();
+        let start = Instant::now();
+        history.record(counters(100, 0, 0), start, T0);
+        assert!(history.points(Resolution::Second, 0).is_empty());
+
+        history.record(counters(300, 2, 0), start + Duration::from_secs(1), T0 + 1_000);
+        history.record(counters(300, 2, 50), start + Duration::from_secs(2), T0 + 2_000);
+        let points = history.points(Resolution::Second, 0);
+        assert_eq!(points.len(), 2);
+        assert_eq!((points[0].binance_rate, points[0].message_rate, points[0].orders), (200.0, 300.0, 2));
+        assert_eq!(points[0].processing_p99_us, Some(1024.0 / 1000.0));
+        // Only the interval's own samples count: all slow
+        assert_eq!((points[1].message_rate, points[1].orders), (0.0, 0));
+        assert!(points[1].processing_p50_us.unwrap() > 1_000.0);
+        assert_eq!(history.points(Resolution::Second, T0 + 1_500).len(), 1);
+    }
+
+    #[test]
+    fn test_minutes_downsample_and_rings_bound() {
+        let mut history = MetricsHistory::new();
+        let start = Instant::now();
+        history.record(Counters::default(), start, T0);
+        // Two and a half minutes at 60 messages/s on Binance
+        for s in 1..=150u64 {
+            history.record(counters(60 * s, s / 30, 0), start + Duration::from_secs(s), T0 + s * 1_000);
+        }
+        let minutes = history.points(Resolution::Minute, 0);
+        assert_eq!(minutes.iter().map(|p| p.timestamp_ms).collect::<Vec<_>>(), vec![T0, T0 + MINUTE_MS, T0 + 2 * MINUTE_MS]);
+        assert!((minutes[0].binance_rate - 60.0).abs() < 1e-9);
+        assert_eq!(minutes.iter().map(|p| p.orders).sum::<u64>(), 5);
+        // The minute in progress covers its first 30 seconds
+        assert_eq!(minutes[2].timestamp_ms, T0 + 120_000);
+
+        for s in 151..=(SECONDS_KEPT as u64 + 200) {
+            history.record(Counters::default(), start + Duration::from_secs(s), T0 + s * 1_000);
+        }
+        assert_eq!(history.points(Resolution::Second, 0).len(), SECONDS_KEPT);
+    }
+}
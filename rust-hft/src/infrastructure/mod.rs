@@ -4,19 +4,60 @@
 //! - Logging and metrics
 //! - Configuration management
 //! - Health monitoring
+//! - Historical opportunity database and spread export
+//! - Opportunity webhooks
+//! - Alert rate limiting
+//! - Peer link between regional instances
+//! - Hot-standby replication link
+//! - Shared-secret authentication of instance links
+//! - Cross-venue symbol presence
+//! - Cold-start symbol priority
+//! - Startup readiness stages
 //! - Graceful shutdown
+//! - systemd watchdog
 
+pub mod alert_limit;
+#[cfg(feature = "alloc-tracking")]
+pub mod alloc_tracking;
 pub mod config;
+#[cfg(feature = "embed-frontend")]
+pub mod embedded;
+pub mod export;
 pub mod health;
+pub mod history;
+pub mod json_writer;
+pub mod link_auth;
 pub mod logging;
+pub mod market_snapshot;
 pub mod metrics;
+pub mod metrics_history;
+pub mod openapi;
+pub mod peer;
 pub mod pool;
+pub mod presence;
 pub mod ring_buffer;
+pub mod screener_cache;
+pub mod snapshot_diff;
+pub mod standby;
+pub mod startup;
+pub mod symbol_priority;
 pub mod time_window_buffer;
+pub mod unparsed;
+pub mod watchdog;
+pub mod webhook;
 pub mod api;
 
-pub use pool::{ObjectPool, ByteBufferPool, MessageBufferPool};
+pub use pool::{ObjectPool, ByteBufferPool, MessageBufferPool, PoolOccupancy};
+pub use json_writer::{JsonBufferPool, WriteJson};
+pub use market_snapshot::{MarketSnapshot, MarketSnapshotter};
+pub use metrics_history::MetricsHistory;
+pub use presence::{SubscriptionState, SymbolPresence};
 pub use ring_buffer::RingBuffer;
+pub use screener_cache::ScreenerCache;
+pub use snapshot_diff::{ScreenerUpdate, SnapshotDiffer};
+pub use startup::{StartupProgress, StartupStage};
+pub use symbol_priority::SymbolPriority;
 pub use time_window_buffer::TimeWindowBuffer;
+pub use unparsed::{ParseQuarantine, UnparsedSnapshot};
 pub use api::start_server;
 pub use logging::init_logging;
@@ -0,0 +1,927 @@
+//! OpenAPI description of the HTTP API (Cold Path)
+//!
+//! The document is assembled from a route table and schema declarations
+//! mirroring the API DTOs, served as `/api/openapi.json` with a Swagger UI
+//! page at `/api/docs`. Adding an endpoint means adding its row to
+//! `OPERATIONS` and declaring any new request or response type with
+//! `api_schema!`.
+
+use serde_json::{json, Map, Value};
+
+use crate::engine::SizeConstraint;
+use crate::hot_path::invariants::{InvariantCount, InvariantSnapshot};
+use crate::hot_path::ThresholdSource;
+use crate::infrastructure::api::{
+    BookDto, BookLevelDto, DashboardDto, DebugDto, ExecutionAttemptDto, HealthDto, FlattenDto, FlattenLegDto, HeatmapDto, LeadLagDto, LegTimingDto, ManualOrderBody,
+    ManualOrderDto, MarketSnapshotDto, OpportunityDto, PauseDto, RebalanceDto,
+    ScreenerDto, SessionBucketDto, SnapshotPairDto, SnapshotQuoteDto, SessionStatsDto, SymbolExecutionDto, SymbolPresenceDto, SystemStatusDto, TrackerOccupancyDto,
+    VenueBookDto, VenuePresenceDto, WarmupDto,
+};
+use crate::infrastructure::health::ExchangeHealth;
+use crate::infrastructure::history::{EpisodeRow, ExecutionRow};
+use crate::infrastructure::metrics::{
+    ConnectionBufferSnapshot, ConnectionSnapshot, EpisodePnlSnapshot, ExecutionQualitySnapshot, FeedRaceSnapshot, LegSlippageSnapshot, OrderLatencySnapshot,
+    PnlAttributionSnapshot, PnlBucketSnapshot, PoolSnapshot, QueueSnapshot,
+    RecorderSnapshot, SendLaneSnapshot, ServerPingSnapshot, TaskSnapshot, TaskState, VenueQualitySnapshot,
+};
+use crate::infrastructure::metrics_history::MetricsPoint;
+use crate::infrastructure::presence::SubscriptionState;
+use crate::infrastructure::startup::{StageSnapshot, StartupSnapshot, StartupStage};
+use crate::infrastructure::unparsed::{UnparsedCount, UnparsedSample, UnparsedSnapshot};
+use crate::ws::{BreakerStatus, CircuitState};
+
+/// JSON schema of a type as it appears in API responses
+pub trait ApiSchema {
+    /// Inline schema, or a `$ref` for named components
+    fn schema() -> Value;
+
+    /// Named component definition (None for primitives and containers)
+    fn component() -> Option<(&'static str, Value)> {
+        None
+    }
+}
+
+macro_rules! primitive_schema {
+    ($($ty:ty => $schema:tt),* $(,)?) => {
+        $(impl ApiSchema for $ty {
+            fn schema() -> Value {
+                json!($schema)
+            }
+        })*
+    };
+}
+
+primitive_schema! {
+    bool => { "type": "boolean" },
+    u32 => { "type": "integer", "format": "int32", "minimum": 0 },
+    u64 => { "type": "integer", "format": "int64", "minimum": 0 },
+    usize => { "type": "integer", "format": "int64", "minimum": 0 },
+    f64 => { "type": "number", "format": "double" },
+    String => { "type": "string" },
+    &'static str => { "type": "string" },
+}
+
+impl<T: ApiSchema> ApiSchema for Option<T> {
+    fn schema() -> Value {
+        match T::schema() {
+            // OpenAPI 3.0 cannot mark a bare $ref nullable
+            reference @ Value::Object(_) if reference.get("$ref").is_some() => {
+                json!({ "allOf": [reference], "nullable": true })
+            }
+            Value::Object(mut schema) => {
+                schema.insert("nullable".to_string(), Value::Bool(true));
+                Value::Object(schema)
+            }
+            other => other,
+        }
+    }
+}
+
+impl<T: ApiSchema> ApiSchema for Vec<T> {
+    fn schema() -> Value {
+        json!({ "type": "array", "items": T::schema() })
+    }
+}
+
+fn reference(name: &str) -> Value {
+    json!({ "$ref": format!("#/components/schemas/{}", name) })
+}
+
+/// snake_case field → camelCase JSON key (`#[serde(rename_all = "camelCase")]`)
+fn camel_case(field: &str) -> String {
+    let mut out = String::with_capacity(field.len());
+    let mut upper = false;
+    for c in field.chars() {
+        if c == '_' {
+            upper = true;
+        } else if upper {
+            out.push(c.to_ascii_uppercase());
+            upper = false;
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Object component with every field required (Option fields are nullable)
+macro_rules! api_schema {
+    ($name:ident { $($field:ident: $ty:ty),* $(,)? }) => {
+        impl ApiSchema for $name {
+            fn schema() -> Value {
+                reference(stringify!($name))
+            }
+
+            fn component() -> Option<(&'static str, Value)> {
+                let mut properties = Map::new();
+                $(properties.insert(camel_case(stringify!($field)), <$ty as ApiSchema>::schema());)*
+                let required: Vec<Value> = properties.keys().cloned().map(Value::String).collect();
+                Some((stringify!($name), json!({ "type": "object", "properties": properties, "required": required })))
+            }
+        }
+    };
+    ($name:ident [$($variant:literal),* $(,)?]) => {
+        impl ApiSchema for $name {
+            fn schema() -> Value {
+                reference(stringify!($name))
+            }
+
+            fn component() -> Option<(&'static str, Value)> {
+                Some((stringify!($name), json!({ "type": "string", "enum": [$($variant),*] })))
+            }
+        }
+    };
+}
+
+api_schema!(ThresholdSource ["static", "calibrated", "override"]);
+api_schema!(SizeConstraint ["liquidity", "balance", "maxNotional", "volatility", "minNotional", "fillProbability"]);
+api_schema!(SubscriptionState ["unsubscribed", "subscribed", "focused", "delisted"]);
+api_schema!(TaskState ["starting", "running", "restarting", "failed", "stopped"]);
+api_schema!(CircuitState ["closed", "open", "halfOpen"]);
+api_schema!(StartupStage ["config", "registry", "discovery", "connections", "subscriptions", "warmup", "ready"]);
+
+api_schema!(SystemStatusDto {
+    is_connected: bool,
+    latency_ms: u64,
+    active_symbols: usize,
+    binance_connected: bool,
+    bybit_connected: bool,
+    suppressed_cooldown: u64,
+    suppressed_rate_limit: u64,
+    suppressed_unfocused: u64,
+    suppressed_warmup: u64,
+    suppressed_paused: u64,
+    suppressed_disabled: u64,
+    suppressed_delisted: u64,
+    suppressed_halted: u64,
+    suppressed_peer: u64,
+    suppressed_standby: u64,
+    suppressed_private_stream: u64,
+    suppressed_risk: u64,
+    suppressed_read_only: u64,
+    quarantined_quotes: u64,
+    locked_books: u64,
+    crossed_books: u64,
+    focused_symbols: u64,
+    task_restarts: u64,
+    failed_tasks: u64,
+    engine_stalls: u64,
+});
+api_schema!(ScreenerDto {
+    symbol: String,
+    current_spread: f64,
+    spread_ticks: Option<f64>,
+    break_even: f64,
+    edge_after_costs: f64,
+    spread_range: f64,
+    spread_p1: Option<f64>,
+    spread_p99: Option<f64>,
+    robust_range: Option<f64>,
+    hits: u64,
+    threshold: f64,
+    threshold_source: ThresholdSource,
+    est_half_life: f64,
+    liquidation_volume: f64,
+    price_volatility: f64,
+    stale_venue: Option<String>,
+    suspended_venue: Option<String>,
+    suspended_status: Option<String>,
+    is_spread_na: bool,
+});
+api_schema!(DashboardDto { system: SystemStatusDto, screener: Vec<ScreenerDto> });
+api_schema!(WarmupDto { active: bool, remaining_secs: u64, warm_symbols: u64 });
+api_schema!(PauseDto { paused: bool, tracker_paused: bool, paused_for_secs: Option<u64> });
+api_schema!(TaskSnapshot { feed: usize, label: String, state: TaskState, restarts: u64, last_error: Option<String> });
+api_schema!(BreakerStatus { state: CircuitState, consecutive_failures: u32, times_opened: u64, retry_in_ms: u64 });
+api_schema!(ExchangeHealth { exchange: &'static str, circuit: BreakerStatus });
+api_schema!(StageSnapshot { stage: StartupStage, completed_ms: Option<u64> });
+api_schema!(StartupSnapshot { stage: StartupStage, ready: bool, stages: Vec<StageSnapshot> });
+api_schema!(HealthDto {
+    ready: bool,
+    startup: StartupSnapshot,
+    uptime_seconds: u64,
+    binance_connected: bool,
+    bybit_connected: bool,
+    warmup: WarmupDto,
+    pause: PauseDto,
+    read_only: bool,
+    tasks: Vec<TaskSnapshot>,
+    circuits: Vec<ExchangeHealth>,
+});
+api_schema!(OpportunityDto {
+    symbol: String,
+    spread: f64,
+    long_exchange: String,
+    short_exchange: String,
+    timestamp: u64,
+    quantity: f64,
+    constraint: SizeConstraint,
+    liquidity_cap: f64,
+    balance_cap: f64,
+    notional_cap: f64,
+    volatility_cap: f64,
+    fill_probability: f64,
+    net_edge: Option<f64>,
+    passive_net_edge: Option<f64>,
+    passive_exchange: Option<String>,
+    passive_price: Option<f64>,
+});
+api_schema!(HeatmapDto { start_ms: u64, bucket_ms: u64, symbols: Vec<String>, counts: Vec<Vec<u32>> });
+api_schema!(LegTimingDto { exchange: &'static str, side: &'static str, sent_ms: f64, acked_ms: Option<f64> });
+api_schema!(ExecutionAttemptDto {
+    symbol: String,
+    timestamp: u64,
+    outcome: &'static str,
+    elapsed_ms: f64,
+    legs: Vec<LegTimingDto>,
+});
+api_schema!(SessionBucketDto { opportunities: u64, samples: u64, avg_net_spread: f64 });
+api_schema!(SessionStatsDto { symbol: String, hours: Vec<SessionBucketDto>, weekdays: Vec<SessionBucketDto> });
+api_schema!(LeadLagDto {
+    symbol: String,
+    leader: Option<String>,
+    lag_ms: u64,
+    correlation: f64,
+    contemporaneous: f64,
+    active_bins: f64,
+});
+api_schema!(RebalanceDto {
+    symbol: String,
+    buy_exchange: String,
+    sell_exchange: String,
+    spread: f64,
+    trading_fees: f64,
+    transfer_cost: f64,
+    net_edge: f64,
+    transfer_secs: u64,
+});
+api_schema!(VenuePresenceDto {
+    exchange: &'static str,
+    listed: bool,
+    last_update_age_ms: Option<u64>,
+    locked_books: u64,
+    crossed_books: u64,
+});
+api_schema!(SymbolPresenceDto {
+    id: u32,
+    symbol: String,
+    subscription: SubscriptionState,
+    execution_enabled: bool,
+    venues: Vec<VenuePresenceDto>,
+});
+api_schema!(SymbolExecutionDto { symbol: String, execution_enabled: bool });
+api_schema!(ManualOrderBody {
+    venue: String,
+    symbol: String,
+    side: String,
+    order_type: String,
+    price: Option<f64>,
+    qty: f64,
+});
+api_schema!(FlattenLegDto { exchange: &'static str, symbol: String, initial_qty: f64, remaining_qty: f64, confirmed: bool, orders: u32 });
+api_schema!(FlattenDto { target: &'static str, status: &'static str, attempts: u32, elapsed_ms: f64, legs: Vec<FlattenLegDto> });
+api_schema!(ManualOrderDto { status: &'static str, reason: Option<&'static str> });
+api_schema!(BookLevelDto { price: f64, qty: f64, cum_notional: f64 });
+api_schema!(VenueBookDto { exchange: &'static str, bids: Vec<BookLevelDto>, asks: Vec<BookLevelDto> });
+api_schema!(BookDto { symbol: String, venues: Vec<VenueBookDto> });
+api_schema!(FeedRaceSnapshot {
+    feed: usize,
+    label: String,
+    wins: u64,
+    losses: u64,
+    win_rate: f64,
+    mean_lag_us: f64,
+    lag_histogram: Vec<u64>,
+});
+api_schema!(ConnectionSnapshot {
+    connection: usize,
+    label: String,
+    messages: u64,
+    max_gap_ms: f64,
+    mean_latency_ms: f64,
+    max_latency_ms: f64,
+    conflated: u64,
+    dropped: u64,
+});
+api_schema!(EpisodeRow {
+    id: u64,
+    symbol: String,
+    long_exchange: String,
+    short_exchange: String,
+    start_ms: u64,
+    end_ms: u64,
+    duration_ms: u64,
+    peak_spread: f64,
+    updates: u64,
+    aborted: bool,
+});
+api_schema!(ExecutionRow {
+    exchange: String,
+    symbol: String,
+    side: String,
+    price: f64,
+    quantity: f64,
+    timestamp_ms: u64,
+    strategy: Option<String>,
+    episode_id: Option<u64>,
+});
+api_schema!(PoolSnapshot { name: &'static str, available: usize, capacity: usize });
+api_schema!(MetricsPoint {
+    timestamp_ms: u64,
+    message_rate: f64,
+    binance_rate: f64,
+    bybit_rate: f64,
+    duplicate_rate: f64,
+    processing_p50_us: Option<f64>,
+    processing_p99_us: Option<f64>,
+    orders: u64,
+    exchange_errors: u64,
+    quarantined: u64,
+});
+api_schema!(SnapshotQuoteDto {
+    exchange: &'static str,
+    bid: f64,
+    bid_qty: f64,
+    ask: f64,
+    ask_qty: f64,
+    exchange_ms: u64,
+    age_ms: f64,
+});
+api_schema!(SnapshotPairDto { symbol: String, quotes: Vec<SnapshotQuoteDto> });
+api_schema!(MarketSnapshotDto { tick: u64, taken_ms: u64, pairs: Vec<SnapshotPairDto> });
+api_schema!(VenueQualitySnapshot {
+    date: String,
+    exchange: &'static str,
+    fills: u64,
+    notional: f64,
+    avg_vs_mid_bps: f64,
+    avg_vs_touch_bps: f64,
+    avg_vs_submitted_bps: Option<f64>,
+    worst_vs_mid_bps: f64,
+});
+api_schema!(LegSlippageSnapshot {
+    timestamp_ms: u64,
+    exchange: &'static str,
+    symbol: String,
+    side: &'static str,
+    decision_mid: f64,
+    decision_touch: f64,
+    submitted_price: Option<f64>,
+    fill_price: f64,
+    quantity: f64,
+    vs_mid_bps: f64,
+    vs_touch_bps: f64,
+    vs_submitted_bps: Option<f64>,
+});
+api_schema!(ExecutionQualitySnapshot { days: Vec<VenueQualitySnapshot>, recent: Vec<LegSlippageSnapshot> });
+api_schema!(PnlBucketSnapshot { name: String, fills: u64, notional: f64, realized_pnl: f64, fees: f64, net_pnl: f64 });
+api_schema!(EpisodePnlSnapshot {
+    id: u64,
+    symbol: String,
+    fills: u64,
+    notional: f64,
+    realized_pnl: f64,
+    fees: f64,
+    net_pnl: f64,
+});
+api_schema!(PnlAttributionSnapshot {
+    strategies: Vec<PnlBucketSnapshot>,
+    symbols: Vec<PnlBucketSnapshot>,
+    episodes: Vec<EpisodePnlSnapshot>,
+});
+api_schema!(OrderLatencySnapshot {
+    exchange: &'static str,
+    samples: u64,
+    p50_ms: f64,
+    p90_ms: f64,
+    p99_ms: f64,
+    estimate_ms: f64,
+});
+api_schema!(QueueSnapshot { name: &'static str, depth: u64, high_water: u64, capacity: u64 });
+api_schema!(TrackerOccupancyDto { symbols: usize, capacity: usize, memory_bytes: usize, evictions: u64 });
+api_schema!(SendLaneSnapshot { lane: &'static str, frames: u64, mean_delay_us: f64, max_delay_us: f64 });
+api_schema!(ConnectionBufferSnapshot {
+    feed: usize,
+    label: String,
+    read_buffer_bytes: u64,
+    max_frame_bytes: u64,
+    send_lanes: Vec<SendLaneSnapshot>,
+    server_pings: ServerPingSnapshot,
+});
+api_schema!(ServerPingSnapshot { received: u64, last_ms: Option<u64>, last_interval_ms: Option<u64>, max_interval_ms: Option<u64> });
+api_schema!(RecorderSnapshot { rows_written: u64, bytes_written: u64, bytes_per_sec: f64, dropped: u64 });
+api_schema!(DebugDto {
+    pools: Vec<PoolSnapshot>,
+    queues: Vec<QueueSnapshot>,
+    tracker: TrackerOccupancyDto,
+    connections: Vec<ConnectionBufferSnapshot>,
+    recorder: RecorderSnapshot,
+    invariants: InvariantSnapshot,
+});
+api_schema!(InvariantCount { exchange: &'static str, invariant: &'static str, count: u64 });
+api_schema!(InvariantSnapshot { checked: u64, violations: Vec<InvariantCount> });
+api_schema!(UnparsedCount { exchange: &'static str, kind: &'static str, count: u64 });
+api_schema!(UnparsedSample { exchange: &'static str, kind: &'static str, received_ms: u64, raw: String, truncated: bool });
+api_schema!(UnparsedSnapshot { counts: Vec<UnparsedCount>, samples: Vec<UnparsedSample> });
+
+/// Every named component referenced by the document
+fn components() -> Map<String, Value> {
+    [
+        ThresholdSource::component(),
+        SizeConstraint::component(),
+        SubscriptionState::component(),
+        TaskState::component(),
+        StartupStage::component(),
+        SystemStatusDto::component(),
+        ScreenerDto::component(),
+        DashboardDto::component(),
+        WarmupDto::component(),
+        PauseDto::component(),
+        TaskSnapshot::component(),
+        CircuitState::component(),
+        BreakerStatus::component(),
+        ExchangeHealth::component(),
+        StageSnapshot::component(),
+        StartupSnapshot::component(),
+        HealthDto::component(),
+        OpportunityDto::component(),
+        LegTimingDto::component(),
+        ExecutionAttemptDto::component(),
+        HeatmapDto::component(),
+        SessionBucketDto::component(),
+        SessionStatsDto::component(),
+        LeadLagDto::component(),
+        RebalanceDto::component(),
+        VenuePresenceDto::component(),
+        SymbolPresenceDto::component(),
+        SymbolExecutionDto::component(),
+        ManualOrderBody::component(),
+        ManualOrderDto::component(),
+        FlattenLegDto::component(),
+        FlattenDto::component(),
+        BookLevelDto::component(),
+        VenueBookDto::component(),
+        BookDto::component(),
+        FeedRaceSnapshot::component(),
+        OrderLatencySnapshot::component(),
+        VenueQualitySnapshot::component(),
+        LegSlippageSnapshot::component(),
+        ExecutionQualitySnapshot::component(),
+        PnlBucketSnapshot::component(),
+        EpisodePnlSnapshot::component(),
+        PnlAttributionSnapshot::component(),
+        MetricsPoint::component(),
+        SnapshotQuoteDto::component(),
+        SnapshotPairDto::component(),
+        MarketSnapshotDto::component(),
+        ConnectionSnapshot::component(),
+        EpisodeRow::component(),
+        ExecutionRow::component(),
+        PoolSnapshot::component(),
+        QueueSnapshot::component(),
+        TrackerOccupancyDto::component(),
+        SendLaneSnapshot::component(),
+        ConnectionBufferSnapshot::component(),
+        ServerPingSnapshot::component(),
+        RecorderSnapshot::component(),
+        DebugDto::component(),
+        InvariantCount::component(),
+        InvariantSnapshot::component(),
+        UnparsedCount::component(),
+        UnparsedSample::component(),
+        UnparsedSnapshot::component(),
+    ]
+    .into_iter()
+    .flatten()
+    .map(|(name, schema)| (name.to_string(), schema))
+    .collect()
+}
+
+/// Query or path parameter
+struct Param {
+    name: &'static str,
+    location: &'static str,
+    schema: fn() -> Value,
+    description: &'static str,
+}
+
+const SYMBOL_FILTER: Param = Param {
+    name: "symbol",
+    location: "query",
+    schema: String::schema,
+    description: "Only this symbol (case-insensitive)",
+};
+
+const HISTORY_PARAMS: &[Param] = &[
+    SYMBOL_FILTER,
+    Param { name: "from", location: "query", schema: u64::schema, description: "Start, milliseconds since epoch" },
+    Param { name: "to", location: "query", schema: u64::schema, description: "End, milliseconds since epoch" },
+    Param { name: "min_spread", location: "query", schema: f64::schema, description: "Minimum peak spread (episodes only)" },
+    Param { name: "limit", location: "query", schema: usize::schema, description: "Maximum rows returned" },
+];
+
+const METRICS_HISTORY_PARAMS: &[Param] = &[
+    Param { name: "resolution", location: "query", schema: String::schema, description: "\"1s\" (last hour, default) or \"1m\" (last 24 hours)" },
+    Param { name: "since", location: "query", schema: u64::schema, description: "Oldest point, milliseconds since epoch" },
+];
+
+const HEATMAP_PARAMS: &[Param] = &[
+    Param { name: "hours", location: "query", schema: u64::schema, description: "Hours back from now (default 24)" },
+    Param { name: "bucketMinutes", location: "query", schema: u64::schema, description: "Bucket width, multiple of 5 (default 15)" },
+];
+
+/// Documented response body
+enum Body {
+    Json(fn() -> Value),
+    Text,
+    /// WebSocket upgrade (101)
+    Upgrade,
+    /// Accepted without a body (202)
+    Accepted,
+}
+
+/// One documented endpoint
+struct Operation {
+    method: &'static str,
+    path: &'static str,
+    tag: &'static str,
+    summary: &'static str,
+    params: &'static [Param],
+    /// JSON request body schema
+    request: Option<fn() -> Value>,
+    response: Body,
+    /// Behind the bearer token
+    secured: bool,
+}
+
+const fn get(path: &'static str, tag: &'static str, summary: &'static str, response: Body) -> Operation {
+    Operation { method: "get", path, tag, summary, params: &[], request: None, response, secured: false }
+}
+
+const OPERATIONS: &[Operation] = &[
+    get("/api/dashboard/stats", "screener", "System status and screener rows", Body::Json(DashboardDto::schema)),
+    get("/api/screener/stats", "screener", "Screener rows", Body::Json(Vec::<ScreenerDto>::schema)),
+    get(
+        "/api/screener/ws",
+        "screener",
+        "Screener update stream (WebSocket; send `resync` for a full snapshot)",
+        Body::Upgrade,
+    ),
+    get("/api/opportunities", "trading", "Recent journaled opportunities with sizing", Body::Json(Vec::<OpportunityDto>::schema)),
+    get(
+        "/api/execution-attempts",
+        "trading",
+        "Recent execution attempts with per-leg send/ack timing",
+        Body::Json(Vec::<ExecutionAttemptDto>::schema),
+    ),
+    Operation {
+        params: HEATMAP_PARAMS,
+        ..get("/api/heatmap", "trading", "Opportunity hits per symbol and time bucket", Body::Json(HeatmapDto::schema))
+    },
+    Operation {
+        params: &[SYMBOL_FILTER],
+        ..get("/api/sessions", "trading", "Opportunity statistics by UTC hour and weekday", Body::Json(Vec::<SessionStatsDto>::schema))
+    },
+    Operation {
+        params: &[SYMBOL_FILTER],
+        ..get("/api/lead-lag", "market", "Which venue leads mid-price moves, and by how many ms", Body::Json(Vec::<LeadLagDto>::schema))
+    },
+    get("/api/rebalance", "trading", "Inventory rebalance opportunities", Body::Json(Vec::<RebalanceDto>::schema)),
+    Operation {
+        params: &[SYMBOL_FILTER],
+        ..get("/api/symbols", "market", "Registered symbols with per-venue availability", Body::Json(Vec::<SymbolPresenceDto>::schema))
+    },
+    Operation {
+        params: &[Param { name: "symbol", location: "path", schema: String::schema, description: "Symbol, e.g. BTCUSDT" }],
+        ..get("/api/book/{symbol}", "market", "Top of book on both venues", Body::Json(BookDto::schema))
+    },
+    Operation {
+        params: HISTORY_PARAMS,
+        ..get("/api/history/episodes", "history", "Stored opportunity episodes", Body::Json(Vec::<EpisodeRow>::schema))
+    },
+    Operation {
+        params: HISTORY_PARAMS,
+        ..get("/api/history/executions", "history", "Stored executions", Body::Json(Vec::<ExecutionRow>::schema))
+    },
+    get("/api/metrics/feeds", "metrics", "A/B feed race win rates and lag", Body::Json(Vec::<FeedRaceSnapshot>::schema)),
+    get("/api/metrics/connections", "metrics", "Message rate, gaps and latency per connection", Body::Json(Vec::<ConnectionSnapshot>::schema)),
+    Operation {
+        params: METRICS_HISTORY_PARAMS,
+        ..get("/api/metrics/history", "metrics", "Message-rate and latency trends (1s for 1h, 1m for 24h)", Body::Json(Vec::<MetricsPoint>::schema))
+    },
+    get("/api/latency", "metrics", "Order-ack round-trip percentiles per venue", Body::Json(Vec::<OrderLatencySnapshot>::schema)),
+    get("/api/snapshot", "market", "All quote pairs captured at one snapshot tick", Body::Json(MarketSnapshotDto::schema)),
+    get(
+        "/api/execution-quality",
+        "metrics",
+        "Fill slippage vs decision price per venue and day",
+        Body::Json(ExecutionQualitySnapshot::schema),
+    ),
+    get(
+        "/api/pnl/attribution",
+        "metrics",
+        "Realized PnL and estimated fees per strategy, symbol and recent episode",
+        Body::Json(PnlAttributionSnapshot::schema),
+    ),
+    Operation {
+        secured: true,
+        ..get("/api/debug", "metrics", "Pool, queue, tracker and buffer introspection", Body::Json(DebugDto::schema))
+    },
+    Operation {
+        secured: true,
+        ..get("/api/debug/unparsed", "metrics", "Parse failures by message type and the last raw frames", Body::Json(UnparsedSnapshot::schema))
+    },
+    get("/api/health", "health", "Readiness, startup stages, warm-up, pause, feed task state and reconnect circuits", Body::Json(HealthDto::schema)),
+    get("/api/health/live", "health", "Liveness probe", Body::Text),
+    Operation {
+        method: "post",
+        params: &[Param {
+            name: "tracker",
+            location: "query",
+            schema: bool::schema,
+            description: "Also freeze spread tracking",
+        }],
+        secured: true,
+        ..get("/api/pause", "control", "Pause execution; feeds stay connected", Body::Json(PauseDto::schema))
+    },
+    Operation {
+        method: "post",
+        secured: true,
+        ..get("/api/resume", "control", "Resume execution", Body::Json(PauseDto::schema))
+    },
+    Operation {
+        method: "post",
+        secured: true,
+        ..get("/api/shutdown", "control", "Stop the engine and write the session report", Body::Accepted)
+    },
+    Operation {
+        method: "post",
+        secured: true,
+        params: &[
+            Param { name: "symbol", location: "path", schema: String::schema, description: "Symbol, e.g. BTCUSDT" },
+            Param { name: "enabled", location: "query", schema: bool::schema, description: "Allow execution on the symbol" },
+        ],
+        ..get("/api/symbols/{symbol}/execution", "control", "Enable or disable execution on one symbol", Body::Json(SymbolExecutionDto::schema))
+    },
+    Operation {
+        method: "post",
+        secured: true,
+        request: Some(ManualOrderBody::schema),
+        ..get(
+            "/api/order",
+            "control",
+            "Manual order through the engine's live gates (400 malformed, 404 unknown symbol, 503 engine not running)",
+            Body::Json(ManualOrderDto::schema),
+        )
+    },
+    Operation {
+        method: "post",
+        secured: true,
+        params: &[Param {
+            name: "target",
+            location: "path",
+            schema: String::schema,
+            description: "Symbol, e.g. BTCUSDT, or `all`",
+        }],
+        ..get(
+            "/api/flatten/{target}",
+            "control",
+            "Market-close positions on every venue with retries and a final report, verified against venue position reports",
+            Body::Json(FlattenDto::schema),
+        )
+    },
+];
+
+impl Operation {
+    fn to_json(&self) -> Value {
+        let success = match self.response {
+            Body::Json(schema) => json!({
+                "200": { "description": "OK", "content": { "application/json": { "schema": schema() } } }
+            }),
+            Body::Text => json!({
+                "200": { "description": "OK", "content": { "text/plain": { "schema": { "type": "string" } } } }
+            }),
+            Body::Upgrade => json!({ "101": { "description": "Switching to WebSocket" } }),
+            Body::Accepted => json!({ "202": { "description": "Accepted" } }),
+        };
+        let mut operation = json!({
+            "tags": [self.tag],
+            "summary": self.summary,
+            "responses": success,
+        });
+        if !self.params.is_empty() {
+            let params: Vec<Value> = self
+                .params
+                .iter()
+                .map(|p| {
+                    json!({
+                        "name": p.name,
+                        "in": p.location,
+                        "required": p.location == "path",
+                        "description": p.description,
+                        "schema": (p.schema)(),
+                    })
+                })
+                .collect();
+            operation["parameters"] = Value::Array(params);
+        }
+        if let Some(schema) = self.request {
+            operation["requestBody"] = json!({
+                "required": true,
+                "content": { "application/json": { "schema": schema() } },
+            });
+        }
+        if self.secured {
+            operation["security"] = json!([{ "bearerAuth": [] }]);
+            operation["responses"]["401"] = json!({ "description": "Missing or invalid bearer token" });
+        }
+        operation
+    }
+}
+
+/// OpenAPI 3.0 document for the whole API
+pub fn document() -> Value {
+    let mut paths = Map::new();
+    for op in OPERATIONS {
+        let path = paths.entry(op.path).or_insert_with(|| Value::Object(Map::new()));
+        path[op.method] = op.to_json();
+    }
+    json!({
+        "openapi": "3.0.3",
+        "info": {
+            "title": "rust-hft API",
+            "version": env!("CARGO_PKG_VERSION"),
+            "description": "Screener, metrics and control API. Clients outside the configured allowlist get 403.",
+        },
+        "paths": paths,
+        "components": {
+            "schemas": components(),
+            "securitySchemes": { "bearerAuth": { "type": "http", "scheme": "bearer" } },
+        },
+    })
+}
+
+/// Swagger UI page for /api/docs (assets from the swagger-ui-dist CDN)
+pub const SWAGGER_UI: &str = r##"<!DOCTYPE html>
+<html lang="en">
+<head>
+  <meta charset="utf-8">
+  <title>rust-hft API</title>
+  <link rel="stylesheet" href="https://unpkg.com/swagger-ui-dist@5/swagger-ui.css">
+</head>
+<body>
+  <div id="swagger-ui"></div>
+  <script src="https://unpkg.com/swagger-ui-dist@5/swagger-ui-bundle.js"></script>
+  <script>
+    window.ui = SwaggerUIBundle({ url: "/api/openapi.json", dom_id: "#swagger-ui" });
+  </script>
+</body>
+</html>
+"##;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::Serialize;
+
+    /// Serialized keys match the declared properties
+    fn assert_matches<T: Serialize + ApiSchema>(value: T) {
+        let (name, schema) = T::component().unwrap();
+        let declared: Vec<&String> = schema["properties"].as_object().unwrap().keys().collect();
+        let serialized = serde_json::to_value(value).unwrap();
+        let actual: Vec<&String> = serialized.as_object().unwrap().keys().collect();
+        assert_eq!(declared, actual, "{} schema out of date", name);
+    }
+
+    #[test]
+    fn test_openapi_document() {
+        let doc = document();
+        assert_eq!(doc["paths"]["/api/book/{symbol}"]["get"]["parameters"][0]["in"], "path");
+        assert_eq!(doc["paths"]["/api/pause"]["post"]["security"][0]["bearerAuth"], json!([]));
+        assert_eq!(doc["paths"]["/api/debug/unparsed"]["get"]["security"][0]["bearerAuth"], json!([]));
+        assert_eq!(
+            doc["paths"]["/api/order"]["post"]["requestBody"]["content"]["application/json"]["schema"]["$ref"],
+            "#/components/schemas/ManualOrderBody"
+        );
+        assert_eq!(doc["components"]["schemas"]["PauseDto"]["properties"]["pausedForSecs"]["nullable"], true);
+
+        // Every $ref resolves to a declared component
+        let schemas = doc["components"]["schemas"].as_object().unwrap();
+        let text = doc.to_string();
+        for reference in text.split("\"#/components/schemas/").skip(1) {
+            let name = &reference[..reference.find('"').unwrap()];
+            assert!(schemas.contains_key(name), "undeclared schema {}", name);
+        }
+
+        assert_matches(PauseDto { paused: true, tracker_paused: false, paused_for_secs: Some(3) });
+        assert_matches(WarmupDto { active: false, remaining_secs: 0, warm_symbols: 4 });
+        assert_matches(ExchangeHealth {
+            exchange: "bybit",
+            circuit: BreakerStatus { state: CircuitState::HalfOpen, consecutive_failures: 5, times_opened: 1, retry_in_ms: 0 },
+        });
+        assert_matches(StartupSnapshot {
+            stage: StartupStage::Connections,
+            ready: false,
+            stages: vec![StageSnapshot { stage: StartupStage::Config, completed_ms: Some(1) }],
+        });
+        assert_matches(TrackerOccupancyDto { symbols: 1, capacity: 2, memory_bytes: 3, evictions: 4 });
+        assert_matches(BookLevelDto { price: 1.0, qty: 2.0, cum_notional: 2.0 });
+        assert_matches(HeatmapDto { start_ms: 0, bucket_ms: 300_000, symbols: vec![], counts: vec![] });
+        assert_matches(FlattenLegDto { exchange: "bybit", symbol: "BTCUSDT".into(), initial_qty: -1.0, remaining_qty: 0.0, confirmed: true, orders: 1 });
+        assert_matches(FlattenDto { target: "all", status: "verified", attempts: 1, elapsed_ms: 2.0, legs: vec![] });
+        assert_matches(ManualOrderDto { status: "suppressed", reason: Some("paused") });
+        assert_matches(LegTimingDto { exchange: "bybit", side: "sell", sent_ms: 0.5, acked_ms: None });
+        assert_matches(SessionBucketDto { opportunities: 1, samples: 2, avg_net_spread: 0.1 });
+        assert_matches(QueueSnapshot { name: "q", depth: 0, high_water: 0, capacity: 8 });
+        assert_matches(OrderLatencySnapshot { exchange: "bybit", samples: 1, p50_ms: 1.0, p90_ms: 2.0, p99_ms: 3.0, estimate_ms: 1.5 });
+        assert_matches(MetricsPoint {
+            timestamp_ms: 0,
+            message_rate: 3.0,
+            binance_rate: 2.0,
+            bybit_rate: 1.0,
+            duplicate_rate: 0.0,
+            processing_p50_us: Some(1.0),
+            processing_p99_us: None,
+            orders: 0,
+            exchange_errors: 0,
+            quarantined: 0,
+        });
+        assert_matches(MarketSnapshotDto {
+            tick: 3,
+            taken_ms: 0,
+            pairs: vec![SnapshotPairDto {
+                symbol: "BTCUSDT".into(),
+                quotes: vec![SnapshotQuoteDto {
+                    exchange: "binance",
+                    bid: 1.0,
+                    bid_qty: 2.0,
+                    ask: 1.1,
+                    ask_qty: 3.0,
+                    exchange_ms: 0,
+                    age_ms: 0.5,
+                }],
+            }],
+        });
+        assert_matches(ExecutionQualitySnapshot {
+            days: vec![VenueQualitySnapshot {
+                date: "2026-10-16".into(),
+                exchange: "binance",
+                fills: 2,
+                notional: 200.0,
+                avg_vs_mid_bps: 1.5,
+                avg_vs_touch_bps: -0.5,
+                avg_vs_submitted_bps: None,
+                worst_vs_mid_bps: 2.0,
+            }],
+            recent: vec![LegSlippageSnapshot {
+                timestamp_ms: 0,
+                exchange: "binance",
+                symbol: "BTCUSDT".into(),
+                side: "buy",
+                decision_mid: 100.0,
+                decision_touch: 100.5,
+                submitted_price: Some(100.5),
+                fill_price: 100.5,
+                quantity: 1.0,
+                vs_mid_bps: 50.0,
+                vs_touch_bps: 0.0,
+                vs_submitted_bps: Some(0.0),
+            }],
+        });
+        let bucket = PnlBucketSnapshot { name: "arb-main".into(), fills: 2, notional: 200.0, realized_pnl: 1.0, fees: 0.2, net_pnl: 0.8 };
+        assert_matches(bucket.clone());
+        assert_matches(PnlAttributionSnapshot {
+            strategies: vec![bucket.clone()],
+            symbols: vec![PnlBucketSnapshot { name: "BTCUSDT".into(), ..bucket }],
+            episodes: vec![EpisodePnlSnapshot {
+                id: 7,
+                symbol: "BTCUSDT".into(),
+                fills: 2,
+                notional: 200.0,
+                realized_pnl: 1.0,
+                fees: 0.2,
+                net_pnl: 0.8,
+            }],
+        });
+        assert_matches(RecorderSnapshot { rows_written: 0, bytes_written: 0, bytes_per_sec: 0.0, dropped: 0 });
+        assert_matches(ServerPingSnapshot { received: 2, last_ms: Some(1), last_interval_ms: None, max_interval_ms: None });
+        assert_matches(InvariantSnapshot {
+            checked: 10,
+            violations: vec![InvariantCount { exchange: "bybit", invariant: "crossedBook", count: 1 }],
+        });
+        assert_matches(UnparsedSnapshot {
+            counts: vec![UnparsedCount { exchange: "binance", kind: "bookTicker", count: 1 }],
+            samples: vec![UnparsedSample { exchange: "binance", kind: "bookTicker", received_ms: 1, raw: "{}".into(), truncated: false }],
+        });
+        assert_matches(ExecutionRow {
+            exchange: "binance".to_string(),
+            symbol: "BTCUSDT".to_string(),
+            side: "buy".to_string(),
+            price: 1.0,
+            quantity: 1.0,
+            timestamp_ms: 1,
+            strategy: Some("arb-main".to_string()),
+            episode_id: None,
+        });
+    }
+}
@@ -0,0 +1,292 @@
+//! Peer link between regional instances (Cold Path)
+//!
+//! Newline-delimited JSON frames over one TCP connection: each side sends
+//! a `hello` with its node id and venues, then its local-venue tickers and a
+//! heartbeat every `heartbeat_ms`. Prices travel as raw fixed-point values,
+//! and update ids are kept so the engine deduplicates peer tickers against
+//! its own feeds. A link silent for `timeout_ms` is dropped and reported
+//! [`PeerEvent::Lost`]; the engine fences execution until the next `hello`
+//! (see [`crate::engine::peer`]). Both sides must hold the same secret;
+//! every frame is signed (see [`super::link_auth`]).
+
+use crate::core::{FixedPoint8, Symbol, TickerData};
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::PeerConfig;
+use crate::infrastructure::link_auth::{self, LinkSecret};
+use crate::{HftError, Result};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::mpsc;
+use tokio::time::Instant;
+
+/// Capacity of the engine → peer ticker channel (tickers dropped when full)
+pub const PEER_QUEUE_CAPACITY: usize = 4096;
+
+/// Wire frame
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+pub enum PeerFrame {
+    #[serde(rename_all = "camelCase")]
+    Hello { node_id: String, venues: Vec<String> },
+    Heartbeat,
+    /// Prices and quantities as raw FixedPoint8 values
+    #[serde(rename_all = "camelCase")]
+    Ticker {
+        exchange: String,
+        symbol: String,
+        bid_price: i64,
+        bid_qty: i64,
+        ask_price: i64,
+        ask_qty: i64,
+        timestamp: u64,
+        update_id: u64,
+    },
+}
+
+impl PeerFrame {
+    pub fn ticker(exchange: Exchange, ticker: &TickerData) -> Self {
+        Self::Ticker {
+            exchange: exchange.name().to_string(),
+            symbol: ticker.symbol.as_str().to_string(),
+            bid_price: ticker.bid_price.as_raw(),
+            bid_qty: ticker.bid_qty.as_raw(),
+            ask_price: ticker.ask_price.as_raw(),
+            ask_qty: ticker.ask_qty.as_raw(),
+            timestamp: ticker.timestamp,
+            update_id: ticker.update_id,
+        }
+    }
+
+    /// Engine event (None for heartbeats, unknown venues and symbols)
+    pub fn into_event(self) -> Option<PeerEvent> {
+        match self {
+            Self::Hello { node_id, venues } => Some(PeerEvent::Joined {
+                node_id,
+                venues: venues.iter().filter_map(|v| Exchange::from_name(v)).collect(),
+            }),
+            Self::Heartbeat => None,
+            Self::Ticker { exchange, symbol, bid_price, bid_qty, ask_price, ask_qty, timestamp, update_id } => {
+                let exchange = Exchange::from_name(&exchange)?;
+                let ticker = TickerData {
+                    symbol: Symbol::from_bytes(symbol.as_bytes())?,
+                    bid_price: FixedPoint8::from_raw(bid_price),
+                    bid_qty: FixedPoint8::from_raw(bid_qty),
+                    ask_price: FixedPoint8::from_raw(ask_price),
+                    ask_qty: FixedPoint8::from_raw(ask_qty),
+                    timestamp,
+                    update_id,
+                };
+                Some(PeerEvent::Ticker(exchange, ticker))
+            }
+        }
+    }
+}
+
+/// Peer link event for the engine
+#[derive(Debug, Clone, PartialEq)]
+pub enum PeerEvent {
+    /// Peer (re)connected and announced its venues
+    Joined { node_id: String, venues: Vec<Exchange> },
+    /// Ticker from the peer's venue
+    Ticker(Exchange, TickerData),
+    /// Link dropped or silent past the timeout
+    Lost,
+}
+
+/// Exactly one of `listen` / `connect` must be set
+pub fn validate(config: &PeerConfig) -> Result<()> {
+    if config.listen.is_some() == config.connect.is_some() {
+        return Err(HftError::Config("peer needs exactly one of listen or connect".to_string()));
+    }
+    Ok(())
+}
+
+/// Keep the peer link up until the engine drops its event receiver
+///
+/// `outbound` carries this instance's local-venue tickers; frames queued
+/// while disconnected are discarded as stale on reconnect. A connection
+/// that never authenticates is dropped without reporting a loss.
+pub async fn run(
+    config: PeerConfig,
+    secret: LinkSecret,
+    venues: Vec<Exchange>,
+    mut outbound: mpsc::Receiver<(Exchange, TickerData)>,
+    events: mpsc::Sender<PeerEvent>,
+) {
+    let hello = PeerFrame::Hello {
+        node_id: config.node_id.clone(),
+        venues: venues.iter().map(|v| v.name().to_string()).collect(),
+    };
+    let listener = match &config.listen {
+        Some(addr) => match TcpListener::bind(addr).await {
+            Ok(listener) => Some(listener),
+            Err(e) => {
+                tracing::error!("Peer link disabled, cannot listen on {}: {}", addr, e);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    while !events.is_closed() {
+        let stream = match (&listener, &config.connect) {
+            (Some(listener), _) => listener.accept().await.map(|(stream, _)| stream),
+            (None, Some(addr)) => TcpStream::connect(addr).await,
+            (None, None) => return,
+        };
+        match stream {
+            Ok(stream) => {
+                let _ = stream.set_nodelay(true);
+                while outbound.try_recv().is_ok() {}
+                let addr = stream.peer_addr().ok();
+                tracing::info!("Peer link connected ({:?})", addr);
+                let mut authenticated = false;
+                if let Err(e) = serve(stream, &secret, &hello, &config, &mut outbound, &events, &mut authenticated).await {
+                    if authenticated {
+                        tracing::warn!("Peer link down: {}", e);
+                    } else {
+                        tracing::warn!("Peer link rejected ({:?}): {}", addr, e);
+                    }
+                }
+                if authenticated && events.send(PeerEvent::Lost).await.is_err() {
+                    return;
+                }
+            }
+            Err(e) => tracing::debug!("Peer link connect failed: {}", e),
+        }
+        if listener.is_none() {
+            tokio::time::sleep(Duration::from_millis(config.reconnect_ms)).await;
+        }
+    }
+}
+
+/// Exchange frames on one connection until it fails or goes silent
+///
+/// `authenticated` is set once the peer has sent a verified frame.
+async fn serve(
+    stream: TcpStream,
+    secret: &LinkSecret,
+    hello: &PeerFrame,
+    config: &PeerConfig,
+    outbound: &mut mpsc::Receiver<(Exchange, TickerData)>,
+    events: &mpsc::Sender<PeerEvent>,
+    authenticated: &mut bool,
+) -> io::Result<()> {
+    let timeout = Duration::from_millis(config.timeout_ms);
+    let (mut reader, mut writer) = link_auth::handshake(stream, secret, timeout).await?;
+    let mut heartbeat = tokio::time::interval(Duration::from_millis(config.heartbeat_ms.max(1)));
+    let mut deadline = Instant::now() + timeout;
+    writer.send(hello).await?;
+
+    loop {
+        tokio::select! {
+            frame = tokio::time::timeout_at(deadline, reader.next_frame()) => {
+                let frame = frame
+                    .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "peer silent"))??
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "peer closed"))?;
+                *authenticated = true;
+                deadline = Instant::now() + timeout;
+                match serde_json::from_str::<PeerFrame>(&frame) {
+                    Ok(frame) => {
+                        if let Some(event) = frame.into_event() {
+                            if events.send(event).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Bad peer frame: {}", e),
+                }
+            }
+            Some((exchange, ticker)) = outbound.recv() => {
+                writer.send(&PeerFrame::ticker(exchange, &ticker)).await?;
+            }
+            _ = heartbeat.tick() => {
+                writer.send(&PeerFrame::Heartbeat).await?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    fn ticker(symbol: &str, bid: f64) -> TickerData {
+        TickerData {
+            symbol: Symbol::from_bytes(symbol.as_bytes()).unwrap(),
+            bid_price: FixedPoint8::from_f64(bid).unwrap(),
+            bid_qty: FixedPoint8::from_f64(1.5).unwrap(),
+            ask_price: FixedPoint8::from_f64(bid + 0.1).unwrap(),
+            ask_qty: FixedPoint8::from_f64(2.0).unwrap(),
+            timestamp: 1_700_000_000_000_000_000,
+            update_id: 42,
+        }
+    }
+
+    #[tokio::test]
+    async fn test_peers_exchange_hello_and_tickers() {
+        init_test_registry();
+        let port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+        let addr = format!("127.0.0.1:{}", port);
+        let base = PeerConfig { enabled: true, heartbeat_ms: 50, timeout_ms: 300, reconnect_ms: 20, ..Default::default() };
+        let tokyo = PeerConfig { node_id: "tokyo".into(), listen: Some(addr.clone()), ..base.clone() };
+        let singapore = PeerConfig { node_id: "singapore".into(), connect: Some(addr.clone()), ..base };
+        validate(&tokyo).unwrap();
+        assert!(validate(&PeerConfig::default()).is_err());
+
+        let (tokyo_out, tokyo_out_rx) = mpsc::channel(16);
+        let (tokyo_events_tx, mut tokyo_events) = mpsc::channel(16);
+        let (_singapore_out, singapore_out_rx) = mpsc::channel(16);
+        let (singapore_events_tx, mut singapore_events) = mpsc::channel(16);
+        let secret = LinkSecret::new("s3cret");
+        tokio::spawn(run(tokyo, secret.clone(), vec![Exchange::Binance], tokyo_out_rx, tokyo_events_tx));
+
+        // Anyone else reaching the port is dropped without a trace in the engine
+        let mut intruder = loop {
+            match TcpStream::connect(&addr).await {
+                Ok(stream) => break stream,
+                Err(_) => tokio::time::sleep(Duration::from_millis(5)).await,
+            }
+        };
+        let hello = r#"{"type":"hello","nodeId":"mallory","venues":["binance","bybit"]}"#;
+        intruder.write_all(format!("0123456789abcdef0123456789abcdef\n{}\n", hello).as_bytes()).await.unwrap();
+        // Closed (or reset) by tokyo on the unsigned hello
+        let _ = intruder.read_to_end(&mut Vec::new()).await;
+        assert!(tokyo_events.try_recv().is_err());
+
+        let singapore_link = tokio::spawn(run(singapore, secret, vec![Exchange::Bybit], singapore_out_rx, singapore_events_tx));
+
+        let joined = singapore_events.recv().await.unwrap();
+        assert_eq!(joined, PeerEvent::Joined { node_id: "tokyo".into(), venues: vec![Exchange::Binance] });
+        let joined = tokyo_events.recv().await.unwrap();
+        assert_eq!(joined, PeerEvent::Joined { node_id: "singapore".into(), venues: vec![Exchange::Bybit] });
+
+        let sent = ticker("BTCUSDT", 100.25);
+        tokyo_out.send((Exchange::Binance, sent)).await.unwrap();
+        assert_eq!(singapore_events.recv().await.unwrap(), PeerEvent::Ticker(Exchange::Binance, sent));
+
+        // Peer gone: the other side reports the loss
+        singapore_link.abort();
+        assert_eq!(tokyo_events.recv().await.unwrap(), PeerEvent::Lost);
+    }
+
+    #[test]
+    fn test_unknown_symbol_dropped() {
+        init_test_registry();
+        let mut frame = PeerFrame::ticker(Exchange::Bybit, &ticker("ETHUSDT", 10.0));
+        if let PeerFrame::Ticker { symbol, .. } = &mut frame {
+            *symbol = "NOTLISTEDUSDT".into();
+        }
+        let json = serde_json::to_string(&frame).unwrap();
+        assert!(json.starts_with(r#"{"type":"ticker","exchange":"bybit""#), "{}", json);
+        assert_eq!(serde_json::from_str::<PeerFrame>(&json).unwrap().into_event(), None);
+        assert_eq!(PeerFrame::Heartbeat.into_event(), None);
+    }
+}
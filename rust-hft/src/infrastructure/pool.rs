@@ -37,6 +37,8 @@ impl<T: Send> ObjectPool<T> {
     /// 
     /// # Example
     /// ```
+    /// use rust_hft::infrastructure::pool::ObjectPool;
+    ///
     /// let pool = ObjectPool::with_capacity(1000, || String::with_capacity(256));
     /// ```
     pub fn with_capacity<F>(capacity: usize, factory: F) -> Self
@@ -47,7 +49,7 @@ impl<T: Send> ObjectPool<T> {
         
         // Pre-populate the pool
         for _ in 0..capacity {
-            if let Err(_) = stack.push(factory()) {
+            if stack.push(factory()).is_err() {
                 break; // Queue is full
             }
         }
@@ -113,17 +115,37 @@ impl<T: Send> ObjectPool<T> {
     }
 }
 
+/// Type-erased pool occupancy (for debug introspection)
+pub trait PoolOccupancy: Send + Sync {
+    /// Objects currently available in the pool
+    fn len(&self) -> usize;
+    /// Whether the pool is exhausted
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+    /// Maximum number of pooled objects
+    fn capacity(&self) -> usize;
+}
+
+impl<T: Send> PoolOccupancy for ObjectPool<T> {
+    #[inline]
+    fn len(&self) -> usize {
+        ObjectPool::len(self)
+    }
+
+    #[inline]
+    fn capacity(&self) -> usize {
+        ObjectPool::capacity(self)
+    }
+}
+
 /// Specialized pool for byte buffers (Vec<u8>)
 pub type ByteBufferPool = ObjectPool<Vec<u8>>;
 
 impl ByteBufferPool {
     /// Create a pool of byte buffers with fixed capacity
     pub fn with_buffer_size(pool_capacity: usize, buffer_size: usize) -> Self {
-        Self::with_capacity(pool_capacity, move || {
-            let mut buf = Vec::with_capacity(buffer_size);
-            buf.resize(buffer_size, 0);
-            buf
-        })
+        Self::with_capacity(pool_capacity, move || vec![0u8; buffer_size])
     }
     
     /// Acquire and reset the buffer
@@ -259,9 +281,16 @@ mod tests {
         }
         
         // Pool should still have objects
-        assert!(pool.len() > 0);
+        assert!(!pool.is_empty());
     }
     
+    #[test]
+    fn test_pool_occupancy() {
+        let pool: Box<dyn PoolOccupancy> = Box::new(ObjectPool::with_capacity(4, || 0u8));
+        assert_eq!(pool.len(), 4);
+        assert_eq!(pool.capacity(), 4);
+    }
+
     #[test]
     fn test_send_sync() {
         fn assert_send_sync<T: Send + Sync>() {}
@@ -0,0 +1,125 @@
+//! Cross-venue symbol presence (Cold Path)
+//!
+//! Which registered symbols each venue lists (as liquid), and what the
+//! engine is currently subscribed to. Discovery fills the listings at
+//! startup; the engine updates subscription state as symbols are
+//! subscribed, focused or delisted. Served by `/api/symbols`.
+
+use crate::core::Symbol;
+use crate::exchanges::Exchange;
+use parking_lot::RwLock;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+
+/// Subscription state of a symbol across feeds
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SubscriptionState {
+    /// Registered but not subscribed
+    Unsubscribed,
+    /// Ticker, mark price and liquidation streams
+    Subscribed,
+    /// Subscribed with trade/depth streams (auto-focus)
+    Focused,
+    /// Unsubscribed after a delisting notice
+    Delisted,
+}
+
+#[derive(Debug, Default)]
+struct Presence {
+    listed: HashMap<Symbol, [bool; Exchange::COUNT]>,
+    subscribed: HashSet<Symbol>,
+    focused: HashSet<Symbol>,
+    delisted: HashSet<Symbol>,
+}
+
+/// Shared symbol presence board (interior locking, cheap reads)
+#[derive(Debug, Default)]
+pub struct SymbolPresence {
+    inner: RwLock<Presence>,
+}
+
+impl SymbolPresence {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Replace the symbols listed on exchange
+    pub fn set_listed(&self, exchange: Exchange, symbols: &[Symbol]) {
+        let mut inner = self.inner.write();
+        for venues in inner.listed.values_mut() {
+            venues[exchange.index()] = false;
+        }
+        for &symbol in symbols {
+            inner.listed.entry(symbol).or_default()[exchange.index()] = true;
+        }
+    }
+
+    /// Mark symbols as subscribed on every feed
+    pub fn set_subscribed(&self, symbols: &[Symbol]) {
+        self.inner.write().subscribed.extend(symbols.iter().copied());
+    }
+
+    /// Replace the focused set
+    pub fn set_focused(&self, symbols: &[Symbol]) {
+        self.inner.write().focused = symbols.iter().copied().collect();
+    }
+
+    /// Mark symbol as delisted (unsubscribed)
+    pub fn mark_delisted(&self, symbol: Symbol) {
+        self.inner.write().delisted.insert(symbol);
+    }
+
+    /// Listing flags per exchange (indexed by Exchange::index)
+    pub fn listed(&self, symbol: Symbol) -> [bool; Exchange::COUNT] {
+        self.inner.read().listed.get(&symbol).copied().unwrap_or_default()
+    }
+
+    /// Current subscription state
+    pub fn state(&self, symbol: Symbol) -> SubscriptionState {
+        let inner = self.inner.read();
+        if inner.delisted.contains(&symbol) {
+            SubscriptionState::Delisted
+        } else if inner.focused.contains(&symbol) {
+            SubscriptionState::Focused
+        } else if inner.subscribed.contains(&symbol) {
+            SubscriptionState::Subscribed
+        } else {
+            SubscriptionState::Unsubscribed
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_listing_and_state() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let sol = Symbol::from_bytes(b"SOLUSDT").unwrap();
+        let presence = SymbolPresence::new();
+
+        presence.set_listed(Exchange::Binance, &[btc, eth]);
+        presence.set_listed(Exchange::Bybit, &[btc]);
+        assert_eq!(presence.listed(btc), [true, true]);
+        assert_eq!(presence.listed(eth), [true, false]);
+        assert_eq!(presence.listed(sol), [false, false]);
+
+        // Relisting replaces the venue's set
+        presence.set_listed(Exchange::Binance, &[eth]);
+        assert_eq!(presence.listed(btc), [false, true]);
+
+        presence.set_subscribed(&[btc, eth]);
+        presence.set_focused(&[btc]);
+        assert_eq!(presence.state(btc), SubscriptionState::Focused);
+        assert_eq!(presence.state(eth), SubscriptionState::Subscribed);
+        assert_eq!(presence.state(sol), SubscriptionState::Unsubscribed);
+
+        presence.mark_delisted(btc);
+        assert_eq!(presence.state(btc), SubscriptionState::Delisted);
+    }
+}
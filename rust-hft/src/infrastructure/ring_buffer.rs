@@ -55,6 +55,12 @@ impl<T: Copy + Default + Debug, const N: usize> RingBuffer<T, N> {
     }
 }
 
+impl<T: Copy + Default + Debug, const N: usize> Default for RingBuffer<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 // Special implementation for FixedPoint8 to maintain sum
 use crate::core::FixedPoint8;
 
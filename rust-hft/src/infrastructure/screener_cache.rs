@@ -0,0 +1,127 @@
+//! Pre-serialized screener snapshot (Cold Path)
+//!
+//! Dashboard pollers hit /api/screener/stats far more often than the
+//! screener changes. The cache keeps the JSON bytes of the last snapshot:
+//! within `max_age` every poller gets the same bytes without touching the
+//! tracker lock; after that the next poller takes a new snapshot, and the
+//! rows are re-encoded only if they differ from the cached ones. Pollers
+//! arriving during a rebuild wait for it instead of starting their own.
+
+use axum::body::Bytes;
+use std::time::{Duration, Instant};
+use tokio::sync::{Mutex, RwLock};
+
+use crate::core::clock;
+use crate::hot_path::{ScreenerStats, ThresholdTracker};
+use crate::infrastructure::json_writer::WriteJson;
+
+/// Last snapshot and its encoding
+#[derive(Debug)]
+struct Snapshot {
+    /// When the rows were taken (None = never)
+    taken: Option<Instant>,
+    rows: Vec<ScreenerStats>,
+    /// `rows` as a JSON array (ScreenerDto shape)
+    body: Bytes,
+}
+
+impl Snapshot {
+    /// Replace the rows, re-encoding only on change; true if re-encoded
+    fn refresh(&mut self, rows: Vec<ScreenerStats>, now: Instant) -> bool {
+        self.taken = Some(now);
+        if self.body.is_empty() || rows != self.rows {
+            let mut out = Vec::with_capacity(self.body.len().max(256));
+            out.push(b'[');
+            for (i, row) in rows.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                row.write_json(&mut out);
+            }
+            out.push(b']');
+            self.body = Bytes::from(out);
+            self.rows = rows;
+            return true;
+        }
+        false
+    }
+}
+
+/// Screener JSON shared by all pollers
+#[derive(Debug)]
+pub struct ScreenerCache {
+    max_age: Duration,
+    snapshot: Mutex<Snapshot>,
+}
+
+impl ScreenerCache {
+    /// Cache serving a snapshot for up to `max_age`
+    pub fn new(max_age: Duration) -> Self {
+        Self {
+            max_age,
+            snapshot: Mutex::new(Snapshot {
+                taken: None,
+                rows: Vec::new(),
+                body: Bytes::new(),
+            }),
+        }
+    }
+
+    /// Screener rows as a JSON array, refreshed from `tracker` when stale
+    pub async fn get(&self, tracker: &RwLock<ThresholdTracker>) -> Bytes {
+        let mut snapshot = self.snapshot.lock().await;
+        let now = clock::coarse_now();
+        let fresh = snapshot.taken.is_some_and(|t| now.saturating_duration_since(t) < self.max_age);
+        if !fresh {
+            // Write lock: get_all_stats evicts stale entries (cold path)
+            let rows = tracker.write().await.get_all_stats();
+            snapshot.refresh(rows, now);
+        }
+        snapshot.body.clone()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{FixedPoint8, Symbol, TickerData};
+    use crate::exchanges::Exchange;
+    use crate::infrastructure::api::ScreenerDto;
+    use crate::test_utils::init_test_registry;
+
+    fn ticker(symbol: Symbol, price: i64) -> TickerData {
+        let fp = |v: i64| FixedPoint8::from_raw(v * FixedPoint8::SCALE);
+        TickerData::new(symbol, fp(price), FixedPoint8::ONE, fp(price + 1), FixedPoint8::ONE, 1000)
+    }
+
+    #[tokio::test]
+    async fn test_screener_cache() {
+        init_test_registry();
+        let sym = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let tracker = RwLock::new(ThresholdTracker::new());
+        let cache = ScreenerCache::new(Duration::from_secs(3600));
+        assert_eq!(&cache.get(&tracker).await[..], b"[]");
+
+        {
+            let mut tracker = tracker.write().await;
+            tracker.update(ticker(sym, 100), Exchange::Binance);
+            tracker.update(ticker(sym, 101), Exchange::Bybit);
+        }
+        // Still fresh: the cached bytes are served without a new snapshot
+        assert_eq!(&cache.get(&tracker).await[..], b"[]");
+
+        let mut snapshot = cache.snapshot.lock().await;
+        let rows = tracker.write().await.get_all_stats();
+        assert!(snapshot.refresh(rows.clone(), clock::coarse_now()));
+        let body = snapshot.body.clone();
+        // Unchanged rows keep the same buffer
+        assert!(!snapshot.refresh(rows.clone(), clock::coarse_now()));
+        assert_eq!(snapshot.body.as_ptr(), body.as_ptr());
+
+        let parsed: serde_json::Value = serde_json::from_slice(&body).unwrap();
+        let expected = serde_json::to_value(rows.into_iter().map(ScreenerDto::from).collect::<Vec<_>>()).unwrap();
+        assert_eq!(parsed[0]["symbol"], expected[0]["symbol"]);
+        assert_eq!(parsed[0]["currentSpread"].as_f64(), expected[0]["currentSpread"].as_f64());
+        assert_eq!(parsed.as_array().unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,204 @@
+//! Screener snapshot diffing (Cold Path)
+//!
+//! Streaming clients receive a full snapshot first, then only rows that
+//! changed (and symbols that disappeared) between consecutive snapshots.
+//! A full snapshot is re-sent every `resync_every` updates so a client that
+//! missed or misapplied a delta converges again.
+
+use crate::core::Symbol;
+use crate::hot_path::ScreenerStats;
+use crate::infrastructure::json_writer::{write_str, WriteJson};
+use std::collections::HashMap;
+
+/// One update pushed to a streaming client
+#[derive(Debug, Clone, PartialEq)]
+pub enum ScreenerUpdate {
+    /// Complete snapshot (replaces client state)
+    Full(Vec<ScreenerStats>),
+    /// Changed/new rows and removed symbols since the previous update
+    Delta {
+        changed: Vec<ScreenerStats>,
+        removed: Vec<Symbol>,
+    },
+}
+
+impl ScreenerUpdate {
+    /// Delta with nothing to apply (full snapshots are never empty updates)
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Self::Full(_) => false,
+            Self::Delta { changed, removed } => changed.is_empty() && removed.is_empty(),
+        }
+    }
+}
+
+/// Per-client diff state
+#[derive(Debug)]
+pub struct SnapshotDiffer {
+    last: HashMap<Symbol, ScreenerStats>,
+    resync_every: u32,
+    since_full: u32,
+}
+
+impl SnapshotDiffer {
+    /// Create differ sending a full snapshot every `resync_every` updates
+    ///
+    /// The first update is always full.
+    pub fn new(resync_every: u32) -> Self {
+        Self {
+            last: HashMap::new(),
+            resync_every: resync_every.max(1),
+            since_full: 0,
+        }
+    }
+
+    /// Force the next update to be a full snapshot
+    pub fn reset(&mut self) {
+        self.last.clear();
+        self.since_full = 0;
+    }
+
+    /// Diff `snapshot` against the previous one and remember it
+    pub fn diff(&mut self, snapshot: Vec<ScreenerStats>) -> ScreenerUpdate {
+        let full = self.since_full == 0 || self.since_full >= self.resync_every;
+
+        let update = if full {
+            self.since_full = 1;
+            ScreenerUpdate::Full(snapshot.clone())
+        } else {
+            self.since_full += 1;
+            let changed = snapshot
+                .iter()
+                .filter(|row| self.last.get(&row.symbol) != Some(row))
+                .copied()
+                .collect();
+            let mut removed: Vec<Symbol> = self
+                .last
+                .keys()
+                .filter(|symbol| !snapshot.iter().any(|row| row.symbol == **symbol))
+                .copied()
+                .collect();
+            removed.sort();
+            ScreenerUpdate::Delta { changed, removed }
+        };
+
+        self.last.clear();
+        self.last.extend(snapshot.into_iter().map(|row| (row.symbol, row)));
+        update
+    }
+}
+
+/// `{"type":"full","rows":[..]}` or `{"type":"delta","changed":[..],"removed":[..]}`
+impl WriteJson for ScreenerUpdate {
+    fn write_json(&self, out: &mut Vec<u8>) {
+        fn write_rows(out: &mut Vec<u8>, rows: &[ScreenerStats]) {
+            out.push(b'[');
+            for (i, row) in rows.iter().enumerate() {
+                if i > 0 {
+                    out.push(b',');
+                }
+                row.write_json(out);
+            }
+            out.push(b']');
+        }
+
+        match self {
+            Self::Full(rows) => {
+                out.extend_from_slice(br#"{"type":"full","rows":"#);
+                write_rows(out, rows);
+            }
+            Self::Delta { changed, removed } => {
+                out.extend_from_slice(br#"{"type":"delta","changed":"#);
+                write_rows(out, changed);
+                out.extend_from_slice(br#","removed":["#);
+                for (i, symbol) in removed.iter().enumerate() {
+                    if i > 0 {
+                        out.push(b',');
+                    }
+                    write_str(out, symbol.as_str());
+                }
+                out.push(b']');
+            }
+        }
+        out.push(b'}');
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Bps, FixedPoint8};
+    use crate::hot_path::ThresholdSource;
+    use crate::test_utils::init_test_registry;
+
+    fn row(name: &str, spread: i64) -> ScreenerStats {
+        ScreenerStats {
+            symbol: Symbol::from_bytes(name.as_bytes()).unwrap(),
+            current_spread: Bps::from_raw(spread),
+            spread_ticks: None,
+            break_even: Bps::ZERO,
+            edge_after_costs: Bps::ZERO,
+            spread_range: Bps::ZERO,
+            spread_p1: None,
+            spread_p99: None,
+            robust_range: None,
+            hits: 0,
+            threshold: Bps::ZERO,
+            threshold_source: ThresholdSource::Static,
+            liquidation_volume: FixedPoint8::ZERO,
+            price_volatility: FixedPoint8::ZERO,
+            stale_venue: None,
+            suspended: None,
+            is_valid: true,
+        }
+    }
+
+    #[test]
+    fn test_full_then_deltas() {
+        init_test_registry();
+        let mut differ = SnapshotDiffer::new(3);
+
+        let first = differ.diff(vec![row("BTCUSDT", 1), row("ETHUSDT", 2)]);
+        assert!(matches!(first, ScreenerUpdate::Full(ref rows) if rows.len() == 2));
+
+        // Unchanged snapshot: empty delta
+        assert!(differ.diff(vec![row("BTCUSDT", 1), row("ETHUSDT", 2)]).is_empty());
+
+        // ETH changed, BTC removed, SOL added
+        let update = differ.diff(vec![row("ETHUSDT", 5), row("SOLUSDT", 1)]);
+        assert_eq!(
+            update,
+            ScreenerUpdate::Delta {
+                changed: vec![row("ETHUSDT", 5), row("SOLUSDT", 1)],
+                removed: vec![Symbol::from_bytes(b"BTCUSDT").unwrap()],
+            }
+        );
+
+        // Periodic resync
+        assert!(matches!(differ.diff(vec![row("ETHUSDT", 5)]), ScreenerUpdate::Full(_)));
+
+        differ.reset();
+        assert!(matches!(differ.diff(vec![]), ScreenerUpdate::Full(_)));
+    }
+
+    #[test]
+    fn test_update_json() {
+        init_test_registry();
+        let mut out = Vec::new();
+        ScreenerUpdate::Delta {
+            changed: vec![row("BTCUSDT", 100_000)],
+            removed: vec![Symbol::from_bytes(b"ETHUSDT").unwrap()],
+        }
+        .write_json(&mut out);
+
+        let value: serde_json::Value = serde_json::from_slice(&out).unwrap();
+        assert_eq!(value["type"], "delta");
+        assert_eq!(value["changed"][0]["symbol"], "BTCUSDT");
+        assert_eq!(value["changed"][0]["currentSpread"], 0.001);
+        assert_eq!(value["removed"], serde_json::json!(["ETHUSDT"]));
+
+        out.clear();
+        ScreenerUpdate::Full(vec![]).write_json(&mut out);
+        assert_eq!(out, br#"{"type":"full","rows":[]}"#);
+    }
+}
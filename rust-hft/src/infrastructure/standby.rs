@@ -0,0 +1,398 @@
+//! Hot-standby replication link (Cold Path)
+//!
+//! Newline-delimited JSON frames over one TCP connection between the active
+//! and the passive instance. Both sides send a heartbeat every
+//! `heartbeat_ms` carrying their role and epoch (see
+//! [`crate::engine::failover`]); the active side also sends a full
+//! [`ReplicaState`] snapshot every `snapshot_ms` and each fill as it happens.
+//! Frames queued while disconnected are discarded on reconnect: the next
+//! snapshot supersedes them. Prices travel as raw fixed-point values.
+//! Heartbeats move the lease and the epoch, so the link is signed like the
+//! peer link (see [`super::link_auth`]).
+
+use crate::core::{FixedPoint8, Side, Symbol, TickerData};
+use crate::engine::failover::Contact;
+use crate::engine::Fill;
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::StandbyConfig;
+use crate::infrastructure::link_auth::{self, LinkSecret};
+use crate::{HftError, Result};
+use serde::{Deserialize, Serialize};
+use std::io;
+use std::time::Duration;
+use tokio::net::{TcpListener, TcpStream};
+use tokio::sync::{mpsc, watch};
+use tokio::time::Instant;
+
+/// Capacity of the engine → standby link channel (frames dropped when full)
+pub const STANDBY_QUEUE_CAPACITY: usize = 1024;
+
+/// Replicated engine state
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ReplicaState {
+    /// Open positions: exchange, symbol, signed net quantity, entry price
+    pub positions: Vec<(Exchange, Symbol, FixedPoint8, FixedPoint8)>,
+    pub realized: FixedPoint8,
+    pub fills: u64,
+    /// Order legs sent but not acknowledged (cancelled on takeover)
+    pub open_legs: Vec<(Exchange, Symbol)>,
+    /// Latest ticker per symbol and venue
+    pub tickers: Vec<(Exchange, TickerData)>,
+}
+
+/// Replication link event for the engine
+#[derive(Debug, Clone, PartialEq)]
+pub enum StandbyEvent {
+    /// Heartbeat from the other instance
+    Contact(Contact),
+    /// Full state from the active side
+    Snapshot(ReplicaState),
+    /// Fill on the active side since the last snapshot
+    Fill(Fill),
+    /// Link dropped or silent past the heartbeat timeout
+    Lost,
+}
+
+/// Outbound payload from the engine (heartbeats are added by the link)
+#[derive(Debug, Clone, PartialEq)]
+pub enum Replication {
+    Snapshot(ReplicaState),
+    Fill(Fill),
+}
+
+/// Wire frame
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "camelCase")]
+enum StandbyFrame {
+    Heartbeat { epoch: u64, active: bool, paired: bool },
+    #[serde(rename_all = "camelCase")]
+    Snapshot {
+        positions: Vec<WirePosition>,
+        realized: i64,
+        fills: u64,
+        open_legs: Vec<(String, String)>,
+        tickers: Vec<WireTicker>,
+    },
+    Fill(WireFill),
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct WirePosition {
+    exchange: String,
+    symbol: String,
+    net: i64,
+    entry: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct WireTicker {
+    exchange: String,
+    symbol: String,
+    bid_price: i64,
+    bid_qty: i64,
+    ask_price: i64,
+    ask_qty: i64,
+    timestamp: u64,
+    update_id: u64,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+struct WireFill {
+    exchange: String,
+    symbol: String,
+    buy: bool,
+    price: i64,
+    quantity: i64,
+    timestamp: u64,
+}
+
+/// Venue and symbol by name (None if either is unknown here)
+fn resolve(exchange: &str, symbol: &str) -> Option<(Exchange, Symbol)> {
+    Some((Exchange::from_name(exchange)?, Symbol::from_bytes(symbol.as_bytes())?))
+}
+
+impl StandbyFrame {
+    fn encode(replication: &Replication) -> Self {
+        match replication {
+            Replication::Snapshot(state) => Self::Snapshot {
+                positions: state
+                    .positions
+                    .iter()
+                    .map(|(exchange, symbol, net, entry)| WirePosition {
+                        exchange: exchange.name().to_string(),
+                        symbol: symbol.as_str().to_string(),
+                        net: net.as_raw(),
+                        entry: entry.as_raw(),
+                    })
+                    .collect(),
+                realized: state.realized.as_raw(),
+                fills: state.fills,
+                open_legs: state
+                    .open_legs
+                    .iter()
+                    .map(|(exchange, symbol)| (exchange.name().to_string(), symbol.as_str().to_string()))
+                    .collect(),
+                tickers: state
+                    .tickers
+                    .iter()
+                    .map(|(exchange, ticker)| WireTicker {
+                        exchange: exchange.name().to_string(),
+                        symbol: ticker.symbol.as_str().to_string(),
+                        bid_price: ticker.bid_price.as_raw(),
+                        bid_qty: ticker.bid_qty.as_raw(),
+                        ask_price: ticker.ask_price.as_raw(),
+                        ask_qty: ticker.ask_qty.as_raw(),
+                        timestamp: ticker.timestamp,
+                        update_id: ticker.update_id,
+                    })
+                    .collect(),
+            },
+            Replication::Fill(fill) => Self::Fill(WireFill {
+                exchange: fill.exchange.name().to_string(),
+                symbol: fill.symbol.as_str().to_string(),
+                buy: fill.side == Side::Buy,
+                price: fill.price.as_raw(),
+                quantity: fill.quantity.as_raw(),
+                timestamp: fill.timestamp,
+            }),
+        }
+    }
+
+    /// Engine event; entries on venues or symbols unknown here are skipped
+    fn into_event(self) -> Option<StandbyEvent> {
+        match self {
+            Self::Heartbeat { epoch, active, paired } => Some(StandbyEvent::Contact(Contact { epoch, active, paired })),
+            Self::Snapshot { positions, realized, fills, open_legs, tickers } => Some(StandbyEvent::Snapshot(ReplicaState {
+                positions: positions
+                    .into_iter()
+                    .filter_map(|p| {
+                        let (exchange, symbol) = resolve(&p.exchange, &p.symbol)?;
+                        Some((exchange, symbol, FixedPoint8::from_raw(p.net), FixedPoint8::from_raw(p.entry)))
+                    })
+                    .collect(),
+                realized: FixedPoint8::from_raw(realized),
+                fills,
+                open_legs: open_legs.iter().filter_map(|(exchange, symbol)| resolve(exchange, symbol)).collect(),
+                tickers: tickers
+                    .into_iter()
+                    .filter_map(|t| {
+                        let (exchange, symbol) = resolve(&t.exchange, &t.symbol)?;
+                        Some((
+                            exchange,
+                            TickerData {
+                                symbol,
+                                bid_price: FixedPoint8::from_raw(t.bid_price),
+                                bid_qty: FixedPoint8::from_raw(t.bid_qty),
+                                ask_price: FixedPoint8::from_raw(t.ask_price),
+                                ask_qty: FixedPoint8::from_raw(t.ask_qty),
+                                timestamp: t.timestamp,
+                                update_id: t.update_id,
+                            },
+                        ))
+                    })
+                    .collect(),
+            })),
+            Self::Fill(f) => {
+                let (exchange, symbol) = resolve(&f.exchange, &f.symbol)?;
+                Some(StandbyEvent::Fill(Fill {
+                    exchange,
+                    symbol,
+                    side: if f.buy { Side::Buy } else { Side::Sell },
+                    price: FixedPoint8::from_raw(f.price),
+                    quantity: FixedPoint8::from_raw(f.quantity),
+                    timestamp: f.timestamp,
+                }))
+            }
+        }
+    }
+}
+
+/// Exactly one of `listen` / `connect`, and a takeover slower than the lease
+pub fn validate(config: &StandbyConfig) -> Result<()> {
+    if config.listen.is_some() == config.connect.is_some() {
+        return Err(HftError::Config("standby needs exactly one of listen or connect".to_string()));
+    }
+    if config.takeover_ms <= config.lease_ms {
+        return Err(HftError::Config(format!(
+            "standby.takeover_ms ({}) must exceed lease_ms ({}) or both instances may trade",
+            config.takeover_ms, config.lease_ms
+        )));
+    }
+    Ok(())
+}
+
+/// Keep the replication link up until the engine drops its event receiver
+///
+/// Heartbeats announce the engine's current `contact`; `outbound` carries
+/// snapshots and fills while this instance is active. A connection that
+/// never authenticates is dropped without reporting a loss.
+pub async fn run(
+    config: StandbyConfig,
+    secret: LinkSecret,
+    contact: watch::Receiver<Contact>,
+    mut outbound: mpsc::Receiver<Replication>,
+    events: mpsc::Sender<StandbyEvent>,
+) {
+    let listener = match &config.listen {
+        Some(addr) => match TcpListener::bind(addr).await {
+            Ok(listener) => Some(listener),
+            Err(e) => {
+                tracing::error!("Standby link disabled, cannot listen on {}: {}", addr, e);
+                return;
+            }
+        },
+        None => None,
+    };
+
+    while !events.is_closed() {
+        let stream = match (&listener, &config.connect) {
+            (Some(listener), _) => listener.accept().await.map(|(stream, _)| stream),
+            (None, Some(addr)) => TcpStream::connect(addr).await,
+            (None, None) => return,
+        };
+        match stream {
+            Ok(stream) => {
+                let _ = stream.set_nodelay(true);
+                while outbound.try_recv().is_ok() {}
+                let addr = stream.peer_addr().ok();
+                tracing::info!("Standby link connected ({:?})", addr);
+                let mut authenticated = false;
+                if let Err(e) = serve(stream, &secret, &config, &contact, &mut outbound, &events, &mut authenticated).await {
+                    if authenticated {
+                        tracing::warn!("Standby link down: {}", e);
+                    } else {
+                        tracing::warn!("Standby link rejected ({:?}): {}", addr, e);
+                    }
+                }
+                if authenticated && events.send(StandbyEvent::Lost).await.is_err() {
+                    return;
+                }
+            }
+            Err(e) => tracing::debug!("Standby link connect failed: {}", e),
+        }
+        if listener.is_none() {
+            tokio::time::sleep(Duration::from_millis(config.reconnect_ms)).await;
+        }
+    }
+}
+
+/// Exchange frames on one connection until it fails or goes silent
+///
+/// A connection silent for the takeover timeout is dropped; failover itself
+/// is timed by the engine from the last heartbeat. `authenticated` is set
+/// once the other side has sent a verified frame.
+async fn serve(
+    stream: TcpStream,
+    secret: &LinkSecret,
+    config: &StandbyConfig,
+    contact: &watch::Receiver<Contact>,
+    outbound: &mut mpsc::Receiver<Replication>,
+    events: &mpsc::Sender<StandbyEvent>,
+    authenticated: &mut bool,
+) -> io::Result<()> {
+    let timeout = Duration::from_millis(config.takeover_ms);
+    let (mut reader, mut writer) = link_auth::handshake(stream, secret, timeout).await?;
+    let mut heartbeat = tokio::time::interval(Duration::from_millis(config.heartbeat_ms.max(1)));
+    let mut deadline = Instant::now() + timeout;
+
+    loop {
+        tokio::select! {
+            frame = tokio::time::timeout_at(deadline, reader.next_frame()) => {
+                let frame = frame
+                    .map_err(|_| io::Error::new(io::ErrorKind::TimedOut, "standby link silent"))??
+                    .ok_or_else(|| io::Error::new(io::ErrorKind::UnexpectedEof, "standby link closed"))?;
+                *authenticated = true;
+                deadline = Instant::now() + timeout;
+                match serde_json::from_str::<StandbyFrame>(&frame) {
+                    Ok(frame) => {
+                        if let Some(event) = frame.into_event() {
+                            if events.send(event).await.is_err() {
+                                return Ok(());
+                            }
+                        }
+                    }
+                    Err(e) => tracing::warn!("Bad standby frame: {}", e),
+                }
+            }
+            Some(replication) = outbound.recv() => {
+                writer.send(&StandbyFrame::encode(&replication)).await?;
+            }
+            _ = heartbeat.tick() => {
+                let Contact { epoch, active, paired } = *contact.borrow();
+                writer.send(&StandbyFrame::Heartbeat { epoch, active, paired }).await?;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[tokio::test]
+    async fn test_link_replicates_state_and_heartbeats() {
+        init_test_registry();
+        let port = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+        let addr = format!("127.0.0.1:{}", port);
+        let base = StandbyConfig { enabled: true, heartbeat_ms: 50, reconnect_ms: 20, ..Default::default() };
+        let primary = StandbyConfig { listen: Some(addr.clone()), ..base.clone() };
+        let standby = StandbyConfig { connect: Some(addr), ..base };
+        validate(&primary).unwrap();
+        assert!(validate(&StandbyConfig { lease_ms: 3_000, ..primary.clone() }).is_err());
+
+        let active = Contact { epoch: 1, active: true, paired: true };
+        let (_primary_contact, primary_contact_rx) = watch::channel(active);
+        let (primary_out, primary_out_rx) = mpsc::channel(16);
+        let (primary_events_tx, _primary_events) = mpsc::channel(16);
+        let (_standby_contact, standby_contact_rx) = watch::channel(Contact { epoch: 0, active: false, paired: true });
+        let (_standby_out, standby_out_rx) = mpsc::channel(16);
+        let (standby_events_tx, mut standby_events) = mpsc::channel(16);
+        let secret = LinkSecret::new("s3cret");
+        tokio::spawn(run(primary, secret.clone(), primary_contact_rx, primary_out_rx, primary_events_tx));
+        tokio::spawn(run(standby, secret, standby_contact_rx, standby_out_rx, standby_events_tx));
+
+        assert_eq!(standby_events.recv().await.unwrap(), StandbyEvent::Contact(active));
+
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let ticker = TickerData {
+            symbol: btc,
+            bid_price: FixedPoint8::from_f64(100.0).unwrap(),
+            bid_qty: FixedPoint8::ONE,
+            ask_price: FixedPoint8::from_f64(100.5).unwrap(),
+            ask_qty: FixedPoint8::ONE,
+            timestamp: 7,
+            update_id: 9,
+        };
+        let state = ReplicaState {
+            positions: vec![(Exchange::Bybit, btc, FixedPoint8::from_f64(-0.5).unwrap(), FixedPoint8::from_f64(100.0).unwrap())],
+            realized: FixedPoint8::from_f64(12.5).unwrap(),
+            fills: 3,
+            open_legs: vec![(Exchange::Binance, btc)],
+            tickers: vec![(Exchange::Binance, ticker)],
+        };
+        let fill = Fill {
+            exchange: Exchange::Binance,
+            symbol: btc,
+            side: Side::Sell,
+            price: FixedPoint8::from_f64(101.0).unwrap(),
+            quantity: FixedPoint8::ONE,
+            timestamp: 8,
+        };
+        primary_out.send(Replication::Snapshot(state.clone())).await.unwrap();
+        primary_out.send(Replication::Fill(fill)).await.unwrap();
+
+        let mut replicated = Vec::new();
+        while replicated.len() < 2 {
+            match standby_events.recv().await.unwrap() {
+                StandbyEvent::Contact(_) => {}
+                event => replicated.push(event),
+            }
+        }
+        assert_eq!(replicated, vec![StandbyEvent::Snapshot(state), StandbyEvent::Fill(fill)]);
+    }
+}
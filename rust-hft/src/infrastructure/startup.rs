@@ -0,0 +1,183 @@
+//! Startup readiness stages (Cold Path)
+//!
+//! Startup runs through a fixed sequence: config loaded, symbol registry
+//! initialized, liquid symbols discovered, every venue connected, every feed
+//! subscribed, warm-up gate open, ready. `main` completes the first three as
+//! it goes; the feeds and the engine complete the rest as they observe them.
+//! A stage completes only after its predecessor, so a reordered startup fails
+//! loudly instead of reporting ready early. Stages never regress: a feed that
+//! drops later shows up in the connection state of /api/health, not here.
+
+use crate::{HftError, Result};
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Startup stage, in order
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum StartupStage {
+    Config,
+    Registry,
+    Discovery,
+    Connections,
+    Subscriptions,
+    Warmup,
+    /// Terminal: reached once every other stage completed
+    Ready,
+}
+
+impl StartupStage {
+    pub const COUNT: usize = 7;
+    pub const ALL: [StartupStage; Self::COUNT] = [
+        Self::Config,
+        Self::Registry,
+        Self::Discovery,
+        Self::Connections,
+        Self::Subscriptions,
+        Self::Warmup,
+        Self::Ready,
+    ];
+
+    #[inline]
+    pub const fn index(self) -> usize {
+        self as usize
+    }
+}
+
+/// One stage for API export
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StageSnapshot {
+    pub stage: StartupStage,
+    /// Milliseconds from process start to completion (None = pending)
+    pub completed_ms: Option<u64>,
+}
+
+/// Startup progress for API export
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct StartupSnapshot {
+    /// First stage not yet completed (`ready` once all are)
+    pub stage: StartupStage,
+    pub ready: bool,
+    pub stages: Vec<StageSnapshot>,
+}
+
+/// Completed startup stages, shared by `main`, the feeds and the API
+#[derive(Debug)]
+pub struct StartupProgress {
+    started: Instant,
+    /// Completion time per stage (Ready is set with the last one)
+    completed: Mutex<[Option<Duration>; StartupStage::COUNT]>,
+}
+
+impl StartupProgress {
+    pub fn new() -> Self {
+        Self {
+            started: Instant::now(),
+            completed: Mutex::new([None; StartupStage::COUNT]),
+        }
+    }
+
+    /// First stage not yet completed
+    pub fn stage(&self) -> StartupStage {
+        pending(&self.completed.lock())
+    }
+
+    #[inline]
+    pub fn is_ready(&self) -> bool {
+        self.stage() == StartupStage::Ready
+    }
+
+    /// Mark `stage` complete at `now`
+    ///
+    /// Completing a stage again is a no-op (feeds reconnect); completing one
+    /// before its predecessor, or `Ready` directly, is an error.
+    pub fn complete(&self, stage: StartupStage, now: Instant) -> Result<()> {
+        let mut completed = self.completed.lock();
+        if completed[stage.index()].is_some() {
+            return Ok(());
+        }
+        let pending = pending(&completed);
+        if stage != pending || stage == StartupStage::Ready {
+            return Err(HftError::Config(format!("startup stage {:?} completed while {:?} is pending", stage, pending)));
+        }
+        let elapsed = now.saturating_duration_since(self.started);
+        completed[stage.index()] = Some(elapsed);
+        if stage == StartupStage::Warmup {
+            completed[StartupStage::Ready.index()] = Some(elapsed);
+        }
+        drop(completed);
+        tracing::info!("Startup: {:?} complete after {:?}", stage, elapsed);
+        Ok(())
+    }
+
+    /// Complete `stage` if it is the pending one (runtime observers)
+    pub fn observe(&self, stage: StartupStage, now: Instant) {
+        if self.stage() == stage {
+            // Only this stage is pending, so completing it cannot fail
+            let _ = self.complete(stage, now);
+        }
+    }
+
+    pub fn snapshot(&self) -> StartupSnapshot {
+        let completed = *self.completed.lock();
+        let stage = pending(&completed);
+        StartupSnapshot {
+            stage,
+            ready: stage == StartupStage::Ready,
+            stages: StartupStage::ALL
+                .into_iter()
+                .map(|s| StageSnapshot {
+                    stage: s,
+                    completed_ms: completed[s.index()].map(|d| d.as_millis() as u64),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// First stage without a completion time
+fn pending(completed: &[Option<Duration>; StartupStage::COUNT]) -> StartupStage {
+    StartupStage::ALL.into_iter().find(|s| completed[s.index()].is_none()).unwrap_or(StartupStage::Ready)
+}
+
+impl Default for StartupProgress {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stages_complete_in_order() {
+        let progress = StartupProgress::new();
+        let now = Instant::now();
+        assert_eq!(progress.stage(), StartupStage::Config);
+        // Out of order, and Ready directly
+        assert!(progress.complete(StartupStage::Registry, now).is_err());
+        assert!(progress.complete(StartupStage::Ready, now).is_err());
+
+        progress.complete(StartupStage::Config, now).unwrap();
+        progress.complete(StartupStage::Config, now).unwrap();
+        progress.complete(StartupStage::Registry, now).unwrap();
+        progress.complete(StartupStage::Discovery, now).unwrap();
+        // Runtime observers only complete the pending stage
+        progress.observe(StartupStage::Subscriptions, now);
+        assert_eq!(progress.stage(), StartupStage::Connections);
+        progress.observe(StartupStage::Connections, now);
+        progress.observe(StartupStage::Subscriptions, now);
+        assert!(!progress.is_ready());
+
+        progress.observe(StartupStage::Warmup, now);
+        let snapshot = progress.snapshot();
+        assert!(snapshot.ready && progress.is_ready());
+        assert_eq!(snapshot.stage, StartupStage::Ready);
+        assert!(snapshot.stages.iter().all(|s| s.completed_ms.is_some()));
+        assert_eq!(snapshot.stages.len(), StartupStage::COUNT);
+    }
+}
@@ -0,0 +1,133 @@
+//! Cold-start symbol priority (Cold Path)
+//!
+//! Per-symbol opportunity rates (completed episodes per observed hour) are
+//! persisted across runs in one JSON file. On startup, discovered symbols
+//! are ordered by that rate before the subscription cut, so historically
+//! productive symbols are subscribed, warmed up and given tracker state
+//! first; symbols without history keep their volume order behind them.
+//! Each session is merged in with older sessions decayed by a one-week
+//! half-life of observed time, so the ranking follows the recent regime.
+
+use crate::core::Symbol;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Observed hours after which older sessions count half
+const HALF_LIFE_HOURS: f64 = 168.0;
+
+/// Decayed episode count and observed time of one symbol
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+struct SymbolRate {
+    episodes: f64,
+    hours: f64,
+}
+
+/// Historical opportunity rates by symbol name
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct SymbolPriority {
+    symbols: HashMap<String, SymbolRate>,
+}
+
+impl SymbolPriority {
+    /// Rates saved at `path` (empty if the file is missing or unreadable)
+    pub fn load(path: &Path) -> Self {
+        let text = match fs::read_to_string(path) {
+            Ok(text) => text,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Self::default(),
+            Err(e) => {
+                tracing::warn!("Failed to read symbol priority {}: {}", path.display(), e);
+                return Self::default();
+            }
+        };
+        serde_json::from_str(&text).unwrap_or_else(|e| {
+            tracing::warn!("Ignoring corrupt symbol priority {}: {}", path.display(), e);
+            Self::default()
+        })
+    }
+
+    /// Write the rates to `path` (through a temporary file, so a crash keeps the old one)
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir)?;
+        }
+        let tmp = path.with_extension("tmp");
+        fs::write(&tmp, serde_json::to_vec(self).map_err(io::Error::other)?)?;
+        fs::rename(&tmp, path)
+    }
+
+    /// Completed episodes per observed hour (0 without history)
+    pub fn rate(&self, symbol: &str) -> f64 {
+        self.symbols.get(symbol).map_or(0.0, |r| if r.hours > 0.0 { r.episodes / r.hours } else { 0.0 })
+    }
+
+    /// Symbols with a recorded opportunity
+    pub fn len(&self) -> usize {
+        self.symbols.values().filter(|r| r.episodes > 0.0).count()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Order `symbols` by rate, highest first (stable: ties keep their order)
+    pub fn prioritize<T>(&self, symbols: &mut [T], symbol: impl Fn(&T) -> Symbol) {
+        symbols.sort_by(|a, b| self.rate(symbol(b).as_str()).total_cmp(&self.rate(symbol(a).as_str())));
+    }
+
+    /// Merge a session of `hours` over which `observed` symbols completed `episodes`
+    pub fn record_session(&mut self, observed: &[Symbol], episodes: &[(Symbol, u64)], hours: f64) {
+        if hours <= 0.0 {
+            return;
+        }
+        let decay = 0.5f64.powf(hours / HALF_LIFE_HOURS);
+        for rate in self.symbols.values_mut() {
+            rate.episodes *= decay;
+            rate.hours *= decay;
+        }
+        for symbol in observed {
+            self.symbols.entry(symbol.as_str().to_string()).or_default().hours += hours;
+        }
+        for &(symbol, count) in episodes {
+            self.symbols.entry(symbol.as_str().to_string()).or_default().episodes += count as f64;
+        }
+        // Forget symbols whose history has decayed away
+        self.symbols.retain(|_, r| r.hours >= 0.01);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::test_utils::init_test_registry;
+
+    #[test]
+    fn test_symbol_priority() {
+        init_test_registry();
+        let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+        let sol = Symbol::from_bytes(b"SOLUSDT").unwrap();
+        let mut priority = SymbolPriority::default();
+        priority.record_session(&[btc, eth, sol], &[(eth, 12), (sol, 3)], 2.0);
+        assert_eq!(priority.rate("ETHUSDT"), 6.0);
+        assert_eq!((priority.rate("BTCUSDT"), priority.len()), (0.0, 2));
+
+        // Volume order BTC, SOL, ETH becomes ETH, SOL, BTC
+        let mut symbols = vec![btc, sol, eth];
+        priority.prioritize(&mut symbols, |&s| s);
+        assert_eq!(symbols, vec![eth, sol, btc]);
+
+        // A week later SOL is the busier one
+        priority.record_session(&[eth, sol], &[(sol, 400)], HALF_LIFE_HOURS);
+        assert!(priority.rate("SOLUSDT") > priority.rate("ETHUSDT"));
+
+        let path = std::env::temp_dir().join(format!("rust-hft-priority-{}.json", std::process::id()));
+        priority.save(&path).unwrap();
+        assert_eq!(SymbolPriority::load(&path), priority);
+        std::fs::write(&path, b"not json").unwrap();
+        assert!(SymbolPriority::load(&path).is_empty());
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -2,6 +2,8 @@
 //!
 //! Stores values with timestamps and evicts entries older than window duration.
 //! Used for calculating min/max over a time window (e.g., 2 minutes).
+//! Optionally keeps a fixed-bucket histogram of the window for quantiles
+//! that a single outlier cannot move.
 
 use crate::core::FixedPoint8;
 use std::collections::VecDeque;
@@ -14,6 +16,61 @@ struct TimedEntry {
     timestamp: Instant,
 }
 
+/// Fixed-width buckets over the values in the window
+///
+/// Values outside the range land in the edge buckets. Updated on push and
+/// eviction, so quantiles cost one pass over the buckets.
+#[derive(Debug, Clone)]
+struct WindowHistogram {
+    /// Lower bound of bucket 0 (raw)
+    lowest: i64,
+    /// Bucket width (raw)
+    width: i64,
+    counts: Box<[u32]>,
+}
+
+impl WindowHistogram {
+    fn new(range: FixedPoint8, width: FixedPoint8) -> Self {
+        let width = width.as_raw().max(1);
+        let half = range.as_raw().abs();
+        let buckets = (2 * half / width).max(1) as usize;
+        Self { lowest: -half, width, counts: vec![0; buckets].into_boxed_slice() }
+    }
+
+    #[inline]
+    fn bucket(&self, value: FixedPoint8) -> usize {
+        let offset = value.as_raw().saturating_sub(self.lowest).max(0) / self.width;
+        (offset as usize).min(self.counts.len() - 1)
+    }
+
+    #[inline]
+    fn add(&mut self, value: FixedPoint8) {
+        let bucket = self.bucket(value);
+        self.counts[bucket] = self.counts[bucket].saturating_add(1);
+    }
+
+    #[inline]
+    fn remove(&mut self, value: FixedPoint8) {
+        let bucket = self.bucket(value);
+        self.counts[bucket] = self.counts[bucket].saturating_sub(1);
+    }
+
+    /// Midpoint of the bucket holding quantile `q` of `total` values
+    fn quantile(&self, q: f64, total: usize) -> FixedPoint8 {
+        let rank = ((q.clamp(0.0, 1.0) * total as f64).ceil() as u64).max(1);
+        let mut seen = 0u64;
+        let bucket = self
+            .counts
+            .iter()
+            .position(|&count| {
+                seen += count as u64;
+                seen >= rank
+            })
+            .unwrap_or(self.counts.len() - 1);
+        FixedPoint8::from_raw(self.lowest + bucket as i64 * self.width + self.width / 2)
+    }
+}
+
 /// Time-window buffer for maintaining values within a time window
 ///
 /// Efficiently tracks min/max by using a deque-based approach.
@@ -30,6 +87,8 @@ pub struct TimeWindowBuffer {
     max: FixedPoint8,
     /// Whether min/max need recalculation
     dirty: bool,
+    /// Quantile buckets (None = min/max only)
+    histogram: Option<WindowHistogram>,
 }
 
 impl TimeWindowBuffer {
@@ -41,22 +100,44 @@ impl TimeWindowBuffer {
             min: FixedPoint8::ZERO,
             max: FixedPoint8::ZERO,
             dirty: false,
+            histogram: None,
+        }
+    }
+
+    /// Buffer that also answers [`quantile`](Self::quantile) queries
+    ///
+    /// Buckets are `width` wide over `-range..range`; quantiles are bucket
+    /// midpoints, so they are within `width / 2` of the true value inside the range.
+    pub fn with_histogram(window: Duration, range: FixedPoint8, width: FixedPoint8) -> Self {
+        Self {
+            histogram: Some(WindowHistogram::new(range, width)),
+            ..Self::new(window)
         }
     }
 
     /// Push a new value with current timestamp
     /// Evicts old entries outside the window
     pub fn push(&mut self, value: FixedPoint8) {
-        let now = Instant::now();
+        self.push_at(value, Instant::now());
+    }
 
+    /// Push a value observed at `timestamp` (e.g. historical seed data)
+    ///
+    /// Timestamps must be non-decreasing: eviction assumes the deque is
+    /// ordered oldest-first.
+    pub fn push_at(&mut self, value: FixedPoint8, timestamp: Instant) {
         // Add new entry
-        self.entries.push_back(TimedEntry {
-            value,
-            timestamp: now,
-        });
+        self.entries.push_back(TimedEntry { value, timestamp });
+        if let Some(histogram) = &mut self.histogram {
+            histogram.add(value);
+        }
 
         // Evict old entries
-        self.evict_old(now);
+        self.evict_old(Instant::now());
+        if self.entries.is_empty() {
+            // Seed value already outside the window
+            return;
+        }
 
         // Update min/max if needed
         if self.entries.len() == 1 {
@@ -82,6 +163,9 @@ impl TimeWindowBuffer {
                 if front.value == self.min || front.value == self.max {
                     self.dirty = true;
                 }
+                if let Some(histogram) = &mut self.histogram {
+                    histogram.remove(front.value);
+                }
                 self.entries.pop_front();
             } else {
                 break;
@@ -132,6 +216,25 @@ impl TimeWindowBuffer {
         (self.min, self.max)
     }
 
+    /// Quantile `q` (0..=1) of the values within the window
+    ///
+    /// None when empty or created without a histogram.
+    pub fn quantile(&mut self, q: f64) -> Option<FixedPoint8> {
+        self.evict_old(Instant::now());
+        let total = self.entries.len();
+        let histogram = self.histogram.as_ref()?;
+        (total > 0).then(|| histogram.quantile(q, total))
+    }
+
+    /// Sum of values within the window
+    pub fn sum(&mut self) -> FixedPoint8 {
+        self.evict_old(Instant::now());
+
+        self.entries
+            .iter()
+            .fold(FixedPoint8::ZERO, |acc, e| acc.checked_add(e.value).unwrap_or(FixedPoint8::MAX))
+    }
+
     /// Get current entry count
     pub fn len(&self) -> usize {
         self.entries.len()
@@ -142,9 +245,18 @@ impl TimeWindowBuffer {
         self.entries.is_empty()
     }
 
+    /// Heap allocated by the entries and histogram (bytes)
+    pub fn heap_bytes(&self) -> usize {
+        self.entries.capacity() * std::mem::size_of::<TimedEntry>()
+            + self.histogram.as_ref().map_or(0, |h| h.counts.len() * std::mem::size_of::<u32>())
+    }
+
     /// Clear all entries
     pub fn clear(&mut self) {
         self.entries.clear();
+        if let Some(histogram) = &mut self.histogram {
+            histogram.counts.fill(0);
+        }
         self.min = FixedPoint8::ZERO;
         self.max = FixedPoint8::ZERO;
         self.dirty = false;
@@ -193,6 +305,35 @@ mod tests {
         assert_eq!(max.as_raw(), 300);
     }
 
+    #[test]
+    fn test_sum() {
+        let mut buf = TimeWindowBuffer::new(Duration::from_millis(100));
+        assert_eq!(buf.sum(), FixedPoint8::ZERO);
+
+        buf.push(FixedPoint8::from_raw(100));
+        buf.push(FixedPoint8::from_raw(250));
+        assert_eq!(buf.sum().as_raw(), 350);
+
+        // Expired entries are not counted
+        thread::sleep(Duration::from_millis(150));
+        assert_eq!(buf.sum(), FixedPoint8::ZERO);
+    }
+
+    #[test]
+    fn test_push_at_backdated() {
+        let mut buf = TimeWindowBuffer::new(Duration::from_secs(60));
+        let now = Instant::now();
+
+        // Outside the window: dropped immediately
+        buf.push_at(FixedPoint8::from_raw(1), now - Duration::from_secs(90));
+        assert!(buf.is_empty());
+
+        buf.push_at(FixedPoint8::from_raw(100), now - Duration::from_secs(30));
+        buf.push(FixedPoint8::from_raw(200));
+        assert_eq!(buf.len(), 2);
+        assert_eq!(buf.min_max(), (FixedPoint8::from_raw(100), FixedPoint8::from_raw(200)));
+    }
+
     #[test]
     fn test_empty_buffer() {
         let mut buf = TimeWindowBuffer::new(Duration::from_secs(60));
@@ -233,4 +374,36 @@ mod tests {
             (min.is_positive() && max.is_positive()) || (min.is_negative() && max.is_negative());
         assert!(is_spread_na);
     }
+
+    #[test]
+    fn test_quantiles_ignore_outlier() {
+        // ±1% in 0.01% buckets
+        let mut buf = TimeWindowBuffer::with_histogram(Duration::from_millis(100), FixedPoint8::from_raw(1_000_000), FixedPoint8::from_raw(10_000));
+        assert_eq!(buf.quantile(0.5), None);
+        for i in 0..100 {
+            buf.push(FixedPoint8::from_raw(i * 1_000)); // 0 .. 0.099%
+        }
+        // One glitched quote far outside the range
+        buf.push(FixedPoint8::from_raw(-50_000_000));
+        assert_eq!(buf.min_max().0.as_raw(), -50_000_000);
+        let p1 = buf.quantile(0.01).unwrap().as_raw();
+        let p99 = buf.quantile(0.99).unwrap().as_raw();
+        assert_eq!((p1, p99), (5_000, 95_000));
+        // The outlier itself is clamped into the edge bucket
+        assert_eq!(buf.quantile(0.0).unwrap().as_raw(), -995_000);
+
+        // Evicted values leave the histogram
+        std::thread::sleep(Duration::from_millis(150));
+        buf.push(FixedPoint8::from_raw(200_000));
+        assert_eq!(buf.quantile(0.01).unwrap().as_raw(), 205_000);
+        buf.clear();
+        assert_eq!(buf.quantile(0.5), None);
+    }
+
+    #[test]
+    fn test_quantile_needs_histogram() {
+        let mut buf = TimeWindowBuffer::new(Duration::from_secs(60));
+        buf.push(FixedPoint8::ONE);
+        assert_eq!(buf.quantile(0.5), None);
+    }
 }
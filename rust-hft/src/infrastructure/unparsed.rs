@@ -0,0 +1,171 @@
+//! Parse-error quarantine (Cold Path)
+//!
+//! A frame whose type was detected but whose fields failed to parse is
+//! usually a venue format change. Instead of only logging it, feeds record
+//! it here: the last `capacity` raw frames per exchange are kept (truncated
+//! to [`MAX_SAMPLE_BYTES`]) next to failure counts per exchange and detected
+//! message type, and served by /api/debug/unparsed, so the new format can be
+//! diagnosed from production without a capture run.
+
+use crate::core::clock;
+use crate::exchanges::Exchange;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::collections::VecDeque;
+
+/// Raw frames kept per exchange by default
+pub const DEFAULT_SAMPLES_PER_EXCHANGE: usize = 32;
+
+/// Longest raw frame kept (bytes); longer frames are truncated
+pub const MAX_SAMPLE_BYTES: usize = 4096;
+
+/// Frame of a detected message type whose fields failed to parse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseFailure {
+    /// Detected message type (venue naming, e.g. "bookTicker")
+    pub kind: &'static str,
+}
+
+/// Quarantined raw frame
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnparsedSample {
+    pub exchange: &'static str,
+    pub kind: &'static str,
+    /// Milliseconds since epoch
+    pub received_ms: u64,
+    pub raw: String,
+    /// Raw frame cut at MAX_SAMPLE_BYTES
+    pub truncated: bool,
+}
+
+/// Failures of one detected message type
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnparsedCount {
+    pub exchange: &'static str,
+    pub kind: &'static str,
+    pub count: u64,
+}
+
+/// /api/debug/unparsed response
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UnparsedSnapshot {
+    /// Most failures first
+    pub counts: Vec<UnparsedCount>,
+    /// Newest first, all exchanges
+    pub samples: Vec<UnparsedSample>,
+}
+
+#[derive(Debug, Default)]
+struct Quarantined {
+    samples: [VecDeque<UnparsedSample>; Exchange::COUNT],
+    /// Failures per (exchange, detected type); few distinct types per venue
+    counts: Vec<(Exchange, &'static str, u64)>,
+}
+
+/// Shared by every feed; recording happens on parse failures only
+#[derive(Debug)]
+pub struct ParseQuarantine {
+    capacity: usize,
+    inner: Mutex<Quarantined>,
+}
+
+impl ParseQuarantine {
+    /// Keep the last `capacity` frames per exchange
+    pub fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            inner: Mutex::new(Quarantined::default()),
+        }
+    }
+
+    /// Record a frame of `exchange` that failed to parse
+    pub fn record(&self, exchange: Exchange, failure: ParseFailure, raw: &str) {
+        let mut end = raw.len().min(MAX_SAMPLE_BYTES);
+        while !raw.is_char_boundary(end) {
+            end -= 1;
+        }
+        let sample = UnparsedSample {
+            exchange: exchange.name(),
+            kind: failure.kind,
+            received_ms: clock::coarse_unix_ms(),
+            raw: raw[..end].to_string(),
+            truncated: end < raw.len(),
+        };
+
+        let mut inner = self.inner.lock();
+        match inner.counts.iter_mut().find(|(e, kind, _)| *e == exchange && *kind == failure.kind) {
+            Some((_, _, count)) => *count += 1,
+            None => inner.counts.push((exchange, failure.kind, 1)),
+        }
+        if self.capacity == 0 {
+            return;
+        }
+        let samples = &mut inner.samples[exchange.index()];
+        if samples.len() == self.capacity {
+            samples.pop_front();
+        }
+        samples.push_back(sample);
+    }
+
+    /// Total failures across exchanges and types
+    pub fn total(&self) -> u64 {
+        self.inner.lock().counts.iter().map(|(_, _, count)| count).sum()
+    }
+
+    pub fn snapshot(&self) -> UnparsedSnapshot {
+        let inner = self.inner.lock();
+        let mut counts: Vec<UnparsedCount> = inner
+            .counts
+            .iter()
+            .map(|&(exchange, kind, count)| UnparsedCount { exchange: exchange.name(), kind, count })
+            .collect();
+        counts.sort_by_key(|c| std::cmp::Reverse(c.count));
+        let mut samples: Vec<UnparsedSample> = inner.samples.iter().flatten().cloned().collect();
+        samples.sort_by_key(|s| std::cmp::Reverse(s.received_ms));
+        UnparsedSnapshot { counts, samples }
+    }
+}
+
+impl Default for ParseQuarantine {
+    fn default() -> Self {
+        Self::new(DEFAULT_SAMPLES_PER_EXCHANGE)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_quarantine_keeps_last_frames_and_counts() {
+        let quarantine = ParseQuarantine::new(2);
+        let ticker = ParseFailure { kind: "bookTicker" };
+        for raw in [r#"{"e":"bookTicker","b":1}"#, r#"{"e":"bookTicker","b":2}"#, r#"{"e":"bookTicker","b":3}"#] {
+            quarantine.record(Exchange::Binance, ticker, raw);
+        }
+        quarantine.record(Exchange::Bybit, ParseFailure { kind: "publicTrade" }, "{}");
+
+        let snapshot = quarantine.snapshot();
+        assert_eq!(quarantine.total(), 4);
+        assert_eq!(
+            snapshot.counts,
+            vec![
+                UnparsedCount { exchange: "binance", kind: "bookTicker", count: 3 },
+                UnparsedCount { exchange: "bybit", kind: "publicTrade", count: 1 },
+            ]
+        );
+        let binance: Vec<&str> = snapshot.samples.iter().filter(|s| s.exchange == "binance").map(|s| s.raw.as_str()).collect();
+        assert_eq!(binance.len(), 2);
+        assert!(!binance.contains(&r#"{"e":"bookTicker","b":1}"#));
+
+        // Long frames are cut on a char boundary
+        let long = format!("{}é", "x".repeat(MAX_SAMPLE_BYTES - 1));
+        quarantine.record(Exchange::Bybit, ParseFailure { kind: "tickers" }, &long);
+        let sample = quarantine.snapshot().samples.into_iter().find(|s| s.kind == "tickers").unwrap();
+        assert!(sample.truncated);
+        assert_eq!(sample.raw.len(), MAX_SAMPLE_BYTES - 1);
+    }
+}
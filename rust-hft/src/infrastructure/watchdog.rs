@@ -0,0 +1,208 @@
+//! systemd watchdog integration (Cold Path)
+//!
+//! Critical tasks (engine message loop, tracker, API) bump a [`Heartbeat`]
+//! counter as they make progress. [`run`] sends `WATCHDOG=1` over
+//! `$NOTIFY_SOCKET` only while every heartbeat has advanced within the stall
+//! timeout, so a stuck task stops the pings and systemd restarts the process
+//! instead of leaving a zombie running.
+//!
+//! Example unit:
+//! ```text
+//! [Service]
+//! Type=notify
+//! WatchdogSec=30
+//! Restart=on-failure
+//! ```
+//!
+//! Without `$NOTIFY_SOCKET` (not supervised) the watchdog is a no-op.
+
+use std::io;
+use std::os::unix::net::UnixDatagram;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+
+/// Ping interval when systemd does not provide WATCHDOG_USEC
+const DEFAULT_PING_INTERVAL: Duration = Duration::from_secs(10);
+
+/// Progress counter for one task (cheap to bump from the hot path)
+#[derive(Debug, Clone, Default)]
+pub struct Heartbeat(Arc<AtomicU64>);
+
+impl Heartbeat {
+    /// Record progress
+    #[inline]
+    pub fn beat(&self) {
+        self.0.fetch_add(1, Ordering::Relaxed);
+    }
+
+    #[inline]
+    fn count(&self) -> u64 {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Task being watched
+#[derive(Debug)]
+struct Watched {
+    name: &'static str,
+    heartbeat: Heartbeat,
+    last_count: u64,
+    last_progress: Instant,
+}
+
+/// Tracks heartbeats of critical tasks
+#[derive(Debug)]
+pub struct Watchdog {
+    tasks: Vec<Watched>,
+    stall_timeout: Duration,
+}
+
+impl Watchdog {
+    /// Create watchdog treating a task as stuck after `stall_timeout` without progress
+    pub fn new(stall_timeout: Duration) -> Self {
+        Self {
+            tasks: Vec::new(),
+            stall_timeout,
+        }
+    }
+
+    /// Register critical task, returning the heartbeat it must bump
+    pub fn register(&mut self, name: &'static str) -> Heartbeat {
+        let heartbeat = Heartbeat::default();
+        self.tasks.push(Watched {
+            name,
+            heartbeat: heartbeat.clone(),
+            last_count: 0,
+            last_progress: Instant::now(),
+        });
+        heartbeat
+    }
+
+    /// Tasks without progress for longer than the stall timeout at `now`
+    pub fn check(&mut self, now: Instant) -> Vec<&'static str> {
+        let mut stalled = Vec::new();
+        for task in &mut self.tasks {
+            let count = task.heartbeat.count();
+            if count != task.last_count {
+                task.last_count = count;
+                task.last_progress = now;
+            } else if now.saturating_duration_since(task.last_progress) > self.stall_timeout {
+                stalled.push(task.name);
+            }
+        }
+        stalled
+    }
+}
+
+/// sd_notify client (datagrams to `$NOTIFY_SOCKET`)
+#[derive(Debug)]
+pub struct SdNotify {
+    socket: UnixDatagram,
+    path: String,
+}
+
+impl SdNotify {
+    /// Connect to `$NOTIFY_SOCKET` (None if not running under systemd)
+    pub fn from_env() -> Option<Self> {
+        let path = std::env::var("NOTIFY_SOCKET").ok().filter(|p| !p.is_empty())?;
+        match UnixDatagram::unbound() {
+            Ok(socket) => Some(Self { socket, path }),
+            Err(e) => {
+                tracing::warn!("sd_notify socket unavailable: {}", e);
+                None
+            }
+        }
+    }
+
+    /// Send state string (e.g. `READY=1`, `WATCHDOG=1`)
+    pub fn notify(&self, state: &str) -> io::Result<()> {
+        if let Some(name) = self.path.strip_prefix('@') {
+            // Abstract namespace socket
+            use std::os::linux::net::SocketAddrExt;
+            let addr = std::os::unix::net::SocketAddr::from_abstract_name(name)?;
+            self.socket.send_to_addr(state.as_bytes(), &addr)?;
+        } else {
+            self.socket.send_to(state.as_bytes(), &self.path)?;
+        }
+        Ok(())
+    }
+
+    /// Half the interval systemd expects pings at (`$WATCHDOG_USEC`)
+    pub fn ping_interval() -> Duration {
+        let for_us = std::env::var("WATCHDOG_PID")
+            .ok()
+            .and_then(|pid| pid.parse::<u32>().ok())
+            .is_none_or(|pid| pid == std::process::id());
+        std::env::var("WATCHDOG_USEC")
+            .ok()
+            .and_then(|usec| usec.parse::<u64>().ok())
+            .filter(|&usec| for_us && usec > 0)
+            .map(|usec| Duration::from_micros(usec / 2))
+            .unwrap_or(DEFAULT_PING_INTERVAL)
+    }
+}
+
+/// Send READY=1, then WATCHDOG=1 every `interval` while no task is stalled
+pub async fn run(mut watchdog: Watchdog, notifier: SdNotify, interval: Duration) {
+    if let Err(e) = notifier.notify("READY=1") {
+        tracing::warn!("sd_notify READY failed: {}", e);
+    }
+    let mut ticker = tokio::time::interval(interval);
+    loop {
+        ticker.tick().await;
+        let stalled = watchdog.check(Instant::now());
+        if stalled.is_empty() {
+            if let Err(e) = notifier.notify("WATCHDOG=1") {
+                tracing::warn!("sd_notify WATCHDOG failed: {}", e);
+            }
+        } else {
+            tracing::error!("ALERT: watchdog withheld, stalled tasks: {:?}", stalled);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_stall_detection() {
+        let mut watchdog = Watchdog::new(Duration::from_secs(30));
+        let engine = watchdog.register("engine");
+        let api = watchdog.register("api");
+        let t0 = Instant::now();
+
+        engine.beat();
+        api.beat();
+        assert!(watchdog.check(t0).is_empty());
+
+        // Engine keeps beating, API stops
+        engine.beat();
+        assert!(watchdog.check(t0 + Duration::from_secs(20)).is_empty());
+        engine.beat();
+        assert_eq!(watchdog.check(t0 + Duration::from_secs(31)), vec!["api"]);
+
+        // Recovers once it beats again
+        api.beat();
+        assert!(watchdog.check(t0 + Duration::from_secs(40)).is_empty());
+    }
+
+    #[test]
+    fn test_notify_datagram() {
+        let path = std::env::temp_dir().join(format!("rust-hft-notify-{}", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+        let listener = UnixDatagram::bind(&path).unwrap();
+
+        let notifier = SdNotify {
+            socket: UnixDatagram::unbound().unwrap(),
+            path: path.to_string_lossy().into_owned(),
+        };
+        notifier.notify("WATCHDOG=1").unwrap();
+
+        let mut buf = [0u8; 32];
+        let n = listener.recv(&mut buf).unwrap();
+        assert_eq!(&buf[..n], b"WATCHDOG=1");
+        std::fs::remove_file(&path).unwrap();
+    }
+}
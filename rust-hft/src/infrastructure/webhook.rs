@@ -0,0 +1,354 @@
+//! Opportunity webhooks (Cold Path)
+//!
+//! Signal-only users get opportunity open/close alerts POSTed as JSON to
+//! their own URLs. The engine sends [`Alert`]s over a channel (dropping them
+//! if the sink lags) and [`run_sink`] delivers each to every configured
+//! target in order. A failed delivery (transport error or non-2xx status) is
+//! retried with exponential backoff, then dropped with a warning.
+//!
+//! Each target is a rate-limited alert channel (see [`super::alert_limit`]):
+//! a payload sent after suppressed ones carries their count, and counts left
+//! over after a quiet interval are posted as a `suppressed` summary.
+//!
+//! Targets with a `secret_env` sign the body: `X-Signature` carries the hex
+//! HMAC-SHA256 of the exact body bytes under that environment variable.
+
+use crate::core::clock;
+use crate::engine::Alert;
+use crate::infrastructure::alert_limit::{Admission, AlertLimiter, SuppressedSummary};
+use crate::infrastructure::config::WebhookConfig;
+use crate::rest::RequestSigner;
+use serde::Serialize;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+
+/// Capacity of the engine → webhook sink channel
+pub const WEBHOOK_QUEUE_CAPACITY: usize = 256;
+
+/// Header carrying the body signature
+pub const SIGNATURE_HEADER: &str = "X-Signature";
+
+/// Webhook body
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct WebhookPayload {
+    /// "opened" or "closed"
+    pub event: &'static str,
+    pub symbol: String,
+    pub long_exchange: String,
+    pub short_exchange: String,
+    /// Spread at open, peak spread at close (fraction)
+    pub spread: f64,
+    /// Milliseconds since epoch (episode end for closes)
+    pub timestamp_ms: u64,
+    /// Episode length (closes only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub duration_ms: Option<u64>,
+    /// An execution attempt ran out of its budget (closes only)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub aborted: Option<bool>,
+    /// Similar alerts suppressed on this target since the last one sent
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub suppressed: Option<u64>,
+}
+
+/// Summary of alerts suppressed on a target
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SuppressedPayload {
+    /// Always "suppressed"
+    pub event: &'static str,
+    /// Suppressed alert kind ("opened" or "closed")
+    pub kind: &'static str,
+    pub count: u64,
+    /// First suppression, milliseconds since epoch
+    pub since_ms: u64,
+}
+
+impl SuppressedPayload {
+    fn new(summary: &SuppressedSummary, now: Instant) -> Self {
+        let ago = now.saturating_duration_since(summary.since).as_millis() as u64;
+        Self {
+            event: "suppressed",
+            kind: summary.kind,
+            count: summary.count,
+            since_ms: clock::coarse_unix_ms().saturating_sub(ago),
+        }
+    }
+}
+
+impl WebhookPayload {
+    /// Payload for an open or close alert (None for conflated updates)
+    pub fn from_alert(alert: &Alert) -> Option<Self> {
+        match alert {
+            Alert::Opened(event) => Some(Self {
+                event: "opened",
+                symbol: event.symbol.as_str().to_string(),
+                long_exchange: event.long_ex.name().to_string(),
+                short_exchange: event.short_ex.name().to_string(),
+                spread: event.spread.fraction_f64(),
+                timestamp_ms: event.timestamp / 1_000_000,
+                duration_ms: None,
+                aborted: None,
+                suppressed: None,
+            }),
+            Alert::Update { .. } => None,
+            Alert::Closed(episode) => Some(Self {
+                event: "closed",
+                symbol: episode.symbol.as_str().to_string(),
+                long_exchange: episode.long_ex.name().to_string(),
+                short_exchange: episode.short_ex.name().to_string(),
+                spread: episode.peak_spread.fraction_f64(),
+                timestamp_ms: episode.end / 1_000_000,
+                duration_ms: Some(episode.end.saturating_sub(episode.start) / 1_000_000),
+                aborted: Some(episode.aborted),
+                suppressed: None,
+            }),
+        }
+    }
+}
+
+/// One configured endpoint
+#[derive(Debug, Clone)]
+pub struct WebhookTarget {
+    url: String,
+    /// Body signer (None = unsigned)
+    signer: Option<RequestSigner>,
+    max_retries: u32,
+    initial_backoff: Duration,
+}
+
+impl WebhookTarget {
+    /// Target from config, reading the signing secret from `secret_env`
+    ///
+    /// A configured but unset secret is an error rather than a silently
+    /// unsigned webhook.
+    pub fn from_config(config: &WebhookConfig) -> crate::Result<Self> {
+        let signer = match &config.secret_env {
+            Some(var) => {
+                let secret = std::env::var(var).ok().filter(|s| !s.is_empty()).ok_or_else(|| {
+                    crate::HftError::Config(format!("Webhook secret {} is not set", var))
+                })?;
+                Some(RequestSigner::new(String::new(), secret))
+            }
+            None => None,
+        };
+        Ok(Self {
+            url: config.url.clone(),
+            signer,
+            max_retries: config.max_retries,
+            initial_backoff: Duration::from_millis(config.initial_backoff_ms),
+        })
+    }
+
+    #[inline]
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Delay before retry `attempt` (1-based)
+    fn backoff(&self, attempt: u32) -> Duration {
+        self.initial_backoff.saturating_mul(1 << (attempt - 1).min(16))
+    }
+
+    /// Serialize and deliver `payload`; true once delivered
+    async fn post(&self, client: &reqwest::Client, payload: &(impl Serialize + std::fmt::Debug)) -> bool {
+        match serde_json::to_string(payload) {
+            Ok(body) => self.deliver(client, &body).await,
+            Err(e) => {
+                tracing::warn!("Failed to serialize webhook {:?}: {}", payload, e);
+                false
+            }
+        }
+    }
+
+    /// POST `body`, retrying failures; true once delivered
+    async fn deliver(&self, client: &reqwest::Client, body: &str) -> bool {
+        for attempt in 0..=self.max_retries {
+            if attempt > 0 {
+                tokio::time::sleep(self.backoff(attempt)).await;
+            }
+            let mut request = client
+                .post(&self.url)
+                .header(reqwest::header::CONTENT_TYPE, "application/json")
+                .body(body.to_string());
+            if let Some(signer) = &self.signer {
+                request = request.header(SIGNATURE_HEADER, signer.sign(body));
+            }
+            match request.send().await {
+                Ok(response) if response.status().is_success() => return true,
+                Ok(response) => tracing::debug!("Webhook {} attempt {} returned {}", self.url, attempt + 1, response.status()),
+                Err(e) => tracing::debug!("Webhook {} attempt {} failed: {}", self.url, attempt + 1, e),
+            }
+        }
+        false
+    }
+}
+
+/// Targets for every configured webhook (an error if any is misconfigured)
+pub fn targets(configs: &[WebhookConfig]) -> crate::Result<Vec<WebhookTarget>> {
+    configs.iter().map(WebhookTarget::from_config).collect()
+}
+
+/// Deliver alerts from the engine until the channel closes
+///
+/// Each target is a channel of `limiter`, named by its URL.
+pub async fn run_sink(targets: Vec<WebhookTarget>, timeout: Duration, limiter: Arc<AlertLimiter>, mut rx: mpsc::Receiver<Alert>) {
+    let client = match reqwest::Client::builder().timeout(timeout).build() {
+        Ok(client) => client,
+        Err(e) => {
+            tracing::error!("Webhook sink disabled, HTTP client failed: {}", e);
+            return;
+        }
+    };
+    let mut summaries = tokio::time::interval(limiter.summary_interval().max(Duration::from_secs(1)));
+    loop {
+        tokio::select! {
+            alert = rx.recv() => {
+                let Some(alert) = alert else { break };
+                let Some(payload) = WebhookPayload::from_alert(&alert) else { continue };
+                for target in &targets {
+                    let Admission::Send { suppressed } = limiter.admit(&target.url, payload.event, Instant::now()) else {
+                        continue;
+                    };
+                    let payload = WebhookPayload { suppressed: (suppressed > 0).then_some(suppressed), ..payload.clone() };
+                    if !target.post(&client, &payload).await {
+                        tracing::warn!(
+                            "Webhook {} dropped {} {} after {} retries",
+                            target.url,
+                            payload.event,
+                            payload.symbol,
+                            target.max_retries
+                        );
+                    }
+                }
+            }
+            _ = summaries.tick() => {
+                for target in &targets {
+                    let now = Instant::now();
+                    for summary in limiter.take_summaries(&target.url, now) {
+                        target.post(&client, &SuppressedPayload::new(&summary, now)).await;
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::{Bps, Symbol};
+    use crate::engine::Episode;
+    use crate::exchanges::Exchange;
+    use crate::hot_path::SpreadEvent;
+    use crate::test_utils::init_test_registry;
+    use tokio::io::{AsyncReadExt, AsyncWriteExt};
+
+    #[test]
+    fn test_payload_from_alerts() {
+        init_test_registry();
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+        let event = SpreadEvent {
+            symbol,
+            spread: Bps::from_bps(30),
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            timestamp: 1_700_000_000_000_000_000,
+            spread_ticks: None,
+        };
+        let opened = WebhookPayload::from_alert(&Alert::Opened(event)).unwrap();
+        assert_eq!((opened.event, opened.symbol.as_str(), opened.timestamp_ms), ("opened", "BTCUSDT", 1_700_000_000_000));
+        assert!((opened.spread - 0.003).abs() < 1e-12);
+        let json = serde_json::to_string(&opened).unwrap();
+        assert!(json.contains(r#""longExchange":"binance""#) && !json.contains("durationMs"), "{}", json);
+
+        let update = Alert::Update { event, peak: Bps::from_bps(40), conflated: 3 };
+        assert_eq!(WebhookPayload::from_alert(&update), None);
+
+        let episode = Episode {
+            id: 1,
+            symbol,
+            long_ex: Exchange::Binance,
+            short_ex: Exchange::Bybit,
+            start: 1_000_000_000,
+            end: 3_500_000_000,
+            peak_spread: Bps::from_bps(40),
+            updates: 6,
+            aborted: true,
+        };
+        let closed = WebhookPayload::from_alert(&Alert::Closed(episode)).unwrap();
+        assert_eq!((closed.event, closed.timestamp_ms, closed.duration_ms, closed.aborted), ("closed", 3_500, Some(2_500), Some(true)));
+    }
+
+    /// Accept `statuses.len()` requests, answering each with the next status; returns the raw requests
+    async fn serve(listener: tokio::net::TcpListener, statuses: Vec<u16>) -> Vec<String> {
+        let mut requests = Vec::new();
+        for status in statuses {
+            let (mut stream, _) = listener.accept().await.unwrap();
+            let mut buf = vec![0u8; 8192];
+            let mut len = 0;
+            // Headers, then a body of Content-Length bytes
+            loop {
+                len += stream.read(&mut buf[len..]).await.unwrap();
+                let text = String::from_utf8_lossy(&buf[..len]).to_string();
+                if let Some(end) = text.find("\r\n\r\n") {
+                    let body_len = text
+                        .lines()
+                        .find_map(|l| l.to_ascii_lowercase().strip_prefix("content-length: ").map(|v| v.trim().parse::<usize>().unwrap()))
+                        .unwrap_or(0);
+                    if len >= end + 4 + body_len {
+                        requests.push(text);
+                        break;
+                    }
+                }
+            }
+            let response = format!("HTTP/1.1 {} X\r\ncontent-length: 0\r\nconnection: close\r\n\r\n", status);
+            stream.write_all(response.as_bytes()).await.unwrap();
+        }
+        requests
+    }
+
+    #[tokio::test]
+    async fn test_sink_signs_and_retries() {
+        init_test_registry();
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}/hook", listener.local_addr().unwrap());
+        let server = tokio::spawn(serve(listener, vec![500, 200]));
+
+        std::env::set_var("TEST_WEBHOOK_SECRET", "s3cret");
+        let config = WebhookConfig {
+            url,
+            secret_env: Some("TEST_WEBHOOK_SECRET".to_string()),
+            max_retries: 2,
+            initial_backoff_ms: 10,
+        };
+        let targets = targets(std::slice::from_ref(&config)).unwrap();
+        let (tx, rx) = mpsc::channel(4);
+        let sink = tokio::spawn(run_sink(targets, Duration::from_secs(5), Arc::new(AlertLimiter::default()), rx));
+
+        let event = SpreadEvent {
+            symbol: Symbol::from_bytes(b"ETHUSDT").unwrap(),
+            spread: Bps::from_bps(25),
+            long_ex: Exchange::Bybit,
+            short_ex: Exchange::Binance,
+            timestamp: 0,
+            spread_ticks: None,
+        };
+        tx.send(Alert::Opened(event)).await.unwrap();
+        drop(tx);
+        sink.await.unwrap();
+
+        let requests = server.await.unwrap();
+        assert_eq!(requests.len(), 2);
+        let last = &requests[1];
+        let body = &last[last.find("\r\n\r\n").unwrap() + 4..];
+        assert!(body.contains(r#""event":"opened""#) && body.contains(r#""symbol":"ETHUSDT""#), "{}", body);
+        let signature = RequestSigner::new("", "s3cret").sign(body);
+        assert!(last.to_ascii_lowercase().contains(&format!("x-signature: {}", signature)), "{}", last);
+
+        let unset = WebhookConfig { secret_env: Some("TEST_WEBHOOK_SECRET_UNSET".to_string()), ..config };
+        assert!(WebhookTarget::from_config(&unset).is_err());
+    }
+}
@@ -13,6 +13,23 @@ pub mod engine;
 #[cfg(test)]
 pub mod test_utils;
 
+#[cfg(feature = "alloc-tracking")]
+#[global_allocator]
+static ALLOCATOR: infrastructure::alloc_tracking::TrackingAllocator =
+    infrastructure::alloc_tracking::TrackingAllocator;
+
+/// Mark the enclosing function as hot path (must not allocate)
+///
+/// With feature `alloc-tracking`, panics in debug builds if the function
+/// allocates before returning. Expands to nothing otherwise.
+#[macro_export]
+macro_rules! hot_path_guard {
+    ($name:expr) => {
+        #[cfg(feature = "alloc-tracking")]
+        let _hot_path_guard = $crate::infrastructure::alloc_tracking::HotPathGuard::enter($name);
+    };
+}
+
 // Re-export commonly used types
 pub use infrastructure::config::{Config, HftConfig, ApiConfig};
 
@@ -8,16 +8,25 @@
 //! - **rest**: REST API clients
 //! - **infrastructure**: Cold path (logging, metrics, config, api)
 
-#![feature(portable_simd)]
-#![allow(incomplete_features)]
-
-use rust_hft::hot_path::ThresholdTracker;
-use rust_hft::infrastructure::{start_server, metrics::MetricsCollector, config::Config, logging};
-use rust_hft::engine::AppEngine;
-use rust_hft::exchanges::{BinanceWsClient, BybitWsClient, ExchangeClient};
-use rust_hft::core::{Symbol, SymbolDiscovery, SymbolRegistry};
+use rust_hft::hot_path::{CostModel, QuoteSanity, ThresholdPolicy, ThresholdTracker, TransferModel};
+use rust_hft::infrastructure::{start_server, metrics::MetricsCollector, config::Config, logging, StartupStage, SymbolPriority};
+use rust_hft::engine::{AppEngine, FailoverGuard, PeerGuard, SizingModel};
+use rust_hft::exchanges::{BinanceWsClient, BybitPrivateClient, BybitWsClient, ExchangeClient};
+use rust_hft::core::{clock, QuoteRates, Symbol, SymbolDiscovery, SymbolRegistry};
+use rust_hft::exchanges::Exchange;
+use rust_hft::infrastructure::config::{CostConfig, LeverageConfig, NetworkConfig, RatesConfig, TradingPermit, WarmupConfig, WatchdogConfig};
+use rust_hft::infrastructure::export::{self, ExportArgs};
+use rust_hft::infrastructure::history::{self, HistoryStore, HISTORY_QUEUE_CAPACITY};
+use rust_hft::infrastructure::link_auth::LinkSecret;
+use rust_hft::infrastructure::peer::{self, PEER_QUEUE_CAPACITY};
+use rust_hft::infrastructure::standby::{self, STANDBY_QUEUE_CAPACITY};
+use rust_hft::infrastructure::webhook::{self, WEBHOOK_QUEUE_CAPACITY};
+use rust_hft::infrastructure::watchdog;
+use rust_hft::rest::{leverage, FeeTierFetcher, KlineFetcher, LeverageManager};
 use rust_hft::{HftError, Result};
+use futures_util::StreamExt;
 use std::sync::Arc;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 use tokio::sync::RwLock;
 use tracing_appender::non_blocking::WorkerGuard;
 
@@ -38,63 +47,614 @@ impl HftApp {
     /// Run the main event loop
     pub async fn run(&self) -> Result<()> {
         tracing::info!("Starting HFT Arbitrage Bot...");
+
+        // 0. Time source: coarse clock ticker, optional TSC fine timer
+        {
+            let config = self.config.read().await;
+            clock::spawn_ticker(Duration::from_millis(config.hft.clock_tick_ms.max(1)));
+            if config.hft.tsc_clock {
+                if clock::enable_tsc() {
+                    tracing::info!("Fine timestamps from the TSC");
+                } else {
+                    tracing::warn!("tsc_clock set but no invariant TSC; using the OS clock");
+                }
+            }
+        }
         
         // 1. Initialize Core Components
-        let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+        let mut tracker = ThresholdTracker::new();
+        tracker.set_cost_model(CostModel::new(&self.config.read().await.costs));
+        tracker.set_transfer_model(TransferModel::new(&self.config.read().await.transfers));
+        {
+            let config = self.config.read().await;
+            tracker.set_threshold_policy(ThresholdPolicy::new(&config.thresholds, config.opportunity_threshold()));
+            tracker.set_quote_sanity(QuoteSanity::new(&config.sanity));
+        }
+        let tracker = Arc::new(RwLock::new(tracker));
         let metrics = Arc::new(MetricsCollector::new());
+        // Startup stages: config, registry and discovery complete here in
+        // order; connections, subscriptions and warm-up as the engine runs
+        metrics.startup().complete(StartupStage::Config, std::time::Instant::now())?;
+        
+        let mut engine = AppEngine::new(tracker.clone(), metrics.clone());
+        engine.set_sizing(SizingModel::new(&self.config.read().await.sizing));
+        engine.set_cost_model(CostModel::new(&self.config.read().await.costs));
+        {
+            let config = self.config.read().await;
+            engine.control().configure_symbols(&config.execution);
+            match config.trading_permit() {
+                Some(permit) => engine.set_execution_config(&config.execution, permit),
+                None => tracing::warn!("Read-only mode: no execution engine, orders are dropped"),
+            }
+            if config.execution.shadow {
+                engine.enable_shadow(config.report.enabled.then(|| config.report.dir.clone()));
+            }
+        }
+        engine.set_focus_config(&self.config.read().await.focus);
+        engine.set_alert_config(&self.config.read().await.alerts);
+
+        // Peer instance in another region (optional, Cold Path)
+        let peer_config = self.config.read().await.peer.clone();
+        if peer_config.enabled {
+            peer::validate(&peer_config)?;
+            let secret = LinkSecret::from_env(&peer_config.secret_env)?;
+            let guard = PeerGuard::new(&peer_config)?;
+            let venues = guard.local_venues();
+            let (tx, rx) = tokio::sync::mpsc::channel(PEER_QUEUE_CAPACITY);
+            engine.set_peer(guard, tx);
+            tokio::spawn(peer::run(peer_config, secret, venues, rx, engine.peer_sender()));
+        }
+
+        // Hot-standby pair (optional, Cold Path)
+        let standby_config = self.config.read().await.standby.clone();
+        if standby_config.enabled {
+            standby::validate(&standby_config)?;
+            let secret = LinkSecret::from_env(&standby_config.secret_env)?;
+            let (tx, rx) = tokio::sync::mpsc::channel(STANDBY_QUEUE_CAPACITY);
+            let snapshot_interval = Duration::from_millis(standby_config.snapshot_ms);
+            let contact = engine.set_failover(FailoverGuard::new(&standby_config, std::time::Instant::now()), tx, snapshot_interval);
+            tracing::info!("Hot standby enabled, starting as {:?}", standby_config.role);
+            tokio::spawn(standby::run(standby_config, secret, contact, rx, engine.standby_sender()));
+        }
+
+        // Opportunity webhooks (optional sink, Cold Path)
+        let alert_config = self.config.read().await.alerts.clone();
+        if !alert_config.webhooks.is_empty() {
+            let targets = webhook::targets(&alert_config.webhooks)?;
+            let (tx, rx) = tokio::sync::mpsc::channel(WEBHOOK_QUEUE_CAPACITY);
+            engine.set_webhook_sink(tx);
+            let timeout = std::time::Duration::from_millis(alert_config.webhook_timeout_ms);
+            tokio::spawn(webhook::run_sink(targets, timeout, engine.alert_limiter(), rx));
+        }
+        
+        // Historical opportunity database (optional sink, Cold Path)
+        let history_config = self.config.read().await.history.clone();
+        let history = if history_config.enabled {
+            let (store, writer) = HistoryStore::open(&history_config)?;
+            engine.resume_episode_ids(store.last_episode_id());
+            let store = Arc::new(RwLock::new(store));
+            let (tx, rx) = tokio::sync::mpsc::channel(HISTORY_QUEUE_CAPACITY);
+            engine.set_history_sink(tx);
+            metrics.debug().set_history_capacity(HISTORY_QUEUE_CAPACITY);
+            tokio::spawn(history::run_writer(store.clone(), writer, rx, metrics.clone()));
+            Some(store)
+        } else {
+            None
+        };
         
         // 2. Start API Server (Cold Path)
         let tracker_for_api = tracker.clone();
         let metrics_for_api = metrics.clone();
+        let journal_for_api = engine.journal();
+        let presence = engine.presence();
+        let presence_for_api = presence.clone();
+        let control_for_api = engine.control();
         let config_guard = self.config.read().await;
         let api_config = config_guard.api.clone();
         drop(config_guard); // Release lock early
         
         tokio::spawn(async move {
-            if let Err(e) = start_server(tracker_for_api, metrics_for_api, &api_config).await {
+            if let Err(e) = start_server(tracker_for_api, metrics_for_api, journal_for_api, history, presence_for_api, control_for_api, &api_config).await {
                 tracing::error!("API Server failed: {}", e);
             }
         });
         
         // 3. Start AppEngine (Hot Path)
-        let mut engine = AppEngine::new(tracker.clone(), metrics.clone());
+        
+        // Strategies selected from config
+        let strategy_config = self.config.read().await.strategies.clone();
+        #[cfg(feature = "plugins")]
+        {
+            let registry = rust_hft::engine::plugins::StrategyRegistry::linked();
+            tracing::info!("Strategy plugins: {:?}", registry.names().collect::<Vec<_>>());
+            for strategy in registry.build_enabled(&strategy_config)? {
+                engine.add_strategy(strategy);
+            }
+            for (strategy, budget) in registry.build_instances(&strategy_config)? {
+                engine.add_strategy_instance(strategy, budget)?;
+            }
+        }
+        #[cfg(not(feature = "plugins"))]
+        if !strategy_config.enabled.is_empty() || !strategy_config.instances.is_empty() {
+            tracing::warn!(
+                "Strategies {:?} and {} instances configured but binary built without `plugins` feature",
+                strategy_config.enabled,
+                strategy_config.instances.len()
+            );
+        }
         
         // Add exchanges
-        engine.add_exchange(ExchangeClient::Binance(BinanceWsClient::new()));
-        engine.add_exchange(ExchangeClient::Bybit(BybitWsClient::new()));
+        let network = self.config.read().await.network.clone();
+        if network.is_bound() {
+            tracing::info!(
+                "Binding outbound connections: v4={:?} v6={:?} prefer_ipv6={}",
+                network.bind_ipv4,
+                network.bind_ipv6,
+                network.prefer_ipv6
+            );
+        }
+        let tuning = {
+            let config = self.config.read().await;
+            engine.set_supervision_config(&config.supervision);
+            engine.set_queue_capacity(config.hft.engine_queue_capacity);
+            engine.set_stall_budget(std::time::Duration::from_millis(config.hft.stall_budget_ms));
+            config.hft.feed_tuning()
+        };
+        let binance_network = network.clone();
+        let binance_unparsed = metrics.debug().unparsed().clone();
+        let binance_invariants = metrics.debug().invariants().clone();
+        engine.add_exchange(move || {
+            ExchangeClient::Binance(
+                BinanceWsClient::new()
+                    .with_network(binance_network.clone())
+                    .with_tuning(tuning)
+                    .with_quarantine(binance_unparsed.clone())
+                    .with_invariants(binance_invariants.clone()),
+            )
+        });
+        let bybit_network = network.clone();
+        let bybit_unparsed = metrics.debug().unparsed().clone();
+        let bybit_invariants = metrics.debug().invariants().clone();
+        engine.add_exchange(move || {
+            ExchangeClient::Bybit(
+                BybitWsClient::new()
+                    .with_network(bybit_network.clone())
+                    .with_tuning(tuning)
+                    .with_quarantine(bybit_unparsed.clone())
+                    .with_invariants(bybit_invariants.clone()),
+            )
+        });
         
+        // Quote currency rates: volumes, sizing and PnL in USDT
+        let rates_config = self.config.read().await.rates.clone();
+        let rates = Arc::new(QuoteRates::new(&rates_config));
+        engine.set_rates(rates.clone());
+
         // 4. Discover liquid symbols dynamically (Cold Path - startup only)
         tracing::info!("Discovering liquid symbols from exchanges...");
         
         // Step 1: Fetch symbol names
-        let discovery = SymbolDiscovery::new();
+        let discovery = SymbolDiscovery::with_network(&network).with_rates(rates.clone());
+        if rates_config.enabled {
+            refresh_rates(&discovery, &rates, rates_config.max_depeg).await;
+        }
         let names = discovery.fetch_symbol_names().await
             .map_err(|e| HftError::RestApi(format!("Failed to fetch symbol names: {}", e)))?;
         tracing::info!("Fetched {} symbol names", names.len());
         
-        // Step 2: Register symbols in global registry
-        SymbolRegistry::initialize(&names)
-            .map_err(|e| HftError::Config(format!("Failed to initialize symbol registry: {}", e)))?;
+        // Step 2: Register symbols in global registry (overflow is dropped with a warning)
+        SymbolRegistry::initialize_trimmed(&names);
+        metrics.startup().complete(StartupStage::Registry, std::time::Instant::now())?;
         
+        // Private account stream: order acks, fills and stream health (Cold Path)
+        // Started after the registry is filled: events name symbols through it
+        match BybitPrivateClient::from_env() {
+            Some(client) => {
+                let client = client
+                    .with_network(network.clone())
+                    .with_tuning(tuning)
+                    .with_quarantine(metrics.debug().unparsed().clone());
+                tokio::spawn(client.run(engine.user_stream_sender()));
+            }
+            None => tracing::info!("No Bybit API credentials, private stream disabled"),
+        }
+
         // Step 3: Fetch full data with registered symbols
-        let discovered = discovery.fetch_all_liquid().await
+        let listings = discovery.fetch_liquid_by_venue().await
             .map_err(|e| HftError::RestApi(format!("Failed to fetch liquid symbols: {}", e)))?;
+        for exchange in Exchange::ALL {
+            let listed: Vec<Symbol> = listings.iter()
+                .filter(|d| d.exchange == exchange)
+                .map(|d| d.symbol)
+                .collect();
+            presence.set_listed(exchange, &listed);
+        }
         
-        let symbols: Vec<Symbol> = discovered.into_iter()
+        // Historically productive symbols first, then by volume
+        let priority_config = self.config.read().await.priority.clone();
+        let mut priority = SymbolPriority::default();
+        let mut listings = SymbolDiscovery::unique(listings);
+        if priority_config.enabled {
+            priority = SymbolPriority::load(&priority_config.path);
+            priority.prioritize(&mut listings, |d| d.symbol);
+            tracing::info!("Prioritized {} symbols by historical opportunity rate", priority.len());
+        }
+        let symbols: Vec<Symbol> = listings.into_iter()
             .map(|d| d.symbol)
             .take(200)  // Increased from 50 to 200 symbols
             .collect();
         tracing::info!("Discovered {} liquid symbols", symbols.len());
+        {
+            // Tracker state for productive symbols before their first ticker
+            let mut tracker = tracker.write().await;
+            for &symbol in symbols.iter().filter(|s| priority.rate(s.as_str()) > 0.0) {
+                tracker.state_or_insert(symbol);
+            }
+        }
+        
+        // Tick sizes for tick-normalized spreads (Cold Path - startup only)
+        {
+            let ticks = discovery.fetch_tick_sizes().await;
+            let mut tracker = tracker.write().await;
+            for &(symbol, exchange, tick) in &ticks {
+                tracker.set_tick_size(symbol, exchange, tick);
+            }
+            tracing::info!("Loaded {} tick sizes", ticks.len());
+        }
+        metrics.startup().complete(StartupStage::Discovery, std::time::Instant::now())?;
         
-        // Run engine (this blocks the task)
-        engine.run(&symbols).await?;
+        // 5. Pre-seed rolling windows from recent klines (Cold Path - startup only)
+        let warmup = self.config.read().await.warmup.clone();
+        if warmup.enabled {
+            warm_up(&tracker, &symbols, &network, &warmup).await;
+        }
+        
+        // 6. Periodic instruments refresh: delisting and halt detection (Cold Path)
+        let delisting = self.config.read().await.delisting.clone();
+        if delisting.enabled {
+            let notices = engine.delisting_sender();
+            let status_notices = engine.status_sender();
+            tokio::spawn(async move {
+                let horizon_ms = delisting.horizon_hours.saturating_mul(3_600_000);
+                let mut interval = tokio::time::interval(Duration::from_secs(delisting.refresh_secs.max(1)));
+                loop {
+                    interval.tick().await;
+                    let (delistings, statuses) = discovery.fetch_instrument_notices(unix_millis(), horizon_ms).await;
+                    for notice in delistings {
+                        if notices.send(notice).await.is_err() {
+                            return; // Engine stopped
+                        }
+                    }
+                    for notice in statuses {
+                        if status_notices.send(notice).await.is_err() {
+                            return;
+                        }
+                    }
+                }
+            });
+        }
+        
+        // Stablecoin rate refresh (Cold Path, periodic)
+        if rates_config.enabled {
+            start_rate_refresh(&rates, &network, &rates_config);
+        }
+
+        // Idle symbol eviction (Warm Path state, periodic)
+        let idle_evict_secs = self.config.read().await.hft.idle_evict_secs;
+        if idle_evict_secs > 0 {
+            start_idle_eviction(&tracker, Duration::from_secs(idle_evict_secs));
+        }
+
+        // 7. Account fee tier detection (Cold Path, periodic)
+        let costs = self.config.read().await.costs.clone();
+        if costs.detect_fees {
+            start_fee_detection(&tracker, &network, &costs);
+        }
         
-        Ok(())
+        // Leverage and margin mode management (Cold Path, periodic)
+        let leverage = self.config.read().await.leverage.clone();
+        if leverage.enabled {
+            match self.config.read().await.trading_permit() {
+                Some(permit) => start_leverage_management(&leverage, &network, permit),
+                None => tracing::warn!("Read-only mode: leverage management disabled"),
+            }
+        }
+        
+        // 8. systemd watchdog: ping only while engine, tracker and API progress
+        let watchdog = self.config.read().await.watchdog.clone();
+        let api_port = self.config.read().await.api.port;
+        if watchdog.enabled {
+            start_watchdog(&mut engine, &tracker, api_port, &watchdog);
+        }
+        
+        // Warm-up gate starts now: execution disabled until windows fill
+        engine.set_warmup_config(&self.config.read().await.warmup);
+        
+        // Run engine (this blocks the task until shutdown or every feed stops)
+        let started = std::time::Instant::now();
+        let result = engine.run(&symbols).await;
+        if priority_config.enabled {
+            priority.record_session(&symbols, &engine.episode_counts(), started.elapsed().as_secs_f64() / 3600.0);
+            if let Err(e) = priority.save(&priority_config.path) {
+                tracing::warn!("Failed to save symbol priority to {}: {}", priority_config.path.display(), e);
+            }
+        }
+        
+        // Session summary for the operator
+        let report = engine.session_report().await;
+        tracing::info!("{}", report.render());
+        let report_config = self.config.read().await.report.clone();
+        if report_config.enabled {
+            match report.write_to(&report_config.dir, unix_millis()) {
+                Ok(path) => tracing::info!("Session report written to {}", path.display()),
+                Err(e) => tracing::warn!("Failed to write session report: {}", e),
+            }
+        }
+        if let Some(shadow) = engine.shadow_report() {
+            tracing::info!("{}", shadow.render());
+            if report_config.enabled {
+                match shadow.write_to(&report_config.dir) {
+                    Ok(path) => tracing::info!("Shadow report written to {}", path.display()),
+                    Err(e) => tracing::warn!("Failed to write shadow report: {}", e),
+                }
+            }
+        }
+        
+        result
     }
 }
 
+/// Seed tracker estimators from recent 1-minute klines on both exchanges
+///
+/// Failures are logged and skipped: the affected symbol simply warms up
+/// from live data.
+async fn warm_up(
+    tracker: &Arc<RwLock<ThresholdTracker>>,
+    symbols: &[Symbol],
+    network: &NetworkConfig,
+    config: &WarmupConfig,
+) {
+    let fetcher = KlineFetcher::with_network(network);
+    let fetcher = &fetcher;
+    tracing::info!("Warming up {} symbols from {} x 1m klines", symbols.len(), config.klines);
+
+    let mut results = futures_util::stream::iter(symbols.iter().copied())
+        .map(|symbol| async move {
+            let (binance, bybit) = tokio::join!(
+                fetcher.fetch_binance(symbol, config.klines),
+                fetcher.fetch_bybit(symbol, config.klines)
+            );
+            (symbol, binance, bybit)
+        })
+        .buffer_unordered(config.concurrency.max(1));
+
+    let mut seeded = 0usize;
+    while let Some((symbol, binance, bybit)) = results.next().await {
+        let now_ms = unix_millis();
+        let mut tracker = tracker.write().await;
+        let Some(state) = tracker.state_or_insert(symbol) else {
+            continue;
+        };
+        let binance = binance
+            .inspect_err(|e| tracing::warn!("Warm-up {} on Binance failed: {}", symbol.as_str(), e))
+            .unwrap_or_default();
+        let bybit = bybit
+            .inspect_err(|e| tracing::warn!("Warm-up {} on Bybit failed: {}", symbol.as_str(), e))
+            .unwrap_or_default();
+        state.seed_klines(Exchange::Binance, &binance, now_ms);
+        state.seed_klines(Exchange::Bybit, &bybit, now_ms);
+        if config.seed_spread {
+            state.seed_spread(&binance, &bybit, now_ms);
+        }
+        if !binance.is_empty() || !bybit.is_empty() {
+            seeded += 1;
+        }
+    }
+    tracing::info!("Warm-up seeded {}/{} symbols", seeded, symbols.len());
+}
+
+/// Spawn periodic eviction of symbols idle for `idle` on either venue
+fn start_idle_eviction(tracker: &Arc<RwLock<ThresholdTracker>>, idle: Duration) {
+    let tracker = tracker.clone();
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval((idle / 4).max(Duration::from_secs(1)));
+        loop {
+            interval.tick().await;
+            let mut tracker = tracker.write().await;
+            let evicted = tracker.evict_idle(idle, clock::now());
+            if evicted > 0 {
+                tracing::info!(
+                    "Evicted {} idle symbols ({} live, ~{} KiB tracker state)",
+                    evicted,
+                    tracker.occupancy(),
+                    tracker.memory_bytes() / 1024
+                );
+            }
+        }
+    });
+}
+
+/// Apply fetched stablecoin rates; rates off par are logged and ignored
+async fn refresh_rates(discovery: &SymbolDiscovery, rates: &QuoteRates, max_depeg: f64) {
+    for (currency, rate) in discovery.fetch_quote_rates().await {
+        if rates.update(currency, rate, unix_millis()) {
+            tracing::debug!("{} rate {} USDT", currency.name(), rate);
+        } else {
+            tracing::warn!("Ignoring {} rate {} USDT (more than {} off par)", currency.name(), rate, max_depeg);
+        }
+    }
+}
+
+/// Spawn periodic stablecoin rate refresh (a failed refresh keeps the last rate)
+fn start_rate_refresh(rates: &Arc<QuoteRates>, network: &NetworkConfig, config: &RatesConfig) {
+    let rates = rates.clone();
+    let discovery = SymbolDiscovery::with_network(network);
+    let max_depeg = config.max_depeg;
+    let period = Duration::from_secs(config.refresh_secs.max(1));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval_at(tokio::time::Instant::now() + period, period);
+        loop {
+            interval.tick().await;
+            refresh_rates(&discovery, &rates, max_depeg).await;
+        }
+    });
+}
+
+/// Spawn periodic fee tier detection feeding the tracker's cost model
+///
+/// Venues without credentials keep the configured taker fee. A failed
+/// refresh keeps the last known rate.
+fn start_fee_detection(tracker: &Arc<RwLock<ThresholdTracker>>, network: &NetworkConfig, config: &CostConfig) {
+    let fetcher = FeeTierFetcher::from_env(network);
+    let venues = fetcher.venues();
+    if venues.is_empty() {
+        tracing::info!("No exchange API credentials, using configured fees");
+        return;
+    }
+    let configured = CostModel::new(config);
+    let tracker = tracker.clone();
+    let refresh = Duration::from_secs(config.fee_refresh_secs.max(60));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(refresh);
+        loop {
+            interval.tick().await;
+            for &exchange in &venues {
+                match fetcher.fetch(exchange).await {
+                    Ok(tier) => {
+                        let (taker, maker) = (tier.effective_taker(), tier.effective_maker());
+                        let mut tracker = tracker.write().await;
+                        let costs = tracker.cost_model_mut();
+                        if costs.taker_fee(exchange) != taker || costs.maker_fee(exchange) != maker {
+                            tracing::info!(
+                                "{} fee tier {}: taker {} maker {} (configured {} / {})",
+                                exchange.name(),
+                                tier.tier,
+                                taker,
+                                maker,
+                                configured.taker_fee(exchange),
+                                configured.maker_fee(exchange)
+                            );
+                        }
+                        costs.set_taker_fee(exchange, taker);
+                        costs.set_maker_fee(exchange, maker);
+                    }
+                    Err(e) => tracing::warn!("{} fee tier detection failed: {}", exchange.name(), e),
+                }
+            }
+        }
+    });
+}
+
+/// Spawn periodic leverage / margin mode enforcement
+///
+/// Each pass sets the configured values and reads them back; a venue
+/// reporting something else (e.g. leverage capped by its risk limit) raises
+/// an alert. Failed calls are retried on the next pass.
+fn start_leverage_management(config: &LeverageConfig, network: &NetworkConfig, permit: TradingPermit) {
+    let manager = LeverageManager::from_env(network, permit);
+    let targets = leverage::targets(config, &manager.venues());
+    if targets.is_empty() {
+        tracing::info!("No managed leverage symbols with exchange credentials");
+        return;
+    }
+    let refresh = Duration::from_secs(config.refresh_secs.max(60));
+    tokio::spawn(async move {
+        let mut interval = tokio::time::interval(refresh);
+        loop {
+            interval.tick().await;
+            let mut verified = 0;
+            for target in &targets {
+                match manager.apply(target).await {
+                    Ok(reported) if reported == target.setting => verified += 1,
+                    Ok(reported) => tracing::error!(
+                        "ALERT: {} {} reports {:?} {}x, requested {:?} {}x",
+                        target.exchange.name(),
+                        target.symbol,
+                        reported.margin_mode,
+                        reported.leverage,
+                        target.setting.margin_mode,
+                        target.setting.leverage
+                    ),
+                    Err(e) => tracing::warn!("{} {} leverage update failed: {}", target.exchange.name(), target.symbol, e),
+                }
+            }
+            tracing::info!("Leverage verified for {}/{} symbol venues", verified, targets.len());
+        }
+    });
+}
+
+/// Register critical tasks and spawn the systemd watchdog loop
+///
+/// No-op unless started by systemd (`$NOTIFY_SOCKET`). The tracker probe
+/// bumps its heartbeat only after acquiring the tracker lock; the API probe
+/// only after a successful liveness request.
+fn start_watchdog(
+    engine: &mut AppEngine,
+    tracker: &Arc<RwLock<ThresholdTracker>>,
+    api_port: u16,
+    config: &WatchdogConfig,
+) {
+    let Some(notifier) = watchdog::SdNotify::from_env() else {
+        tracing::debug!("NOTIFY_SOCKET not set, systemd watchdog disabled");
+        return;
+    };
+    let stall = Duration::from_secs(config.stall_secs.max(1));
+    let interval = watchdog::SdNotify::ping_interval();
+    let mut dog = watchdog::Watchdog::new(stall);
+    engine.set_heartbeat(dog.register("engine"));
+
+    let tracker_beat = dog.register("tracker");
+    let tracker = tracker.clone();
+    tokio::spawn(async move {
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            let _guard = tracker.read().await;
+            tracker_beat.beat();
+        }
+    });
+
+    let api_beat = dog.register("api");
+    tokio::spawn(async move {
+        let client = reqwest::Client::builder()
+            .timeout(stall)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new());
+        let url = format!("http://127.0.0.1:{}/api/health/live", api_port);
+        let mut ticker = tokio::time::interval(interval);
+        loop {
+            ticker.tick().await;
+            if client.get(&url).send().await.is_ok_and(|r| r.status().is_success()) {
+                api_beat.beat();
+            }
+        }
+    });
+
+    tracing::info!("systemd watchdog: ping every {:?}, stall timeout {:?}", interval, stall);
+    tokio::spawn(watchdog::run(dog, notifier, interval));
+}
+
+/// Wall-clock time in Unix millis
+fn unix_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 #[tokio::main]
 async fn main() -> Result<()> {
+    // `rust-hft export ...`: offline history export, no feeds or log files
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    if args.first().is_some_and(|a| a == "export") {
+        let config = Config::load().unwrap_or_default();
+        let export = ExportArgs::parse(args.into_iter().skip(1))?;
+        let rows = export::run(&export, &config.history)?;
+        // Summary on stderr: no subscriber is set up here and stdout may carry the rows
+        eprintln!("Exported {} episodes", rows);
+        return Ok(());
+    }
+
     // Initialize centralized file logging
     // Guards must be kept alive for the duration of the program
     let _log_guards: Vec<WorkerGuard> = logging::init_logging();
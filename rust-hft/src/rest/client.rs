@@ -1,5 +1,17 @@
 //! Placeholder for REST client module
 //!
-//! Will implement HTTP client with connection pooling
+//! Will implement HTTP client with connection pooling. Order placement needs
+//! a [`TradingPermit`], so no trading client can exist in read-only mode.
 
-pub struct RestClient;
+use crate::infrastructure::config::TradingPermit;
+
+pub struct RestClient {
+    _permit: TradingPermit,
+}
+
+impl RestClient {
+    /// Create trading client (requires a permit from config)
+    pub fn new(permit: TradingPermit) -> Self {
+        Self { _permit: permit }
+    }
+}
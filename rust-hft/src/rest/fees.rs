@@ -0,0 +1,249 @@
+//! Account fee tier detection (Cold Path)
+//!
+//! Reads the account's actual maker/taker rates, VIP level and fee discounts
+//! from private REST endpoints so the break-even cost model follows the
+//! account instead of config values that silently go stale. Called at
+//! startup and then periodically - NOT in hot path.
+//!
+//! Rates are queried for a reference symbol; both venues apply the account
+//! tier uniformly across USDT perpetuals.
+
+use crate::core::FixedPoint8;
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::NetworkConfig;
+use crate::rest::klines::RestError;
+use crate::rest::models::{
+    BinanceAccountTier, BinanceCommissionRate, BinanceFeeBurn, BybitApiKeyInfo, BybitFeeRate, BybitList,
+    BybitResponse, FeeTier,
+};
+use crate::rest::signing::{RequestSigner, RECV_WINDOW_MS};
+use serde::de::DeserializeOwned;
+use std::net::IpAddr;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Binance USDⓈ-M futures REST base
+pub const BINANCE_FAPI_URL: &str = "https://fapi.binance.com";
+
+/// Bybit V5 REST base
+pub const BYBIT_API_URL: &str = "https://api.bybit.com";
+
+/// Symbol whose rates stand for the account tier
+const REFERENCE_SYMBOL: &str = "BTCUSDT";
+
+/// Fees paid with the BNB discount enabled (10% off on USDⓈ-M futures)
+const BNB_DISCOUNT_FACTOR: FixedPoint8 = FixedPoint8::from_raw(90_000_000);
+
+/// Private fee tier client
+pub struct FeeTierFetcher {
+    client: reqwest::Client,
+    binance: Option<RequestSigner>,
+    bybit: Option<RequestSigner>,
+    binance_url: String,
+    bybit_url: String,
+}
+
+impl FeeTierFetcher {
+    /// Create fetcher with credentials from `BINANCE_API_*` / `BYBIT_API_*`
+    pub fn from_env(network: &NetworkConfig) -> Self {
+        Self::new(network, RequestSigner::from_env("BINANCE"), RequestSigner::from_env("BYBIT"))
+    }
+
+    /// Create fetcher; a venue without credentials is skipped
+    pub fn new(network: &NetworkConfig, binance: Option<RequestSigner>, bybit: Option<RequestSigner>) -> Self {
+        Self {
+            client: Self::build_client(network.rest_local_address()),
+            binance,
+            bybit,
+            binance_url: BINANCE_FAPI_URL.to_string(),
+            bybit_url: BYBIT_API_URL.to_string(),
+        }
+    }
+
+    fn build_client(local_address: Option<IpAddr>) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent("rust-hft/0.1")
+            .local_address(local_address)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new())
+    }
+
+    /// Override REST bases (mock servers, alternative hosts)
+    pub fn with_endpoints(mut self, binance_url: impl Into<String>, bybit_url: impl Into<String>) -> Self {
+        self.binance_url = binance_url.into();
+        self.bybit_url = bybit_url.into();
+        self
+    }
+
+    /// Venues with credentials
+    pub fn venues(&self) -> Vec<Exchange> {
+        let mut venues = Vec::new();
+        if self.binance.is_some() {
+            venues.push(Exchange::Binance);
+        }
+        if self.bybit.is_some() {
+            venues.push(Exchange::Bybit);
+        }
+        venues
+    }
+
+    /// Fetch fee tier for `exchange`
+    pub async fn fetch(&self, exchange: Exchange) -> Result<FeeTier, RestError> {
+        match exchange {
+            Exchange::Binance => self.fetch_binance().await,
+            Exchange::Bybit => self.fetch_bybit().await,
+        }
+    }
+
+    /// Binance: commission rate, fee tier and BNB fee burn
+    ///
+    /// API: GET /fapi/v1/commissionRate, /fapi/v2/account, /fapi/v1/feeBurn (signed)
+    pub async fn fetch_binance(&self) -> Result<FeeTier, RestError> {
+        let signer = self.binance.as_ref().ok_or_else(|| RestError::Api("no Binance credentials".to_string()))?;
+        let rate: BinanceCommissionRate = self
+            .binance_get(signer, "/fapi/v1/commissionRate", &format!("symbol={}", REFERENCE_SYMBOL))
+            .await?;
+        let account: BinanceAccountTier = self.binance_get(signer, "/fapi/v2/account", "").await?;
+        let burn: BinanceFeeBurn = self.binance_get(signer, "/fapi/v1/feeBurn", "").await?;
+
+        Ok(FeeTier {
+            exchange: Exchange::Binance,
+            tier: account.fee_tier.to_string(),
+            maker: rate.maker_commission_rate,
+            taker: rate.taker_commission_rate,
+            discount_factor: if burn.fee_burn { BNB_DISCOUNT_FACTOR } else { FixedPoint8::ONE },
+        })
+    }
+
+    /// Bybit: linear fee rate and VIP level
+    ///
+    /// API: GET /v5/account/fee-rate, /v5/user/query-api (signed)
+    pub async fn fetch_bybit(&self) -> Result<FeeTier, RestError> {
+        let signer = self.bybit.as_ref().ok_or_else(|| RestError::Api("no Bybit credentials".to_string()))?;
+        let rates: BybitList<BybitFeeRate> = self
+            .bybit_get(signer, "/v5/account/fee-rate", &format!("category=linear&symbol={}", REFERENCE_SYMBOL))
+            .await?;
+        let rate = rates
+            .list
+            .into_iter()
+            .next()
+            .ok_or_else(|| RestError::Parse("empty fee-rate list".to_string()))?;
+        let key: BybitApiKeyInfo = self.bybit_get(signer, "/v5/user/query-api", "").await?;
+
+        Ok(FeeTier {
+            exchange: Exchange::Bybit,
+            tier: key.vip_level,
+            maker: rate.maker_fee_rate,
+            taker: rate.taker_fee_rate,
+            discount_factor: FixedPoint8::ONE,
+        })
+    }
+
+    async fn binance_get<T: DeserializeOwned>(&self, signer: &RequestSigner, path: &str, query: &str) -> Result<T, RestError> {
+        let url = format!("{}{}?{}", self.binance_url, path, signer.binance_query(query, now_ms()));
+        let response = self
+            .client
+            .get(url)
+            .header("X-MBX-APIKEY", signer.api_key())
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(RestError::Http(response.status().as_u16()));
+        }
+        response.json().await.map_err(|e| RestError::Parse(e.to_string()))
+    }
+
+    async fn bybit_get<T: DeserializeOwned>(&self, signer: &RequestSigner, path: &str, query: &str) -> Result<T, RestError> {
+        let timestamp = now_ms();
+        let url = if query.is_empty() {
+            format!("{}{}", self.bybit_url, path)
+        } else {
+            format!("{}{}?{}", self.bybit_url, path, query)
+        };
+        let response = self
+            .client
+            .get(url)
+            .header("X-BAPI-API-KEY", signer.api_key())
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", RECV_WINDOW_MS.to_string())
+            .header("X-BAPI-SIGN", signer.bybit_signature(query, timestamp))
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(RestError::Http(response.status().as_u16()));
+        }
+        let body: BybitResponse<T> = response.json().await.map_err(|e| RestError::Parse(e.to_string()))?;
+        body.into_result().map_err(|(code, msg)| RestError::Api(format!("{}: {}", code, msg)))
+    }
+}
+
+/// Unix time in milliseconds
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use axum::{http::HeaderMap, routing::get, Router};
+
+    #[tokio::test]
+    async fn test_fetch_fee_tiers() {
+        let app = Router::new()
+            .route(
+                "/binance/fapi/v1/commissionRate",
+                get(|headers: HeaderMap| async move {
+                    assert_eq!(headers["X-MBX-APIKEY"], "bkey");
+                    r#"{"symbol":"BTCUSDT","makerCommissionRate":"0.00016","takerCommissionRate":"0.0004"}"#
+                }),
+            )
+            .route("/binance/fapi/v2/account", get(|| async { r#"{"feeTier":2,"canTrade":true}"# }))
+            .route("/binance/fapi/v1/feeBurn", get(|| async { r#"{"feeBurn":true}"# }))
+            .route(
+                "/bybit/v5/account/fee-rate",
+                get(|headers: HeaderMap| async move {
+                    assert_eq!(headers["X-BAPI-API-KEY"], "ykey");
+                    assert_eq!(headers["X-BAPI-SIGN"].len(), 64);
+                    r#"{"retCode":0,"retMsg":"OK","result":{"list":[
+                        {"symbol":"BTCUSDT","takerFeeRate":"0.00055","makerFeeRate":"0.0002"}]}}"#
+                }),
+            )
+            .route(
+                "/bybit/v5/user/query-api",
+                get(|| async { r#"{"retCode":0,"retMsg":"OK","result":{"vipLevel":"VIP-1","readOnly":1}}"# }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let fetcher = FeeTierFetcher::new(
+            &NetworkConfig::default(),
+            Some(RequestSigner::new("bkey", "bsecret")),
+            Some(RequestSigner::new("ykey", "ysecret")),
+        )
+        .with_endpoints(format!("{base}/binance"), format!("{base}/bybit"));
+        assert_eq!(fetcher.venues(), vec![Exchange::Binance, Exchange::Bybit]);
+
+        let binance = fetcher.fetch(Exchange::Binance).await.unwrap();
+        assert_eq!(binance.tier, "2");
+        assert_eq!(binance.taker, FixedPoint8::from_raw(40_000));
+        // BNB fee burn: 10% off
+        assert_eq!(binance.effective_taker(), FixedPoint8::from_raw(36_000));
+
+        let bybit = fetcher.fetch(Exchange::Bybit).await.unwrap();
+        assert_eq!(bybit.tier, "VIP-1");
+        assert_eq!(bybit.maker, FixedPoint8::from_raw(20_000));
+        assert_eq!(bybit.effective_taker(), FixedPoint8::from_raw(55_000));
+
+        let anonymous = FeeTierFetcher::new(&NetworkConfig::default(), None, None);
+        assert!(anonymous.venues().is_empty());
+        assert!(matches!(anonymous.fetch_binance().await, Err(RestError::Api(_))));
+    }
+}
@@ -0,0 +1,185 @@
+//! Kline (candle) fetcher (Cold Path)
+//!
+//! Pulls recent 1-minute klines from public market-data endpoints so rolling
+//! windows can be pre-seeded at startup instead of starting empty.
+//! Called once per symbol at startup - NOT in hot path.
+
+use crate::core::Symbol;
+use crate::infrastructure::config::NetworkConfig;
+use crate::rest::models::{BinanceKline, BybitKlines, BybitResponse, Kline};
+use std::net::IpAddr;
+use std::time::Duration;
+
+/// Binance Futures klines endpoint
+pub const BINANCE_KLINES_URL: &str = "https://fapi.binance.com/fapi/v1/klines";
+
+/// Bybit V5 klines endpoint
+pub const BYBIT_KLINES_URL: &str = "https://api.bybit.com/v5/market/kline";
+
+/// Public kline client
+pub struct KlineFetcher {
+    client: reqwest::Client,
+    binance_url: String,
+    bybit_url: String,
+}
+
+impl KlineFetcher {
+    /// Create fetcher bound to configured source address
+    pub fn with_network(network: &NetworkConfig) -> Self {
+        Self {
+            client: Self::build_client(network.rest_local_address()),
+            binance_url: BINANCE_KLINES_URL.to_string(),
+            bybit_url: BYBIT_KLINES_URL.to_string(),
+        }
+    }
+
+    fn build_client(local_address: Option<IpAddr>) -> reqwest::Client {
+        reqwest::Client::builder()
+            .timeout(Duration::from_secs(10))
+            .user_agent("rust-hft/0.1")
+            .local_address(local_address)
+            .build()
+            .unwrap_or_else(|_| reqwest::Client::new())
+    }
+
+    /// Override kline endpoints (mock servers, alternative hosts)
+    pub fn with_endpoints(mut self, binance_url: impl Into<String>, bybit_url: impl Into<String>) -> Self {
+        self.binance_url = binance_url.into();
+        self.bybit_url = bybit_url.into();
+        self
+    }
+
+    /// Fetch the last `limit` 1-minute klines from Binance Futures (oldest first)
+    ///
+    /// API: GET https://fapi.binance.com/fapi/v1/klines?symbol=..&interval=1m&limit=..
+    /// The last kline is still open.
+    pub async fn fetch_binance(&self, symbol: Symbol, limit: u16) -> Result<Vec<Kline>, RestError> {
+        let limit = limit.to_string();
+        let response = self
+            .client
+            .get(&self.binance_url)
+            .query(&[("symbol", symbol.as_str()), ("interval", "1m"), ("limit", limit.as_str())])
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(RestError::Http(response.status().as_u16()));
+        }
+
+        let rows: Vec<BinanceKline> = response
+            .json()
+            .await
+            .map_err(|e| RestError::Parse(e.to_string()))?;
+
+        Ok(rows.into_iter().map(Kline::from).collect())
+    }
+
+    /// Fetch the last `limit` 1-minute klines from Bybit V5 linear (oldest first)
+    ///
+    /// API: GET https://api.bybit.com/v5/market/kline?category=linear&symbol=..&interval=1&limit=..
+    /// Bybit returns newest first; the result is reversed.
+    pub async fn fetch_bybit(&self, symbol: Symbol, limit: u16) -> Result<Vec<Kline>, RestError> {
+        let limit = limit.to_string();
+        let response = self
+            .client
+            .get(&self.bybit_url)
+            .query(&[
+                ("category", "linear"),
+                ("symbol", symbol.as_str()),
+                ("interval", "1"),
+                ("limit", limit.as_str()),
+            ])
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+
+        if !response.status().is_success() {
+            return Err(RestError::Http(response.status().as_u16()));
+        }
+
+        let body: BybitResponse<BybitKlines> = response
+            .json()
+            .await
+            .map_err(|e| RestError::Parse(e.to_string()))?;
+        let result = body
+            .into_result()
+            .map_err(|(code, msg)| RestError::Api(format!("{}: {}", code, msg)))?;
+
+        let mut klines: Vec<Kline> = result.list.into_iter().map(Kline::from).collect();
+        klines.sort_by_key(|k| k.open_time);
+        Ok(klines)
+    }
+}
+
+/// REST errors
+#[derive(Debug, thiserror::Error)]
+pub enum RestError {
+    #[error("Network error: {0}")]
+    Network(String),
+
+    #[error("HTTP error: {0}")]
+    Http(u16),
+
+    #[error("Parse error: {0}")]
+    Parse(String),
+
+    #[error("API error: {0}")]
+    Api(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::FixedPoint8;
+    use crate::test_utils::init_test_registry;
+    use axum::{extract::Query, routing::get, Router};
+    use std::collections::HashMap;
+
+    #[tokio::test]
+    async fn test_fetch_oldest_first() {
+        init_test_registry();
+        let app = Router::new()
+            .route(
+                "/binance",
+                get(|Query(q): Query<HashMap<String, String>>| async move {
+                    assert_eq!(q["symbol"], "BTCUSDT");
+                    assert_eq!(q["interval"], "1m");
+                    assert_eq!(q["limit"], "2");
+                    r#"[[60000,"1","2","0.5","1.5","10",119999,"15",3,"5","7.5","0"],
+                        [120000,"1.5","3","1","2","20",179999,"40",4,"10","20","0"]]"#
+                }),
+            )
+            .route(
+                "/bybit",
+                get(|Query(q): Query<HashMap<String, String>>| async move {
+                    assert_eq!(q["category"], "linear");
+                    assert_eq!(q["interval"], "1");
+                    r#"{"retCode":0,"retMsg":"OK","result":{"symbol":"BTCUSDT","category":"linear","list":[
+                        ["120000","1.5","3","1","2","20","40"],
+                        ["60000","1","2","0.5","1.5","10","15"]]}}"#
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let fetcher = KlineFetcher::with_network(&NetworkConfig::default())
+            .with_endpoints(format!("{base}/binance"), format!("{base}/bybit"));
+        let symbol = Symbol::from_bytes(b"BTCUSDT").unwrap();
+
+        for klines in [
+            fetcher.fetch_binance(symbol, 2).await.unwrap(),
+            fetcher.fetch_bybit(symbol, 2).await.unwrap(),
+        ] {
+            assert_eq!(klines.len(), 2);
+            assert_eq!(klines[0].open_time, 60_000);
+            assert_eq!(klines[1].close, FixedPoint8::from_raw(200_000_000));
+            assert_eq!(klines[1].quote_volume, FixedPoint8::from_raw(4_000_000_000));
+        }
+
+        let missing = KlineFetcher::with_network(&NetworkConfig::default())
+            .with_endpoints(format!("{base}/missing"), format!("{base}/missing"));
+        assert!(matches!(missing.fetch_binance(symbol, 2).await, Err(RestError::Http(404))));
+    }
+}
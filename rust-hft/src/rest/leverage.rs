@@ -0,0 +1,368 @@
+//! Leverage and margin mode management (Cold Path)
+//!
+//! Sets each managed symbol's margin mode and leverage on both venues from
+//! config, then reads the position settings back to verify them. "Not
+//! modified" rejections count as success. Changing account settings needs
+//! a [`TradingPermit`], so nothing is changed in read-only mode.
+//!
+//! API: Binance POST /fapi/v1/marginType, /fapi/v1/leverage and GET
+//! /fapi/v2/positionRisk; Bybit POST /v5/position/switch-isolated,
+//! /v5/position/set-leverage and GET /v5/position/list (all signed).
+
+use crate::exchanges::Exchange;
+use crate::infrastructure::config::{LeverageConfig, MarginMode, NetworkConfig, TradingPermit};
+use crate::rest::fees::{BINANCE_FAPI_URL, BYBIT_API_URL};
+use crate::rest::klines::RestError;
+use crate::rest::models::{BinanceApiError, BinancePosition, BybitList, BybitPosition, BybitResponse};
+use crate::rest::signing::{RequestSigner, RECV_WINDOW_MS};
+use serde::de::{DeserializeOwned, IgnoredAny};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+/// Binance: margin type already as requested
+const BINANCE_MARGIN_UNCHANGED: i32 = -4046;
+
+/// Bybit: margin mode / leverage already as requested
+const BYBIT_MARGIN_UNCHANGED: i32 = 110026;
+const BYBIT_LEVERAGE_UNCHANGED: i32 = 110043;
+
+/// Margin mode and leverage of one position
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct LeverageSetting {
+    pub margin_mode: MarginMode,
+    pub leverage: u32,
+}
+
+/// One symbol to manage on one venue
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LeverageTarget {
+    pub exchange: Exchange,
+    pub symbol: String,
+    pub setting: LeverageSetting,
+}
+
+/// Managed (venue, symbol) pairs from config, limited to `venues`
+///
+/// Symbols naming an unknown venue are skipped with a warning.
+pub fn targets(config: &LeverageConfig, venues: &[Exchange]) -> Vec<LeverageTarget> {
+    let mut targets = Vec::new();
+    for (symbol, entry) in &config.symbols {
+        let only = match entry.exchange.as_deref().map(Exchange::from_name) {
+            None => None,
+            Some(Some(exchange)) => Some(exchange),
+            Some(None) => {
+                tracing::warn!("Leverage for {}: unknown exchange {:?}", symbol, entry.exchange);
+                continue;
+            }
+        };
+        let setting = LeverageSetting {
+            margin_mode: entry.margin_mode.unwrap_or(config.margin_mode),
+            leverage: entry.leverage.unwrap_or(config.leverage).max(1),
+        };
+        for &exchange in venues.iter().filter(|&&e| only.is_none_or(|o| o == e)) {
+            targets.push(LeverageTarget { exchange, symbol: symbol.to_ascii_uppercase(), setting });
+        }
+    }
+    targets.sort_by(|a, b| (&a.symbol, a.exchange.index()).cmp(&(&b.symbol, b.exchange.index())));
+    targets
+}
+
+/// Private client for position settings
+pub struct LeverageManager {
+    client: reqwest::Client,
+    binance: Option<RequestSigner>,
+    bybit: Option<RequestSigner>,
+    binance_url: String,
+    bybit_url: String,
+    _permit: TradingPermit,
+}
+
+impl LeverageManager {
+    /// Create manager with credentials from `BINANCE_API_*` / `BYBIT_API_*`
+    pub fn from_env(network: &NetworkConfig, permit: TradingPermit) -> Self {
+        Self::new(network, RequestSigner::from_env("BINANCE"), RequestSigner::from_env("BYBIT"), permit)
+    }
+
+    /// Create manager; a venue without credentials is skipped
+    pub fn new(network: &NetworkConfig, binance: Option<RequestSigner>, bybit: Option<RequestSigner>, permit: TradingPermit) -> Self {
+        Self {
+            client: reqwest::Client::builder()
+                .timeout(Duration::from_secs(10))
+                .user_agent("rust-hft/0.1")
+                .local_address(network.rest_local_address())
+                .build()
+                .unwrap_or_else(|_| reqwest::Client::new()),
+            binance,
+            bybit,
+            binance_url: BINANCE_FAPI_URL.to_string(),
+            bybit_url: BYBIT_API_URL.to_string(),
+            _permit: permit,
+        }
+    }
+
+    /// Override REST bases (mock servers, alternative hosts)
+    pub fn with_endpoints(mut self, binance_url: impl Into<String>, bybit_url: impl Into<String>) -> Self {
+        self.binance_url = binance_url.into();
+        self.bybit_url = bybit_url.into();
+        self
+    }
+
+    /// Venues with credentials
+    pub fn venues(&self) -> Vec<Exchange> {
+        let mut venues = Vec::new();
+        if self.binance.is_some() {
+            venues.push(Exchange::Binance);
+        }
+        if self.bybit.is_some() {
+            venues.push(Exchange::Bybit);
+        }
+        venues
+    }
+
+    /// Set margin mode and leverage of `target`, then read back what the venue reports
+    pub async fn apply(&self, target: &LeverageTarget) -> Result<LeverageSetting, RestError> {
+        match target.exchange {
+            Exchange::Binance => self.apply_binance(&target.symbol, target.setting).await?,
+            Exchange::Bybit => self.apply_bybit(&target.symbol, target.setting).await?,
+        }
+        self.query(target.exchange, &target.symbol).await
+    }
+
+    /// Margin mode and leverage `exchange` reports for `symbol`
+    pub async fn query(&self, exchange: Exchange, symbol: &str) -> Result<LeverageSetting, RestError> {
+        let (leverage, margin_mode) = match exchange {
+            Exchange::Binance => {
+                let signer = self.signer(exchange)?;
+                let query = format!("symbol={}", symbol);
+                let url = format!("{}/fapi/v2/positionRisk?{}", self.binance_url, signer.binance_query(&query, now_ms()));
+                let positions: Vec<BinancePosition> = self.binance_send(self.client.get(url), signer).await?;
+                let position = positions.into_iter().next().ok_or_else(|| RestError::Parse(format!("no {} position entry", symbol)))?;
+                let isolated = position.margin_type.eq_ignore_ascii_case("isolated");
+                (position.leverage, if isolated { MarginMode::Isolated } else { MarginMode::Cross })
+            }
+            Exchange::Bybit => {
+                let signer = self.signer(exchange)?;
+                let query = format!("category=linear&symbol={}", symbol);
+                let timestamp = now_ms();
+                let request = self
+                    .client
+                    .get(format!("{}/v5/position/list?{}", self.bybit_url, query))
+                    .header("X-BAPI-SIGN", signer.bybit_signature(&query, timestamp));
+                let positions: BybitList<BybitPosition> = self.bybit_send(request, signer, timestamp, &[]).await?;
+                let position = positions.list.into_iter().next().ok_or_else(|| RestError::Parse(format!("no {} position entry", symbol)))?;
+                (position.leverage, if position.trade_mode == 1 { MarginMode::Isolated } else { MarginMode::Cross })
+            }
+        };
+        Ok(LeverageSetting { margin_mode, leverage: leverage.to_f64().round() as u32 })
+    }
+
+    async fn apply_binance(&self, symbol: &str, setting: LeverageSetting) -> Result<(), RestError> {
+        let signer = self.signer(Exchange::Binance)?;
+        let margin_type = match setting.margin_mode {
+            MarginMode::Isolated => "ISOLATED",
+            MarginMode::Cross => "CROSSED",
+        };
+        let calls = [
+            ("/fapi/v1/marginType", format!("symbol={}&marginType={}", symbol, margin_type)),
+            ("/fapi/v1/leverage", format!("symbol={}&leverage={}", symbol, setting.leverage)),
+        ];
+        for (path, query) in calls {
+            let url = format!("{}{}?{}", self.binance_url, path, signer.binance_query(&query, now_ms()));
+            let result: Result<IgnoredAny, RestError> = self.binance_send(self.client.post(url), signer).await;
+            match result {
+                Ok(_) => {}
+                Err(RestError::Api(msg)) if msg.starts_with(&format!("{}:", BINANCE_MARGIN_UNCHANGED)) => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    async fn apply_bybit(&self, symbol: &str, setting: LeverageSetting) -> Result<(), RestError> {
+        let signer = self.signer(Exchange::Bybit)?;
+        let leverage = setting.leverage.to_string();
+        let trade_mode = match setting.margin_mode {
+            MarginMode::Cross => 0,
+            MarginMode::Isolated => 1,
+        };
+        let calls = [
+            (
+                "/v5/position/switch-isolated",
+                serde_json::json!({
+                    "category": "linear",
+                    "symbol": symbol,
+                    "tradeMode": trade_mode,
+                    "buyLeverage": leverage,
+                    "sellLeverage": leverage,
+                }),
+                BYBIT_MARGIN_UNCHANGED,
+            ),
+            (
+                "/v5/position/set-leverage",
+                serde_json::json!({
+                    "category": "linear",
+                    "symbol": symbol,
+                    "buyLeverage": leverage,
+                    "sellLeverage": leverage,
+                }),
+                BYBIT_LEVERAGE_UNCHANGED,
+            ),
+        ];
+        for (path, body, unchanged) in calls {
+            let body = body.to_string();
+            let timestamp = now_ms();
+            let request = self
+                .client
+                .post(format!("{}{}", self.bybit_url, path))
+                .header("Content-Type", "application/json")
+                .header("X-BAPI-SIGN", signer.bybit_signature(&body, timestamp))
+                .body(body);
+            let _: IgnoredAny = self.bybit_send(request, signer, timestamp, &[unchanged]).await?;
+        }
+        Ok(())
+    }
+
+    fn signer(&self, exchange: Exchange) -> Result<&RequestSigner, RestError> {
+        let signer = match exchange {
+            Exchange::Binance => self.binance.as_ref(),
+            Exchange::Bybit => self.bybit.as_ref(),
+        };
+        signer.ok_or_else(|| RestError::Api(format!("no {} credentials", exchange.name())))
+    }
+
+    /// Send a signed Binance request; rejections become `Api("<code>: <msg>")`
+    async fn binance_send<T: DeserializeOwned>(&self, request: reqwest::RequestBuilder, signer: &RequestSigner) -> Result<T, RestError> {
+        let response = request
+            .header("X-MBX-APIKEY", signer.api_key())
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+        let status = response.status();
+        if !status.is_success() {
+            return Err(match response.json::<BinanceApiError>().await {
+                Ok(error) => RestError::Api(format!("{}: {}", error.code, error.msg)),
+                Err(_) => RestError::Http(status.as_u16()),
+            });
+        }
+        response.json().await.map_err(|e| RestError::Parse(e.to_string()))
+    }
+
+    /// Send a signed Bybit request; a retCode in `accepted` counts as success (null result)
+    async fn bybit_send<T: DeserializeOwned>(
+        &self,
+        request: reqwest::RequestBuilder,
+        signer: &RequestSigner,
+        timestamp: u64,
+        accepted: &[i32],
+    ) -> Result<T, RestError> {
+        let response = request
+            .header("X-BAPI-API-KEY", signer.api_key())
+            .header("X-BAPI-TIMESTAMP", timestamp.to_string())
+            .header("X-BAPI-RECV-WINDOW", RECV_WINDOW_MS.to_string())
+            .send()
+            .await
+            .map_err(|e| RestError::Network(e.to_string()))?;
+        if !response.status().is_success() {
+            return Err(RestError::Http(response.status().as_u16()));
+        }
+        let body: BybitResponse<serde_json::Value> = response.json().await.map_err(|e| RestError::Parse(e.to_string()))?;
+        let result = match body.into_result() {
+            Ok(result) => result,
+            Err((code, _)) if accepted.contains(&code) => serde_json::Value::Null,
+            Err((code, msg)) => return Err(RestError::Api(format!("{}: {}", code, msg))),
+        };
+        serde_json::from_value(result).map_err(|e| RestError::Parse(e.to_string()))
+    }
+}
+
+/// Unix time in milliseconds
+fn now_ms() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::infrastructure::config::{Config, SymbolLeverage};
+    use axum::{http::HeaderMap, routing::{get, post}, Router};
+
+    #[test]
+    fn test_leverage_targets() {
+        let mut config = LeverageConfig { leverage: 3, ..Default::default() };
+        config.symbols.insert("btcusdt".into(), SymbolLeverage { leverage: Some(10), ..Default::default() });
+        config.symbols.insert(
+            "ETHUSDT".into(),
+            SymbolLeverage { margin_mode: Some(MarginMode::Cross), exchange: Some("bybit".into()), ..Default::default() },
+        );
+        config.symbols.insert("SOLUSDT".into(), SymbolLeverage { exchange: Some("okx".into()), ..Default::default() });
+
+        let all = targets(&config, &[Exchange::Binance, Exchange::Bybit]);
+        let summary: Vec<_> = all.iter().map(|t| (t.exchange, t.symbol.as_str(), t.setting.margin_mode, t.setting.leverage)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (Exchange::Binance, "BTCUSDT", MarginMode::Isolated, 10),
+                (Exchange::Bybit, "BTCUSDT", MarginMode::Isolated, 10),
+                (Exchange::Bybit, "ETHUSDT", MarginMode::Cross, 3),
+            ]
+        );
+        // Venues without credentials are left out
+        assert_eq!(targets(&config, &[Exchange::Binance]).len(), 1);
+    }
+
+    #[tokio::test]
+    async fn test_apply_and_verify() {
+        let app = Router::new()
+            .route(
+                "/binance/fapi/v1/marginType",
+                post(|| async {
+                    (axum::http::StatusCode::BAD_REQUEST, r#"{"code":-4046,"msg":"No need to change margin type."}"#)
+                }),
+            )
+            .route("/binance/fapi/v1/leverage", post(|| async { r#"{"leverage":5,"maxNotionalValue":"1000000","symbol":"BTCUSDT"}"# }))
+            .route(
+                "/binance/fapi/v2/positionRisk",
+                get(|| async {
+                    r#"[{"symbol":"BTCUSDT","positionAmt":"0","entryPrice":"0","markPrice":"64000","unRealizedProfit":"0",
+                        "liquidationPrice":"0","leverage":"5","marginType":"isolated","positionSide":"BOTH","updateTime":0}]"#
+                }),
+            )
+            .route(
+                "/bybit/v5/position/switch-isolated",
+                post(|headers: HeaderMap, body: String| async move {
+                    assert_eq!(headers["X-BAPI-SIGN"].len(), 64);
+                    assert!(body.contains(r#""tradeMode":1"#), "{}", body);
+                    r#"{"retCode":110026,"retMsg":"Cross/isolated margin mode is not modified","result":{}}"#
+                }),
+            )
+            .route("/bybit/v5/position/set-leverage", post(|| async { r#"{"retCode":0,"retMsg":"OK","result":{}}"# }))
+            .route(
+                "/bybit/v5/position/list",
+                // The venue caps leverage below the request
+                get(|| async {
+                    r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"symbol":"BTCUSDT","side":"","size":"0","avgPrice":"",
+                        "markPrice":"64000","unrealisedPnl":"","liqPrice":"","leverage":"4","tradeMode":1,"positionIdx":0,
+                        "updatedTime":"1672280219169"}]}}"#
+                }),
+            );
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let base = format!("http://{}", listener.local_addr().unwrap());
+        tokio::spawn(async move { axum::serve(listener, app).await.unwrap() });
+
+        let manager = LeverageManager::new(
+            &NetworkConfig::default(),
+            Some(RequestSigner::new("bkey", "bsecret")),
+            Some(RequestSigner::new("ykey", "ysecret")),
+            Config::default().trading_permit().unwrap(),
+        )
+        .with_endpoints(format!("{base}/binance"), format!("{base}/bybit"));
+        let setting = LeverageSetting { margin_mode: MarginMode::Isolated, leverage: 5 };
+        let target = |exchange| LeverageTarget { exchange, symbol: "BTCUSDT".into(), setting };
+
+        assert_eq!(manager.apply(&target(Exchange::Binance)).await.unwrap(), setting);
+        let bybit = manager.apply(&target(Exchange::Bybit)).await.unwrap();
+        assert_eq!(bybit, LeverageSetting { margin_mode: MarginMode::Isolated, leverage: 4 });
+    }
+}
@@ -1,7 +1,15 @@
-//! REST API clients for order placement
+//! REST API clients for order placement and market data
 
 pub mod client;
+pub mod fees;
+pub mod klines;
+pub mod leverage;
+pub mod models;
 pub mod signing;
 
 pub use client::RestClient;
+pub use fees::FeeTierFetcher;
+pub use klines::{KlineFetcher, RestError};
+pub use leverage::{LeverageManager, LeverageSetting, LeverageTarget};
+pub use models::{FeeTier, Kline};
 pub use signing::RequestSigner;
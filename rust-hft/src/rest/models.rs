@@ -0,0 +1,482 @@
+//! Typed REST response models
+//!
+//! Order acks, positions, balances and klines for Binance USDⓈ-M futures and
+//! Bybit V5. Prices and quantities deserialize straight from the exchange's
+//! decimal strings into FixedPoint8 (never via f64), so accounting does not
+//! drift. Venue payloads convert into venue-neutral types where one exists.
+
+use crate::core::{FixedPoint8, Position, Side, SymbolMapper};
+use crate::exchanges::Exchange;
+use serde::de::{self, Deserializer, IgnoredAny};
+use serde::Deserialize;
+
+/// FixedPoint8 field where the venue sends "" for "not applicable"
+pub fn fixed_or_zero<'de, D: Deserializer<'de>>(deserializer: D) -> Result<FixedPoint8, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    if s.is_empty() {
+        return Ok(FixedPoint8::ZERO);
+    }
+    FixedPoint8::parse_bytes(s.as_bytes())
+        .ok_or_else(|| de::Error::invalid_value(de::Unexpected::Str(&s), &"a decimal string"))
+}
+
+/// u64 field sent as a decimal string (Bybit timestamps)
+pub fn u64_from_str<'de, D: Deserializer<'de>>(deserializer: D) -> Result<u64, D::Error> {
+    let s = String::deserialize(deserializer)?;
+    s.parse()
+        .map_err(|_| de::Error::invalid_value(de::Unexpected::Str(&s), &"an integer string"))
+}
+
+// === Venue-neutral ===
+
+/// Candlestick (venue-neutral)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Kline {
+    /// Open time (Unix millis)
+    pub open_time: u64,
+    pub open: FixedPoint8,
+    pub high: FixedPoint8,
+    pub low: FixedPoint8,
+    pub close: FixedPoint8,
+    /// Base-asset volume
+    pub volume: FixedPoint8,
+    /// Quote-asset volume (turnover)
+    pub quote_volume: FixedPoint8,
+}
+
+/// Account fee tier on one venue (venue-neutral)
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct FeeTier {
+    pub exchange: Exchange,
+    /// VIP level as reported by the venue ("0", "VIP1", "No VIP", ...)
+    pub tier: String,
+    /// Maker/taker rates before discounts (fraction)
+    pub maker: FixedPoint8,
+    pub taker: FixedPoint8,
+    /// Fraction of fees still paid after discounts (ONE = no discount)
+    pub discount_factor: FixedPoint8,
+}
+
+impl FeeTier {
+    /// Taker rate actually paid
+    pub fn effective_taker(&self) -> FixedPoint8 {
+        self.taker.safe_mul(self.discount_factor).unwrap_or(self.taker)
+    }
+
+    /// Maker rate actually paid (a rebate is not discounted)
+    pub fn effective_maker(&self) -> FixedPoint8 {
+        if self.maker.is_negative() {
+            return self.maker;
+        }
+        self.maker.safe_mul(self.discount_factor).unwrap_or(self.maker)
+    }
+}
+
+/// Build core position from venue fields; None if flat or symbol unknown
+fn position_from(
+    exchange: Exchange,
+    symbol: &str,
+    side: Side,
+    quantity: FixedPoint8,
+    entry_price: FixedPoint8,
+    liquidation_price: FixedPoint8,
+) -> Option<Position> {
+    if quantity.is_zero() {
+        return None;
+    }
+    let symbol = SymbolMapper::from_exchange_name(symbol, exchange)?;
+    Some(Position::new(symbol, exchange, side, quantity, entry_price, liquidation_price))
+}
+
+// === Binance (USDⓈ-M futures) ===
+
+/// POST /fapi/v1/order response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceOrderAck {
+    pub order_id: u64,
+    pub client_order_id: String,
+    pub symbol: String,
+    pub status: String,
+    pub side: String,
+    #[serde(rename = "type")]
+    pub order_type: String,
+    pub price: FixedPoint8,
+    pub avg_price: FixedPoint8,
+    pub orig_qty: FixedPoint8,
+    pub executed_qty: FixedPoint8,
+    pub update_time: u64,
+}
+
+/// GET /fapi/v2/positionRisk entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinancePosition {
+    pub symbol: String,
+    /// Signed size (negative = short in one-way mode)
+    pub position_amt: FixedPoint8,
+    pub entry_price: FixedPoint8,
+    pub mark_price: FixedPoint8,
+    #[serde(rename = "unRealizedProfit")]
+    pub unrealized_profit: FixedPoint8,
+    pub liquidation_price: FixedPoint8,
+    pub leverage: FixedPoint8,
+    /// "isolated" or "cross"
+    #[serde(default)]
+    pub margin_type: String,
+    pub position_side: String,
+    pub update_time: u64,
+}
+
+impl BinancePosition {
+    /// Convert to core position (None if flat or symbol not registered)
+    pub fn to_position(&self) -> Option<Position> {
+        let side = if self.position_amt.is_negative() { Side::Sell } else { Side::Buy };
+        position_from(
+            Exchange::Binance,
+            &self.symbol,
+            side,
+            self.position_amt.checked_abs()?,
+            self.entry_price,
+            self.liquidation_price,
+        )
+    }
+}
+
+/// Error body of a rejected Binance request
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceApiError {
+    pub code: i32,
+    pub msg: String,
+}
+
+/// GET /fapi/v2/balance entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceBalance {
+    pub asset: String,
+    pub balance: FixedPoint8,
+    pub available_balance: FixedPoint8,
+    pub cross_un_pnl: FixedPoint8,
+    pub update_time: u64,
+}
+
+/// GET /fapi/v1/commissionRate response
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceCommissionRate {
+    pub symbol: String,
+    pub maker_commission_rate: FixedPoint8,
+    pub taker_commission_rate: FixedPoint8,
+}
+
+/// GET /fapi/v1/feeBurn response (BNB fee discount)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceFeeBurn {
+    pub fee_burn: bool,
+}
+
+/// GET /fapi/v2/account (only the fee tier is used)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BinanceAccountTier {
+    pub fee_tier: u32,
+}
+
+/// GET /fapi/v1/klines row (positional array)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BinanceKline(
+    pub u64,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    /// Close time
+    pub u64,
+    pub FixedPoint8,
+    /// Number of trades
+    pub u64,
+    /// Taker buy base volume
+    pub FixedPoint8,
+    /// Taker buy quote volume
+    pub FixedPoint8,
+    IgnoredAny,
+);
+
+impl From<BinanceKline> for Kline {
+    fn from(k: BinanceKline) -> Self {
+        Self {
+            open_time: k.0,
+            open: k.1,
+            high: k.2,
+            low: k.3,
+            close: k.4,
+            volume: k.5,
+            quote_volume: k.7,
+        }
+    }
+}
+
+// === Bybit (V5) ===
+
+/// V5 response envelope
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitResponse<T> {
+    pub ret_code: i32,
+    pub ret_msg: String,
+    pub result: T,
+    #[serde(default)]
+    pub time: u64,
+}
+
+impl<T> BybitResponse<T> {
+    /// Result if retCode is 0, otherwise (retCode, retMsg)
+    pub fn into_result(self) -> Result<T, (i32, String)> {
+        if self.ret_code == 0 {
+            Ok(self.result)
+        } else {
+            Err((self.ret_code, self.ret_msg))
+        }
+    }
+}
+
+/// V5 paginated list result
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitList<T> {
+    pub list: Vec<T>,
+    #[serde(default)]
+    pub next_page_cursor: String,
+}
+
+/// POST /v5/order/create result
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitOrderAck {
+    pub order_id: String,
+    pub order_link_id: String,
+}
+
+/// GET /v5/position/list entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitPosition {
+    pub symbol: String,
+    /// "Buy", "Sell" or "" when flat
+    pub side: String,
+    pub size: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub avg_price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub mark_price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub unrealised_pnl: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub liq_price: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub leverage: FixedPoint8,
+    /// 0 = cross margin, 1 = isolated
+    #[serde(default)]
+    pub trade_mode: u8,
+    pub position_idx: u8,
+    #[serde(deserialize_with = "u64_from_str")]
+    pub updated_time: u64,
+}
+
+impl BybitPosition {
+    /// Convert to core position (None if flat or symbol not registered)
+    pub fn to_position(&self) -> Option<Position> {
+        let side = Side::from_bytes(self.side.as_bytes())?;
+        position_from(
+            Exchange::Bybit,
+            &self.symbol,
+            side,
+            self.size,
+            self.avg_price,
+            self.liq_price,
+        )
+    }
+}
+
+/// GET /v5/account/wallet-balance account entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitWalletBalance {
+    pub account_type: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub total_equity: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub total_available_balance: FixedPoint8,
+    pub coin: Vec<BybitCoinBalance>,
+}
+
+/// Per-coin balance inside a wallet balance entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitCoinBalance {
+    pub coin: String,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub wallet_balance: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub equity: FixedPoint8,
+    #[serde(deserialize_with = "fixed_or_zero")]
+    pub unrealised_pnl: FixedPoint8,
+}
+
+/// GET /v5/account/fee-rate entry
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitFeeRate {
+    pub symbol: String,
+    pub taker_fee_rate: FixedPoint8,
+    pub maker_fee_rate: FixedPoint8,
+}
+
+/// GET /v5/user/query-api result (only the VIP level is used)
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BybitApiKeyInfo {
+    pub vip_level: String,
+}
+
+/// GET /v5/market/kline result (list is newest first)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BybitKlines {
+    pub symbol: String,
+    pub list: Vec<BybitKline>,
+}
+
+/// GET /v5/market/kline row (positional array of strings)
+#[derive(Debug, Clone, Deserialize)]
+pub struct BybitKline(
+    #[serde(deserialize_with = "u64_from_str")] pub u64,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    pub FixedPoint8,
+    /// Turnover (quote volume)
+    pub FixedPoint8,
+);
+
+impl From<BybitKline> for Kline {
+    fn from(k: BybitKline) -> Self {
+        Self {
+            open_time: k.0,
+            open: k.1,
+            high: k.2,
+            low: k.3,
+            close: k.4,
+            volume: k.5,
+            quote_volume: k.6,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::core::Symbol;
+    use crate::test_utils::init_test_registry;
+
+    fn fp(s: &str) -> FixedPoint8 {
+        FixedPoint8::parse_bytes(s.as_bytes()).unwrap()
+    }
+
+    #[test]
+    fn test_binance_order_ack() {
+        let json = r#"{"orderId":22542179,"clientOrderId":"arb-1","symbol":"BTCUSDT","status":"NEW",
+            "side":"BUY","type":"LIMIT","price":"0.10000000","avgPrice":"0.00000","origQty":"10",
+            "executedQty":"0","updateTime":1566818724722,"timeInForce":"GTC"}"#;
+        let ack: BinanceOrderAck = serde_json::from_str(json).unwrap();
+        assert_eq!(ack.order_id, 22542179);
+        assert_eq!(ack.price, fp("0.1"));
+        assert_eq!(ack.orig_qty, fp("10"));
+    }
+
+    #[test]
+    fn test_binance_position_and_balance() {
+        init_test_registry();
+        let json = r#"[{"symbol":"BTCUSDT","positionAmt":"-0.003","entryPrice":"64123.45678901",
+            "markPrice":"64000.1","unRealizedProfit":"0.37","liquidationPrice":"70000",
+            "leverage":"10","positionSide":"BOTH","updateTime":1700000000000}]"#;
+        let positions: Vec<BinancePosition> = serde_json::from_str(json).unwrap();
+        // 8 decimals survive exactly (would drift through f64 accounting)
+        assert_eq!(positions[0].entry_price.as_raw(), 6_412_345_678_901);
+
+        let pos = positions[0].to_position().unwrap();
+        assert_eq!(pos.symbol, Symbol::from_bytes(b"BTCUSDT").unwrap());
+        assert_eq!(pos.side, Side::Sell);
+        assert_eq!(pos.quantity, fp("0.003"));
+
+        let json = r#"[{"accountAlias":"x","asset":"USDT","balance":"122.60","crossWalletBalance":"122.60",
+            "crossUnPnl":"0.00","availableBalance":"122.60","maxWithdrawAmount":"122.60",
+            "marginAvailable":true,"updateTime":1617939110373}]"#;
+        let balances: Vec<BinanceBalance> = serde_json::from_str(json).unwrap();
+        assert_eq!(balances[0].available_balance, fp("122.6"));
+    }
+
+    #[test]
+    fn test_binance_kline() {
+        let json = r#"[[1499040000000,"0.01634790","0.80000000","0.01575800","0.01577100",
+            "148976.11427815",1499644799999,"2434.19055334",308,"1756.87402397","28.46694368","0"]]"#;
+        let rows: Vec<BinanceKline> = serde_json::from_str(json).unwrap();
+        let k = Kline::from(rows[0].clone());
+        assert_eq!(k.open_time, 1499040000000);
+        assert_eq!(k.high, fp("0.8"));
+        assert_eq!(k.quote_volume, fp("2434.19055334"));
+    }
+
+    #[test]
+    fn test_bybit_envelope_and_order_ack() {
+        let json = r#"{"retCode":0,"retMsg":"OK","result":{"orderId":"1321003749386327552",
+            "orderLinkId":"arb-1"},"retExtInfo":{},"time":1672211918471}"#;
+        let resp: BybitResponse<BybitOrderAck> = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.into_result().unwrap().order_link_id, "arb-1");
+
+        let json = r#"{"retCode":10001,"retMsg":"params error","result":{},"time":1}"#;
+        let resp: BybitResponse<serde_json::Value> = serde_json::from_str(json).unwrap();
+        assert_eq!(resp.into_result().unwrap_err().0, 10001);
+    }
+
+    #[test]
+    fn test_bybit_position_and_wallet() {
+        init_test_registry();
+        let json = r#"{"retCode":0,"retMsg":"OK","result":{"list":[
+            {"symbol":"BTCUSDT","side":"Buy","size":"0.01","avgPrice":"30000.5","markPrice":"30100",
+             "unrealisedPnl":"1.0","liqPrice":"","leverage":"10","positionIdx":0,"updatedTime":"1672280219169"},
+            {"symbol":"BTCUSDT","side":"","size":"0","avgPrice":"0","markPrice":"30100",
+             "unrealisedPnl":"","liqPrice":"","leverage":"10","positionIdx":0,"updatedTime":"1672280219169"}
+            ],"nextPageCursor":"","category":"linear"},"time":1672280219169}"#;
+        let resp: BybitResponse<BybitList<BybitPosition>> = serde_json::from_str(json).unwrap();
+        let list = resp.into_result().unwrap().list;
+        assert_eq!(list[0].liq_price, FixedPoint8::ZERO);
+        assert_eq!(list[0].updated_time, 1672280219169);
+        let pos = list[0].to_position().unwrap();
+        assert_eq!(pos.side, Side::Buy);
+        assert_eq!(pos.entry_price, fp("30000.5"));
+        assert!(list[1].to_position().is_none()); // flat
+
+        let json = r#"{"retCode":0,"retMsg":"OK","result":{"list":[{"accountType":"UNIFIED",
+            "totalEquity":"3.31","totalAvailableBalance":"3.00","coin":[{"coin":"USDT",
+            "walletBalance":"3.3","equity":"3.31","unrealisedPnl":"0.01","availableToWithdraw":""}]}]},"time":1}"#;
+        let resp: BybitResponse<BybitList<BybitWalletBalance>> = serde_json::from_str(json).unwrap();
+        let wallet = &resp.result.list[0];
+        assert_eq!(wallet.total_available_balance, fp("3"));
+        assert_eq!(wallet.coin[0].wallet_balance, fp("3.3"));
+    }
+
+    #[test]
+    fn test_bybit_kline() {
+        let json = r#"{"symbol":"BTCUSDT","category":"linear","list":[
+            ["1670608800000","17071","17073","17027","17055.5","268611","4582348.9"]]}"#;
+        let klines: BybitKlines = serde_json::from_str(json).unwrap();
+        let k = Kline::from(klines.list[0].clone());
+        assert_eq!(k.open_time, 1670608800000);
+        assert_eq!(k.close, fp("17055.5"));
+        assert_eq!(k.quote_volume, fp("4582348.9"));
+    }
+}
@@ -1,5 +1,124 @@
-//! Placeholder for request signing module
+//! Request signing for private REST endpoints
 //!
-//! Will implement HMAC-SHA256 signing for Binance and Bybit APIs
+//! HMAC-SHA256 over the venue's canonical payload, hex encoded:
+//! - Binance: the query string; sent as `signature=` with `X-MBX-APIKEY`
+//! - Bybit V5: `timestamp + api_key + recv_window + query`; sent in
+//!   `X-BAPI-*` headers
+//! - Bybit V5 private WebSocket: `GET/realtime + expires`; sent in the
+//!   `auth` op
+//!
+//! Credentials come from the environment, never from config.toml.
+
+use hmac::{Hmac, Mac};
+use sha2::Sha256;
+
+/// Receive window accepted by both venues (milliseconds)
+pub const RECV_WINDOW_MS: u64 = 5_000;
+
+/// API credentials for one venue
+#[derive(Clone)]
+pub struct RequestSigner {
+    api_key: String,
+    secret: String,
+}
+
+impl std::fmt::Debug for RequestSigner {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RequestSigner").field("api_key", &self.api_key).finish_non_exhaustive()
+    }
+}
+
+impl RequestSigner {
+    pub fn new(api_key: impl Into<String>, secret: impl Into<String>) -> Self {
+        Self {
+            api_key: api_key.into(),
+            secret: secret.into(),
+        }
+    }
+
+    /// Credentials from `<PREFIX>_API_KEY` / `<PREFIX>_API_SECRET` (None if unset)
+    pub fn from_env(prefix: &str) -> Option<Self> {
+        let api_key = std::env::var(format!("{}_API_KEY", prefix)).ok()?;
+        let secret = std::env::var(format!("{}_API_SECRET", prefix)).ok()?;
+        (!api_key.is_empty() && !secret.is_empty()).then(|| Self::new(api_key, secret))
+    }
+
+    #[inline]
+    pub fn api_key(&self) -> &str {
+        &self.api_key
+    }
+
+    /// Hex HMAC-SHA256 of `payload`
+    pub fn sign(&self, payload: &str) -> String {
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes()).expect("HMAC accepts any key length");
+        mac.update(payload.as_bytes());
+        let mut out = [0u8; 64];
+        // 32-byte digest always fits 64 hex chars
+        let _ = hex::encode_to_slice(mac.finalize().into_bytes(), &mut out);
+        out.iter().map(|&b| b as char).collect()
+    }
+
+    /// Whether `signature` is the hex HMAC-SHA256 of `payload` (constant-time compare)
+    pub fn verify(&self, payload: &str, signature: &str) -> bool {
+        let mut digest = [0u8; 32];
+        if hex::decode_to_slice(signature, &mut digest).is_err() {
+            return false;
+        }
+        let mut mac = Hmac::<Sha256>::new_from_slice(self.secret.as_bytes()).expect("HMAC accepts any key length");
+        mac.update(payload.as_bytes());
+        mac.verify_slice(&digest).is_ok()
+    }
+
+    /// Binance signed query: `query&recvWindow=..&timestamp=..&signature=..`
+    pub fn binance_query(&self, query: &str, timestamp_ms: u64) -> String {
+        let mut payload = String::with_capacity(query.len() + 128);
+        if !query.is_empty() {
+            payload.push_str(query);
+            payload.push('&');
+        }
+        payload.push_str(&format!("recvWindow={}&timestamp={}", RECV_WINDOW_MS, timestamp_ms));
+        let signature = self.sign(&payload);
+        payload.push_str("&signature=");
+        payload.push_str(&signature);
+        payload
+    }
+
+    /// Bybit V5 signature for a GET with `query` (or a POST with JSON body `query`)
+    pub fn bybit_signature(&self, query: &str, timestamp_ms: u64) -> String {
+        self.sign(&format!("{}{}{}{}", timestamp_ms, self.api_key, RECV_WINDOW_MS, query))
+    }
+
+    /// Bybit V5 private WebSocket auth signature for a request valid until `expires_ms`
+    pub fn bybit_ws_signature(&self, expires_ms: u64) -> String {
+        self.sign(&format!("GET/realtime{}", expires_ms))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_binance_reference_signature() {
+        // Example from the Binance API documentation
+        let signer = RequestSigner::new(
+            "vmPUZE6mv9SD5VNHk4HlWFsOr6aKE2zvsw0MuIgwCIPy6utIco14y7Ju91duEh8A",
+            "NhqPtmdSJYdKjVHjA7PZj4Mge3R5YNiP1e3UZjInClVN65XAbvqqM6A7H5fATj0j",
+        );
+        let query = "symbol=LTCBTC&side=BUY&type=LIMIT&timeInForce=GTC&quantity=1&price=0.1&recvWindow=5000&timestamp=1499827319559";
+        assert_eq!(signer.sign(query), "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71");
+
+        let signed = signer.binance_query("symbol=BTCUSDT", 1);
+        assert!(signed.starts_with("symbol=BTCUSDT&recvWindow=5000&timestamp=1&signature="));
+        assert!(!format!("{:?}", signer).contains("NhqPtmd"));
+        assert!(signer.verify(query, "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b71"));
+        assert!(!signer.verify(query, "c8db56825ae71d6d79447849e617115f4a920fa2acdcab2b053c4b2838bd6b70"));
+        assert!(!signer.verify(query, "not hex"));
+    }
 
-pub struct RequestSigner;
+    #[test]
+    fn test_bybit_ws_signature_signs_realtime_path() {
+        let signer = RequestSigner::new("key", "secret");
+        assert_eq!(signer.bybit_ws_signature(1_700_000_000_000), signer.sign("GET/realtime1700000000000"));
+    }
+}
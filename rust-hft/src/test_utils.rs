@@ -1,8 +1,16 @@
 //! Test utilities for symbol registry initialization
 //!
 //! All tests should call `init_test_registry()` before using symbols.
+//!
+//! Replay captures can be distorted with per-connection [`VenueClock`]s
+//! (venue clock skew, one-way latency) via [`skew_capture`] to test
+//! cross-venue behavior under unequal clocks and network paths.
 
+use crate::engine::CapturedMessage;
+use crate::exchanges::ExchangeMessage;
+use crate::ws::ConnectionId;
 use std::sync::Once;
+use std::time::Duration;
 
 static INIT: Once = Once::new();
 
@@ -58,6 +66,46 @@ pub fn init_test_registry() {
             "BONKUSDT".to_string(),
             "FLOKIUSDT".to_string(),
         ];
-        let _ = SymbolRegistry::initialize(&symbols);
+        SymbolRegistry::initialize(&symbols).expect("test registry");
     });
 }
+
+/// Simulated clock and network path of one connection
+#[derive(Debug, Clone, Copy, Default)]
+pub struct VenueClock {
+    /// Venue clock minus ours (ns); positive = venue clock ahead
+    pub clock_offset_ns: i64,
+    /// Extra one-way latency before the message reaches us
+    pub latency: Duration,
+}
+
+/// Shift the exchange timestamp of `msg` by `offset_ns` (no-op without one)
+pub fn shift_timestamp(msg: &mut ExchangeMessage, offset_ns: i64) {
+    let ts = match msg {
+        ExchangeMessage::Trade(_, trade) => &mut trade.timestamp,
+        ExchangeMessage::Ticker(_, ticker) => &mut ticker.timestamp,
+        ExchangeMessage::MarkPrice(_, mark) => &mut mark.timestamp,
+        ExchangeMessage::Liquidation(_, liquidation) => &mut liquidation.timestamp,
+        ExchangeMessage::Status(_) | ExchangeMessage::Heartbeat | ExchangeMessage::Error(_) => return,
+    };
+    if *ts != 0 {
+        *ts = ts.saturating_add_signed(offset_ns);
+    }
+}
+
+/// Apply each connection's clock to `capture`, re-ordered by the new arrival
+/// offsets (stable, so equal arrivals keep capture order)
+pub fn skew_capture(capture: &[CapturedMessage], clock: impl Fn(ConnectionId) -> VenueClock) -> Vec<CapturedMessage> {
+    let mut skewed: Vec<CapturedMessage> = capture
+        .iter()
+        .map(|captured| {
+            let venue = clock(captured.connection);
+            let mut captured = captured.clone();
+            shift_timestamp(&mut captured.msg, venue.clock_offset_ns);
+            captured.offset += venue.latency;
+            captured
+        })
+        .collect();
+    skewed.sort_by_key(|captured| captured.offset);
+    skewed
+}
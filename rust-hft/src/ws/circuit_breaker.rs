@@ -0,0 +1,286 @@
+//! Reconnect circuit breaker
+//!
+//! Bounds reconnect storms when an exchange rejects every attempt (e.g. IP ban):
+//! - Closed: attempts allowed, consecutive failures counted
+//! - Open: attempts refused until `open_duration` has elapsed
+//! - HalfOpen: a single probe attempt; success closes, failure re-opens
+//!
+//! One breaker per exchange ([`ReconnectCircuits`]) is shared by every
+//! supervised feed of that venue, so shards cannot keep hammering a venue
+//! that refuses their siblings.
+//!
+//! Cold path: called once per reconnect attempt.
+
+use crate::exchanges::Exchange;
+use crate::infrastructure::health::ExchangeHealth;
+use parking_lot::Mutex;
+use serde::Serialize;
+use std::time::{Duration, Instant};
+
+/// Circuit breaker configuration
+#[derive(Debug, Clone, Copy)]
+pub struct CircuitBreakerConfig {
+    /// Consecutive failures before the circuit opens
+    pub failure_threshold: u32,
+    /// Time the circuit stays open before a half-open probe
+    pub open_duration: Duration,
+}
+
+impl Default for CircuitBreakerConfig {
+    fn default() -> Self {
+        Self {
+            failure_threshold: 5,
+            open_duration: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Circuit state
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CircuitState {
+    /// Attempts allowed
+    Closed,
+    /// Attempts refused
+    Open,
+    /// Probe attempt in flight
+    HalfOpen,
+}
+
+/// Breaker snapshot for health reporting
+#[derive(Debug, Clone, Copy, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BreakerStatus {
+    pub state: CircuitState,
+    pub consecutive_failures: u32,
+    /// Number of times the circuit has opened
+    pub times_opened: u64,
+    /// Time until the next probe is allowed (0 unless open)
+    pub retry_in_ms: u64,
+}
+
+/// Per-exchange reconnect circuit breaker
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    config: CircuitBreakerConfig,
+    state: CircuitState,
+    consecutive_failures: u32,
+    times_opened: u64,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Create closed breaker
+    pub fn new(config: CircuitBreakerConfig) -> Self {
+        Self {
+            config,
+            state: CircuitState::Closed,
+            consecutive_failures: 0,
+            times_opened: 0,
+            opened_at: None,
+        }
+    }
+
+    /// Whether a connection attempt may be made at `now`
+    ///
+    /// An open circuit whose timeout has elapsed moves to half-open and
+    /// allows exactly one probe.
+    pub fn allow_attempt(&mut self, now: Instant) -> bool {
+        match self.state {
+            CircuitState::Closed => true,
+            CircuitState::HalfOpen => false,
+            CircuitState::Open => {
+                let elapsed = self
+                    .opened_at
+                    .map(|at| now.saturating_duration_since(at))
+                    .unwrap_or(Duration::MAX);
+                if elapsed >= self.config.open_duration {
+                    self.state = CircuitState::HalfOpen;
+                    true
+                } else {
+                    false
+                }
+            }
+        }
+    }
+
+    /// Record a successful connection (closes the circuit)
+    pub fn record_success(&mut self) {
+        self.state = CircuitState::Closed;
+        self.consecutive_failures = 0;
+        self.opened_at = None;
+    }
+
+    /// Record a failed connection attempt at `now`
+    ///
+    /// Returns true if this failure opened the circuit (caller should alert).
+    pub fn record_failure(&mut self, now: Instant) -> bool {
+        self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+        let open = match self.state {
+            CircuitState::Closed => self.consecutive_failures >= self.config.failure_threshold,
+            // Failed probe re-opens immediately
+            CircuitState::HalfOpen => true,
+            CircuitState::Open => false,
+        };
+        if open {
+            self.state = CircuitState::Open;
+            self.opened_at = Some(now);
+            self.times_opened += 1;
+        }
+        open
+    }
+
+    /// Current state
+    #[inline]
+    pub fn state(&self) -> CircuitState {
+        self.state
+    }
+
+    /// Snapshot at `now`
+    pub fn status(&self, now: Instant) -> BreakerStatus {
+        let retry_in = match (self.state, self.opened_at) {
+            (CircuitState::Open, Some(at)) => self
+                .config
+                .open_duration
+                .saturating_sub(now.saturating_duration_since(at)),
+            _ => Duration::ZERO,
+        };
+        BreakerStatus {
+            state: self.state,
+            consecutive_failures: self.consecutive_failures,
+            times_opened: self.times_opened,
+            retry_in_ms: retry_in.as_millis() as u64,
+        }
+    }
+}
+
+impl Default for CircuitBreaker {
+    fn default() -> Self {
+        Self::new(CircuitBreakerConfig::default())
+    }
+}
+
+/// Reconnect breaker of every exchange (indexed by Exchange::index)
+#[derive(Debug, Default)]
+pub struct ReconnectCircuits {
+    breakers: Mutex<[CircuitBreaker; Exchange::COUNT]>,
+}
+
+impl ReconnectCircuits {
+    /// Reset every breaker to closed with `config`
+    pub fn configure(&self, config: CircuitBreakerConfig) {
+        *self.breakers.lock() = std::array::from_fn(|_| CircuitBreaker::new(config));
+    }
+
+    /// See [`CircuitBreaker::allow_attempt`]
+    pub fn allow_attempt(&self, exchange: Exchange, now: Instant) -> bool {
+        self.breakers.lock()[exchange.index()].allow_attempt(now)
+    }
+
+    /// See [`CircuitBreaker::record_success`]
+    pub fn record_success(&self, exchange: Exchange) {
+        self.breakers.lock()[exchange.index()].record_success();
+    }
+
+    /// See [`CircuitBreaker::record_failure`]
+    pub fn record_failure(&self, exchange: Exchange, now: Instant) -> bool {
+        self.breakers.lock()[exchange.index()].record_failure(now)
+    }
+
+    /// Snapshot of one exchange at `now`
+    pub fn status(&self, exchange: Exchange, now: Instant) -> BreakerStatus {
+        self.breakers.lock()[exchange.index()].status(now)
+    }
+
+    /// Snapshot of every exchange at `now`
+    pub fn health(&self, now: Instant) -> Vec<ExchangeHealth> {
+        let breakers = self.breakers.lock();
+        Exchange::ALL
+            .into_iter()
+            .map(|exchange| ExchangeHealth {
+                exchange: exchange.name(),
+                circuit: breakers[exchange.index()].status(now),
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn breaker() -> CircuitBreaker {
+        CircuitBreaker::new(CircuitBreakerConfig {
+            failure_threshold: 3,
+            open_duration: Duration::from_secs(10),
+        })
+    }
+
+    #[test]
+    fn test_opens_after_threshold() {
+        let mut b = breaker();
+        let t0 = Instant::now();
+        assert!(!b.record_failure(t0));
+        assert!(!b.record_failure(t0));
+        assert!(b.allow_attempt(t0));
+        assert!(b.record_failure(t0));
+        assert_eq!(b.state(), CircuitState::Open);
+        assert!(!b.allow_attempt(t0 + Duration::from_secs(5)));
+        assert_eq!(b.status(t0 + Duration::from_secs(4)).retry_in_ms, 6_000);
+    }
+
+    #[test]
+    fn test_half_open_single_probe() {
+        let mut b = breaker();
+        let t0 = Instant::now();
+        for _ in 0..3 {
+            b.record_failure(t0);
+        }
+        let t1 = t0 + Duration::from_secs(10);
+        assert!(b.allow_attempt(t1));
+        assert_eq!(b.state(), CircuitState::HalfOpen);
+        assert!(!b.allow_attempt(t1)); // probe in flight
+
+        // Failed probe re-opens
+        assert!(b.record_failure(t1));
+        assert_eq!(b.state(), CircuitState::Open);
+        assert_eq!(b.status(t1).times_opened, 2);
+
+        // Successful probe closes
+        let t2 = t1 + Duration::from_secs(10);
+        assert!(b.allow_attempt(t2));
+        b.record_success();
+        assert_eq!(b.state(), CircuitState::Closed);
+        assert_eq!(b.status(t2).consecutive_failures, 0);
+    }
+
+    #[test]
+    fn test_success_resets_failures() {
+        let mut b = breaker();
+        let t0 = Instant::now();
+        b.record_failure(t0);
+        b.record_failure(t0);
+        b.record_success();
+        assert!(!b.record_failure(t0));
+        assert_eq!(b.state(), CircuitState::Closed);
+    }
+
+    #[test]
+    fn test_circuits_per_exchange() {
+        let circuits = ReconnectCircuits::default();
+        circuits.configure(CircuitBreakerConfig {
+            failure_threshold: 1,
+            open_duration: Duration::from_secs(10),
+        });
+        let t0 = Instant::now();
+        assert!(circuits.record_failure(Exchange::Bybit, t0));
+        assert!(!circuits.allow_attempt(Exchange::Bybit, t0));
+        assert!(circuits.allow_attempt(Exchange::Binance, t0));
+
+        let health = circuits.health(t0);
+        assert_eq!(health.len(), Exchange::COUNT);
+        assert_eq!(health[Exchange::Binance.index()].circuit.state, CircuitState::Closed);
+        assert_eq!(health[Exchange::Bybit.index()].exchange, "bybit");
+        assert_eq!(health[Exchange::Bybit.index()].circuit.state, CircuitState::Open);
+    }
+}
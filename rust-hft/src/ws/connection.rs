@@ -6,20 +6,33 @@
 //! - Disabled compression (reduces latency)
 //! - TCP optimizations (NODELAY, large buffers)
 //! - No logging in hot path
-
+//! - Optional source-address binding (multi-uplink servers)
+//! - Close-frame classification (scheduled rotation vs error)
+//! - Two-lane outbound queue drained by a writer task: control/trading
+//!   frames before bulk subscription frames (see [`super::outbound`])
+//! - Immediate pong replies to server pings, with ping cadence tracking
+
+use super::outbound::{Lane, LaneStats, OutboundQueue};
+use crate::core::clock;
+use crate::infrastructure::config::NetworkConfig;
+use bytes::Bytes;
 use std::time::Duration;
-use tokio::net::TcpStream;
+use tokio::net::{TcpSocket, TcpStream};
 use tokio::time::{timeout, Instant};
+use futures_util::stream::SplitStream;
 use tokio_tungstenite::{
-    connect_async,
-    tungstenite::protocol::Message,
+    client_async_tls, connect_async,
+    tungstenite::{client::IntoClientRequest, protocol::Message},
     MaybeTlsStream, WebSocketStream,
 };
 
+/// Read buffer per connection unless configured (see `hft.read_buffer_bytes`)
+pub const DEFAULT_READ_BUFFER_BYTES: usize = 64 * 1024;
+
 /// WebSocket connection optimized for HFT
 pub struct WebSocketConnection {
-    /// Underlying WebSocket stream
-    stream: WebSocketStream<MaybeTlsStream<TcpStream>>,
+    /// Read half of the WebSocket stream (the write half is owned by `outbound`)
+    stream: SplitStream<WebSocketStream<MaybeTlsStream<TcpStream>>>,
     /// Reusable read buffer (avoids allocation per message)
     read_buffer: Vec<u8>,
     /// Connection state
@@ -30,6 +43,90 @@ pub struct WebSocketConnection {
     url: String,
     /// Read buffer capacity
     buffer_capacity: usize,
+    /// Largest frame payload received (bytes)
+    max_frame_len: usize,
+    /// Time the connection was established (for lifetime rotation)
+    connected_at: Instant,
+    /// Close frame received from the server, if any
+    last_close: Option<CloseInfo>,
+    /// Writer task fed by one channel per lane
+    outbound: OutboundQueue,
+    /// Pings received from the server (all answered)
+    server_pings: ServerPingStats,
+}
+
+/// Classification of a server close frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CloseKind {
+    /// Normal closure (1000)
+    Normal,
+    /// Server-initiated rotation/maintenance; reconnect immediately
+    ScheduledRotation,
+    /// Protocol, policy or server error
+    Error,
+}
+
+/// Close frame received from the server
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CloseInfo {
+    pub code: u16,
+    pub reason: String,
+    pub kind: CloseKind,
+}
+
+/// Classify a close code/reason
+///
+/// 1001 (going away), 1012 (service restart) and 1013 (try again later) are
+/// sent on planned disconnects such as Binance's 24h connection lifetime;
+/// reasons mentioning the lifetime are treated the same way regardless of code.
+pub fn classify_close(code: u16, reason: &str) -> CloseKind {
+    let reason = reason.to_ascii_lowercase();
+    if reason.contains("24h") || reason.contains("lifetime") || reason.contains("expired") {
+        return CloseKind::ScheduledRotation;
+    }
+    match code {
+        1000 => CloseKind::Normal,
+        1001 | 1012 | 1013 => CloseKind::ScheduledRotation,
+        _ => CloseKind::Error,
+    }
+}
+
+/// Buffer sizes of a connection (for slow-consumer/leak diagnostics)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct BufferStats {
+    /// Allocated read buffer capacity (bytes)
+    pub read_buffer_capacity: usize,
+    /// Largest frame payload received so far (bytes)
+    pub max_frame_len: usize,
+    /// Outbound frames and queue delay per lane (indexed by Lane::index)
+    pub send_lanes: [LaneStats; Lane::COUNT],
+    /// Server ping cadence
+    pub server_pings: ServerPingStats,
+}
+
+/// Protocol-level pings sent by the server
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ServerPingStats {
+    /// Pings received (each answered with a pong)
+    pub received: u64,
+    /// Last ping (Unix millis, 0 = none yet)
+    pub last_ms: u64,
+    /// Gap between the last two pings (0 = fewer than two)
+    pub last_interval_ms: u64,
+    /// Longest gap between two pings
+    pub max_interval_ms: u64,
+}
+
+impl ServerPingStats {
+    /// Count a ping received at `now_ms`
+    pub fn record(&mut self, now_ms: u64) {
+        if self.last_ms != 0 {
+            self.last_interval_ms = now_ms.saturating_sub(self.last_ms);
+            self.max_interval_ms = self.max_interval_ms.max(self.last_interval_ms);
+        }
+        self.received += 1;
+        self.last_ms = now_ms;
+    }
 }
 
 /// Connection state
@@ -88,14 +185,93 @@ impl WebSocketConnection {
             Self::optimize_tcp_stream(tcp)?;
         }
 
-        Ok(Self {
-            stream: ws_stream,
-            read_buffer: Vec::with_capacity(64 * 1024), // 64KB initial
+        Ok(Self::from_stream(ws_stream, url))
+    }
+
+    /// Connect using outbound network settings (source address binding)
+    ///
+    /// Falls back to [`connect`](Self::connect) when no bind address is set.
+    pub async fn connect_with(url: &str, network: &NetworkConfig) -> Result<Self> {
+        if !network.is_bound() {
+            return Self::connect(url).await;
+        }
+
+        let tcp = timeout(Duration::from_secs(10), Self::connect_bound_tcp(url, network))
+            .await
+            .map_err(|_| WebSocketError::Timeout)??;
+
+        // Bound path owns the TCP stream, so NODELAY applies to TLS too
+        Self::optimize_tcp_stream(&tcp)?;
+
+        let (ws_stream, _) = timeout(Duration::from_secs(10), client_async_tls(url, tcp))
+            .await
+            .map_err(|_| WebSocketError::Timeout)?
+            .map_err(|e| WebSocketError::ConnectionFailed(e.to_string()))?;
+
+        Ok(Self::from_stream(ws_stream, url))
+    }
+
+    /// Resolve host and open TCP connection from configured local address
+    async fn connect_bound_tcp(url: &str, network: &NetworkConfig) -> Result<TcpStream> {
+        let request = url
+            .into_client_request()
+            .map_err(|e| WebSocketError::ConnectionFailed(e.to_string()))?;
+        let uri = request.uri();
+        let host = uri
+            .host()
+            .ok_or_else(|| WebSocketError::ConnectionFailed(format!("No host in {}", url)))?
+            .trim_start_matches('[')
+            .trim_end_matches(']');
+        let port = uri
+            .port_u16()
+            .unwrap_or(if uri.scheme_str() == Some("wss") { 443 } else { 80 });
+
+        let resolved = tokio::net::lookup_host((host, port))
+            .await
+            .map_err(|e| WebSocketError::ConnectionFailed(e.to_string()))?;
+
+        let mut last_error = None;
+        for remote in network.candidates(resolved) {
+            let socket = if remote.is_ipv4() {
+                TcpSocket::new_v4()
+            } else {
+                TcpSocket::new_v6()
+            }
+            .map_err(|e| WebSocketError::ConnectionFailed(e.to_string()))?;
+
+            if let Some(local) = network.local_addr_for(&remote) {
+                socket
+                    .bind(local)
+                    .map_err(|e| WebSocketError::ConnectionFailed(format!("bind {}: {}", local, e)))?;
+            }
+
+            match socket.connect(remote).await {
+                Ok(stream) => return Ok(stream),
+                Err(e) => last_error = Some(format!("{}: {}", remote, e)),
+            }
+        }
+
+        Err(WebSocketError::ConnectionFailed(last_error.unwrap_or_else(|| {
+            format!("No address of {} matches configured bind family", host)
+        })))
+    }
+
+    /// Wrap established stream, spawning its writer task
+    fn from_stream(stream: WebSocketStream<MaybeTlsStream<TcpStream>>, url: &str) -> Self {
+        let (sink, stream) = stream.split();
+        Self {
+            stream,
+            read_buffer: Vec::with_capacity(DEFAULT_READ_BUFFER_BYTES),
             state: ConnectionState::Connected,
             last_activity: Instant::now(),
             url: url.to_string(),
-            buffer_capacity: 64 * 1024,
-        })
+            buffer_capacity: DEFAULT_READ_BUFFER_BYTES,
+            max_frame_len: 0,
+            connected_at: Instant::now(),
+            last_close: None,
+            outbound: OutboundQueue::spawn(sink),
+            server_pings: ServerPingStats::default(),
+        }
     }
 
     /// Apply HFT TCP optimizations
@@ -112,31 +288,43 @@ impl WebSocketConnection {
         Ok(())
     }
 
-    /// Send a message
+    /// Send a message on the priority lane
     ///
     /// # HFT Notes
     /// - No logging in hot path
     /// - Returns immediately on error
     pub async fn send(&mut self, msg: Message) -> Result<()> {
+        self.send_on(Lane::Priority, msg).await
+    }
+
+    /// Queue a message on `lane` for the writer task
+    ///
+    /// Returns once queued, without waiting for the write; priority frames
+    /// are written before any remaining bulk frame. Fails once a write has
+    /// failed.
+    pub async fn send_on(&mut self, lane: Lane, msg: Message) -> Result<()> {
         if self.state != ConnectionState::Connected {
             return Err(WebSocketError::NotConnected);
         }
-
-        self.stream
-            .send(msg)
-            .await
-            .map_err(|e| WebSocketError::SendFailed(e.to_string()))?;
-
+        self.outbound
+            .push(lane, msg, std::time::Instant::now())
+            .map_err(WebSocketError::SendFailed)?;
         self.last_activity = Instant::now();
         Ok(())
     }
 
-    /// Send text message
+    /// Send text message (priority lane: auth, pings, orders)
     #[inline]
     pub async fn send_text(&mut self, text: &str) -> Result<()> {
         self.send(Message::text(text)).await
     }
 
+    /// Send text message on the bulk lane (subscription management)
+    #[inline]
+    pub async fn send_bulk_text(&mut self, text: &str) -> Result<()> {
+        self.send_on(Lane::Bulk, Message::text(text)).await
+    }
+
     /// Send binary message
     #[inline]
     pub async fn send_binary(
@@ -149,7 +337,6 @@ impl WebSocketConnection {
     /// Send ping message
     #[inline]
     pub async fn send_ping(&mut self) -> Result<()> {
-        use bytes::Bytes;
         self.send(Message::Ping(Bytes::new())).await
     }
 
@@ -159,6 +346,8 @@ impl WebSocketConnection {
     /// - Reuses internal buffer (no allocation per message)
     /// - Returns None on graceful close
     /// - No logging in hot path (except debug)
+    ///
+    /// Server pings are answered before being returned.
     pub async fn recv(&mut self) -> Result<Option<Message>> {
         if self.state != ConnectionState::Connected {
             return Err(WebSocketError::NotConnected);
@@ -167,6 +356,14 @@ impl WebSocketConnection {
         match self.stream.next().await {
             Some(Ok(msg)) => {
                 self.last_activity = Instant::now();
+                self.max_frame_len = self.max_frame_len.max(msg.len());
+                match &msg {
+                    Message::Close(frame) => {
+                        self.record_close(frame.as_ref().map(|f| (u16::from(f.code), f.reason.as_str())));
+                    }
+                    Message::Ping(payload) => self.answer_ping(payload.clone()).await?,
+                    _ => {}
+                }
                 Ok(Some(msg))
             }
             Some(Err(e)) => {
@@ -181,9 +378,51 @@ impl WebSocketConnection {
         }
     }
 
-    /// Set read buffer capacity
+    /// Reply to a server ping right away
+    ///
+    /// tungstenite queues an automatic pong but only writes it along with
+    /// the next outbound frame; a feed that only reads may never send one,
+    /// and Binance drops connections leaving pings unanswered for 10
+    /// minutes. An explicit pong replaces the queued one, so only one goes
+    /// out.
+    async fn answer_ping(&mut self, payload: Bytes) -> Result<()> {
+        self.server_pings.record(clock::coarse_unix_ms());
+        self.send(Message::Pong(payload)).await
+    }
+
+    /// Record and classify a server close frame (cold path)
+    fn record_close(&mut self, frame: Option<(u16, &str)>) {
+        // No frame = no status code received (1005)
+        let (code, reason) = frame.unwrap_or((1005, ""));
+        let kind = classify_close(code, reason);
+        match kind {
+            CloseKind::Error => tracing::warn!("WS closed by server: {} {} ({})", code, reason, self.url),
+            _ => tracing::info!("WS closed by server ({:?}): {} {} ({})", kind, code, reason, self.url),
+        }
+        self.last_close = Some(CloseInfo {
+            code,
+            reason: reason.to_string(),
+            kind,
+        });
+    }
+
+    /// Close frame received from the server, if any
+    #[inline]
+    pub fn last_close(&self) -> Option<&CloseInfo> {
+        self.last_close.as_ref()
+    }
+
+    /// Time since the connection was established
+    #[inline]
+    pub fn age(&self) -> Duration {
+        self.connected_at.elapsed()
+    }
+
+    /// Set read buffer capacity (shrinks or grows the buffer; call before reading)
     pub fn set_read_buffer_capacity(&mut self, size: usize) {
         self.buffer_capacity = size;
+        self.read_buffer.clear();
+        self.read_buffer.shrink_to(size);
         self.read_buffer.reserve(size);
     }
 
@@ -194,6 +433,17 @@ impl WebSocketConnection {
         &mut self.read_buffer
     }
 
+    /// Get buffer sizes
+    #[inline]
+    pub fn buffer_stats(&self) -> BufferStats {
+        BufferStats {
+            read_buffer_capacity: self.read_buffer.capacity(),
+            max_frame_len: self.max_frame_len,
+            send_lanes: self.outbound.stats(),
+            server_pings: self.server_pings,
+        }
+    }
+
     /// Get current connection state
     #[inline(always)]
     pub fn state(&self) -> ConnectionState {
@@ -213,13 +463,11 @@ impl WebSocketConnection {
     }
 
     /// Close the connection gracefully
+    ///
+    /// The close frame overtakes queued bulk frames, which are dropped.
     pub async fn close(&mut self) -> Result<()> {
         if self.state == ConnectionState::Connected {
-            let _ = self
-                .stream
-                .close(None)
-                .await
-                .map_err(|e| WebSocketError::SendFailed(e.to_string()));
+            self.outbound.close(Duration::from_secs(5)).await;
             self.state = ConnectionState::Disconnected;
         }
         Ok(())
@@ -231,12 +479,13 @@ impl WebSocketConnection {
     }
 }
 
-// Import needed for Stream and Sink traits
-use futures_util::{SinkExt, StreamExt};
+// Import needed for Stream traits
+use futures_util::StreamExt;
 
 #[cfg(test)]
 mod tests {
     use super::*;
+    use futures_util::SinkExt;
 
     // Note: These tests require a WebSocket echo server
     // For unit tests without network, we mock the behavior
@@ -254,6 +503,124 @@ mod tests {
         );
     }
 
+    #[tokio::test]
+    async fn test_connect_bound_family_mismatch() {
+        // IPv6-only bind cannot reach an IPv4 literal host
+        let network = NetworkConfig {
+            bind_ipv6: Some(std::net::Ipv6Addr::LOCALHOST),
+            ..Default::default()
+        };
+        let err = WebSocketConnection::connect_bound_tcp("ws://127.0.0.1:9", &network)
+            .await
+            .unwrap_err();
+        assert!(err.to_string().contains("bind family"));
+    }
+
+    #[tokio::test]
+    async fn test_connect_bound_local() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        let network = NetworkConfig {
+            bind_ipv4: Some(std::net::Ipv4Addr::LOCALHOST),
+            ..Default::default()
+        };
+
+        let url = format!("ws://127.0.0.1:{}/ws", port);
+        let stream = WebSocketConnection::connect_bound_tcp(&url, &network).await.unwrap();
+        assert!(stream.local_addr().unwrap().ip().is_loopback());
+    }
+
+    #[test]
+    fn test_classify_close() {
+        assert_eq!(classify_close(1000, ""), CloseKind::Normal);
+        assert_eq!(classify_close(1001, "going away"), CloseKind::ScheduledRotation);
+        assert_eq!(classify_close(1012, ""), CloseKind::ScheduledRotation);
+        assert_eq!(classify_close(1008, "policy violation"), CloseKind::Error);
+        assert_eq!(classify_close(1006, ""), CloseKind::Error);
+        // Lifetime reason wins over code
+        assert_eq!(
+            classify_close(1000, "Connection lifetime 24h exceeded"),
+            CloseKind::ScheduledRotation
+        );
+    }
+
+    #[tokio::test]
+    async fn test_server_ping_answered() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            ws.send(Message::Ping(Bytes::from_static(b"hb"))).await.unwrap();
+            ws.send(Message::Ping(Bytes::from_static(b"hb"))).await.unwrap();
+            // Both pongs arrive without the client sending anything else
+            let mut pongs = Vec::new();
+            while pongs.len() < 2 {
+                if let Message::Pong(payload) = ws.next().await.unwrap().unwrap() {
+                    pongs.push(payload);
+                }
+            }
+            pongs
+        });
+
+        let mut conn = WebSocketConnection::connect(&url).await.unwrap();
+        assert!(conn.recv().await.unwrap().unwrap().is_ping());
+        assert!(conn.recv().await.unwrap().unwrap().is_ping());
+        let pongs = timeout(Duration::from_secs(5), server).await.unwrap().unwrap();
+        assert_eq!(pongs, vec![Bytes::from_static(b"hb"); 2]);
+
+        let pings = conn.buffer_stats().server_pings;
+        assert_eq!(pings.received, 2);
+        assert!(pings.last_ms > 0);
+        // The writer counts a frame once its write returns
+        let written = async {
+            while conn.buffer_stats().send_lanes[Lane::Priority.index()].frames < 2 {
+                tokio::time::sleep(Duration::from_millis(1)).await;
+            }
+        };
+        timeout(Duration::from_secs(1), written).await.unwrap();
+
+        let mut stats = ServerPingStats::default();
+        stats.record(1_000);
+        stats.record(181_000);
+        stats.record(200_000);
+        assert_eq!((stats.received, stats.last_interval_ms, stats.max_interval_ms), (3, 19_000, 180_000));
+    }
+
+    #[tokio::test]
+    async fn test_priority_frame_overtakes_bulk_burst() {
+        const BURST: usize = 200;
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+        let (queued_tx, queued_rx) = tokio::sync::oneshot::channel::<()>();
+        let server = tokio::spawn(async move {
+            let (tcp, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(tcp).await.unwrap();
+            // Not reading until everything is queued: the socket fills up
+            queued_rx.await.unwrap();
+            let mut frames = Vec::new();
+            while frames.len() < BURST + 1 {
+                if let Message::Text(text) = ws.next().await.unwrap().unwrap() {
+                    frames.push(text.to_string());
+                }
+            }
+            frames
+        });
+
+        let mut conn = WebSocketConnection::connect(&url).await.unwrap();
+        let payload = "x".repeat(64 * 1024);
+        for i in 0..BURST {
+            conn.send_bulk_text(&format!("sub-{} {}", i, payload)).await.unwrap();
+        }
+        conn.send_text("order").await.unwrap();
+        queued_tx.send(()).unwrap();
+
+        let frames = timeout(Duration::from_secs(10), server).await.unwrap().unwrap();
+        let order_at = frames.iter().position(|f| f == "order").unwrap();
+        assert!(order_at < BURST / 2, "order written after {} bulk frames", order_at);
+        assert!(frames[order_at + 1..].iter().all(|f| f.starts_with("sub-")));
+    }
+
     #[test]
     fn test_websocket_error_display() {
         let err = WebSocketError::NotConnected;
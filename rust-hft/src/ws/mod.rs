@@ -1,10 +1,16 @@
 //! WebSocket clients for real-time market data
 
+pub mod circuit_breaker;
 pub mod connection;
+pub mod outbound;
 pub mod ping;
 pub mod pool;
+pub mod rotation;
 pub mod subscription;
 
-pub use connection::{WebSocketConnection, ConnectionState, WebSocketError};
+pub use circuit_breaker::{BreakerStatus, CircuitBreaker, CircuitBreakerConfig, CircuitState, ReconnectCircuits};
+pub use connection::{WebSocketConnection, ConnectionState, WebSocketError, BufferStats, CloseInfo, CloseKind, ServerPingStats};
+pub use outbound::{Lane, LaneStats, OutboundQueue};
 pub use ping::{PingHandler, ConnectionMonitor, HeartbeatManager, ConnectionHealth};
 pub use pool::{ConnectionPool, ConnectionConfig, ConnectionId, PoolStats};
+pub use rotation::{RotationPolicy, Rotator};
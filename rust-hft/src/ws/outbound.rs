@@ -0,0 +1,219 @@
+//! Outbound frame queue with priority lanes
+//!
+//! Control and trading frames (auth, pings, order place/cancel) go in the
+//! priority lane; subscribe/unsubscribe batches go in the bulk lane. A
+//! writer task owns the socket's sink and is fed by one channel per lane:
+//! callers enqueue without waiting for the write, and the writer always
+//! takes every queued priority frame before the next bulk frame, so an
+//! order never waits behind a burst of subscription requests (at most
+//! behind the one bulk frame being written). Queue delay (enqueue to
+//! written) is tracked per lane.
+
+use futures_util::{Sink, SinkExt};
+use parking_lot::Mutex;
+use std::fmt::Display;
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Outbound lane of a frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Lane {
+    /// Auth, pings, orders: never queued behind bulk frames
+    Priority,
+    /// Subscription management
+    Bulk,
+}
+
+impl Lane {
+    pub const COUNT: usize = 2;
+    pub const ALL: [Lane; Self::COUNT] = [Lane::Priority, Lane::Bulk];
+
+    #[inline]
+    pub const fn index(&self) -> usize {
+        match self {
+            Lane::Priority => 0,
+            Lane::Bulk => 1,
+        }
+    }
+
+    pub fn name(&self) -> &'static str {
+        match self {
+            Lane::Priority => "priority",
+            Lane::Bulk => "bulk",
+        }
+    }
+}
+
+/// Frames written from one lane and their queue delay
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct LaneStats {
+    pub frames: u64,
+    /// Sum of queue delays (ns)
+    pub total_delay_ns: u64,
+    /// Longest queue delay (ns)
+    pub max_delay_ns: u64,
+}
+
+impl LaneStats {
+    /// Mean queue delay (zero before the first frame)
+    pub fn mean_delay(&self) -> Duration {
+        Duration::from_nanos(self.total_delay_ns.checked_div(self.frames).unwrap_or(0))
+    }
+
+    fn record(&mut self, delay: Duration) {
+        let ns = delay.as_nanos().min(u64::MAX as u128) as u64;
+        self.frames += 1;
+        self.total_delay_ns = self.total_delay_ns.saturating_add(ns);
+        self.max_delay_ns = self.max_delay_ns.max(ns);
+    }
+}
+
+/// Writer state shared with the connection
+#[derive(Debug, Default)]
+struct WriterState {
+    stats: [LaneStats; Lane::COUNT],
+    /// Write error that stopped the writer
+    error: Option<String>,
+}
+
+type Frame = (Instant, Message);
+
+/// Sending side of a connection: one channel per lane into the writer task
+#[derive(Debug)]
+pub struct OutboundQueue {
+    lanes: [mpsc::UnboundedSender<Frame>; Lane::COUNT],
+    state: Arc<Mutex<WriterState>>,
+    writer: JoinHandle<()>,
+}
+
+impl OutboundQueue {
+    /// Spawn the writer task owning `sink`
+    pub fn spawn<S>(sink: S) -> Self
+    where
+        S: Sink<Message> + Unpin + Send + 'static,
+        S::Error: Display,
+    {
+        let (priority_tx, priority_rx) = mpsc::unbounded_channel();
+        let (bulk_tx, bulk_rx) = mpsc::unbounded_channel();
+        let state = Arc::new(Mutex::new(WriterState::default()));
+        let writer = tokio::spawn(write_frames(sink, priority_rx, bulk_rx, state.clone()));
+        Self {
+            lanes: [priority_tx, bulk_tx],
+            state,
+            writer,
+        }
+    }
+
+    /// Queue `msg` on `lane` at `now`; fails once the writer has stopped
+    #[inline]
+    pub fn push(&self, lane: Lane, msg: Message, now: Instant) -> Result<(), String> {
+        self.lanes[lane.index()].send((now, msg)).map_err(|_| {
+            self.state
+                .lock()
+                .error
+                .clone()
+                .unwrap_or_else(|| "writer stopped".to_string())
+        })
+    }
+
+    /// Per-lane totals (indexed by Lane::index)
+    #[inline]
+    pub fn stats(&self) -> [LaneStats; Lane::COUNT] {
+        self.state.lock().stats
+    }
+
+    /// Send a close frame ahead of queued bulk frames and wait up to
+    /// `timeout` for the writer to finish
+    pub async fn close(&mut self, timeout: Duration) {
+        if self.push(Lane::Priority, Message::Close(None), Instant::now()).is_ok() {
+            let _ = tokio::time::timeout(timeout, &mut self.writer).await;
+        }
+    }
+}
+
+/// Writer task: priority frames first, until both lanes close, a close
+/// frame is written or the sink fails
+async fn write_frames<S>(
+    mut sink: S,
+    mut priority: mpsc::UnboundedReceiver<Frame>,
+    mut bulk: mpsc::UnboundedReceiver<Frame>,
+    state: Arc<Mutex<WriterState>>,
+) where
+    S: Sink<Message> + Unpin,
+    S::Error: Display,
+{
+    loop {
+        let (lane, (queued, msg)) = tokio::select! {
+            biased;
+            Some(frame) = priority.recv() => (Lane::Priority, frame),
+            Some(frame) = bulk.recv() => (Lane::Bulk, frame),
+            else => break,
+        };
+        let closing = msg.is_close();
+        if let Err(e) = sink.send(msg).await {
+            state.lock().error = Some(e.to_string());
+            return;
+        }
+        state.lock().stats[lane.index()].record(queued.elapsed());
+        if closing {
+            break;
+        }
+    }
+    let _ = sink.close().await;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::convert::Infallible;
+    use tokio::sync::Semaphore;
+
+    #[tokio::test]
+    async fn test_priority_overtakes_queued_bulk() {
+        // Sink that writes one frame per permit, like a socket that is full
+        let (written_tx, mut written) = mpsc::unbounded_channel();
+        let permits = Arc::new(Semaphore::new(0));
+        let gate = permits.clone();
+        let sink = futures_util::sink::unfold((), move |(), msg: Message| {
+            let (gate, written_tx) = (gate.clone(), written_tx.clone());
+            async move {
+                gate.acquire().await.unwrap().forget();
+                let _ = written_tx.send(msg.into_text().unwrap().to_string());
+                Ok::<_, Infallible>(())
+            }
+        });
+        let queue = OutboundQueue::spawn(Box::pin(sink));
+        let t0 = Instant::now();
+        for i in 1..=3 {
+            queue.push(Lane::Bulk, Message::text(format!("sub-{}", i)), t0).unwrap();
+        }
+        permits.add_permits(1);
+        assert_eq!(written.recv().await.unwrap(), "sub-1");
+
+        // An order queued mid-burst overtakes the remaining bulk frames
+        queue.push(Lane::Priority, Message::text("order"), Instant::now()).unwrap();
+        permits.add_permits(3);
+        let mut order = Vec::new();
+        for _ in 0..3 {
+            order.push(written.recv().await.unwrap());
+        }
+        // "sub-2" may already be in the sink's hands when the order arrives
+        assert!(order == ["order", "sub-2", "sub-3"] || order == ["sub-2", "order", "sub-3"], "{:?}", order);
+
+        let stats = queue.stats();
+        assert_eq!((stats[Lane::Priority.index()].frames, stats[Lane::Bulk.index()].frames), (1, 3));
+    }
+
+    #[test]
+    fn test_lane_stats() {
+        let mut stats = LaneStats::default();
+        stats.record(Duration::from_micros(30));
+        stats.record(Duration::from_micros(10));
+        assert_eq!(stats.frames, 2);
+        assert_eq!(stats.mean_delay(), Duration::from_micros(20));
+        assert_eq!(stats.max_delay_ns, 30_000);
+    }
+}
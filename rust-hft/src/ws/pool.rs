@@ -10,9 +10,20 @@ use std::time::{Duration, Instant};
 use tokio::time::sleep;
 
 /// Connection identifier
+///
+/// Also tags every message a feed forwards to the engine (feed index =
+/// registration order), so gaps and latency are attributable per connection.
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ConnectionId(pub u64);
 
+impl ConnectionId {
+    /// Index into per-connection tables
+    #[inline(always)]
+    pub const fn index(self) -> usize {
+        self.0 as usize
+    }
+}
+
 /// Connection configuration
 #[derive(Debug, Clone)]
 pub struct ConnectionConfig {
@@ -154,7 +165,7 @@ impl ConnectionPool {
                         conn.reset_reconnect_delay();
                     }
                     Err(e) => {
-                        eprintln!("Failed to connect {:?}: {}", id, e);
+                        tracing::warn!("Failed to connect {:?}: {}", id, e);
                         conn.state = ConnectionState::Disconnected;
                     }
                 }
@@ -264,18 +275,15 @@ impl ConnectionPool {
                 }
 
                 // Update last activity if connected
-                match conn.connection {
-                    Some(ref ws_conn) => {
-                        let current_state = ws_conn.state();
-                        if current_state == ConnectionState::Connected {
-                            conn.last_activity = Instant::now();
-                        } else if current_state == ConnectionState::Disconnected {
-                            // Connection dropped
-                            conn.connection = None;
-                            conn.state = ConnectionState::Disconnected;
-                        }
+                if let Some(ref ws_conn) = conn.connection {
+                    let current_state = ws_conn.state();
+                    if current_state == ConnectionState::Connected {
+                        conn.last_activity = Instant::now();
+                    } else if current_state == ConnectionState::Disconnected {
+                        // Connection dropped
+                        conn.connection = None;
+                        conn.state = ConnectionState::Disconnected;
                     }
-                    None => {}
                 }
             }
         }
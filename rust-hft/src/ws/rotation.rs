@@ -0,0 +1,221 @@
+//! Make-before-break connection rotation
+//!
+//! Venues that hard-cap connection lifetime (Binance: 24h) drop the socket
+//! regardless of activity. Rotation replaces the connection ahead of time:
+//! 1. Client opens a replacement and replays its subscriptions
+//! 2. [`Rotator::switch`] waits until the replacement delivers market data
+//! 3. Frames already buffered on the old connection are drained
+//! 4. The old connection is closed and replaced
+//!
+//! Drained frames and the replacement's first data frame are returned as a
+//! backlog for the client to process before reading the new connection.
+//! Updates seen on both connections are dropped downstream by deduplication.
+//!
+//! Cold path: runs once per rotation.
+
+use crate::ws::connection::{WebSocketConnection, WebSocketError};
+use std::collections::VecDeque;
+use std::time::Duration;
+use tokio::time::{timeout, timeout_at, Instant};
+use tokio_tungstenite::tungstenite::protocol::Message;
+
+/// Rotation policy
+#[derive(Debug, Clone, Copy)]
+pub struct RotationPolicy {
+    /// Rotate connections older than this (None = never rotate)
+    pub max_age: Option<Duration>,
+    /// Max wait for the replacement to deliver data
+    pub ready_timeout: Duration,
+    /// Time spent draining the old connection after the replacement is ready
+    pub drain_window: Duration,
+    /// Delay before retrying a failed rotation
+    pub retry_delay: Duration,
+}
+
+impl RotationPolicy {
+    /// Policy that never rotates
+    pub fn disabled() -> Self {
+        Self { max_age: None, ..Self::default() }
+    }
+
+    /// Policy rotating after `max_age`
+    pub fn after(max_age: Duration) -> Self {
+        Self { max_age: Some(max_age), ..Self::default() }
+    }
+}
+
+impl Default for RotationPolicy {
+    fn default() -> Self {
+        Self {
+            max_age: None,
+            ready_timeout: Duration::from_secs(10),
+            drain_window: Duration::from_millis(50),
+            retry_delay: Duration::from_secs(60),
+        }
+    }
+}
+
+/// Tracks rotation schedule for a single-connection client
+#[derive(Debug, Clone)]
+pub struct Rotator {
+    policy: RotationPolicy,
+    /// Earliest next attempt (pushed back after a failure)
+    retry_at: Instant,
+}
+
+impl Rotator {
+    /// Create rotator with policy
+    pub fn new(policy: RotationPolicy) -> Self {
+        Self {
+            policy,
+            retry_at: Instant::now(),
+        }
+    }
+
+    /// Rotation policy
+    #[inline]
+    pub fn policy(&self) -> &RotationPolicy {
+        &self.policy
+    }
+
+    /// Whether `conn` should be rotated now
+    #[inline]
+    pub fn is_due(&self, conn: &WebSocketConnection) -> bool {
+        match self.policy.max_age {
+            Some(max_age) => conn.age() >= max_age && Instant::now() >= self.retry_at,
+            None => false,
+        }
+    }
+
+    /// Postpone the next attempt after a failed rotation
+    pub fn record_failure(&mut self) {
+        self.retry_at = Instant::now() + self.policy.retry_delay;
+    }
+
+    /// Switch `current` to `replacement` once the replacement delivers data
+    ///
+    /// `is_data` classifies text frames as market data (vs acks/pongs).
+    /// On error the current connection is left untouched and the next
+    /// attempt is postponed by `retry_delay`.
+    pub async fn switch<F>(
+        &mut self,
+        current: &mut Option<WebSocketConnection>,
+        mut replacement: WebSocketConnection,
+        is_data: F,
+    ) -> Result<VecDeque<Message>, WebSocketError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        let first = match timeout(self.policy.ready_timeout, Self::first_data(&mut replacement, &is_data)).await {
+            Ok(Ok(msg)) => msg,
+            Ok(Err(e)) => {
+                self.record_failure();
+                return Err(e);
+            }
+            Err(_) => {
+                self.record_failure();
+                return Err(WebSocketError::Timeout);
+            }
+        };
+
+        let mut backlog = VecDeque::new();
+        if let Some(mut old) = current.take() {
+            Self::drain(&mut old, self.policy.drain_window, &mut backlog).await;
+            let _ = old.close().await;
+        }
+        backlog.push_back(first);
+        *current = Some(replacement);
+        Ok(backlog)
+    }
+
+    /// Read until the first market data frame
+    async fn first_data<F>(conn: &mut WebSocketConnection, is_data: &F) -> Result<Message, WebSocketError>
+    where
+        F: Fn(&str) -> bool,
+    {
+        loop {
+            match conn.recv().await? {
+                Some(msg) => {
+                    if msg.to_text().is_ok_and(is_data) {
+                        return Ok(msg);
+                    }
+                }
+                None => return Err(WebSocketError::ReceiveFailed("closed before delivering data".to_string())),
+            }
+        }
+    }
+
+    /// Collect text frames from `old` for up to `window`
+    async fn drain(old: &mut WebSocketConnection, window: Duration, backlog: &mut VecDeque<Message>) {
+        let deadline = Instant::now() + window;
+        while let Ok(Ok(Some(msg))) = timeout_at(deadline, old.recv()).await {
+            if msg.is_close() {
+                break;
+            }
+            if msg.is_text() {
+                backlog.push_back(msg);
+            }
+        }
+    }
+}
+
+impl Default for Rotator {
+    fn default() -> Self {
+        Self::new(RotationPolicy::disabled())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_policy_constructors() {
+        assert!(RotationPolicy::disabled().max_age.is_none());
+        assert_eq!(
+            RotationPolicy::after(Duration::from_secs(5)).max_age,
+            Some(Duration::from_secs(5))
+        );
+    }
+
+    #[tokio::test]
+    async fn test_switch_drains_old_and_keeps_first_frame() {
+        use futures_util::{SinkExt, StreamExt};
+
+        // Old feed: one buffered frame; new feed: ack then data
+        let serve = |frames: Vec<&'static str>| async move {
+            let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+            let url = format!("ws://{}/ws", listener.local_addr().unwrap());
+            let handle = tokio::spawn(async move {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+                for frame in frames {
+                    ws.send(Message::text(frame)).await.unwrap();
+                }
+                // Old connection must be closed by the switch
+                while let Some(Ok(msg)) = ws.next().await {
+                    if msg.is_close() {
+                        return true;
+                    }
+                }
+                true
+            });
+            (url, handle)
+        };
+        let (old_url, old_server) = serve(vec!["old-1"]).await;
+        let (new_url, _new_server) = serve(vec!["ack", "new-1"]).await;
+
+        let mut current = Some(WebSocketConnection::connect(&old_url).await.unwrap());
+        let replacement = WebSocketConnection::connect(&new_url).await.unwrap();
+        let mut rotator = Rotator::new(RotationPolicy::after(Duration::ZERO));
+
+        let backlog = rotator
+            .switch(&mut current, replacement, |text| text != "ack")
+            .await
+            .unwrap();
+        let texts: Vec<&str> = backlog.iter().map(|m| m.to_text().unwrap()).collect();
+        assert_eq!(texts, vec!["old-1", "new-1"]);
+        assert_eq!(current.as_ref().unwrap().url(), new_url);
+        assert!(old_server.await.unwrap());
+    }
+}
@@ -2,13 +2,67 @@
 //!
 //! Manages symbol subscriptions with batching (200 symbols per request for Binance).
 //! Tracks pending and active subscriptions, handles confirmations and retries.
+//! With a [`VenueCapacity`] the manager also accounts streams per connection
+//! (requests beyond the venue cap are refused instead of silently dropped
+//! by the exchange) and paces outbound control messages.
 
 use crate::core::Symbol;
+use crate::{HftError, Result};
 use std::collections::{HashMap, HashSet};
+use std::time::{Duration, Instant};
 
 /// Maximum symbols per subscription batch (Binance limit)
 pub const MAX_BATCH_SIZE: usize = 200;
 
+/// Per-connection subscription limits of a venue
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct VenueCapacity {
+    /// Streams (topics) one connection may carry
+    pub streams_per_connection: usize,
+    /// Control messages (subscribe/unsubscribe) per second (None = not published)
+    pub messages_per_sec: Option<u32>,
+    /// Streams one symbol takes with every stream type subscribed
+    pub streams_per_symbol: usize,
+    /// Mark prices ride on the ticker stream (no stream of their own; the
+    /// client subscribes the ticker, which is what gets counted)
+    pub mark_on_ticker: bool,
+}
+
+impl VenueCapacity {
+    /// Binance USDⓈ-M futures: 1024 streams and 10 incoming messages/s per connection
+    pub const BINANCE: Self = Self {
+        streams_per_connection: 1024,
+        messages_per_sec: Some(10),
+        streams_per_symbol: 4,
+        mark_on_ticker: false,
+    };
+
+    /// Bybit V5 linear: the 21k-character args limit per connection, taken
+    /// conservatively at ~25 characters per topic
+    pub const BYBIT: Self = Self {
+        streams_per_connection: 800,
+        messages_per_sec: None,
+        streams_per_symbol: 4,
+        mark_on_ticker: true,
+    };
+
+    /// Symbols one connection carries with every stream type subscribed
+    pub fn symbols_per_connection(&self) -> usize {
+        (self.streams_per_connection / self.streams_per_symbol.max(1)).max(1)
+    }
+
+    /// Connections needed for `symbols` symbols
+    pub fn connections_for(&self, symbols: usize) -> usize {
+        symbols.div_ceil(self.symbols_per_connection())
+    }
+
+    /// Minimum gap between control messages (zero = unpaced)
+    pub fn send_spacing(&self) -> Duration {
+        self.messages_per_sec
+            .map_or(Duration::ZERO, |rate| Duration::from_secs(1) / rate.max(1))
+    }
+}
+
 /// Subscription request status
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum SubscriptionStatus {
@@ -38,6 +92,10 @@ pub enum StreamType {
     Trade,
     /// Ticker/bookTicker stream
     Ticker,
+    /// Mark/index price stream
+    MarkPrice,
+    /// Forced liquidation stream
+    Liquidation,
     /// Order book stream
     OrderBook,
     /// User data stream (private)
@@ -50,6 +108,8 @@ impl StreamType {
         match self {
             StreamType::Trade => "@aggTrade",
             StreamType::Ticker => "@bookTicker",
+            StreamType::MarkPrice => "@markPrice@1s",
+            StreamType::Liquidation => "@forceOrder",
             StreamType::OrderBook => "@depth",
             StreamType::UserData => "@userData",
         }
@@ -72,6 +132,10 @@ pub struct SubscriptionManager {
     active_by_type: HashMap<StreamType, HashSet<Symbol>>,
     /// Maximum retry attempts
     max_retries: u32,
+    /// Venue limits (None = unlimited)
+    capacity: Option<VenueCapacity>,
+    /// Earliest time the next control message may go out
+    next_send: Option<Instant>,
 }
 
 impl SubscriptionManager {
@@ -87,7 +151,67 @@ impl SubscriptionManager {
             subscriptions: HashMap::new(),
             active_by_type,
             max_retries: 3,
+            capacity: None,
+            next_send: None,
+        }
+    }
+
+    /// Create a manager enforcing `capacity`
+    pub fn with_capacity(capacity: VenueCapacity) -> Self {
+        Self {
+            capacity: Some(capacity),
+            ..Self::new()
+        }
+    }
+
+    /// Venue limits (None = unlimited)
+    pub fn capacity(&self) -> Option<VenueCapacity> {
+        self.capacity
+    }
+
+    /// Streams currently requested or active on the connection
+    pub fn stream_count(&self) -> usize {
+        let mark_on_ticker = self.capacity.is_some_and(|c| c.mark_on_ticker);
+        self.subscriptions
+            .values()
+            .filter(|sub| matches!(sub.status, SubscriptionStatus::Pending | SubscriptionStatus::Active))
+            .filter(|sub| !(mark_on_ticker && sub.stream_type == StreamType::MarkPrice))
+            .count()
+    }
+
+    /// Refuse a request that would take the connection past its stream cap
+    pub fn check_capacity(&self, symbols: &[Symbol], stream_type: StreamType) -> Result<()> {
+        let Some(capacity) = self.capacity else {
+            return Ok(());
+        };
+        if capacity.mark_on_ticker && stream_type == StreamType::MarkPrice {
+            return Ok(());
+        }
+        let added = symbols
+            .iter()
+            .filter(|&&s| !self.is_subscribed(s, stream_type))
+            .collect::<HashSet<_>>()
+            .len();
+        let total = self.stream_count() + added;
+        if total > capacity.streams_per_connection {
+            return Err(HftError::Config(format!(
+                "subscribing {} {:?} streams needs {} streams on one connection (limit {}); \
+                 shard the symbols across more connections",
+                added, stream_type, total, capacity.streams_per_connection
+            )));
         }
+        Ok(())
+    }
+
+    /// Reserve a slot for one control message at `now`; returns how long to wait before sending
+    pub fn reserve_send(&mut self, now: Instant) -> Duration {
+        let spacing = self.capacity.map_or(Duration::ZERO, |c| c.send_spacing());
+        if spacing.is_zero() {
+            return Duration::ZERO;
+        }
+        let at = self.next_send.map_or(now, |next| next.max(now));
+        self.next_send = Some(at + spacing);
+        at - now
     }
 
     /// Request subscription for symbols
@@ -99,14 +223,17 @@ impl SubscriptionManager {
         for &symbol in symbols {
             let key = (symbol, stream_type);
 
-            if !self.subscriptions.contains_key(&key) {
-                let subscription = Subscription {
-                    symbol,
-                    status: SubscriptionStatus::Pending,
-                    retry_count: 0,
-                    stream_type,
-                };
-                self.subscriptions.insert(key, subscription);
+            let sub = self.subscriptions.entry(key).or_insert(Subscription {
+                symbol,
+                status: SubscriptionStatus::Pending,
+                retry_count: 0,
+                stream_type,
+            });
+
+            // Re-subscribing after a cancel starts over
+            if sub.status == SubscriptionStatus::Cancelled {
+                sub.status = SubscriptionStatus::Pending;
+                sub.retry_count = 0;
             }
         }
     }
@@ -194,6 +321,19 @@ impl SubscriptionManager {
             .unwrap_or_default()
     }
 
+    /// Get all pending or active subscriptions for a stream type
+    /// (replayed on a fresh connection after rotation/reconnect)
+    pub fn get_subscribed(&self, stream_type: StreamType) -> Vec<Symbol> {
+        self.subscriptions
+            .values()
+            .filter(|sub| {
+                sub.stream_type == stream_type
+                    && matches!(sub.status, SubscriptionStatus::Pending | SubscriptionStatus::Active)
+            })
+            .map(|sub| sub.symbol)
+            .collect()
+    }
+
     /// Check if symbol is subscribed for stream type
     pub fn is_subscribed(&self, symbol: Symbol, stream_type: StreamType) -> bool {
         let key = (symbol, stream_type);
@@ -276,11 +416,9 @@ impl Default for SubscriptionManager {
 }
 
 #[cfg(test)]
-use crate::test_utils::init_test_registry;
 mod tests {
     use super::*;
-    use crate::core::registry::SymbolRegistry;
-
+    use crate::test_utils::init_test_registry;
 
     fn btc() -> Symbol {
         Symbol::from_bytes(b"BTCUSDT").unwrap()
@@ -307,6 +445,18 @@ mod tests {
         assert!(manager.is_subscribed(eth(), StreamType::Trade));
     }
 
+    #[test]
+    fn test_get_subscribed() {
+        init_test_registry();
+        let mut manager = SubscriptionManager::new();
+        manager.request_subscription(&[btc(), eth()], StreamType::Ticker);
+        manager.confirm(&[btc()], StreamType::Ticker);
+        manager.cancel_subscription(&[eth()], StreamType::Ticker);
+
+        assert_eq!(manager.get_subscribed(StreamType::Ticker), vec![btc()]);
+        assert!(manager.get_subscribed(StreamType::Trade).is_empty());
+    }
+
     #[test]
     fn test_confirm_subscription() {
         init_test_registry();
@@ -351,6 +501,10 @@ mod tests {
 
         manager.cancel_subscription(&[btc()], StreamType::Trade);
         assert!(!manager.is_subscribed(btc(), StreamType::Trade));
+
+        // Subscribing again after a cancel is pending again
+        manager.request_subscription(&[btc()], StreamType::Trade);
+        assert_eq!(manager.get_subscribed(StreamType::Trade), vec![btc()]);
     }
 
     #[test]
@@ -395,6 +549,55 @@ mod tests {
         assert_eq!(manager.total_count(), 0);
         assert_eq!(manager.active_count(StreamType::Trade), 0);
     }
+
+    #[test]
+    fn test_capacity_refuses_overflow() {
+        init_test_registry();
+        let capacity = VenueCapacity {
+            streams_per_connection: 3,
+            messages_per_sec: None,
+            streams_per_symbol: 2,
+            mark_on_ticker: true,
+        };
+        assert_eq!(capacity.symbols_per_connection(), 1);
+        assert_eq!(capacity.connections_for(3), 3);
+        assert_eq!(VenueCapacity::BINANCE.connections_for(200), 1);
+        assert_eq!(VenueCapacity::BINANCE.connections_for(300), 2);
+
+        let mut manager = SubscriptionManager::with_capacity(capacity);
+        manager.check_capacity(&[btc(), eth()], StreamType::Ticker).unwrap();
+        manager.request_subscription(&[btc(), eth()], StreamType::Ticker);
+        // Marks ride on the tickers; resubscribing costs nothing
+        manager.check_capacity(&[btc(), eth()], StreamType::MarkPrice).unwrap();
+        manager.request_subscription(&[btc(), eth()], StreamType::MarkPrice);
+        manager.check_capacity(&[btc()], StreamType::Ticker).unwrap();
+        assert_eq!(manager.stream_count(), 2);
+
+        manager.check_capacity(&[btc()], StreamType::Trade).unwrap();
+        let err = manager.check_capacity(&[btc(), eth()], StreamType::Trade).unwrap_err();
+        assert!(err.to_string().contains("limit 3"), "{}", err);
+
+        // Cancelled streams free their slots
+        manager.cancel_subscription(&[eth()], StreamType::Ticker);
+        assert_eq!(manager.stream_count(), 1);
+        manager.check_capacity(&[btc(), eth()], StreamType::Trade).unwrap();
+        assert!(SubscriptionManager::new().check_capacity(&[btc()], StreamType::Trade).is_ok());
+    }
+
+    #[test]
+    fn test_reserve_send_paces_messages() {
+        let mut manager = SubscriptionManager::with_capacity(VenueCapacity::BINANCE);
+        let now = Instant::now();
+        assert_eq!(manager.reserve_send(now), Duration::ZERO);
+        assert_eq!(manager.reserve_send(now), Duration::from_millis(100));
+        assert_eq!(manager.reserve_send(now), Duration::from_millis(200));
+        // An idle gap refills the budget
+        assert_eq!(manager.reserve_send(now + Duration::from_secs(1)), Duration::ZERO);
+
+        let mut unpaced = SubscriptionManager::with_capacity(VenueCapacity::BYBIT);
+        assert_eq!(unpaced.reserve_send(now), Duration::ZERO);
+        assert_eq!(unpaced.reserve_send(now), Duration::ZERO);
+    }
 }
 
 // HFT Checklist:
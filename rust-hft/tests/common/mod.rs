@@ -0,0 +1,109 @@
+//! Mock exchange server for integration tests
+//!
+//! Serves the REST endpoints used by symbol discovery and one WebSocket
+//! endpoint per venue. Each WebSocket connection waits for the client's
+//! first subscription, replays a scripted list of frames, drains further
+//! client messages until idle, then closes so the engine loop can finish.
+
+#![allow(dead_code)]
+
+use axum::{routing::get, Json, Router};
+use futures_util::{SinkExt, StreamExt};
+use std::net::SocketAddr;
+use std::time::Duration;
+use tokio::net::TcpListener;
+use tokio_tungstenite::tungstenite::Message;
+
+/// Client is considered done subscribing after this much silence
+const IDLE: Duration = Duration::from_millis(300);
+
+/// Running mock exchange endpoints
+pub struct MockExchange {
+    pub rest_addr: SocketAddr,
+    pub binance_ws: String,
+    pub bybit_ws: String,
+}
+
+impl MockExchange {
+    /// Start REST and WebSocket servers on ephemeral local ports
+    pub async fn start(
+        symbols: &[(&str, f64)],
+        binance_frames: Vec<String>,
+        bybit_frames: Vec<String>,
+    ) -> Self {
+        let rest_addr = serve_rest(symbols).await;
+        let binance_ws = format!("ws://{}/ws", serve_ws(binance_frames).await);
+        let bybit_ws = format!("ws://{}/v5/public/linear", serve_ws(bybit_frames).await);
+        Self {
+            rest_addr,
+            binance_ws,
+            bybit_ws,
+        }
+    }
+
+    /// Binance 24h ticker endpoint URL
+    pub fn binance_rest(&self) -> String {
+        format!("http://{}/fapi/v1/ticker/24hr", self.rest_addr)
+    }
+
+    /// Bybit linear tickers endpoint URL
+    pub fn bybit_rest(&self) -> String {
+        format!("http://{}/v5/market/tickers?category=linear", self.rest_addr)
+    }
+}
+
+async fn serve_rest(symbols: &[(&str, f64)]) -> SocketAddr {
+    let binance: Vec<serde_json::Value> = symbols
+        .iter()
+        .map(|(s, vol)| serde_json::json!({ "symbol": s, "quoteVolume": vol }))
+        .collect();
+    let bybit = serde_json::json!({
+        "retCode": 0,
+        "retMsg": "OK",
+        "result": {
+            "list": symbols
+                .iter()
+                .map(|(s, vol)| serde_json::json!({
+                    "symbol": s,
+                    "volume24h": vol.to_string(),
+                    "lastPrice": "1",
+                }))
+                .collect::<Vec<_>>(),
+        },
+    });
+
+    let app = Router::new()
+        .route("/fapi/v1/ticker/24hr", get(move || async move { Json(binance) }))
+        .route("/v5/market/tickers", get(move || async move { Json(bybit) }));
+
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        axum::serve(listener, app).await.unwrap();
+    });
+    addr
+}
+
+async fn serve_ws(frames: Vec<String>) -> SocketAddr {
+    let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+    let addr = listener.local_addr().unwrap();
+    tokio::spawn(async move {
+        let (stream, _) = listener.accept().await.unwrap();
+        let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+
+        // Wait for first subscription before replaying
+        while let Some(Ok(msg)) = ws.next().await {
+            if msg.is_text() {
+                break;
+            }
+        }
+        for frame in frames {
+            ws.send(Message::text(frame)).await.unwrap();
+        }
+
+        // Drain remaining subscriptions until the client goes quiet
+        while let Ok(Some(Ok(_))) = tokio::time::timeout(IDLE, ws.next()).await {}
+        let _ = ws.close(None).await;
+    });
+    addr
+}
@@ -0,0 +1,293 @@
+//! End-to-end pipeline test against the mock exchange
+//!
+//! discovery → registry init → subscriptions → tickers from both venues
+//! → tracker spread crosses threshold → strategy decision → paper order,
+//! and the paper orders' simulated acknowledgements against the execution
+//! budget.
+
+mod common;
+
+use common::MockExchange;
+use rust_hft::core::registry::SymbolRegistry;
+use rust_hft::core::{Bps, DelistingNotice, FixedPoint8, Side, Symbol, SymbolDiscovery};
+use rust_hft::engine::{Action, AppEngine, AttemptOutcome, FailoverGuard, Fill, OrderRequest, RestartPolicy, Strategy};
+use rust_hft::exchanges::{BinanceWsClient, BybitWsClient, Exchange, ExchangeClient};
+use rust_hft::hot_path::{SpreadEvent, ThresholdTracker};
+use rust_hft::infrastructure::config::{Config, ExecutionConfig, FailoverRole, StandbyConfig};
+use rust_hft::infrastructure::metrics::MetricsCollector;
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+use tokio::sync::{mpsc, RwLock};
+
+/// Spread threshold for the test strategy (0.25%)
+const THRESHOLD: Bps = Bps::from_bps(25);
+
+/// Buys the cheap leg once the spread crosses THRESHOLD, recording decisions
+struct Recorder {
+    decisions: Arc<Mutex<Vec<Action>>>,
+}
+
+impl Strategy for Recorder {
+    fn name(&self) -> &str {
+        "recorder"
+    }
+
+    fn on_spread(&mut self, event: &SpreadEvent) -> Vec<Action> {
+        if event.spread < THRESHOLD {
+            return Vec::new();
+        }
+        let action = Action::PlaceOrder(OrderRequest {
+            exchange: event.long_ex,
+            symbol: event.symbol,
+            side: Side::Buy,
+            quantity: FixedPoint8::ONE,
+            price: None,
+        });
+        self.decisions.lock().unwrap().push(action.clone());
+        vec![action]
+    }
+}
+
+/// Opens both legs of the first opportunity over THRESHOLD
+struct BothLegs {
+    fired: bool,
+}
+
+impl Strategy for BothLegs {
+    fn name(&self) -> &str {
+        "both-legs"
+    }
+
+    fn on_spread(&mut self, event: &SpreadEvent) -> Vec<Action> {
+        if self.fired || event.spread < THRESHOLD {
+            return Vec::new();
+        }
+        self.fired = true;
+        let leg = |exchange, side| {
+            Action::PlaceOrder(OrderRequest {
+                exchange,
+                symbol: event.symbol,
+                side,
+                quantity: FixedPoint8::ONE,
+                price: None,
+            })
+        };
+        vec![leg(event.long_ex, Side::Buy), leg(event.short_ex, Side::Sell)]
+    }
+}
+
+fn binance_book(symbol: &str, update_id: u64, bid: &str, ask: &str) -> String {
+    format!(
+        r#"{{"e":"bookTicker","u":{},"s":"{}","b":"{}","B":"2.0","a":"{}","A":"2.0","T":1700000000000,"E":1700000000000}}"#,
+        update_id, symbol, bid, ask
+    )
+}
+
+fn bybit_ticker(symbol: &str, seq: u64, bid: &str, ask: &str) -> String {
+    format!(
+        r#"{{"topic":"tickers.{}","type":"snapshot","data":{{"symbol":"{}","bid1Price":"{}","bid1Size":"2.0","ask1Price":"{}","ask1Size":"2.0"}},"cs":{},"ts":1700000000000}}"#,
+        symbol, symbol, bid, ask, seq
+    )
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_pipeline_discovery_to_decision() {
+    let mock = MockExchange::start(
+        &[("BTCUSDT", 5.0e9), ("ETHUSDT", 2.0e9)],
+        vec![
+            binance_book("BTCUSDT", 1, "50000.0", "50001.0"),
+            binance_book("ETHUSDT", 1, "3000.0", "3000.5"),
+        ],
+        vec![
+            // BTC: Bybit bid 50300 vs Binance ask 50001 → ~0.6%
+            bybit_ticker("BTCUSDT", 1, "50300.0", "50301.0"),
+            // ETH: no opportunity
+            bybit_ticker("ETHUSDT", 1, "3000.1", "3000.6"),
+        ],
+    )
+    .await;
+
+    // Discovery and registry init (same sequence as main)
+    let discovery = SymbolDiscovery::new().with_endpoints(mock.binance_rest(), mock.bybit_rest());
+    let names = discovery.fetch_symbol_names().await.unwrap();
+    assert_eq!(names, vec!["BTCUSDT".to_string(), "ETHUSDT".to_string()]);
+    SymbolRegistry::initialize(&names).unwrap();
+    let symbols: Vec<_> = discovery
+        .fetch_all_liquid()
+        .await
+        .unwrap()
+        .into_iter()
+        .map(|d| d.symbol)
+        .collect();
+    assert_eq!(symbols.len(), 2);
+
+    let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+    let metrics = Arc::new(MetricsCollector::new());
+    let mut engine = AppEngine::new(tracker.clone(), metrics.clone());
+    let decisions = Arc::new(Mutex::new(Vec::new()));
+    engine.add_strategy(Box::new(Recorder {
+        decisions: decisions.clone(),
+    }));
+    let (binance_ws, bybit_ws) = (mock.binance_ws.clone(), mock.bybit_ws.clone());
+    engine.add_exchange(move || ExchangeClient::Binance(BinanceWsClient::new().with_url(&binance_ws)));
+    engine.add_exchange(move || ExchangeClient::Bybit(BybitWsClient::new().with_url(&bybit_ws)));
+    engine.set_restart_policy(RestartPolicy::never());
+
+    // Without restarts the engine returns once the mock closes both feeds
+    tokio::time::timeout(Duration::from_secs(10), engine.run(&symbols))
+        .await
+        .expect("engine did not finish")
+        .unwrap();
+
+    // Both venues delivered and the tracker sees both symbols
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.binance_messages, 2);
+    assert_eq!(snapshot.bybit_messages, 2);
+    let stats = tracker.write().await.get_all_stats();
+    assert_eq!(stats.len(), 2);
+
+    // Exactly one decision: buy BTC on Binance
+    let decisions = std::mem::take(&mut *decisions.lock().unwrap());
+    assert_eq!(decisions.len(), 1);
+    match &decisions[0] {
+        Action::PlaceOrder(order) => {
+            assert_eq!(order.symbol.as_str(), "BTCUSDT");
+            assert_eq!(order.exchange, rust_hft::exchanges::Exchange::Binance);
+        }
+        other => panic!("unexpected action {:?}", other),
+    }
+
+    // Opportunity was sized and journaled
+    let journal = engine.journal();
+    let journal = journal.read().await;
+    let record = journal.recent(1).next().expect("opportunity not journaled");
+    assert_eq!(record.symbol.as_str(), "BTCUSDT");
+    assert!(record.size.quantity.is_positive());
+}
+
+/// Run a BTC opportunity through an engine executing paper orders with `execution`
+async fn paper_session(execution: ExecutionConfig) -> (AppEngine, Arc<MetricsCollector>) {
+    let mock = MockExchange::start(
+        &[("BTCUSDT", 5.0e9)],
+        vec![binance_book("BTCUSDT", 1, "50000.0", "50001.0")],
+        vec![bybit_ticker("BTCUSDT", 1, "50300.0", "50301.0")],
+    )
+    .await;
+    let discovery = SymbolDiscovery::new().with_endpoints(mock.binance_rest(), mock.bybit_rest());
+    SymbolRegistry::initialize(&discovery.fetch_symbol_names().await.unwrap()).unwrap();
+    let symbols: Vec<_> = discovery.fetch_all_liquid().await.unwrap().into_iter().map(|d| d.symbol).collect();
+
+    let tracker = Arc::new(RwLock::new(ThresholdTracker::new()));
+    let metrics = Arc::new(MetricsCollector::new());
+    let mut engine = AppEngine::new(tracker, metrics.clone());
+    engine.set_execution_config(&execution, Config::default().trading_permit().unwrap());
+    engine.add_strategy(Box::new(BothLegs { fired: false }));
+    let (binance_ws, bybit_ws) = (mock.binance_ws.clone(), mock.bybit_ws.clone());
+    engine.add_exchange(move || ExchangeClient::Binance(BinanceWsClient::new().with_url(&binance_ws)));
+    engine.add_exchange(move || ExchangeClient::Bybit(BybitWsClient::new().with_url(&bybit_ws)));
+    engine.set_restart_policy(RestartPolicy::never());
+
+    // The mock keeps both feeds open while idle, past the acks and the budget
+    tokio::time::timeout(Duration::from_secs(10), engine.run(&symbols))
+        .await
+        .expect("engine did not finish")
+        .unwrap();
+    assert_eq!(metrics.snapshot().orders_submitted, 2);
+    (engine, metrics)
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_default_throttle_submits_both_legs() {
+    // Default cooldown: the second leg must not be held back by the first
+    let (_engine, metrics) = paper_session(ExecutionConfig::default()).await;
+    let snapshot = metrics.snapshot();
+    assert_eq!(snapshot.orders_submitted, 2);
+    assert_eq!(snapshot.suppressed_cooldown, 0);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_paper_acks_measure_order_latency() {
+    let (engine, metrics) = paper_session(ExecutionConfig {
+        paper_ack_ms: 5,
+        leg_budget_ms: 150,
+        ..Default::default()
+    })
+    .await;
+
+    // Both legs acknowledged through the engine: one round trip per venue
+    let latency = metrics.order_latency();
+    assert_eq!(latency.len(), 2);
+    for venue in &latency {
+        assert_eq!(venue.samples, 1);
+        assert!(venue.p50_ms >= 5.0, "{:?}", venue);
+    }
+    assert!(engine.order_latency().summary(Exchange::Binance).is_some());
+
+    let journal = engine.journal();
+    let journal = journal.read().await;
+    let attempt = journal.recent_attempts(1).next().expect("attempt not journaled");
+    assert_eq!(attempt.outcome, AttemptOutcome::Completed);
+    let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+    assert!(!engine.open_episode(btc).expect("episode open").aborted);
+}
+
+#[tokio::test(flavor = "multi_thread", worker_threads = 2)]
+async fn test_unacked_legs_abort_episode() {
+    // Acks due long after the budget runs out
+    let (engine, metrics) = paper_session(ExecutionConfig {
+        paper_ack_ms: 60_000,
+        leg_budget_ms: 20,
+        ..Default::default()
+    })
+    .await;
+
+    assert!(metrics.order_latency().is_empty());
+    let journal = engine.journal();
+    let journal = journal.read().await;
+    let attempt = journal.recent_attempts(1).next().expect("attempt not journaled");
+    assert_eq!(attempt.outcome, AttemptOutcome::Aborted);
+    assert_eq!(attempt.unacked().count(), 2);
+    let btc = Symbol::from_bytes(b"BTCUSDT").unwrap();
+    assert!(engine.open_episode(btc).expect("episode open").aborted);
+}
+
+/// Paper engine holding a long ETH position on Bybit
+fn engine_with_position() -> (AppEngine, Arc<MetricsCollector>, Symbol) {
+    SymbolRegistry::initialize(&["ETHUSDT".to_string()]).unwrap();
+    let eth = Symbol::from_bytes(b"ETHUSDT").unwrap();
+    let metrics = Arc::new(MetricsCollector::new());
+    let mut engine = AppEngine::new(Arc::new(RwLock::new(ThresholdTracker::new())), metrics.clone());
+    engine.set_execution_config(&ExecutionConfig::default(), Config::default().trading_permit().unwrap());
+    engine.dispatch_fill(&Fill {
+        exchange: Exchange::Bybit,
+        symbol: eth,
+        side: Side::Buy,
+        price: FixedPoint8::from_f64(3000.0).unwrap(),
+        quantity: FixedPoint8::from_f64(1.0).unwrap(),
+        timestamp: 0,
+    });
+    (engine, metrics, eth)
+}
+
+fn delisting(symbol: Symbol) -> DelistingNotice {
+    DelistingNotice { symbol, exchange: Exchange::Bybit, status: "SETTLING".to_string(), delivery_time_ms: None }
+}
+
+#[test]
+fn test_delisting_flattens_open_position() {
+    let (mut engine, metrics, eth) = engine_with_position();
+    engine.handle_delisting(delisting(eth));
+    assert_eq!(metrics.snapshot().orders_submitted, 1);
+}
+
+#[test]
+fn test_delisting_on_standby_submits_nothing() {
+    let (mut engine, metrics, eth) = engine_with_position();
+    let standby = StandbyConfig { role: FailoverRole::Standby, ..Default::default() };
+    let (tx, _rx) = mpsc::channel(8);
+    engine.set_failover(FailoverGuard::new(&standby, Instant::now()), tx, Duration::from_secs(1));
+
+    engine.handle_delisting(delisting(eth));
+    assert!(engine.is_delisted(eth));
+    assert_eq!(metrics.snapshot().orders_submitted, 0);
+}
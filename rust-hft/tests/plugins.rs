@@ -0,0 +1,40 @@
+//! Strategies registered outside the engine crate reach the plugin registry
+
+#![cfg(feature = "plugins")]
+
+use rust_hft::engine::plugins::StrategyRegistry;
+use rust_hft::engine::Strategy;
+use rust_hft::infrastructure::config::StrategyConfig;
+
+struct External(String);
+
+impl Strategy for External {
+    fn name(&self) -> &str {
+        &self.0
+    }
+}
+
+fn make_external(params: &toml::Table) -> rust_hft::Result<Box<dyn Strategy>> {
+    let label = params.get("label").and_then(|v| v.as_str()).unwrap_or("external");
+    Ok(Box::new(External(label.to_string())))
+}
+
+rust_hft::register_strategy!("external", make_external);
+
+#[test]
+fn test_linked_strategy_built_from_config() {
+    let registry = StrategyRegistry::linked();
+    assert!(registry.names().any(|name| name == "external"));
+
+    let config: StrategyConfig = toml::from_str(
+        r#"
+        enabled = ["external"]
+        [params.external]
+        label = "from-config"
+        "#,
+    )
+    .unwrap();
+    let strategies = registry.build_enabled(&config).unwrap();
+    assert_eq!(strategies.len(), 1);
+    assert_eq!(strategies[0].name(), "from-config");
+}